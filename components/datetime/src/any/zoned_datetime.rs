@@ -146,6 +146,8 @@ impl ZonedAnyDateTimeFormat {
             + ResourceProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneRegionNamesV1Marker>
             + ?Sized,
         PP: ResourceProvider<OrdinalV1Marker> + ?Sized,
         DEP: ResourceProvider<DecimalSymbolsV1Marker> + ?Sized,
@@ -195,7 +197,8 @@ impl ZonedAnyDateTimeFormat {
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
     /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `plurals/ordinals@1`,
     /// `time_zone/formats@1`, `time_zone/exemplar_cities@1`, `time_zone/generic_long@1`, `time_zone/generic_short@1`,
-    /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`.
+    /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`,
+    /// `time_zone/identifiers@1`, `time_zone/region_names@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
@@ -229,7 +232,8 @@ impl ZonedAnyDateTimeFormat {
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
     /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `plurals/ordinals@1`,
     /// `time_zone/formats@1`, `time_zone/exemplar_cities@1`, `time_zone/generic_long@1`, `time_zone/generic_short@1`,
-    /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`.
+    /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`,
+    /// `time_zone/identifiers@1`, `time_zone/region_names@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
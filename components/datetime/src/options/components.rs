@@ -501,6 +501,21 @@ pub enum TimeZoneName {
     //  * falling back to long localized GMT
     /// Long generic non-location format (e.g.: Pacific Time, Nordamerikanische Westküstenzeit),
     LongGeneric,
+
+    // UTS-35 fields: VV
+    //  * falling back to the unknown time zone, Etc/Unknown
+    /// The long time zone ID, which is the canonical IANA ID (e.g.: America/Los_Angeles).
+    TimeZoneId,
+
+    // UTS-35 fields: VVV
+    //  * falling back to the exemplar city of the unknown time zone, Etc/Unknown
+    /// The exemplar city of the time zone (e.g.: Los Angeles, Unknown City).
+    ExemplarCity,
+
+    // UTS-35 fields: VVVV
+    //  * falling back to long localized GMT
+    /// Generic location format (e.g.: Los Angeles Time, Los Angeles (Ortszeit)).
+    GenericLocation,
 }
 
 impl From<TimeZoneName> for Field {
@@ -526,6 +541,18 @@ impl From<TimeZoneName> for Field {
                 symbol: FieldSymbol::TimeZone(fields::TimeZone::LowerV),
                 length: FieldLength::Wide,
             },
+            TimeZoneName::TimeZoneId => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::TwoDigit,
+            },
+            TimeZoneName::ExemplarCity => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::Abbreviated,
+            },
+            TimeZoneName::GenericLocation => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::Wide,
+            },
         }
     }
 }
@@ -705,7 +732,11 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         },
                         fields::TimeZone::UpperO => TimeZoneName::GmtOffset,
                         fields::TimeZone::UpperZ => unimplemented!("fields::TimeZone::UpperZ"),
-                        fields::TimeZone::UpperV => unimplemented!("fields::TimeZone::UpperV"),
+                        fields::TimeZone::UpperV => match field.length {
+                            FieldLength::Abbreviated => TimeZoneName::ExemplarCity,
+                            FieldLength::Wide => TimeZoneName::GenericLocation,
+                            _ => TimeZoneName::TimeZoneId,
+                        },
                         fields::TimeZone::LowerX => unimplemented!("fields::TimeZone::LowerX"),
                         fields::TimeZone::UpperX => unimplemented!("fields::TimeZone::UpperX"),
                     });
@@ -3,8 +3,9 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::borrow::Cow;
+use icu_locid::subtags::Region;
 use icu_provider::{yoke, zerofrom};
-use tinystr::{TinyAsciiStr, TinyStr8};
+use tinystr::{tinystr, TinyAsciiStr, TinyStr8};
 use zerovec::ule::{AsULE, ULE};
 use zerovec::{ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 
@@ -116,6 +117,22 @@ pub struct ExemplarCitiesV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))] pub ZeroMap<'data, TimeZoneBcp47Id, str>,
 );
 
+/// An ICU4X mapping from the regions that have a primary time zone to their localized names,
+/// for the generic location format of those time zones.
+/// See CLDR-JSON territories.json for more context.
+#[icu_provider::data_struct(TimeZoneRegionNamesV1Marker = "time_zone/region_names@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::time_zones),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct TimeZoneRegionNamesV1<'data>(
+    #[cfg_attr(feature = "serde", serde(borrow))] pub ZeroMap<'data, TinyAsciiStr<3>, str>,
+);
+
 /// An ICU4X mapping to the long-form generic metazone names.
 /// See CLDR-JSON timeZoneNames.json for more context.
 #[icu_provider::data_struct(MetaZoneGenericNamesLongV1Marker = "time_zone/generic_long@1")]
@@ -214,3 +231,95 @@ pub struct MetaZonePeriodV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub ZeroMap2d<'data, TimeZoneBcp47Id, str, Option<MetaZoneId>>,
 );
+
+/// An ICU4X mapping between Windows time zone IDs and BCP-47 time zone IDs.
+/// See CLDR-JSON windowsZones.json for more context.
+#[icu_provider::data_struct(WindowsZonesV1Marker = "time_zone/windows_zones@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::time_zones),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct WindowsZonesV1<'data> {
+    /// The mapping from Windows time zone ID and region to BCP-47 time zone ID.
+    /// The region "001" holds the default zone for each Windows time zone ID.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub windows_to_bcp47: ZeroMap2d<'data, str, TinyAsciiStr<3>, TimeZoneBcp47Id>,
+    /// The mapping from BCP-47 time zone ID to Windows time zone ID.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_to_windows: ZeroMap<'data, TimeZoneBcp47Id, str>,
+}
+
+impl<'data> WindowsZonesV1<'data> {
+    /// Returns the BCP-47 time zone ID for a Windows time zone ID, such as
+    /// "Pacific Standard Time".
+    ///
+    /// If `region` is given and has its own entry for the Windows time zone, that entry is
+    /// returned; otherwise the default zone for the Windows time zone is returned.
+    pub fn get_bcp47_id(
+        &self,
+        windows_id: &str,
+        region: Option<Region>,
+    ) -> Option<TimeZoneBcp47Id> {
+        region
+            .and_then(|region| {
+                self.windows_to_bcp47
+                    .get_copied(windows_id, &region.into())
+                    .ok()
+            })
+            .or_else(|| {
+                self.windows_to_bcp47
+                    .get_copied(windows_id, &tinystr!(3, "001"))
+                    .ok()
+            })
+    }
+
+    /// Returns the Windows time zone ID for a BCP-47 time zone ID, if there is one.
+    pub fn get_windows_id(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        self.bcp47_to_windows.get(&bcp47_id)
+    }
+}
+
+/// An ICU4X mapping from BCP-47 time zone IDs to canonical IANA time zone IDs, and to the
+/// regions of which they are the primary time zone.
+/// See CLDR-JSON timezone.json and the CLDR primaryZones for more context.
+#[icu_provider::data_struct(TimeZoneIdentifiersV1Marker = "time_zone/identifiers@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::time_zones),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct TimeZoneIdentifiersV1<'data> {
+    /// The mapping from BCP-47 time zone ID to canonical IANA time zone ID.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_to_iana: ZeroMap<'data, TimeZoneBcp47Id, str>,
+    /// The mapping from BCP-47 time zone ID to region, for each time zone that is the primary
+    /// time zone of its region: either the only time zone in the region, or the time zone
+    /// that CLDR designates as primary.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub primary_zones: ZeroMap<'data, TimeZoneBcp47Id, TinyAsciiStr<3>>,
+}
+
+impl<'data> TimeZoneIdentifiersV1<'data> {
+    /// Returns the canonical IANA time zone ID for a BCP-47 time zone ID, such as
+    /// "America/Los_Angeles" for "uslax".
+    pub fn get_iana_id(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        self.bcp47_to_iana.get(&bcp47_id)
+    }
+
+    /// Returns the region of which the BCP-47 time zone ID is the primary time zone, if any.
+    ///
+    /// Per UTS 35, the generic location format of such a time zone uses the name of the
+    /// region rather than its exemplar city.
+    pub fn get_primary_region(&self, bcp47_id: TimeZoneBcp47Id) -> Option<Region> {
+        self.primary_zones
+            .get_copied(&bcp47_id)
+            .and_then(|region| Region::from_bytes(region.as_bytes()).ok())
+    }
+}
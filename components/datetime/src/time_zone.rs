@@ -96,6 +96,10 @@ pub struct TimeZoneDataPayloads {
     pub(super) zone_formats: DataPayload<provider::time_zones::TimeZoneFormatsV1Marker>,
     /// The exemplar cities for time zones.
     pub(super) exemplar_cities: Option<DataPayload<provider::time_zones::ExemplarCitiesV1Marker>>,
+    /// The IANA time zone IDs and primary time zones.
+    pub(super) identifiers: Option<DataPayload<provider::time_zones::TimeZoneIdentifiersV1Marker>>,
+    /// The names of the regions with a primary time zone.
+    pub(super) region_names: Option<DataPayload<provider::time_zones::TimeZoneRegionNamesV1Marker>>,
     /// The generic long metazone names, e.g. Pacific Time
     pub(super) mz_generic_long:
         Option<DataPayload<provider::time_zones::MetaZoneGenericNamesLongV1Marker>>,
@@ -127,6 +131,8 @@ impl TimeZoneFormat {
             + ResourceProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneRegionNamesV1Marker>
             + ?Sized,
     {
         let locale = locale.into();
@@ -139,6 +145,8 @@ impl TimeZoneFormat {
                 })?
                 .take_payload()?,
             exemplar_cities: None,
+            identifiers: None,
+            region_names: None,
             mz_generic_long: None,
             mz_generic_short: None,
             mz_specific_long: None,
@@ -206,8 +214,12 @@ impl TimeZoneFormat {
                     }
                 },
                 TimeZone::UpperV => match length {
-                    1 => (), // BCP-47 identifier, no CLDR-data necessary.
-                    2 => (), // IANA time-zone ID, no CLDR data necessary.
+                    1 => {
+                        tz_format.load_bcp47_id_format()?;
+                    }
+                    2 => {
+                        tz_format.load_iana_id_format(zone_provider)?;
+                    }
                     3 => {
                         tz_format.load_exemplar_city_format(zone_provider)?;
                     }
@@ -379,6 +391,8 @@ impl TimeZoneFormat {
             + ResourceProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneRegionNamesV1Marker>
             + ?Sized,
     {
         let locale = locale.into();
@@ -391,6 +405,8 @@ impl TimeZoneFormat {
                 })?
                 .take_payload()?,
             exemplar_cities: None,
+            identifiers: None,
+            region_names: None,
             mz_generic_long: None,
             mz_generic_short: None,
             mz_specific_long: None,
@@ -524,7 +540,10 @@ impl TimeZoneFormat {
         zone_provider: &ZP,
     ) -> Result<&mut TimeZoneFormat, DateTimeFormatError>
     where
-        ZP: ResourceProvider<provider::time_zones::ExemplarCitiesV1Marker> + ?Sized,
+        ZP: ResourceProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneRegionNamesV1Marker>
+            + ?Sized,
     {
         if self.data_payloads.exemplar_cities == None {
             load_resource(
@@ -533,6 +552,14 @@ impl TimeZoneFormat {
                 zone_provider,
             )?;
         }
+        if self.data_payloads.region_names == None {
+            load_resource(
+                &self.locale,
+                &mut self.data_payloads.region_names,
+                zone_provider,
+            )?;
+        }
+        self.load_identifiers(zone_provider)?;
         self.format_units.push(TimeZoneFormatUnit::GenericLocation(
             GenericLocationFormat {},
         ));
@@ -545,7 +572,9 @@ impl TimeZoneFormat {
         zone_provider: &ZP,
     ) -> Result<&mut TimeZoneFormat, DateTimeFormatError>
     where
-        ZP: ResourceProvider<provider::time_zones::ExemplarCitiesV1Marker> + ?Sized,
+        ZP: ResourceProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ?Sized,
     {
         if self.data_payloads.exemplar_cities == None {
             load_resource(
@@ -554,11 +583,48 @@ impl TimeZoneFormat {
                 zone_provider,
             )?;
         }
+        self.load_identifiers(zone_provider)?;
         self.format_units
             .push(TimeZoneFormatUnit::ExemplarCity(ExemplarCityFormat {}));
         Ok(self)
     }
 
+    /// Load BCP-47 time zone ID format for timezone. For example, uslax.
+    fn load_bcp47_id_format(&mut self) -> Result<&mut TimeZoneFormat, DateTimeFormatError> {
+        self.format_units
+            .push(TimeZoneFormatUnit::Bcp47Id(Bcp47IdFormat {}));
+        Ok(self)
+    }
+
+    /// Load IANA time zone ID format for timezone. For example, America/Los_Angeles.
+    fn load_iana_id_format<ZP>(
+        &mut self,
+        zone_provider: &ZP,
+    ) -> Result<&mut TimeZoneFormat, DateTimeFormatError>
+    where
+        ZP: ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker> + ?Sized,
+    {
+        self.load_identifiers(zone_provider)?;
+        self.format_units
+            .push(TimeZoneFormatUnit::IanaId(IanaIdFormat {}));
+        Ok(self)
+    }
+
+    /// Loads the IANA time zone IDs and primary time zones, which are not locale-specific.
+    fn load_identifiers<ZP>(&mut self, zone_provider: &ZP) -> Result<(), DateTimeFormatError>
+    where
+        ZP: ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker> + ?Sized,
+    {
+        if self.data_payloads.identifiers.is_none() {
+            self.data_payloads.identifiers = Some(
+                zone_provider
+                    .load_resource(&DataRequest::default())?
+                    .take_payload()?,
+            );
+        }
+        Ok(())
+    }
+
     /// Load localized GMT format for timezone. For example, GMT-07:00.
     pub fn load_localized_gmt_format(
         &mut self,
@@ -871,6 +937,16 @@ pub(super) struct Iso8601Format {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(super) struct ExemplarCityFormat {}
 
+// uslax
+// It is only used for pattern in special case and not public to users.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Bcp47IdFormat {}
+
+// America/Los_Angeles
+// It is only used for pattern in special case and not public to users.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct IanaIdFormat {}
+
 // An enum for time zone format unit.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(super) enum TimeZoneFormatUnit {
@@ -882,6 +958,8 @@ pub(super) enum TimeZoneFormatUnit {
     LocalizedGmt(LocalizedGmtFormat),
     Iso8601(Iso8601Format),
     ExemplarCity(ExemplarCityFormat),
+    Bcp47Id(Bcp47IdFormat),
+    IanaId(IanaIdFormat),
 }
 
 impl Default for TimeZoneFormatUnit {
@@ -915,6 +993,8 @@ impl FormatTimeZone for TimeZoneFormatUnit {
             Self::LocalizedGmt(unit) => unit.format(sink, time_zone, data_payloads),
             Self::Iso8601(unit) => unit.format(sink, time_zone, data_payloads),
             Self::ExemplarCity(unit) => unit.format(sink, time_zone, data_payloads),
+            Self::Bcp47Id(unit) => unit.format(sink, time_zone, data_payloads),
+            Self::IanaId(unit) => unit.format(sink, time_zone, data_payloads),
         }
     }
 }
@@ -1125,10 +1205,68 @@ impl FormatTimeZone for LocalizedGmtFormat {
     }
 }
 
+/// The BCP-47 ID of the unknown time zone, "Etc/Unknown".
+const UNKNOWN_TIME_ZONE: TimeZoneBcp47Id = TimeZoneBcp47Id(tinystr!(8, "unk"));
+
+/// Returns the exemplar city of a time zone, as defined by the UTS-35 spec.
+///
+/// If the locale has no exemplar city for the time zone, the last segment of its IANA time zone
+/// ID is used, with underscores replaced by spaces, e.g. "Los Angeles" for America/Los_Angeles.
+/// Time zones that do not represent a location, such as Etc/UTC, have no exemplar city.
+/// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
+fn exemplar_city(
+    time_zone_id: TimeZoneBcp47Id,
+    data_payloads: &TimeZoneDataPayloads,
+) -> Option<Cow<'_, str>> {
+    data_payloads
+        .exemplar_cities
+        .as_ref()
+        .and_then(|p| p.get().0.get(&time_zone_id))
+        .map(Cow::Borrowed)
+        .or_else(|| {
+            let iana_id = data_payloads
+                .identifiers
+                .as_ref()
+                .and_then(|p| p.get().get_iana_id(time_zone_id))?;
+            if iana_id.starts_with("Etc/") || iana_id.starts_with("SystemV/") {
+                return None;
+            }
+            iana_id
+                .rsplit_once('/')
+                .map(|(_, city)| Cow::Owned(city.replace('_', " ")))
+        })
+}
+
+/// Returns the name of the region of which the time zone is the primary time zone, such as
+/// "France" for Europe/Paris, if the locale has a name for it.
+fn region_name(
+    time_zone_id: TimeZoneBcp47Id,
+    data_payloads: &TimeZoneDataPayloads,
+) -> Option<Cow<'_, str>> {
+    let region = data_payloads
+        .identifiers
+        .as_ref()?
+        .get()
+        .get_primary_region(time_zone_id)?;
+    data_payloads
+        .region_names
+        .as_ref()?
+        .get()
+        .0
+        .get(&region.into())
+        .map(Cow::Borrowed)
+}
+
 impl FormatTimeZone for GenericLocationFormat {
     /// Writes the time zone in generic location format as defined by the UTS-35 spec.
-    /// e.g. France Time
+    /// e.g. France Time for Europe/Paris, or Los Angeles Time for America/Los_Angeles
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
+    ///
+    /// The location is the name of the region for the primary time zone of a region, and the
+    /// exemplar city for other time zones, or if the locale has no name for the region.
+    ///
+    /// The unknown time zone, Etc/Unknown, has no generic location format, so that the
+    /// fallback format is used instead.
     fn format<W: fmt::Write + ?Sized>(
         &self,
         sink: &mut W,
@@ -1136,17 +1274,18 @@ impl FormatTimeZone for GenericLocationFormat {
         data_payloads: &TimeZoneDataPayloads,
     ) -> Result<fmt::Result, DateTimeFormatError> {
         // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
-        let formatted_time_zone: Option<alloc::string::String> = data_payloads
-            .exemplar_cities
-            .as_ref()
-            .map(|p| p.get())
-            .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.0.get(&id)))
+        let formatted_time_zone: Option<alloc::string::String> = time_zone
+            .time_zone_id()
+            .filter(|&id| id != UNKNOWN_TIME_ZONE)
+            .and_then(|id| {
+                region_name(id, data_payloads).or_else(|| exemplar_city(id, data_payloads))
+            })
             .map(|location| {
                 data_payloads
                     .zone_formats
                     .get()
                     .region_format
-                    .replace("{0}", location)
+                    .replace("{0}", &location)
             });
         match formatted_time_zone {
             Some(ftz) => Ok(sink.write_str(&ftz)),
@@ -1237,37 +1376,80 @@ impl FormatTimeZone for Iso8601Format {
 }
 
 impl FormatTimeZone for ExemplarCityFormat {
+    /// Writes the exemplar city of the time zone as defined by the UTS-35 spec.
+    /// e.g. Los Angeles
+    /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
+    ///
+    /// If the time zone is unknown or has no exemplar city, the exemplar city of the unknown
+    /// time zone, Etc/Unknown, is written instead.
     fn format<W: fmt::Write + ?Sized>(
         &self,
         sink: &mut W,
         time_zone: &impl TimeZoneInput,
         data_payloads: &TimeZoneDataPayloads,
     ) -> Result<fmt::Result, DateTimeFormatError> {
-        // Writes the exemplar city associated with this time zone.
-        let formatted_exemplar_city = data_payloads
-            .exemplar_cities
-            .as_ref()
-            .map(|p| p.get())
-            .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.0.get(&id)));
+        let formatted_exemplar_city = time_zone
+            .time_zone_id()
+            .and_then(|id| exemplar_city(id, data_payloads));
 
         match formatted_exemplar_city {
-            Some(ftz) => Ok(sink.write_str(ftz)),
+            Some(ftz) => Ok(sink.write_str(&ftz)),
             None => {
                 // Writes the unknown city "Etc/Unknown" for the current locale.
                 //
                 // If there is no localized form of "Etc/Unknown" for the current locale,
                 // returns the "Etc/Uknown" value of the `und` locale as a hard-coded string.
-                //
-                // This can be used as a fallback if [`exemplar_city()`](TimeZoneFormat::exemplar_city())
-                // is unable to produce a localized form of the time zone's exemplar city in the current locale.
                 let formatted_unknown_city = data_payloads
                     .exemplar_cities
                     .as_ref()
                     .map(|p| p.get())
-                    .and_then(|cities| cities.0.get(&TimeZoneBcp47Id(tinystr!(8, "unk"))))
-                    .unwrap_or(&Cow::Borrowed("Unknown"));
+                    .and_then(|cities| cities.0.get(&UNKNOWN_TIME_ZONE))
+                    .unwrap_or("Unknown");
                 Ok(sink.write_str(formatted_unknown_city))
             }
         }
     }
 }
+
+impl FormatTimeZone for Bcp47IdFormat {
+    /// Writes the BCP-47 ID of the time zone, which UTS-35 calls the short time zone ID.
+    /// e.g. uslax
+    /// https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    ///
+    /// If the time zone is unknown, the ID of the unknown time zone, unk, is written instead.
+    fn format<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        time_zone: &impl TimeZoneInput,
+        _data_payloads: &TimeZoneDataPayloads,
+    ) -> Result<fmt::Result, DateTimeFormatError> {
+        let time_zone_id = time_zone.time_zone_id().unwrap_or(UNKNOWN_TIME_ZONE);
+        Ok(sink.write_str(&time_zone_id.0))
+    }
+}
+
+impl FormatTimeZone for IanaIdFormat {
+    /// Writes the canonical IANA ID of the time zone, which UTS-35 calls the long time zone ID.
+    /// e.g. America/Los_Angeles
+    /// https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    ///
+    /// If the time zone is unknown, the ID of the unknown time zone, Etc/Unknown, is written
+    /// instead.
+    fn format<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        time_zone: &impl TimeZoneInput,
+        data_payloads: &TimeZoneDataPayloads,
+    ) -> Result<fmt::Result, DateTimeFormatError> {
+        let iana_id = time_zone
+            .time_zone_id()
+            .and_then(|id| {
+                data_payloads
+                    .identifiers
+                    .as_ref()
+                    .and_then(|p| p.get().get_iana_id(id))
+            })
+            .unwrap_or("Etc/Unknown");
+        Ok(sink.write_str(iana_id))
+    }
+}
@@ -135,6 +135,8 @@ impl<C: CldrCalendar> ZonedDateTimeFormat<C> {
             + ResourceProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + ResourceProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneIdentifiersV1Marker>
+            + ResourceProvider<provider::time_zones::TimeZoneRegionNamesV1Marker>
             + ?Sized,
         PP: ResourceProvider<OrdinalV1Marker> + ?Sized,
         DEP: ResourceProvider<DecimalSymbolsV1Marker> + ?Sized,
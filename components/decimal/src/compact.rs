@@ -0,0 +1,399 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compact decimal formatting, such as "1.2K" or "3 million".
+
+use crate::options::FixedDecimalFormatOptions;
+use crate::provider::*;
+use crate::{FixedDecimalFormat, FixedDecimalFormatError};
+use core::fmt::{self, Write};
+use fixed_decimal::{CompactDecimal, FixedDecimal};
+use icu_locid::Locale;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+use writeable::{PartsWrite, Writeable};
+use zerovec::ule::AsULE;
+
+/// A formatter for [`FixedDecimal`] in compact notation, such as "1.2K" or "3 million".
+///
+/// The number is divided by the power of ten given by the locale's pattern for its magnitude,
+/// and rounded to an integer, or to two significant digits if it has a single integer digit.
+/// If fraction or significant digit options are set, the divided number is rounded according
+/// to them instead. The pattern is then selected by the plural category of the rounded number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::CompactDecimalFormat;
+/// use icu::locid::locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let short = CompactDecimalFormat::try_new_short(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// let long = CompactDecimalFormat::try_new_long(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!("1.2K", short.format(&1234.into()).write_to_string());
+/// assert_eq!("35M", short.format(&35_123_456.into()).write_to_string());
+/// assert_eq!("-1M", short.format(&(-999_999).into()).write_to_string());
+/// assert_eq!("3 million", long.format(&3_000_000.into()).write_to_string());
+/// assert_eq!("999", long.format(&999.into()).write_to_string());
+/// ```
+pub struct CompactDecimalFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    plural_rules: PluralRules,
+    compact_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
+}
+
+impl CompactDecimalFormat {
+    /// Creates a new [`CompactDecimalFormat`] with short patterns, such as "1.2K".
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new_short<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: FixedDecimalFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<ShortCompactDecimalFormatDataV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_internal::<ShortCompactDecimalFormatDataV1Marker, D>(
+            locale.into(),
+            data_provider,
+            options,
+        )
+    }
+
+    /// Creates a new [`CompactDecimalFormat`] with long patterns, such as "1.2 thousand".
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new_long<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: FixedDecimalFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<LongCompactDecimalFormatDataV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_internal::<LongCompactDecimalFormatDataV1Marker, D>(
+            locale.into(),
+            data_provider,
+            options,
+        )
+    }
+
+    fn try_new_internal<M, D>(
+        locale: Locale,
+        data_provider: &D,
+        options: FixedDecimalFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        M: ResourceMarker<Yokeable = CompactDecimalPatternDataV1<'static>>,
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<M>
+            + ResourceProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let compact_data =
+            crate::load_with_numbering_system::<M, D>(&locale, data_provider)?.cast();
+        let plural_rules = PluralRules::try_new_cardinal(locale.id.clone(), data_provider)?;
+        let fixed_decimal_format = FixedDecimalFormat::try_new(locale, data_provider, options)?;
+        Ok(Self {
+            fixed_decimal_format,
+            plural_rules,
+            compact_data,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] in compact notation, returning a [`FormattedCompactDecimal`].
+    pub fn format(&self, value: &FixedDecimal) -> FormattedCompactDecimal<'_> {
+        let patterns = &self.compact_data.get().patterns;
+        let mut magnitude = value.nonzero_magnitude_left();
+        loop {
+            // The patterns for the largest magnitude not exceeding that of the value, if any.
+            let cursor = patterns
+                .iter0()
+                .take_while(|cursor| i16::from_unaligned(*cursor.key0()) <= magnitude)
+                .last();
+            let exponent = cursor
+                .as_ref()
+                .and_then(|cursor| cursor.get1(&Count::Other))
+                .map_or(0, |pattern| CompactPattern::zero_from(pattern).exponent);
+
+            let mut compacted = value.clone();
+            if compacted.multiply_pow10(-i16::from(exponent)).is_err() {
+                // The value is too small to be divided; format it without compacting.
+                return FormattedCompactDecimal {
+                    formatter: self,
+                    value: value.clone(),
+                    pattern: None,
+                };
+            }
+            let options = &self.fixed_decimal_format.options;
+            if options.has_rounding_options() {
+                crate::rounder::apply(&mut compacted, options);
+            } else {
+                let position = core::cmp::min(0, compacted.nonzero_magnitude_left() - 1);
+                compacted.half_even(position);
+                compacted.strip_right();
+            }
+
+            // Rounding may carry into the next magnitude, e.g. 999,999 to 1000K, which
+            // requires the pattern for that magnitude instead, e.g. 1M.
+            let rounded_magnitude = compacted.nonzero_magnitude_left() + i16::from(exponent);
+            if rounded_magnitude > magnitude {
+                magnitude = rounded_magnitude;
+                continue;
+            }
+
+            let count = self.select(&compacted, exponent);
+            let pattern = cursor.and_then(|cursor| {
+                cursor
+                    .get1(&count)
+                    .or_else(|| cursor.get1(&Count::Other))
+                    .map(CompactPattern::zero_from)
+            });
+            return FormattedCompactDecimal {
+                formatter: self,
+                value: compacted,
+                pattern,
+            };
+        }
+    }
+
+    /// Returns the plural category of a compacted number, such as 1.2 with the exponent 6 for
+    /// "1.2 million".
+    ///
+    /// The category is selected on the [`CompactDecimal`] of the compacted number and the
+    /// exponent, whose `c` operand lets rules such as the French `many` for `e != 0..5` apply.
+    fn select(&self, compacted: &FixedDecimal, exponent: i8) -> Count {
+        let compact =
+            CompactDecimal::from_significand_and_exponent(compacted.clone(), i16::from(exponent));
+        Count::from(self.plural_rules.select(&compact))
+    }
+}
+
+/// An intermediate structure returned by [`CompactDecimalFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+pub struct FormattedCompactDecimal<'l> {
+    formatter: &'l CompactDecimalFormat,
+    value: FixedDecimal,
+    pattern: Option<CompactPattern<'l>>,
+}
+
+impl<'l> FormattedCompactDecimal<'l> {
+    /// Returns the number that is rendered in place of the placeholder of the pattern,
+    /// for example 1.2 for "1.2K".
+    pub fn compacted_value(&self) -> &FixedDecimal {
+        &self.value
+    }
+}
+
+/// The [`Part`](writeable::Part)s used by [`FormattedCompactDecimal`], in addition to those of
+/// [`FormattedFixedDecimal`](crate::FormattedFixedDecimal).
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::compact::parts;
+/// use icu::decimal::format::parts as decimal_parts;
+/// use icu::decimal::CompactDecimalFormat;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let cdf = CompactDecimalFormat::try_new_long(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let value = 1_250_000.into();
+/// assert_writeable_parts_eq!(
+///     cdf.format(&value),
+///     "1.2 million",
+///     [
+///         (0, 1, decimal_parts::INTEGER),
+///         (1, 2, decimal_parts::DECIMAL),
+///         (2, 3, decimal_parts::FRACTION),
+///         (4, 11, parts::COMPACT),
+///     ]
+/// );
+/// ```
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the compact symbol or word of the pattern, such as "K" in
+    /// "1.2K" or "million" in "1.2 million". Spaces around it are not part of it.
+    pub const COMPACT: Part = Part {
+        category: "compact",
+        value: "compact",
+    };
+}
+
+/// Writes an affix of a compact pattern, marking the text other than the surrounding
+/// whitespace with the [`COMPACT`](parts::COMPACT) part.
+fn write_affix<S: PartsWrite + ?Sized>(sink: &mut S, affix: &str) -> fmt::Result {
+    let compact = affix.trim();
+    if compact.is_empty() {
+        return sink.write_str(affix);
+    }
+    let start = affix.len() - affix.trim_start().len();
+    sink.write_str(affix.get(..start).unwrap_or_default())?;
+    sink.with_part(parts::COMPACT, |w| w.write_str(compact))?;
+    sink.write_str(affix.get(start + compact.len()..).unwrap_or_default())
+}
+
+impl<'l> Writeable for FormattedCompactDecimal<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let (prefix, suffix) = self
+            .pattern
+            .as_ref()
+            .map_or(("", ""), |pattern| pattern.affixes());
+        write_affix(sink, prefix)?;
+        self.formatter
+            .fixed_decimal_format
+            .format(&self.value)
+            .write_to_parts(sink)?;
+        write_affix(sink, suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::parts as decimal_parts;
+    use icu_locid::{locale, Locale};
+    use writeable::assert_writeable_parts_eq;
+
+    fn format_short(locale: Locale, value: i64) -> String {
+        let provider = icu_testdata::get_provider();
+        CompactDecimalFormat::try_new_short(locale, &provider, Default::default())
+            .unwrap()
+            .format(&value.into())
+            .write_to_string()
+            .into_owned()
+    }
+
+    fn format_long(locale: Locale, value: i64) -> String {
+        let provider = icu_testdata::get_provider();
+        CompactDecimalFormat::try_new_long(locale, &provider, Default::default())
+            .unwrap()
+            .format(&value.into())
+            .write_to_string()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(format_short(locale!("en"), 0), "0");
+        assert_eq!(format_short(locale!("en"), 999), "999");
+        assert_eq!(format_short(locale!("en"), 1000), "1K");
+        assert_eq!(format_short(locale!("en"), 1250), "1.2K");
+        assert_eq!(format_short(locale!("en"), 1350), "1.4K");
+        assert_eq!(format_short(locale!("en"), 12_345), "12K");
+        assert_eq!(format_short(locale!("en"), 999_500), "1M");
+        assert_eq!(format_short(locale!("en"), -4_560_000), "-4.6M");
+        assert_eq!(format_short(locale!("en"), 1_234_567_890_123_456), "1,235T");
+    }
+
+    #[test]
+    fn test_digit_options() {
+        let provider = icu_testdata::get_provider();
+        let options = FixedDecimalFormatOptions {
+            minimum_fraction_digits: Some(2),
+            maximum_fraction_digits: Some(2),
+            ..Default::default()
+        };
+        let cdf = CompactDecimalFormat::try_new_short(locale!("en"), &provider, options).unwrap();
+        for (value, expected) in [
+            (1234, "1.23K"),
+            (12_345, "12.35K"),
+            (999_999, "1.00M"),
+            (12, "12.00"),
+        ] {
+            let value = FixedDecimal::from(value);
+            assert_eq!(cdf.format(&value).write_to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_plurals() {
+        assert_eq!(format_long(locale!("en"), 1_000_000), "1 million");
+        // The operands other than `c` are those of the full value, so Russian 1,000 selects
+        // `many` like 5,000.
+        assert_eq!(format_long(locale!("ru"), 1_000), "1 тысяч");
+        assert_eq!(format_long(locale!("ru"), 2_000), "2 тысяч");
+        assert_eq!(format_long(locale!("ru"), 5_000_000), "5 миллионов");
+        assert_eq!(format_long(locale!("ru"), 1_500_000), "1,5 миллионов");
+        assert_eq!(format_long(locale!("fr"), 1_000), "1 mille");
+        // French uses `many` for millions and above, which falls back to the `other` pattern.
+        assert_eq!(format_long(locale!("fr"), 1_000_000), "1 millions");
+        assert_eq!(format_long(locale!("fr"), 1_200_000), "1,2 millions");
+        assert_eq!(format_long(locale!("fr"), 2_500_000), "2,5 millions");
+    }
+
+    #[test]
+    fn test_compact_exponent() {
+        let provider = icu_testdata::get_provider();
+        let cdf = CompactDecimalFormat::try_new_long(locale!("fr"), &provider, Default::default())
+            .unwrap();
+        // French uses `many` for millions and above in compact notation, which falls back to
+        // the `other` pattern in the data.
+        assert_eq!(cdf.select(&"2.5".parse().unwrap(), 6), Count::Many);
+        assert_eq!(cdf.select(&"2.5".parse().unwrap(), 3), Count::Other);
+        assert_eq!(cdf.select(&"2.5".parse().unwrap(), 0), Count::Other);
+        assert_eq!(cdf.select(&FixedDecimal::from(1), 6), Count::Many);
+    }
+
+    #[test]
+    fn test_parts() {
+        let provider = icu_testdata::get_provider();
+        let cdf = CompactDecimalFormat::try_new_short(locale!("en"), &provider, Default::default())
+            .unwrap();
+        let value = FixedDecimal::from(-12_345);
+        assert_writeable_parts_eq!(
+            cdf.format(&value),
+            "-12K",
+            [
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 3, decimal_parts::INTEGER),
+                (3, 4, parts::COMPACT),
+            ]
+        );
+        let value = FixedDecimal::from(999);
+        assert_writeable_parts_eq!(cdf.format(&value), "999", [(0, 3, decimal_parts::INTEGER)]);
+        let cdf = CompactDecimalFormat::try_new_short(locale!("ja"), &provider, Default::default())
+            .unwrap();
+        let value = FixedDecimal::from(12_345);
+        assert_writeable_parts_eq!(
+            cdf.format(&value),
+            "1.2万",
+            [
+                (0, 1, decimal_parts::INTEGER),
+                (1, 2, decimal_parts::DECIMAL),
+                (2, 3, decimal_parts::FRACTION),
+                (3, 6, parts::COMPACT),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locales() {
+        assert_eq!(format_short(locale!("ja"), 1234), "1,234");
+        assert_eq!(format_short(locale!("ja"), 12_345), "1.2万");
+        assert_eq!(format_short(locale!("ja"), 123_456_789), "1.2億");
+        assert_eq!(format_long(locale!("ar"), 12_000), "١٢ ألف");
+        let ar_latn: Locale = "ar-u-nu-latn".parse().unwrap();
+        assert_eq!(format_long(ar_latn, 12_000), "12 ألف");
+    }
+}
@@ -0,0 +1,758 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Currency formatting, such as "$1,234.00" or "1,234.00 US dollars".
+
+use crate::format::parts as decimal_parts;
+use crate::format::write_literal_with_minus_sign;
+use crate::options::*;
+use crate::provider::*;
+use crate::range;
+use crate::rounder;
+use crate::sign_selector::{self, SignSelection};
+use crate::{FixedDecimalFormat, FixedDecimalFormatError};
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, Sign, Signum};
+use icu_locid::Locale;
+use icu_plurals::provider::{CardinalV1Marker, PluralRangesV1Marker};
+use icu_plurals::PluralRulesWithRanges;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+use writeable::{PartsWrite, Writeable};
+
+/// A formatter for amounts of money, given as a [`FixedDecimal`] and an ISO 4217 currency code.
+///
+/// The amount is rounded to the fraction digits and rounding increment of the currency, and
+/// displayed with the currency's symbol, narrow symbol, ISO code, or name, according to the
+/// [`CurrencyFormatOptions`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::CurrencyFormat;
+/// use icu::locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let cf = CurrencyFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let amount = "1234.5".parse().expect("valid syntax");
+/// assert_eq!("$1,234.50", cf.format(&amount, tinystr!(3, "USD")).write_to_string());
+/// assert_eq!("¥1,234", cf.format(&amount, tinystr!(3, "JPY")).write_to_string());
+/// assert_eq!("€1,234.50", cf.format(&amount, tinystr!(3, "EUR")).write_to_string());
+/// ```
+pub struct CurrencyFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    plural_rules: PluralRulesWithRanges,
+    patterns: DataPayload<CurrencyPatternsV1Marker>,
+    names: DataPayload<CurrencyDisplayNamesV1Marker>,
+    fractions: DataPayload<CurrencyFractionsV1Marker>,
+    range_patterns: DataPayload<MiscPatternsV1Marker>,
+    options: CurrencyFormatOptions,
+}
+
+impl CurrencyFormat {
+    /// Creates a new [`CurrencyFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: CurrencyFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<CurrencyPatternsV1Marker>
+            + ResourceProvider<CurrencyDisplayNamesV1Marker>
+            + ResourceProvider<CurrencyFractionsV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ResourceProvider<PluralRangesV1Marker>
+            + ResourceProvider<MiscPatternsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let patterns = crate::load_with_numbering_system(&locale, data_provider)?;
+        let range_patterns = crate::load_with_numbering_system(&locale, data_provider)?;
+        let names = data_provider
+            .load_resource(&DataRequest {
+                options: locale.id.clone().into(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let fractions = data_provider
+            .load_resource(&DataRequest::default())?
+            .take_payload()?;
+        let plural_rules =
+            PluralRulesWithRanges::try_new_cardinal(locale.id.clone(), data_provider)?;
+        // The sign is part of the currency pattern.
+        let fixed_decimal_format = FixedDecimalFormat::try_new(
+            locale,
+            data_provider,
+            FixedDecimalFormatOptions {
+                grouping_strategy: options.decimal.grouping_strategy,
+                sign_display: SignDisplay::Never,
+                ..Default::default()
+            },
+        )?;
+        Ok(Self {
+            fixed_decimal_format,
+            plural_rules,
+            patterns,
+            names,
+            fractions,
+            range_patterns,
+            options,
+        })
+    }
+
+    /// Formats an amount of money in the currency with the given ISO 4217 code, returning a
+    /// [`FormattedCurrency`].
+    ///
+    /// Currencies without locale data are displayed with their ISO code and two fraction digits.
+    pub fn format(&self, value: &FixedDecimal, currency: TinyAsciiStr<3>) -> FormattedCurrency<'_> {
+        let currency = currency.to_ascii_uppercase();
+        let fractions = self.fractions.get();
+        let fractions = fractions
+            .fractions
+            .get_copied(&currency)
+            .unwrap_or(fractions.default);
+        let (digits, increment) = match self.options.usage {
+            CurrencyUsage::Standard => (fractions.digits, fractions.rounding),
+            CurrencyUsage::Cash => (fractions.cash_digits, fractions.cash_rounding),
+        };
+
+        let mut value = value.clone();
+        let negative = value.signum() == Signum::BelowZero;
+        value.set_sign(Sign::None);
+        if !self.options.decimal.has_rounding_options() {
+            round_to_increment(&mut value, digits, increment);
+        }
+        rounder::apply(&mut value, &self.options.decimal);
+        // An amount that rounds to zero is not negative.
+        let signum = match (value.is_zero(), negative) {
+            (true, _) => Signum::PositiveZero,
+            (false, true) => Signum::BelowZero,
+            (false, false) => Signum::AboveZero,
+        };
+        FormattedCurrency {
+            formatter: self,
+            sign: sign_selector::select(signum, self.options.decimal.sign_display),
+            value,
+            currency,
+        }
+    }
+
+    /// Formats the range of amounts of money from `start` to `end` in the currency with the
+    /// given ISO 4217 code, returning a [`FormattedCurrencyRange`].
+    ///
+    /// As ECMA-402 `formatRange` does, the amounts share the currency if neither is rendered
+    /// with a sign and the currency with the text around it is longer than one code point,
+    /// such as in "CA$3–5" or "3.00–5.00 US dollars", where the name is that of the plural
+    /// category of the range. Otherwise both amounts are rendered with the currency, set apart
+    /// from the range separator with spaces, such as in "$3 – $5". If both amounts are
+    /// formatted identically, the amount is rendered as approximate, such as "~$3", as with
+    /// the default [`RangeIdentityFallback`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::options;
+    /// use icu::decimal::CurrencyFormat;
+    /// use icu::locid::locale;
+    /// use tinystr::tinystr;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let mut options: options::CurrencyFormatOptions = Default::default();
+    /// options.decimal.maximum_fraction_digits = Some(0);
+    /// let cf = CurrencyFormat::try_new(locale!("en"), &provider, options)
+    ///     .expect("Data should load successfully");
+    ///
+    /// let range = |start: &str, end: &str, currency| {
+    ///     cf.format_range(
+    ///         &start.parse().expect("valid syntax"),
+    ///         &end.parse().expect("valid syntax"),
+    ///         currency,
+    ///     )
+    ///     .write_to_string()
+    ///     .into_owned()
+    /// };
+    /// assert_eq!("$3 – $5", range("3", "5", tinystr!(3, "USD")));
+    /// assert_eq!("CA$3–5", range("3", "5", tinystr!(3, "CAD")));
+    /// assert_eq!("-$3 – $5", range("-3", "5", tinystr!(3, "USD")));
+    /// assert_eq!("~$3", range("2.9", "3.1", tinystr!(3, "USD")));
+    /// ```
+    pub fn format_range(
+        &self,
+        start: &FixedDecimal,
+        end: &FixedDecimal,
+        currency: TinyAsciiStr<3>,
+    ) -> FormattedCurrencyRange<'_> {
+        let start = self.format(start, currency);
+        let end = self.format(end, currency);
+        let identical = start.value == end.value && start.sign == end.sign;
+        FormattedCurrencyRange {
+            start,
+            end: if identical { None } else { Some(end) },
+        }
+    }
+}
+
+/// Rounds a nonnegative value half-even to a multiple of the increment, in units of the last
+/// of the given number of fraction digits, and pads it to that many fraction digits.
+fn round_to_increment(value: &mut FixedDecimal, digits: u8, increment: u8) {
+    let mut position = -i16::from(digits);
+    let mut increment = increment;
+    while increment != 0 && increment % 10 == 0 {
+        increment /= 10;
+        position += 1;
+    }
+    // CLDR only uses increments of 1 and 5 times a power of ten.
+    let increment = match increment {
+        5 => RoundingIncrement::MultiplesOf5,
+        _ => RoundingIncrement::MultiplesOf1,
+    };
+    rounder::round_to_increment(value, position, increment, RoundingMode::HalfEven);
+    value.pad_right(-i16::from(digits));
+}
+
+/// An intermediate structure returned by [`CurrencyFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted amount to a string or buffer.
+pub struct FormattedCurrency<'l> {
+    formatter: &'l CurrencyFormat,
+    value: FixedDecimal,
+    sign: SignSelection,
+    currency: TinyAsciiStr<3>,
+}
+
+impl<'l> FormattedCurrency<'l> {
+    /// Returns the absolute value of the amount, rounded to the fraction digits of the currency
+    /// or to the digit options.
+    pub fn rounded_value(&self) -> &FixedDecimal {
+        &self.value
+    }
+
+    /// Writes an affix of a currency pattern, replacing its placeholder with the currency, and
+    /// marking the minus sign of a negative affix.
+    fn write_affix<S: PartsWrite + ?Sized>(
+        sink: &mut S,
+        affix: &str,
+        currency: &str,
+        minus_sign: &str,
+    ) -> fmt::Result {
+        for (i, literal) in affix.split('¤').enumerate() {
+            if i > 0 {
+                sink.with_part(parts::CURRENCY, |w| w.write_str(currency))?;
+            }
+            write_literal_with_minus_sign(sink, literal, minus_sign)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the currency symbol or ISO code to display, or `None` if the currency is
+    /// displayed with its name.
+    fn symbol(&self) -> Option<&str> {
+        let names = self.formatter.names.get();
+        let symbol = || names.symbols.get(&self.currency);
+        match self.formatter.options.display {
+            CurrencyDisplay::Symbol => Some(symbol().unwrap_or(self.currency.as_str())),
+            CurrencyDisplay::NarrowSymbol => Some(
+                names
+                    .narrow_symbols
+                    .get(&self.currency)
+                    .or_else(symbol)
+                    .unwrap_or(self.currency.as_str()),
+            ),
+            CurrencyDisplay::IsoCode => Some(self.currency.as_str()),
+            CurrencyDisplay::Name => None,
+        }
+    }
+
+    /// Returns the affixes of the currency pattern and the minus sign in them, if any, and the
+    /// affixes of the plus sign to put around them, if any.
+    fn affixes(&self) -> (&'l AffixesV1<'l>, &'l str, Option<&'l AffixesV1<'l>>) {
+        let patterns = self.formatter.patterns.get();
+        let symbols = self.formatter.fixed_decimal_format.symbols.get();
+        let pattern = match self.formatter.options.sign {
+            CurrencySign::Standard => &patterns.standard,
+            CurrencySign::Accounting => &patterns.accounting,
+        };
+        // CLDR has no pattern with a plus sign; like ICU, put the plus sign around the
+        // positive pattern.
+        match self.sign {
+            SignSelection::Minus => (&pattern.negative, &*symbols.minus_sign_affixes.prefix, None),
+            SignSelection::Neither => (&pattern.positive, "", None),
+            SignSelection::Plus => (&pattern.positive, "", Some(&symbols.plus_sign_affixes)),
+        }
+    }
+
+    /// Writes the text before the amount with the currency symbol or ISO code.
+    fn write_prefix<S: PartsWrite + ?Sized>(&self, sink: &mut S, currency: &str) -> fmt::Result {
+        let (affixes, minus_sign, plus) = self.affixes();
+        if let Some(plus) = plus.filter(|plus| !plus.prefix.is_empty()) {
+            sink.with_part(decimal_parts::PLUS_SIGN, |w| w.write_str(&plus.prefix))?;
+        }
+        Self::write_affix(sink, &affixes.prefix, currency, minus_sign)?;
+        // The currency spacing of CLDR applies to currencies that do not end or start with
+        // a symbol, which are approximated by those that end or start with a letter.
+        if affixes.prefix.ends_with('¤') && is_letter(currency.chars().next_back()) {
+            sink.write_str(&self.formatter.patterns.get().spacing)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the amount without its sign.
+    fn write_number<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.formatter
+            .fixed_decimal_format
+            .format(&self.value)
+            .write_to_parts(sink)
+    }
+
+    /// Writes the text after the amount with the currency symbol or ISO code.
+    fn write_suffix<S: PartsWrite + ?Sized>(&self, sink: &mut S, currency: &str) -> fmt::Result {
+        let (affixes, minus_sign, plus) = self.affixes();
+        if affixes.suffix.starts_with('¤') && is_letter(currency.chars().next()) {
+            sink.write_str(&self.formatter.patterns.get().spacing)?;
+        }
+        Self::write_affix(sink, &affixes.suffix, currency, minus_sign)?;
+        if let Some(plus) = plus.filter(|plus| !plus.suffix.is_empty()) {
+            sink.with_part(decimal_parts::PLUS_SIGN, |w| w.write_str(&plus.suffix))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the amount with its sign, if any.
+    fn write_signed_number<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let symbols = self.formatter.fixed_decimal_format.symbols.get();
+        let (affixes, part) = match self.sign {
+            SignSelection::Minus => (&symbols.minus_sign_affixes, decimal_parts::MINUS_SIGN),
+            SignSelection::Neither => return self.write_number(sink),
+            SignSelection::Plus => (&symbols.plus_sign_affixes, decimal_parts::PLUS_SIGN),
+        };
+        if !affixes.prefix.is_empty() {
+            sink.with_part(part, |w| w.write_str(&affixes.prefix))?;
+        }
+        self.write_number(sink)?;
+        if !affixes.suffix.is_empty() {
+            sink.with_part(part, |w| w.write_str(&affixes.suffix))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the currency name pattern of a plural category, with `number` writing the
+    /// amount.
+    fn write_with_name<S: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+        count: Count,
+        number: impl FnOnce(&mut S) -> fmt::Result,
+    ) -> fmt::Result {
+        let patterns = &self.formatter.patterns.get().name_patterns;
+        let pattern = patterns
+            .get(&count)
+            .or_else(|| patterns.get(&Count::Other))
+            .unwrap_or("{0} {1}");
+        let names = &self.formatter.names.get().names;
+        let name = names
+            .get(&self.currency, &count)
+            .or_else(|_| names.get(&self.currency, &Count::Other))
+            .unwrap_or_else(|_| self.currency.as_str());
+
+        let mut number = Some(number);
+        let mut rest = pattern;
+        while let Some((literal, placeholder)) = rest.split_once('{') {
+            sink.write_str(literal)?;
+            if let Some(after) = placeholder.strip_prefix("0}") {
+                if let Some(number) = number.take() {
+                    number(sink)?;
+                }
+                rest = after;
+            } else if let Some(after) = placeholder.strip_prefix("1}") {
+                sink.with_part(parts::CURRENCY, |w| w.write_str(name))?;
+                rest = after;
+            } else {
+                sink.write_char('{')?;
+                rest = placeholder;
+            }
+        }
+        sink.write_str(rest)
+    }
+}
+
+/// The [`Part`](writeable::Part)s used by [`FormattedCurrency`], in addition to those of
+/// [`FormattedFixedDecimal`](crate::FormattedFixedDecimal).
+///
+/// The minus sign of the currency pattern is marked with the
+/// [`MINUS_SIGN`](crate::format::parts::MINUS_SIGN) part.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::currency::parts;
+/// use icu::decimal::format::parts as decimal_parts;
+/// use icu::decimal::CurrencyFormat;
+/// use icu::locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let cf = CurrencyFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let amount = "-1234.5".parse().unwrap();
+/// assert_writeable_parts_eq!(
+///     cf.format(&amount, tinystr!(3, "USD")),
+///     "-$1,234.50",
+///     [
+///         (0, 1, decimal_parts::MINUS_SIGN),
+///         (1, 2, parts::CURRENCY),
+///         (2, 7, decimal_parts::INTEGER),
+///         (3, 4, decimal_parts::GROUP),
+///         (7, 8, decimal_parts::DECIMAL),
+///         (8, 10, decimal_parts::FRACTION),
+///     ]
+/// );
+/// ```
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the currency symbol, ISO code, or name, such as "$" in
+    /// "$12.00" or "US dollars" in "12.00 US dollars".
+    pub const CURRENCY: Part = Part {
+        category: "currency",
+        value: "currency",
+    };
+}
+
+/// Returns whether a character is a letter, which the currency spacing of CLDR applies to.
+fn is_letter(c: Option<char>) -> bool {
+    c.map_or(false, char::is_alphabetic)
+}
+
+impl<'l> Writeable for FormattedCurrency<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        match self.symbol() {
+            Some(currency) => {
+                self.write_prefix(sink, currency)?;
+                self.write_number(sink)?;
+                self.write_suffix(sink, currency)
+            }
+            None => {
+                let count = Count::from(self.formatter.plural_rules.rules().select(&self.value));
+                self.write_with_name(sink, count, |sink| self.write_signed_number(sink))
+            }
+        }
+    }
+}
+
+/// An intermediate structure returned by [`CurrencyFormat::format_range`].
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+pub struct FormattedCurrencyRange<'l> {
+    start: FormattedCurrency<'l>,
+    /// The end of the range, or `None` if it is formatted identically to the start.
+    end: Option<FormattedCurrency<'l>>,
+}
+
+impl<'l> Writeable for FormattedCurrencyRange<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let patterns = self.start.formatter.range_patterns.get();
+        let end = match &self.end {
+            Some(end) => end,
+            None => {
+                return range::write_single(
+                    sink,
+                    &patterns.approximately,
+                    Some(range::parts::APPROXIMATELY_SIGN),
+                    |sink| self.start.write_to_parts(sink),
+                )
+            }
+        };
+        let (start, range) = (&self.start, &*patterns.range);
+        let unsigned = |value: &FormattedCurrency| matches!(value.sign, SignSelection::Neither);
+        if unsigned(start) && unsigned(end) {
+            match start.symbol() {
+                Some(currency) => {
+                    let shares_affixes = range::shares_affixes(|w| {
+                        start.write_prefix(w, currency)?;
+                        start.write_suffix(w, currency)
+                    });
+                    if shares_affixes {
+                        start.write_prefix(sink, currency)?;
+                        range::write_range(
+                            sink,
+                            range,
+                            false,
+                            |sink| start.write_number(sink),
+                            |sink| end.write_number(sink),
+                        )?;
+                        return end.write_suffix(sink, currency);
+                    }
+                }
+                None => {
+                    let count = Count::from(
+                        start
+                            .formatter
+                            .plural_rules
+                            .select_range(&start.value, &end.value),
+                    );
+                    if range::shares_affixes(|w| start.write_with_name(w, count, |_| Ok(()))) {
+                        return start.write_with_name(sink, count, |sink| {
+                            range::write_range(
+                                sink,
+                                range,
+                                false,
+                                |sink| start.write_number(sink),
+                                |sink| end.write_number(sink),
+                            )
+                        });
+                    }
+                }
+            }
+        }
+        // The currency or sign next to the separator could be mistaken for it.
+        range::write_range(
+            sink,
+            range,
+            true,
+            |sink| start.write_to_parts(sink),
+            |sink| end.write_to_parts(sink),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::{locale, Locale};
+    use tinystr::tinystr;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    fn formatter(locale: Locale, display: CurrencyDisplay) -> CurrencyFormat {
+        let provider = icu_testdata::get_provider();
+        let options = CurrencyFormatOptions {
+            display,
+            ..Default::default()
+        };
+        CurrencyFormat::try_new(locale, &provider, options).unwrap()
+    }
+
+    #[test]
+    fn test_rounding() {
+        for (value, digits, increment, expected) in [
+            ("1.234", 2, 0, "1.23"),
+            ("1.235", 2, 0, "1.24"),
+            ("1.5", 2, 0, "1.50"),
+            ("2.5", 0, 0, "2"),
+            ("1.22", 2, 5, "1.20"),
+            ("1.225", 2, 5, "1.20"),
+            ("1.2251", 2, 5, "1.25"),
+            ("1.275", 2, 5, "1.30"),
+            ("1.274", 2, 5, "1.25"),
+            ("1.98", 2, 5, "2.00"),
+            ("9.99", 2, 5, "10.00"),
+            ("1.24", 2, 50, "1.00"),
+            ("1.26", 2, 50, "1.50"),
+            ("0.74", 2, 50, "0.50"),
+            ("0.76", 2, 50, "1.00"),
+        ] {
+            let mut value: FixedDecimal = value.parse().unwrap();
+            round_to_increment(&mut value, digits, increment);
+            assert_eq!(value.to_string(), expected, "{} {}", digits, increment);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let value = FixedDecimal::from(-1234567).multiplied_pow10(-2).unwrap();
+        for (display, expected) in [
+            (CurrencyDisplay::Symbol, "-$12,345.67"),
+            (CurrencyDisplay::NarrowSymbol, "-$12,345.67"),
+            (CurrencyDisplay::IsoCode, "-USD\u{a0}12,345.67"),
+            (CurrencyDisplay::Name, "-12,345.67 US dollars"),
+        ] {
+            let cf = formatter(locale!("en"), display);
+            assert_writeable_eq!(cf.format(&value, tinystr!(3, "usd")), expected);
+        }
+
+        let cf = formatter(locale!("en"), CurrencyDisplay::Name);
+        assert_writeable_eq!(cf.format(&1.into(), tinystr!(3, "USD")), "1.00 US dollars");
+        assert_writeable_eq!(cf.format(&1.into(), tinystr!(3, "JPY")), "1 Japanese yen");
+        assert_writeable_eq!(cf.format(&1.into(), tinystr!(3, "XYZ")), "1.00 XYZ");
+    }
+
+    #[test]
+    fn test_locales() {
+        let value = FixedDecimal::from(-1234567).multiplied_pow10(-2).unwrap();
+        let cf = formatter(locale!("fr"), CurrencyDisplay::Symbol);
+        assert_writeable_eq!(
+            cf.format(&value, tinystr!(3, "EUR")),
+            "-12\u{202f}345,67\u{a0}€"
+        );
+        let cf = formatter(locale!("ja"), CurrencyDisplay::Symbol);
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "JPY")), "-￥12,346");
+        let cf = formatter(locale!("ru"), CurrencyDisplay::Name);
+        assert_writeable_eq!(
+            cf.format(&2.into(), tinystr!(3, "RUB")),
+            "2,00 российского рубля"
+        );
+        assert_writeable_eq!(
+            cf.format(&5.into(), tinystr!(3, "RSD")),
+            "5 сербских динаров"
+        );
+        let cf = formatter(locale!("ar"), CurrencyDisplay::Symbol);
+        assert_writeable_eq!(
+            cf.format(&value, tinystr!(3, "EGP")),
+            "؜-١٢٬٣٤٥٫٦٧\u{a0}ج.م.\u{200f}"
+        );
+    }
+
+    #[test]
+    fn test_accounting() {
+        let provider = icu_testdata::get_provider();
+        let options = CurrencyFormatOptions {
+            sign: CurrencySign::Accounting,
+            ..Default::default()
+        };
+        let value = FixedDecimal::from(-1234567).multiplied_pow10(-2).unwrap();
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options.clone()).unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "USD")), "($12,345.67)");
+        assert_writeable_eq!(
+            cf.format(&value, tinystr!(3, "CHF")),
+            "(CHF\u{a0}12,345.67)"
+        );
+        let cf = CurrencyFormat::try_new(locale!("es"), &provider, options).unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "EUR")), "-12.345,67\u{a0}€");
+    }
+
+    #[test]
+    fn test_sign_display() {
+        let provider = icu_testdata::get_provider();
+        let mut options = CurrencyFormatOptions::default();
+        options.decimal.sign_display = SignDisplay::ExceptZero;
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options.clone()).unwrap();
+        assert_writeable_eq!(
+            cf.format(&FixedDecimal::from(5), tinystr!(3, "USD")),
+            "+$5.00"
+        );
+        assert_writeable_eq!(
+            cf.format(&FixedDecimal::from(-5), tinystr!(3, "USD")),
+            "-$5.00"
+        );
+        let value: FixedDecimal = "-0.001".parse().unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "USD")), "$0.00");
+        options.display = CurrencyDisplay::Name;
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options.clone()).unwrap();
+        assert_writeable_eq!(
+            cf.format(&FixedDecimal::from(5), tinystr!(3, "USD")),
+            "+5.00 US dollars"
+        );
+        options.display = CurrencyDisplay::Symbol;
+        options.decimal.sign_display = SignDisplay::Never;
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options).unwrap();
+        assert_writeable_eq!(
+            cf.format(&FixedDecimal::from(-5), tinystr!(3, "USD")),
+            "$5.00"
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        let value = FixedDecimal::from(-1234567).multiplied_pow10(-2).unwrap();
+        let cf = formatter(locale!("en"), CurrencyDisplay::IsoCode);
+        assert_writeable_parts_eq!(
+            cf.format(&value, tinystr!(3, "USD")),
+            "-USD\u{a0}12,345.67",
+            [
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 4, parts::CURRENCY),
+                (6, 12, decimal_parts::INTEGER),
+                (8, 9, decimal_parts::GROUP),
+                (12, 13, decimal_parts::DECIMAL),
+                (13, 15, decimal_parts::FRACTION),
+            ]
+        );
+        let cf = formatter(locale!("en"), CurrencyDisplay::Name);
+        assert_writeable_parts_eq!(
+            cf.format(&value, tinystr!(3, "USD")),
+            "-12,345.67 US dollars",
+            [
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 7, decimal_parts::INTEGER),
+                (3, 4, decimal_parts::GROUP),
+                (7, 8, decimal_parts::DECIMAL),
+                (8, 10, decimal_parts::FRACTION),
+                (11, 21, parts::CURRENCY),
+            ]
+        );
+        let cf = formatter(locale!("fr"), CurrencyDisplay::Symbol);
+        assert_writeable_parts_eq!(
+            cf.format(&FixedDecimal::from(5), tinystr!(3, "EUR")),
+            "5,00\u{a0}€",
+            [
+                (0, 1, decimal_parts::INTEGER),
+                (1, 2, decimal_parts::DECIMAL),
+                (2, 4, decimal_parts::FRACTION),
+                (6, 9, parts::CURRENCY),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let range = |cf: &CurrencyFormat, start: i64, end: i64, currency| {
+            cf.format_range(&start.into(), &end.into(), currency)
+                .write_to_string()
+                .into_owned()
+        };
+        let cf = formatter(locale!("en"), CurrencyDisplay::Symbol);
+        assert_eq!(range(&cf, 3, 5, tinystr!(3, "USD")), "$3.00 – $5.00");
+        assert_eq!(range(&cf, 3, 5, tinystr!(3, "CAD")), "CA$3.00–5.00");
+        assert_eq!(range(&cf, -5, -3, tinystr!(3, "USD")), "-$5.00 – -$3.00");
+        assert_eq!(range(&cf, 5, 5, tinystr!(3, "USD")), "~$5.00");
+        let cf = formatter(locale!("en"), CurrencyDisplay::IsoCode);
+        assert_eq!(range(&cf, 3, 5, tinystr!(3, "USD")), "USD\u{a0}3.00–5.00");
+        let cf = formatter(locale!("en"), CurrencyDisplay::Name);
+        assert_eq!(range(&cf, 3, 5, tinystr!(3, "USD")), "3.00–5.00 US dollars");
+        let cf = formatter(locale!("fr"), CurrencyDisplay::Symbol);
+        assert_eq!(range(&cf, 3, 5, tinystr!(3, "EUR")), "3,00–5,00\u{a0}€");
+
+        let cf = formatter(locale!("en"), CurrencyDisplay::Symbol);
+        let (start, end) = (3.into(), 5.into());
+        assert_writeable_parts_eq!(
+            cf.format_range(&start, &end, tinystr!(3, "JPY")),
+            "¥3 – ¥5",
+            [
+                (0, 2, parts::CURRENCY),
+                (2, 3, decimal_parts::INTEGER),
+                (4, 7, range::parts::RANGE_SEPARATOR),
+                (8, 10, parts::CURRENCY),
+                (10, 11, decimal_parts::INTEGER),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        let provider = icu_testdata::get_provider();
+        let value: FixedDecimal = "1234.567".parse().unwrap();
+        let mut options = CurrencyFormatOptions::default();
+        options.decimal.maximum_fraction_digits = Some(0);
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options.clone()).unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "USD")), "$1,235");
+        options.decimal.maximum_fraction_digits = None;
+        options.decimal.maximum_significant_digits = Some(2);
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options.clone()).unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "USD")), "$1,200");
+        // The fraction digits of the currency apply without fraction or significant digits.
+        options.decimal.maximum_significant_digits = None;
+        options.decimal.grouping_strategy = GroupingStrategy::Never;
+        options.decimal.minimum_integer_digits = Some(5);
+        let cf = CurrencyFormat::try_new(locale!("en"), &provider, options).unwrap();
+        assert_writeable_eq!(cf.format(&value, tinystr!(3, "USD")), "$01234.57");
+    }
+}
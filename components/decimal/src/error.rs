@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Error types for decimal formatting.
+//! Error types for decimal formatting and parsing.
 
 use displaydoc::Display;
 
@@ -13,13 +13,52 @@ pub enum Error {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    /// An error loading the plural rules of the locale.
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// A list of possible error outcomes for [`FixedDecimalParser`](crate::FixedDecimalParser).
+///
+/// Every error has the byte offset in the input at which parsing failed.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input has an unexpected character, or ends where a character was expected.
+    #[displaydoc("unexpected character at byte {0}")]
+    Syntax(usize),
+    /// A grouping separator is missing or at a position that does not match the grouping
+    /// sizes of the locale.
+    #[displaydoc("misplaced grouping separator at byte {0}")]
+    Grouping(usize),
+    /// The number is out of the range of a [`FixedDecimal`](fixed_decimal::FixedDecimal).
+    #[displaydoc("number out of range at byte {0}")]
+    Limit(usize),
+}
+
+impl ParseError {
+    /// Returns the byte offset in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        match *self {
+            Self::Syntax(position) | Self::Grouping(position) | Self::Limit(position) => position,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
 impl From<icu_provider::DataError> for Error {
     fn from(e: icu_provider::DataError) -> Self {
         Error::Data(e)
     }
 }
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
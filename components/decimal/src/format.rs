@@ -8,60 +8,243 @@ use crate::grouper;
 use crate::options::*;
 use crate::provider::*;
 use crate::sign_selector;
+use alloc::borrow::Cow;
+use core::fmt::{self, Write};
 use fixed_decimal::FixedDecimal;
-use writeable::Writeable;
+use writeable::{Part, PartsWrite, Writeable};
 
 /// An intermediate structure returned by [`FixedDecimalFormat`](crate::FixedDecimalFormat).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedFixedDecimal<'l> {
-    pub(crate) value: &'l FixedDecimal,
+    pub(crate) value: Cow<'l, FixedDecimal>,
     pub(crate) options: &'l FixedDecimalFormatOptions,
     pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
-    fn get_affixes(&self) -> Option<&AffixesV1> {
+    /// Returns the number after rounding to the digit options, which is the number that is
+    /// rendered and that should be used to select a plural category.
+    pub fn rounded_value(&self) -> &FixedDecimal {
+        &self.value
+    }
+
+    /// Returns whether the number is rendered with a sign.
+    pub(crate) fn has_sign(&self) -> bool {
+        self.get_affixes().is_some()
+    }
+
+    fn get_affixes(&self) -> Option<(&AffixesV1, Part)> {
         use sign_selector::SignSelection::*;
         match sign_selector::select(self.value.signum(), self.options.sign_display) {
-            Minus => Some(&self.symbols.minus_sign_affixes),
+            Minus => Some((&self.symbols.minus_sign_affixes, parts::MINUS_SIGN)),
             Neither => None,
-            Plus => Some(&self.symbols.plus_sign_affixes),
+            Plus => Some((&self.symbols.plus_sign_affixes, parts::PLUS_SIGN)),
         }
     }
 }
 
+/// The [`Part`]s used by [`FormattedFixedDecimal`], which correspond to the part types of
+/// ECMA-402 `formatToParts`.
+///
+/// The [`INTEGER`](parts::INTEGER) part spans the integer digits along with the
+/// [`GROUP`](parts::GROUP) parts between them.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::format::parts;
+/// use icu::decimal::FixedDecimalFormat;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let fdf = FixedDecimalFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let value = "-1234.5".parse().unwrap();
+/// assert_writeable_parts_eq!(
+///     fdf.format(&value),
+///     "-1,234.5",
+///     [
+///         (0, 1, parts::MINUS_SIGN),
+///         (1, 6, parts::INTEGER),
+///         (2, 3, parts::GROUP),
+///         (6, 7, parts::DECIMAL),
+///         (7, 8, parts::FRACTION),
+///     ]
+/// );
+/// ```
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the integer digits, such as "1,234" in "1,234.5".
+    pub const INTEGER: Part = Part {
+        category: "decimal",
+        value: "integer",
+    };
+
+    /// The [`Part`] used to mark a grouping separator, such as "," in "1,234.5".
+    pub const GROUP: Part = Part {
+        category: "decimal",
+        value: "group",
+    };
+
+    /// The [`Part`] used to mark the decimal separator, such as "." in "1,234.5".
+    pub const DECIMAL: Part = Part {
+        category: "decimal",
+        value: "decimal",
+    };
+
+    /// The [`Part`] used to mark the fraction digits, such as "5" in "1,234.5".
+    pub const FRACTION: Part = Part {
+        category: "decimal",
+        value: "fraction",
+    };
+
+    /// The [`Part`] used to mark a minus sign affix, such as "-" in "-5". The part spans the
+    /// whole affix, including any bidi marks.
+    pub const MINUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "minusSign",
+    };
+
+    /// The [`Part`] used to mark a plus sign affix, such as "+" in "+5". The part spans the
+    /// whole affix, including any bidi marks.
+    pub const PLUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "plusSign",
+    };
+}
+
+/// Writes literal text of a pattern, marking the minus sign in it with the
+/// [`MINUS_SIGN`](parts::MINUS_SIGN) part, such as "-" in the percent prefix "-" of English.
+pub(crate) fn write_literal_with_minus_sign<S: PartsWrite + ?Sized>(
+    sink: &mut S,
+    literal: &str,
+    minus_sign: &str,
+) -> fmt::Result {
+    match literal.split_once(minus_sign) {
+        Some((before, after)) if !minus_sign.is_empty() => {
+            sink.write_str(before)?;
+            sink.with_part(parts::MINUS_SIGN, |w| w.write_str(minus_sign))?;
+            sink.write_str(after)
+        }
+        _ => sink.write_str(literal),
+    }
+}
+
 impl<'l> Writeable for FormattedFixedDecimal<'l> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
-    where
-        W: core::fmt::Write + ?Sized,
-    {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         let affixes = self.get_affixes();
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.prefix)?;
+        if let Some((affixes, part)) = affixes {
+            if !affixes.prefix.is_empty() {
+                sink.with_part(part, |w| w.write_str(&affixes.prefix))?;
+            }
         }
         let range = self.value.magnitude_range();
         let upper_magnitude = *range.end();
-        for m in range.rev() {
-            if m == -1 {
-                sink.write_str(&self.symbols.decimal_separator)?;
-            }
+        let lower_magnitude = *range.start();
+        let write_digit = |w: &mut S::SubPartsWrite, m| {
             let d = self.value.digit_at(m);
-            #[allow(clippy::indexing_slicing)]
-            // TODO(#1668) Clippy exceptions need docs or fixing.
-            sink.write_char(self.symbols.digits[d as usize])?;
-            if grouper::check(
-                upper_magnitude,
-                m,
-                self.options.grouping_strategy,
-                &self.symbols.grouping_sizes,
-            ) {
-                sink.write_str(&self.symbols.grouping_separator)?;
+            #[allow(clippy::indexing_slicing)] // digit_at returns a digit from 0 to 9
+            w.write_char(self.symbols.digits[d as usize])
+        };
+        sink.with_part(parts::INTEGER, |w| {
+            for m in (0..=upper_magnitude).rev() {
+                write_digit(w, m)?;
+                if grouper::check(
+                    upper_magnitude,
+                    m,
+                    self.options.grouping_strategy,
+                    &self.symbols.grouping_sizes,
+                ) {
+                    w.with_part(parts::GROUP, |w| {
+                        w.write_str(&self.symbols.grouping_separator)
+                    })?;
+                }
             }
+            Ok(())
+        })?;
+        if lower_magnitude < 0 {
+            sink.with_part(parts::DECIMAL, |w| {
+                w.write_str(&self.symbols.decimal_separator)
+            })?;
+            sink.with_part(parts::FRACTION, |w| {
+                for m in (lower_magnitude..0).rev() {
+                    write_digit(w, m)?;
+                }
+                Ok(())
+            })?;
         }
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.suffix)?;
+        if let Some((affixes, part)) = affixes {
+            if !affixes.suffix.is_empty() {
+                sink.with_part(part, |w| w.write_str(&affixes.suffix))?;
+            }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedDecimalFormat;
+    use icu_locid::locale;
+    use writeable::assert_writeable_parts_eq;
+
+    #[test]
+    fn test_parts() {
+        let provider = icu_testdata::get_provider();
+        let fdf =
+            FixedDecimalFormat::try_new(locale!("en"), &provider, Default::default()).unwrap();
+        let value = "1234567".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fdf.format(&value),
+            "1,234,567",
+            [
+                (0, 9, parts::INTEGER),
+                (1, 2, parts::GROUP),
+                (5, 6, parts::GROUP),
+            ]
+        );
+        let value = "-0.50".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fdf.format(&value),
+            "-0.50",
+            [
+                (0, 1, parts::MINUS_SIGN),
+                (1, 2, parts::INTEGER),
+                (2, 3, parts::DECIMAL),
+                (3, 5, parts::FRACTION),
+            ]
+        );
+
+        let options = FixedDecimalFormatOptions {
+            sign_display: SignDisplay::Always,
+            ..Default::default()
+        };
+        let fdf = FixedDecimalFormat::try_new(locale!("en"), &provider, options).unwrap();
+        let value = 5.into();
+        assert_writeable_parts_eq!(
+            fdf.format(&value),
+            "+5",
+            [(0, 1, parts::PLUS_SIGN), (1, 2, parts::INTEGER)]
+        );
+
+        // The minus sign affix of Arabic starts with an Arabic letter mark.
+        let fdf =
+            FixedDecimalFormat::try_new(locale!("ar-EG"), &provider, Default::default()).unwrap();
+        let value = "-1.5".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fdf.format(&value),
+            "\u{61c}-١٫٥",
+            [
+                (0, 3, parts::MINUS_SIGN),
+                (3, 5, parts::INTEGER),
+                (5, 7, parts::DECIMAL),
+                (7, 9, parts::FRACTION),
+            ]
+        );
+    }
+}
@@ -7,10 +7,18 @@
 //! [`icu_decimal`](crate) offers localized decimal number formatting.
 //!
 //! Currently, [`icu_decimal`](crate) provides [`FixedDecimalFormat`], which renders basic decimal numbers
-//! in a locale-sensitive way.
+//! in a locale-sensitive way, [`CompactDecimalFormat`], which renders them in compact notation,
+//! such as "1.2K", [`ScientificDecimalFormat`], which renders them in scientific or engineering
+//! notation, such as "6.022E23", [`CurrencyFormat`], which renders amounts of money, such as
+//! "$1,234.50", [`PercentFormat`], which renders percentages, such as "12%", and
+//! [`DecimalRangeFormat`], which renders ranges and approximate numbers, such as "3–5" or "~5".
 //!
-//! Support for currencies, measurement units, and compact notation is planned. To track progress,
-//! follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
+//! [`FixedDecimalParser`] reads localized decimal numbers, such as "1.234,56" in Spanish, back
+//! into [`FixedDecimal`](fixed_decimal::FixedDecimal).
+//!
+//! Formatted numbers annotate their integer, grouping separator, decimal separator, fraction,
+//! and sign with the [`Part`](writeable::Part)s in [`format::parts`], as ECMA-402
+//! `formatToParts` does.
 //!
 //! # Examples
 //!
@@ -70,18 +78,33 @@
 
 extern crate alloc;
 
+pub mod compact;
+pub mod currency;
 pub mod error;
 pub mod format;
 mod grouper;
 pub mod options;
+pub mod parse;
+pub mod percent;
 pub mod provider;
+pub mod range;
+mod rounder;
+pub mod scientific;
 mod sign_selector;
 
+pub use compact::CompactDecimalFormat;
+pub use currency::CurrencyFormat;
 pub use error::Error as FixedDecimalFormatError;
+pub use error::ParseError as FixedDecimalParseError;
 pub use format::FormattedFixedDecimal;
+pub use parse::FixedDecimalParser;
+pub use percent::PercentFormat;
+pub use range::DecimalRangeFormat;
+pub use scientific::ScientificDecimalFormat;
 
+use alloc::borrow::Cow;
 use fixed_decimal::FixedDecimal;
-use icu_locid::Locale;
+use icu_locid::{extensions_unicode_key as key, Locale};
 use icu_provider::prelude::*;
 
 /// A formatter for [`FixedDecimal`], rendering decimal digits in an i18n-friendly way.
@@ -102,6 +125,25 @@ pub struct FixedDecimalFormat {
 
 impl FixedDecimalFormat {
     /// Creates a new [`FixedDecimalFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, which
+    /// can also be one of the aliases `native`, `traditio`, and `finance`. If the data provider
+    /// has no symbols for the requested numbering system, the locale's default is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let locale: Locale = "ar-EG-u-nu-latn".parse().unwrap();
+    /// let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// assert_eq!("1,000,007", fdf.format(&1000007.into()).write_to_string());
+    /// ```
     pub fn try_new<
         T: Into<Locale>,
         D: ResourceProvider<provider::DecimalSymbolsV1Marker> + ?Sized,
@@ -110,17 +152,21 @@ impl FixedDecimalFormat {
         data_provider: &D,
         options: options::FixedDecimalFormatOptions,
     ) -> Result<Self, FixedDecimalFormatError> {
-        let symbols = data_provider
-            .load_resource(&DataRequest {
-                options: locale.into().into(),
-                metadata: Default::default(),
-            })?
-            .take_payload()?;
+        let symbols = load_with_numbering_system(&locale.into(), data_provider)?;
         Ok(Self { options, symbols })
     }
 
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
+    ///
+    /// The number is rounded and padded according to the digit options, if any.
     pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedFixedDecimal<'l> {
+        let value = if self.options.has_digit_options() {
+            let mut value = value.clone();
+            rounder::apply(&mut value, &self.options);
+            Cow::Owned(value)
+        } else {
+            Cow::Borrowed(value)
+        };
         FormattedFixedDecimal {
             value,
             options: &self.options,
@@ -128,3 +174,32 @@ impl FixedDecimalFormat {
         }
     }
 }
+
+/// Loads data for the locale and its `-u-nu` keyword, falling back to the locale's default
+/// numbering system if the data provider has no data for the keyword.
+pub(crate) fn load_with_numbering_system<M, D>(
+    locale: &Locale,
+    data_provider: &D,
+) -> Result<DataPayload<M>, DataError>
+where
+    M: ResourceMarker,
+    D: ResourceProvider<M> + ?Sized,
+{
+    let mut resource_options: ResourceOptions = locale.into();
+    resource_options.retain_unicode_ext(|key| key == &key!("nu"));
+    let response = match data_provider.load_resource(&DataRequest {
+        options: resource_options.clone(),
+        metadata: Default::default(),
+    }) {
+        Err(DataError {
+            kind: DataErrorKind::MissingResourceOptions,
+            ..
+        }) if resource_options.remove_unicode_ext(&key!("nu")).is_some() => data_provider
+            .load_resource(&DataRequest {
+                options: resource_options,
+                metadata: Default::default(),
+            })?,
+        result => result?,
+    };
+    response.take_payload()
+}
@@ -2,10 +2,42 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for [`FixedDecimalFormat`](crate::FixedDecimalFormat).
+//! Options for [`FixedDecimalFormat`](crate::FixedDecimalFormat),
+//! [`ScientificDecimalFormat`](crate::ScientificDecimalFormat),
+//! [`CurrencyFormat`](crate::CurrencyFormat), [`PercentFormat`](crate::PercentFormat),
+//! [`DecimalRangeFormat`](crate::DecimalRangeFormat), and
+//! [`FixedDecimalParser`](crate::FixedDecimalParser).
 
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormat`](crate::FixedDecimalFormat).
+///
+/// The digit options follow ECMA-402 `Intl.NumberFormat`, except that an unset maximum does
+/// not round the number. If no digit options are set, the number is formatted with exactly the
+/// digits of the [`FixedDecimal`](fixed_decimal::FixedDecimal), including leading and trailing
+/// zeros. Otherwise, zeros beyond the minimum digits are removed. A maximum less than the
+/// corresponding minimum is treated as equal to the minimum.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::FixedDecimalFormatOptions = Default::default();
+/// options.minimum_fraction_digits = Some(2);
+/// options.maximum_fraction_digits = Some(2);
+/// let fdf = FixedDecimalFormat::try_new(Locale::UND, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = "1234.5678".parse().expect("valid syntax");
+/// assert_eq!("1,234.57", fdf.format(&value).write_to_string());
+///
+/// let value = 3.into();
+/// assert_eq!("3.00", fdf.format(&value).write_to_string());
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone, Default)]
 #[non_exhaustive]
 pub struct FixedDecimalFormatOptions {
@@ -13,6 +45,174 @@ pub struct FixedDecimalFormatOptions {
     pub grouping_strategy: GroupingStrategy,
     /// When to render the sign.
     pub sign_display: SignDisplay,
+    /// The minimum number of integer digits, padded with leading zeros. Defaults to 1.
+    pub minimum_integer_digits: Option<u8>,
+    /// The maximum number of integer digits. Larger integer digits are removed, such as "22"
+    /// for 2022 with a maximum of 2.
+    pub maximum_integer_digits: Option<u8>,
+    /// The minimum number of fraction digits, padded with trailing zeros. Defaults to 0.
+    pub minimum_fraction_digits: Option<u8>,
+    /// The maximum number of fraction digits, rounded with the [`RoundingMode`] and the
+    /// [`RoundingIncrement`].
+    pub maximum_fraction_digits: Option<u8>,
+    /// The minimum number of significant digits, padded with trailing zeros. Defaults to 1.
+    pub minimum_significant_digits: Option<u8>,
+    /// The maximum number of significant digits, rounded with the [`RoundingMode`].
+    pub maximum_significant_digits: Option<u8>,
+    /// How to round the number to the maximum digits.
+    pub rounding_mode: RoundingMode,
+    /// The multiples of the last fraction digit to round to, such as 0.05 with a maximum of 2
+    /// fraction digits and [`RoundingIncrement::MultiplesOf5`].
+    ///
+    /// The increment only applies to rounding to the maximum fraction digits, not to rounding
+    /// to the maximum significant digits.
+    pub rounding_increment: RoundingIncrement,
+    /// Whether to round to the fraction digits or the significant digits if both are set.
+    pub rounding_priority: RoundingPriority,
+}
+
+impl FixedDecimalFormatOptions {
+    /// Returns whether any digit options are set.
+    pub(crate) fn has_digit_options(&self) -> bool {
+        self.minimum_integer_digits.is_some()
+            || self.maximum_integer_digits.is_some()
+            || self.has_rounding_options()
+    }
+
+    /// Returns whether any fraction or significant digit options are set.
+    pub(crate) fn has_rounding_options(&self) -> bool {
+        self.minimum_fraction_digits.is_some()
+            || self.maximum_fraction_digits.is_some()
+            || self.minimum_significant_digits.is_some()
+            || self.maximum_significant_digits.is_some()
+    }
+}
+
+/// Configuration for how to round a number to its maximum digits, following the rounding modes
+/// of ECMA-402 `Intl.NumberFormat`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let format = |rounding_mode, value: &str| {
+///     let mut options: options::FixedDecimalFormatOptions = Default::default();
+///     options.maximum_fraction_digits = Some(0);
+///     options.rounding_mode = rounding_mode;
+///     let fdf = FixedDecimalFormat::try_new(Locale::UND, &provider, options)
+///         .expect("Data should load successfully");
+///     fdf.format(&value.parse().expect("valid syntax"))
+///         .write_to_string()
+///         .into_owned()
+/// };
+///
+/// assert_eq!("-2", format(options::RoundingMode::Floor, "-1.5"));
+/// assert_eq!("-1", format(options::RoundingMode::Trunc, "-1.5"));
+/// assert_eq!("-2", format(options::RoundingMode::HalfExpand, "-1.5"));
+/// assert_eq!("2", format(options::RoundingMode::HalfEven, "2.5"));
+/// ```
+pub use fixed_decimal::RoundingMode;
+
+/// Configuration for the multiples of the last fraction digit to round to.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::FixedDecimalFormatOptions = Default::default();
+/// options.minimum_fraction_digits = Some(2);
+/// options.maximum_fraction_digits = Some(2);
+/// options.rounding_increment = options::RoundingIncrement::MultiplesOf5;
+/// let fdf = FixedDecimalFormat::try_new(Locale::UND, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = "1.23".parse().expect("valid syntax");
+/// assert_eq!("1.25", fdf.format(&value).write_to_string());
+///
+/// let value = "1.96".parse().expect("valid syntax");
+/// assert_eq!("1.95", fdf.format(&value).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingIncrement {
+    /// Round to any number with the maximum fraction digits, such as 0.01.
+    MultiplesOf1,
+
+    /// Round to multiples of 2 in the last fraction digit, such as 0.02.
+    MultiplesOf2,
+
+    /// Round to multiples of 5 in the last fraction digit, such as 0.05.
+    MultiplesOf5,
+
+    /// Round to multiples of 25 in the last two fraction digits, such as 0.25.
+    MultiplesOf25,
+}
+
+impl Default for RoundingIncrement {
+    fn default() -> Self {
+        Self::MultiplesOf1
+    }
+}
+
+/// Configuration for whether to round to the fraction digits or the significant digits if both
+/// are set, following ECMA-402 `roundingPriority`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let format = |rounding_priority, value: &str| {
+///     let mut options: options::FixedDecimalFormatOptions = Default::default();
+///     options.maximum_fraction_digits = Some(1);
+///     options.maximum_significant_digits = Some(2);
+///     options.rounding_priority = rounding_priority;
+///     let fdf = FixedDecimalFormat::try_new(Locale::UND, &provider, options)
+///         .expect("Data should load successfully");
+///     fdf.format(&value.parse().expect("valid syntax"))
+///         .write_to_string()
+///         .into_owned()
+/// };
+///
+/// assert_eq!("0.012", format(options::RoundingPriority::Auto, "0.0123"));
+/// assert_eq!("0.012", format(options::RoundingPriority::MorePrecision, "0.0123"));
+/// assert_eq!("0", format(options::RoundingPriority::LessPrecision, "0.0123"));
+/// assert_eq!("120", format(options::RoundingPriority::LessPrecision, "123.45"));
+/// assert_eq!("123.5", format(options::RoundingPriority::MorePrecision, "123.45"));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingPriority {
+    /// Round to the significant digits if any are set, and otherwise to the fraction digits.
+    Auto,
+
+    /// Round to whichever of the fraction digits and the significant digits keeps more
+    /// precision, preferring the significant digits if both keep the same.
+    MorePrecision,
+
+    /// Round to whichever of the fraction digits and the significant digits keeps less
+    /// precision, preferring the fraction digits if both keep the same.
+    LessPrecision,
+}
+
+impl Default for RoundingPriority {
+    fn default() -> Self {
+        Self::Auto
+    }
 }
 
 /// Configuration for how often to render grouping separators.
@@ -122,3 +322,398 @@ impl Default for SignDisplay {
         Self::Auto
     }
 }
+
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificDecimalFormat`](crate::ScientificDecimalFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatOptions {
+    /// Options for rendering the mantissa.
+    pub mantissa: FixedDecimalFormatOptions,
+    /// How to choose the exponent.
+    pub notation: ScientificNotation,
+    /// How to render the exponent.
+    pub exponent_style: ExponentStyle,
+}
+
+/// Configuration for how to choose the exponent of a number in scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let locale = Locale::UND;
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::ScientificDecimalFormatOptions = Default::default();
+/// options.notation = options::ScientificNotation::Engineering;
+/// let sdf = ScientificDecimalFormat::try_new(locale, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = 12345.into();
+/// assert_eq!("12.345E3", sdf.format(&value).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ScientificNotation {
+    /// Render exactly one nonzero integer digit, such as "1.2345E4".
+    Scientific,
+
+    /// Render one to three integer digits, such that the exponent is a multiple of 3,
+    /// such as "12.345E3".
+    Engineering,
+}
+
+impl Default for ScientificNotation {
+    fn default() -> Self {
+        Self::Scientific
+    }
+}
+
+/// Configuration for how to render the exponent of a number in scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificDecimalFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let locale = Locale::UND;
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::ScientificDecimalFormatOptions = Default::default();
+/// options.exponent_style = options::ExponentStyle::Superscript;
+/// let sdf = ScientificDecimalFormat::try_new(locale, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = 12345.into();
+/// assert_eq!("1.2345×10⁴", sdf.format(&value).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ExponentStyle {
+    /// Render the exponent after the locale's exponential symbol, such as "6.022E23".
+    Symbol,
+
+    /// Render the exponent as a superscripted power of ten, such as "6.022×10²³".
+    ///
+    /// The exponent is rendered with superscript digits if the numbering system uses the
+    /// digits 0 to 9, and with the digits of the numbering system otherwise.
+    Superscript,
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// A bag of options defining how amounts of money will be formatted by
+/// [`CurrencyFormat`](crate::CurrencyFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct CurrencyFormatOptions {
+    /// How to display the currency.
+    pub display: CurrencyDisplay,
+    /// How to display negative amounts.
+    pub sign: CurrencySign,
+    /// Which fraction digits and rounding of the currency to use.
+    pub usage: CurrencyUsage,
+    /// Options for rendering the amount.
+    ///
+    /// If any fraction or significant digit options are set, the amount is rounded with them
+    /// instead of the fraction digits and rounding of the currency. The sign is placed
+    /// according to the currency pattern of the locale.
+    pub decimal: FixedDecimalFormatOptions,
+}
+
+/// Configuration for how to display the currency of an amount of money.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::CurrencyFormat;
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let format = |display| {
+///     let mut options: options::CurrencyFormatOptions = Default::default();
+///     options.display = display;
+///     let cf = CurrencyFormat::try_new(locale!("en"), &provider, options)
+///         .expect("Data should load successfully");
+///     cf.format(&1234.into(), tinystr!(3, "CAD")).write_to_string().into_owned()
+/// };
+///
+/// assert_eq!("CA$1,234.00", format(options::CurrencyDisplay::Symbol));
+/// assert_eq!("$1,234.00", format(options::CurrencyDisplay::NarrowSymbol));
+/// assert_eq!("CAD\u{a0}1,234.00", format(options::CurrencyDisplay::IsoCode));
+/// assert_eq!("1,234.00 Canadian dollars", format(options::CurrencyDisplay::Name));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyDisplay {
+    /// Display the currency symbol, such as "CA$".
+    Symbol,
+
+    /// Display the narrow currency symbol, such as "$", which may be ambiguous.
+    NarrowSymbol,
+
+    /// Display the ISO 4217 code, such as "CAD".
+    IsoCode,
+
+    /// Display the currency name, such as "Canadian dollars".
+    Name,
+}
+
+impl Default for CurrencyDisplay {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// Configuration for how to display negative amounts of money.
+///
+/// Negative amounts are always displayed with a minus sign if the currency is displayed by its
+/// name.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::CurrencyFormat;
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options: options::CurrencyFormatOptions = Default::default();
+/// options.sign = options::CurrencySign::Accounting;
+/// let cf = CurrencyFormat::try_new(locale!("en"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let refund = (-1234).into();
+/// assert_eq!("($1,234.00)", cf.format(&refund, tinystr!(3, "USD")).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencySign {
+    /// Display negative amounts with the locale's minus sign, such as "-$1.00".
+    Standard,
+
+    /// Display negative amounts as in the locale's accounting format, such as "($1.00)".
+    Accounting,
+}
+
+impl Default for CurrencySign {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Configuration for which fraction digits and rounding increment of a currency to use.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::CurrencyFormat;
+/// use icu_locid::locale;
+/// use fixed_decimal::FixedDecimal;
+/// use tinystr::tinystr;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options: options::CurrencyFormatOptions = Default::default();
+/// options.usage = options::CurrencyUsage::Cash;
+/// let cf = CurrencyFormat::try_new(locale!("en"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let price: FixedDecimal = "12.34".parse().unwrap();
+/// assert_eq!("CHF\u{a0}12.35", cf.format(&price, tinystr!(3, "CHF")).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyUsage {
+    /// Use the fraction digits and rounding for most uses, such as 2 digits for CHF.
+    Standard,
+
+    /// Use the fraction digits and rounding for cash transactions, such as rounding to
+    /// multiples of 0.05 for CHF.
+    Cash,
+}
+
+impl Default for CurrencyUsage {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// A bag of options defining how percentages will be formatted by
+/// [`PercentFormat`](crate::PercentFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct PercentFormatOptions {
+    /// Whether to format the number as a percentage or in parts per thousand.
+    pub unit: PercentUnit,
+    /// Options for rendering the number after scaling it, such as 12.5 for "12.5%".
+    ///
+    /// The sign is placed according to the percent pattern of the locale.
+    pub decimal: FixedDecimalFormatOptions,
+}
+
+/// Configuration for the unit of a number formatted by [`PercentFormat`](crate::PercentFormat).
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::PercentFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let locale = Locale::UND;
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::PercentFormatOptions = Default::default();
+/// options.unit = options::PercentUnit::Permille;
+/// let pf = PercentFormat::try_new(locale, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = "0.125".parse().expect("valid syntax");
+/// assert_eq!("125‰", pf.format(&value).write_to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum PercentUnit {
+    /// Multiply the number by 100 and render the percent sign, such as "12.5%".
+    Percent,
+
+    /// Multiply the number by 1000 and render the permille sign, such as "125‰".
+    Permille,
+}
+
+impl Default for PercentUnit {
+    fn default() -> Self {
+        Self::Percent
+    }
+}
+
+/// A bag of options defining how ranges of numbers will be formatted by
+/// [`DecimalRangeFormat`](crate::DecimalRangeFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct DecimalRangeFormatOptions {
+    /// Options for rendering both numbers of the range.
+    pub decimal: FixedDecimalFormatOptions,
+    /// How to render a range whose numbers are formatted identically.
+    pub identity_fallback: RangeIdentityFallback,
+}
+
+/// Configuration for how to render a range whose start and end are formatted identically,
+/// such as 3 to 3, or 2.99 to 3.01 rounded to integers.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::DecimalRangeFormat;
+/// use icu_locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let format = |identity_fallback, start: &str, end: &str| {
+///     let mut options: options::DecimalRangeFormatOptions = Default::default();
+///     options.decimal.maximum_fraction_digits = Some(0);
+///     options.identity_fallback = identity_fallback;
+///     let drf = DecimalRangeFormat::try_new(Locale::UND, &provider, options)
+///         .expect("Data should load successfully");
+///     drf.format_range(
+///         &start.parse().expect("valid syntax"),
+///         &end.parse().expect("valid syntax"),
+///     )
+///     .write_to_string()
+///     .into_owned()
+/// };
+///
+/// use options::RangeIdentityFallback::*;
+/// assert_eq!("~3", format(Approximately, "3", "3"));
+/// assert_eq!("3", format(SingleValue, "2.99", "3.01"));
+/// assert_eq!("3", format(ApproximatelyOrSingleValue, "3", "3"));
+/// assert_eq!("~3", format(ApproximatelyOrSingleValue, "2.99", "3.01"));
+/// assert_eq!("3–3", format(Range, "2.99", "3.01"));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RangeIdentityFallback {
+    /// Render the number once, such as "3".
+    SingleValue,
+
+    /// Render the number once if the start and end are equal, and otherwise render it as
+    /// approximate, such as "~3".
+    ApproximatelyOrSingleValue,
+
+    /// Render the number as approximate, such as "~3", as ECMA-402 `formatRange` does.
+    Approximately,
+
+    /// Render the range, such as "3–3".
+    Range,
+}
+
+impl Default for RangeIdentityFallback {
+    fn default() -> Self {
+        Self::Approximately
+    }
+}
+
+/// A bag of options defining how numbers will be parsed by
+/// [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct FixedDecimalParserOptions {
+    /// How closely the input has to match the formatted number.
+    pub mode: ParseMode,
+}
+
+/// Configuration for how closely the input of [`FixedDecimalParser`](crate::FixedDecimalParser)
+/// has to match a number formatted by [`FixedDecimalFormat`](crate::FixedDecimalFormat).
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::Locale;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let mut options: options::FixedDecimalParserOptions = Default::default();
+/// options.mode = options::ParseMode::Lenient;
+/// let parser = FixedDecimalParser::try_new(Locale::UND, &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let value = parser.parse(" 12,34.5 ").expect("valid input");
+/// assert_eq!("1234.5", value.to_string());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParseMode {
+    /// Accept only the digits, separators, and signs of the locale, with grouping separators,
+    /// if any, at the positions of the locale's grouping sizes.
+    Strict,
+
+    /// Additionally accept ASCII digits and signs, grouping separators at any position in the
+    /// integer digits, any kind of space for a space grouping separator, a missing integer or
+    /// fraction part, and surrounding whitespace and bidi marks.
+    Lenient,
+}
+
+impl Default for ParseMode {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
@@ -0,0 +1,366 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized decimal numbers, such as "1.234,56" in Spanish.
+
+use crate::error::ParseError;
+use crate::grouper;
+use crate::options::*;
+use crate::provider::*;
+use crate::FixedDecimalFormatError;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+
+/// A parser for [`FixedDecimal`], reading decimal numbers formatted in a locale-sensitive way,
+/// as by [`FixedDecimalFormat`](crate::FixedDecimalFormat).
+///
+/// [`FixedDecimalParser`] reads:
+///
+/// 1. Digits in the local numbering system
+/// 2. The local decimal and grouping separators
+/// 3. The local plus and minus signs
+///
+/// The parsed number keeps the digits of the input, including leading and trailing zeros.
+///
+/// Read more about the strict and lenient modes in [`ParseMode`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::{FixedDecimalParseError, FixedDecimalParser};
+/// use icu::locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let parser = FixedDecimalParser::try_new(locale!("es"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// let value = parser.parse("-1.234,56").expect("valid input");
+/// assert_eq!("-1234.56", value.to_string());
+///
+/// // Grouping separators have to match the grouping sizes of the locale. The error has
+/// // the position of the missing separator.
+/// assert_eq!(
+///     parser.parse("12.34,56"),
+///     Err(FixedDecimalParseError::Grouping(1))
+/// );
+///
+/// let parser = FixedDecimalParser::try_new(locale!("ar"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// let value = parser.parse("١٬٠٠٠٫٥").expect("valid input");
+/// assert_eq!("1000.5", value.to_string());
+/// ```
+pub struct FixedDecimalParser {
+    options: FixedDecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+impl FixedDecimalParser {
+    /// Creates a new [`FixedDecimalParser`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`](crate::FixedDecimalFormat::try_new).
+    pub fn try_new<T: Into<Locale>, D: ResourceProvider<DecimalSymbolsV1Marker> + ?Sized>(
+        locale: T,
+        data_provider: &D,
+        options: FixedDecimalParserOptions,
+    ) -> Result<Self, FixedDecimalFormatError> {
+        let symbols = crate::load_with_numbering_system(&locale.into(), data_provider)?;
+        Ok(Self { options, symbols })
+    }
+
+    /// Parses a localized decimal number into a [`FixedDecimal`].
+    ///
+    /// Returns a [`ParseError`] with the byte offset in the input at which parsing failed.
+    pub fn parse(&self, input: &str) -> Result<FixedDecimal, ParseError> {
+        let symbols = self.symbols.get();
+        let lenient = self.options.mode == ParseMode::Lenient;
+        let mut cursor = if lenient {
+            let start = input.len() - input.trim_start().len();
+            Cursor {
+                input,
+                position: start,
+                end: start.max(input.trim_end().len()),
+                lenient,
+            }
+        } else {
+            Cursor {
+                input,
+                position: 0,
+                end: input.len(),
+                lenient,
+            }
+        };
+
+        let minus = &symbols.minus_sign_affixes;
+        let plus = &symbols.plus_sign_affixes;
+        let mut signs = vec![
+            (b'-', &*minus.prefix, &*minus.suffix),
+            (b'+', &*plus.prefix, &*plus.suffix),
+        ];
+        if lenient {
+            signs.extend([(b'-', "-", ""), (b'+', "+", "")]);
+        }
+        // A sign with a prefix is read before the number, and a sign without a prefix after it.
+        let prefixed_sign = signs
+            .iter()
+            .find(|(_, prefix, _)| !prefix.is_empty() && cursor.eat(prefix));
+
+        let digits_start = cursor.position;
+        let mut integer = String::new();
+        // The byte offsets of the integer digits and of the grouping separators, with the number
+        // of integer digits before each separator.
+        let mut integer_positions = Vec::new();
+        let mut separators = Vec::new();
+        loop {
+            let position = cursor.position;
+            if let Some(digit) = cursor.eat_digit(&symbols.digits) {
+                integer.push(digit);
+                integer_positions.push(position);
+            } else if !symbols.grouping_separator.is_empty()
+                && cursor.eat(&symbols.grouping_separator)
+            {
+                // A grouping separator is always between two digits.
+                if integer.is_empty() || cursor.peek_digit(&symbols.digits).is_none() {
+                    return Err(ParseError::Syntax(position));
+                }
+                separators.push((position, integer.len()));
+            } else {
+                break;
+            }
+        }
+        let mut fraction = String::new();
+        if cursor.eat(&symbols.decimal_separator) {
+            while let Some(digit) = cursor.eat_digit(&symbols.digits) {
+                fraction.push(digit);
+            }
+            if fraction.is_empty() && (!lenient || integer.is_empty()) {
+                return Err(ParseError::Syntax(cursor.position));
+            }
+        }
+        if integer.is_empty() && (!lenient || fraction.is_empty()) {
+            return Err(ParseError::Syntax(digits_start));
+        }
+
+        if !lenient && !separators.is_empty() {
+            check_grouping(&symbols.grouping_sizes, &separators, &integer_positions)?;
+        }
+
+        let sign = match prefixed_sign {
+            Some((sign, _, suffix)) => {
+                if !cursor.eat(suffix) {
+                    return Err(ParseError::Syntax(cursor.position));
+                }
+                Some(*sign)
+            }
+            None => signs
+                .iter()
+                .find(|(_, prefix, suffix)| {
+                    prefix.is_empty() && !suffix.is_empty() && cursor.eat(suffix)
+                })
+                .map(|(sign, _, _)| *sign),
+        };
+        if !cursor.eat_end() {
+            return Err(ParseError::Syntax(cursor.position));
+        }
+
+        let mut decimal = String::with_capacity(integer.len() + fraction.len() + 3);
+        decimal.extend(sign.map(char::from));
+        if integer.is_empty() {
+            decimal.push('0');
+        }
+        decimal.push_str(&integer);
+        if !fraction.is_empty() {
+            decimal.push('.');
+            decimal.push_str(&fraction);
+        }
+        decimal.parse().map_err(|_| ParseError::Limit(digits_start))
+    }
+}
+
+/// Checks that the grouping separators are at the positions of the grouping sizes, which are
+/// the positions of [`GroupingStrategy::Always`] regardless of the minimum grouping digits.
+///
+/// `separators` has the byte offset of every separator and the number of integer digits before
+/// it, and `integer_positions` the byte offset of every integer digit.
+fn check_grouping(
+    sizes: &GroupingSizesV1,
+    separators: &[(usize, usize)],
+    integer_positions: &[usize],
+) -> Result<(), ParseError> {
+    let sizes = GroupingSizesV1 {
+        min_grouping: 1,
+        ..*sizes
+    };
+    let len = integer_positions.len();
+    let upper_magnitude = len as i16 - 1;
+    let mut separators = separators.iter().peekable();
+    for (i, &position) in integer_positions.iter().enumerate().skip(1) {
+        // The magnitude of the digit before the position of a separator.
+        let magnitude = (len - i) as i16;
+        let expected = grouper::check(upper_magnitude, magnitude, GroupingStrategy::Always, &sizes);
+        match separators.peek() {
+            Some(&&(separator, digits)) if digits == i => {
+                if !expected {
+                    return Err(ParseError::Grouping(separator));
+                }
+                separators.next();
+            }
+            _ if expected => return Err(ParseError::Grouping(position)),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// A position in the input between surrounding whitespace, if lenient.
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+    end: usize,
+    lenient: bool,
+}
+
+impl Cursor<'_> {
+    fn rest(&self) -> &str {
+        self.input.get(self.position..self.end).unwrap_or_default()
+    }
+
+    /// Skips bidi marks, if lenient.
+    fn skip_ignorable(&mut self) {
+        if self.lenient {
+            let rest = self.rest();
+            self.position += rest.len() - rest.trim_start_matches(is_bidi_mark).len();
+        }
+    }
+
+    /// Reads a string, returning whether the input continues with it. If lenient, bidi marks
+    /// are ignored and any kind of space matches any other.
+    fn eat(&mut self, expected: &str) -> bool {
+        let start = self.position;
+        for expected in expected.chars() {
+            if self.lenient && is_bidi_mark(expected) {
+                continue;
+            }
+            self.skip_ignorable();
+            match self.rest().chars().next() {
+                Some(c) if c == expected || (self.lenient && is_space(c) && is_space(expected)) => {
+                    self.position += c.len_utf8();
+                }
+                _ => {
+                    self.position = start;
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the next digit as an ASCII digit, in the numbering system or, if lenient, in
+    /// ASCII.
+    fn peek_digit(&mut self, digits: &[char; 10]) -> Option<(char, usize)> {
+        self.skip_ignorable();
+        let c = self.rest().chars().next()?;
+        let digit = match digits.iter().position(|&digit| digit == c) {
+            Some(digit) => digit as u32,
+            None if self.lenient => c.to_digit(10).filter(|_| c.is_ascii_digit())?,
+            None => return None,
+        };
+        char::from_digit(digit, 10).map(|digit| (digit, c.len_utf8()))
+    }
+
+    fn eat_digit(&mut self, digits: &[char; 10]) -> Option<char> {
+        let (digit, len) = self.peek_digit(digits)?;
+        self.position += len;
+        Some(digit)
+    }
+
+    /// Skips bidi marks, if lenient, returning whether the input ends.
+    fn eat_end(&mut self) -> bool {
+        self.skip_ignorable();
+        self.rest().is_empty()
+    }
+}
+
+fn is_bidi_mark(c: char) -> bool {
+    matches!(c, '\u{61C}' | '\u{200E}' | '\u{200F}')
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{A0}' | '\u{202F}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    fn parse(locale: Locale, mode: ParseMode, input: &str) -> Result<String, ParseError> {
+        let provider = icu_testdata::get_provider();
+        let parser =
+            FixedDecimalParser::try_new(locale, &provider, FixedDecimalParserOptions { mode })
+                .unwrap();
+        parser.parse(input).map(|value| value.to_string())
+    }
+
+    #[test]
+    fn test_strict() {
+        let strict = |locale, input| parse(locale, ParseMode::Strict, input);
+        assert_eq!(
+            strict(locale!("en"), "1,234,567.89"),
+            Ok("1234567.89".into())
+        );
+        assert_eq!(strict(locale!("en"), "1234567.89"), Ok("1234567.89".into()));
+        assert_eq!(strict(locale!("en"), "+007.50"), Ok("+007.50".into()));
+        assert_eq!(strict(locale!("en"), "-0"), Ok("-0".into()));
+        assert_eq!(strict(locale!("es"), "1.234,56"), Ok("1234.56".into()));
+        // The minimum grouping digits only affect formatting.
+        assert_eq!(strict(locale!("es"), "1.000"), Ok("1000".into()));
+        assert_eq!(strict(locale!("fr"), "1\u{202F}234,5"), Ok("1234.5".into()));
+        assert_eq!(strict(locale!("ar"), "\u{61C}-١٢٣٫٤"), Ok("-123.4".into()));
+        assert_eq!(strict(locale!("bn"), "১২,৩৪,৫৬৭"), Ok("1234567".into()));
+
+        assert_eq!(strict(locale!("en"), ""), Err(ParseError::Syntax(0)));
+        assert_eq!(strict(locale!("en"), " 1"), Err(ParseError::Syntax(0)));
+        assert_eq!(strict(locale!("en"), "1.2.3"), Err(ParseError::Syntax(3)));
+        assert_eq!(strict(locale!("en"), ".5"), Err(ParseError::Syntax(0)));
+        assert_eq!(strict(locale!("en"), "5."), Err(ParseError::Syntax(2)));
+        assert_eq!(strict(locale!("en"), "1,,000"), Err(ParseError::Syntax(1)));
+        assert_eq!(
+            strict(locale!("en"), "1,234,56"),
+            Err(ParseError::Grouping(1))
+        );
+        // A missing separator is reported at the digit after it.
+        assert_eq!(
+            strict(locale!("en"), "1234,567"),
+            Err(ParseError::Grouping(1))
+        );
+        assert_eq!(
+            strict(locale!("bn"), "১২৩,৪৫৬"),
+            Err(ParseError::Grouping(3))
+        );
+        // Only the digits of the numbering system are accepted.
+        assert_eq!(strict(locale!("ar"), "12"), Err(ParseError::Syntax(0)));
+        assert_eq!(strict(locale!("fr"), "1 234"), Err(ParseError::Syntax(1)));
+    }
+
+    #[test]
+    fn test_lenient() {
+        let lenient = |locale, input| parse(locale, ParseMode::Lenient, input);
+        assert_eq!(lenient(locale!("en"), " 1,23,4.5 "), Ok("1234.5".into()));
+        assert_eq!(lenient(locale!("en"), ".5"), Ok("0.5".into()));
+        assert_eq!(lenient(locale!("en"), "5."), Ok("5".into()));
+        assert_eq!(lenient(locale!("fr"), "-1 234,5"), Ok("-1234.5".into()));
+        assert_eq!(lenient(locale!("ar"), "-١٢٣"), Ok("-123".into()));
+        assert_eq!(lenient(locale!("ar"), "\u{200F}123٫٥"), Ok("123.5".into()));
+
+        assert_eq!(lenient(locale!("en"), "."), Err(ParseError::Syntax(1)));
+        assert_eq!(lenient(locale!("en"), "1,"), Err(ParseError::Syntax(1)));
+        assert_eq!(lenient(locale!("en"), "1 2"), Err(ParseError::Syntax(1)));
+        assert_eq!(lenient(locale!("en"), "-"), Err(ParseError::Syntax(1)));
+    }
+}
@@ -0,0 +1,506 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Percent and permille formatting, such as "12%" or "125‰".
+
+use crate::format::parts as decimal_parts;
+use crate::format::write_literal_with_minus_sign;
+use crate::options::*;
+use crate::provider::*;
+use crate::range;
+use crate::rounder;
+use crate::sign_selector::{self, SignSelection};
+use crate::{FixedDecimalFormat, FixedDecimalFormatError};
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+/// A formatter for [`FixedDecimal`] as a percentage, such as "12%", or in parts per thousand,
+/// such as "125‰".
+///
+/// The number is multiplied by 100 or 1000 and formatted with the percent pattern of the locale,
+/// which places the percent sign before or after the number, with or without a space.
+///
+/// The number keeps its fraction digits after scaling, including trailing zeros, unless digit
+/// options are set in [`PercentFormatOptions::decimal`], which apply to the scaled number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::PercentFormat;
+/// use icu::locid::locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let value = "0.12".parse().expect("valid syntax");
+///
+/// let pf = PercentFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// assert_eq!("12%", pf.format(&value).write_to_string());
+///
+/// let pf = PercentFormat::try_new(locale!("fr"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// assert_eq!("12\u{a0}%", pf.format(&value).write_to_string());
+///
+/// let pf = PercentFormat::try_new(locale!("tr"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// assert_eq!("%12", pf.format(&value).write_to_string());
+/// ```
+pub struct PercentFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    pattern: DataPayload<PercentPatternV1Marker>,
+    range_patterns: DataPayload<MiscPatternsV1Marker>,
+    options: PercentFormatOptions,
+}
+
+impl PercentFormat {
+    /// Creates a new [`PercentFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: PercentFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<PercentPatternV1Marker>
+            + ResourceProvider<MiscPatternsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let pattern = crate::load_with_numbering_system(&locale, data_provider)?;
+        let range_patterns = crate::load_with_numbering_system(&locale, data_provider)?;
+        // The sign is part of the percent pattern.
+        let fixed_decimal_format = FixedDecimalFormat::try_new(
+            locale,
+            data_provider,
+            FixedDecimalFormatOptions {
+                sign_display: SignDisplay::Never,
+                ..options.decimal
+            },
+        )?;
+        Ok(Self {
+            fixed_decimal_format,
+            pattern,
+            range_patterns,
+            options,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] as a percentage or in parts per thousand, returning a
+    /// [`FormattedPercent`].
+    ///
+    /// If the scaled number is out of the range of [`FixedDecimal`], the number is formatted
+    /// without scaling.
+    pub fn format(&self, value: &FixedDecimal) -> FormattedPercent<'_> {
+        let mut scaled = value.clone();
+        let delta = match self.options.unit {
+            PercentUnit::Percent => 2,
+            PercentUnit::Permille => 3,
+        };
+        if scaled.multiply_pow10(delta).is_err() {
+            scaled = value.clone();
+        }
+        // Scaling moves the leading zeros of a number below one into the integer digits.
+        scaled.strip_left();
+        // A number that rounds to zero is rendered with the sign of zero.
+        rounder::apply(&mut scaled, &self.options.decimal);
+        FormattedPercent {
+            formatter: self,
+            sign: sign_selector::select(scaled.signum(), self.options.decimal.sign_display),
+            value: scaled,
+        }
+    }
+
+    /// Formats the range from `start` to `end` as percentages or in parts per thousand,
+    /// returning a [`FormattedPercentRange`].
+    ///
+    /// As ECMA-402 `formatRange` does, the numbers share the percent sign if neither is rendered
+    /// with a sign and the percent sign with the text around it is longer than one code point,
+    /// such as in French, where the percent sign follows a no-break space. Otherwise both
+    /// numbers are rendered with the percent sign, set apart from the range separator with
+    /// spaces, such as in "3% – 5%". If both numbers are formatted identically, the number is
+    /// rendered as approximate, such as "~5%", as with the default [`RangeIdentityFallback`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::PercentFormat;
+    /// use icu::locid::locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let pf = PercentFormat::try_new(locale!("en"), &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// let range = |start: &str, end: &str| {
+    ///     pf.format_range(
+    ///         &start.parse().expect("valid syntax"),
+    ///         &end.parse().expect("valid syntax"),
+    ///     )
+    ///     .write_to_string()
+    ///     .into_owned()
+    /// };
+    /// assert_eq!("3% – 5%", range("0.03", "0.05"));
+    /// assert_eq!("-3% – 5%", range("-0.03", "0.05"));
+    /// assert_eq!("~5%", range("0.05", "0.05"));
+    /// ```
+    pub fn format_range(
+        &self,
+        start: &FixedDecimal,
+        end: &FixedDecimal,
+    ) -> FormattedPercentRange<'_> {
+        let start = self.format(start);
+        let end = self.format(end);
+        let identical = self.fixed_decimal_format.format(&start.value).value
+            == self.fixed_decimal_format.format(&end.value).value;
+        FormattedPercentRange {
+            start,
+            end: if identical { None } else { Some(end) },
+        }
+    }
+}
+
+/// An intermediate structure returned by [`PercentFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted percentage to a string or buffer.
+pub struct FormattedPercent<'l> {
+    formatter: &'l PercentFormat,
+    value: FixedDecimal,
+    sign: SignSelection,
+}
+
+impl<'l> FormattedPercent<'l> {
+    /// Returns the number after multiplying it by 100 or 1000 and rounding it to the digit
+    /// options, such as 12 for "12%".
+    pub fn scaled_value(&self) -> &FixedDecimal {
+        &self.value
+    }
+
+    /// Writes an affix of the percent pattern, replacing its placeholder with the percent or
+    /// permille sign, and marking the minus sign of a negative affix.
+    fn write_affix<S: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+        affix: &str,
+        minus_sign: &str,
+    ) -> fmt::Result {
+        let pattern = self.formatter.pattern.get();
+        let (sign, part) = match self.formatter.options.unit {
+            PercentUnit::Percent => (&pattern.percent_sign, parts::PERCENT_SIGN),
+            PercentUnit::Permille => (&pattern.permille_sign, parts::PERMILLE_SIGN),
+        };
+        for (i, literal) in affix.split('%').enumerate() {
+            if i > 0 {
+                sink.with_part(part, |w| w.write_str(sign))?;
+            }
+            write_literal_with_minus_sign(sink, literal, minus_sign)?;
+        }
+        Ok(())
+    }
+}
+
+/// The [`Part`](writeable::Part)s used by [`FormattedPercent`], in addition to those of
+/// [`FormattedFixedDecimal`](crate::FormattedFixedDecimal).
+///
+/// The minus sign of the percent pattern is marked with the
+/// [`MINUS_SIGN`](crate::format::parts::MINUS_SIGN) part.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::format::parts as decimal_parts;
+/// use icu::decimal::percent::parts;
+/// use icu::decimal::PercentFormat;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let pf = PercentFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let value = "-0.125".parse().unwrap();
+/// assert_writeable_parts_eq!(
+///     pf.format(&value),
+///     "-12.5%",
+///     [
+///         (0, 1, decimal_parts::MINUS_SIGN),
+///         (1, 3, decimal_parts::INTEGER),
+///         (3, 4, decimal_parts::DECIMAL),
+///         (4, 5, decimal_parts::FRACTION),
+///         (5, 6, parts::PERCENT_SIGN),
+///     ]
+/// );
+/// ```
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the percent sign, such as "%" in "12%".
+    pub const PERCENT_SIGN: Part = Part {
+        category: "percent",
+        value: "percentSign",
+    };
+
+    /// The [`Part`] used to mark the permille sign, such as "‰" in "125‰".
+    pub const PERMILLE_SIGN: Part = Part {
+        category: "percent",
+        value: "permilleSign",
+    };
+}
+
+impl<'l> FormattedPercent<'l> {
+    /// Returns the affixes of the percent pattern and the minus sign in them, if any, and the
+    /// affixes of the plus sign to put around them, if any.
+    fn affixes(&self) -> (&'l AffixesV1<'l>, &'l str, Option<&'l AffixesV1<'l>>) {
+        let symbols = self.formatter.fixed_decimal_format.symbols.get();
+        let pattern = self.formatter.pattern.get();
+        // CLDR has no pattern with a plus sign; like ICU, put the plus sign around the
+        // positive pattern.
+        match self.sign {
+            SignSelection::Minus => (&pattern.negative, &*symbols.minus_sign_affixes.prefix, None),
+            SignSelection::Neither => (&pattern.positive, "", None),
+            SignSelection::Plus => (&pattern.positive, "", Some(&symbols.plus_sign_affixes)),
+        }
+    }
+
+    /// Writes the text before the number.
+    fn write_prefix<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let (affixes, minus_sign, plus) = self.affixes();
+        if let Some(plus) = plus.filter(|plus| !plus.prefix.is_empty()) {
+            sink.with_part(decimal_parts::PLUS_SIGN, |w| w.write_str(&plus.prefix))?;
+        }
+        self.write_affix(sink, &affixes.prefix, minus_sign)
+    }
+
+    /// Writes the number without its sign.
+    fn write_number<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.formatter
+            .fixed_decimal_format
+            .format(&self.value)
+            .write_to_parts(sink)
+    }
+
+    /// Writes the text after the number.
+    fn write_suffix<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let (affixes, minus_sign, plus) = self.affixes();
+        self.write_affix(sink, &affixes.suffix, minus_sign)?;
+        if let Some(plus) = plus.filter(|plus| !plus.suffix.is_empty()) {
+            sink.with_part(decimal_parts::PLUS_SIGN, |w| w.write_str(&plus.suffix))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'l> Writeable for FormattedPercent<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.write_prefix(sink)?;
+        self.write_number(sink)?;
+        self.write_suffix(sink)
+    }
+}
+
+/// An intermediate structure returned by [`PercentFormat::format_range`].
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+pub struct FormattedPercentRange<'l> {
+    start: FormattedPercent<'l>,
+    /// The end of the range, or `None` if it is formatted identically to the start.
+    end: Option<FormattedPercent<'l>>,
+}
+
+impl<'l> Writeable for FormattedPercentRange<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let patterns = self.start.formatter.range_patterns.get();
+        let end = match &self.end {
+            Some(end) => end,
+            None => {
+                return range::write_single(
+                    sink,
+                    &patterns.approximately,
+                    Some(range::parts::APPROXIMATELY_SIGN),
+                    |sink| self.start.write_to_parts(sink),
+                )
+            }
+        };
+        let unsigned = |value: &FormattedPercent| matches!(value.sign, SignSelection::Neither);
+        let shares_affixes = || {
+            range::shares_affixes(|w| {
+                self.start.write_prefix(w)?;
+                self.start.write_suffix(w)
+            })
+        };
+        if unsigned(&self.start) && unsigned(end) && shares_affixes() {
+            // The numbers share the affixes of the unsigned pattern.
+            self.start.write_prefix(sink)?;
+            range::write_range(
+                sink,
+                &patterns.range,
+                false,
+                |sink| self.start.write_number(sink),
+                |sink| end.write_number(sink),
+            )?;
+            end.write_suffix(sink)
+        } else {
+            // The percent or other sign next to the separator could be mistaken for it.
+            range::write_range(
+                sink,
+                &patterns.range,
+                true,
+                |sink| self.start.write_to_parts(sink),
+                |sink| end.write_to_parts(sink),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::{locale, Locale};
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    fn formatter(locale: Locale, options: PercentFormatOptions) -> PercentFormat {
+        let provider = icu_testdata::get_provider();
+        PercentFormat::try_new(locale, &provider, options).unwrap()
+    }
+
+    #[test]
+    fn test_locales() {
+        for (locale, positive, negative) in [
+            (locale!("en"), "12.5%", "-12.5%"),
+            (locale!("fr"), "12,5\u{a0}%", "-12,5\u{a0}%"),
+            (locale!("tr"), "%12,5", "-%12,5"),
+            (locale!("ar"), "١٢٫٥٪\u{61c}", "\u{61c}-١٢٫٥٪\u{61c}"),
+        ] {
+            let pf = formatter(locale, Default::default());
+            let value: FixedDecimal = "0.125".parse().unwrap();
+            assert_writeable_eq!(pf.format(&value), positive);
+            let value: FixedDecimal = "-0.125".parse().unwrap();
+            assert_writeable_eq!(pf.format(&value), negative);
+        }
+    }
+
+    #[test]
+    fn test_permille() {
+        let options = PercentFormatOptions {
+            unit: PercentUnit::Permille,
+            ..Default::default()
+        };
+        let value: FixedDecimal = "-0.0125".parse().unwrap();
+        let pf = formatter(locale!("en"), options.clone());
+        assert_writeable_eq!(pf.format(&value), "-12.5‰");
+        let pf = formatter(locale!("ar"), options);
+        assert_writeable_eq!(pf.format(&value), "\u{61c}-١٢٫٥؉");
+        assert_eq!(pf.format(&value).scaled_value().to_string(), "-12.5");
+    }
+
+    #[test]
+    fn test_sign_display() {
+        let mut options = PercentFormatOptions::default();
+        options.decimal.sign_display = SignDisplay::ExceptZero;
+        let pf = formatter(locale!("en"), options.clone());
+        assert_writeable_eq!(pf.format(&FixedDecimal::from(1)), "+100%");
+        assert_writeable_eq!(pf.format(&FixedDecimal::from(0)), "0%");
+        let pf = formatter(locale!("tr"), options.clone());
+        assert_writeable_eq!(pf.format(&FixedDecimal::from(1)), "+%100");
+
+        options.decimal.maximum_fraction_digits = Some(0);
+        let value: FixedDecimal = "-0.0001".parse().unwrap();
+        let pf = formatter(locale!("en"), options.clone());
+        assert_writeable_eq!(pf.format(&value), "0%");
+        options.decimal.sign_display = SignDisplay::Negative;
+        let pf = formatter(locale!("en"), options);
+        assert_writeable_eq!(pf.format(&value), "0%");
+    }
+
+    #[test]
+    fn test_parts() {
+        let value: FixedDecimal = "-0.125".parse().unwrap();
+        let pf = formatter(locale!("tr"), Default::default());
+        assert_writeable_parts_eq!(
+            pf.format(&value),
+            "-%12,5",
+            [
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 2, parts::PERCENT_SIGN),
+                (2, 4, decimal_parts::INTEGER),
+                (4, 5, decimal_parts::DECIMAL),
+                (5, 6, decimal_parts::FRACTION),
+            ]
+        );
+
+        let mut options = PercentFormatOptions {
+            unit: PercentUnit::Permille,
+            ..Default::default()
+        };
+        options.decimal.sign_display = SignDisplay::Always;
+        let pf = formatter(locale!("fr"), options);
+        let value = FixedDecimal::from(1);
+        assert_writeable_parts_eq!(
+            pf.format(&value),
+            "+1\u{202f}000\u{a0}‰",
+            [
+                (0, 1, decimal_parts::PLUS_SIGN),
+                (1, 8, decimal_parts::INTEGER),
+                (2, 5, decimal_parts::GROUP),
+                (10, 13, parts::PERMILLE_SIGN),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let range = |pf: &PercentFormat, start: &str, end: &str| {
+            pf.format_range(&start.parse().unwrap(), &end.parse().unwrap())
+                .write_to_string()
+                .into_owned()
+        };
+        let pf = formatter(locale!("en"), Default::default());
+        assert_eq!(range(&pf, "0.03", "0.05"), "3% – 5%");
+        assert_eq!(range(&pf, "-0.05", "-0.03"), "-5% – -3%");
+        assert_eq!(range(&pf, "0.05", "0.05"), "~5%");
+        let pf = formatter(locale!("fr"), Default::default());
+        assert_eq!(range(&pf, "0.03", "0.05"), "3–5\u{a0}%");
+        let pf = formatter(locale!("tr"), Default::default());
+        assert_eq!(range(&pf, "0.03", "0.05"), "%3 – %5");
+
+        let mut options = PercentFormatOptions::default();
+        options.decimal.sign_display = SignDisplay::ExceptZero;
+        let pf = formatter(locale!("en"), options);
+        assert_eq!(range(&pf, "0", "0.05"), "0% – +5%");
+
+        let pf = formatter(locale!("en"), Default::default());
+        let (start, end) = ("0.03".parse().unwrap(), "0.05".parse().unwrap());
+        assert_writeable_parts_eq!(
+            pf.format_range(&start, &end),
+            "3% – 5%",
+            [
+                (0, 1, decimal_parts::INTEGER),
+                (1, 2, parts::PERCENT_SIGN),
+                (3, 6, range::parts::RANGE_SEPARATOR),
+                (7, 8, decimal_parts::INTEGER),
+                (8, 9, parts::PERCENT_SIGN),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        let mut options = PercentFormatOptions::default();
+        options.decimal.maximum_fraction_digits = Some(0);
+        let pf = formatter(locale!("en"), options.clone());
+        let value: FixedDecimal = "0.12345".parse().unwrap();
+        assert_writeable_eq!(pf.format(&value), "12%");
+        options.decimal.minimum_fraction_digits = Some(2);
+        options.decimal.maximum_fraction_digits = Some(2);
+        let pf = formatter(locale!("en"), options.clone());
+        assert_writeable_eq!(pf.format(&value), "12.35%");
+        assert_writeable_eq!(pf.format(&FixedDecimal::from(1)), "100.00%");
+        options.decimal.grouping_strategy = GroupingStrategy::Never;
+        let pf = formatter(locale!("en"), options);
+        assert_writeable_eq!(pf.format(&FixedDecimal::from(25)), "2500.00%");
+    }
+}
@@ -8,9 +8,15 @@
 
 // Provider structs must be stable
 #![allow(clippy::exhaustive_structs)]
+// The ULE types generated by `make_varule` slice their bytes and have undocumented fields.
+#![allow(clippy::indexing_slicing, missing_docs)] // TODO(#1668) Clippy exceptions need docs or fixing.
 
 use alloc::borrow::Cow;
-use icu_provider::{yoke, zerofrom};
+use icu_plurals::PluralCategory;
+use icu_provider::{yoke, zerofrom, DataMarker};
+use tinystr::TinyAsciiStr;
+use zerofrom::ZeroFrom;
+use zerovec::{ZeroMap, ZeroMap2d};
 
 /// A collection of strings to affix to a decimal number.
 #[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
@@ -111,3 +117,383 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// Symbols and metadata required for formatting a number in scientific notation, such as
+/// "6.022E23" or "6.022×10²³", in a locale and numbering system.
+#[icu_provider::data_struct(ScientificSymbolsV1Marker = "decimal/scientific@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ScientificSymbolsV1<'data> {
+    /// String used to separate the mantissa and the exponent, such as "E" in "6.022E23".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential_symbol: Cow<'data, str>,
+
+    /// String used to multiply the mantissa by a power of ten with a superscripted exponent,
+    /// such as "×" in "6.022×10²³".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+
+    /// The minimum number of digits of the exponent.
+    pub min_exponent_digits: u8,
+}
+
+impl Default for ScientificSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            exponential_symbol: Cow::Borrowed("E"),
+            superscripting_exponent: Cow::Borrowed("×"),
+            min_exponent_digits: 1,
+        }
+    }
+}
+
+/// Patterns for compact decimal formatting, such as "1.2K" or "3 million", for one length.
+///
+/// The patterns are keyed by the magnitude of the number to format and its plural category.
+/// A pattern applies to all numbers of at least its magnitude, up to the next magnitude in the
+/// map; numbers of lower magnitude than the first entry are not compacted.
+#[icu_provider::data_struct(
+    ShortCompactDecimalFormatDataV1Marker = "decimal/compact_short@1",
+    LongCompactDecimalFormatDataV1Marker = "decimal/compact_long@1"
+)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CompactDecimalPatternDataV1<'data> {
+    /// A map from magnitude and plural category to pattern.
+    ///
+    /// Every magnitude has a pattern for [`Count::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, i16, Count, CompactPatternULE>,
+}
+
+pub(crate) struct ErasedCompactDecimalFormatDataV1Marker;
+
+impl DataMarker for ErasedCompactDecimalFormatDataV1Marker {
+    type Yokeable = CompactDecimalPatternDataV1<'static>;
+}
+
+/// The plural category of a compact decimal pattern.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(CountULE)]
+#[repr(u8)]
+#[allow(clippy::exhaustive_enums)] // mirrors PluralCategory
+pub enum Count {
+    /// The CLDR keyword `zero`.
+    Zero = 0,
+    /// The CLDR keyword `one`.
+    One = 1,
+    /// The CLDR keyword `two`.
+    Two = 2,
+    /// The CLDR keyword `few`.
+    Few = 3,
+    /// The CLDR keyword `many`.
+    Many = 4,
+    /// The CLDR keyword `other`.
+    Other = 5,
+}
+
+impl From<PluralCategory> for Count {
+    fn from(category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::Zero => Count::Zero,
+            PluralCategory::One => Count::One,
+            PluralCategory::Two => Count::Two,
+            PluralCategory::Few => Count::Few,
+            PluralCategory::Many => Count::Many,
+            PluralCategory::Other => Count::Other,
+        }
+    }
+}
+
+/// A compact decimal pattern, such as `0K` or `00 thousand`.
+///
+/// The pattern is stored as its literal text and the position at which the number is inserted.
+#[zerovec::make_varule(CompactPatternULE)]
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_decimal::provider),
+)]
+pub struct CompactPattern<'data> {
+    /// The power of ten by which the number is divided before it is inserted into the pattern.
+    /// For example, this is 3 for `0K` and `00K`, and 0 for the pattern `0`, which does not
+    /// compact the number.
+    pub exponent: i8,
+    /// The byte index in [`CompactPattern::literal_text`] at which the number is inserted.
+    pub index: u8,
+    /// The text of the pattern, without the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub literal_text: Cow<'data, str>,
+}
+
+impl core::fmt::Debug for CompactPatternULE {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        CompactPattern::zero_from(self).fmt(f)
+    }
+}
+
+impl<'data> CompactPattern<'data> {
+    /// Splits the literal text of the pattern at the position of the number.
+    pub(crate) fn affixes(&self) -> (&str, &str) {
+        let index = self.index as usize;
+        match (
+            self.literal_text.get(..index),
+            self.literal_text.get(index..),
+        ) {
+            (Some(prefix), Some(suffix)) => (prefix, suffix),
+            _ => (&self.literal_text, ""),
+        }
+    }
+}
+
+/// The affixes of the percent pattern of a locale and numbering system for positive and
+/// negative numbers, such as the suffix `%` of the pattern `#,##0%`, and its percent and
+/// permille signs.
+///
+/// The percent or permille sign is inserted at the position of the placeholder `%` in the
+/// affixes.
+#[icu_provider::data_struct(PercentPatternV1Marker = "decimal/percent@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PercentPatternV1<'data> {
+    /// Affixes for positive numbers and zero.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub positive: AffixesV1<'data>,
+
+    /// Affixes for negative numbers, including the localized minus sign, if any.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative: AffixesV1<'data>,
+
+    /// String used to mark a number as a percentage, such as "%" in "12%".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub percent_sign: Cow<'data, str>,
+
+    /// String used to mark a number as parts per thousand, such as "‰" in "12‰".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub permille_sign: Cow<'data, str>,
+}
+
+impl Default for PercentPatternV1<'static> {
+    fn default() -> Self {
+        Self {
+            positive: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("%"),
+            },
+            negative: AffixesV1 {
+                prefix: Cow::Borrowed("-"),
+                suffix: Cow::Borrowed("%"),
+            },
+            percent_sign: Cow::Borrowed("%"),
+            permille_sign: Cow::Borrowed("‰"),
+        }
+    }
+}
+
+/// Patterns for approximate numbers and ranges of numbers in a locale and numbering system,
+/// such as `~{0}` or `{0}–{1}`, where `{0}` and `{1}` are the formatted numbers.
+#[icu_provider::data_struct(MiscPatternsV1Marker = "decimal/misc_patterns@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MiscPatternsV1<'data> {
+    /// The pattern for an approximate number, such as `~{0}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub approximately: Cow<'data, str>,
+
+    /// The pattern for a number or more, such as `{0}+`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub at_least: Cow<'data, str>,
+
+    /// The pattern for a number or less, such as `≤{0}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub at_most: Cow<'data, str>,
+
+    /// The pattern for a range from `{0}` to `{1}`, such as `{0}–{1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub range: Cow<'data, str>,
+}
+
+impl Default for MiscPatternsV1<'static> {
+    fn default() -> Self {
+        Self {
+            approximately: Cow::Borrowed("~{0}"),
+            at_least: Cow::Borrowed("≥{0}"),
+            at_most: Cow::Borrowed("≤{0}"),
+            range: Cow::Borrowed("{0}–{1}"),
+        }
+    }
+}
+
+/// The affixes of a currency pattern for positive and negative numbers, such as `¤` and `(¤`
+/// and `)` for the accounting pattern `¤#,##0.00;(¤#,##0.00)`.
+///
+/// The currency is inserted at the position of the placeholder `¤` in the affixes.
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CurrencyPatternV1<'data> {
+    /// Affixes for positive numbers and zero.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub positive: AffixesV1<'data>,
+
+    /// Affixes for negative numbers, including the localized minus sign, if any.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative: AffixesV1<'data>,
+}
+
+/// Patterns for formatting an amount of money in a locale and numbering system.
+#[icu_provider::data_struct(CurrencyPatternsV1Marker = "decimal/currency_patterns@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyPatternsV1<'data> {
+    /// The pattern for displaying the currency symbol or ISO code, such as `¤#,##0.00`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard: CurrencyPatternV1<'data>,
+
+    /// The pattern for displaying the currency symbol or ISO code in accounting, which often
+    /// puts negative amounts in parentheses, such as `¤#,##0.00;(¤#,##0.00)`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub accounting: CurrencyPatternV1<'data>,
+
+    /// String inserted between a currency and an adjacent number if the currency ends or
+    /// starts with a letter, such as the space in "USD 1.00".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub spacing: Cow<'data, str>,
+
+    /// Patterns for displaying the currency name, keyed by the plural category of the number,
+    /// such as `{0} {1}`, where `{0}` is the number and `{1}` is the name.
+    ///
+    /// There is always a pattern for [`Count::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub name_patterns: ZeroMap<'data, Count, str>,
+}
+
+/// The symbols and names of currencies in a locale, keyed by ISO 4217 code.
+///
+/// Currencies without a symbol are displayed with their ISO code, and currencies without a
+/// narrow symbol are displayed with their symbol.
+#[icu_provider::data_struct(CurrencyDisplayNamesV1Marker = "decimal/currency_names@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyDisplayNamesV1<'data> {
+    /// Map from ISO code to currency symbol, such as "$" for USD, if it differs from the code.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+
+    /// Map from ISO code to narrow currency symbol, such as "$" for CAD, if it differs from
+    /// the symbol.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow_symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+
+    /// Map from ISO code and plural category to currency name, such as "US dollars" for USD
+    /// and [`Count::Other`].
+    ///
+    /// Every currency with a name has one for [`Count::Other`], and names for other plural
+    /// categories are only present if they differ from it.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap2d<'data, TinyAsciiStr<3>, Count, str>,
+}
+
+/// The number of fraction digits and the rounding increment of a currency.
+///
+/// The rounding increment is given in units of the last fraction digit; for example, an
+/// increment of 5 with 2 digits rounds to multiples of 0.05. An increment of 0 or 1 means
+/// that there is no rounding beyond the fraction digits.
+#[zerovec::make_ule(CurrencyFractionsULE)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CurrencyFractions {
+    /// The number of fraction digits for most uses, such as 2 for USD.
+    pub digits: u8,
+    /// The rounding increment for most uses.
+    pub rounding: u8,
+    /// The number of fraction digits for cash transactions.
+    pub cash_digits: u8,
+    /// The rounding increment for cash transactions, such as 5 for CHF.
+    pub cash_rounding: u8,
+}
+
+impl Default for CurrencyFractions {
+    fn default() -> Self {
+        Self {
+            digits: 2,
+            rounding: 0,
+            cash_digits: 2,
+            cash_rounding: 0,
+        }
+    }
+}
+
+/// The fraction digits and rounding increments of currencies, keyed by ISO 4217 code.
+#[icu_provider::data_struct(CurrencyFractionsV1Marker = "decimal/currency_fractions@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyFractionsV1<'data> {
+    /// Map from ISO code to fractions, for currencies that differ from the default.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fractions: ZeroMap<'data, TinyAsciiStr<3>, CurrencyFractions>,
+
+    /// The fractions of all other currencies.
+    pub default: CurrencyFractions,
+}
@@ -0,0 +1,455 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of ranges and approximate numbers, such as "3–5" or "~5".
+
+use crate::format::FormattedFixedDecimal;
+use crate::options::*;
+use crate::provider::*;
+use crate::{FixedDecimalFormat, FixedDecimalFormatError};
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_plurals::provider::{CardinalV1Marker, PluralRangesV1Marker};
+use icu_plurals::{PluralCategory, PluralRulesWithRanges};
+use icu_provider::prelude::*;
+use writeable::{Part, PartsWrite, Writeable};
+
+/// A formatter for ranges of [`FixedDecimal`], such as "3–5", and for approximate numbers,
+/// numbers or more, and numbers or less, such as "~5", "5+", and "≤5".
+///
+/// Both numbers of a range are formatted with the same [`FixedDecimalFormatOptions`]. If they
+/// are formatted identically, the range is rendered according to the
+/// [`RangeIdentityFallback`], by default as an approximate number, as ECMA-402 `formatRange`
+/// does. If either number is rendered with a sign, the numbers are separated by spaces, such
+/// as in "-5 – -3", so that the sign is not mistaken for the range separator.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::DecimalRangeFormat;
+/// use icu::locid::locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let drf = DecimalRangeFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!("3–5", drf.format_range(&3.into(), &5.into()).write_to_string());
+/// assert_eq!("~5", drf.format_range(&5.into(), &5.into()).write_to_string());
+/// assert_eq!("-5 – -3", drf.format_range(&(-5).into(), &(-3).into()).write_to_string());
+/// assert_eq!("1,000+", drf.format_at_least(&1000.into()).write_to_string());
+///
+/// let drf = DecimalRangeFormat::try_new(locale!("ja"), &provider, Default::default())
+///     .expect("Data should load successfully");
+/// assert_eq!("約 5", drf.format_approximately(&5.into()).write_to_string());
+/// ```
+pub struct DecimalRangeFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    plural_rules: PluralRulesWithRanges,
+    patterns: DataPayload<MiscPatternsV1Marker>,
+    options: DecimalRangeFormatOptions,
+}
+
+impl DecimalRangeFormat {
+    /// Creates a new [`DecimalRangeFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: DecimalRangeFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<MiscPatternsV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ResourceProvider<PluralRangesV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let patterns = crate::load_with_numbering_system(&locale, data_provider)?;
+        let plural_rules =
+            PluralRulesWithRanges::try_new_cardinal(locale.id.clone(), data_provider)?;
+        let fixed_decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, options.decimal.clone())?;
+        Ok(Self {
+            fixed_decimal_format,
+            plural_rules,
+            patterns,
+            options,
+        })
+    }
+
+    /// Formats the range from `start` to `end`, returning a [`FormattedDecimalRange`].
+    pub fn format_range<'l>(
+        &'l self,
+        start: &'l FixedDecimal,
+        end: &'l FixedDecimal,
+    ) -> FormattedDecimalRange<'l> {
+        let start_formatted = self.fixed_decimal_format.format(start);
+        let end_formatted = self.fixed_decimal_format.format(end);
+        let patterns = self.patterns.get();
+        let kind = if start_formatted.value != end_formatted.value {
+            Kind::Range(start_formatted, end_formatted)
+        } else {
+            match self.options.identity_fallback {
+                RangeIdentityFallback::SingleValue => Kind::Single(None, start_formatted),
+                RangeIdentityFallback::ApproximatelyOrSingleValue if start == end => {
+                    Kind::Single(None, start_formatted)
+                }
+                RangeIdentityFallback::Range => Kind::Range(start_formatted, end_formatted),
+                _ => Kind::Single(
+                    Some((&*patterns.approximately, Some(parts::APPROXIMATELY_SIGN))),
+                    start_formatted,
+                ),
+            }
+        };
+        FormattedDecimalRange { patterns, kind }
+    }
+
+    /// Formats an approximate number, such as "~5", returning a [`FormattedDecimalRange`].
+    pub fn format_approximately<'l>(
+        &'l self,
+        value: &'l FixedDecimal,
+    ) -> FormattedDecimalRange<'l> {
+        self.format_single(
+            &self.patterns.get().approximately,
+            Some(parts::APPROXIMATELY_SIGN),
+            value,
+        )
+    }
+
+    /// Formats a number or more, such as "5+", returning a [`FormattedDecimalRange`].
+    pub fn format_at_least<'l>(&'l self, value: &'l FixedDecimal) -> FormattedDecimalRange<'l> {
+        self.format_single(&self.patterns.get().at_least, None, value)
+    }
+
+    /// Formats a number or less, such as "≤5", returning a [`FormattedDecimalRange`].
+    pub fn format_at_most<'l>(&'l self, value: &'l FixedDecimal) -> FormattedDecimalRange<'l> {
+        self.format_single(&self.patterns.get().at_most, None, value)
+    }
+
+    fn format_single<'l>(
+        &'l self,
+        pattern: &'l str,
+        part: Option<Part>,
+        value: &'l FixedDecimal,
+    ) -> FormattedDecimalRange<'l> {
+        FormattedDecimalRange {
+            patterns: self.patterns.get(),
+            kind: Kind::Single(
+                Some((pattern, part)),
+                self.fixed_decimal_format.format(value),
+            ),
+        }
+    }
+
+    /// Returns the plural category of the range from `start` to `end`, such as for choosing
+    /// the unit in "1–2 items".
+    ///
+    /// The category is determined from the categories of the start and the end of the range
+    /// after rounding, according to the plural ranges of the locale.
+    pub fn select(&self, start: &FixedDecimal, end: &FixedDecimal) -> PluralCategory {
+        self.plural_rules.select_range(
+            &*self.fixed_decimal_format.format(start).value,
+            &*self.fixed_decimal_format.format(end).value,
+        )
+    }
+}
+
+enum Kind<'l> {
+    /// A single number, with a pattern such as `~{0}` and the part of its text, if any.
+    Single(Option<(&'l str, Option<Part>)>, FormattedFixedDecimal<'l>),
+    /// A range of two numbers.
+    Range(FormattedFixedDecimal<'l>, FormattedFixedDecimal<'l>),
+}
+
+/// An intermediate structure returned by [`DecimalRangeFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+pub struct FormattedDecimalRange<'l> {
+    patterns: &'l MiscPatternsV1<'l>,
+    kind: Kind<'l>,
+}
+
+/// The [`Part`]s used by [`FormattedDecimalRange`], in addition to those of
+/// [`FormattedFixedDecimal`].
+///
+/// The text of the patterns for numbers or more and numbers or less, such as "+" in "5+", is
+/// not marked.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::format::parts as decimal_parts;
+/// use icu::decimal::range::parts;
+/// use icu::decimal::DecimalRangeFormat;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let drf = DecimalRangeFormat::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let (three, five) = (3.into(), 5.into());
+/// assert_writeable_parts_eq!(
+///     drf.format_range(&three, &five),
+///     "3–5",
+///     [
+///         (0, 1, decimal_parts::INTEGER),
+///         (1, 4, parts::RANGE_SEPARATOR),
+///         (4, 5, decimal_parts::INTEGER),
+///     ]
+/// );
+/// assert_writeable_parts_eq!(
+///     drf.format_approximately(&five),
+///     "~5",
+///     [(0, 1, parts::APPROXIMATELY_SIGN), (1, 2, decimal_parts::INTEGER)]
+/// );
+/// ```
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the separator between the numbers of a range, such as "–" in
+    /// "3–5". Spaces added around it to set it apart from a sign are not part of it.
+    pub const RANGE_SEPARATOR: Part = Part {
+        category: "range",
+        value: "rangeSeparator",
+    };
+
+    /// The [`Part`] used to mark the sign of an approximate number, such as "~" in "~5".
+    /// Spaces around it are not part of it.
+    pub const APPROXIMATELY_SIGN: Part = Part {
+        category: "range",
+        value: "approximatelySign",
+    };
+}
+
+/// Writes literal text of a pattern, marking the text other than the surrounding whitespace
+/// with a part, if any.
+fn write_literal<S: PartsWrite + ?Sized>(
+    sink: &mut S,
+    literal: &str,
+    part: Option<Part>,
+) -> fmt::Result {
+    let text = literal.trim();
+    match part {
+        Some(part) if !text.is_empty() => {
+            let start = literal.len() - literal.trim_start().len();
+            sink.write_str(literal.get(..start).unwrap_or_default())?;
+            sink.with_part(part, |w| w.write_str(text))?;
+            sink.write_str(literal.get(start + text.len()..).unwrap_or_default())
+        }
+        _ => sink.write_str(literal),
+    }
+}
+
+/// Returns whether the numbers of a range share the affixes written by `write_affixes`,
+/// such as "US dollars" in "3.00–5.00 US dollars", rather than repeating them, as in
+/// "$3.00 – $5.00".
+///
+/// As in the automatic collapsing of ICU, affixes are shared only if they are longer than one
+/// code point.
+pub(crate) fn shares_affixes(
+    write_affixes: impl FnOnce(&mut CodePointCounter) -> fmt::Result,
+) -> bool {
+    let mut counter = CodePointCounter(0);
+    write_affixes(&mut counter).is_ok() && counter.0 > 1
+}
+
+/// A sink that counts the code points written to it.
+pub(crate) struct CodePointCounter(usize);
+
+impl fmt::Write for CodePointCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl PartsWrite for CodePointCounter {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        _part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        f(self)
+    }
+}
+
+/// Writes a number into a pattern such as `~{0}`, marking the text of the pattern with a part,
+/// if any.
+pub(crate) fn write_single<S: PartsWrite + ?Sized>(
+    sink: &mut S,
+    pattern: &str,
+    part: Option<Part>,
+    value: impl FnOnce(&mut S) -> fmt::Result,
+) -> fmt::Result {
+    match pattern.split_once("{0}") {
+        Some((prefix, suffix)) => {
+            write_literal(sink, prefix, part)?;
+            value(sink)?;
+            write_literal(sink, suffix, part)
+        }
+        None => value(sink),
+    }
+}
+
+/// Writes two numbers into a range pattern such as `{0}–{1}`, separating them from the range
+/// separator with spaces if `spacing` is set.
+pub(crate) fn write_range<S: PartsWrite + ?Sized>(
+    sink: &mut S,
+    pattern: &str,
+    spacing: bool,
+    start: impl FnOnce(&mut S) -> fmt::Result,
+    end: impl FnOnce(&mut S) -> fmt::Result,
+) -> fmt::Result {
+    let (prefix, rest) = pattern.split_once("{0}").unwrap_or(("", "–{1}"));
+    let (separator, suffix) = rest.split_once("{1}").unwrap_or((rest, ""));
+    sink.write_str(prefix)?;
+    start(sink)?;
+    if spacing && !separator.starts_with(char::is_whitespace) {
+        sink.write_char(' ')?;
+    }
+    write_literal(sink, separator, Some(parts::RANGE_SEPARATOR))?;
+    if spacing && !separator.ends_with(char::is_whitespace) {
+        sink.write_char(' ')?;
+    }
+    end(sink)?;
+    sink.write_str(suffix)
+}
+
+impl<'l> Writeable for FormattedDecimalRange<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        match &self.kind {
+            Kind::Single(None, value) => value.write_to_parts(sink),
+            Kind::Single(Some((pattern, part)), value) => {
+                write_single(sink, pattern, *part, |sink| value.write_to_parts(sink))
+            }
+            // A sign next to the separator could be mistaken for it.
+            Kind::Range(start, end) => write_range(
+                sink,
+                &self.patterns.range,
+                start.has_sign() || end.has_sign(),
+                |sink| start.write_to_parts(sink),
+                |sink| end.write_to_parts(sink),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::parts as decimal_parts;
+    use icu_locid::locale;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    #[test]
+    fn test_range() {
+        let provider = icu_testdata::get_provider();
+        let drf =
+            DecimalRangeFormat::try_new(locale!("en"), &provider, Default::default()).unwrap();
+        let range = |start: &str, end: &str| {
+            drf.format_range(&start.parse().unwrap(), &end.parse().unwrap())
+                .write_to_string()
+                .into_owned()
+        };
+        assert_eq!(range("1000", "5000"), "1,000–5,000");
+        assert_eq!(range("-5", "3"), "-5 – 3");
+        assert_eq!(range("3", "3.0"), "3–3.0");
+        assert_eq!(range("-0", "0"), "-0 – 0");
+
+        let drf =
+            DecimalRangeFormat::try_new(locale!("es"), &provider, Default::default()).unwrap();
+        let (three, five) = (3.into(), 5.into());
+        assert_writeable_eq!(drf.format_range(&three, &five), "3-5");
+        assert_writeable_eq!(drf.format_at_least(&three), "Más de 3");
+
+        let drf =
+            DecimalRangeFormat::try_new(locale!("ar"), &provider, Default::default()).unwrap();
+        assert_writeable_eq!(drf.format_range(&three, &five), "٣–٥");
+    }
+
+    #[test]
+    fn test_parts() {
+        let provider = icu_testdata::get_provider();
+        let drf =
+            DecimalRangeFormat::try_new(locale!("en"), &provider, Default::default()).unwrap();
+        let (minus_five, three, five) = ((-5).into(), 3.into(), 5.into());
+        assert_writeable_parts_eq!(
+            drf.format_range(&minus_five, &three),
+            "-5 – 3",
+            [
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 2, decimal_parts::INTEGER),
+                (3, 6, parts::RANGE_SEPARATOR),
+                (7, 8, decimal_parts::INTEGER),
+            ]
+        );
+        assert_writeable_parts_eq!(
+            drf.format_range(&five, &five),
+            "~5",
+            [
+                (0, 1, parts::APPROXIMATELY_SIGN),
+                (1, 2, decimal_parts::INTEGER)
+            ]
+        );
+        assert_writeable_parts_eq!(
+            drf.format_at_least(&five),
+            "5+",
+            [(0, 1, decimal_parts::INTEGER)]
+        );
+
+        let drf =
+            DecimalRangeFormat::try_new(locale!("ja"), &provider, Default::default()).unwrap();
+        assert_writeable_parts_eq!(
+            drf.format_approximately(&five),
+            "約 5",
+            [
+                (0, 3, parts::APPROXIMATELY_SIGN),
+                (4, 5, decimal_parts::INTEGER)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let provider = icu_testdata::get_provider();
+        let options = DecimalRangeFormatOptions {
+            decimal: FixedDecimalFormatOptions {
+                maximum_fraction_digits: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let drf = DecimalRangeFormat::try_new(locale!("en"), &provider, options).unwrap();
+        let start = "2.96".parse().unwrap();
+        let end = "3.04".parse().unwrap();
+        assert_writeable_eq!(drf.format_range(&start, &end), "~3");
+        assert_writeable_eq!(drf.format_at_most(&end), "≤3");
+    }
+
+    #[test]
+    fn test_select() {
+        let provider = icu_testdata::get_provider();
+        let drf =
+            DecimalRangeFormat::try_new(locale!("en"), &provider, Default::default()).unwrap();
+        assert_eq!(drf.select(&1.into(), &2.into()), PluralCategory::Other);
+
+        let drf =
+            DecimalRangeFormat::try_new(locale!("ru"), &provider, Default::default()).unwrap();
+        assert_eq!(drf.select(&1.into(), &5.into()), PluralCategory::Many);
+        assert_eq!(drf.select(&1.into(), &2.into()), PluralCategory::Few);
+
+        let drf =
+            DecimalRangeFormat::try_new(locale!("ar"), &provider, Default::default()).unwrap();
+        assert_eq!(drf.select(&0.into(), &1.into()), PluralCategory::Zero);
+        assert_eq!(drf.select(&1.into(), &2.into()), PluralCategory::Other);
+        assert_eq!(drf.select(&3.into(), &11.into()), PluralCategory::Many);
+    }
+}
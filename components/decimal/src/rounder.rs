@@ -0,0 +1,416 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::*;
+use core::cmp::Ordering;
+use fixed_decimal::{FixedDecimal, Signum};
+
+/// Applies the digit options to a number: rounds it to the maximum fraction or significant
+/// digits, removes zeros beyond the minimum digits, and pads it to the minimum digits.
+pub fn apply(value: &mut FixedDecimal, options: &FixedDecimalFormatOptions) {
+    let significant = options.minimum_significant_digits.is_some()
+        || options.maximum_significant_digits.is_some();
+    let fraction =
+        options.minimum_fraction_digits.is_some() || options.maximum_fraction_digits.is_some();
+    match options.rounding_priority {
+        _ if !significant && !fraction => {}
+        RoundingPriority::Auto if significant => {
+            round_significant(value, options);
+        }
+        RoundingPriority::Auto => {
+            round_fraction(value, options);
+        }
+        priority => {
+            let mut by_fraction = value.clone();
+            let fraction_magnitude = round_fraction(&mut by_fraction, options);
+            let significant_magnitude = round_significant(value, options);
+            let prefer_significant = match priority {
+                RoundingPriority::LessPrecision => significant_magnitude > fraction_magnitude,
+                _ => significant_magnitude <= fraction_magnitude,
+            };
+            if !prefer_significant {
+                *value = by_fraction;
+            }
+        }
+    }
+
+    let min = options.minimum_integer_digits.unwrap_or(1);
+    if let Some(max) = options.maximum_integer_digits {
+        value.truncate_left(i16::from(core::cmp::max(min, max)));
+    }
+    value.pad_left(i16::from(min));
+}
+
+/// Rounds to the maximum fraction digits and pads to the minimum fraction digits, returning the
+/// magnitude of the last digit kept by the rounding, or `i16::MIN` if there is no maximum.
+fn round_fraction(value: &mut FixedDecimal, options: &FixedDecimalFormatOptions) -> i16 {
+    let min = options.minimum_fraction_digits.unwrap_or(0);
+    let magnitude = match options.maximum_fraction_digits {
+        Some(max) => {
+            let position = -i16::from(core::cmp::max(min, max));
+            round_to_increment(
+                value,
+                position,
+                options.rounding_increment,
+                options.rounding_mode,
+            );
+            position
+        }
+        None => i16::MIN,
+    };
+    value.strip_right();
+    value.pad_right(-i16::from(min));
+    magnitude
+}
+
+/// Rounds to the maximum significant digits and pads to the minimum significant digits,
+/// returning the magnitude of the last digit kept by the rounding, or `i16::MIN` if there is no
+/// maximum.
+fn round_significant(value: &mut FixedDecimal, options: &FixedDecimalFormatOptions) -> i16 {
+    let min = options.minimum_significant_digits.unwrap_or(1);
+    let magnitude = match options.maximum_significant_digits {
+        Some(max) => {
+            let max = i16::from(core::cmp::max(min, max));
+            value.round(
+                value.nonzero_magnitude_left().saturating_sub(max - 1),
+                options.rounding_mode,
+            );
+            // Rounding may carry into the next magnitude, such as 9.99 to 10.0.
+            value.nonzero_magnitude_left().saturating_sub(max - 1)
+        }
+        None => i16::MIN,
+    };
+    value.strip_right();
+    value.pad_right(
+        value
+            .nonzero_magnitude_left()
+            .saturating_sub(i16::from(min) - 1),
+    );
+    magnitude
+}
+
+/// Rounds a number to a multiple of the increment at the given position, such as to multiples
+/// of 0.05 for [`RoundingIncrement::MultiplesOf5`] at position -2.
+pub fn round_to_increment(
+    value: &mut FixedDecimal,
+    position: i16,
+    increment: RoundingIncrement,
+    mode: RoundingMode,
+) {
+    // The multiples of the increment repeat in every block of 10^width units at the position,
+    // so only the digits from the position up to the block matter.
+    let (increment, width, block_size) = match increment {
+        RoundingIncrement::MultiplesOf1 => return value.round(position, mode),
+        RoundingIncrement::MultiplesOf2 => (2, 1, 10),
+        RoundingIncrement::MultiplesOf5 => (5, 1, 10),
+        RoundingIncrement::MultiplesOf25 => (25, 2, 100),
+    };
+    let block = position.saturating_add(width);
+    let digits = (position..block)
+        .rev()
+        .fold(0u8, |acc, m| acc * 10 + value.digit_at(m));
+    let lower = digits - digits % increment;
+    let above_lower = digits % increment;
+    let exact = value.is_zero() || value.nonzero_magnitude_right() >= position;
+
+    // Compares the distance to the lower multiple with half the increment.
+    let half = if exact {
+        (2 * above_lower).cmp(&increment)
+    } else if 2 * above_lower >= increment {
+        Ordering::Greater
+    } else if 2 * above_lower + 2 <= increment {
+        Ordering::Less
+    } else {
+        // The increment is odd and the digits are half a unit below the midpoint, so the
+        // remaining digits decide.
+        match value.digit_at(position - 1).cmp(&5) {
+            Ordering::Equal if value.nonzero_magnitude_right() < position - 1 => Ordering::Greater,
+            ordering => ordering,
+        }
+    };
+
+    let negative = matches!(value.signum(), Signum::BelowZero | Signum::NegativeZero);
+    let expand = if above_lower == 0 && exact {
+        false
+    } else {
+        match (mode, half) {
+            (RoundingMode::Ceil, _) => !negative,
+            (RoundingMode::Floor, _) => negative,
+            (RoundingMode::Expand, _) => true,
+            (RoundingMode::Trunc, _) => false,
+            (_, Ordering::Less) => false,
+            (_, Ordering::Greater) => true,
+            (RoundingMode::HalfCeil, Ordering::Equal) => !negative,
+            (RoundingMode::HalfFloor, Ordering::Equal) => negative,
+            (RoundingMode::HalfExpand, Ordering::Equal) => true,
+            (RoundingMode::HalfTrunc, Ordering::Equal) => false,
+            (RoundingMode::HalfEven, Ordering::Equal) => {
+                // The parity of the lower multiple, which depends on the digit above the block
+                // if a block has an odd number of multiples.
+                let multiples_per_block = block_size / increment;
+                (lower / increment + multiples_per_block % 2 * value.digit_at(block)) % 2 == 1
+            }
+        }
+    };
+
+    let multiple = if expand { lower + increment } else { lower };
+    if multiple == block_size {
+        // The next multiple is the next unit of the block, such as 1.00 for 0.96 rounded up
+        // to multiples of 0.05.
+        value.expand(block);
+        value.pad_right(position);
+    } else {
+        value.truncate_right(block);
+        if multiple != 0 {
+            // The digits of the multiple are below the remaining digits, so this cannot fail.
+            let _ = value.concatenate_right(
+                FixedDecimal::from(multiple)
+                    .multiplied_pow10(position)
+                    .unwrap_or_default(),
+            );
+        }
+        value.pad_right(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_increment() {
+        for (value, position, increment, mode, expected) in [
+            (
+                "1.23",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "1.25",
+            ),
+            (
+                "1.225",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "1.25",
+            ),
+            (
+                "1.225",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfTrunc,
+                "1.20",
+            ),
+            (
+                "1.2249",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "1.20",
+            ),
+            (
+                "1.2251",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "1.25",
+            ),
+            (
+                "1.975",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "2.00",
+            ),
+            (
+                "1.975",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfTrunc,
+                "1.95",
+            ),
+            (
+                "1.925",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfEven,
+                "1.90",
+            ),
+            (
+                "1.975",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfEven,
+                "2.00",
+            ),
+            (
+                "-1.21",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::Ceil,
+                "-1.20",
+            ),
+            (
+                "-1.21",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::Floor,
+                "-1.25",
+            ),
+            (
+                "1.21",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::Trunc,
+                "1.20",
+            ),
+            (
+                "1.21",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::Expand,
+                "1.25",
+            ),
+            (
+                "1.2",
+                -2,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::Expand,
+                "1.20",
+            ),
+            (
+                "1.3",
+                -1,
+                RoundingIncrement::MultiplesOf2,
+                RoundingMode::HalfEven,
+                "1.2",
+            ),
+            (
+                "1.5",
+                -1,
+                RoundingIncrement::MultiplesOf2,
+                RoundingMode::HalfEven,
+                "1.6",
+            ),
+            (
+                "1.37",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfExpand,
+                "1.25",
+            ),
+            (
+                "1.38",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfExpand,
+                "1.50",
+            ),
+            (
+                "1.375",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfEven,
+                "1.50",
+            ),
+            (
+                "1.625",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfEven,
+                "1.50",
+            ),
+            (
+                "1.88",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfExpand,
+                "2.00",
+            ),
+            (
+                "0.01",
+                -2,
+                RoundingIncrement::MultiplesOf25,
+                RoundingMode::HalfExpand,
+                "0.00",
+            ),
+            (
+                "12",
+                0,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "10",
+            ),
+            (
+                "13",
+                0,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "15",
+            ),
+            (
+                "98",
+                0,
+                RoundingIncrement::MultiplesOf5,
+                RoundingMode::HalfExpand,
+                "100",
+            ),
+        ] {
+            let mut decimal: FixedDecimal = value.parse().unwrap();
+            round_to_increment(&mut decimal, position, increment, mode);
+            assert_eq!(
+                decimal.to_string(),
+                expected,
+                "{} {:?} {:?}",
+                value,
+                increment,
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_digits() {
+        let format = |value: &str, options: &FixedDecimalFormatOptions| {
+            let mut decimal: FixedDecimal = value.parse().unwrap();
+            apply(&mut decimal, options);
+            decimal.to_string()
+        };
+
+        let mut options = FixedDecimalFormatOptions {
+            maximum_significant_digits: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(format("001.500", &options), "1.5");
+        assert_eq!(format("9.996", &options), "10");
+        assert_eq!(format("123456", &options), "123000");
+        assert_eq!(format("0.00123456", &options), "0.00123");
+        options.minimum_significant_digits = Some(3);
+        assert_eq!(format("9.996", &options), "10.0");
+        assert_eq!(format("0", &options), "0.00");
+
+        let mut options = FixedDecimalFormatOptions {
+            minimum_fraction_digits: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(format("2.500", &options), "2.5");
+        assert_eq!(format("2", &options), "2.0");
+        options.maximum_fraction_digits = Some(3);
+        assert_eq!(format("2.34567", &options), "2.346");
+        // The maximum is at least the minimum.
+        options.minimum_fraction_digits = Some(4);
+        assert_eq!(format("2.34567", &options), "2.3457");
+
+        let mut options = FixedDecimalFormatOptions {
+            minimum_integer_digits: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(format("7.5", &options), "007.5");
+        options.maximum_integer_digits = Some(2);
+        assert_eq!(format("2022", &options), "022");
+        options.minimum_integer_digits = None;
+        assert_eq!(format("2022", &options), "22");
+        assert_eq!(format("2005", &options), "5");
+    }
+}
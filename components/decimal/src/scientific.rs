@@ -0,0 +1,373 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Scientific and engineering notation, such as "6.022E23" or "6.022×10²³".
+
+use crate::options::*;
+use crate::provider::*;
+use crate::{FixedDecimalFormat, FixedDecimalFormatError};
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+/// A formatter for [`FixedDecimal`] in scientific or engineering notation, such as "6.022E23"
+/// or "6.022×10²³".
+///
+/// The number is split into a mantissa, which is formatted like a number by
+/// [`FixedDecimalFormat`], and a power of ten, whose exponent is formatted as a separate
+/// [`Part`](writeable::Part). The locale's exponential symbol, superscripting symbol, and
+/// minimum number of exponent digits are taken from its [`ScientificSymbolsV1`].
+///
+/// The mantissa keeps the fraction digits of the value, including trailing zeros, while
+/// trailing zeros of an integer value are not significant. Set the digit options of the
+/// mantissa to round it, such as a maximum of 3 significant digits for "6.02E23".
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu::decimal::options;
+/// use icu::decimal::ScientificDecimalFormat;
+/// use icu::locid::locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options: options::ScientificDecimalFormatOptions = Default::default();
+/// let sdf = ScientificDecimalFormat::try_new(locale!("en"), &provider, options.clone())
+///     .expect("Data should load successfully");
+///
+/// let avogadro = FixedDecimal::from(6022).multiplied_pow10(20).unwrap();
+/// assert_eq!("6.022E23", sdf.format(&avogadro).write_to_string());
+///
+/// let planck = FixedDecimal::from(6626).multiplied_pow10(-37).unwrap();
+/// assert_eq!("6.626E-34", sdf.format(&planck).write_to_string());
+///
+/// options.exponent_style = options::ExponentStyle::Superscript;
+/// let sdf = ScientificDecimalFormat::try_new(locale!("en"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!("6.022×10²³", sdf.format(&avogadro).write_to_string());
+/// ```
+pub struct ScientificDecimalFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    symbols: DataPayload<ScientificSymbolsV1Marker>,
+    notation: ScientificNotation,
+    exponent_style: ExponentStyle,
+}
+
+impl ScientificDecimalFormat {
+    /// Creates a new [`ScientificDecimalFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system can be selected with the `-u-nu` Unicode extension keyword, as for
+    /// [`FixedDecimalFormat::try_new`].
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: ScientificDecimalFormatOptions,
+    ) -> Result<Self, FixedDecimalFormatError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<ScientificSymbolsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let symbols = crate::load_with_numbering_system(&locale, data_provider)?;
+        let fixed_decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, options.mantissa)?;
+        Ok(Self {
+            fixed_decimal_format,
+            symbols,
+            notation: options.notation,
+            exponent_style: options.exponent_style,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] in scientific notation, returning a
+    /// [`FormattedScientificDecimal`].
+    pub fn format(&self, value: &FixedDecimal) -> FormattedScientificDecimal<'_> {
+        let magnitude = value.nonzero_magnitude_left();
+        let exponent = match self.notation {
+            ScientificNotation::Scientific => magnitude,
+            ScientificNotation::Engineering => magnitude - magnitude.rem_euclid(3),
+        };
+        let mut mantissa = value.clone();
+        if mantissa.multiply_pow10(-exponent).is_err() {
+            // The fraction digits of the value cannot be shifted any further; render it
+            // without an exponent.
+            return FormattedScientificDecimal {
+                formatter: self,
+                mantissa: value.clone(),
+                exponent: 0,
+            };
+        }
+        mantissa.strip_left();
+        if *value.magnitude_range().start() == 0 {
+            mantissa.strip_right();
+        }
+
+        let options = &self.fixed_decimal_format.options;
+        let mut exponent = exponent;
+        if options.has_rounding_options() {
+            crate::rounder::apply(&mut mantissa, options);
+            // Rounding may carry into the next exponent, such as 9.99E2 to 1.0E3.
+            let step = match self.notation {
+                ScientificNotation::Scientific => 1,
+                ScientificNotation::Engineering => 3,
+            };
+            if mantissa.nonzero_magnitude_left() >= step && mantissa.multiply_pow10(-step).is_ok() {
+                exponent += step;
+                crate::rounder::apply(&mut mantissa, options);
+            }
+        }
+
+        FormattedScientificDecimal {
+            formatter: self,
+            mantissa,
+            exponent,
+        }
+    }
+}
+
+/// The [`Part`](writeable::Part)s used by [`FormattedScientificDecimal`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the mantissa, such as "6.022" in "6.022E23".
+    pub const MANTISSA: Part = Part {
+        category: "scientific",
+        value: "mantissa",
+    };
+
+    /// The [`Part`] used to mark the text between the mantissa and the exponent, such as "E"
+    /// in "6.022E23" or "×10" in "6.022×10²³".
+    pub const EXPONENT_SEPARATOR: Part = Part {
+        category: "scientific",
+        value: "exponentSeparator",
+    };
+
+    /// The [`Part`] used to mark the exponent, including its sign, such as "23" in "6.022E23".
+    pub const EXPONENT: Part = Part {
+        category: "scientific",
+        value: "exponent",
+    };
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// An intermediate structure returned by [`ScientificDecimalFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+pub struct FormattedScientificDecimal<'l> {
+    formatter: &'l ScientificDecimalFormat,
+    mantissa: FixedDecimal,
+    exponent: i16,
+}
+
+impl<'l> FormattedScientificDecimal<'l> {
+    /// Returns the mantissa, for example 6.022 for "6.022E23".
+    pub fn mantissa(&self) -> &FixedDecimal {
+        &self.mantissa
+    }
+
+    /// Returns the exponent of the power of ten, for example 23 for "6.022E23".
+    pub fn exponent(&self) -> i16 {
+        self.exponent
+    }
+
+    /// Writes the absolute value of the exponent, padded to the minimum number of digits.
+    fn write_exponent_digits<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        digits: &[char; 10],
+    ) -> fmt::Result {
+        let exponent = FixedDecimal::from(self.exponent.unsigned_abs())
+            .padded_left(i16::from(self.formatter.symbols.get().min_exponent_digits));
+        for m in exponent.magnitude_range().rev() {
+            #[allow(clippy::indexing_slicing)] // digit_at returns a digit from 0 to 9
+            sink.write_char(digits[exponent.digit_at(m) as usize])?;
+        }
+        Ok(())
+    }
+}
+
+impl<'l> Writeable for FormattedScientificDecimal<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let symbols = self.formatter.fixed_decimal_format.symbols.get();
+        let scientific_symbols = self.formatter.symbols.get();
+        sink.with_part(parts::MANTISSA, |w| {
+            self.formatter
+                .fixed_decimal_format
+                .format(&self.mantissa)
+                .write_to_parts(w)
+        })?;
+        match self.formatter.exponent_style {
+            ExponentStyle::Symbol => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&scientific_symbols.exponential_symbol)
+                })?;
+                sink.with_part(parts::EXPONENT, |w| {
+                    if self.exponent < 0 {
+                        w.write_str(&symbols.minus_sign_affixes.prefix)?;
+                    }
+                    self.write_exponent_digits(w, &symbols.digits)?;
+                    if self.exponent < 0 {
+                        w.write_str(&symbols.minus_sign_affixes.suffix)?;
+                    }
+                    Ok(())
+                })
+            }
+            ExponentStyle::Superscript => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&scientific_symbols.superscripting_exponent)?;
+                    w.write_char(symbols.digits[1])?;
+                    w.write_char(symbols.digits[0])
+                })?;
+                let (minus_sign, digits) =
+                    if symbols.digits == ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'] {
+                        ('⁻', &SUPERSCRIPT_DIGITS)
+                    } else {
+                        ('-', &symbols.digits)
+                    };
+                sink.with_part(parts::EXPONENT, |w| {
+                    if self.exponent < 0 {
+                        w.write_char(minus_sign)?;
+                    }
+                    self.write_exponent_digits(w, digits)
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::parts as decimal_parts;
+    use icu_locid::{locale, Locale};
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    fn formatter(
+        locale: Locale,
+        notation: ScientificNotation,
+        exponent_style: ExponentStyle,
+    ) -> ScientificDecimalFormat {
+        let provider = icu_testdata::get_provider();
+        let options = ScientificDecimalFormatOptions {
+            notation,
+            exponent_style,
+            ..Default::default()
+        };
+        ScientificDecimalFormat::try_new(locale, &provider, options).unwrap()
+    }
+
+    #[test]
+    fn test_notation() {
+        let scientific = formatter(
+            locale!("en"),
+            ScientificNotation::Scientific,
+            ExponentStyle::Symbol,
+        );
+        let engineering = formatter(
+            locale!("en"),
+            ScientificNotation::Engineering,
+            ExponentStyle::Symbol,
+        );
+        for (value, expected_scientific, expected_engineering) in [
+            ("0", "0E0", "0E0"),
+            ("1", "1E0", "1E0"),
+            ("12345", "1.2345E4", "12.345E3"),
+            ("120000", "1.2E5", "120E3"),
+            ("1.50", "1.50E0", "1.50E0"),
+            ("0.00123", "1.23E-3", "1.23E-3"),
+            ("0.000123", "1.23E-4", "123E-6"),
+            ("-98765", "-9.8765E4", "-98.765E3"),
+        ] {
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_writeable_eq!(scientific.format(&value), expected_scientific);
+            assert_writeable_eq!(engineering.format(&value), expected_engineering);
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        let provider = icu_testdata::get_provider();
+        for (notation, value, expected) in [
+            (ScientificNotation::Scientific, "123456", "1.23E5"),
+            (ScientificNotation::Scientific, "998.7", "9.99E2"),
+            (ScientificNotation::Scientific, "999.7", "1.00E3"),
+            (ScientificNotation::Engineering, "999600", "1.00E6"),
+            (ScientificNotation::Engineering, "0.5", "500E-3"),
+        ] {
+            let mut options = ScientificDecimalFormatOptions {
+                notation,
+                ..Default::default()
+            };
+            options.mantissa.minimum_significant_digits = Some(3);
+            options.mantissa.maximum_significant_digits = Some(3);
+            let sdf = ScientificDecimalFormat::try_new(locale!("en"), &provider, options).unwrap();
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_writeable_eq!(sdf.format(&value), expected);
+        }
+    }
+
+    #[test]
+    fn test_exponent_style() {
+        let value: FixedDecimal = "-0.000123".parse().unwrap();
+        let sdf = formatter(
+            locale!("en"),
+            ScientificNotation::Scientific,
+            ExponentStyle::Superscript,
+        );
+        assert_writeable_parts_eq!(
+            sdf.format(&value),
+            "-1.23×10⁻⁴",
+            [
+                (0, 5, parts::MANTISSA),
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 2, decimal_parts::INTEGER),
+                (2, 3, decimal_parts::DECIMAL),
+                (3, 5, decimal_parts::FRACTION),
+                (5, 9, parts::EXPONENT_SEPARATOR),
+                (9, 15, parts::EXPONENT),
+            ]
+        );
+
+        let sdf = formatter(
+            locale!("en"),
+            ScientificNotation::Scientific,
+            ExponentStyle::Symbol,
+        );
+        assert_writeable_parts_eq!(
+            sdf.format(&value),
+            "-1.23E-4",
+            [
+                (0, 5, parts::MANTISSA),
+                (0, 1, decimal_parts::MINUS_SIGN),
+                (1, 2, decimal_parts::INTEGER),
+                (2, 3, decimal_parts::DECIMAL),
+                (3, 5, decimal_parts::FRACTION),
+                (5, 6, parts::EXPONENT_SEPARATOR),
+                (6, 8, parts::EXPONENT),
+            ]
+        );
+        let formatted = sdf.format(&value);
+        assert_eq!(formatted.mantissa().to_string(), "-1.23");
+        assert_eq!(formatted.exponent(), -4);
+    }
+
+    #[test]
+    fn test_locales() {
+        let value = FixedDecimal::from(12345);
+        let ar: Locale = "ar-EG".parse().unwrap();
+        let sdf = formatter(ar, ScientificNotation::Scientific, ExponentStyle::Symbol);
+        assert_writeable_eq!(sdf.format(&value), "١٫٢٣٤٥اس٤");
+        let sdf = formatter(
+            locale!("bn"),
+            ScientificNotation::Scientific,
+            ExponentStyle::Superscript,
+        );
+        assert_writeable_eq!(sdf.format(&value), "১.২৩৪৫×১০৪");
+    }
+}
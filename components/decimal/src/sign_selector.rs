@@ -7,6 +7,7 @@
 use crate::options::SignDisplay;
 use fixed_decimal::Signum;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SignSelection {
     /// Render the minus sign.
     Minus,
@@ -43,6 +43,64 @@
 //! );
 //! ```
 //!
+//! ## Format a duration as a list of units
+//!
+//! ```
+//! use icu_list::{ListFormatter, ListStyle};
+//! use icu_locid::locale;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let list_formatter = ListFormatter::try_new_unit(locale!("en"), &provider, ListStyle::Short)
+//!     .expect("Data should load successfully");
+//! assert_eq!(
+//!     list_formatter
+//!         .format(["5 hrs", "30 min"].iter())
+//!         .write_to_string(),
+//!     "5 hrs, 30 min"
+//! );
+//!
+//! let list_formatter = ListFormatter::try_new_unit(locale!("en"), &provider, ListStyle::Narrow)
+//!     .expect("Data should load successfully");
+//! assert_eq!(
+//!     list_formatter.format(["5h", "30m"].iter()).write_to_string(),
+//!     "5h 30m"
+//! );
+//! ```
+//!
+//! ## Format values of different types
+//!
+//! With the `either` feature of `writeable`, values of two types can be combined with
+//! `either::Either` and formatted without writing them to strings first:
+//!
+//! ```
+//! use either::Either;
+//! use fixed_decimal::FixedDecimal;
+//! use icu_decimal::FixedDecimalFormat;
+//! use icu_list::{ListFormatter, ListStyle};
+//! use icu_locid::locale;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let fdf = FixedDecimalFormat::try_new(locale!("en"), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//! let list_formatter = ListFormatter::try_new_or(locale!("en"), &provider, ListStyle::Wide)
+//!     .expect("Data should load successfully");
+//!
+//! let limits = [FixedDecimal::from(1000u32), FixedDecimal::from(5000u32)];
+//! let values = [
+//!     Either::Left(fdf.format(&limits[0])),
+//!     Either::Left(fdf.format(&limits[1])),
+//!     Either::Right("unlimited"),
+//! ];
+//! assert_eq!(
+//!     list_formatter.format(values.iter()).write_to_string(),
+//!     "1,000, 5,000, or unlimited"
+//! );
+//! ```
+//!
 //! [`ListFormatter`]: ListFormatter
 
 #![cfg_attr(not(feature = "std"), no_std)]
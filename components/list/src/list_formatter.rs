@@ -261,6 +261,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_either() {
+        let formatter = formatter(ListStyle::Wide);
+        let values: [either::Either<u8, &str>; 2] =
+            [either::Either::Left(48), either::Either::Right("ten")];
+
+        assert_writeable_parts_eq!(
+            formatter.format(values.iter()),
+            "$48;ten+",
+            [
+                (0, 1, parts::LITERAL),
+                (1, 3, parts::ELEMENT),
+                (3, 4, parts::LITERAL),
+                (4, 7, parts::ELEMENT),
+                (7, 8, parts::LITERAL),
+            ]
+        );
+    }
+
     #[test]
     fn test_conditional() {
         let formatter = formatter(ListStyle::Narrow);
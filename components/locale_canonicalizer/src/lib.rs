@@ -19,6 +19,9 @@
 //! This minimize method returns a new Locale that is the result of running the
 //! 'Remove Likely Subtags' algorithm from [`UTS #35: Unicode LDML 3. Likely Subtags`].
 //!
+//! The [`LocaleMatcher`] picks the best supported locale for a list of desired locales,
+//! using the language matching data from [`CLDR`] and the likely subtags of `maximize`.
+//!
 //! # Examples
 //!
 //! ```
@@ -93,6 +96,8 @@
 extern crate alloc;
 
 pub mod locale_canonicalizer;
+pub mod locale_matcher;
 pub mod provider;
 
 pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+pub use locale_matcher::{LocaleMatch, LocaleMatcher, LocaleMatcherOptions};
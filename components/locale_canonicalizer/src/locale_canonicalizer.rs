@@ -11,6 +11,7 @@ use core::cmp::Ordering;
 use core::mem;
 use icu_locid::subtags::{Language, Region, Script};
 use icu_locid::{
+    accept_language::AcceptLanguage,
     extensions::unicode::Key,
     subtags::{Variant, Variants},
     subtags_language as language, LanguageIdentifier, Locale,
@@ -571,6 +572,34 @@ impl LocaleCanonicalizer {
         result
     }
 
+    /// Canonicalizes each locale of a parsed `Accept-Language` header with
+    /// [`LocaleCanonicalizer::canonicalize`], dropping the locales that become duplicates of
+    /// locales with a higher quality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    /// use icu_locid::accept_language::AcceptLanguage;
+    /// use icu_locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).expect("create failed");
+    ///
+    /// let mut accept_language = AcceptLanguage::parse("iw-IL, he;q=0.9, en;q=0.5");
+    /// lc.canonicalize_accept_language(&mut accept_language);
+    ///
+    /// assert_eq!(
+    ///     accept_language.locales().collect::<Vec<_>>(),
+    ///     [&locale!("he-IL"), &locale!("he"), &locale!("en")]
+    /// );
+    /// ```
+    pub fn canonicalize_accept_language(&self, accept_language: &mut AcceptLanguage) {
+        accept_language.canonicalize_with(|locale| {
+            self.canonicalize(locale);
+        });
+    }
+
     /// The maximize method potentially updates a passed in locale in place
     /// depending up the results of running the 'Add Likely Subtags' algorithm
     /// from <https://www.unicode.org/reports/tr35/#Likely_Subtags>.
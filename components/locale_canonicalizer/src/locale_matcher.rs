@@ -0,0 +1,364 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collection of code for locale matching.
+
+use crate::provider::*;
+use crate::LocaleCanonicalizer;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use icu_locid::{subtags::Variants, LanguageIdentifier};
+use icu_provider::prelude::*;
+
+/// Options for a [`LocaleMatcher`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct LocaleMatcherOptions {
+    /// Supported locales whose distance from a desired locale is this or more are
+    /// never matched to it.
+    ///
+    /// Defaults to 50, which rejects locales with a different language or script
+    /// unless the language matching data says that they are close.
+    pub threshold: u16,
+    /// The distance that is added for each desired locale before the one that is
+    /// matched, so that desired locales earlier in the list are preferred.
+    ///
+    /// Defaults to 5, which is a little more than the distance between two regions.
+    pub demotion_per_desired_locale: u16,
+}
+
+impl Default for LocaleMatcherOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 50,
+            demotion_per_desired_locale: 5,
+        }
+    }
+}
+
+/// The result of [`LocaleMatcher::best_match`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a> {
+    /// The supported locale that was chosen.
+    pub supported: &'a LanguageIdentifier,
+    /// The index of `supported` in the supported locales.
+    pub supported_index: usize,
+    /// The desired locale that `supported` was matched to.
+    pub desired: &'a LanguageIdentifier,
+    /// The index of `desired` in the desired locales.
+    pub desired_index: usize,
+    /// The distance between `desired` and `supported`, without the demotion for the
+    /// position of `desired`.
+    pub distance: u16,
+}
+
+/// A [`LocaleMatcher`] picks the supported locale that best fits a list of desired locales,
+/// such as the languages a user has chosen in their browser.
+///
+/// The distance between two locales is computed with the 'Language Matching' algorithm from
+/// [`UTS #35: Unicode LDML 4. Language Matching`]. Both locales are maximized with the likely
+/// subtags of [`LocaleCanonicalizer::maximize`], and the language, script and region subtags
+/// are then compared in turn with the rules from [`CLDR`]. Those rules consider regional
+/// groupings, such as the Americas for Spanish, and prefer paradigm locales such as `es-419`
+/// over other locales of the same language.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_canonicalizer::{LocaleMatcher, LocaleMatcherOptions};
+/// use icu_locid::langid;
+///
+/// let provider = icu_testdata::get_provider();
+/// let matcher = LocaleMatcher::try_new(&provider, LocaleMatcherOptions::default())
+///     .expect("create failed");
+///
+/// let supported = [langid!("en"), langid!("es"), langid!("es-419")];
+/// let desired = [langid!("es-MX"), langid!("en")];
+///
+/// let result = matcher
+///     .best_match(&desired, &supported)
+///     .expect("no match");
+/// assert_eq!(result.supported, &langid!("es-419"));
+/// assert_eq!(result.desired, &langid!("es-MX"));
+/// assert_eq!(result.distance, 3);
+///
+/// assert_eq!(matcher.best_match(&[langid!("ja")], &supported), None);
+/// ```
+///
+/// [`CLDR`]: http://cldr.unicode.org/
+/// [`UTS #35: Unicode LDML 4. Language Matching`]: https://www.unicode.org/reports/tr35/#LanguageMatching
+pub struct LocaleMatcher {
+    /// Data to support maximizing the locales that are compared.
+    canonicalizer: LocaleCanonicalizer,
+    /// Data to support computing distances.
+    language_matching: DataPayload<LanguageMatchingV1Marker>,
+    /// The maximized paradigm locales.
+    paradigm_locales: Vec<LanguageIdentifier>,
+    options: LocaleMatcherOptions,
+}
+
+/// Returns whether a subtag matches a subtag of a rule pattern, which may be `*`, a match
+/// variable such as `$enUS`, or a negated match variable such as `$!enUS`.
+fn subtag_matches(data: &LanguageMatchingV1, pattern: &str, subtag: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let (variable, negated) = match pattern.strip_prefix("$!") {
+        Some(variable) => (variable, true),
+        None => match pattern.strip_prefix('$') {
+            Some(variable) => (variable, false),
+            None => return pattern == subtag,
+        },
+    };
+    let contained = data
+        .match_variables
+        .get(variable)
+        .map(|regions| regions.iter().any(|region| region.as_str() == subtag))
+        .unwrap_or(false);
+    contained != negated
+}
+
+/// Returns whether the first `subtags.len()` subtags of a locale match a rule pattern
+/// with exactly that many subtags.
+fn pattern_matches(data: &LanguageMatchingV1, pattern: &str, subtags: &[&str]) -> bool {
+    let mut patterns = pattern.split('_');
+    subtags.iter().all(|subtag| {
+        patterns
+            .next()
+            .map(|pattern| subtag_matches(data, pattern, subtag))
+            .unwrap_or(false)
+    }) && patterns.next().is_none()
+}
+
+impl LocaleMatcher {
+    /// A constructor which takes a [`ResourceProvider`] and creates a [`LocaleMatcher`].
+    pub fn try_new<P>(provider: &P, options: LocaleMatcherOptions) -> Result<Self, DataError>
+    where
+        P: ResourceProvider<AliasesV1Marker>
+            + ResourceProvider<LikelySubtagsV1Marker>
+            + ResourceProvider<LanguageMatchingV1Marker>
+            + ?Sized,
+    {
+        let canonicalizer = LocaleCanonicalizer::new(provider)?;
+
+        let language_matching: DataPayload<LanguageMatchingV1Marker> = provider
+            .load_resource(&DataRequest::default())?
+            .take_payload()?;
+
+        let paradigm_locales = language_matching
+            .get()
+            .paradigm_locales
+            .iter()
+            .filter_map(|locale| locale.parse().ok())
+            .map(|mut locale: LanguageIdentifier| {
+                canonicalizer.maximize(&mut locale);
+                locale
+            })
+            .collect();
+
+        Ok(Self {
+            canonicalizer,
+            language_matching,
+            paradigm_locales,
+            options,
+        })
+    }
+
+    /// Returns the distance between a desired and a supported locale. Identical locales
+    /// have a distance of 0, and locales with unrelated languages have a distance of 80
+    /// or more.
+    ///
+    /// The distance is not necessarily symmetric: for example, a reader of Swiss German
+    /// can read German, but not the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::{LocaleMatcher, LocaleMatcherOptions};
+    /// use icu_locid::langid;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let matcher = LocaleMatcher::try_new(&provider, LocaleMatcherOptions::default())
+    ///     .expect("create failed");
+    ///
+    /// assert_eq!(matcher.distance(&langid!("en-US"), &langid!("en")), 0);
+    /// assert_eq!(matcher.distance(&langid!("en-AU"), &langid!("en-GB")), 2);
+    /// assert_eq!(matcher.distance(&langid!("gsw"), &langid!("de")), 8);
+    /// assert_eq!(matcher.distance(&langid!("de"), &langid!("gsw")), 84);
+    /// ```
+    pub fn distance(&self, desired: &LanguageIdentifier, supported: &LanguageIdentifier) -> u16 {
+        let mut desired = desired.clone();
+        self.canonicalizer.maximize(&mut desired);
+        let mut supported = supported.clone();
+        self.canonicalizer.maximize(&mut supported);
+        self.maximized_distance(&desired, &supported)
+    }
+
+    /// Returns the supported locale that best matches the desired locales, which are in
+    /// order of preference. Returns `None` if no supported locale is close enough to any
+    /// desired locale.
+    ///
+    /// Each distance is increased by the demotion for the position of its desired locale,
+    /// and the match with the lowest result is chosen. Ties go to the supported locale
+    /// that comes first.
+    pub fn best_match<'a>(
+        &self,
+        desired: impl IntoIterator<Item = &'a LanguageIdentifier>,
+        supported: &'a [LanguageIdentifier],
+    ) -> Option<LocaleMatch<'a>> {
+        let maximized_supported: Vec<LanguageIdentifier> = supported
+            .iter()
+            .map(|locale| {
+                let mut locale = locale.clone();
+                self.canonicalizer.maximize(&mut locale);
+                locale
+            })
+            .collect();
+
+        let mut best: Option<(LocaleMatch<'a>, u16)> = None;
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let demotion = self
+                .options
+                .demotion_per_desired_locale
+                .saturating_mul(u16::try_from(desired_index).unwrap_or(u16::MAX));
+            if matches!(best, Some((_, score)) if score <= demotion) {
+                break;
+            }
+            let mut maximized_desired = desired.clone();
+            self.canonicalizer.maximize(&mut maximized_desired);
+            for (supported_index, (supported, maximized_supported)) in
+                supported.iter().zip(&maximized_supported).enumerate()
+            {
+                let distance = self.maximized_distance(&maximized_desired, maximized_supported);
+                if distance >= self.options.threshold {
+                    continue;
+                }
+                let score = distance.saturating_add(demotion);
+                if matches!(best, Some((_, best_score)) if best_score <= score) {
+                    continue;
+                }
+                best = Some((
+                    LocaleMatch {
+                        supported,
+                        supported_index,
+                        desired,
+                        desired_index,
+                        distance,
+                    },
+                    score,
+                ));
+            }
+        }
+        best.map(|(result, _)| result)
+    }
+
+    fn maximized_distance(
+        &self,
+        desired: &LanguageIdentifier,
+        supported: &LanguageIdentifier,
+    ) -> u16 {
+        let desired_subtags = [
+            desired.language.as_str(),
+            desired.script.as_ref().map(|s| s.as_str()).unwrap_or(""),
+            desired.region.as_ref().map(|r| r.as_str()).unwrap_or(""),
+        ];
+        let supported_subtags = [
+            supported.language.as_str(),
+            supported.script.as_ref().map(|s| s.as_str()).unwrap_or(""),
+            supported.region.as_ref().map(|r| r.as_str()).unwrap_or(""),
+        ];
+
+        let mut distance = 0;
+        for len in 1..=desired_subtags.len() {
+            let (desired_subtags, supported_subtags) =
+                match (desired_subtags.get(..len), supported_subtags.get(..len)) {
+                    (Some(desired_subtags), Some(supported_subtags)) => {
+                        (desired_subtags, supported_subtags)
+                    }
+                    _ => break,
+                };
+            if desired_subtags.last() == supported_subtags.last() {
+                continue;
+            }
+            let mut subtag_distance = self.rule_distance(desired_subtags, supported_subtags);
+            // Paradigm locales are preferred over other regions of the same language.
+            if len == desired_subtags.len()
+                && subtag_distance > 0
+                && self.paradigm_locales.contains(supported)
+            {
+                subtag_distance -= 1;
+            }
+            distance += subtag_distance;
+        }
+        distance
+    }
+
+    /// Returns the distance of the first rule whose patterns match the subtags.
+    fn rule_distance(&self, desired_subtags: &[&str], supported_subtags: &[&str]) -> u16 {
+        let data = self.language_matching.get();
+        data.rules
+            .iter()
+            .map(zerofrom::ZeroFrom::zero_from)
+            .zip(data.distances.iter())
+            .find(|(StrStrPair(desired, supported), _)| {
+                pattern_matches(data, desired, desired_subtags)
+                    && pattern_matches(data, supported, supported_subtags)
+            })
+            .map(|(_, distance)| distance.into())
+            // The data always ends with catch-all rules, so this is not reached.
+            .unwrap_or_else(|| u8::MAX.into())
+    }
+}
+
+/// Returns the first supported locale that is found by the 'Lookup' scheme from
+/// [`RFC 4647`], or `None` if there is no such locale.
+///
+/// Each desired locale is compared with the supported locales, and then its last subtag is
+/// removed until a supported locale is equal to it. Unlike [`LocaleMatcher::best_match`],
+/// this does not use any data, so `en-US` is not found for `en`, and neither is `pt-BR` for
+/// `pt-PT`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_canonicalizer::locale_matcher::lookup;
+/// use icu_locid::langid;
+///
+/// let supported = [langid!("de"), langid!("zh-Hant"), langid!("en-US")];
+///
+/// assert_eq!(
+///     lookup(&[langid!("zh-Hant-TW"), langid!("de")], &supported),
+///     Some(&langid!("zh-Hant"))
+/// );
+/// assert_eq!(lookup(&[langid!("de-CH")], &supported), Some(&langid!("de")));
+/// assert_eq!(lookup(&[langid!("en")], &supported), None);
+/// ```
+///
+/// [`RFC 4647`]: https://www.rfc-editor.org/rfc/rfc4647#section-3.4
+pub fn lookup<'a>(
+    desired: impl IntoIterator<Item = &'a LanguageIdentifier>,
+    supported: &'a [LanguageIdentifier],
+) -> Option<&'a LanguageIdentifier> {
+    for desired in desired {
+        let mut range = desired.clone();
+        loop {
+            if let Some(found) = supported.iter().find(|&locale| *locale == range) {
+                return Some(found);
+            }
+            if !range.variants.is_empty() {
+                let mut variants = range.variants.to_vec();
+                variants.pop();
+                range.variants = Variants::from_vec_unchecked(variants);
+            } else if range.region.is_some() {
+                range.region = None;
+            } else if range.script.is_some() {
+                range.script = None;
+            } else {
+                break;
+            }
+        }
+    }
+    None
+}
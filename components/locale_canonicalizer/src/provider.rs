@@ -13,7 +13,7 @@ use alloc::borrow::Cow;
 use icu_locid::subtags::{Language, Region, Script, Variant};
 use icu_provider::prelude::*;
 use tinystr::TinyAsciiStr;
-use zerovec::{VarZeroVec, ZeroMap, ZeroSlice};
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec};
 
 // We use raw TinyAsciiStrs for map keys, as we then don't have to
 // validate them as subtags on deserialization. Map lookup can be
@@ -157,3 +157,35 @@ pub struct LikelySubtagsV1<'data> {
     /// Undefined.
     pub und: (Language, Script, Region),
 }
+
+#[icu_provider::data_struct(LanguageMatchingV1Marker = "locale_canonicalizer/matching@1")]
+#[derive(PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locale_canonicalizer::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This language matching data is used to compute the distance between two locales,
+/// based upon the rules in <https://www.unicode.org/reports/tr35/#LanguageMatching>.
+///
+/// Rules are stored as pairs of desired and supported patterns, such as `en_*_$enUS`,
+/// in the order in which they are to be tried. Rules that apply in both directions are
+/// stored once for each direction.
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatchingV1<'data> {
+    /// Locales that are preferred over other locales with the same language, such as
+    /// `es-419` over `es-MX`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub paradigm_locales: VarZeroVec<'data, UnvalidatedLanguageIdentifier>,
+    /// `[variable] -> [region]+`
+    /// The regions of each variable include all the regions they contain.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub match_variables: ZeroMap<'data, str, ZeroSlice<Region>>,
+    /// `[desired pattern, supported pattern]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: VarZeroVec<'data, UnvalidatedLanguageIdentifierPair>,
+    /// The distance of each rule in `rules`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub distances: ZeroVec<'data, u8>,
+}
@@ -10,3 +10,11 @@ pub struct CanonicalizationTest {
     pub output: String,
     pub disabled: Option<bool>,
 }
+
+#[derive(Deserialize)]
+pub struct MatchingTest {
+    pub desired: Vec<String>,
+    pub supported: Vec<String>,
+    pub output: Option<String>,
+    pub distance: Option<u16>,
+}
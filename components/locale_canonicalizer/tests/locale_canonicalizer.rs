@@ -5,8 +5,10 @@
 mod fixtures;
 mod helpers;
 
-use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
-use icu_locid::Locale;
+use icu_locale_canonicalizer::{
+    CanonicalizationResult, LocaleCanonicalizer, LocaleMatcher, LocaleMatcherOptions,
+};
+use icu_locid::{LanguageIdentifier, Locale};
 
 #[test]
 fn test_maximize() {
@@ -82,3 +84,54 @@ fn test_canonicalize() {
         }
     }
 }
+
+#[test]
+fn test_best_match() {
+    let provider = icu_testdata::get_provider();
+    let matcher = LocaleMatcher::try_new(&provider, LocaleMatcherOptions::default()).unwrap();
+
+    let path = "./tests/fixtures/matching.json";
+    let testcases: Vec<fixtures::MatchingTest> =
+        helpers::read_fixture(path).expect("Failed to read a fixture");
+
+    for case in testcases {
+        let desired: Vec<LanguageIdentifier> =
+            case.desired.iter().map(|l| l.parse().unwrap()).collect();
+        let supported: Vec<LanguageIdentifier> =
+            case.supported.iter().map(|l| l.parse().unwrap()).collect();
+        let result = matcher.best_match(&desired, &supported);
+        assert_eq!(
+            result.map(|m| m.supported.to_string()),
+            case.output,
+            "{:?}",
+            case.desired
+        );
+        assert_eq!(result.map(|m| m.distance), case.distance, "{:?}", case.desired);
+    }
+}
+
+#[test]
+fn test_best_match_options() {
+    let provider = icu_testdata::get_provider();
+    let supported: [LanguageIdentifier; 2] = ["en".parse().unwrap(), "fr".parse().unwrap()];
+    let desired: [LanguageIdentifier; 2] = ["de".parse().unwrap(), "fr".parse().unwrap()];
+
+    let mut options = LocaleMatcherOptions::default();
+    let matcher = LocaleMatcher::try_new(&provider, options).unwrap();
+    assert_eq!(
+        matcher.best_match(&desired, &supported).map(|m| m.desired_index),
+        Some(1)
+    );
+
+    // Every locale matches with a high enough threshold.
+    options.threshold = 100;
+    let matcher = LocaleMatcher::try_new(&provider, options).unwrap();
+    assert_eq!(
+        matcher.best_match(&desired, &supported).map(|m| m.desired_index),
+        Some(1)
+    );
+    options.demotion_per_desired_locale = 100;
+    let matcher = LocaleMatcher::try_new(&provider, options).unwrap();
+    let result = matcher.best_match(&desired, &supported).unwrap();
+    assert_eq!((result.desired_index, result.supported_index), (0, 0));
+}
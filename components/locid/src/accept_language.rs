@@ -0,0 +1,284 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of the HTTP `Accept-Language` header.
+//!
+//! The header lists the languages that a user prefers, each with an optional quality value
+//! (its `q` parameter), as defined by [`RFC 9110`]. [`AcceptLanguage`] parses the header
+//! into [`Locale`]s ranked by their quality.
+//!
+//! [`RFC 9110`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+
+use crate::{Locale, ParserError};
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::str::FromStr;
+use displaydoc::Display;
+
+/// The quality of an entry with no `q` parameter, which is the highest quality.
+pub const MAX_QUALITY: u16 = 1000;
+
+/// Legacy tags that are not well-formed locale identifiers, and their replacements.
+///
+/// These are the irregular and some of the regular grandfathered tags of [`RFC 5646`],
+/// with the replacements from the CLDR language aliases.
+///
+/// [`RFC 5646`]: https://www.rfc-editor.org/rfc/rfc5646#section-2.2.8
+const LEGACY_TAGS: &[(&str, &str)] = &[
+    ("en-GB-oed", "en-GB-oxendict"),
+    ("i-ami", "ami"),
+    ("i-bnn", "bnn"),
+    ("i-default", "en-x-i-default"),
+    ("i-enochian", "und-x-i-enochian"),
+    ("i-hak", "hak"),
+    ("i-klingon", "tlh"),
+    ("i-lux", "lb"),
+    ("i-mingo", "see-x-i-mingo"),
+    ("i-navajo", "nv"),
+    ("i-pwn", "pwn"),
+    ("i-tao", "tao"),
+    ("i-tay", "tay"),
+    ("i-tsu", "tsu"),
+    ("no-bok", "nb"),
+    ("no-nyn", "nn"),
+    ("sgn-BE-FR", "sfb"),
+    ("sgn-BE-NL", "vgt"),
+    ("sgn-CH-DE", "sgg"),
+    ("zh-min", "nan-x-zh-min"),
+    ("zh-min-nan", "nan"),
+];
+
+/// The errors of the entries of an `Accept-Language` header that could not be parsed.
+#[derive(Display, Debug, PartialEq, Copy, Clone)]
+#[non_exhaustive]
+pub enum AcceptLanguageError {
+    /// The language range is not a locale identifier or `*`.
+    #[displaydoc("The language range is invalid: {0}")]
+    InvalidLanguageRange(ParserError),
+    /// The quality value is not a number from 0 to 1 with at most three decimals.
+    #[displaydoc("The quality value is invalid")]
+    InvalidQuality,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AcceptLanguageError {}
+
+impl From<ParserError> for AcceptLanguageError {
+    fn from(e: ParserError) -> Self {
+        Self::InvalidLanguageRange(e)
+    }
+}
+
+/// The language range of an entry of an `Accept-Language` header.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)] // almost all ranges are locales
+pub enum LanguageRange {
+    /// A locale, such as `fr-CH`.
+    Locale(Locale),
+    /// The wildcard `*`, which stands for all the languages that are not listed.
+    Wildcard,
+}
+
+/// An entry of an `Accept-Language` header.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct AcceptLanguageEntry {
+    /// The language range of the entry.
+    pub range: LanguageRange,
+    /// The quality of the entry in thousandths, from 0 (not acceptable) to [`MAX_QUALITY`].
+    pub quality: u16,
+}
+
+/// The entries of an HTTP `Accept-Language` header, ranked by their quality.
+///
+/// Parsing is lenient: entries that cannot be parsed are reported by [`AcceptLanguage::errors`]
+/// instead of failing the whole header, locale identifiers may use underscores, and legacy
+/// tags such as `i-klingon` are replaced with their modern equivalents.
+///
+/// Entries with the same quality keep their order in the header, and entries for a locale
+/// that was already listed are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::accept_language::{AcceptLanguage, AcceptLanguageError};
+/// use icu::locid::{locale, ParserError};
+///
+/// let accept_language =
+///     AcceptLanguage::parse("en;q=0.8, fr-CH, *;q=0.5, fr;q=0.9, i-klingon;q=0.1, 42;q=0.7");
+///
+/// assert_eq!(
+///     accept_language.locales().collect::<Vec<_>>(),
+///     [
+///         &locale!("fr-CH"),
+///         &locale!("fr"),
+///         &locale!("en"),
+///         &locale!("tlh"),
+///     ]
+/// );
+/// assert_eq!(accept_language.wildcard_quality(), Some(500));
+///
+/// assert_eq!(
+///     accept_language.errors(),
+///     [(
+///         53..61,
+///         AcceptLanguageError::InvalidLanguageRange(ParserError::InvalidLanguage)
+///     )]
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct AcceptLanguage {
+    entries: Vec<AcceptLanguageEntry>,
+    errors: Vec<(Range<usize>, AcceptLanguageError)>,
+}
+
+impl AcceptLanguage {
+    /// Parses the value of an `Accept-Language` header.
+    pub fn parse(header: &str) -> Self {
+        let mut result = Self::default();
+        let mut start = 0;
+        for entry in header.split(',') {
+            let end = start + entry.len();
+            let trimmed = entry.trim();
+            if !trimmed.is_empty() {
+                match parse_entry(trimmed) {
+                    Ok(entry) => result.entries.push(entry),
+                    Err(e) => {
+                        let leading = entry.len() - entry.trim_start().len();
+                        let position = start + leading..start + leading + trimmed.len();
+                        result.errors.push((position, e));
+                    }
+                }
+            }
+            start = end + 1;
+        }
+        // The sort is stable, so entries with the same quality keep their order.
+        result.entries.sort_by(|a, b| b.quality.cmp(&a.quality));
+        result.remove_duplicates();
+        result
+    }
+
+    /// Returns the entries that could be parsed, including the wildcard and the entries
+    /// that are not acceptable, ranked by their quality.
+    pub fn entries(&self) -> &[AcceptLanguageEntry] {
+        &self.entries
+    }
+
+    /// Returns the acceptable locales, ranked by their quality.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| entry.quality > 0)
+            .filter_map(|entry| match &entry.range {
+                LanguageRange::Locale(locale) => Some(locale),
+                LanguageRange::Wildcard => None,
+            })
+    }
+
+    /// Returns the quality of the wildcard `*`, if the header has one.
+    ///
+    /// A wildcard with a quality above 0 means that any language is acceptable after the
+    /// listed ones, so callers may fall back to their default language.
+    pub fn wildcard_quality(&self) -> Option<u16> {
+        self.entries
+            .iter()
+            .find(|entry| entry.range == LanguageRange::Wildcard)
+            .map(|entry| entry.quality)
+    }
+
+    /// Returns the errors of the entries that could not be parsed, with the position of
+    /// each entry in the header.
+    pub fn errors(&self) -> &[(Range<usize>, AcceptLanguageError)] {
+        &self.errors
+    }
+
+    /// Applies `f` to each locale, for example to canonicalize it, and then drops the
+    /// entries for locales that were already listed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::accept_language::AcceptLanguage;
+    /// use icu::locid::locale;
+    ///
+    /// let mut accept_language = AcceptLanguage::parse("en-US, en-GB;q=0.9, en;q=0.8");
+    /// accept_language.canonicalize_with(|locale| locale.id.region = None);
+    ///
+    /// assert_eq!(
+    ///     accept_language.locales().collect::<Vec<_>>(),
+    ///     [&locale!("en")]
+    /// );
+    /// ```
+    pub fn canonicalize_with(&mut self, mut f: impl FnMut(&mut Locale)) {
+        for entry in self.entries.iter_mut() {
+            if let LanguageRange::Locale(locale) = &mut entry.range {
+                f(locale);
+            }
+        }
+        self.remove_duplicates();
+    }
+
+    /// Drops the entries whose range is the same as that of an earlier entry.
+    fn remove_duplicates(&mut self) {
+        let mut i = 0;
+        while i < self.entries.len() {
+            let (seen, rest) = self.entries.split_at(i);
+            let duplicate = rest
+                .first()
+                .map(|entry| seen.iter().any(|e| e.range == entry.range))
+                .unwrap_or(false);
+            if duplicate {
+                self.entries.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+fn parse_entry(entry: &str) -> Result<AcceptLanguageEntry, AcceptLanguageError> {
+    let mut parts = entry.split(';');
+    let range = parts.next().unwrap_or_default().trim();
+    let mut quality = MAX_QUALITY;
+    for parameter in parts {
+        if let Some((name, value)) = parameter.split_once('=') {
+            if name.trim().eq_ignore_ascii_case("q") {
+                quality = parse_quality(value.trim()).ok_or(AcceptLanguageError::InvalidQuality)?;
+            }
+        }
+    }
+    let range = if range == "*" {
+        LanguageRange::Wildcard
+    } else {
+        let range = LEGACY_TAGS
+            .iter()
+            .find(|(legacy, _)| legacy.eq_ignore_ascii_case(&range.replace('_', "-")))
+            .map(|&(_, replacement)| replacement)
+            .unwrap_or(range);
+        LanguageRange::Locale(Locale::from_str(range)?)
+    };
+    Ok(AcceptLanguageEntry { range, quality })
+}
+
+/// Parses a quality value such as `0.8` into thousandths.
+fn parse_quality(value: &str) -> Option<u16> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let quality = match integer {
+        "0" => 0,
+        "1" => MAX_QUALITY,
+        _ => return None,
+    };
+    let mut thousandths: u16 = 0;
+    for (b, scale) in fraction.bytes().zip([100, 10, 1]) {
+        thousandths += u16::from(b - b'0') * scale;
+    }
+    if quality == MAX_QUALITY && thousandths > 0 {
+        return None;
+    }
+    Some(quality + thousandths)
+}
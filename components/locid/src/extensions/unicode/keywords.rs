@@ -147,6 +147,28 @@ impl Keywords {
         self.0.get(key)
     }
 
+    /// Returns an iterator over the keys and values, ordered by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-nu-latn-ca-buddhist".parse().expect("Parsing failed.");
+    ///
+    /// let keywords: Vec<String> = loc
+    ///     .extensions
+    ///     .unicode
+    ///     .keywords
+    ///     .iter()
+    ///     .map(|(key, value)| format!("{}={}", key, value))
+    ///     .collect();
+    /// assert_eq!(keywords, ["ca=buddhist", "nu=latn"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Value)> + '_ {
+        self.0.iter()
+    }
+
     /// Returns a mutable reference to the [`Value`] corresponding to the [`Key`].
     ///
     /// Returns `None` if the key doesn't exist or if the key has no value.
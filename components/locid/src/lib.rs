@@ -79,6 +79,7 @@ extern crate alloc;
 #[macro_use]
 mod helpers;
 
+pub mod accept_language;
 #[cfg(feature = "databake")]
 mod databake;
 pub mod extensions;
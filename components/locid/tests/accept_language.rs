@@ -0,0 +1,79 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid::accept_language::{AcceptLanguage, AcceptLanguageError, LanguageRange};
+use icu_locid::Locale;
+
+fn locales(accept_language: &AcceptLanguage) -> Vec<String> {
+    accept_language.locales().map(Locale::to_string).collect()
+}
+
+#[test]
+fn test_ranking() {
+    let accept_language = AcceptLanguage::parse("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+    assert_eq!(locales(&accept_language), ["fr-CH", "fr", "en"]);
+    assert_eq!(accept_language.wildcard_quality(), Some(500));
+    assert!(accept_language.errors().is_empty());
+
+    let qualities: Vec<u16> = accept_language
+        .entries()
+        .iter()
+        .map(|entry| entry.quality)
+        .collect();
+    assert_eq!(qualities, [1000, 900, 800, 500]);
+    assert_eq!(
+        accept_language.entries().last().map(|entry| &entry.range),
+        Some(&LanguageRange::Wildcard)
+    );
+
+    // Entries with the same quality keep their order, and duplicates are dropped.
+    let accept_language = AcceptLanguage::parse("de;q=0.5, en;q=0.5, fr, de;q=0.9, en");
+    assert_eq!(locales(&accept_language), ["fr", "en", "de"]);
+
+    // Entries with a quality of 0 are not acceptable.
+    let accept_language = AcceptLanguage::parse("en, de;q=0, *;q=0");
+    assert_eq!(locales(&accept_language), ["en"]);
+    assert_eq!(accept_language.entries().len(), 3);
+    assert_eq!(accept_language.wildcard_quality(), Some(0));
+}
+
+#[test]
+fn test_leniency() {
+    let accept_language = AcceptLanguage::parse(
+        " en_us ; Q=0.7 ,,I-KLINGON;q=0.25, zh-min-nan;level=1, sgn-CH-DE;q=0.",
+    );
+    assert_eq!(locales(&accept_language), ["nan", "en-US", "tlh"]);
+    assert_eq!(accept_language.wildcard_quality(), None);
+    assert_eq!(accept_language.entries().len(), 4);
+    assert!(accept_language.errors().is_empty());
+}
+
+#[test]
+fn test_errors() {
+    let header = "en-US, x;q=0.5, fr;q=2, de;q=0.1234, it;q=abc, *;q=0.1";
+    let accept_language = AcceptLanguage::parse(header);
+    assert_eq!(locales(&accept_language), ["en-US"]);
+    assert_eq!(accept_language.wildcard_quality(), Some(100));
+
+    let errors: Vec<(&str, AcceptLanguageError)> = accept_language
+        .errors()
+        .iter()
+        .map(|(position, error)| (&header[position.clone()], *error))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (
+                "x;q=0.5",
+                AcceptLanguageError::InvalidLanguageRange(icu_locid::ParserError::InvalidLanguage)
+            ),
+            ("fr;q=2", AcceptLanguageError::InvalidQuality),
+            ("de;q=0.1234", AcceptLanguageError::InvalidQuality),
+            ("it;q=abc", AcceptLanguageError::InvalidQuality),
+        ]
+    );
+
+    let accept_language = AcceptLanguage::parse("");
+    assert_eq!(accept_language, AcceptLanguage::default());
+}
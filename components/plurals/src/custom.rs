@@ -0,0 +1,73 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compilation of plural rule strings supplied at runtime.
+
+use crate::provider::PluralRulesV1;
+use crate::rules::reference::{self, ast};
+use crate::samples;
+use crate::{PluralCategory, PluralRules, PluralRulesError};
+use alloc::vec::Vec;
+
+/// Parses the rule strings, compiles their conditions into a [`PluralRulesV1`], and returns
+/// the parsed rules for checking their samples.
+pub(crate) fn compile<'s>(
+    rules: impl IntoIterator<Item = (PluralCategory, &'s str)>,
+) -> Result<(PluralRulesV1<'static>, Vec<(PluralCategory, ast::Rule)>), PluralRulesError> {
+    let mut data = PluralRulesV1::default();
+    let mut parsed: Vec<(PluralCategory, ast::Rule)> = Vec::new();
+    for (category, rule) in rules {
+        if parsed.iter().any(|(c, _)| *c == category) {
+            return Err(PluralRulesError::InvalidRule(category));
+        }
+        let rule = reference::parse(rule.as_bytes())?;
+        let slot = match category {
+            PluralCategory::Zero => Some(&mut data.zero),
+            PluralCategory::One => Some(&mut data.one),
+            PluralCategory::Two => Some(&mut data.two),
+            PluralCategory::Few => Some(&mut data.few),
+            PluralCategory::Many => Some(&mut data.many),
+            PluralCategory::Other => {
+                // "other" applies to any number that matches no other rule.
+                if !rule.condition.0.is_empty() {
+                    return Err(PluralRulesError::InvalidRule(category));
+                }
+                None
+            }
+        };
+        if let Some(slot) = slot {
+            if rule.condition.0.is_empty() {
+                return Err(PluralRulesError::InvalidRule(category));
+            }
+            *slot = Some((&rule).into());
+        }
+        parsed.push((category, rule));
+    }
+    Ok((data, parsed))
+}
+
+/// Checks that the `@integer` and `@decimal` samples of each rule select the category of the
+/// rule.
+pub(crate) fn check_samples(
+    plural_rules: &PluralRules,
+    rules: &[(PluralCategory, ast::Rule)],
+) -> Result<(), PluralRulesError> {
+    for (category, rule) in rules {
+        let samples = match &rule.samples {
+            Some(samples) => samples,
+            None => continue,
+        };
+        for range in samples::ranges(samples) {
+            let range = samples::SampleRange::try_from_ast(range)
+                .ok_or(PluralRulesError::InvalidSample(*category))?;
+            if range
+                .values()
+                .any(|value| plural_rules.select(&value) != *category)
+            {
+                return Err(PluralRulesError::SampleMismatch(*category));
+            }
+        }
+    }
+    Ok(())
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::rules::reference::parser::ParserError;
+use crate::PluralCategory;
 use displaydoc::Display;
 use icu_provider::prelude::DataError;
 
@@ -16,6 +17,16 @@ pub enum PluralRulesError {
     /// An error originating from [`icu_provider`].
     #[displaydoc("Data provider error: {0}")]
     DataProvider(DataError),
+    /// A rule string was given twice for a category, or has a condition that is invalid for
+    /// its category.
+    #[displaydoc("Invalid rule for plural category {0:?}")]
+    InvalidRule(PluralCategory),
+    /// A sample of a rule string is not a valid number or range of numbers.
+    #[displaydoc("Invalid sample for plural category {0:?}")]
+    InvalidSample(PluralCategory),
+    /// A sample of a rule string does not select the category of the rule.
+    #[displaydoc("Sample does not match plural category {0:?}")]
+    SampleMismatch(PluralCategory),
 }
 
 #[cfg(feature = "std")]
@@ -57,6 +57,11 @@
 //! * [`Cardinal`](PluralRuleType::Cardinal): `3 doors`, `1 month`, `10 dollars`
 //! * [`Ordinal`](PluralRuleType::Ordinal): `1st place`, `10th day`, `11th floor`
 //!
+//! ## Plural Ranges
+//!
+//! The category of a range of numbers, such as `1–2 days`, depends on the categories of its
+//! start and its end. [`PluralRulesWithRanges`] selects it for cardinal numbers.
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`Plural Type`]: PluralRuleType
 //! [`Plural Category`]: PluralCategory
@@ -80,10 +85,13 @@
 
 extern crate alloc;
 
+mod custom;
 mod error;
 mod operands;
 pub mod provider;
+mod ranges;
 pub mod rules;
+mod samples;
 
 use core::cmp::{Ord, PartialOrd};
 pub use error::PluralRulesError;
@@ -93,6 +101,7 @@ pub use operands::PluralOperands;
 use provider::CardinalV1Marker;
 use provider::ErasedPluralRulesV1Marker;
 use provider::OrdinalV1Marker;
+pub use ranges::PluralRulesWithRanges;
 use rules::runtime::test_rule;
 
 /// A type of a plural rule which can be associated with the [`PluralRules`] struct.
@@ -148,8 +157,14 @@ pub enum PluralRuleType {
 /// assert_eq!(pr.select(5_usize), PluralCategory::Other);
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
-#[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals),
+)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(PluralCategoryULE)]
+#[repr(u8)]
 #[allow(clippy::exhaustive_enums)] // this type is mostly stable. new categories may potentially be added in the future,
                                    // but at a cadence slower than the ICU4X release cycle
 pub enum PluralCategory {
@@ -159,7 +174,7 @@ pub enum PluralCategory {
     ///
     /// - 0 in Arabic (ar), Latvian (lv)
     /// - 10~20, 30, 40, 50, ... in Latvian (lv)
-    Zero,
+    Zero = 0,
     /// CLDR "one" plural category. Signifies the singular form in many languages.
     ///
     /// Examples of numbers having this category:
@@ -168,14 +183,14 @@ pub enum PluralCategory {
     /// - 1 in English (en) and most other languages
     /// - 2.1 in Filipino (fil), Croatian (hr), Latvian (lv), Serbian (sr)
     /// - 2, 3, 5, 7, 8, ... in Filipino (fil)
-    One,
+    One = 1,
     /// CLDR "two" plural category. Used in Arabic, Hebrew, and Slovenian, among others.
     ///
     /// Examples of numbers having this category:
     ///
     /// - 2 in Arabic (ar), Hebrew (iw), Slovenian (sl)
     /// - 2.0 in Arabic (ar)
-    Two,
+    Two = 2,
     /// CLDR "few" plural category. Used in Romanian, Polish, Russian, and others.
     ///
     /// Examples of numbers having this category:
@@ -184,7 +199,7 @@ pub enum PluralCategory {
     /// - 1.2 in Croatian (hr), Romanian (ro), Slovenian (sl), Serbian (sr)
     /// - 2 in Polish (pl), Russian (ru), Czech (cs), ...
     /// - 5 in Arabic (ar), Lithuanian (lt), Romanian (ro)
-    Few,
+    Few = 3,
     /// CLDR "many" plural category. Used in Polish, Russian, Ukrainian, and others.
     ///
     /// Examples of numbers having this category:
@@ -193,7 +208,7 @@ pub enum PluralCategory {
     /// - 1.0 in Czech (cs), Slovak (sk)
     /// - 1.1 in Czech (cs), Lithuanian (lt), Slovak (sk)
     /// - 15 in Arabic (ar), Polish (pl), Russian (ru), Ukrainian (uk)
-    Many,
+    Many = 4,
     /// CLDR "other" plural category, used as a catch-all. Each language supports it, and it
     /// is also used as a fail safe result for in case no better match can be identified.
     ///
@@ -205,7 +220,7 @@ pub enum PluralCategory {
     /// - 0 in English (en), German (de), Spanish (es), ...
     /// - 1 in Japanese (ja), Korean (ko), Chinese (zh), Thai (th), ...
     /// - 2 in English (en), German (de), Spanish (es), ...
-    Other,
+    Other = 5,
 }
 
 impl PluralCategory {
@@ -281,6 +296,9 @@ impl PluralCategory {
 pub struct PluralRules {
     _locale: Locale,
     rules: DataPayload<ErasedPluralRulesV1Marker>,
+    /// The parsed samples of the rules of each category.
+    #[cfg(feature = "samples")]
+    samples: alloc::vec::Vec<(PluralCategory, samples::SampleRange)>,
 }
 
 impl PluralRules {
@@ -407,6 +425,187 @@ impl PluralRules {
         Self::new(locale, rules)
     }
 
+    /// Constructs a new `PluralRules` from rule strings in the
+    /// [UTS 35 syntax](https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax),
+    /// such as those of the CLDR `plurals.json` file, instead of from a data provider.
+    ///
+    /// Each rule string is given along with the category it selects. The rule for
+    /// [`PluralCategory::Other`] has no condition, since it matches any number not matched by
+    /// another rule, but it may have samples like the other rules.
+    ///
+    /// The `@integer` and `@decimal` samples of each rule are checked to select the category
+    /// of the rule; for sample ranges such as `0.0~1.5`, the first 1000 values and the upper
+    /// bound are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::{PluralCategory, PluralRules, PluralRulesError};
+    ///
+    /// let rules = PluralRules::try_new_from_rule_strings([
+    ///     (PluralCategory::One, "i = 1 and v = 0 @integer 1"),
+    ///     (PluralCategory::Other, " @integer 0, 2~16, 100 @decimal 0.0~1.5"),
+    /// ])
+    /// .expect("The rules and their samples should be valid");
+    ///
+    /// assert_eq!(rules.select(1_usize), PluralCategory::One);
+    /// assert_eq!(rules.select(2_usize), PluralCategory::Other);
+    ///
+    /// // 1.0 has a visible fraction digit, so it does not match the rule for "one".
+    /// assert!(matches!(
+    ///     PluralRules::try_new_from_rule_strings([(PluralCategory::One, "i = 1 and v = 0 @decimal 1.0")]),
+    ///     Err(PluralRulesError::SampleMismatch(PluralCategory::One))
+    /// ));
+    /// ```
+    pub fn try_new_from_rule_strings<'s, I>(rules: I) -> Result<Self, PluralRulesError>
+    where
+        I: IntoIterator<Item = (PluralCategory, &'s str)>,
+    {
+        let (data, parsed) = custom::compile(rules)?;
+        let plural_rules = Self::new(Locale::UND, DataPayload::from_owned(data))?;
+        custom::check_samples(&plural_rules, &parsed)?;
+        #[cfg(feature = "samples")]
+        let plural_rules = Self {
+            samples: samples::parse_all(
+                parsed
+                    .iter()
+                    .filter_map(|(category, rule)| Some((*category, rule.samples.as_ref()?))),
+            ),
+            ..plural_rules
+        };
+        Ok(plural_rules)
+    }
+
+    /// Constructs a new `PluralRules` for a given locale and [`type`], along with the samples
+    /// of its rules, which are returned by [`PluralRules::samples()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRuleType, PluralRules};
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new_with_samples(locale!("en"), &dp, PluralRuleType::Ordinal)
+    ///     .expect("Data should be present");
+    ///
+    /// let samples: Vec<String> = pr
+    ///     .samples(PluralCategory::Two)
+    ///     .take(3)
+    ///     .map(|n| n.to_string())
+    ///     .collect();
+    /// assert_eq!(samples, ["2", "22", "32"]);
+    /// ```
+    ///
+    /// [`type`]: PluralRuleType
+    #[cfg(feature = "samples")]
+    pub fn try_new_with_samples<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        rule_type: PluralRuleType,
+    ) -> Result<Self, PluralRulesError>
+    where
+        D: ResourceProvider<CardinalV1Marker>
+            + ResourceProvider<OrdinalV1Marker>
+            + ResourceProvider<provider::CardinalSamplesV1Marker>
+            + ResourceProvider<provider::OrdinalSamplesV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let request = DataRequest {
+            options: locale.clone().into(),
+            metadata: Default::default(),
+        };
+        let data: DataPayload<provider::ErasedPluralSamplesV1Marker> = match rule_type {
+            PluralRuleType::Cardinal => {
+                ResourceProvider::<provider::CardinalSamplesV1Marker>::load_resource(
+                    data_provider,
+                    &request,
+                )?
+                .take_payload()?
+                .cast()
+            }
+            PluralRuleType::Ordinal => {
+                ResourceProvider::<provider::OrdinalSamplesV1Marker>::load_resource(
+                    data_provider,
+                    &request,
+                )?
+                .take_payload()?
+                .cast()
+            }
+        };
+        // The samples are parsed once here rather than on every call to `samples()`.
+        let parsed = data
+            .get()
+            .samples
+            .iter()
+            .filter_map(|(category, samples)| {
+                Some((
+                    <PluralCategory as zerovec::ule::AsULE>::from_unaligned(*category),
+                    rules::reference::parse(samples.as_bytes()).ok()?.samples?,
+                ))
+            })
+            .collect::<alloc::vec::Vec<_>>();
+        let plural_rules = Self::try_new(locale, data_provider, rule_type)?;
+        Ok(Self {
+            samples: samples::parse_all(
+                parsed
+                    .iter()
+                    .map(|(category, samples)| (*category, samples)),
+            ),
+            ..plural_rules
+        })
+    }
+
+    /// Returns example numbers of the given [`Plural Category`], from the `@integer` and
+    /// `@decimal` samples of its rule, such as 0, 2, 3, …, 16, 100, 0.0, 0.1, …, 1.5 for
+    /// [`PluralCategory::Other`] in English.
+    ///
+    /// Sample ranges, such as `0.0~1.5`, are expanded into their values, up to 1000 values and
+    /// the upper bound for each range. Samples with a compact exponent, such as `1.2c6`, are
+    /// skipped, since a [`FixedDecimal`](fixed_decimal::FixedDecimal) does not carry the
+    /// exponent that their category depends on.
+    ///
+    /// Samples are only available for a `PluralRules` constructed with
+    /// [`PluralRules::try_new_with_samples()`] or
+    /// [`PluralRules::try_new_from_rule_strings()`]; otherwise, no samples are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRuleType, PluralRules};
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new_with_samples(locale!("ru"), &dp, PluralRuleType::Cardinal)
+    ///     .expect("Data should be present");
+    ///
+    /// // "1 день", "2 дня", "5 дней", "1,5 дня"
+    /// for category in pr.categories() {
+    ///     for sample in pr.samples(category) {
+    ///         assert_eq!(pr.select(&sample), category);
+    ///     }
+    /// }
+    /// assert_eq!(pr.samples(PluralCategory::Many).next(), Some(0.into()));
+    /// assert_eq!(pr.samples(PluralCategory::Other).next(), Some("0.0".parse().unwrap()));
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    #[cfg(feature = "samples")]
+    pub fn samples(
+        &self,
+        category: PluralCategory,
+    ) -> impl Iterator<Item = fixed_decimal::FixedDecimal> + '_ {
+        self.samples
+            .iter()
+            .filter(move |(c, _)| *c == category)
+            .flat_map(|(_, range)| range.values())
+            .filter(|value| value.exponent() == 0)
+            .map(fixed_decimal::CompactDecimal::into_significand)
+    }
+
     /// Returns the [`Plural Category`] appropriate for the given number.
     ///
     /// # Examples
@@ -538,6 +737,8 @@ impl PluralRules {
         Ok(Self {
             _locale: locale,
             rules,
+            #[cfg(feature = "samples")]
+            samples: alloc::vec::Vec::new(),
         })
     }
 }
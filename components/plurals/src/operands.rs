@@ -7,7 +7,7 @@ use core::isize;
 use core::num::ParseIntError;
 use core::str::FromStr;
 use displaydoc::Display;
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 
 /// A full plural operands representation of a number. See [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules) for complete operands description.
 /// Plural operands in compliance with [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules).
@@ -21,6 +21,7 @@ use fixed_decimal::FixedDecimal;
 /// - Integers, signed and unsigned
 /// - Strings representing an arbitrary-precision decimal
 /// - [`FixedDecimal`]
+/// - [`CompactDecimal`], which sets the `c` operand
 ///
 /// This crate does not support selection from a floating-point number, because floats are not
 /// capable of carrying trailing zeros, which are required for proper plural rule selection. For
@@ -82,6 +83,26 @@ use fixed_decimal::FixedDecimal;
 ///         .map(|d| (&d).into())
 /// )
 /// ```
+///
+/// From [`CompactDecimal`]
+///
+/// ```
+/// use fixed_decimal::CompactDecimal;
+/// use icu::plurals::PluralOperands;
+/// assert_eq!(
+///     Ok(PluralOperands {
+///         i: 1200000,
+///         v: 0,
+///         w: 0,
+///         f: 0,
+///         t: 0,
+///         c: 6,
+///     }),
+///     "1.2c6"
+///         .parse::<CompactDecimal>()
+///         .map(|d| (&d).into())
+/// )
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[allow(clippy::exhaustive_structs)] // mostly stable, new operands may be added at the cadence of ICU's release cycle
 pub struct PluralOperands {
@@ -295,3 +316,22 @@ impl From<&FixedDecimal> for PluralOperands {
         }
     }
 }
+
+impl From<&CompactDecimal> for PluralOperands {
+    /// Converts a [`fixed_decimal::CompactDecimal`] to [`PluralOperands`]. The operands other
+    /// than `c` are those of the full value, so 1.2c6 has the same `i` as 1200000.
+    ///
+    /// A negative exponent, as in scientific notation of a number below one, such as 1.2c-3,
+    /// leaves `c` at zero, since the operand is unsigned.
+    fn from(compact: &CompactDecimal) -> Self {
+        // Values beyond the bounds of a FixedDecimal keep only the digits of the significand,
+        // just like the operands of a FixedDecimal keep at most 18 integer digits.
+        let value = compact
+            .to_fixed_decimal()
+            .unwrap_or_else(|_| compact.significand().clone());
+        Self {
+            c: usize::try_from(compact.exponent()).unwrap_or(0),
+            ..Self::from(&value)
+        }
+    }
+}
@@ -10,11 +10,11 @@
 //! Read more about data providers: [`icu_provider`]
 
 use crate::rules::runtime::ast::Rule;
+use crate::PluralCategory;
 use icu_provider::DataMarker;
 use icu_provider::{yoke, zerofrom};
-
-#[cfg(doc)]
-use crate::PluralCategory;
+use zerovec::ZeroMap;
+use zerovec::ZeroMap2d;
 
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
 /// standard plural forms. If none of the rules match, the "other" category is assumed.
@@ -49,8 +49,59 @@ pub struct PluralRulesV1<'data> {
     pub many: Option<Rule<'data>>,
 }
 
+/// Plural categories of ranges of numbers, such as "1–2 items", conforming to UTS 35.
+///
+/// Only the ranges whose category differs from the category of their end are stored, since
+/// the category of any other range is that of its end.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+#[icu_provider::data_struct(PluralRangesV1Marker = "plurals/ranges@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct PluralRangesV1<'data> {
+    /// The category of a range, keyed by the categories of its start and its end.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ranges: ZeroMap2d<'data, PluralCategory, PluralCategory, PluralCategory>,
+}
+
 pub(crate) struct ErasedPluralRulesV1Marker;
 
 impl DataMarker for ErasedPluralRulesV1Marker {
     type Yokeable = PluralRulesV1<'static>;
 }
+
+/// The samples of plural rules, which are example numbers of each category, such as
+/// `@integer 2~16, 100 @decimal 0.0~1.5`.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Samples>
+#[icu_provider::data_struct(
+    CardinalSamplesV1Marker = "plurals/cardinal_samples@1",
+    OrdinalSamplesV1Marker = "plurals/ordinal_samples@1"
+)]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct PluralSamplesV1<'data> {
+    /// The samples of each category in the UTS 35 syntax, without the rule.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub samples: ZeroMap<'data, PluralCategory, str>,
+}
+
+#[cfg(feature = "samples")]
+pub(crate) struct ErasedPluralSamplesV1Marker;
+
+#[cfg(feature = "samples")]
+impl DataMarker for ErasedPluralSamplesV1Marker {
+    type Yokeable = PluralSamplesV1<'static>;
+}
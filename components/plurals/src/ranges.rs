@@ -0,0 +1,135 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Plural categories of ranges of numbers, such as "1–2 items".
+
+use crate::provider::{CardinalV1Marker, PluralRangesV1Marker};
+use crate::{PluralCategory, PluralOperands, PluralRules, PluralRulesError};
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+
+/// A struct which provides an ability to retrieve an appropriate [`Plural Category`] for a
+/// range of numbers, such as "1–2 items", as ECMA-402 `selectRange` does.
+///
+/// The category of a range is determined from the categories of its start and its end
+/// according to the [CLDR plural ranges](https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges)
+/// of the locale. Plural ranges are only defined for cardinal numbers.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::locale;
+/// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let pr = PluralRulesWithRanges::try_new_cardinal(locale!("ru"), &provider)
+///     .expect("Data should be present");
+///
+/// // "1–2 дня"
+/// assert_eq!(pr.select_range(1_usize, 2_usize), PluralCategory::Few);
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+pub struct PluralRulesWithRanges {
+    rules: PluralRules,
+    ranges: DataPayload<PluralRangesV1Marker>,
+}
+
+impl PluralRulesWithRanges {
+    /// Constructs a new `PluralRulesWithRanges` for a given locale for cardinal numbers.
+    ///
+    /// This constructor will fail if the [`Data Provider`] does not have the plural rules or
+    /// the plural ranges for the locale.
+    ///
+    /// [`Data Provider`]: icu_provider
+    pub fn try_new_cardinal<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+    ) -> Result<Self, PluralRulesError>
+    where
+        D: ResourceProvider<CardinalV1Marker> + ResourceProvider<PluralRangesV1Marker> + ?Sized,
+    {
+        let locale = locale.into();
+        let ranges = data_provider
+            .load_resource(&DataRequest {
+                options: locale.clone().into(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let rules = PluralRules::try_new_cardinal(locale, data_provider)?;
+        Ok(Self { rules, ranges })
+    }
+
+    /// Returns the [`PluralRules`] used to select the categories of the start and the end of
+    /// a range.
+    pub fn rules(&self) -> &PluralRules {
+        &self.rules
+    }
+
+    /// Returns the [`Plural Category`] appropriate for the range from `start` to `end`.
+    ///
+    /// Both numbers should be formatted as they are displayed, for example rounded, since the
+    /// category of each of them depends on its visible fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRulesWithRanges::try_new_cardinal(locale!("en"), &provider)
+    ///     .expect("Data should be present");
+    ///
+    /// // "1–2 items"
+    /// assert_eq!(pr.select_range(1_usize, 2_usize), PluralCategory::Other);
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn select_range<S, E>(&self, start: S, end: E) -> PluralCategory
+    where
+        S: Into<PluralOperands>,
+        E: Into<PluralOperands>,
+    {
+        self.resolve_range(self.rules.select(start), self.rules.select(end))
+    }
+
+    /// Returns the [`Plural Category`] of a range whose start and end have the categories
+    /// `start` and `end`.
+    ///
+    /// The category of the range is that of its end, unless the plural ranges of the locale
+    /// specify otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRulesWithRanges::try_new_cardinal(locale!("ar"), &provider)
+    ///     .expect("Data should be present");
+    ///
+    /// assert_eq!(
+    ///     pr.resolve_range(PluralCategory::Zero, PluralCategory::One),
+    ///     PluralCategory::Zero
+    /// );
+    /// assert_eq!(
+    ///     pr.resolve_range(PluralCategory::Few, PluralCategory::Many),
+    ///     PluralCategory::Many
+    /// );
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn resolve_range(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        self.ranges
+            .get()
+            .ranges
+            .get_copied(&start, &end)
+            .unwrap_or(end)
+    }
+}
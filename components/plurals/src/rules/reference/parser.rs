@@ -304,8 +304,16 @@ impl<'p> Parser<'p> {
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
+        // The exponent of samples is written with `c`, or with `e` before CLDR 38.
+        let exponent = if self.take_if(Token::E) {
+            Some('e')
+        } else if self.take_if(Token::Operand(ast::Operand::C)) {
+            Some('c')
+        } else {
+            None
+        };
+        if let Some(exponent) = exponent {
+            s.push(exponent);
             match self.lexer.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
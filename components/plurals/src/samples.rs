@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Expansion of the samples of plural rules into values.
+
+use crate::rules::reference::ast;
+#[cfg(feature = "samples")]
+use crate::PluralCategory;
+use alloc::string::String;
+use core::convert::TryFrom;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
+
+/// The maximum number of values of a sample range, such as `0.0~1.5`, that are expanded.
+pub(crate) const MAX_RANGE_SAMPLES: usize = 1000;
+
+/// A parsed sample of a plural rule: a single value, such as `1.5` or `1.2c6`, or a range of
+/// values with the same number of fraction digits, such as `0.0~1.5`.
+#[derive(Debug, Clone)]
+pub(crate) enum SampleRange {
+    /// A single value.
+    Single(CompactDecimal),
+    /// The values from `lower` to `upper`, both multiplied by ten to the power of
+    /// `fraction_digits`.
+    Range {
+        lower: u64,
+        upper: u64,
+        fraction_digits: i16,
+    },
+}
+
+impl SampleRange {
+    /// Parses a sample range of a rule, returning `None` if it is invalid.
+    pub(crate) fn try_from_ast(range: &ast::SampleRange) -> Option<Self> {
+        let upper = match &range.upper_val {
+            None => return parse_value(&range.lower_val.0).map(Self::Single),
+            Some(upper) => &upper.0,
+        };
+        let lower = &range.lower_val.0;
+        let fraction_digits = |s: &str| s.find('.').map_or(0, |i| s.len() - i - 1);
+        let scaled = |s: &str| s.replace('.', "").parse::<u64>().ok();
+        let digits = fraction_digits(lower);
+        if digits != fraction_digits(upper) {
+            return None;
+        }
+        let (lower, upper) = (scaled(lower)?, scaled(upper)?);
+        if lower > upper {
+            return None;
+        }
+        Some(Self::Range {
+            lower,
+            upper,
+            fraction_digits: i16::try_from(digits).ok()?,
+        })
+    }
+
+    /// Returns the values of the sample: the value itself for a single sample, and the values
+    /// from the lower to the upper bound for a range. At most [`MAX_RANGE_SAMPLES`] values of a
+    /// range are returned, followed by its upper bound if it was not reached.
+    pub(crate) fn values(&self) -> impl Iterator<Item = CompactDecimal> + '_ {
+        let (single, range) = match self {
+            Self::Single(value) => (Some(value.clone()), None),
+            Self::Range {
+                lower,
+                upper,
+                fraction_digits,
+            } => {
+                let truncated = upper - lower >= MAX_RANGE_SAMPLES as u64;
+                let values = (*lower..=*upper)
+                    .take(MAX_RANGE_SAMPLES)
+                    .chain(truncated.then(|| *upper))
+                    .filter_map(move |value| {
+                        FixedDecimal::from(value)
+                            .multiplied_pow10(-*fraction_digits)
+                            .ok()
+                            .map(CompactDecimal::from)
+                    });
+                (None, Some(values))
+            }
+        };
+        single.into_iter().chain(range.into_iter().flatten())
+    }
+}
+
+/// Returns the `@integer` and `@decimal` sample ranges of a rule.
+pub(crate) fn ranges(samples: &ast::Samples) -> impl Iterator<Item = &ast::SampleRange> {
+    samples
+        .integer
+        .iter()
+        .chain(samples.decimal.iter())
+        .flat_map(|list| list.sample_ranges.iter())
+}
+
+/// Parses the samples of rules, skipping invalid sample ranges.
+#[cfg(feature = "samples")]
+pub(crate) fn parse_all<'a>(
+    rules: impl IntoIterator<Item = (PluralCategory, &'a ast::Samples)>,
+) -> alloc::vec::Vec<(PluralCategory, SampleRange)> {
+    rules
+        .into_iter()
+        .flat_map(|(category, samples)| {
+            ranges(samples)
+                .filter_map(SampleRange::try_from_ast)
+                .map(move |range| (category, range))
+        })
+        .collect()
+}
+
+/// Parses a single sample value, such as `1.5` or `1.2c6`.
+fn parse_value(value: &str) -> Option<CompactDecimal> {
+    // The exponent of samples was written with `e` before CLDR 38, but CompactDecimal only
+    // accepts `c`.
+    let value: String = value
+        .chars()
+        .map(|c| if c == 'e' { 'c' } else { c })
+        .collect();
+    value.parse().ok()
+}
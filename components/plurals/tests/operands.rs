@@ -7,7 +7,7 @@ mod helpers;
 
 use std::convert::TryInto;
 
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 use icu_plurals::PluralOperands;
 
 #[test]
@@ -68,3 +68,22 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+fn test_from_compact_decimals() {
+    for (input, i, v, f, c) in [
+        ("1.2c6", 1_200_000, 0, 0, 6),
+        ("1c3", 1000, 0, 0, 3),
+        ("1.0000001c6", 1_000_000, 1, 1, 6),
+        ("2.50", 2, 2, 50, 0),
+    ] {
+        let compact: CompactDecimal = input.parse().expect("Failed to parse a compact decimal");
+        let operands = PluralOperands::from(&compact);
+        assert_eq!(
+            (operands.i, operands.v, operands.f, operands.c),
+            (i, v, f, c),
+            "{}",
+            input
+        );
+    }
+}
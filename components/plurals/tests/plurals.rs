@@ -2,8 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use fixed_decimal::CompactDecimal;
 use icu_locid::locale;
-use icu_plurals::{provider::CardinalV1Marker, PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::{
+    provider::CardinalV1Marker, PluralCategory, PluralRuleType, PluralRules, PluralRulesError,
+};
 use icu_provider::prelude::*;
 
 #[test]
@@ -15,6 +18,127 @@ fn test_plural_rules() {
     assert_eq!(pr.select(5_usize), PluralCategory::Other);
 }
 
+#[test]
+fn test_plural_rules_compact_exponent() {
+    let provider = icu_testdata::get_provider();
+
+    let pr = PluralRules::try_new(locale!("fr"), &provider, PluralRuleType::Cardinal).unwrap();
+
+    let compact: CompactDecimal = "1.2c6".parse().unwrap();
+    assert_eq!(pr.select(&compact), PluralCategory::Many);
+    let compact: CompactDecimal = "1.2c3".parse().unwrap();
+    assert_eq!(pr.select(&compact), PluralCategory::Other);
+    assert_eq!(pr.select(&compact.into_significand()), PluralCategory::One);
+}
+
+#[test]
+fn test_plural_rules_from_rule_strings() {
+    let provider = icu_testdata::get_provider();
+
+    // The rules of CLDR 41, whose samples use the `c` exponent.
+    let fr = PluralRules::try_new_from_rule_strings([
+        (
+            PluralCategory::One,
+            "i = 0,1 @integer 0, 1 @decimal 0.0~1.5",
+        ),
+        (
+            PluralCategory::Many,
+            "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 \
+             @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … \
+             @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …",
+        ),
+        (
+            PluralCategory::Other,
+            " @integer 2~17, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … \
+             @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, \
+             1.0001c3, 1.1c3, 2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+        ),
+    ])
+    .expect("CLDR rules should be valid");
+    let fr_data = PluralRules::try_new(locale!("fr"), &provider, PluralRuleType::Cardinal).unwrap();
+    for n in [0_usize, 1, 2, 1_000_000, 2_000_000, 2_000_001] {
+        assert_eq!(fr.select(n), fr_data.select(n), "{}", n);
+    }
+    let compact: CompactDecimal = "1.2c6".parse().unwrap();
+    assert_eq!(fr.select(&compact), PluralCategory::Many);
+
+    // Rules without samples are not checked.
+    let rules = PluralRules::try_new_from_rule_strings([(PluralCategory::Two, "n = 2")]).unwrap();
+    assert_eq!(rules.select(2_usize), PluralCategory::Two);
+    assert_eq!(rules.select(1_usize), PluralCategory::Other);
+}
+
+#[test]
+#[cfg(feature = "samples")]
+fn test_plural_rules_samples() {
+    let provider = icu_testdata::get_provider();
+
+    let fr = PluralRules::try_new_with_samples(locale!("fr"), &provider, PluralRuleType::Cardinal)
+        .unwrap();
+    let samples: Vec<String> = fr
+        .samples(PluralCategory::Many)
+        .map(|n| n.to_string())
+        .collect();
+    assert_eq!(samples, ["1000000"]);
+    assert_eq!(fr.samples(PluralCategory::One).count(), 2 + 16);
+    assert_eq!(fr.samples(PluralCategory::Two).count(), 0);
+
+    let rules = PluralRules::try_new_from_rule_strings([
+        (PluralCategory::One, "n = 1 @integer 1 @decimal 1.0, 1.00"),
+        (PluralCategory::Other, " @integer 0, 2~4"),
+    ])
+    .unwrap();
+    let samples: Vec<String> = rules
+        .samples(PluralCategory::One)
+        .map(|n| n.to_string())
+        .collect();
+    assert_eq!(samples, ["1", "1.0", "1.00"]);
+    assert_eq!(rules.samples(PluralCategory::Other).count(), 4);
+
+    let without_samples =
+        PluralRules::try_new(locale!("fr"), &provider, PluralRuleType::Cardinal).unwrap();
+    assert_eq!(without_samples.samples(PluralCategory::One).count(), 0);
+}
+
+#[test]
+fn test_plural_rules_from_invalid_rule_strings() {
+    let error = |rules: &[(PluralCategory, &str)]| {
+        PluralRules::try_new_from_rule_strings(rules.iter().copied())
+            .err()
+            .expect("The rules should be invalid")
+    };
+    assert!(matches!(
+        error(&[(PluralCategory::One, "i = ")]),
+        PluralRulesError::Parser(_)
+    ));
+    assert!(matches!(
+        error(&[
+            (PluralCategory::One, "i = 1"),
+            (PluralCategory::One, "i = 2")
+        ]),
+        PluralRulesError::InvalidRule(PluralCategory::One)
+    ));
+    assert!(matches!(
+        error(&[(PluralCategory::Other, "i = 1")]),
+        PluralRulesError::InvalidRule(PluralCategory::Other)
+    ));
+    assert!(matches!(
+        error(&[(PluralCategory::Few, " @integer 3")]),
+        PluralRulesError::InvalidRule(PluralCategory::Few)
+    ));
+    assert!(matches!(
+        error(&[(PluralCategory::One, "i = 1 @decimal 1.0~1.05")]),
+        PluralRulesError::InvalidSample(PluralCategory::One)
+    ));
+    assert!(matches!(
+        error(&[
+            (PluralCategory::One, "i = 1 @integer 1"),
+            (PluralCategory::Other, " @integer 0~2"),
+        ]),
+        PluralRulesError::SampleMismatch(PluralCategory::Other)
+    ));
+}
+
 #[test]
 fn test_static_load_works() {
     let provider = icu_testdata::get_provider();
@@ -0,0 +1,418 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Display names of languages, scripts, regions, variants and whole locales.
+
+use crate::options::{DisplayNamesOptions, LanguageDisplay, Style};
+use crate::provider::*;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_locid::subtags::{Language, Region, Script, Variant};
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_provider::prelude::*;
+
+/// Display names of languages, such as "German" for `de`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::LanguageDisplayNames;
+/// use icu_locid::{locale, subtags_language as language};
+///
+/// let provider = icu_testdata::get_provider();
+/// let names = LanguageDisplayNames::try_new(locale!("fr"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(names.of(language!("de")), Some("allemand"));
+/// assert_eq!(names.of(language!("xyz")), None);
+/// ```
+pub struct LanguageDisplayNames {
+    options: DisplayNamesOptions,
+    data: DataPayload<LanguageDisplayNamesV1Marker>,
+}
+
+impl LanguageDisplayNames {
+    /// Creates a new [`LanguageDisplayNames`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ResourceProvider<LanguageDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            data: load(&locale.into(), data_provider)?,
+        })
+    }
+
+    /// Returns the display name of a language, or `None` if the locale has no name for it.
+    pub fn of(&self, language: Language) -> Option<&str> {
+        self.data.get().get(language.as_str(), self.options.style)
+    }
+}
+
+/// Display names of scripts, such as "Cyrillic" for `Cyrl`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::ScriptDisplayNames;
+/// use icu_locid::{locale, subtags_script as script};
+///
+/// let provider = icu_testdata::get_provider();
+/// let names = ScriptDisplayNames::try_new(locale!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(names.of(script!("Cyrl")), Some("Cyrillic"));
+/// assert_eq!(names.of(script!("Hant")), Some("Traditional Han"));
+/// ```
+pub struct ScriptDisplayNames {
+    options: DisplayNamesOptions,
+    data: DataPayload<ScriptDisplayNamesV1Marker>,
+}
+
+impl ScriptDisplayNames {
+    /// Creates a new [`ScriptDisplayNames`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ResourceProvider<ScriptDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            data: load(&locale.into(), data_provider)?,
+        })
+    }
+
+    /// Returns the display name of a script on its own, or `None` if the locale has no name
+    /// for it.
+    ///
+    /// This is the stand-alone name of the script if it has one, such as "Traditional Han",
+    /// rather than "Traditional" as in "Chinese (Traditional)".
+    pub fn of(&self, script: Script) -> Option<&str> {
+        let data = self.data.get();
+        match self.options.style {
+            Style::Short => data.short_names.get(&script),
+            _ => None,
+        }
+        .or_else(|| data.stand_alone_names.get(&script))
+        .or_else(|| data.names.get(&script))
+    }
+}
+
+/// Display names of regions, such as "Switzerland" for `CH`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::RegionDisplayNames;
+/// use icu_locid::{locale, subtags_region as region};
+///
+/// let provider = icu_testdata::get_provider();
+/// let names = RegionDisplayNames::try_new(locale!("ja"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(names.of(region!("CH")), Some("スイス"));
+/// ```
+pub struct RegionDisplayNames {
+    options: DisplayNamesOptions,
+    data: DataPayload<RegionDisplayNamesV1Marker>,
+}
+
+impl RegionDisplayNames {
+    /// Creates a new [`RegionDisplayNames`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ResourceProvider<RegionDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            data: load(&locale.into(), data_provider)?,
+        })
+    }
+
+    /// Returns the display name of a region, or `None` if the locale has no name for it.
+    pub fn of(&self, region: Region) -> Option<&str> {
+        self.data.get().get(&region, self.options.style)
+    }
+}
+
+/// Display names of variants, such as "Valencian" for `valencia`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::VariantDisplayNames;
+/// use icu_locid::{locale, subtags_variant as variant};
+///
+/// let provider = icu_testdata::get_provider();
+/// let names = VariantDisplayNames::try_new(locale!("en"), &provider)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(names.of(variant!("valencia")), Some("Valencian"));
+/// ```
+pub struct VariantDisplayNames {
+    data: DataPayload<VariantDisplayNamesV1Marker>,
+}
+
+impl VariantDisplayNames {
+    /// Creates a new [`VariantDisplayNames`] from locale data.
+    pub fn try_new<T: Into<Locale>, D>(locale: T, data_provider: &D) -> Result<Self, DataError>
+    where
+        D: ResourceProvider<VariantDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            data: load(&locale.into(), data_provider)?,
+        })
+    }
+
+    /// Returns the display name of a variant, or `None` if the locale has no name for it.
+    pub fn of(&self, variant: Variant) -> Option<&str> {
+        self.data.get().names.get(&variant)
+    }
+}
+
+/// Display names of locales, such as "Chinese (Traditional, Taiwan)" for `zh-Hant-TW`.
+///
+/// The names are composed following the
+/// [locale display name algorithm](https://unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm)
+/// of UTS 35: the language name is followed by the names of the script, region, variants and
+/// Unicode extension keywords as qualifiers. Locales that have a name of their own, such as
+/// "British English" for `en-GB`, are displayed with it, unless
+/// [`LanguageDisplay::Standard`] is requested. Subtags without a name are displayed with
+/// their code.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+/// let formatter =
+///     LocaleDisplayNamesFormatter::try_new(locale!("en"), &provider, Default::default())
+///         .expect("Data should load successfully");
+///
+/// assert_eq!(formatter.of(&locale!("de-CH")), "Swiss High German");
+/// assert_eq!(formatter.of(&locale!("de-DE")), "German (Germany)");
+/// assert_eq!(
+///     formatter.of(&locale!("zh-Hant-TW")),
+///     "Traditional Chinese (Taiwan)"
+/// );
+/// assert_eq!(
+///     formatter.of(&"de-u-ca-gregory-nu-arab".parse().unwrap()),
+///     "German (Gregorian Calendar, Arabic-Indic Digits)"
+/// );
+///
+/// let formatter =
+///     LocaleDisplayNamesFormatter::try_new(locale!("fr"), &provider, Default::default())
+///         .expect("Data should load successfully");
+///
+/// assert_eq!(formatter.of(&locale!("de-CH")), "allemand suisse");
+/// assert_eq!(formatter.of(&locale!("zh-TW")), "chinois (Taïwan)");
+/// ```
+pub struct LocaleDisplayNamesFormatter {
+    options: DisplayNamesOptions,
+    language_data: DataPayload<LanguageDisplayNamesV1Marker>,
+    script_data: DataPayload<ScriptDisplayNamesV1Marker>,
+    region_data: DataPayload<RegionDisplayNamesV1Marker>,
+    variant_data: DataPayload<VariantDisplayNamesV1Marker>,
+    locale_data: DataPayload<LocaleDisplayNamesV1Marker>,
+}
+
+impl LocaleDisplayNamesFormatter {
+    /// Creates a new [`LocaleDisplayNamesFormatter`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ResourceProvider<LanguageDisplayNamesV1Marker>
+            + ResourceProvider<ScriptDisplayNamesV1Marker>
+            + ResourceProvider<RegionDisplayNamesV1Marker>
+            + ResourceProvider<VariantDisplayNamesV1Marker>
+            + ResourceProvider<LocaleDisplayNamesV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        Ok(Self {
+            options,
+            language_data: load(&locale, data_provider)?,
+            script_data: load(&locale, data_provider)?,
+            region_data: load(&locale, data_provider)?,
+            variant_data: load(&locale, data_provider)?,
+            locale_data: load(&locale, data_provider)?,
+        })
+    }
+
+    /// Returns the display name of a locale.
+    ///
+    /// Transform and private use extensions are not displayed.
+    pub fn of(&self, locale: &Locale) -> String {
+        let id = &locale.id;
+        let style = self.options.style;
+        let locale_data = self.locale_data.get();
+
+        let (name, show_script, show_region) = self.dialect_name(id).unwrap_or_else(|| {
+            let name = self.language_data.get().get(id.language.as_str(), style);
+            (name.unwrap_or_else(|| id.language.as_str()), true, true)
+        });
+
+        let mut qualifiers: Vec<Cow<str>> = Vec::new();
+        if let Some(script) = id.script.as_ref().filter(|_| show_script) {
+            let script_data = self.script_data.get();
+            let script_name = match style {
+                Style::Short => script_data.short_names.get(script),
+                _ => None,
+            }
+            .or_else(|| script_data.names.get(script));
+            qualifiers.push(qualifier(script_name.unwrap_or_else(|| script.as_str())));
+        }
+        if let Some(region) = id.region.as_ref().filter(|_| show_region) {
+            let region_name = self.region_data.get().get(region, style);
+            qualifiers.push(qualifier(region_name.unwrap_or_else(|| region.as_str())));
+        }
+        for variant in id.variants.iter() {
+            let variant_name = self.variant_data.get().names.get(variant);
+            qualifiers.push(qualifier(variant_name.unwrap_or_else(|| variant.as_str())));
+        }
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            let value = value.to_string();
+            let keyword = [key.as_str(), &value].join("-");
+            qualifiers.push(match locale_data.types.get(&keyword) {
+                Some(type_name) => qualifier(type_name),
+                None => {
+                    let key_name = locale_data.keys.get(key.as_str());
+                    let key_name = qualifier(key_name.unwrap_or_else(|| key.as_str()));
+                    Cow::Owned(substitute(&locale_data.key_type_pattern, &key_name, &value))
+                }
+            });
+        }
+
+        let mut qualifiers = qualifiers.into_iter();
+        match qualifiers.next() {
+            Some(first) => {
+                let qualifiers = qualifiers.fold(first, |joined, next| {
+                    Cow::Owned(substitute(&locale_data.separator, &joined, &next))
+                });
+                substitute(&locale_data.pattern, name, &qualifiers)
+            }
+            None => name.to_string(),
+        }
+    }
+
+    /// Returns the name of the longest prefix of the language identifier with a script or a
+    /// region that has a name of its own, and whether the script and the region still have to
+    /// be displayed as qualifiers.
+    fn dialect_name(&self, id: &LanguageIdentifier) -> Option<(&str, bool, bool)> {
+        if self.options.language_display != LanguageDisplay::Dialect {
+            return None;
+        }
+        [(id.script, id.region), (id.script, None), (None, id.region)]
+            .iter()
+            .filter(|(script, region)| script.is_some() || region.is_some())
+            .find_map(|&(script, region)| {
+                let dialect = LanguageIdentifier {
+                    language: id.language,
+                    script,
+                    region,
+                    variants: Default::default(),
+                };
+                self.language_data
+                    .get()
+                    .get(&dialect.to_string(), self.options.style)
+                    .map(|name| (name, script.is_none(), region.is_none()))
+            })
+    }
+}
+
+impl LanguageDisplayNamesV1<'_> {
+    /// Returns the name of a language or locale in a style, falling back to the standard
+    /// name.
+    fn get(&self, id: &str, style: Style) -> Option<&str> {
+        match style {
+            Style::Standard => None,
+            Style::Short => self.short_names.get(id),
+            Style::Long => self.long_names.get(id),
+            Style::Menu => self.menu_names.get(id),
+        }
+        .or_else(|| self.names.get(id))
+    }
+}
+
+impl RegionDisplayNamesV1<'_> {
+    /// Returns the name of a region in a style, falling back to the standard name.
+    fn get(&self, region: &Region, style: Style) -> Option<&str> {
+        match style {
+            Style::Short => self.short_names.get(region),
+            _ => None,
+        }
+        .or_else(|| self.names.get(region))
+    }
+}
+
+fn load<M, D>(locale: &Locale, data_provider: &D) -> Result<DataPayload<M>, DataError>
+where
+    M: ResourceMarker,
+    D: ResourceProvider<M> + ?Sized,
+{
+    data_provider
+        .load_resource(&DataRequest {
+            options: locale.id.clone().into(),
+            metadata: Default::default(),
+        })?
+        .take_payload()
+}
+
+/// Replaces parentheses in the name of a qualifier with brackets, so that they do not clash
+/// with the parentheses of the locale pattern.
+fn qualifier(name: &str) -> Cow<str> {
+    if name.contains(&['(', ')', '（', '）'][..]) {
+        Cow::Owned(
+            name.chars()
+                .map(|c| match c {
+                    '(' => '[',
+                    ')' => ']',
+                    '（' => '［',
+                    '）' => '］',
+                    c => c,
+                })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Replaces `{0}` and `{1}` in a pattern with `first` and `second`.
+fn substitute(pattern: &str, first: &str, second: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + first.len() + second.len());
+    let mut rest = pattern;
+    while let Some((before, after)) = rest.split_once('{') {
+        result.push_str(before);
+        rest = if let Some(after) = after.strip_prefix("0}") {
+            result.push_str(first);
+            after
+        } else if let Some(after) = after.strip_prefix("1}") {
+            result.push_str(second);
+            after
+        } else {
+            result.push('{');
+            after
+        };
+    }
+    result.push_str(rest);
+    result
+}
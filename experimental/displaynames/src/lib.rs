@@ -0,0 +1,65 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![warn(missing_docs)]
+
+//! [`icu_displaynames`](crate) offers localized display names of languages, scripts, regions,
+//! variants and whole locales, such as for language pickers.
+//!
+//! [`LocaleDisplayNamesFormatter`] composes the name of a locale from the names of its subtags,
+//! such as "Chinese (Traditional, Taiwan)", using a name of the locale's own where there is
+//! one, such as "British English". [`LanguageDisplayNames`], [`ScriptDisplayNames`],
+//! [`RegionDisplayNames`] and [`VariantDisplayNames`] look up the names of single subtags.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay};
+//! use icu_displaynames::LocaleDisplayNamesFormatter;
+//! use icu_locid::locale;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let mut options = DisplayNamesOptions::default();
+//! options.language_display = LanguageDisplay::Standard;
+//! let formatter = LocaleDisplayNamesFormatter::try_new(locale!("en"), &provider, options)
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(
+//!     formatter.of(&locale!("zh-Hant-TW")),
+//!     "Chinese (Traditional, Taiwan)"
+//! );
+//!
+//! let formatter =
+//!     LocaleDisplayNamesFormatter::try_new(locale!("ja"), &provider, Default::default())
+//!         .expect("Data should load successfully");
+//!
+//! assert_eq!(formatter.of(&locale!("en-GB")), "イギリス英語");
+//! assert_eq!(formatter.of(&locale!("zh-Hant-TW")), "繁体中国語 (台湾)");
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums
+    )
+)]
+
+extern crate alloc;
+
+pub mod displaynames;
+pub mod options;
+pub mod provider;
+
+pub use displaynames::{
+    LanguageDisplayNames, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+    VariantDisplayNames,
+};
+pub use options::DisplayNamesOptions;
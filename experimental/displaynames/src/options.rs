@@ -0,0 +1,99 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for the display name formatters.
+
+/// A bag of options defining how display names are selected and composed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DisplayNamesOptions {
+    /// Which form of the names to use.
+    pub style: Style,
+    /// Whether locales with a name of their own are displayed with it.
+    pub language_display: LanguageDisplay,
+}
+
+/// The form of a display name.
+///
+/// Names that have no name in the requested form use the standard name.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, Style};
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+/// let name = |style| {
+///     let mut options = DisplayNamesOptions::default();
+///     options.style = style;
+///     let formatter = LocaleDisplayNamesFormatter::try_new(locale!("en"), &provider, options)
+///         .expect("Data should load successfully");
+///     formatter.of(&locale!("zh-HK"))
+/// };
+///
+/// assert_eq!(name(Style::Standard), "Chinese (Hong Kong SAR China)");
+/// assert_eq!(name(Style::Short), "Chinese (Hong Kong)");
+/// assert_eq!(name(Style::Long), "Mandarin Chinese (Hong Kong SAR China)");
+/// assert_eq!(name(Style::Menu), "Chinese, Mandarin (Hong Kong SAR China)");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// The standard names, such as "United Kingdom".
+    Standard,
+
+    /// Shorter names, such as "UK".
+    Short,
+
+    /// More explicit names, such as "Mandarin Chinese".
+    Long,
+
+    /// Names that sort well in a menu, such as "Chinese, Mandarin".
+    Menu,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// How to display locales that have a name of their own, such as `en-GB`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay};
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+/// let name = |language_display| {
+///     let mut options = DisplayNamesOptions::default();
+///     options.language_display = language_display;
+///     let formatter = LocaleDisplayNamesFormatter::try_new(locale!("en"), &provider, options)
+///         .expect("Data should load successfully");
+///     formatter.of(&locale!("en-GB"))
+/// };
+///
+/// assert_eq!(name(LanguageDisplay::Dialect), "British English");
+/// assert_eq!(name(LanguageDisplay::Standard), "English (United Kingdom)");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum LanguageDisplay {
+    /// Use the name of the locale, such as "British English".
+    Dialect,
+
+    /// Always add the script and region as qualifiers, such as "English (United Kingdom)".
+    Standard,
+}
+
+impl Default for LanguageDisplay {
+    fn default() -> Self {
+        Self::Dialect
+    }
+}
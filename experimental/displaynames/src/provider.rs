@@ -0,0 +1,135 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+
+use alloc::borrow::Cow;
+use icu_locid::subtags::{Region, Script, Variant};
+use icu_provider::{yoke, zerofrom};
+use zerovec::ZeroMap;
+
+/// Display names of languages, such as "German", and of locales that have a name of their own,
+/// such as "British English" for `en-GB`.
+///
+/// The names are keyed by the BCP-47 form of a language identifier without variants, such as
+/// `de`, `en-GB` or `zh-Hant`.
+#[icu_provider::data_struct(LanguageDisplayNamesV1Marker = "displaynames/languages@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct LanguageDisplayNamesV1<'data> {
+    /// The standard names, such as "Chinese".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, str, str>,
+    /// The short names that differ from the standard names, such as "UK English".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, str, str>,
+    /// The long names that differ from the standard names, such as "Mandarin Chinese".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long_names: ZeroMap<'data, str, str>,
+    /// The names for menus that differ from the standard names, such as "Chinese, Mandarin".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub menu_names: ZeroMap<'data, str, str>,
+}
+
+/// Display names of scripts, such as "Cyrillic".
+#[icu_provider::data_struct(ScriptDisplayNamesV1Marker = "displaynames/scripts@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ScriptDisplayNamesV1<'data> {
+    /// The names used in locale names, such as "Traditional" in "Chinese (Traditional)".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, Script, str>,
+    /// The short names that differ from the standard names.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, Script, str>,
+    /// The names used on their own that differ from the standard names, such as
+    /// "Traditional Han".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub stand_alone_names: ZeroMap<'data, Script, str>,
+}
+
+/// Display names of regions, such as "Switzerland".
+#[icu_provider::data_struct(RegionDisplayNamesV1Marker = "displaynames/regions@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RegionDisplayNamesV1<'data> {
+    /// The standard names, such as "United Kingdom".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, Region, str>,
+    /// The short names that differ from the standard names, such as "UK".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, Region, str>,
+}
+
+/// Display names of variants, such as "Valencian".
+#[icu_provider::data_struct(VariantDisplayNamesV1Marker = "displaynames/variants@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct VariantDisplayNamesV1<'data> {
+    /// The names of the variants.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, Variant, str>,
+}
+
+/// The patterns for composing locale names, and the names of the keys and values of
+/// Unicode extension keywords, such as "Calendar" for `ca` and "Gregorian Calendar" for
+/// `ca-gregory`.
+#[icu_provider::data_struct(LocaleDisplayNamesV1Marker = "displaynames/locales@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct LocaleDisplayNamesV1<'data> {
+    /// The pattern for adding qualifiers to a language name, such as `{0} ({1})`, where `{0}`
+    /// is the language name and `{1}` the qualifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: Cow<'data, str>,
+    /// The pattern for joining two qualifiers, such as `{0}, {1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub separator: Cow<'data, str>,
+    /// The pattern for a keyword whose value has no name, such as `{0}: {1}`, where `{0}` is
+    /// the name of the key and `{1}` the value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub key_type_pattern: Cow<'data, str>,
+    /// The names of keys, such as "Calendar" for `ca`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub keys: ZeroMap<'data, str, str>,
+    /// The names of keywords, keyed by the key and value joined by `-`, such as
+    /// "Gregorian Calendar" for `ca-gregory`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub types: ZeroMap<'data, str, str>,
+}
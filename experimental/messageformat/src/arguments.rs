@@ -0,0 +1,111 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The values that messages are formatted with.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::iter::FromIterator;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{DateTime, Gregorian};
+use litemap::LiteMap;
+
+/// The value of an argument of a message.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Value<'a> {
+    /// A number, which is formatted with the decimal format of the locale.
+    Number(FixedDecimal),
+    /// A string, which is formatted as it is.
+    String(Cow<'a, str>),
+    /// A date and time, which is formatted by a `date`, `time`, or `datetime` function.
+    DateTime(DateTime<Gregorian>),
+}
+
+impl<'a> From<FixedDecimal> for Value<'a> {
+    fn from(value: FixedDecimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl<'a> From<String> for Value<'a> {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<DateTime<Gregorian>> for Value<'a> {
+    fn from(value: DateTime<Gregorian>) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+macro_rules! impl_from_integer_type {
+    ($($t:ty),+) => {
+        $(impl<'a> From<$t> for Value<'a> {
+            fn from(value: $t) -> Self {
+                Self::Number(value.into())
+            }
+        })+
+    };
+}
+
+impl_from_integer_type!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The named arguments of a message.
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::{Arguments, Value};
+///
+/// let arguments = Arguments::new().with("count", 3).with("name", "Alice");
+///
+/// assert!(matches!(arguments.get("count"), Some(Value::Number(_))));
+/// assert!(arguments.get("other").is_none());
+/// ```
+#[derive(Debug, Default)]
+pub struct Arguments<'a> {
+    values: LiteMap<&'a str, Value<'a>>,
+}
+
+impl<'a> Arguments<'a> {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the argument `name` to `value`, replacing any previous value.
+    pub fn insert(&mut self, name: &'a str, value: impl Into<Value<'a>>) {
+        self.values.insert(name, value.into());
+    }
+
+    /// Sets the argument `name` to `value`, replacing any previous value, and returns the
+    /// arguments.
+    pub fn with(mut self, name: &'a str, value: impl Into<Value<'a>>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Returns the value of the argument `name`.
+    pub fn get(&self, name: &str) -> Option<&Value<'a>> {
+        self.values.get(name)
+    }
+}
+
+impl<'a, V: Into<Value<'a>>> FromIterator<(&'a str, V)> for Arguments<'a> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, V)>>(iter: I) -> Self {
+        let mut arguments = Self::new();
+        for (name, value) in iter {
+            arguments.insert(name, value);
+        }
+        arguments
+    }
+}
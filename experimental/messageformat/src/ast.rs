@@ -0,0 +1,204 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The parsed structure of a message.
+//!
+//! Messages in [ICU MessageFormat](https://unicode-org.github.io/icu/userguide/format_parse/messages/)
+//! syntax and in [MessageFormat 2](https://github.com/unicode-org/message-format-wg) syntax
+//! are parsed into the same [`Message`] structure. The structure borrows names and text from
+//! the source string wherever possible; only text with escapes or quoting owns its contents.
+
+use crate::parser::{icu, mf2};
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use icu_datetime::options::length;
+
+/// A parsed message: its declarations, followed by the pattern to format.
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::ast::{Message, PatternPart};
+///
+/// let message = Message::try_from_icu_syntax("Hello, {name}!").expect("valid syntax");
+/// assert!(message.declarations.is_empty());
+/// assert!(matches!(message.pattern.parts[0], PatternPart::Text(ref text) if text == "Hello, "));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Message<'data> {
+    /// The `.input` and `.local` declarations of a MessageFormat 2 message, in order. Messages
+    /// in ICU MessageFormat syntax have no declarations.
+    pub declarations: Vec<Declaration<'data>>,
+    /// The pattern of the message.
+    pub pattern: Pattern<'data>,
+}
+
+impl<'data> Message<'data> {
+    /// Parses a message in ICU MessageFormat syntax, such as
+    /// `"{count, plural, one {# file} other {# files}}"`.
+    ///
+    /// Apostrophes quote syntax characters as in ICU's default `DOUBLE_OPTIONAL` mode: `''` is
+    /// an apostrophe, and `'{'` is a literal brace.
+    ///
+    /// Supported argument types are `number` (with the `integer` style), `date` and `time`
+    /// (with the `short`, `medium`, `long`, and `full` styles), `plural` (with an optional
+    /// `offset:`), `selectordinal`, and `select`. Other types and styles return
+    /// [`Error::Unsupported`].
+    pub fn try_from_icu_syntax(source: &'data str) -> Result<Self, Error> {
+        icu::parse(source)
+    }
+
+    /// Parses a message in MessageFormat 2 syntax, such as `"Hello, {$name}!"` or
+    /// `".input {$count :number} .match $count one {{one file}} * {{{$count} files}}"`.
+    ///
+    /// Supported functions are `:number` and `:integer` (with the `select` option),
+    /// `:date` and `:time` (with the `style` option), `:datetime` (with the `dateStyle` and
+    /// `timeStyle` options), and `:string`. Other functions and options, as well as markup and
+    /// attributes, return [`Error::Unsupported`].
+    pub fn try_from_mf2_syntax(source: &'data str) -> Result<Self, Error> {
+        mf2::parse(source)
+    }
+}
+
+/// A declaration of a local variable, such as `.local $n = {$count :number}`.
+///
+/// An `.input {$count :number}` declaration is a declaration of `count` whose expression
+/// refers to the argument of the same name.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Declaration<'data> {
+    /// The name of the variable, without the `$`.
+    pub name: &'data str,
+    /// The expression whose value the variable takes.
+    pub expression: Expression<'data>,
+}
+
+/// A sequence of text and placeholders.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct Pattern<'data> {
+    /// The parts of the pattern, in order.
+    pub parts: Vec<PatternPart<'data>>,
+}
+
+/// A part of a [`Pattern`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PatternPart<'data> {
+    /// Literal text, with escapes and quoting resolved.
+    Text(Cow<'data, str>),
+    /// A placeholder that formats the value of an expression, such as `{count, number}` or
+    /// `{$count :number}`.
+    Expression(Expression<'data>),
+    /// A choice between patterns, such as `{count, plural, one {…} other {…}}` or a
+    /// `.match` of MessageFormat 2.
+    Select(Select<'data>),
+    /// The `#` of ICU MessageFormat, which formats the number of the innermost `plural` or
+    /// `selectordinal` argument, less its offset.
+    Number,
+}
+
+/// A value with an optional function that formats it or selects on it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Expression<'data> {
+    /// The value of the expression.
+    pub operand: Operand<'data>,
+    /// The function applied to the value; if there is none, the value is formatted according
+    /// to its type, or according to the function of the declaration that it refers to.
+    pub function: Option<Function>,
+}
+
+/// The value of an [`Expression`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Operand<'data> {
+    /// A variable, which refers to a declaration or to an argument, without the `$`.
+    Variable(&'data str),
+    /// A literal, with escapes resolved.
+    Literal(Cow<'data, str>),
+}
+
+/// A function of an [`Expression`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Function {
+    /// Formats a number with the decimal format of the locale, and selects on it by its
+    /// plural category or its exact value.
+    Number(NumberOptions),
+    /// Formats a date, a time, or both, with the given lengths.
+    DateTime(length::Bag),
+    /// Formats a value as a string, and selects on it by its string value.
+    String,
+}
+
+/// The options of [`Function::Number`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[non_exhaustive]
+pub struct NumberOptions {
+    /// Whether the number is rounded to an integer, as with `{n, number, integer}` or
+    /// `:integer`.
+    pub integer: bool,
+    /// How variants are selected by the number.
+    pub select: NumberSelect,
+    /// The offset of an ICU MessageFormat `plural` argument, which is subtracted from the
+    /// number for selecting a plural category and for formatting `#`, but not for matching
+    /// explicit values such as `=0`.
+    pub offset: u32,
+}
+
+/// How variants are selected by a number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum NumberSelect {
+    /// By exact value, then by cardinal plural category, as in `one` for "1 file".
+    Plural,
+    /// By exact value, then by ordinal plural category, as in `two` for "2nd".
+    Ordinal,
+    /// By exact value only.
+    Exact,
+}
+
+impl Default for NumberSelect {
+    fn default() -> Self {
+        Self::Plural
+    }
+}
+
+/// A choice between [`Variant`]s according to the values of one or more selectors.
+///
+/// The variant whose keys match the selectors best is chosen, preferring for each selector in
+/// order an exact match over a plural category over the catch-all key.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Select<'data> {
+    /// The selectors; an ICU MessageFormat argument has a single selector.
+    pub selectors: Vec<Expression<'data>>,
+    /// The variants, with as many keys each as there are selectors.
+    pub variants: Vec<Variant<'data>>,
+}
+
+/// A pattern and the keys that select it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Variant<'data> {
+    /// The keys of the variant, one per selector.
+    pub keys: Vec<Key<'data>>,
+    /// The pattern of the variant.
+    pub pattern: Pattern<'data>,
+}
+
+/// A key of a [`Variant`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Key<'data> {
+    /// A literal key, which matches a number of equal value, a number whose plural category
+    /// has the name of the key, or a string equal to the key. Explicit values such as `=1`
+    /// of ICU MessageFormat are stored without the `=`.
+    Literal(Cow<'data, str>),
+    /// The catch-all key, `*` or ICU MessageFormat's `other`, which matches any value.
+    CatchAll,
+}
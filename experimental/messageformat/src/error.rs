@@ -0,0 +1,64 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for message formatting.
+
+use displaydoc::Display;
+
+/// A list of possible error outcomes for operations in this crate.
+#[derive(Display, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// An error loading the plural rules of the locale.
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
+    /// An error loading the number formatter of the locale.
+    #[displaydoc("error loading number format: {0}")]
+    Decimal(icu_decimal::FixedDecimalFormatError),
+    /// An error loading a date or time formatter of the locale.
+    #[displaydoc("error loading date format: {0}")]
+    DateTime(icu_datetime::DateTimeFormatError),
+    /// The message is not well-formed or nests arguments too deeply; the value is the byte
+    /// offset of the error.
+    #[displaydoc("syntax error at byte {0}")]
+    Syntax(usize),
+    /// The message uses an argument type, function, or option that is not supported; the
+    /// value is the byte offset of its name.
+    #[displaydoc("unsupported function or option at byte {0}")]
+    Unsupported(usize),
+    /// A plural or select argument has no `other` variant, or a matcher has no variant whose
+    /// keys are all `*`; the value is the byte offset of the argument or matcher.
+    #[displaydoc("missing catch-all variant at byte {0}")]
+    MissingCatchAll(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<icu_decimal::FixedDecimalFormatError> for Error {
+    fn from(e: icu_decimal::FixedDecimalFormatError) -> Self {
+        Error::Decimal(e)
+    }
+}
+
+impl From<icu_datetime::DateTimeFormatError> for Error {
+    fn from(e: icu_datetime::DateTimeFormatError) -> Self {
+        Error::DateTime(e)
+    }
+}
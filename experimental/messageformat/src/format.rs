@@ -0,0 +1,627 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of parsed messages with their arguments.
+
+use crate::arguments::{Arguments, Value};
+use crate::ast::*;
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, RoundingMode};
+use icu_calendar::Gregorian;
+use icu_datetime::options::length;
+use icu_datetime::provider::calendar::{
+    DatePatternsV1Marker, DateSkeletonPatternsV1Marker, DateSymbolsV1Marker, TimePatternsV1Marker,
+    TimeSymbolsV1Marker,
+};
+use icu_datetime::provider::week_data::WeekDataV1Marker;
+use icu_datetime::DateTimeFormat;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+/// A formatter for a [`Message`], which selects plural and select variants with
+/// [`PluralRules`], and formats numbers with [`FixedDecimalFormat`] and dates and times with
+/// [`DateTimeFormat`].
+///
+/// The formatter loads the data for the functions that the message uses when it is created.
+/// Formatting does not fail: a placeholder whose argument is missing, or whose value cannot be
+/// formatted by its function, is written as its variable name in braces, such as `{$count}`,
+/// and marked with [`parts::FALLBACK`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_messageformat::ast::Message;
+/// use icu_messageformat::{Arguments, MessageFormat};
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let message = Message::try_from_icu_syntax("{count, plural, one {# file} other {# files}}")
+///     .expect("valid syntax");
+/// let mf = MessageFormat::try_new(locale!("en"), &provider, message)
+///     .expect("Data should load successfully");
+///
+/// let arguments = Arguments::new().with("count", 1234);
+/// assert_writeable_parts_eq!(
+///     mf.format(&arguments),
+///     "1,234 files",
+///     [
+///         (0, 5, icu_decimal::format::parts::INTEGER),
+///         (0, 5, icu_messageformat::format::parts::NUMBER),
+///         (1, 2, icu_decimal::format::parts::GROUP),
+///     ]
+/// );
+/// ```
+pub struct MessageFormat<'data> {
+    message: Message<'data>,
+    decimal: FixedDecimalFormat,
+    cardinal: Option<PluralRules>,
+    ordinal: Option<PluralRules>,
+    datetime: Vec<(length::Bag, DateTimeFormat<Gregorian>)>,
+}
+
+impl<'data> MessageFormat<'data> {
+    /// Creates a new [`MessageFormat`] for a [`Message`], loading the plural rules and the date
+    /// and time formats that the message needs.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        message: Message<'data>,
+    ) -> Result<Self, Error>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ResourceProvider<OrdinalV1Marker>
+            + ResourceProvider<DateSymbolsV1Marker>
+            + ResourceProvider<TimeSymbolsV1Marker>
+            + ResourceProvider<DatePatternsV1Marker>
+            + ResourceProvider<TimePatternsV1Marker>
+            + ResourceProvider<DateSkeletonPatternsV1Marker>
+            + ResourceProvider<WeekDataV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let mut functions = Vec::new();
+        for declaration in message.declarations.iter() {
+            functions.extend(declaration.expression.function);
+        }
+        collect_functions(&message.pattern, &mut functions);
+
+        let mut cardinal = None;
+        let mut ordinal = None;
+        let mut datetime: Vec<(length::Bag, DateTimeFormat<Gregorian>)> = Vec::new();
+        for function in functions {
+            match function {
+                Function::Number(NumberOptions {
+                    select: NumberSelect::Plural,
+                    ..
+                }) if cardinal.is_none() => {
+                    cardinal = Some(PluralRules::try_new_cardinal(
+                        locale.clone(),
+                        data_provider,
+                    )?);
+                }
+                Function::Number(NumberOptions {
+                    select: NumberSelect::Ordinal,
+                    ..
+                }) if ordinal.is_none() => {
+                    ordinal = Some(PluralRules::try_new_ordinal(locale.clone(), data_provider)?);
+                }
+                Function::DateTime(bag) if !datetime.iter().any(|(b, _)| *b == bag) => {
+                    let format =
+                        DateTimeFormat::try_new(locale.clone(), data_provider, &bag.into())?;
+                    datetime.push((bag, format));
+                }
+                _ => {}
+            }
+        }
+        let decimal = FixedDecimalFormat::try_new(locale, data_provider, Default::default())?;
+
+        Ok(Self {
+            message,
+            decimal,
+            cardinal,
+            ordinal,
+            datetime,
+        })
+    }
+
+    /// Returns the [`Message`] that this formatter formats.
+    pub fn message(&self) -> &Message<'data> {
+        &self.message
+    }
+
+    /// Formats the message with the given arguments, returning a [`FormattedMessage`].
+    pub fn format<'l>(&'l self, arguments: &'l Arguments<'l>) -> FormattedMessage<'l> {
+        FormattedMessage {
+            format: self,
+            arguments,
+        }
+    }
+
+    /// Formats the message with the given arguments, returning a [`String`].
+    pub fn format_to_string(&self, arguments: &Arguments) -> String {
+        self.format(arguments).write_to_string().into_owned()
+    }
+}
+
+fn collect_functions(pattern: &Pattern, functions: &mut Vec<Function>) {
+    for part in pattern.parts.iter() {
+        match part {
+            PatternPart::Expression(expression) => functions.extend(expression.function),
+            PatternPart::Select(select) => {
+                for selector in select.selectors.iter() {
+                    functions.extend(selector.function);
+                }
+                for variant in select.variants.iter() {
+                    collect_functions(&variant.pattern, functions);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// [`Part`](writeable::Part)s used by [`FormattedMessage`].
+///
+/// Formatted numbers are also annotated with the parts of
+/// [`icu_decimal::format::parts`] within [`NUMBER`](parts::NUMBER).
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark a formatted number, including the `#` of a plural variant.
+    pub const NUMBER: Part = Part {
+        category: "message",
+        value: "number",
+    };
+
+    /// The [`Part`] used to mark a formatted date or time.
+    pub const DATETIME: Part = Part {
+        category: "message",
+        value: "datetime",
+    };
+
+    /// The [`Part`] used to mark the value of a string argument.
+    pub const STRING: Part = Part {
+        category: "message",
+        value: "string",
+    };
+
+    /// The [`Part`] used to mark a placeholder that could not be formatted, such as `{$count}`
+    /// for a missing argument.
+    pub const FALLBACK: Part = Part {
+        category: "message",
+        value: "fallback",
+    };
+}
+
+/// A message formatted with its arguments by [`MessageFormat`].
+#[derive(Clone, Copy)]
+pub struct FormattedMessage<'l> {
+    format: &'l MessageFormat<'l>,
+    arguments: &'l Arguments<'l>,
+}
+
+/// The value of an expression after resolving its variable.
+enum Resolved<'l> {
+    Value(&'l Value<'l>),
+    Literal(&'l str),
+    Missing,
+}
+
+/// The value of a selector, as matched against the keys of variants.
+enum Selector<'l> {
+    /// A number, with its plural category unless it is selected by exact value only, and the
+    /// number less its offset, which is what `#` formats.
+    Number {
+        value: Cow<'l, FixedDecimal>,
+        category: Option<PluralCategory>,
+        offset_value: FixedDecimal,
+    },
+    String(Cow<'l, str>),
+    /// A value that can only match the catch-all key.
+    Unmatchable,
+}
+
+impl<'l> Selector<'l> {
+    /// Returns how well `key` matches the selector, lower being better, or `None` if it does
+    /// not match.
+    fn rank(&self, key: &Key) -> Option<u8> {
+        match (key, self) {
+            (Key::CatchAll, _) => Some(2),
+            (
+                Key::Literal(key),
+                Selector::Number {
+                    value, category, ..
+                },
+            ) => {
+                let exact = key
+                    .parse::<FixedDecimal>()
+                    .ok()
+                    .and_then(|key| key.checked_sub(value).ok())
+                    .map_or(false, |difference| difference.is_zero());
+                if exact {
+                    Some(0)
+                } else if category.is_some() && PluralCategory::from_tr35_string(key) == *category {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+            (Key::Literal(key), Selector::String(value)) => (key == value).then(|| 0),
+            (Key::Literal(_), Selector::Unmatchable) => None,
+        }
+    }
+}
+
+impl<'l> FormattedMessage<'l> {
+    /// Resolves the operand of `expression`, looking up variables in the first `scope`
+    /// declarations and then in the arguments, and returns it with the function that applies
+    /// to it.
+    fn resolve(
+        &self,
+        expression: &'l Expression<'l>,
+        scope: usize,
+    ) -> (Resolved<'l>, Option<Function>) {
+        let (resolved, function) = match &expression.operand {
+            Operand::Literal(literal) => (Resolved::Literal(literal), None),
+            Operand::Variable(name) => {
+                let declaration = self
+                    .format
+                    .message
+                    .declarations
+                    .iter()
+                    .take(scope)
+                    .enumerate()
+                    .rev()
+                    .find(|(_, declaration)| declaration.name == *name);
+                match declaration {
+                    Some((index, declaration)) => self.resolve(&declaration.expression, index),
+                    None => match self.arguments.get(name) {
+                        Some(value) => (Resolved::Value(value), None),
+                        None => (Resolved::Missing, None),
+                    },
+                }
+            }
+        };
+        (resolved, expression.function.or(function))
+    }
+
+    fn selector(&self, expression: &'l Expression<'l>) -> Selector<'l> {
+        let scope = self.format.message.declarations.len();
+        let (resolved, function) = self.resolve(expression, scope);
+        match function {
+            Some(Function::Number(options)) => {
+                let value = match to_number(&resolved, options) {
+                    Some(value) => value,
+                    None => return Selector::Unmatchable,
+                };
+                let offset_value = value
+                    .checked_sub(&FixedDecimal::from(options.offset))
+                    .unwrap_or_else(|_| value.clone().into_owned());
+                let rules = match options.select {
+                    NumberSelect::Plural => self.format.cardinal.as_ref(),
+                    NumberSelect::Ordinal => self.format.ordinal.as_ref(),
+                    NumberSelect::Exact => None,
+                };
+                Selector::Number {
+                    category: rules.map(|rules| rules.select(&offset_value)),
+                    value,
+                    offset_value,
+                }
+            }
+            Some(Function::DateTime(_)) => Selector::Unmatchable,
+            None | Some(Function::String) => match resolved {
+                Resolved::Value(Value::String(value)) => Selector::String(Cow::Borrowed(value)),
+                Resolved::Value(Value::Number(value)) => {
+                    Selector::String(Cow::Owned(value.to_string()))
+                }
+                Resolved::Literal(literal) => Selector::String(Cow::Borrowed(literal)),
+                _ => Selector::Unmatchable,
+            },
+        }
+    }
+
+    /// Returns the variant of `select` that matches its selectors best, and the number that
+    /// `#` formats in the variant, if any.
+    fn select(&self, select: &'l Select<'l>) -> Option<(&'l Variant<'l>, Option<FixedDecimal>)> {
+        let selectors: Vec<Selector> = select
+            .selectors
+            .iter()
+            .map(|selector| self.selector(selector))
+            .collect();
+        let mut best: Option<(&Variant, Vec<u8>)> = None;
+        for variant in select.variants.iter() {
+            let ranks: Option<Vec<u8>> = variant
+                .keys
+                .iter()
+                .zip(selectors.iter())
+                .map(|(key, selector)| selector.rank(key))
+                .collect();
+            if let Some(ranks) = ranks {
+                if best.as_ref().map_or(true, |(_, best)| ranks < *best) {
+                    best = Some((variant, ranks));
+                }
+            }
+        }
+        let number = selectors.into_iter().find_map(|selector| match selector {
+            Selector::Number { offset_value, .. } => Some(offset_value),
+            _ => None,
+        });
+        best.map(|(variant, _)| (variant, number))
+    }
+
+    fn write_pattern<S: PartsWrite + ?Sized>(
+        &self,
+        pattern: &'l Pattern<'l>,
+        number: Option<&FixedDecimal>,
+        sink: &mut S,
+    ) -> fmt::Result {
+        for part in pattern.parts.iter() {
+            match part {
+                PatternPart::Text(text) => sink.write_str(text)?,
+                PatternPart::Expression(expression) => self.write_expression(expression, sink)?,
+                PatternPart::Select(select) => {
+                    if let Some((variant, selected)) = self.select(select) {
+                        self.write_pattern(&variant.pattern, selected.as_ref().or(number), sink)?;
+                    }
+                }
+                PatternPart::Number => match number {
+                    Some(number) => self.write_number(number, sink)?,
+                    None => sink.write_char('#')?,
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn write_expression<S: PartsWrite + ?Sized>(
+        &self,
+        expression: &'l Expression<'l>,
+        sink: &mut S,
+    ) -> fmt::Result {
+        let scope = self.format.message.declarations.len();
+        let (resolved, function) = self.resolve(expression, scope);
+        match (function, &resolved) {
+            (Some(Function::Number(options)), _) => {
+                if let Some(value) = to_number(&resolved, options) {
+                    return self.write_number(&value, sink);
+                }
+            }
+            (Some(Function::DateTime(bag)), Resolved::Value(Value::DateTime(value))) => {
+                if let Some((_, format)) = self.format.datetime.iter().find(|(b, _)| *b == bag) {
+                    return sink.with_part(parts::DATETIME, |w| format.format(value).write_to(w));
+                }
+            }
+            (None, Resolved::Value(Value::Number(value))) => {
+                return self.write_number(value, sink);
+            }
+            (Some(Function::String), Resolved::Value(Value::Number(value))) => {
+                return sink.with_part(parts::STRING, |w| value.write_to(w));
+            }
+            (None | Some(Function::String), Resolved::Value(Value::String(value))) => {
+                return sink.with_part(parts::STRING, |w| w.write_str(value));
+            }
+            (None | Some(Function::String), Resolved::Literal(literal)) => {
+                return sink.write_str(literal);
+            }
+            _ => {}
+        }
+        sink.with_part(parts::FALLBACK, |w| match &expression.operand {
+            Operand::Variable(name) => write!(w, "{{${}}}", name),
+            Operand::Literal(literal) => write!(w, "{{|{}|}}", literal),
+        })
+    }
+
+    fn write_number<S: PartsWrite + ?Sized>(
+        &self,
+        value: &FixedDecimal,
+        sink: &mut S,
+    ) -> fmt::Result {
+        sink.with_part(parts::NUMBER, |w| {
+            self.format.decimal.format(value).write_to_parts(w)
+        })
+    }
+}
+
+/// Returns the number that a resolved operand of a number function stands for.
+fn to_number<'l>(resolved: &Resolved<'l>, options: NumberOptions) -> Option<Cow<'l, FixedDecimal>> {
+    let value = match *resolved {
+        Resolved::Value(Value::Number(value)) => Cow::Borrowed(value),
+        Resolved::Value(Value::String(ref value)) => Cow::Owned(value.parse().ok()?),
+        Resolved::Literal(literal) => Cow::Owned(literal.parse().ok()?),
+        _ => return None,
+    };
+    if options.integer {
+        Some(Cow::Owned(
+            value.into_owned().rounded(0, RoundingMode::HalfEven),
+        ))
+    } else {
+        Some(value)
+    }
+}
+
+impl<'l> Writeable for FormattedMessage<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.write_pattern(&self.format.message.pattern, None, sink)
+    }
+}
+
+impl<'l> fmt::Display for FormattedMessage<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_calendar::DateTime;
+    use icu_locid::locale;
+
+    fn format_icu(locale: Locale, message: &str, arguments: &Arguments) -> String {
+        let provider = icu_testdata::get_provider();
+        let message = Message::try_from_icu_syntax(message).unwrap();
+        MessageFormat::try_new(locale, &provider, message)
+            .unwrap()
+            .format_to_string(arguments)
+    }
+
+    fn format_mf2(locale: Locale, message: &str, arguments: &Arguments) -> String {
+        let provider = icu_testdata::get_provider();
+        let message = Message::try_from_mf2_syntax(message).unwrap();
+        MessageFormat::try_new(locale, &provider, message)
+            .unwrap()
+            .format_to_string(arguments)
+    }
+
+    #[test]
+    fn test_icu_plural() {
+        let message = "{count, plural, offset:1 =0 {nobody} =1 {{name}} one {{name} and # other} \
+                       other {{name} and # others}}";
+        for (count, expected) in [
+            (0, "nobody"),
+            (1, "Alice"),
+            (2, "Alice and 1 other"),
+            (3, "Alice and 2 others"),
+            (1001, "Alice and 1,000 others"),
+        ] {
+            let arguments = Arguments::new().with("count", count).with("name", "Alice");
+            assert_eq!(format_icu(locale!("en"), message, &arguments), expected);
+        }
+
+        let message = "{n, plural, one {# книга} few {# книги} many {# книг} other {# книги}}";
+        for (n, expected) in [
+            ("1", "1 книга"),
+            ("3", "3 книги"),
+            ("11", "11 книг"),
+            ("1.5", "1,5 книги"),
+        ] {
+            let arguments = Arguments::new().with("n", n.parse::<FixedDecimal>().unwrap());
+            assert_eq!(format_icu(locale!("ru"), message, &arguments), expected);
+        }
+    }
+
+    #[test]
+    fn test_icu_selectordinal_and_select() {
+        let message = "{gender, select, female {She} male {He} other {They}} finished \
+                       {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}.";
+        for (gender, place, expected) in [
+            ("female", 1, "She finished 1st."),
+            ("male", 22, "He finished 22nd."),
+            ("other", 13, "They finished 13th."),
+            ("unknown", 103, "They finished 103rd."),
+        ] {
+            let arguments = Arguments::new().with("gender", gender).with("place", place);
+            assert_eq!(format_icu(locale!("en"), message, &arguments), expected);
+        }
+    }
+
+    #[test]
+    fn test_icu_number_and_date() {
+        let date = DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 28).unwrap();
+        let arguments = Arguments::new()
+            .with("n", "-1234.5".parse::<FixedDecimal>().unwrap())
+            .with("date", date);
+        assert_eq!(
+            format_icu(
+                locale!("en"),
+                "{n} {n, number, integer} {date, date, long}",
+                &arguments
+            ),
+            "-1,234.5 -1,234 September 1, 2020"
+        );
+        assert_eq!(
+            format_icu(locale!("fr"), "{n} {date, date, short}", &arguments),
+            "-1\u{202f}234,5 01/09/2020"
+        );
+    }
+
+    #[test]
+    fn test_icu_quoting() {
+        let arguments = Arguments::new().with("n", 2);
+        assert_eq!(
+            format_icu(
+                locale!("en"),
+                "It''s '{n}' or {n, plural, one {'#' #} other {# '#'}} #",
+                &arguments
+            ),
+            "It's {n} or 2 # #"
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let arguments = Arguments::new().with("name", "Alice");
+        assert_eq!(
+            format_icu(locale!("en"), "{name}: {count, number}", &arguments),
+            "Alice: {$count}"
+        );
+        assert_eq!(
+            format_icu(
+                locale!("en"),
+                "{name, plural, one {one} other {other}}",
+                &arguments
+            ),
+            "other"
+        );
+    }
+
+    #[test]
+    fn test_mf2() {
+        let arguments = Arguments::new()
+            .with("count", 1)
+            .with("name", "Alice")
+            .with("pi", "3.14159".parse::<FixedDecimal>().unwrap());
+        assert_eq!(
+            format_mf2(
+                locale!("en"),
+                "  Hello, {$name} \\{{|literal|}\\}! ",
+                &arguments
+            ),
+            "  Hello, Alice {literal}! "
+        );
+        assert_eq!(
+            format_mf2(
+                locale!("en"),
+                ".local $rounded = {$pi :integer} {{{$pi} is about {$rounded}}}",
+                &arguments
+            ),
+            "3.14159 is about 3"
+        );
+
+        let message = ".input {$count :number}
+                       .input {$name :string}
+                       .match $count $name
+                       1 Alice {{Alice has one file}}
+                       one *   {{{$name} has {$count} file}}
+                       * Alice {{Alice has {$count} files}}
+                       * *     {{{$name} has {$count} files}}";
+        for (count, name, expected) in [
+            (1, "Alice", "Alice has one file"),
+            (1, "Bob", "Bob has 1 file"),
+            (2, "Alice", "Alice has 2 files"),
+            (2, "Bob", "Bob has 2 files"),
+        ] {
+            let arguments = Arguments::new().with("count", count).with("name", name);
+            assert_eq!(format_mf2(locale!("en"), message, &arguments), expected);
+        }
+
+        let message = ".input {$count :number select=exact} .match $count 1 {{one}} * {{other}}";
+        for (count, expected) in [("1", "one"), ("1.0", "one"), ("2", "other")] {
+            let arguments = Arguments::new().with("count", count);
+            assert_eq!(format_mf2(locale!("en"), message, &arguments), expected);
+        }
+    }
+}
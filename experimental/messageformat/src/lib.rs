@@ -0,0 +1,84 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![warn(missing_docs)]
+
+//! [`icu_messageformat`](crate) formats messages with placeholders, plural and select
+//! variants, and formatted numbers and dates, such as
+//! `"{count, plural, one {# file} other {# files}}"`.
+//!
+//! Messages are parsed from
+//! [ICU MessageFormat](https://unicode-org.github.io/icu/userguide/format_parse/messages/)
+//! syntax or [MessageFormat 2](https://github.com/unicode-org/message-format-wg) syntax into a
+//! [`Message`](ast::Message) that borrows from the source string. A [`MessageFormat`] selects
+//! variants with [`PluralRules`](icu_plurals::PluralRules), formats numbers with
+//! [`FixedDecimalFormat`](icu_decimal::FixedDecimalFormat) and dates with
+//! [`DateTimeFormat`](icu_datetime::DateTimeFormat), and returns a
+//! [`Writeable`](writeable::Writeable) whose parts mark the formatted arguments.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_messageformat::ast::Message;
+//! use icu_messageformat::{Arguments, MessageFormat};
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let message = Message::try_from_icu_syntax(
+//!     "{name} has {count, plural, =0 {no files} one {# file} other {# files}}.",
+//! )
+//! .expect("valid syntax");
+//! let mf = MessageFormat::try_new(locale!("en"), &provider, message)
+//!     .expect("Data should load successfully");
+//!
+//! let arguments = Arguments::new().with("name", "Alice").with("count", 0);
+//! assert_eq!(mf.format(&arguments).write_to_string(), "Alice has no files.");
+//! let arguments = Arguments::new().with("name", "Bob").with("count", 1200);
+//! assert_eq!(mf.format(&arguments).write_to_string(), "Bob has 1,200 files.");
+//!
+//! let message = Message::try_from_mf2_syntax(
+//!     ".input {$count :number select=ordinal}
+//!      .match $count
+//!      one {{{$count}st place}}
+//!      two {{{$count}nd place}}
+//!      few {{{$count}rd place}}
+//!      *   {{{$count}th place}}",
+//! )
+//! .expect("valid syntax");
+//! let mf = MessageFormat::try_new(locale!("en"), &provider, message)
+//!     .expect("Data should load successfully");
+//!
+//! let arguments = Arguments::new().with("count", 22);
+//! assert_eq!(mf.format(&arguments).write_to_string(), "22nd place");
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums
+    )
+)]
+
+extern crate alloc;
+
+mod arguments;
+pub mod ast;
+pub mod error;
+pub mod format;
+mod parser;
+
+pub use arguments::{Arguments, Value};
+pub use error::Error as MessageFormatError;
+pub use format::{FormattedMessage, MessageFormat};
+
+use error::Error;
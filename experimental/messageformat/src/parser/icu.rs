@@ -0,0 +1,260 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for the syntax of ICU MessageFormat.
+
+use super::{Cursor, Text};
+use crate::ast::*;
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_datetime::options::length;
+use icu_plurals::PluralCategory;
+
+pub(crate) fn parse(source: &str) -> Result<Message, Error> {
+    let mut cursor = Cursor::new(source);
+    let pattern = parse_pattern(&mut cursor, false, false)?;
+    Ok(Message {
+        declarations: Vec::new(),
+        pattern,
+    })
+}
+
+/// Parses a message up to the end of the source, or up to the `}` that closes it if it is the
+/// message of a variant.
+fn parse_pattern<'data>(
+    cursor: &mut Cursor<'data>,
+    in_plural: bool,
+    nested: bool,
+) -> Result<Pattern<'data>, Error> {
+    let mut parts = Vec::new();
+    let mut text = Text::default();
+    loop {
+        match cursor.peek() {
+            None if nested => return Err(cursor.error()),
+            None => break,
+            Some(b'}') if nested => break,
+            Some(b'}') => return Err(cursor.error()),
+            Some(b'{') => {
+                text.flush_into(&mut parts);
+                parts.push(parse_argument(cursor)?);
+            }
+            Some(b'#') if in_plural => {
+                text.flush_into(&mut parts);
+                parts.push(PatternPart::Number);
+                cursor.bump();
+            }
+            Some(b'\'') => parse_apostrophe(cursor, in_plural, &mut text),
+            Some(_) => text.push_str(
+                cursor.take_while(|c| !(matches!(c, '{' | '}' | '\'') || in_plural && c == '#')),
+            ),
+        }
+    }
+    text.flush_into(&mut parts);
+    Ok(Pattern { parts })
+}
+
+/// Parses an apostrophe, which is either a doubled apostrophe, the start of quoted text, or
+/// an apostrophe by itself.
+fn parse_apostrophe<'data>(cursor: &mut Cursor<'data>, in_plural: bool, text: &mut Text<'data>) {
+    let start = cursor.pos;
+    cursor.bump();
+    match cursor.peek() {
+        Some(b'\'') => {
+            text.push_str(cursor.slice_from(start));
+            cursor.bump();
+        }
+        Some(b'{') | Some(b'}') | Some(b'|') => parse_quoted(cursor, text),
+        Some(b'#') if in_plural => parse_quoted(cursor, text),
+        _ => text.push_str(cursor.slice_from(start)),
+    }
+}
+
+/// Parses quoted text after its opening apostrophe. Quoted text that is not closed extends to
+/// the end of the message, as in ICU.
+fn parse_quoted<'data>(cursor: &mut Cursor<'data>, text: &mut Text<'data>) {
+    loop {
+        text.push_str(cursor.take_while(|c| c != '\''));
+        if cursor.is_done() {
+            return;
+        }
+        let apostrophe = cursor.pos;
+        cursor.bump();
+        if cursor.peek() != Some(b'\'') {
+            return;
+        }
+        text.push_str(cursor.slice_from(apostrophe));
+        cursor.bump();
+    }
+}
+
+fn parse_name<'data>(cursor: &mut Cursor<'data>) -> Result<&'data str, Error> {
+    let name = cursor.take_while(|c| c.is_alphanumeric() || c == '_');
+    if name.is_empty() {
+        Err(cursor.error())
+    } else {
+        Ok(name)
+    }
+}
+
+fn parse_argument<'data>(cursor: &mut Cursor<'data>) -> Result<PatternPart<'data>, Error> {
+    let start = cursor.pos;
+    cursor.expect(b'{')?;
+    cursor.skip_whitespace();
+    let name = parse_name(cursor)?;
+    cursor.skip_whitespace();
+    if cursor.eat(b'}') {
+        return Ok(PatternPart::Expression(argument(name, None)));
+    }
+    cursor.expect(b',')?;
+    cursor.skip_whitespace();
+    let type_start = cursor.pos;
+    let argument_type = parse_name(cursor)?;
+    cursor.skip_whitespace();
+    let function = match argument_type {
+        "number" => {
+            let integer = match parse_style(cursor)? {
+                None => false,
+                Some(("integer", _)) => true,
+                Some((_, style_start)) => return Err(Error::Unsupported(style_start)),
+            };
+            Function::Number(NumberOptions {
+                integer,
+                ..Default::default()
+            })
+        }
+        "date" => {
+            let (style, style_start) = parse_style(cursor)?.unwrap_or(("medium", type_start));
+            let date = match style {
+                "short" => length::Date::Short,
+                "medium" => length::Date::Medium,
+                "long" => length::Date::Long,
+                "full" => length::Date::Full,
+                _ => return Err(Error::Unsupported(style_start)),
+            };
+            Function::DateTime(length::Bag::from_date_style(date))
+        }
+        "time" => {
+            let (style, style_start) = parse_style(cursor)?.unwrap_or(("medium", type_start));
+            let time = match style {
+                "short" => length::Time::Short,
+                "medium" => length::Time::Medium,
+                "long" => length::Time::Long,
+                "full" => length::Time::Full,
+                _ => return Err(Error::Unsupported(style_start)),
+            };
+            Function::DateTime(length::Bag::from_time_style(time))
+        }
+        "plural" => return parse_plural(cursor, start, name, NumberSelect::Plural),
+        "selectordinal" => return parse_plural(cursor, start, name, NumberSelect::Ordinal),
+        "select" => {
+            cursor.expect(b',')?;
+            let variants = parse_variants(cursor, start, false)?;
+            return Ok(PatternPart::Select(Select {
+                selectors: vec![argument(name, Some(Function::String))],
+                variants,
+            }));
+        }
+        _ => return Err(Error::Unsupported(type_start)),
+    };
+    Ok(PatternPart::Expression(argument(name, Some(function))))
+}
+
+fn argument(name: &str, function: Option<Function>) -> Expression {
+    Expression {
+        operand: Operand::Variable(name),
+        function,
+    }
+}
+
+/// Parses the optional style of a `number`, `date`, or `time` argument, and the `}` that
+/// closes the argument, returning the style and its offset.
+fn parse_style<'data>(cursor: &mut Cursor<'data>) -> Result<Option<(&'data str, usize)>, Error> {
+    if cursor.eat(b'}') {
+        return Ok(None);
+    }
+    cursor.expect(b',')?;
+    cursor.skip_whitespace();
+    let start = cursor.pos;
+    let style = cursor.take_while(|c| c != '}').trim_end();
+    cursor.expect(b'}')?;
+    Ok(Some((style, start)))
+}
+
+fn parse_plural<'data>(
+    cursor: &mut Cursor<'data>,
+    start: usize,
+    name: &'data str,
+    select: NumberSelect,
+) -> Result<PatternPart<'data>, Error> {
+    cursor.expect(b',')?;
+    cursor.skip_whitespace();
+    let mut offset = 0;
+    if select == NumberSelect::Plural && cursor.eat_str("offset:") {
+        cursor.skip_whitespace();
+        let digits_start = cursor.pos;
+        offset = cursor
+            .take_while(|c| c.is_ascii_digit())
+            .parse()
+            .map_err(|_| Error::Syntax(digits_start))?;
+    }
+    let variants = parse_variants(cursor, start, true)?;
+    let function = Function::Number(NumberOptions {
+        select,
+        offset,
+        ..Default::default()
+    });
+    Ok(PatternPart::Select(Select {
+        selectors: vec![argument(name, Some(function))],
+        variants,
+    }))
+}
+
+/// Parses the variants of a `plural`, `selectordinal`, or `select` argument, and the `}` that
+/// closes the argument.
+fn parse_variants<'data>(
+    cursor: &mut Cursor<'data>,
+    start: usize,
+    plural: bool,
+) -> Result<Vec<Variant<'data>>, Error> {
+    let mut variants = Vec::new();
+    loop {
+        cursor.skip_whitespace();
+        if cursor.eat(b'}') {
+            break;
+        }
+        let key_start = cursor.pos;
+        let key = if plural && cursor.eat(b'=') {
+            let value = cursor.take_while(|c| c.is_ascii_digit() || c == '.' || c == '-');
+            if value.parse::<FixedDecimal>().is_err() {
+                return Err(Error::Syntax(key_start));
+            }
+            Key::Literal(Cow::Borrowed(value))
+        } else {
+            match parse_name(cursor)? {
+                "other" => Key::CatchAll,
+                keyword if plural && PluralCategory::from_tr35_string(keyword).is_none() => {
+                    return Err(Error::Syntax(key_start));
+                }
+                keyword => Key::Literal(Cow::Borrowed(keyword)),
+            }
+        };
+        cursor.skip_whitespace();
+        cursor.expect(b'{')?;
+        cursor.enter()?;
+        let pattern = parse_pattern(cursor, plural, true)?;
+        cursor.leave();
+        cursor.expect(b'}')?;
+        variants.push(Variant {
+            keys: vec![key],
+            pattern,
+        });
+    }
+    if !variants.iter().any(|v| v.keys == [Key::CatchAll]) {
+        return Err(Error::MissingCatchAll(start));
+    }
+    Ok(variants)
+}
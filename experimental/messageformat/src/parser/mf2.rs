@@ -0,0 +1,340 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for the syntax of MessageFormat 2.
+
+use super::{Cursor, Text};
+use crate::ast::*;
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_datetime::options::length;
+
+pub(crate) fn parse(source: &str) -> Result<Message, Error> {
+    let mut cursor = Cursor::new(source);
+    cursor.skip_whitespace();
+    if cursor.peek() != Some(b'.') && !cursor.rest().starts_with("{{") {
+        // A simple message, whose leading and trailing whitespace is part of the pattern.
+        let mut cursor = Cursor::new(source);
+        let pattern = parse_pattern(&mut cursor, false)?;
+        return Ok(Message {
+            declarations: Vec::new(),
+            pattern,
+        });
+    }
+
+    let mut declarations: Vec<Declaration> = Vec::new();
+    loop {
+        cursor.skip_whitespace();
+        let start = cursor.pos;
+        let declaration = if cursor.eat_str(".input") {
+            cursor.skip_whitespace();
+            let expression = parse_placeholder(&mut cursor)?;
+            match expression.operand {
+                Operand::Variable(name) => Declaration { name, expression },
+                Operand::Literal(_) => return Err(Error::Syntax(start)),
+            }
+        } else if cursor.eat_str(".local") {
+            if !cursor.skip_whitespace() {
+                return Err(cursor.error());
+            }
+            cursor.expect(b'$')?;
+            let name = parse_name(&mut cursor)?;
+            cursor.skip_whitespace();
+            cursor.expect(b'=')?;
+            cursor.skip_whitespace();
+            let expression = parse_placeholder(&mut cursor)?;
+            Declaration { name, expression }
+        } else {
+            break;
+        };
+        if declarations.iter().any(|d| d.name == declaration.name) {
+            return Err(Error::Syntax(start));
+        }
+        declarations.push(declaration);
+    }
+
+    let start = cursor.pos;
+    let pattern = if cursor.eat_str(".match") {
+        Pattern {
+            parts: vec![PatternPart::Select(parse_matcher(&mut cursor, start)?)],
+        }
+    } else {
+        parse_quoted_pattern(&mut cursor)?
+    };
+    cursor.skip_whitespace();
+    if !cursor.is_done() {
+        return Err(cursor.error());
+    }
+    Ok(Message {
+        declarations,
+        pattern,
+    })
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn parse_name<'data>(cursor: &mut Cursor<'data>) -> Result<&'data str, Error> {
+    let name = cursor.take_while(is_name_char);
+    if name.is_empty() {
+        Err(cursor.error())
+    } else {
+        Ok(name)
+    }
+}
+
+/// Parses a pattern up to the end of the source, or up to the `}}` that closes it if it is
+/// quoted.
+fn parse_pattern<'data>(cursor: &mut Cursor<'data>, quoted: bool) -> Result<Pattern<'data>, Error> {
+    let mut parts = Vec::new();
+    let mut text = Text::default();
+    loop {
+        match cursor.peek() {
+            None if quoted => return Err(cursor.error()),
+            None => break,
+            Some(b'}') if quoted && cursor.peek_at(1) == Some(b'}') => break,
+            Some(b'}') => return Err(cursor.error()),
+            Some(b'{') => {
+                text.flush_into(&mut parts);
+                parts.push(PatternPart::Expression(parse_placeholder(cursor)?));
+            }
+            Some(b'\\') => parse_escape(cursor, &mut text)?,
+            Some(_) => text.push_str(cursor.take_while(|c| !matches!(c, '{' | '}' | '\\'))),
+        }
+    }
+    text.flush_into(&mut parts);
+    Ok(Pattern { parts })
+}
+
+fn parse_quoted_pattern<'data>(cursor: &mut Cursor<'data>) -> Result<Pattern<'data>, Error> {
+    if !cursor.eat_str("{{") {
+        return Err(cursor.error());
+    }
+    let pattern = parse_pattern(cursor, true)?;
+    if !cursor.eat_str("}}") {
+        return Err(cursor.error());
+    }
+    Ok(pattern)
+}
+
+/// Parses one of the escapes `\\`, `\{`, `\}`, and `\|`.
+fn parse_escape<'data>(cursor: &mut Cursor<'data>, text: &mut Text<'data>) -> Result<(), Error> {
+    let start = cursor.pos;
+    cursor.expect(b'\\')?;
+    match cursor.peek() {
+        Some(b'\\') | Some(b'{') | Some(b'}') | Some(b'|') => {
+            let escaped = cursor.pos;
+            cursor.bump();
+            text.push_str(cursor.slice_from(escaped));
+            Ok(())
+        }
+        _ => Err(Error::Syntax(start)),
+    }
+}
+
+/// Parses a quoted literal such as `|a b|`, or an unquoted literal such as `one` or `1.5`.
+fn parse_literal<'data>(cursor: &mut Cursor<'data>) -> Result<Cow<'data, str>, Error> {
+    if !cursor.eat(b'|') {
+        return parse_name_or_number(cursor).map(Cow::Borrowed);
+    }
+    let mut text = Text::default();
+    loop {
+        match cursor.peek() {
+            None => return Err(cursor.error()),
+            Some(b'|') => break,
+            Some(b'\\') => parse_escape(cursor, &mut text)?,
+            Some(_) => text.push_str(cursor.take_while(|c| !matches!(c, '|' | '\\'))),
+        }
+    }
+    cursor.bump();
+    Ok(text.take())
+}
+
+fn parse_name_or_number<'data>(cursor: &mut Cursor<'data>) -> Result<&'data str, Error> {
+    let literal = cursor.take_while(|c| is_name_char(c) || c == '+');
+    if literal.is_empty() {
+        Err(cursor.error())
+    } else {
+        Ok(literal)
+    }
+}
+
+/// Parses an expression in braces, such as `{$count :number}`.
+fn parse_placeholder<'data>(cursor: &mut Cursor<'data>) -> Result<Expression<'data>, Error> {
+    let start = cursor.pos;
+    cursor.expect(b'{')?;
+    cursor.skip_whitespace();
+    let operand = match cursor.peek() {
+        Some(b'$') => {
+            cursor.bump();
+            Operand::Variable(parse_name(cursor)?)
+        }
+        // Markup and expressions without an operand.
+        Some(b'#') | Some(b'/') | Some(b':') => return Err(Error::Unsupported(start)),
+        _ => Operand::Literal(parse_literal(cursor)?),
+    };
+    let function = if cursor.skip_whitespace() && cursor.peek() == Some(b':') {
+        cursor.bump();
+        Some(parse_function(cursor)?)
+    } else {
+        None
+    };
+    cursor.skip_whitespace();
+    if cursor.peek() == Some(b'@') {
+        return Err(Error::Unsupported(cursor.pos));
+    }
+    cursor.expect(b'}')?;
+    Ok(Expression { operand, function })
+}
+
+/// Parses a function and its options after the `:`.
+fn parse_function(cursor: &mut Cursor) -> Result<Function, Error> {
+    let start = cursor.pos;
+    let name = cursor.take_while(|c| is_name_char(c) || c == ':');
+    let mut options = Vec::new();
+    while cursor.skip_whitespace() && !matches!(cursor.peek(), Some(b'}') | Some(b'@') | None) {
+        let option_start = cursor.pos;
+        let key = parse_name(cursor)?;
+        cursor.skip_whitespace();
+        cursor.expect(b'=')?;
+        cursor.skip_whitespace();
+        if cursor.peek() == Some(b'$') {
+            return Err(Error::Unsupported(option_start));
+        }
+        options.push((key, parse_literal(cursor)?, option_start));
+    }
+
+    match name {
+        "number" | "integer" => {
+            let mut number = NumberOptions {
+                integer: name == "integer",
+                ..Default::default()
+            };
+            for (key, value, option_start) in options {
+                number.select = match (key, &*value) {
+                    ("select", "plural") => NumberSelect::Plural,
+                    ("select", "ordinal") => NumberSelect::Ordinal,
+                    ("select", "exact") => NumberSelect::Exact,
+                    _ => return Err(Error::Unsupported(option_start)),
+                };
+            }
+            Ok(Function::Number(number))
+        }
+        "date" | "time" | "datetime" => {
+            let mut date = None;
+            let mut time = None;
+            for (key, value, option_start) in options {
+                match (name, key) {
+                    ("date", "style") | ("datetime", "dateStyle") => {
+                        date =
+                            Some(parse_date_style(&value).ok_or(Error::Unsupported(option_start))?);
+                    }
+                    ("time", "style") | ("datetime", "timeStyle") => {
+                        time =
+                            Some(parse_time_style(&value).ok_or(Error::Unsupported(option_start))?);
+                    }
+                    _ => return Err(Error::Unsupported(option_start)),
+                }
+            }
+            Ok(Function::DateTime(match (name, date, time) {
+                (_, Some(date), Some(time)) => length::Bag::from_date_time_style(date, time),
+                (_, Some(date), None) => length::Bag::from_date_style(date),
+                (_, None, Some(time)) => length::Bag::from_time_style(time),
+                ("date", None, None) => length::Bag::from_date_style(length::Date::Medium),
+                ("time", None, None) => length::Bag::from_time_style(length::Time::Medium),
+                (_, None, None) => {
+                    length::Bag::from_date_time_style(length::Date::Medium, length::Time::Medium)
+                }
+            }))
+        }
+        "string" => match options.first() {
+            Some(&(_, _, option_start)) => Err(Error::Unsupported(option_start)),
+            None => Ok(Function::String),
+        },
+        _ => Err(Error::Unsupported(start)),
+    }
+}
+
+fn parse_date_style(style: &str) -> Option<length::Date> {
+    match style {
+        "short" => Some(length::Date::Short),
+        "medium" => Some(length::Date::Medium),
+        "long" => Some(length::Date::Long),
+        "full" => Some(length::Date::Full),
+        _ => None,
+    }
+}
+
+fn parse_time_style(style: &str) -> Option<length::Time> {
+    match style {
+        "short" => Some(length::Time::Short),
+        "medium" => Some(length::Time::Medium),
+        "long" => Some(length::Time::Long),
+        "full" => Some(length::Time::Full),
+        _ => None,
+    }
+}
+
+/// Parses the selectors and variants of a matcher after `.match`.
+fn parse_matcher<'data>(cursor: &mut Cursor<'data>, start: usize) -> Result<Select<'data>, Error> {
+    let mut selectors = Vec::new();
+    loop {
+        let space = cursor.skip_whitespace();
+        match cursor.peek() {
+            Some(b'$') if space => {
+                cursor.bump();
+                selectors.push(Expression {
+                    operand: Operand::Variable(parse_name(cursor)?),
+                    function: None,
+                });
+            }
+            Some(b'{') if cursor.peek_at(1) != Some(b'{') => {
+                selectors.push(parse_placeholder(cursor)?)
+            }
+            _ => break,
+        }
+    }
+    if selectors.is_empty() {
+        return Err(cursor.error());
+    }
+
+    let mut variants = Vec::new();
+    while !cursor.is_done() {
+        let variant_start = cursor.pos;
+        let mut keys = Vec::new();
+        loop {
+            keys.push(if cursor.eat(b'*') {
+                Key::CatchAll
+            } else {
+                Key::Literal(parse_literal(cursor)?)
+            });
+            let space = cursor.skip_whitespace();
+            if cursor.peek() == Some(b'{') {
+                break;
+            }
+            if !space {
+                return Err(cursor.error());
+            }
+        }
+        if keys.len() != selectors.len() {
+            return Err(Error::Syntax(variant_start));
+        }
+        let pattern = parse_quoted_pattern(cursor)?;
+        variants.push(Variant { keys, pattern });
+        cursor.skip_whitespace();
+    }
+    if !variants
+        .iter()
+        .any(|v| v.keys.iter().all(|k| *k == Key::CatchAll))
+    {
+        return Err(Error::MissingCatchAll(start));
+    }
+    Ok(Select {
+        selectors,
+        variants,
+    })
+}
@@ -0,0 +1,254 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsers for the message syntaxes.
+
+pub(crate) mod icu;
+pub(crate) mod mf2;
+
+use crate::ast::PatternPart;
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+/// The maximum depth of nested patterns, which guards against overflowing the stack while
+/// parsing deeply nested messages.
+const MAX_DEPTH: u8 = 64;
+
+/// A position in the source of a message.
+///
+/// The position is always on a character boundary: it only moves over ASCII bytes one at a
+/// time, and over other characters whole.
+struct Cursor<'data> {
+    source: &'data str,
+    pos: usize,
+    /// The number of patterns that the position is nested in.
+    depth: u8,
+}
+
+impl<'data> Cursor<'data> {
+    fn new(source: &'data str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    /// Enters a nested pattern, failing if patterns are nested too deeply.
+    fn enter(&mut self) -> Result<(), Error> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Leaves a nested pattern entered with [`Cursor::enter`].
+    fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.source.as_bytes().get(self.pos + offset).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    /// Moves past the current byte, which must be ASCII.
+    fn bump(&mut self) {
+        self.pos += 1;
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), Error> {
+        if self.eat(b) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn rest(&self) -> &'data str {
+        self.source.get(self.pos..).unwrap_or_default()
+    }
+
+    fn slice_from(&self, start: usize) -> &'data str {
+        self.source.get(start..self.pos).unwrap_or_default()
+    }
+
+    /// Moves past the characters that satisfy `predicate`, returning them.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'data str {
+        let start = self.pos;
+        let len = self
+            .rest()
+            .char_indices()
+            .find(|&(_, c)| !predicate(c))
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.rest().len());
+        self.pos += len;
+        self.slice_from(start)
+    }
+
+    /// Moves past whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        !self.take_while(char::is_whitespace).is_empty()
+    }
+
+    fn error(&self) -> Error {
+        Error::Syntax(self.pos)
+    }
+}
+
+/// Literal text that borrows from the source unless escapes have to be resolved.
+#[derive(Default)]
+struct Text<'data>(Cow<'data, str>);
+
+impl<'data> Text<'data> {
+    fn push_str(&mut self, s: &'data str) {
+        if self.0.is_empty() {
+            self.0 = Cow::Borrowed(s);
+        } else {
+            self.0.to_mut().push_str(s);
+        }
+    }
+
+    fn take(&mut self) -> Cow<'data, str> {
+        core::mem::take(&mut self.0)
+    }
+
+    /// Appends the text to `parts` if it is not empty.
+    fn flush_into(&mut self, parts: &mut Vec<PatternPart<'data>>) {
+        if !self.0.is_empty() {
+            parts.push(PatternPart::Text(self.take()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::*;
+    use crate::Error;
+    use alloc::borrow::Cow;
+
+    #[test]
+    fn test_borrowed_text() {
+        let message = Message::try_from_icu_syntax("Hello, {name}! It''s {n, number}.").unwrap();
+        assert!(matches!(
+            message.pattern.parts.as_slice(),
+            [
+                PatternPart::Text(Cow::Borrowed("Hello, ")),
+                PatternPart::Expression(Expression {
+                    operand: Operand::Variable("name"),
+                    function: None,
+                }),
+                PatternPart::Text(Cow::Owned(_)),
+                PatternPart::Expression(Expression {
+                    function: Some(Function::Number(_)),
+                    ..
+                }),
+                PatternPart::Text(Cow::Borrowed(".")),
+            ]
+        ));
+
+        let message =
+            Message::try_from_mf2_syntax(".match {$n :number} one {{a \\{b}} * {{c}}").unwrap();
+        let select = match message.pattern.parts.as_slice() {
+            [PatternPart::Select(select)] => select,
+            parts => panic!("{:?}", parts),
+        };
+        assert_eq!(
+            select.variants[0].keys,
+            [Key::Literal(Cow::Borrowed("one"))]
+        );
+        assert_eq!(
+            select.variants[0].pattern.parts,
+            [PatternPart::Text(Cow::Owned("a {b".into()))]
+        );
+        assert_eq!(select.variants[1].keys, [Key::CatchAll]);
+        assert_eq!(
+            select.variants[1].pattern.parts,
+            [PatternPart::Text(Cow::Borrowed("c"))]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for (message, expected) in [
+            ("{name", Error::Syntax(5)),
+            ("name}", Error::Syntax(4)),
+            ("{n, spellout}", Error::Unsupported(4)),
+            ("{n, number, percent}", Error::Unsupported(12)),
+            ("{n, plural, one {#}}", Error::MissingCatchAll(0)),
+            ("{n, plural, single {#} other {#}}", Error::Syntax(12)),
+            ("{n, select, a {x} other {y}", Error::Syntax(27)),
+        ] {
+            let error = Message::try_from_icu_syntax(message).unwrap_err();
+            assert_eq!(
+                format!("{:?}", error),
+                format!("{:?}", expected),
+                "{}",
+                message
+            );
+        }
+
+        for (message, expected) in [
+            ("{$name", Error::Syntax(6)),
+            ("a } b", Error::Syntax(2)),
+            ("\\n", Error::Syntax(0)),
+            ("{$n :percent}", Error::Unsupported(5)),
+            ("{$n :number style=percent}", Error::Unsupported(12)),
+            ("{#b}", Error::Unsupported(0)),
+            (".match {$n :number} one {{x}}", Error::MissingCatchAll(0)),
+            (".match {$n :number} one * {{x}}", Error::Syntax(20)),
+            (".local $a = {1} .local $a = {2} {{x}}", Error::Syntax(16)),
+            ("{{x}} y", Error::Syntax(6)),
+        ] {
+            let error = Message::try_from_mf2_syntax(message).unwrap_err();
+            assert_eq!(
+                format!("{:?}", error),
+                format!("{:?}", expected),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_depth() {
+        let nested = |depth: usize| "{a, select, other {".repeat(depth) + "x" + &"}}".repeat(depth);
+        assert!(Message::try_from_icu_syntax(&nested(64)).is_ok());
+        assert!(matches!(
+            Message::try_from_icu_syntax(&nested(65)),
+            Err(Error::Syntax(1235))
+        ));
+        assert!(matches!(
+            Message::try_from_icu_syntax(&nested(100_000)),
+            Err(Error::Syntax(_))
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for rule-based number formatting.
+
+use displaydoc::Display;
+
+/// A list of possible error outcomes for operations in this crate.
+#[derive(Display, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// An error loading the plural rules of the locale.
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
+    /// An error loading the number formatter of the locale.
+    #[displaydoc("error loading number format: {0}")]
+    Decimal(icu_decimal::FixedDecimalFormatError),
+    /// The locale has no public rule set of the name.
+    #[displaydoc("unknown rule set")]
+    UnknownRuleSet,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<icu_decimal::FixedDecimalFormatError> for Error {
+    fn from(e: icu_decimal::FixedDecimalFormatError) -> Self {
+        Error::Decimal(e)
+    }
+}
@@ -0,0 +1,621 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Evaluation of rule sets on [`FixedDecimal`].
+
+use crate::provider::*;
+use crate::RbnfError;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, Sign, Signum};
+use icu_decimal::options::{FixedDecimalFormatOptions, GroupingStrategy};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+use writeable::Writeable;
+use zerovec::maps::ZeroMap2dCursor;
+use zerovec::ule::AsULE;
+
+/// The maximum depth of nested substitutions, which guards against rule sets that refer to
+/// each other in a cycle.
+const MAX_DEPTH: u8 = 64;
+
+/// A formatter for [`FixedDecimal`] with a rule set of CLDR rule-based number formatting, such
+/// as "one thousand two hundred thirty-four" with `spellout-numbering`, "twenty-first" with
+/// `spellout-ordinal`, or "21st" with `digits-ordinal`.
+///
+/// Numbers are formatted following
+/// [UTS 35](https://unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting).
+/// If a rule set has no rule for numbers with a fraction, it applies the rule of the rounded
+/// number, which may still pass the number on to another rule set with `=…=`. Numbers that are
+/// too large for the rule set are formatted with digits.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_rbnf::RuleBasedNumberFormat;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let rbnf = RuleBasedNumberFormat::try_new(locale!("en"), &provider, "spellout-numbering")
+///     .expect("Data should load successfully");
+/// assert_eq!(
+///     "one thousand two hundred thirty-four",
+///     rbnf.format(&1234.into()).write_to_string()
+/// );
+///
+/// let rbnf = RuleBasedNumberFormat::try_new(locale!("en"), &provider, "spellout-ordinal")
+///     .expect("Data should load successfully");
+/// assert_eq!("twenty-first", rbnf.format(&21.into()).write_to_string());
+///
+/// let rbnf = RuleBasedNumberFormat::try_new(locale!("en"), &provider, "digits-ordinal")
+///     .expect("Data should load successfully");
+/// assert_eq!("1,002nd", rbnf.format(&1002.into()).write_to_string());
+/// ```
+pub struct RuleBasedNumberFormat {
+    rules: DataPayload<RbnfRulesV1Marker>,
+    rule_set: String,
+    grouped: FixedDecimalFormat,
+    ungrouped: FixedDecimalFormat,
+    cardinal: PluralRules,
+    ordinal: PluralRules,
+}
+
+impl RuleBasedNumberFormat {
+    /// Creates a new [`RuleBasedNumberFormat`] for a public rule set of the locale, such as
+    /// `spellout-numbering`, `spellout-ordinal`, or `digits-ordinal`.
+    ///
+    /// Returns [`RbnfError::UnknownRuleSet`] if the locale has no public rule set of the name.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        rule_set: &str,
+    ) -> Result<Self, RbnfError>
+    where
+        D: ResourceProvider<RbnfRulesV1Marker>
+            + ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ResourceProvider<OrdinalV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let rules: DataPayload<RbnfRulesV1Marker> = data_provider
+            .load_resource(&DataRequest {
+                options: (&locale).into(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        // Private rule sets are stored with a leading `%`.
+        if rule_set.starts_with('%') || !rules.get().rules.contains_key0(rule_set) {
+            return Err(RbnfError::UnknownRuleSet);
+        }
+        let cardinal = PluralRules::try_new_cardinal(locale.id.clone(), data_provider)?;
+        let ordinal = PluralRules::try_new_ordinal(locale.id.clone(), data_provider)?;
+        let grouped =
+            FixedDecimalFormat::try_new(locale.clone(), data_provider, Default::default())?;
+        let mut options = FixedDecimalFormatOptions::default();
+        options.grouping_strategy = GroupingStrategy::Never;
+        let ungrouped = FixedDecimalFormat::try_new(locale, data_provider, options)?;
+        Ok(Self {
+            rules,
+            rule_set: rule_set.to_string(),
+            grouped,
+            ungrouped,
+            cardinal,
+            ordinal,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] with the rule set, returning a
+    /// [`FormattedRuleBasedNumber`].
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedRuleBasedNumber<'l> {
+        FormattedRuleBasedNumber {
+            formatter: self,
+            value,
+        }
+    }
+
+    /// Writes a number with a rule set.
+    fn write_value<W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        rule_set: &str,
+        value: &FixedDecimal,
+        depth: u8,
+    ) -> fmt::Result {
+        let rules = match self.rules.get().rules.get0(rule_set) {
+            Some(rules) if depth <= MAX_DEPTH => rules,
+            _ => return self.grouped.format(value).write_to(sink),
+        };
+        let find = |kind| iter_rules(&rules).find(|(_, rule)| rule.kind == kind);
+
+        if value.signum() == Signum::BelowZero {
+            let absolute = value.clone().with_sign(Sign::None);
+            return match find(RuleKind::Negative) {
+                Some((index, rule)) => self.write_rule(
+                    sink,
+                    &rules,
+                    index,
+                    &rule,
+                    &Operand::Decimal(&absolute),
+                    depth,
+                ),
+                None => {
+                    sink.write_char('-')?;
+                    self.write_value(sink, rule_set, &absolute, depth + 1)
+                }
+            };
+        }
+
+        if value.nonzero_magnitude_right() < 0 {
+            let rule = if value.nonzero_magnitude_left() < 0 {
+                find(RuleKind::ProperFraction).or_else(|| find(RuleKind::ImproperFraction))
+            } else {
+                find(RuleKind::ImproperFraction)
+            };
+            if let Some((index, rule)) = rule {
+                return self.write_rule(
+                    sink,
+                    &rules,
+                    index,
+                    &rule,
+                    &Operand::Decimal(value),
+                    depth,
+                );
+            }
+        }
+
+        let n = match to_integer(value) {
+            Some(n) => n,
+            None => return self.grouped.format(value).write_to(sink),
+        };
+        // The last normal rule whose base value does not exceed the number.
+        let rule = iter_rules(&rules)
+            .filter(|(_, rule)| rule.kind == RuleKind::Normal && rule.base_value <= n)
+            .last();
+        let rule = match rule {
+            // A rule with a remainder substitution whose base value is not a multiple of its
+            // divisor, such as a rule of 110 with the divisor 100, does not apply to multiples
+            // of the divisor; the preceding rule applies instead.
+            Some((index, rule))
+                if rule.body.contains('>')
+                    && n % divisor(&rule) == 0
+                    && rule.base_value % divisor(&rule) != 0 =>
+            {
+                index
+                    .checked_sub(1)
+                    .and_then(|previous| get_rule(&rules, previous))
+                    .or(Some((index, rule)))
+            }
+            rule => rule,
+        };
+        match rule {
+            Some((index, rule)) => self.write_rule(
+                sink,
+                &rules,
+                index,
+                &rule,
+                &Operand::Integer(n, value),
+                depth,
+            ),
+            None => self.grouped.format(value).write_to(sink),
+        }
+    }
+
+    /// Writes a number with a rule.
+    fn write_rule<'l, W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        rules: &ZeroMap2dCursor<'l, 'l, str, u16, RuleULE>,
+        index: u16,
+        rule: &Rule,
+        operand: &Operand,
+        depth: u8,
+    ) -> fmt::Result {
+        let rule_set = rules.key0();
+        let depth = depth + 1;
+        let mut body = rule.body.as_ref();
+        loop {
+            let mut chars = body.chars();
+            let c = match chars.next() {
+                Some(c) => c,
+                None => return Ok(()),
+            };
+            let rest = chars.as_str();
+            match c {
+                '[' => {
+                    body = if operand.omits_optional(rule) {
+                        rest.split_once(']').map_or("", |(_, after)| after)
+                    } else {
+                        rest
+                    };
+                }
+                ']' => body = rest,
+                '$' if rest.starts_with('(') => {
+                    let (plural, after) = rest.split_once(")$").unwrap_or((rest, ""));
+                    let plural = plural.strip_prefix('(').unwrap_or(plural);
+                    self.write_plural(sink, plural, operand, rule)?;
+                    body = after;
+                }
+                '<' | '>' | '=' => {
+                    // `>>>` formats the remainder with the preceding rule.
+                    if let Some(after) = body.strip_prefix(">>>") {
+                        if let Operand::Integer(n, _) = operand {
+                            let remainder = n % divisor(rule);
+                            match index.checked_sub(1).and_then(|i| get_rule(rules, i)) {
+                                Some((previous_index, previous)) => self.write_rule(
+                                    sink,
+                                    rules,
+                                    previous_index,
+                                    &previous,
+                                    &Operand::Integer(remainder, &remainder.into()),
+                                    depth,
+                                )?,
+                                None => {
+                                    self.write_value(sink, rule_set, &remainder.into(), depth)?
+                                }
+                            }
+                        } else {
+                            self.write_fraction_digits(sink, rule_set, operand, "", depth)?;
+                        }
+                        body = after;
+                        continue;
+                    }
+                    let (description, after) = rest.split_once(c).unwrap_or((rest, ""));
+                    let target = match description.strip_prefix('%') {
+                        Some(name) => Target::RuleSet(name),
+                        None if description.is_empty() => Target::RuleSet(rule_set),
+                        None => Target::Decimal(description),
+                    };
+                    self.write_substitution(sink, c, target, rule_set, operand, rule, depth)?;
+                    body = after;
+                }
+                _ => {
+                    // A `$` that does not start a plural is literal text.
+                    let end = rest
+                        .find(['[', ']', '$', '<', '>', '='])
+                        .unwrap_or(rest.len());
+                    let (text, after) = rest.split_at(end);
+                    sink.write_char(c)?;
+                    sink.write_str(text)?;
+                    body = after;
+                }
+            }
+        }
+    }
+
+    /// Writes the substitution `<<`, `>>`, or `==` of a rule, with a rule set or a decimal
+    /// pattern.
+    #[allow(clippy::too_many_arguments)]
+    fn write_substitution<W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        token: char,
+        target: Target,
+        rule_set: &str,
+        operand: &Operand,
+        rule: &Rule,
+        depth: u8,
+    ) -> fmt::Result {
+        let value: FixedDecimal = match (token, operand) {
+            ('<', Operand::Integer(n, _)) => (n / divisor(rule)).into(),
+            ('>', Operand::Integer(n, _)) => (n % divisor(rule)).into(),
+            ('=', Operand::Integer(_, value)) => (*value).clone(),
+            ('<', Operand::Decimal(value)) => {
+                (*value).clone().truncated_right(0).with_sign(Sign::None)
+            }
+            ('>', Operand::Decimal(_)) if rule.kind != RuleKind::Negative => {
+                let separator = match target {
+                    Target::RuleSet(_) => " ",
+                    Target::Decimal(_) => "",
+                };
+                return self.write_fraction_digits(sink, rule_set, operand, separator, depth);
+            }
+            (_, Operand::Decimal(value)) => (*value).clone(),
+            _ => return Ok(()),
+        };
+        match target {
+            Target::RuleSet(name) => self.write_value(sink, name, &value, depth),
+            Target::Decimal(pattern) => self.write_decimal(sink, pattern, value),
+        }
+    }
+
+    /// Writes every fraction digit of a number with a rule set, such as "one two" for 0.12.
+    fn write_fraction_digits<W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        rule_set: &str,
+        operand: &Operand,
+        separator: &str,
+        depth: u8,
+    ) -> fmt::Result {
+        let value = match operand {
+            Operand::Decimal(value) => value,
+            Operand::Integer(..) => return Ok(()),
+        };
+        for magnitude in (value.nonzero_magnitude_right()..0).rev() {
+            if magnitude != -1 {
+                sink.write_str(separator)?;
+            }
+            self.write_value(sink, rule_set, &value.digit_at(magnitude).into(), depth)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a number with a decimal pattern, such as `#,##0` or `#,##0.#`.
+    fn write_decimal<W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        pattern: &str,
+        mut value: FixedDecimal,
+    ) -> fmt::Result {
+        let fraction = pattern.split_once('.').map_or("", |(_, fraction)| fraction);
+        let maximum = fraction.chars().filter(|&c| c == '#' || c == '0').count() as i16;
+        let minimum = fraction.chars().filter(|&c| c == '0').count() as i16;
+        value.half_even(-maximum);
+        value.strip_right();
+        value.pad_right(-minimum);
+        if pattern.contains(',') {
+            self.grouped.format(&value).write_to(sink)
+        } else {
+            self.ungrouped.format(&value).write_to(sink)
+        }
+    }
+
+    /// Writes the text of a plural substitution, such as `ordinal,one{st}two{nd}other{th}`,
+    /// for the category of the number divided by the divisor of the rule.
+    fn write_plural<W: Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        plural: &str,
+        operand: &Operand,
+        rule: &Rule,
+    ) -> fmt::Result {
+        let (plural_type, mut cases) = plural.split_once(',').unwrap_or(("", plural));
+        let plural_rules = match plural_type {
+            "ordinal" => &self.ordinal,
+            _ => &self.cardinal,
+        };
+        let category = match operand {
+            Operand::Integer(n, _) => plural_rules.select(n / divisor(rule)),
+            Operand::Decimal(value) => plural_rules.select(*value),
+        };
+        let mut other = "";
+        while let Some((keyword, rest)) = cases.split_once('{') {
+            let (text, rest) = rest.split_once('}').unwrap_or((rest, ""));
+            match PluralCategory::from_tr35_string(keyword) {
+                Some(c) if c == category => return sink.write_str(text),
+                Some(PluralCategory::Other) => other = text,
+                _ => (),
+            }
+            cases = rest;
+        }
+        sink.write_str(other)
+    }
+}
+
+/// The number to which a rule applies.
+enum Operand<'a> {
+    /// A non-negative number rounded to an integer, for normal rules, along with the number
+    /// itself for `==` substitutions.
+    Integer(u64, &'a FixedDecimal),
+    /// Any number, for negative number and fraction rules.
+    Decimal(&'a FixedDecimal),
+}
+
+impl Operand<'_> {
+    /// Returns whether the optional text of a rule in `[]` is omitted, which is the case if
+    /// the remainder of a normal rule is zero, or the integer part of a fraction rule is zero.
+    fn omits_optional(&self, rule: &Rule) -> bool {
+        match self {
+            Operand::Integer(n, _) => n % divisor(rule) == 0,
+            Operand::Decimal(value) => value.nonzero_magnitude_left() < 0 || value.is_zero(),
+        }
+    }
+}
+
+/// The rule set or decimal pattern of a substitution.
+enum Target<'a> {
+    /// A rule set, by its name without the first `%`.
+    RuleSet(&'a str),
+    /// A decimal pattern, such as `#,##0`.
+    Decimal(&'a str),
+}
+
+/// Returns the rules of a rule set with their indices, in order.
+fn iter_rules<'l>(
+    rules: &'l ZeroMap2dCursor<'l, 'l, str, u16, RuleULE>,
+) -> impl Iterator<Item = (u16, Rule<'l>)> + 'l {
+    rules
+        .iter1()
+        .map(|(index, rule)| (u16::from_unaligned(*index), Rule::zero_from(rule)))
+}
+
+/// Returns the rule of a rule set at an index, if any.
+fn get_rule<'l>(
+    rules: &ZeroMap2dCursor<'l, 'l, str, u16, RuleULE>,
+    index: u16,
+) -> Option<(u16, Rule<'l>)> {
+    rules
+        .get1(&index)
+        .map(|rule| (index, Rule::zero_from(rule)))
+}
+
+/// Returns the divisor of a rule. Invalid data with a divisor of 0 is treated as 1 so that the
+/// substitutions do not divide by zero.
+fn divisor(rule: &Rule) -> u64 {
+    rule.divisor.max(1)
+}
+
+/// Returns a non-negative number rounded to an integer, if it fits into a `u64`.
+fn to_integer(value: &FixedDecimal) -> Option<u64> {
+    let value = value.clone().half_evened(0);
+    let mut result = 0u64;
+    for magnitude in (0..=value.nonzero_magnitude_left().max(0)).rev() {
+        result = result
+            .checked_mul(10)?
+            .checked_add(u64::from(value.digit_at(magnitude)))?;
+    }
+    Some(result)
+}
+
+/// An intermediate structure returned by [`RuleBasedNumberFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+pub struct FormattedRuleBasedNumber<'l> {
+    formatter: &'l RuleBasedNumberFormat,
+    value: &'l FixedDecimal,
+}
+
+impl<'l> Writeable for FormattedRuleBasedNumber<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        self.formatter
+            .write_value(sink, &self.formatter.rule_set, self.value, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    fn format(locale: Locale, rule_set: &str, value: &str) -> String {
+        let provider = icu_testdata::get_provider();
+        RuleBasedNumberFormat::try_new(locale, &provider, rule_set)
+            .unwrap()
+            .format(&value.parse().unwrap())
+            .write_to_string()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_spellout_numbering() {
+        for (value, expected) in [
+            ("0", "zero"),
+            ("7", "seven"),
+            ("13", "thirteen"),
+            ("40", "forty"),
+            ("42", "forty-two"),
+            ("100", "one hundred"),
+            ("101", "one hundred one"),
+            ("999", "nine hundred ninety-nine"),
+            ("1000", "one thousand"),
+            ("1234", "one thousand two hundred thirty-four"),
+            ("2000000", "two million"),
+            ("-15", "minus fifteen"),
+            ("3.14", "three point one four"),
+            ("0.5", "zero point five"),
+            ("1000000000000000000", "1,000,000,000,000,000,000"),
+            ("123456789012345678901", "123,456,789,012,345,678,901"),
+        ] {
+            assert_eq!(
+                format(locale!("en"), "spellout-numbering", value),
+                expected,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_spellout_ordinal() {
+        for (value, expected) in [
+            ("1", "first"),
+            ("2", "second"),
+            ("12", "twelfth"),
+            ("13", "thirteenth"),
+            ("20", "twentieth"),
+            ("21", "twenty-first"),
+            ("99", "ninety-ninth"),
+            ("100", "one hundredth"),
+            ("103", "one hundred third"),
+            ("1000", "one thousandth"),
+            ("1200", "one thousand two hundredth"),
+            // Numbers with a fraction are formatted with digits by the `x.x` rule.
+            ("2.5", "2.5"),
+        ] {
+            assert_eq!(
+                format(locale!("en"), "spellout-ordinal", value),
+                expected,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_digits_ordinal() {
+        for (locale, value, expected) in [
+            (locale!("en"), "1", "1st"),
+            (locale!("en"), "2", "2nd"),
+            (locale!("en"), "3", "3rd"),
+            (locale!("en"), "11", "11th"),
+            (locale!("en"), "23", "23rd"),
+            (locale!("en"), "1000", "1,000th"),
+            // Without a rule for fractions, numbers are rounded half to even.
+            (locale!("en"), "2.5", "2nd"),
+            (locale!("en"), "-1", "−1st"),
+            (locale!("fr"), "1", "1er"),
+            (locale!("fr"), "2", "2e"),
+            (locale!("fr"), "1000", "1\u{202f}000e"),
+            (locale!("ja"), "3", "第3"),
+        ] {
+            assert_eq!(
+                format(locale, "digits-ordinal", value),
+                expected,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_rule_set() {
+        let provider = icu_testdata::get_provider();
+        for rule_set in ["unknown", "%tieth"] {
+            assert!(matches!(
+                RuleBasedNumberFormat::try_new(locale!("en"), &provider, rule_set),
+                Err(RbnfError::UnknownRuleSet)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let provider = icu_testdata::get_provider();
+        let mut data = RbnfRulesV1::default();
+        for (index, base_value, body) in [
+            (0, 0, "=#,##0="),
+            (1, 10, "$(cardinal,one{one}other{many})$[ >>]"),
+        ] {
+            let rule = Rule {
+                kind: RuleKind::Normal,
+                base_value,
+                divisor: 0,
+                body: body.into(),
+            };
+            data.rules.insert(
+                "spellout-numbering",
+                &index,
+                &*zerovec::ule::encode_varule_to_box(&rule),
+            );
+        }
+        let rbnf = RuleBasedNumberFormat {
+            rules: DataPayload::from_owned(data),
+            ..RuleBasedNumberFormat::try_new(locale!("en"), &provider, "spellout-numbering")
+                .unwrap()
+        };
+        // A divisor of 0 is treated as 1.
+        assert_eq!(rbnf.format(&7.into()).write_to_string(), "7");
+        assert_eq!(rbnf.format(&42.into()).write_to_string(), "many");
+    }
+}
@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![warn(missing_docs)]
+
+//! [`icu_rbnf`](crate) offers rule-based number formatting, such as spelled-out numbers and
+//! ordinal numbers.
+//!
+//! [`RuleBasedNumberFormat`] evaluates a rule set of the
+//! [CLDR RBNF data](https://unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting)
+//! on a [`FixedDecimal`](fixed_decimal::FixedDecimal). The rule sets are compiled into
+//! zero-copy data by `icu_datagen`; this crate supports the public rule sets
+//! `spellout-numbering`, `spellout-ordinal`, and `digits-ordinal` along with the private rule
+//! sets they refer to.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_rbnf::RuleBasedNumberFormat;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let rbnf = RuleBasedNumberFormat::try_new(locale!("en"), &provider, "spellout-numbering")
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!("forty-two", rbnf.format(&42.into()).write_to_string());
+//! assert_eq!("minus three point five", rbnf.format(&"-3.5".parse().unwrap()).write_to_string());
+//!
+//! let rbnf = RuleBasedNumberFormat::try_new(locale!("fr"), &provider, "digits-ordinal")
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!("1er", rbnf.format(&1.into()).write_to_string());
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums
+    )
+)]
+
+extern crate alloc;
+
+pub mod error;
+pub mod format;
+pub mod provider;
+
+pub use error::Error as RbnfError;
+pub use format::{FormattedRuleBasedNumber, RuleBasedNumberFormat};
@@ -0,0 +1,96 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+// The ULE types generated by `make_varule` slice their bytes and have undocumented fields.
+#![allow(clippy::indexing_slicing, missing_docs)] // TODO(#1668) Clippy exceptions need docs or fixing.
+
+use alloc::borrow::Cow;
+use icu_provider::{yoke, zerofrom};
+use zerofrom::ZeroFrom;
+use zerovec::ZeroMap2d;
+
+/// The rule sets of a locale for spelling out numbers and for ordinal numbers, such as
+/// `spellout-numbering` or `digits-ordinal`.
+#[icu_provider::data_struct(RbnfRulesV1Marker = "rbnf/rules@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_rbnf::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RbnfRulesV1<'data> {
+    /// The rules keyed by the name of their rule set and their index in it.
+    ///
+    /// Rule set names are stored without their first `%`, such as `spellout-numbering` for
+    /// the public rule set `%spellout-numbering` and `%tieth` for the private rule set
+    /// `%%tieth`. The normal rules of a rule set are sorted by their base value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap2d<'data, str, u16, RuleULE>,
+}
+
+/// The kind of numbers to which a rule applies.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_rbnf::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(RuleKindULE)]
+#[repr(u8)]
+#[allow(clippy::exhaustive_enums)] // mirrors the rule descriptors of UTS 35
+pub enum RuleKind {
+    /// Integers of at least the base value, up to the base value of the next normal rule.
+    Normal = 0,
+    /// Negative numbers, with the descriptor `-x`.
+    Negative = 1,
+    /// Numbers with a fraction, with the descriptor `x.x`.
+    ImproperFraction = 2,
+    /// Numbers between 0 and 1, with the descriptor `0.x`.
+    ProperFraction = 3,
+}
+
+/// A rule of a rule set, such as `<< hundred[ >>]` for integers from 100.
+///
+/// Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting>
+#[zerovec::make_varule(RuleULE)]
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_rbnf::provider),
+)]
+pub struct Rule<'data> {
+    /// The kind of numbers to which the rule applies.
+    pub kind: RuleKind,
+    /// The smallest number to which a normal rule applies, and 0 for other rules.
+    pub base_value: u64,
+    /// The power of the radix by which the number is divided for the substitutions `<<` and
+    /// `>>`, such as 100 for the rule of 100, and 1 for rules other than normal rules.
+    pub divisor: u64,
+    /// The rule text, without the terminating `;` or a leading `'`, and with the arrows `←`,
+    /// `→`, and `≡` replaced by `<`, `>`, and `=`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub body: Cow<'data, str>,
+}
+
+impl core::fmt::Debug for RuleULE {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Rule::zero_from(self).fmt(f)
+    }
+}
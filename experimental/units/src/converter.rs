@@ -0,0 +1,519 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Exact conversion between units, such as from kilometers per hour to miles per hour.
+
+use crate::provider::*;
+use crate::ratio::Ratio;
+use crate::UnitsError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+use zerofrom::ZeroFrom;
+
+/// The SI and binary prefixes of UTS 35 unit identifiers, with their base and exponent.
+const PREFIXES: &[(&str, i128, i32)] = &[
+    ("yotta", 10, 24),
+    ("zetta", 10, 21),
+    ("exa", 10, 18),
+    ("peta", 10, 15),
+    ("tera", 10, 12),
+    ("giga", 10, 9),
+    ("mega", 10, 6),
+    ("kilo", 10, 3),
+    ("hecto", 10, 2),
+    ("deka", 10, 1),
+    ("deci", 10, -1),
+    ("centi", 10, -2),
+    ("milli", 10, -3),
+    ("micro", 10, -6),
+    ("nano", 10, -9),
+    ("pico", 10, -12),
+    ("femto", 10, -15),
+    ("atto", 10, -18),
+    ("zepto", 10, -21),
+    ("yocto", 10, -24),
+    ("kibi", 2, 10),
+    ("mebi", 2, 20),
+    ("gibi", 2, 30),
+    ("tebi", 2, 40),
+    ("pebi", 2, 50),
+    ("exbi", 2, 60),
+    ("zebi", 2, 70),
+    ("yobi", 2, 80),
+];
+
+fn overflow<T>(value: Option<T>) -> Result<T, UnitsError> {
+    value.ok_or(UnitsError::Overflow)
+}
+
+/// Evaluates a factor or offset of [`ConversionInfo`], such as `0.3048/12`.
+fn evaluate(expression: &str) -> Result<Ratio, UnitsError> {
+    if expression.is_empty() {
+        return Ok(Ratio::ZERO);
+    }
+    let product = |factors: &str| {
+        factors.split('*').try_fold(Ratio::ONE, |acc, factor| {
+            let factor = factor.trim().parse::<Ratio>().map_err(|_| {
+                DataError::custom("Invalid conversion factor").with_display_context(expression)
+            })?;
+            overflow(acc.mul(factor))
+        })
+    };
+    match expression.split_once('/') {
+        Some((numerator, denominator)) => overflow(product(numerator)?.div(product(denominator)?)),
+        None => product(expression),
+    }
+}
+
+/// A unit expressed in base units, such as `1000 meter` for `kilometer`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Resolved {
+    factor: Ratio,
+    offset: Ratio,
+    /// The exponents of the base units, such as `meter: 1, second: -1` for
+    /// `kilometer-per-hour`, sorted by base unit.
+    dimensions: Vec<(String, i32)>,
+}
+
+impl Resolved {
+    fn unit() -> Self {
+        Self {
+            factor: Ratio::ONE,
+            offset: Ratio::ZERO,
+            dimensions: Vec::new(),
+        }
+    }
+
+    /// Multiplies by another unit raised to a power.
+    fn mul(&mut self, other: &Resolved, power: i32) -> Result<(), UnitsError> {
+        self.factor = overflow(self.factor.mul(overflow(other.factor.pow(power))?))?;
+        for (unit, exponent) in other.dimensions.iter() {
+            match self
+                .dimensions
+                .binary_search_by(|(u, _)| u.as_str().cmp(unit))
+            {
+                Ok(i) => {
+                    if let Some((_, e)) = self.dimensions.get_mut(i) {
+                        *e += exponent * power;
+                    }
+                }
+                Err(i) => self.dimensions.insert(i, (unit.clone(), exponent * power)),
+            }
+        }
+        self.dimensions.retain(|(_, exponent)| *exponent != 0);
+        Ok(())
+    }
+
+    /// Returns whether the units have opposite dimensions, such as `liter-per-kilometer` and
+    /// `mile-per-gallon`.
+    fn is_reciprocal_of(&self, other: &Resolved) -> bool {
+        self.dimensions.len() == other.dimensions.len()
+            && self
+                .dimensions
+                .iter()
+                .zip(other.dimensions.iter())
+                .all(|((u1, e1), (u2, e2))| u1 == u2 && *e1 == -*e2)
+    }
+}
+
+/// Resolves a simple unit, possibly with an SI or binary prefix, such as `kilometer`.
+fn resolve_simple(data: &UnitsConversionV1, unit: &str) -> Result<Option<Resolved>, UnitsError> {
+    let (info, prefix) = match data.conversions.get(unit) {
+        Some(info) => (info, Ratio::ONE),
+        None => match PREFIXES.iter().find_map(|(prefix, base, exponent)| {
+            let info = data.conversions.get(unit.strip_prefix(prefix)?)?;
+            Some((info, Ratio::from_integer(*base).pow(*exponent)))
+        }) {
+            Some((info, Some(prefix))) => (info, prefix),
+            Some((_, None)) => return Err(UnitsError::Overflow),
+            None => return Ok(None),
+        },
+    };
+    let info = ConversionInfo::zero_from(info);
+    let mut resolved = if info.base_unit == unit {
+        Resolved {
+            factor: Ratio::ONE,
+            offset: Ratio::ZERO,
+            dimensions: alloc::vec![(unit.to_string(), 1)],
+        }
+    } else {
+        resolve(data, &info.base_unit)?
+    };
+    resolved.factor = overflow(
+        resolved
+            .factor
+            .mul(evaluate(&info.factor)?)
+            .and_then(|f| f.mul(prefix)),
+    )?;
+    resolved.offset = evaluate(&info.offset)?;
+    Ok(Some(resolved))
+}
+
+/// Resolves a product of units, such as `kilogram-square-meter`. The offset is kept only for a
+/// single simple unit, such as `celsius`.
+fn resolve_product(data: &UnitsConversionV1, unit: &str) -> Result<Resolved, UnitsError> {
+    let mut resolved = Resolved::unit();
+    if unit.is_empty() {
+        return Ok(resolved);
+    }
+    let parts: Vec<&str> = unit.split('-').collect();
+    let mut offset = None;
+    let mut i = 0;
+    while i < parts.len() {
+        let power = match parts.get(i).copied() {
+            Some("square") => 2,
+            Some("cubic") => 3,
+            Some(part) => part
+                .strip_prefix("pow")
+                .and_then(|n| n.parse::<i32>().ok())
+                .filter(|n| (2..=15).contains(n))
+                .unwrap_or(1),
+            None => break,
+        };
+        if power != 1 {
+            i += 1;
+        }
+
+        // A constant factor, such as `100` in `liter-per-100-kilometer`
+        if let Some(constant) = parts.get(i).and_then(|part| part.parse::<u64>().ok()) {
+            let constant = Ratio::from_integer(constant.into());
+            resolved.factor = overflow(resolved.factor.mul(overflow(constant.pow(power))?))?;
+            i += 1;
+            continue;
+        }
+
+        // Simple units can contain hyphens, such as `light-year`, so take the longest match.
+        let mut simple = None;
+        for j in (i + 1..=parts.len()).rev() {
+            let name = parts.get(i..j).ok_or(UnitsError::UnknownUnit)?.join("-");
+            if let Some(s) = resolve_simple(data, &name)? {
+                simple = Some((s, j));
+                break;
+            }
+        }
+        let (simple, j) = simple.ok_or(UnitsError::UnknownUnit)?;
+        offset = match offset {
+            None if power == 1 => Some(simple.offset),
+            _ => Some(Ratio::ZERO),
+        };
+        resolved.mul(&simple, power)?;
+        i = j;
+    }
+    resolved.offset = offset.unwrap_or(Ratio::ZERO);
+    Ok(resolved)
+}
+
+/// Resolves a unit identifier to base units, such as `kilometer-per-hour`.
+pub(crate) fn resolve(data: &UnitsConversionV1, unit: &str) -> Result<Resolved, UnitsError> {
+    let (numerator, denominator) = match unit.strip_prefix("per-") {
+        Some(denominator) => ("", denominator),
+        None => unit.split_once("-per-").unwrap_or((unit, "")),
+    };
+    let mut resolved = resolve_product(data, numerator)?;
+    if !denominator.is_empty() {
+        resolved.mul(&resolve_product(data, denominator)?, -1)?;
+        resolved.offset = Ratio::ZERO;
+    }
+    Ok(resolved)
+}
+
+#[derive(Debug)]
+enum Conversion {
+    /// `output = input * factor + offset`
+    Linear { factor: Ratio, offset: Ratio },
+    /// `output = factor / input`
+    Reciprocal { factor: Ratio },
+}
+
+/// A converter of values from one unit to another with exact rational arithmetic, such as from
+/// `kilometer-per-hour` to `mile-per-hour`.
+///
+/// Units are given as CLDR unit identifiers. Prefixed and compound units are converted from the
+/// conversions of their simple units, such as `kilometer` from `meter`, and `mile-per-gallon`
+/// from `mile` and `gallon`. Units for reciprocal quantities can be converted into each other,
+/// such as `liter-per-100-kilometer` and `mile-per-gallon`.
+///
+/// The output unit may be a mixed unit, such as `foot-and-inch`, for
+/// [`UnitsConverter::convert_mixed`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_units::UnitsConverter;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let converter = UnitsConverter::try_new(&provider, "mile", "kilometer")
+///     .expect("Data should load successfully");
+/// assert_eq!(
+///     "1.609344",
+///     converter.convert(&1.into(), 10).unwrap().to_string()
+/// );
+///
+/// let converter = UnitsConverter::try_new(&provider, "celsius", "fahrenheit")
+///     .expect("Data should load successfully");
+/// assert_eq!("98.6", converter.convert(&37.into(), 10).unwrap().to_string());
+///
+/// let converter = UnitsConverter::try_new(&provider, "centimeter", "foot-and-inch")
+///     .expect("Data should load successfully");
+/// let height = converter.convert_mixed(&180.into(), 0).unwrap();
+/// assert_eq!("5", height[0].to_string());
+/// assert_eq!("11", height[1].to_string());
+/// ```
+#[derive(Debug)]
+pub struct UnitsConverter {
+    conversion: Conversion,
+    /// For a mixed output unit, the number of each smaller unit in the previous unit, such as
+    /// 12 for `foot-and-inch`.
+    mixed: Vec<Ratio>,
+}
+
+impl UnitsConverter {
+    /// Creates a new [`UnitsConverter`] from an input unit to an output unit.
+    ///
+    /// Returns [`UnitsError::UnknownUnit`] if either unit is not a CLDR unit, and
+    /// [`UnitsError::IncompatibleUnits`] if the units measure different quantities.
+    pub fn try_new<D>(data_provider: &D, input: &str, output: &str) -> Result<Self, UnitsError>
+    where
+        D: ResourceProvider<UnitsConversionV1Marker> + ?Sized,
+    {
+        let data = data_provider
+            .load_resource(&Default::default())?
+            .take_payload()?;
+        Self::try_new_with_data(data.get(), input, output)
+    }
+
+    pub(crate) fn try_new_with_data(
+        data: &UnitsConversionV1,
+        input: &str,
+        output: &str,
+    ) -> Result<Self, UnitsError> {
+        let input = resolve(data, input)?;
+        let mut outputs = output.split("-and-");
+        let output = resolve(data, outputs.next().unwrap_or(output))?;
+
+        let conversion = if input.dimensions == output.dimensions {
+            Conversion::Linear {
+                factor: overflow(input.factor.div(output.factor))?,
+                offset: overflow(
+                    input
+                        .offset
+                        .sub(output.offset)
+                        .and_then(|o| o.div(output.factor)),
+                )?,
+            }
+        } else if input.is_reciprocal_of(&output) {
+            Conversion::Reciprocal {
+                factor: overflow(input.factor.mul(output.factor).and_then(Ratio::recip))?,
+            }
+        } else {
+            return Err(UnitsError::IncompatibleUnits);
+        };
+
+        let mut mixed = Vec::new();
+        let mut previous = output;
+        for unit in outputs {
+            let unit = resolve(data, unit)?;
+            if unit.dimensions != previous.dimensions {
+                return Err(UnitsError::IncompatibleUnits);
+            }
+            mixed.push(overflow(previous.factor.div(unit.factor))?);
+            previous = unit;
+        }
+
+        Ok(Self { conversion, mixed })
+    }
+
+    /// Converts an exact value in the input unit to the first output unit.
+    pub(crate) fn convert_ratio(&self, value: Ratio) -> Result<Ratio, UnitsError> {
+        overflow(match self.conversion {
+            Conversion::Linear { factor, offset } => value.mul(factor).and_then(|v| v.add(offset)),
+            Conversion::Reciprocal { factor } => factor.div(value),
+        })
+    }
+
+    /// Converts a value in the input unit to the output unit.
+    ///
+    /// The result is exact if it has at most `max_fraction_digits` fraction digits, and is
+    /// otherwise rounded half to even. For a mixed output unit, this returns the value in its
+    /// first unit, such as 5.5 for 5 feet 6 inches.
+    ///
+    /// Returns [`UnitsError::Overflow`] if the exact conversion exceeds the supported precision,
+    /// or the value is zero for a reciprocal conversion.
+    pub fn convert(
+        &self,
+        value: &FixedDecimal,
+        max_fraction_digits: u8,
+    ) -> Result<FixedDecimal, UnitsError> {
+        let value = overflow(Ratio::from_fixed_decimal(value))?;
+        let converted = self.convert_ratio(value)?;
+        overflow(
+            converted
+                .round_half_even(max_fraction_digits)
+                .and_then(Ratio::to_fixed_decimal),
+        )
+    }
+
+    /// Converts a value in the input unit to a mixed output unit, returning a value for each of
+    /// its units, such as 5 and 6 for 5 feet 6 inches in `foot-and-inch`.
+    ///
+    /// All values but the last are integers. The last value is rounded half to even to
+    /// `max_fraction_digits`, with any carry into the larger units. A negative value has its sign
+    /// on the first nonzero value.
+    pub fn convert_mixed(
+        &self,
+        value: &FixedDecimal,
+        max_fraction_digits: u8,
+    ) -> Result<Vec<FixedDecimal>, UnitsError> {
+        let value = overflow(Ratio::from_fixed_decimal(value))?;
+        let converted = self.convert_ratio(value)?;
+
+        // Round in the smallest unit, so that the rounding carries into the larger units.
+        let scale = overflow(
+            self.mixed
+                .iter()
+                .try_fold(Ratio::ONE, |acc, ratio| acc.mul(*ratio)),
+        )?;
+        let total = overflow(
+            converted
+                .abs()
+                .and_then(|c| c.mul(scale))
+                .and_then(|c| c.round_half_even(max_fraction_digits)),
+        )?;
+
+        let mut remaining = overflow(total.div(scale))?;
+        let mut values = Vec::with_capacity(self.mixed.len() + 1);
+        for ratio in self.mixed.iter() {
+            let whole = Ratio::from_integer(remaining.floor());
+            values.push(whole);
+            remaining = overflow(remaining.sub(whole).and_then(|r| r.mul(*ratio)))?;
+        }
+        values.push(overflow(remaining.round_half_even(max_fraction_digits))?);
+
+        if converted.is_negative() {
+            if let Some(first) = values.iter_mut().find(|v| !v.is_zero()) {
+                *first = overflow(first.neg())?;
+            }
+        }
+        values
+            .into_iter()
+            .map(|v| overflow(v.to_fixed_decimal()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, output: &str, value: &str) -> String {
+        let provider = icu_testdata::get_provider();
+        let converter = UnitsConverter::try_new(&provider, input, output).unwrap();
+        converter
+            .convert(&value.parse().unwrap(), 10)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_convert() {
+        for (input, output, value, expected) in [
+            ("meter", "foot", "0.3048", "1"),
+            ("foot", "inch", "1.5", "18"),
+            ("kilogram", "pound", "0.45359237", "1"),
+            ("gram", "ounce", "1", "0.0352739619"),
+            ("kilometer-per-hour", "meter-per-second", "36", "10"),
+            (
+                "kilometer-per-hour",
+                "mile-per-hour",
+                "100",
+                "62.1371192237",
+            ),
+            ("square-meter", "square-foot", "1", "10.7639104167"),
+            ("cubic-meter", "liter", "1", "1000"),
+            ("gallon", "liter", "1", "3.785411784"),
+            ("hour", "second", "-1.5", "-5400"),
+            ("celsius", "fahrenheit", "-40", "-40"),
+            ("fahrenheit", "celsius", "212", "100"),
+            ("kelvin", "celsius", "0", "-273.15"),
+            ("newton", "pound-force", "4.4482216152605", "1"),
+            ("kibibyte", "byte", "1", "1024"),
+        ] {
+            assert_eq!(
+                convert(input, output, value),
+                expected,
+                "{} {} to {}",
+                value,
+                input,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        assert_eq!(
+            convert("mile-per-gallon", "liter-per-100-kilometer", "30"),
+            "7.8404861111"
+        );
+        assert_eq!(
+            convert("liter-per-100-kilometer", "mile-per-gallon", "10"),
+            "23.5214583333"
+        );
+
+        let provider = icu_testdata::get_provider();
+        let converter =
+            UnitsConverter::try_new(&provider, "mile-per-gallon", "liter-per-kilometer").unwrap();
+        assert!(matches!(
+            converter.convert(&0.into(), 10),
+            Err(UnitsError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_mixed() {
+        let provider = icu_testdata::get_provider();
+        let converter = UnitsConverter::try_new(&provider, "meter", "foot-and-inch").unwrap();
+        for (value, digits, expected) in [
+            ("1.8", 0, ["5", "11"]),
+            ("1.8", 2, ["5", "10.87"]),
+            // 5 feet 11.99 inches rounds up to 6 feet
+            ("1.8288", 0, ["6", "0"]),
+            ("-1.8", 0, ["-5", "11"]),
+            ("-0.1", 0, ["0", "-4"]),
+        ] {
+            let values = converter
+                .convert_mixed(&value.parse().unwrap(), digits)
+                .unwrap();
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            assert_eq!(values, expected, "{}", value);
+        }
+        assert_eq!(
+            converter
+                .convert(&"1.8288".parse().unwrap(), 10)
+                .unwrap()
+                .to_string(),
+            "6"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let provider = icu_testdata::get_provider();
+        assert!(matches!(
+            UnitsConverter::try_new(&provider, "meter", "kilogram"),
+            Err(UnitsError::IncompatibleUnits)
+        ));
+        assert!(matches!(
+            UnitsConverter::try_new(&provider, "meter", "furlong"),
+            Err(UnitsError::UnknownUnit)
+        ));
+        assert!(matches!(
+            UnitsConverter::try_new(&provider, "foot-and-inch", "meter"),
+            Err(UnitsError::UnknownUnit)
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for unit formatting and conversion.
+
+use displaydoc::Display;
+
+/// A list of possible error outcomes for operations in this crate.
+#[derive(Display, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// An error loading the plural rules of the locale.
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
+    /// An error loading the number formatter of the locale.
+    #[displaydoc("error loading number format: {0}")]
+    Decimal(icu_decimal::FixedDecimalFormatError),
+    /// The unit identifier is not a CLDR unit, or the locale has no patterns to compose it.
+    #[displaydoc("unknown unit")]
+    UnknownUnit,
+    /// The units of a conversion measure different quantities, such as `meter` and `kilogram`.
+    #[displaydoc("the units measure different quantities")]
+    IncompatibleUnits,
+    /// The result of a conversion does not fit into the supported precision.
+    #[displaydoc("the conversion exceeds the supported precision")]
+    Overflow,
+    /// There are no unit preferences for the quantity.
+    #[displaydoc("unknown quantity")]
+    UnknownQuantity,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<icu_decimal::FixedDecimalFormatError> for Error {
+    fn from(e: icu_decimal::FixedDecimalFormatError) -> Self {
+        Error::Decimal(e)
+    }
+}
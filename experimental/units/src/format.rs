@@ -0,0 +1,388 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of measurements, such as "12 kilograms" or "5 km/h".
+
+use crate::options::*;
+use crate::provider::*;
+use crate::UnitsError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// A formatter for a [`FixedDecimal`] with a measurement unit, such as "12 kilograms" or
+/// "5 km/h".
+///
+/// The unit is given as a CLDR unit identifier, such as `kilogram` or `kilometer-per-hour`.
+/// If the locale has no pattern for the unit itself, the unit is composed from simpler units:
+///
+/// * `a-per-b` from `a` and the per-unit pattern of `b`, or from the names of `a` and `b` with
+///   the "per" pattern, such as "{0} kilograms per square meter"
+/// * `square-a` and `cubic-a` from `a` with the power patterns, such as "{0} square meters"
+/// * `a-b` from the names of `a` and `b` with the "times" pattern, such as "{0} kilogram-meters"
+///
+/// The pattern is selected by the plural category of the number.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_units::options;
+/// use icu_units::UnitsFormat;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options: options::UnitsFormatOptions = Default::default();
+/// options.width = options::Width::Long;
+///
+/// let uf = UnitsFormat::try_new(locale!("en"), &provider, "kilogram", options.clone())
+///     .expect("Data should load successfully");
+/// assert_eq!("1 kilogram", uf.format(&1.into()).write_to_string());
+/// assert_eq!("12 kilograms", uf.format(&12.into()).write_to_string());
+///
+/// let uf = UnitsFormat::try_new(
+///     locale!("en"),
+///     &provider,
+///     "kilogram-per-square-meter",
+///     options,
+/// )
+/// .expect("Data should load successfully");
+/// assert_eq!(
+///     "12 kilograms per square meter",
+///     uf.format(&12.into()).write_to_string()
+/// );
+/// ```
+pub struct UnitsFormat {
+    fixed_decimal_format: FixedDecimalFormat,
+    plural_rules: PluralRules,
+    /// The pattern for [`Count::Other`], which is used for plural categories without a pattern.
+    other: String,
+    /// The patterns for other plural categories that differ from `other`.
+    patterns: Vec<(Count, String)>,
+}
+
+impl UnitsFormat {
+    /// Creates a new [`UnitsFormat`] for a CLDR unit identifier from locale data and an options
+    /// bag.
+    ///
+    /// Returns [`UnitsError::UnknownUnit`] if the locale has no pattern for the unit and the
+    /// unit cannot be composed from units with patterns.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        unit: &str,
+        options: UnitsFormatOptions,
+    ) -> Result<Self, UnitsError>
+    where
+        D: ResourceProvider<DecimalSymbolsV1Marker>
+            + ResourceProvider<LongUnitPatternsV1Marker>
+            + ResourceProvider<ShortUnitPatternsV1Marker>
+            + ResourceProvider<NarrowUnitPatternsV1Marker>
+            + ResourceProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let data = match options.width {
+            Width::Long => load::<LongUnitPatternsV1Marker, D>(&locale, data_provider)?,
+            Width::Short => load::<ShortUnitPatternsV1Marker, D>(&locale, data_provider)?,
+            Width::Narrow => load::<NarrowUnitPatternsV1Marker, D>(&locale, data_provider)?,
+        };
+        let data = data.get();
+        let other = compose(data, unit, Count::Other).ok_or(UnitsError::UnknownUnit)?;
+        let patterns = [Count::Zero, Count::One, Count::Two, Count::Few, Count::Many]
+            .iter()
+            .filter_map(|&count| {
+                compose(data, unit, count)
+                    .filter(|pattern| *pattern != other)
+                    .map(|pattern| (count, pattern))
+            })
+            .collect();
+
+        let plural_rules = PluralRules::try_new_cardinal(locale.id.clone(), data_provider)?;
+        let fixed_decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, options.number)?;
+        Ok(Self {
+            fixed_decimal_format,
+            plural_rules,
+            other,
+            patterns,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] with the unit, returning a [`FormattedUnit`].
+    ///
+    /// The pattern is selected by the plural category of the number after rounding.
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedUnit<'l> {
+        let count = Count::from(
+            self.plural_rules
+                .select(self.fixed_decimal_format.format(value).rounded_value()),
+        );
+        let pattern = self
+            .patterns
+            .iter()
+            .find(|(c, _)| *c == count)
+            .map_or(self.other.as_str(), |(_, pattern)| pattern.as_str());
+        FormattedUnit {
+            formatter: self,
+            value,
+            pattern,
+        }
+    }
+}
+
+fn load<M, D>(
+    locale: &Locale,
+    data_provider: &D,
+) -> Result<DataPayload<ErasedUnitPatternsV1Marker>, DataError>
+where
+    M: ResourceMarker<Yokeable = UnitPatternsV1<'static>>,
+    D: ResourceProvider<M> + ?Sized,
+{
+    Ok(data_provider
+        .load_resource(&DataRequest {
+            options: locale.id.clone().into(),
+            metadata: Default::default(),
+        })?
+        .take_payload()?
+        .cast())
+}
+
+/// Returns the pattern of a unit for a plural category, composing it from simpler units if
+/// the unit has no pattern of its own.
+fn compose(data: &UnitPatternsV1, unit: &str, count: Count) -> Option<String> {
+    if let Ok(pattern) = data
+        .patterns
+        .get(unit, &count)
+        .or_else(|_| data.patterns.get(unit, &Count::Other))
+    {
+        return Some(pattern.to_string());
+    }
+
+    if let Some((numerator, denominator)) = unit.split_once("-per-") {
+        let numerator = compose(data, numerator, count)?;
+        return match data.per_unit_patterns.get(denominator) {
+            Some(per_unit_pattern) => Some(per_unit_pattern.replacen("{0}", &numerator, 1)),
+            None => {
+                // UTS 35: the denominator uses the singular name of the unit.
+                let denominator = name(data, denominator, Count::One)?;
+                Some(
+                    data.per
+                        .replacen("{0}", &numerator, 1)
+                        .replacen("{1}", &denominator, 1),
+                )
+            }
+        };
+    }
+
+    for (prefix, power_patterns) in [("square-", &data.power2), ("cubic-", &data.power3)] {
+        if let Some(base) = unit.strip_prefix(prefix) {
+            let power_pattern = power_patterns
+                .get(&count)
+                .or_else(|| power_patterns.get(&Count::Other))?;
+            let base = compose(data, base, count)?;
+            return Some(map_name(&base, |name| {
+                power_pattern.replacen("{0}", name, 1)
+            }));
+        }
+    }
+
+    // A product of units, where only the last unit is inflected for the plural category.
+    unit.match_indices('-').find_map(|(i, _)| {
+        let first = name(data, unit.get(..i)?, Count::One)?;
+        let second = compose(data, unit.get(i + 1..)?, count)?;
+        Some(map_name(&second, |name| {
+            data.times
+                .replacen("{0}", &first, 1)
+                .replacen("{1}", name, 1)
+        }))
+    })
+}
+
+/// Returns the name of a unit for a plural category, such as "kilometers".
+fn name(data: &UnitPatternsV1, unit: &str, count: Count) -> Option<String> {
+    Some(
+        compose(data, unit, count)?
+            .replacen("{0}", "", 1)
+            .trim()
+            .to_string(),
+    )
+}
+
+/// Replaces the name of the unit in a pattern, which is the text on one side of the
+/// placeholder, such as "kilometers" in "{0} kilometers".
+fn map_name(pattern: &str, f: impl FnOnce(&str) -> String) -> String {
+    match pattern.split_once("{0}") {
+        Some((before, after)) if after.trim().is_empty() => {
+            let name = before.trim();
+            format!("{}{{0}}{}", before.replacen(name, &f(name), 1), after)
+        }
+        Some((before, after)) => {
+            let name = after.trim();
+            format!("{}{{0}}{}", before, after.replacen(name, &f(name), 1))
+        }
+        None => f(pattern.trim()),
+    }
+}
+
+/// An intermediate structure returned by [`UnitsFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted measurement to a string or buffer.
+pub struct FormattedUnit<'l> {
+    formatter: &'l UnitsFormat,
+    value: &'l FixedDecimal,
+    pattern: &'l str,
+}
+
+impl<'l> Writeable for FormattedUnit<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match self.pattern.split_once("{0}") {
+            Some((before, after)) => {
+                sink.write_str(before)?;
+                self.formatter
+                    .fixed_decimal_format
+                    .format(self.value)
+                    .write_to(sink)?;
+                sink.write_str(after)
+            }
+            None => sink.write_str(self.pattern),
+        }
+    }
+}
+
+impl<'l> fmt::Display for FormattedUnit<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::{locale, Locale};
+    use writeable::assert_writeable_eq;
+
+    fn formatter(locale: Locale, unit: &str, width: Width) -> UnitsFormat {
+        let provider = icu_testdata::get_provider();
+        let options = UnitsFormatOptions {
+            width,
+            ..Default::default()
+        };
+        UnitsFormat::try_new(locale, &provider, unit, options).unwrap()
+    }
+
+    #[test]
+    fn test_widths() {
+        let value = FixedDecimal::from(12);
+        for (unit, long, short, narrow) in [
+            ("kilogram", "12 kilograms", "12 kg", "12kg"),
+            (
+                "kilometer-per-hour",
+                "12 kilometers per hour",
+                "12 km/h",
+                "12km/h",
+            ),
+        ] {
+            for (width, expected) in [
+                (Width::Long, long),
+                (Width::Short, short),
+                (Width::Narrow, narrow),
+            ] {
+                let uf = formatter(locale!("en"), unit, width);
+                assert_writeable_eq!(uf.format(&value), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compound() {
+        let value = FixedDecimal::from(12);
+        for (unit, long, short) in [
+            // Per-unit pattern of the denominator
+            ("kilogram-per-hour", "12 kilograms per hour", "12 kg/h"),
+            // Per pattern with a composed denominator
+            (
+                "kilogram-per-square-meter",
+                "12 kilograms per square meter",
+                "12 kg/m²",
+            ),
+            ("cubic-meter", "12 cubic meters", "12 m³"),
+            ("kilogram-meter", "12 kilogram-meters", "12 kg⋅m"),
+        ] {
+            let uf = formatter(locale!("en"), unit, Width::Long);
+            assert_writeable_eq!(uf.format(&value), long);
+            let uf = formatter(locale!("en"), unit, Width::Short);
+            assert_writeable_eq!(uf.format(&value), short);
+        }
+    }
+
+    #[test]
+    fn test_plurals() {
+        let uf = formatter(locale!("ru"), "kilometer", Width::Long);
+        for (value, expected) in [
+            ("1", "1 километр"),
+            ("2", "2 километра"),
+            ("5", "5 километров"),
+            ("1.5", "1,5 километра"),
+        ] {
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_writeable_eq!(uf.format(&value), expected);
+        }
+
+        // Patterns without a placeholder
+        let uf = formatter(locale!("ar"), "kilometer", Width::Long);
+        for (value, expected) in [(1, "كيلومتر"), (2, "كيلومتران"), (3, "٣ كيلومترات")]
+        {
+            let value = FixedDecimal::from(value);
+            assert_writeable_eq!(uf.format(&value), expected);
+        }
+
+        let uf = formatter(locale!("en"), "meter-per-second", Width::Long);
+        for (value, expected) in [
+            ("1", "1 meter per second"),
+            ("1.5", "1.5 meters per second"),
+        ] {
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_writeable_eq!(uf.format(&value), expected);
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        let provider = icu_testdata::get_provider();
+        let mut options = UnitsFormatOptions {
+            width: Width::Long,
+            ..Default::default()
+        };
+        options.number.maximum_fraction_digits = Some(0);
+        let uf = UnitsFormat::try_new(locale!("en"), &provider, "kilogram", options).unwrap();
+        for (value, expected) in [
+            ("1.2", "1 kilogram"),
+            ("0.6", "1 kilogram"),
+            ("1.5", "2 kilograms"),
+        ] {
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_writeable_eq!(uf.format(&value), expected);
+        }
+    }
+
+    #[test]
+    fn test_unknown_unit() {
+        let provider = icu_testdata::get_provider();
+        assert!(matches!(
+            UnitsFormat::try_new(locale!("en"), &provider, "furlong", Default::default()),
+            Err(UnitsError::UnknownUnit)
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![warn(missing_docs)]
+
+//! [`icu_units`](crate) offers localized formatting and conversion of measurement units.
+//!
+//! [`UnitsFormat`] renders a [`FixedDecimal`](fixed_decimal::FixedDecimal) with a CLDR unit,
+//! such as "12 kilograms" or "5 km/h". Units without patterns of their own, such as
+//! `kilogram-per-square-meter`, are composed from simpler units following
+//! [UTS 35](https://unicode.org/reports/tr35/tr35-general.html#compound-units).
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_units::UnitsFormat;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let uf = UnitsFormat::try_new(locale!("en"), &provider, "kilometer-per-hour", Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!("5 km/h", uf.format(&5.into()).write_to_string());
+//! ```
+//!
+//! [`UnitsConverter`] converts values between units with exact rational arithmetic, and
+//! [`PreferredUnits`] selects the units a region prefers for a usage, such as feet and inches
+//! for the height of a person in the United States.
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_units::{PreferredUnits, UnitsConverter};
+//!
+//! let provider = icu_testdata::get_provider();
+//! let preferences =
+//!     PreferredUnits::try_new(locale!("en-US"), &provider, "length", "person-height")
+//!         .expect("Data should load successfully");
+//! let unit = preferences
+//!     .select(&180.into(), "centimeter")
+//!     .expect("The units should be compatible");
+//! assert_eq!(unit, "foot-and-inch");
+//!
+//! let converter = UnitsConverter::try_new(&provider, "centimeter", unit)
+//!     .expect("Data should load successfully");
+//! let values = converter
+//!     .convert_mixed(&180.into(), 0)
+//!     .expect("The value should be in range");
+//! assert_eq!(
+//!     values.iter().map(ToString::to_string).collect::<Vec<_>>(),
+//!     ["5", "11"]
+//! );
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums
+    )
+)]
+
+extern crate alloc;
+
+pub mod converter;
+pub mod error;
+pub mod format;
+pub mod options;
+pub mod preferences;
+pub mod provider;
+mod ratio;
+
+pub use converter::UnitsConverter;
+pub use error::Error as UnitsError;
+pub use format::{FormattedUnit, UnitsFormat};
+pub use preferences::PreferredUnits;
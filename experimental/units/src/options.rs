@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`UnitsFormat`](crate::UnitsFormat).
+
+use icu_decimal::options::FixedDecimalFormatOptions;
+
+/// A bag of options defining how measurements will be formatted by
+/// [`UnitsFormat`](crate::UnitsFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct UnitsFormatOptions {
+    /// How long the unit should be.
+    pub width: Width,
+    /// Options for rendering the number.
+    pub number: FixedDecimalFormatOptions,
+}
+
+/// Configuration for the length of the unit.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_units::options;
+/// use icu_units::UnitsFormat;
+/// use writeable::Writeable;
+///
+/// let provider = icu_testdata::get_provider();
+/// let format = |width| {
+///     let mut options: options::UnitsFormatOptions = Default::default();
+///     options.width = width;
+///     let uf = UnitsFormat::try_new(locale!("en"), &provider, "kilogram", options)
+///         .expect("Data should load successfully");
+///     uf.format(&12.into()).write_to_string().into_owned()
+/// };
+///
+/// assert_eq!("12 kilograms", format(options::Width::Long));
+/// assert_eq!("12 kg", format(options::Width::Short));
+/// assert_eq!("12kg", format(options::Width::Narrow));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Width {
+    /// Spell out the unit, such as "12 kilograms".
+    Long,
+
+    /// Abbreviate the unit, such as "12 kg".
+    Short,
+
+    /// Abbreviate the unit as much as possible, such as "12kg".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Short
+    }
+}
@@ -0,0 +1,342 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Regional unit preferences, such as feet and inches for the height of a person in the
+//! United States.
+
+use crate::converter::UnitsConverter;
+use crate::provider::*;
+use crate::ratio::Ratio;
+use crate::UnitsError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_locid::{extensions_unicode_key as key, Locale};
+use icu_provider::prelude::*;
+use tinystr::{tinystr, TinyAsciiStr};
+use zerofrom::ZeroFrom;
+
+/// The region whose preferences are used when the locale has no region or the region has no
+/// preferences of its own.
+const WORLD: TinyAsciiStr<3> = tinystr!(3, "001");
+
+/// The preferred units of a locale for measuring a quantity in a given usage, such as `foot`
+/// and `inch` for the height of a person in the United States.
+///
+/// The region of the preferences is, in order of precedence:
+///
+/// 1. The measurement system of the `-u-ms` Unicode extension keyword: `001` for `metric`,
+///    `US` for `ussystem`, and `GB` for `uksystem`.
+/// 2. The region override of the `-u-rg` Unicode extension keyword, such as `US` for `uszzzz`.
+/// 3. The region of the locale.
+/// 4. `001`, the world.
+///
+/// The region is not inferred from the language, so use a locale with a region, such as
+/// `en-US`, or add likely subtags to the locale first.
+///
+/// For temperatures, the `-u-mu` Unicode extension keyword overrides the preferences with
+/// `celsius`, `fahrenh`, or `kelvin`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_units::PreferredUnits;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let preferences =
+///     PreferredUnits::try_new(locale!("en-US"), &provider, "length", "person-height")
+///         .expect("Data should load successfully");
+/// assert_eq!(
+///     preferences.units().collect::<Vec<_>>(),
+///     ["foot-and-inch", "inch"]
+/// );
+///
+/// let preferences = PreferredUnits::try_new(
+///     "en-US-u-ms-metric".parse::<icu_locid::Locale>().unwrap(),
+///     &provider,
+///     "length",
+///     "person-height",
+/// )
+/// .expect("Data should load successfully");
+/// assert_eq!(preferences.units().collect::<Vec<_>>(), ["centimeter"]);
+///
+/// // A road distance of 150 meters is measured in feet, and 1500 meters in miles.
+/// let preferences = PreferredUnits::try_new(locale!("en-US"), &provider, "length", "road")
+///     .expect("Data should load successfully");
+/// assert_eq!(preferences.select(&150.into(), "meter").unwrap(), "foot");
+/// assert_eq!(preferences.select(&1500.into(), "meter").unwrap(), "mile");
+/// ```
+#[derive(Debug)]
+pub struct PreferredUnits {
+    conversion: DataPayload<UnitsConversionV1Marker>,
+    units: Vec<String>,
+    /// The smallest value in each unit but the last for which it is used.
+    thresholds: Vec<Ratio>,
+}
+
+impl PreferredUnits {
+    /// Creates a new [`PreferredUnits`] for a quantity, such as `length`, and a usage, such as
+    /// `person-height`.
+    ///
+    /// Falls back to the `default` usage if the quantity has no preferences for the usage.
+    /// Returns [`UnitsError::UnknownQuantity`] if there are no preferences for the quantity.
+    pub fn try_new<T: Into<Locale>, D>(
+        locale: T,
+        data_provider: &D,
+        quantity: &str,
+        usage: &str,
+    ) -> Result<Self, UnitsError>
+    where
+        D: ResourceProvider<UnitPreferencesV1Marker>
+            + ResourceProvider<UnitsConversionV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let conversion: DataPayload<UnitsConversionV1Marker> = data_provider
+            .load_resource(&Default::default())?
+            .take_payload()?;
+
+        if quantity == "temperature" {
+            if let Some(unit) = measurement_unit_override(&locale) {
+                return Ok(Self {
+                    conversion,
+                    units: alloc::vec![unit.to_string()],
+                    thresholds: Vec::new(),
+                });
+            }
+        }
+
+        let data: DataPayload<UnitPreferencesV1Marker> = data_provider
+            .load_resource(&Default::default())?
+            .take_payload()?;
+        let region = region(&locale);
+        let preferences = [usage, "default"]
+            .iter()
+            .map(|usage| format!("{}-{}", quantity, usage))
+            .find_map(|key| {
+                data.get()
+                    .preferences
+                    .get(&key, &region)
+                    .or_else(|_| data.get().preferences.get(&key, &WORLD))
+                    .ok()
+            })
+            .map(UnitPreferences::zero_from)
+            .ok_or(UnitsError::UnknownQuantity)?;
+
+        let units = preferences.units.iter().map(ToString::to_string).collect();
+        let thresholds = preferences
+            .thresholds
+            .iter()
+            .map(|threshold| {
+                threshold.parse().map_err(|_| {
+                    DataError::custom("Invalid unit preference threshold")
+                        .with_display_context(threshold)
+                        .into()
+                })
+            })
+            .collect::<Result<_, UnitsError>>()?;
+
+        Ok(Self {
+            conversion,
+            units,
+            thresholds,
+        })
+    }
+
+    /// Returns the preferred units, from the largest to the smallest.
+    pub fn units(&self) -> impl Iterator<Item = &str> + '_ {
+        self.units.iter().map(String::as_str)
+    }
+
+    /// Returns the preferred unit for a value, such as `mile` for 1500 meters on a road in the
+    /// United States, which is the first unit in which the absolute value reaches the
+    /// threshold of the unit, or otherwise the last unit.
+    ///
+    /// For a mixed unit, such as `foot-and-inch`, the value is compared in its first unit.
+    pub fn select(&self, value: &FixedDecimal, unit: &str) -> Result<&str, UnitsError> {
+        let value = Ratio::from_fixed_decimal(value)
+            .and_then(Ratio::abs)
+            .ok_or(UnitsError::Overflow)?;
+        for (preferred, threshold) in self.units.iter().zip(self.thresholds.iter()) {
+            let converter =
+                UnitsConverter::try_new_with_data(self.conversion.get(), unit, preferred)?;
+            if converter.convert_ratio(value)? >= *threshold {
+                return Ok(preferred);
+            }
+        }
+        self.units
+            .last()
+            .map(String::as_str)
+            .ok_or(UnitsError::UnknownQuantity)
+    }
+}
+
+/// Returns the temperature unit of the `-u-mu` keyword, such as `fahrenheit` for `fahrenh`.
+fn measurement_unit_override(locale: &Locale) -> Option<&'static str> {
+    match locale
+        .extensions
+        .unicode
+        .keywords
+        .get(&key!("mu"))?
+        .as_tinystr_slice()
+    {
+        [unit] => match unit.as_str() {
+            "celsius" => Some("celsius"),
+            "fahrenh" => Some("fahrenheit"),
+            "kelvin" => Some("kelvin"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the region of the preferences for a locale.
+fn region(locale: &Locale) -> TinyAsciiStr<3> {
+    let keywords = &locale.extensions.unicode.keywords;
+    let measurement_system =
+        keywords
+            .get(&key!("ms"))
+            .and_then(|value| match value.as_tinystr_slice() {
+                [system] => match system.as_str() {
+                    "metric" => Some(WORLD),
+                    "ussystem" => Some(tinystr!(3, "US")),
+                    "uksystem" => Some(tinystr!(3, "GB")),
+                    _ => None,
+                },
+                _ => None,
+            });
+    // A region override is a region followed by a subdivision suffix, such as `uszzzz`.
+    let region_override =
+        keywords
+            .get(&key!("rg"))
+            .and_then(|value| match value.as_tinystr_slice() {
+                [rg] => rg
+                    .as_str()
+                    .get(..2)
+                    .and_then(|region| TinyAsciiStr::from_str(region).ok())
+                    .map(|region: TinyAsciiStr<3>| region.to_ascii_uppercase()),
+                _ => None,
+            });
+    measurement_system
+        .or(region_override)
+        .or_else(|| {
+            locale
+                .id
+                .region
+                .and_then(|region| TinyAsciiStr::from_str(region.as_str()).ok())
+        })
+        .unwrap_or(WORLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    fn units(locale: &str, quantity: &str, usage: &str) -> Vec<String> {
+        let provider = icu_testdata::get_provider();
+        let locale: Locale = locale.parse().unwrap();
+        PreferredUnits::try_new(locale, &provider, quantity, usage)
+            .unwrap()
+            .units()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_regions() {
+        for (locale, expected) in [
+            ("en", ["centimeter"].as_slice()),
+            ("en-US", &["foot-and-inch", "inch"]),
+            ("de-AT", &["centimeter"]),
+            ("en-GB-u-rg-uszzzz", &["foot-and-inch", "inch"]),
+            ("en-US-u-ms-metric", &["centimeter"]),
+            ("en-DE-u-ms-ussystem", &["foot-and-inch", "inch"]),
+        ] {
+            assert_eq!(
+                units(locale, "length", "person-height"),
+                expected,
+                "{}",
+                locale
+            );
+        }
+        assert_eq!(units("en-GB", "mass", "person"), ["stone-and-pound"]);
+    }
+
+    #[test]
+    fn test_fallback() {
+        // No preferences for the usage
+        assert_eq!(units("en-US", "mass", "unknown"), ["pound", "ounce"]);
+        let provider = icu_testdata::get_provider();
+        assert!(matches!(
+            PreferredUnits::try_new(locale!("en-US"), &provider, "unknown", "default"),
+            Err(UnitsError::UnknownQuantity)
+        ));
+    }
+
+    #[test]
+    fn test_temperature() {
+        assert_eq!(units("en-US", "temperature", "weather"), ["fahrenheit"]);
+        assert_eq!(units("en-PR", "temperature", "weather"), ["fahrenheit"]);
+        assert_eq!(units("fr-FR", "temperature", "weather"), ["celsius"]);
+        assert_eq!(
+            units("en-US-u-mu-celsius", "temperature", "weather"),
+            ["celsius"]
+        );
+        assert_eq!(
+            units("fr-FR-u-mu-fahrenh", "temperature", "default"),
+            ["fahrenheit"]
+        );
+        // The keyword only applies to temperatures.
+        assert_eq!(
+            units("en-u-mu-fahrenh", "length", "default")[0],
+            "kilometer"
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let provider = icu_testdata::get_provider();
+        let preferences =
+            PreferredUnits::try_new(locale!("en-US"), &provider, "length", "person-height")
+                .unwrap();
+        assert_eq!(
+            preferences.select(&180.into(), "centimeter").unwrap(),
+            "foot-and-inch"
+        );
+        assert_eq!(
+            preferences.select(&50.into(), "centimeter").unwrap(),
+            "inch"
+        );
+
+        let preferences =
+            PreferredUnits::try_new(locale!("en"), &provider, "length", "road").unwrap();
+        for (meters, expected) in [(2000, "kilometer"), (-2000, "kilometer"), (500, "meter")] {
+            assert_eq!(
+                preferences.select(&meters.into(), "meter").unwrap(),
+                expected
+            );
+        }
+
+        let preferences =
+            PreferredUnits::try_new(locale!("en-US"), &provider, "consumption", "vehicle-fuel")
+                .unwrap();
+        assert_eq!(
+            preferences
+                .select(&8.into(), "liter-per-100-kilometer")
+                .unwrap(),
+            "mile-per-gallon"
+        );
+
+        let preferences =
+            PreferredUnits::try_new(locale!("en-US"), &provider, "length", "road").unwrap();
+        assert!(matches!(
+            preferences.select(&8.into(), "kilogram"),
+            Err(UnitsError::IncompatibleUnits)
+        ));
+    }
+}
@@ -0,0 +1,189 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+// The ULE types generated by `make_varule` slice their bytes and have undocumented fields.
+#![allow(clippy::indexing_slicing, missing_docs)] // TODO(#1668) Clippy exceptions need docs or fixing.
+
+use alloc::borrow::Cow;
+use icu_provider::{yoke, zerofrom, DataMarker};
+use tinystr::TinyAsciiStr;
+use zerofrom::ZeroFrom;
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d};
+
+pub use icu_decimal::provider::Count;
+
+/// Patterns for formatting measurement units, such as "{0} kilometers" or "{0} km", for one
+/// width.
+///
+/// Units are keyed by their CLDR unit identifier without the category, such as `kilometer` or
+/// `kilometer-per-hour`. Units without a pattern of their own are composed from simpler units
+/// with the compound unit patterns.
+#[icu_provider::data_struct(
+    LongUnitPatternsV1Marker = "units/long@1",
+    ShortUnitPatternsV1Marker = "units/short@1",
+    NarrowUnitPatternsV1Marker = "units/narrow@1"
+)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPatternsV1<'data> {
+    /// Patterns keyed by unit and the plural category of the number, such as `{0} kilometers`,
+    /// where `{0}` is the number.
+    ///
+    /// Every unit has a pattern for [`Count::Other`], and patterns for other plural categories
+    /// are only present if they differ from it.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, str, Count, str>,
+
+    /// Patterns for dividing by a unit, keyed by the unit, such as `{0}/h` for `hour`, where
+    /// `{0}` is the formatted numerator.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+
+    /// The pattern for dividing two units without a per-unit pattern, such as `{0} per {1}`,
+    /// where `{0}` is the formatted numerator and `{1}` is the name of the denominator.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per: Cow<'data, str>,
+
+    /// The pattern for multiplying two units, such as `{0}-{1}`, where `{0}` and `{1}` are the
+    /// names of the units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub times: Cow<'data, str>,
+
+    /// Patterns for squaring a unit, keyed by the plural category of the number, such as
+    /// `square {0}`, where `{0}` is the name of the unit.
+    ///
+    /// There is always a pattern for [`Count::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power2: ZeroMap<'data, Count, str>,
+
+    /// Patterns for cubing a unit, keyed by the plural category of the number, such as
+    /// `cubic {0}`, where `{0}` is the name of the unit.
+    ///
+    /// There is always a pattern for [`Count::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power3: ZeroMap<'data, Count, str>,
+}
+
+pub(crate) struct ErasedUnitPatternsV1Marker;
+
+impl DataMarker for ErasedUnitPatternsV1Marker {
+    type Yokeable = UnitPatternsV1<'static>;
+}
+
+/// The conversion of a unit to its base unit, such as `meter` for `foot`.
+///
+/// A value in the unit is converted to the base unit by multiplying it by the factor and
+/// adding the offset.
+#[zerovec::make_varule(ConversionInfoULE)]
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_units::provider),
+)]
+pub struct ConversionInfo<'data> {
+    /// The base unit, such as `meter` for `foot`, or `kilogram-meter-per-square-second` for
+    /// `newton`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_unit: Cow<'data, str>,
+    /// The factor as a product of decimal numbers divided by a product of decimal numbers,
+    /// such as `0.3048/12` for `inch`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub factor: Cow<'data, str>,
+    /// The offset in the same syntax as the factor, such as `273.15` for `celsius`, or the
+    /// empty string if there is no offset.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub offset: Cow<'data, str>,
+}
+
+impl core::fmt::Debug for ConversionInfoULE {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        ConversionInfo::zero_from(self).fmt(f)
+    }
+}
+
+/// The conversions of simple units to their base units, from which the conversions of
+/// prefixed and compound units are computed.
+#[icu_provider::data_struct(UnitsConversionV1Marker = "units/conversion@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsConversionV1<'data> {
+    /// The conversions keyed by unit, such as `foot`. Base units, such as `meter`, convert to
+    /// themselves with a factor of `1`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub conversions: ZeroMap<'data, str, ConversionInfoULE>,
+}
+
+/// The preferred units for a usage in a region, from the largest to the smallest unit.
+#[zerovec::make_varule(UnitPreferencesULE)]
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_units::provider),
+)]
+pub struct UnitPreferences<'data> {
+    /// The units, such as `mile`, `foot`. A unit may be a mixed unit, such as `foot-and-inch`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub units: VarZeroVec<'data, str>,
+    /// For each unit but the last, the smallest value in the unit for which it is used, as a
+    /// decimal number, such as `0.5` for `mile`. The last unit is used for all smaller values.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thresholds: VarZeroVec<'data, str>,
+}
+
+impl core::fmt::Debug for UnitPreferencesULE {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        UnitPreferences::zero_from(self).fmt(f)
+    }
+}
+
+/// The preferred units by quantity, usage, and region, such as `foot-and-inch` for the height
+/// of a person in the United States.
+#[icu_provider::data_struct(UnitPreferencesV1Marker = "units/preferences@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPreferencesV1<'data> {
+    /// The preferences keyed by quantity and usage, such as `length-person-height`, and by
+    /// region, such as `US`. Every usage has preferences for the region `001`, which are used for
+    /// regions without their own preferences.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub preferences: ZeroMap2d<'data, str, TinyAsciiStr<3>, UnitPreferencesULE>,
+}
@@ -0,0 +1,248 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Exact rational arithmetic for unit conversion.
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::str::FromStr;
+use fixed_decimal::FixedDecimal;
+use writeable::Writeable;
+
+/// A rational number in lowest terms with a positive denominator.
+///
+/// All operations return `None` if the result does not fit into an `i128` numerator and
+/// denominator, or on division by zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl Ratio {
+    pub(crate) const ZERO: Self = Self { num: 0, den: 1 };
+    pub(crate) const ONE: Self = Self { num: 1, den: 1 };
+
+    pub(crate) fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let negative = (num < 0) != (den < 0);
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs());
+        let num = i128::try_from(num.unsigned_abs() / g).ok()?;
+        let den = i128::try_from(den.unsigned_abs() / g).ok()?;
+        Some(Self {
+            num: if negative { -num } else { num },
+            den,
+        })
+    }
+
+    pub(crate) fn from_integer(num: i128) -> Self {
+        Self { num, den: 1 }
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    pub(crate) fn is_negative(self) -> bool {
+        self.num < 0
+    }
+
+    pub(crate) fn abs(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_abs()?,
+            den: self.den,
+        })
+    }
+
+    pub(crate) fn neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    pub(crate) fn recip(self) -> Option<Self> {
+        Self::new(self.den, self.num)
+    }
+
+    pub(crate) fn mul(self, other: Self) -> Option<Self> {
+        // Cross-reduce first to keep the intermediate products small.
+        let g1 = i128::try_from(gcd(self.num.unsigned_abs(), other.den.unsigned_abs())).ok()?;
+        let g2 = i128::try_from(gcd(other.num.unsigned_abs(), self.den.unsigned_abs())).ok()?;
+        Self::new(
+            (self.num / g1).checked_mul(other.num / g2)?,
+            (self.den / g2).checked_mul(other.den / g1)?,
+        )
+    }
+
+    pub(crate) fn div(self, other: Self) -> Option<Self> {
+        self.mul(other.recip()?)
+    }
+
+    pub(crate) fn add(self, other: Self) -> Option<Self> {
+        let g = i128::try_from(gcd(self.den.unsigned_abs(), other.den.unsigned_abs())).ok()?;
+        Self::new(
+            self.num
+                .checked_mul(other.den / g)?
+                .checked_add(other.num.checked_mul(self.den / g)?)?,
+            (self.den / g).checked_mul(other.den)?,
+        )
+    }
+
+    pub(crate) fn sub(self, other: Self) -> Option<Self> {
+        self.add(other.neg()?)
+    }
+
+    pub(crate) fn pow(self, exp: i32) -> Option<Self> {
+        let base = if exp < 0 { self.recip()? } else { self };
+        (0..exp.unsigned_abs()).try_fold(Self::ONE, |acc, _| acc.mul(base))
+    }
+
+    /// Returns the largest integer less than or equal to the number.
+    pub(crate) fn floor(self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    /// Rounds the number to the given number of fraction digits, rounding ties to even.
+    pub(crate) fn round_half_even(self, fraction_digits: u8) -> Option<Self> {
+        let scale = 10i128.checked_pow(fraction_digits.into())?;
+        let scaled = self.mul(Self::from_integer(scale))?;
+        let floor = scaled.floor();
+        let remainder = scaled.sub(Self::from_integer(floor))?;
+        let rounded = match remainder.mul(Self::from_integer(2))?.cmp(&Self::ONE) {
+            Ordering::Less => floor,
+            Ordering::Greater => floor.checked_add(1)?,
+            Ordering::Equal if floor % 2 == 0 => floor,
+            Ordering::Equal => floor.checked_add(1)?,
+        };
+        Self::new(rounded, scale)
+    }
+
+    /// Converts a number with a terminating decimal expansion to a [`FixedDecimal`].
+    ///
+    /// Returns `None` if the decimal expansion does not terminate.
+    pub(crate) fn to_fixed_decimal(self) -> Option<FixedDecimal> {
+        let mut scale = 1i128;
+        let mut fraction_digits = 0i16;
+        while scale % self.den != 0 {
+            scale = scale.checked_mul(10)?;
+            fraction_digits += 1;
+        }
+        let mut result = FixedDecimal::from(self.num.checked_mul(scale / self.den)?);
+        result.multiply_pow10(-fraction_digits).ok()?;
+        Some(result)
+    }
+
+    /// Converts a [`FixedDecimal`] to an exact ratio.
+    pub(crate) fn from_fixed_decimal(value: &FixedDecimal) -> Option<Self> {
+        value.write_to_string().parse().ok()
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.sub(*other) {
+            Some(difference) => difference.num.cmp(&0),
+            // The difference overflows, so the numbers are far apart.
+            None => self.num.signum().cmp(&other.num.signum()),
+        }
+    }
+}
+
+impl FromStr for Ratio {
+    type Err = ();
+
+    /// Parses a decimal number in plain or scientific notation, such as `-12.5` or `6.67408E-11`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) = match s.split_once(|c| c == 'E' || c == 'e') {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| ())?),
+            None => (s, 0),
+        };
+        let (negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(mantissa) => (true, mantissa),
+            None => (false, mantissa),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(());
+        }
+        let mut num = 0i128;
+        for c in integer.chars().chain(fraction.chars()) {
+            let digit = c.to_digit(10).ok_or(())?;
+            num = num
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(digit.into()))
+                .ok_or(())?;
+        }
+        if negative {
+            num = -num;
+        }
+        let fraction_digits = i32::try_from(fraction.len()).map_err(|_| ())?;
+        Self::from_integer(num)
+            .mul(
+                Self::from_integer(10)
+                    .pow(exponent - fraction_digits)
+                    .ok_or(())?,
+            )
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("0.3048".parse(), Ok(Ratio::new(381, 1250).unwrap()));
+        assert_eq!("-12.5".parse(), Ok(Ratio::new(-25, 2).unwrap()));
+        assert_eq!("1.5E3".parse(), Ok(Ratio::from_integer(1500)));
+        assert_eq!("5E-1".parse(), Ok(Ratio::new(1, 2).unwrap()));
+        assert_eq!("".parse::<Ratio>(), Err(()));
+        assert_eq!("1/2".parse::<Ratio>(), Err(()));
+    }
+
+    #[test]
+    fn test_round_half_even() {
+        for (num, den, digits, expected) in [
+            (1, 3, 2, "0.33"),
+            (2, 3, 2, "0.67"),
+            (5, 2, 0, "2"),
+            (7, 2, 0, "4"),
+            (-5, 2, 0, "-2"),
+            (1, 8, 2, "0.12"),
+            (3, 8, 2, "0.38"),
+        ] {
+            let rounded = Ratio::new(num, den)
+                .unwrap()
+                .round_half_even(digits)
+                .unwrap();
+            assert_eq!(
+                rounded.to_fixed_decimal().unwrap().to_string(),
+                expected,
+                "{}/{}",
+                num,
+                den
+            );
+        }
+        assert_eq!(Ratio::new(1, 3).unwrap().to_fixed_decimal(), None);
+    }
+}
@@ -35,6 +35,7 @@ typedef enum ICU4XError {
   ICU4XError_PropertyUnknownGeneralCategoryGroupError = 1025,
   ICU4XError_DecimalLimitError = 1280,
   ICU4XError_DecimalSyntaxError = 1281,
+  ICU4XError_DecimalDivisionByZeroError = 1282,
   ICU4XError_PluralParserError = 1536,
 } ICU4XError;
 
@@ -68,6 +68,7 @@ enum struct ICU4XError {
   PropertyUnknownGeneralCategoryGroupError = 1025,
   DecimalLimitError = 1280,
   DecimalSyntaxError = 1281,
+  DecimalDivisionByZeroError = 1282,
   PluralParserError = 1536,
 };
 
@@ -65,6 +65,7 @@ pub mod ffi {
         // decimal errors
         DecimalLimitError = 0x5_00,
         DecimalSyntaxError = 0x5_01,
+        DecimalDivisionByZeroError = 0x5_02,
 
         // plural errors
         PluralParserError = 0x6_00,
@@ -127,6 +128,8 @@ impl From<DecimalError> for ICU4XError {
         match e {
             DecimalError::Limit => ICU4XError::DecimalLimitError,
             DecimalError::Syntax => ICU4XError::DecimalSyntaxError,
+            DecimalError::DivisionByZero => ICU4XError::DecimalDivisionByZeroError,
+            _ => ICU4XError::UnknownError,
         }
     }
 }
@@ -145,6 +148,7 @@ impl From<FixedDecimalFormatError> for ICU4XError {
     fn from(e: FixedDecimalFormatError) -> Self {
         match e {
             FixedDecimalFormatError::Data(e) => e.into(),
+            FixedDecimalFormatError::PluralRules(e) => e.into(),
             _ => ICU4XError::UnknownError,
         }
     }
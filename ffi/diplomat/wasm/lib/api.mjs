@@ -545,6 +545,7 @@ const ICU4XError_js_to_rust = {
   "PropertyUnknownGeneralCategoryGroupError": 1025,
   "DecimalLimitError": 1280,
   "DecimalSyntaxError": 1281,
+  "DecimalDivisionByZeroError": 1282,
   "PluralParserError": 1536,
 };
 const ICU4XError_rust_to_js = {
@@ -572,6 +573,7 @@ const ICU4XError_rust_to_js = {
   1025: "PropertyUnknownGeneralCategoryGroupError",
   1280: "DecimalLimitError",
   1281: "DecimalSyntaxError",
+  1282: "DecimalDivisionByZeroError",
   1536: "PluralParserError",
 };
 
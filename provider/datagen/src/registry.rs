@@ -20,20 +20,45 @@ pub fn all_keys() -> Vec<ResourceKey> {
         icu_datetime::provider::time_zones::MetaZonePeriodV1Marker::KEY,
         icu_datetime::provider::time_zones::MetaZoneSpecificNamesLongV1Marker::KEY,
         icu_datetime::provider::time_zones::MetaZoneSpecificNamesShortV1Marker::KEY,
+        icu_datetime::provider::time_zones::TimeZoneIdentifiersV1Marker::KEY,
+        icu_datetime::provider::time_zones::TimeZoneRegionNamesV1Marker::KEY,
+        icu_datetime::provider::time_zones::WindowsZonesV1Marker::KEY,
         icu_datetime::provider::week_data::WeekDataV1Marker::KEY,
+        icu_decimal::provider::CurrencyDisplayNamesV1Marker::KEY,
+        icu_decimal::provider::CurrencyFractionsV1Marker::KEY,
+        icu_decimal::provider::CurrencyPatternsV1Marker::KEY,
         icu_decimal::provider::DecimalSymbolsV1Marker::KEY,
+        icu_decimal::provider::LongCompactDecimalFormatDataV1Marker::KEY,
+        icu_decimal::provider::MiscPatternsV1Marker::KEY,
+        icu_decimal::provider::PercentPatternV1Marker::KEY,
+        icu_decimal::provider::ScientificSymbolsV1Marker::KEY,
+        icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY,
         icu_list::provider::AndListV1Marker::KEY,
         icu_list::provider::OrListV1Marker::KEY,
         icu_list::provider::UnitListV1Marker::KEY,
         icu_locale_canonicalizer::provider::AliasesV1Marker::KEY,
+        icu_locale_canonicalizer::provider::LanguageMatchingV1Marker::KEY,
         icu_locale_canonicalizer::provider::LikelySubtagsV1Marker::KEY,
+        icu_plurals::provider::CardinalSamplesV1Marker::KEY,
         icu_plurals::provider::CardinalV1Marker::KEY,
+        icu_plurals::provider::OrdinalSamplesV1Marker::KEY,
         icu_plurals::provider::OrdinalV1Marker::KEY,
+        icu_plurals::provider::PluralRangesV1Marker::KEY,
         icu_provider_adapters::fallback::provider::LocaleFallbackLikelySubtagsV1Marker::KEY,
         icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker::KEY,
         #[cfg(feature = "experimental")]
         icu_casemapping::provider::CaseMappingV1Marker::KEY,
         #[cfg(feature = "experimental")]
+        icu_displaynames::provider::LanguageDisplayNamesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_displaynames::provider::LocaleDisplayNamesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_displaynames::provider::RegionDisplayNamesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_displaynames::provider::ScriptDisplayNamesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_displaynames::provider::VariantDisplayNamesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
         icu_normalizer::provider::CanonicalDecompositionDataV1Marker::KEY,
         #[cfg(feature = "experimental")]
         icu_normalizer::provider::CompatibilityDecompositionSupplementV1Marker::KEY,
@@ -51,6 +76,18 @@ pub fn all_keys() -> Vec<ResourceKey> {
         icu_normalizer::provider::CompatibilityCompositionPassthroughV1Marker::KEY,
         #[cfg(feature = "experimental")]
         icu_normalizer::provider::Uts46CompositionPassthroughV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_rbnf::provider::RbnfRulesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_units::provider::LongUnitPatternsV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_units::provider::NarrowUnitPatternsV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_units::provider::ShortUnitPatternsV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_units::provider::UnitPreferencesV1Marker::KEY,
+        #[cfg(feature = "experimental")]
+        icu_units::provider::UnitsConversionV1Marker::KEY,
     ];
     v.extend(icu_properties::provider::ALL_KEYS);
     #[cfg(feature = "experimental")]
@@ -104,9 +141,12 @@ macro_rules! create_datagen_provider {
                 $crate::transform::cldr::CommonDateProvider,
                 $crate::transform::cldr::FallbackRulesProvider,
                 $crate::transform::cldr::JapaneseErasProvider,
+                $crate::transform::cldr::LanguageMatchingProvider,
                 $crate::transform::cldr::LikelySubtagsProvider,
                 $crate::transform::cldr::NumbersProvider,
                 $crate::transform::cldr::PluralsProvider,
+                $crate::transform::cldr::PluralRangesProvider,
+                $crate::transform::cldr::PluralSamplesProvider,
                 $crate::transform::cldr::TimeZonesProvider,
                 $crate::transform::cldr::WeekDataProvider,
                 $crate::transform::cldr::ListProvider,
@@ -170,14 +210,21 @@ macro_rules! create_datagen_provider {
             [
                 $crate::transform::cldr::AliasesProvider,
                 $crate::transform::cldr::CommonDateProvider,
+                $crate::transform::cldr::DisplayNamesProvider,
                 $crate::transform::cldr::FallbackRulesProvider,
                 $crate::transform::cldr::JapaneseErasProvider,
+                $crate::transform::cldr::LanguageMatchingProvider,
                 $crate::transform::cldr::LikelySubtagsProvider,
                 $crate::transform::cldr::NumbersProvider,
                 $crate::transform::cldr::PluralsProvider,
+                $crate::transform::cldr::PluralRangesProvider,
+                $crate::transform::cldr::PluralSamplesProvider,
                 $crate::transform::cldr::TimeZonesProvider,
                 $crate::transform::cldr::WeekDataProvider,
                 $crate::transform::cldr::ListProvider,
+                $crate::transform::cldr::RbnfProvider,
+                $crate::transform::cldr::UnitsProvider,
+                $crate::transform::cldr::UnitsSupplementalProvider,
                 $crate::transform::uprops::CaseMappingDataProvider,
                 $crate::transform::uprops::EnumeratedPropertyCodePointTrieProvider,
                 $crate::transform::uprops::ScriptWithExtensionsPropertyProvider,
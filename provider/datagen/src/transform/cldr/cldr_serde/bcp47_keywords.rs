@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON BCP-47 keyword files, such as calendar.json.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-bcp47/bcp47/calendar.json>
+
+use litemap::LiteMap;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Bcp47Type {
+    #[serde(rename = "_description")]
+    pub description: String,
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+    #[serde(rename = "_deprecated")]
+    pub deprecated: Option<bool>,
+    #[serde(rename = "_preferred")]
+    pub preferred: Option<String>,
+    #[serde(rename = "_since")]
+    pub since: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Bcp47Key {
+    #[serde(rename = "_description")]
+    pub description: String,
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+    #[serde(rename = "_deprecated")]
+    pub deprecated: Option<bool>,
+    #[serde(rename = "_preferred")]
+    pub preferred: Option<String>,
+    #[serde(rename = "_since")]
+    pub since: Option<String>,
+    #[serde(rename = "_valueType")]
+    pub value_type: Option<String>,
+    #[serde(flatten)]
+    pub types: LiteMap<String, Bcp47Type>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Keyword {
+    pub u: LiteMap<String, Bcp47Key>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub keyword: Keyword,
+}
@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON currencies.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-numbers-full/main/en/currencies.json>
+
+use icu_locid::LanguageIdentifier;
+use litemap::LiteMap;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Currency {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub symbol: Option<String>,
+    #[serde(rename = "symbol-alt-narrow")]
+    pub narrow_symbol: Option<String>,
+    /// Remaining fields, including the names keyed by plural category, such as
+    /// "displayName-count-one"
+    #[serde(flatten)]
+    pub other: LiteMap<String, String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Numbers {
+    /// Map from ISO 4217 code to currency
+    pub currencies: LiteMap<String, Currency>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangNumbers {
+    pub numbers: Numbers,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub LiteMap<LanguageIdentifier, LangNumbers>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON locale display name files, such as languages.json
+//! and localeDisplayNames.json.
+//!
+//! Sample files:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/languages.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/localeDisplayNames.json>
+
+use icu_locid::LanguageIdentifier;
+use litemap::LiteMap;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocaleDisplayPattern {
+    #[serde(rename = "localePattern")]
+    pub locale_pattern: String,
+    #[serde(rename = "localeSeparator")]
+    pub locale_separator: String,
+    #[serde(rename = "localeKeyTypePattern")]
+    pub locale_key_type_pattern: String,
+}
+
+/// The names of one file, each of which only has one of the sections.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocaleDisplayNames {
+    #[serde(default)]
+    pub languages: LiteMap<String, String>,
+    #[serde(default)]
+    pub scripts: LiteMap<String, String>,
+    #[serde(default)]
+    pub territories: LiteMap<String, String>,
+    #[serde(default)]
+    pub variants: LiteMap<String, String>,
+    #[serde(rename = "localeDisplayPattern")]
+    pub locale_display_pattern: Option<LocaleDisplayPattern>,
+    #[serde(default)]
+    pub keys: LiteMap<String, String>,
+    #[serde(default)]
+    pub types: LiteMap<String, LiteMap<String, String>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangDisplayNames {
+    #[serde(rename = "localeDisplayNames")]
+    pub locale_display_names: LocaleDisplayNames,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub LiteMap<LanguageIdentifier, LangDisplayNames>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
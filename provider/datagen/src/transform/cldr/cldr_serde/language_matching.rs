@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON languageMatching.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/languageMatching.json>
+
+use litemap::LiteMap;
+use serde::Deserialize;
+
+/// The attributes of an entry, which is either the paradigm locales, a match variable, or a
+/// language match keyed by its supported pattern.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Attributes {
+    #[serde(rename = "_locales")]
+    pub locales: Option<String>,
+    #[serde(rename = "_value")]
+    pub value: Option<String>,
+    #[serde(rename = "_desired")]
+    pub desired: Option<String>,
+    #[serde(rename = "_distance")]
+    pub distance: Option<String>,
+    #[serde(rename = "_oneway")]
+    pub oneway: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LanguageMatches {
+    #[serde(rename = "written-new")]
+    pub written_new: Vec<LiteMap<String, Attributes>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "languageMatching")]
+    pub language_matching: LanguageMatches,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
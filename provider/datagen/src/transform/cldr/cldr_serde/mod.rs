@@ -8,13 +8,27 @@
 //! deserialization support. These structures can be used in the transformers.
 
 pub mod aliases;
+#[cfg(feature = "experimental")]
+pub mod bcp47_keywords;
 pub mod ca;
+pub mod currencies;
+pub mod currency_data;
+pub mod displaynames;
 pub mod japanese;
+pub mod language_matching;
 pub mod likely_subtags;
 pub mod list_patterns;
 pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
+pub mod plural_ranges;
 pub mod plurals;
+#[cfg(feature = "experimental")]
+pub mod rbnf;
+pub mod territory_containment;
 pub mod time_zones;
+#[cfg(feature = "experimental")]
+pub mod units;
+#[cfg(feature = "experimental")]
+pub mod units_supplemental;
 pub mod week_data;
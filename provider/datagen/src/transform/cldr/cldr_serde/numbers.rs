@@ -23,11 +23,79 @@ pub struct Symbols {
     pub minus_sign: String,
     #[serde(rename = "plusSign")]
     pub plus_sign: String,
+    pub exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub superscripting_exponent: String,
+    #[serde(rename = "percentSign")]
+    pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct DecimalFormats {
     pub standard: String,
+    pub short: Option<CompactDecimalFormats>,
+    pub long: Option<CompactDecimalFormats>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CompactDecimalFormats {
+    /// Map from keys of the form "1000-count-one" to patterns
+    #[serde(rename = "decimalFormat")]
+    pub decimal_format: LiteMap<String, String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ScientificFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PercentFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacing {
+    #[serde(rename = "insertBetween")]
+    pub insert_between: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacings {
+    #[serde(rename = "afterCurrency")]
+    pub after_currency: CurrencySpacing,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyFormats {
+    pub standard: String,
+    pub accounting: String,
+    #[serde(rename = "currencySpacing")]
+    pub currency_spacing: CurrencySpacings,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct MiscPatterns {
+    pub approximately: String,
+    #[serde(rename = "atLeast")]
+    pub at_least: String,
+    #[serde(rename = "atMost")]
+    pub at_most: String,
+    pub range: String,
 }
 
 #[derive(PartialEq, Debug, Default)]
@@ -36,6 +104,14 @@ pub struct NumberingSystemData {
     pub symbols: LiteMap<TinyStr8, Symbols>,
     /// Map from numbering system to decimal formats
     pub formats: LiteMap<TinyStr8, DecimalFormats>,
+    /// Map from numbering system to scientific formats
+    pub scientific_formats: LiteMap<TinyStr8, ScientificFormats>,
+    /// Map from numbering system to percent formats
+    pub percent_formats: LiteMap<TinyStr8, PercentFormats>,
+    /// Map from numbering system to currency formats
+    pub currency_formats: LiteMap<TinyStr8, CurrencyFormats>,
+    /// Map from numbering system to misc patterns
+    pub misc_patterns: LiteMap<TinyStr8, MiscPatterns>,
 }
 
 pub struct NumberingSystemDataVisitor;
@@ -71,8 +147,24 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: DecimalFormats = access.next_value()?;
                     result.formats.insert(numsys, value);
                 }
+                "scientificFormats" => {
+                    let value: ScientificFormats = access.next_value()?;
+                    result.scientific_formats.insert(numsys, value);
+                }
+                "percentFormats" => {
+                    let value: PercentFormats = access.next_value()?;
+                    result.percent_formats.insert(numsys, value);
+                }
+                "currencyFormats" => {
+                    let value: CurrencyFormats = access.next_value()?;
+                    result.currency_formats.insert(numsys, value);
+                }
+                "miscPatterns" => {
+                    let value: MiscPatterns = access.next_value()?;
+                    result.misc_patterns.insert(numsys, value);
+                }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume other data by numbering system.
                     // For now, ignore them.
                 }
             }
@@ -94,6 +186,10 @@ impl<'de> Deserialize<'de> for NumberingSystemData {
 pub struct Numbers {
     #[serde(rename = "defaultNumberingSystem")]
     pub default_numbering_system: TinyStr8,
+    /// Map from numbering system aliases ("native", "traditional", "finance") to numbering systems
+    #[serde(rename = "otherNumberingSystems")]
+    #[serde(default)]
+    pub other_numbering_systems: LiteMap<String, TinyStr8>,
     #[serde(rename = "minimumGroupingDigits")]
     #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
     pub minimum_grouping_digits: u8,
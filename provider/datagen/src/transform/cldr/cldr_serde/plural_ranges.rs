@@ -0,0 +1,27 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON pluralRanges.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/pluralRanges.json>
+
+use icu_locid::LanguageIdentifier;
+use litemap::LiteMap;
+use serde::Deserialize;
+
+/// The category of each range of a locale, keyed by strings such as
+/// `pluralRange-start-one-end-other`.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocalePluralRanges(pub LiteMap<String, String>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    pub plurals: LiteMap<LanguageIdentifier, LocalePluralRanges>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
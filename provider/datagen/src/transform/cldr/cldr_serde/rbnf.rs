@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON rbnf files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-rbnf/rbnf/en.json>
+
+use litemap::LiteMap;
+use serde::Deserialize;
+
+/// The rules of a rule set as pairs of descriptor and body, such as `("100", "←← hundred[ →→];")`.
+pub type RuleSet = Vec<(String, String)>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct RuleSetGroups {
+    #[serde(rename = "SpelloutRules", default)]
+    pub spellout: LiteMap<String, RuleSet>,
+    #[serde(rename = "OrdinalRules", default)]
+    pub ordinal: LiteMap<String, RuleSet>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Rbnf {
+    pub rbnf: RuleSetGroups,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub rbnf: Rbnf,
+}
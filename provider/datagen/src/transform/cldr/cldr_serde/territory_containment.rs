@@ -0,0 +1,29 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON territoryContainment.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/territoryContainment.json>
+
+use litemap::LiteMap;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Containment {
+    #[serde(rename = "_contains")]
+    pub contains: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// Keyed by a region, or by a region with a suffix such as `-status-grouping`.
+    #[serde(rename = "territoryContainment")]
+    pub territory_containment: LiteMap<String, Containment>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
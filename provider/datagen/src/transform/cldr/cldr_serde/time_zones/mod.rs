@@ -5,6 +5,7 @@
 pub mod bcp47_tzid;
 pub mod meta_zones;
 pub mod time_zone_names;
+pub mod windows_zones;
 
 use icu_datetime::provider::time_zones::{MetaZoneId, TimeZoneBcp47Id};
 use litemap::LiteMap;
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/en/units.json>
+
+use icu_locid::LanguageIdentifier;
+use litemap::LiteMap;
+use serde::Deserialize;
+
+/// The patterns of one unit or compound unit pattern, such as `unitPattern-count-one`
+/// or `compoundUnitPattern`, keyed by their CLDR attribute name.
+pub type UnitPatterns = LiteMap<String, String>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    pub long: LiteMap<String, UnitPatterns>,
+    pub short: LiteMap<String, UnitPatterns>,
+    pub narrow: LiteMap<String, UnitPatterns>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangUnits {
+    pub units: Units,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub LiteMap<LanguageIdentifier, LangUnits>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
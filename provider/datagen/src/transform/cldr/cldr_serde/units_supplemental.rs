@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON supplemental units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/units.json>
+
+use litemap::LiteMap;
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitConstant {
+    /// An expression of numbers and other constants, such as `ft_to_m*ft_to_m`.
+    #[serde(rename = "_value")]
+    pub value: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ConvertUnit {
+    #[serde(rename = "_baseUnit")]
+    pub base_unit: String,
+    /// An expression such as `ft_to_m/12`, or `None` for a factor of 1.
+    #[serde(rename = "_factor")]
+    pub factor: Option<String>,
+    /// An expression such as `2298.35/9`, or `None` for an offset of 0.
+    #[serde(rename = "_offset")]
+    pub offset: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitPreference {
+    pub unit: String,
+    /// The smallest value in the unit for which it is used, or `None` for all values.
+    pub geq: Option<String>,
+}
+
+/// Unit preferences keyed by space-separated regions, such as `001` or `BS BZ KY PR PW US`.
+pub type RegionPreferences = LiteMap<String, Vec<UnitPreference>>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "unitConstants")]
+    pub unit_constants: LiteMap<String, UnitConstant>,
+    #[serde(rename = "convertUnits")]
+    pub convert_units: LiteMap<String, ConvertUnit>,
+    /// Preferences keyed by quantity, such as `length`, and usage, such as `person-height`.
+    #[serde(rename = "unitPreferenceData")]
+    pub unit_preference_data: LiteMap<String, LiteMap<String, RegionPreferences>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -0,0 +1,266 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR compact decimal patterns.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Compact_Number_Formats>
+
+use super::NumbersProvider;
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use tinystr::tinystr;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::ZeroMap2d;
+
+/// Parses a compact decimal pattern, such as `0K` or `00 thousand`, for the given magnitude.
+///
+/// The number of zeros in the pattern is the number of integer digits of the compacted number,
+/// and the pattern `0` means that numbers of the magnitude are not compacted.
+fn parse_pattern(
+    pattern: &str,
+    magnitude: i8,
+) -> Result<CompactPattern<'static>, Cow<'static, str>> {
+    // Negative subpatterns are not used by compact patterns.
+    let pattern = pattern.split(';').next().unwrap_or(pattern);
+    if pattern == "0" {
+        return Ok(CompactPattern {
+            exponent: 0,
+            index: 0,
+            literal_text: Cow::Borrowed(""),
+        });
+    }
+
+    let mut literal_text = String::new();
+    let mut index = None;
+    let mut zeros = 0;
+    let mut in_quotes = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                literal_text.push('\'');
+            }
+            '\'' => in_quotes = !in_quotes,
+            '0' if !in_quotes => {
+                match index {
+                    None => index = Some(literal_text.len()),
+                    Some(i) if i != literal_text.len() => {
+                        return Err(
+                            format!("Multiple numbers in compact pattern: {}", pattern).into()
+                        )
+                    }
+                    Some(_) => (),
+                }
+                zeros += 1;
+            }
+            _ => literal_text.push(c),
+        }
+    }
+
+    let index = index.ok_or_else(|| format!("No number in compact pattern: {}", pattern))?;
+    Ok(CompactPattern {
+        exponent: magnitude - (zeros - 1),
+        index: u8::try_from(index)
+            .map_err(|_| format!("Compact pattern is too long: {}", pattern))?,
+        literal_text: Cow::Owned(literal_text),
+    })
+}
+
+/// Converts the CLDR compact decimal patterns of one length, keyed by type and plural category.
+///
+/// Patterns that are equal to the `other` pattern of their magnitude, and magnitudes whose
+/// patterns are equal to those of the next lower magnitude, are omitted, since the formatter
+/// falls back to them.
+fn convert_patterns(
+    formats: &cldr_serde::numbers::CompactDecimalFormats,
+) -> Result<CompactDecimalPatternDataV1<'static>, Cow<'static, str>> {
+    let mut patterns_by_magnitude = BTreeMap::<i16, BTreeMap<Count, CompactPattern>>::new();
+    for (key, pattern) in formats.decimal_format.iter() {
+        // Keys are of the form "1000-count-one"; alternative patterns are not supported.
+        let (type_, count) = match key.split_once("-count-") {
+            Some((type_, count)) if !count.contains('-') => (type_, count),
+            _ => continue,
+        };
+        let magnitude = match type_.strip_prefix('1') {
+            Some(zeros) if zeros.bytes().all(|b| b == b'0') => i8::try_from(zeros.len())
+                .map_err(|_| format!("Compact pattern type is too large: {}", type_))?,
+            _ => return Err(format!("Invalid compact pattern type: {}", type_).into()),
+        };
+        // Explicit counts such as "1" are not supported.
+        let count: Count = match PluralCategory::from_tr35_string(count) {
+            Some(category) => category.into(),
+            None => continue,
+        };
+        patterns_by_magnitude
+            .entry(i16::from(magnitude))
+            .or_default()
+            .insert(count, parse_pattern(pattern, magnitude)?);
+    }
+
+    let mut patterns = ZeroMap2d::new();
+    let mut previous = None;
+    for (magnitude, mut magnitude_patterns) in patterns_by_magnitude {
+        let other = magnitude_patterns
+            .get(&Count::Other)
+            .cloned()
+            .ok_or_else(|| format!("Missing other pattern for magnitude {}", magnitude))?;
+        magnitude_patterns.retain(|&count, pattern| count == Count::Other || *pattern != other);
+        if previous.as_ref() == Some(&magnitude_patterns) {
+            continue;
+        }
+        for (count, pattern) in magnitude_patterns.iter() {
+            patterns.insert(&magnitude, count, &*encode_varule_to_box(pattern));
+        }
+        previous = Some(magnitude_patterns);
+    }
+    Ok(CompactDecimalPatternDataV1 { patterns })
+}
+
+impl NumbersProvider {
+    /// Loads the compact decimal patterns of the length selected by `select`.
+    ///
+    /// Like the symbols, this falls back to the patterns of "latn" if the locale does not have
+    /// any for the numbering system.
+    fn load_compact_patterns(
+        &self,
+        req: &DataRequest,
+        select: fn(
+            &cldr_serde::numbers::DecimalFormats,
+        ) -> Option<&cldr_serde::numbers::CompactDecimalFormats>,
+    ) -> Result<CompactDecimalPatternDataV1<'static>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
+        let formats = &numbers.numsys_data.formats;
+        match formats
+            .get(&nsname)
+            .and_then(select)
+            .or_else(|| formats.get(&tinystr!(8, "latn")).and_then(select))
+        {
+            Some(compact_formats) => convert_patterns(compact_formats).map_err(|s| {
+                DataError::custom("Could not create compact decimal patterns")
+                    .with_display_context(&s)
+            }),
+            None => Ok(Default::default()),
+        }
+    }
+}
+
+macro_rules! impl_resource_provider {
+    ($(($marker:ident, $length:ident)),+) => {
+        $(
+            impl ResourceProvider<$marker> for NumbersProvider {
+                fn load_resource(
+                    &self,
+                    req: &DataRequest,
+                ) -> Result<DataResponse<$marker>, DataError> {
+                    let result = self.load_compact_patterns(req, |formats| formats.$length.as_ref())?;
+
+                    let metadata = DataResponseMetadata::default();
+                    // TODO(#1109): Set metadata.data_langid correctly.
+                    Ok(DataResponse {
+                        metadata,
+                        payload: Some(DataPayload::from_owned(result)),
+                    })
+                }
+            }
+
+            impl IterableResourceProvider<$marker> for NumbersProvider {
+                fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+                    self.supported_options_with_numbering_systems()
+                }
+            }
+        )+
+    };
+}
+
+impl_resource_provider!(
+    (ShortCompactDecimalFormatDataV1Marker, short),
+    (LongCompactDecimalFormatDataV1Marker, long)
+);
+
+#[test]
+fn test_parse_pattern() {
+    assert_eq!(
+        parse_pattern("00K", 4),
+        Ok(CompactPattern {
+            exponent: 3,
+            index: 0,
+            literal_text: Cow::Borrowed("K"),
+        })
+    );
+    assert_eq!(
+        parse_pattern("0 'mil' M", 6),
+        Ok(CompactPattern {
+            exponent: 6,
+            index: 0,
+            literal_text: Cow::Borrowed(" mil M"),
+        })
+    );
+    assert_eq!(
+        parse_pattern("\u{200f}000 ألف", 5),
+        Ok(CompactPattern {
+            exponent: 3,
+            index: 3,
+            literal_text: Cow::Borrowed("\u{200f} ألف"),
+        })
+    );
+    assert_eq!(
+        parse_pattern("0", 3),
+        Ok(CompactPattern {
+            exponent: 0,
+            index: 0,
+            literal_text: Cow::Borrowed(""),
+        })
+    );
+    assert!(parse_pattern("0K0", 3).is_err());
+    assert!(parse_pattern("K", 3).is_err());
+}
+
+#[test]
+fn test_compact_patterns() {
+    use icu_locid::locale;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let ja_short: DataPayload<ShortCompactDecimalFormatDataV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let get = |magnitude: i16| {
+        CompactPattern::zero_from(
+            ja_short
+                .get()
+                .patterns
+                .get(&magnitude, &Count::Other)
+                .unwrap(),
+        )
+    };
+
+    // Japanese groups by four digits, so only every fourth magnitude has new patterns.
+    let magnitudes = ja_short
+        .get()
+        .patterns
+        .iter0()
+        .map(|cursor| zerovec::ule::AsULE::from_unaligned(*cursor.key0()))
+        .collect::<Vec<i16>>();
+    assert_eq!(magnitudes, [3, 4, 8, 12]);
+
+    // Thousands are not compacted in Japanese; ten thousands are.
+    assert_eq!(get(3).exponent, 0);
+    assert_eq!(get(4).exponent, 4);
+    assert_eq!(get(4).literal_text, "万");
+    assert_eq!(get(8).exponent, 8);
+    assert_eq!(get(8).literal_text, "億");
+}
@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR currency patterns, display names, and fractions.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Currencies>
+
+use super::decimal_pattern::DecimalPattern;
+use super::NumbersProvider;
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use tinystr::{tinystr, TinyAsciiStr};
+use zerovec::ZeroMap;
+
+/// Parses an ISO 4217 currency code.
+fn parse_code(code: &str) -> Result<TinyAsciiStr<3>, DataError> {
+    code.parse()
+        .map_err(|_| DataError::custom("Invalid currency code").with_display_context(&code))
+}
+
+impl ResourceProvider<CurrencyPatternsV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<CurrencyPatternsV1Marker>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
+        let latn = tinystr!(8, "latn");
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.symbols.get(&latn))
+            .ok_or_else(|| {
+                DataError::custom("Could not find symbols for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.currency_formats.get(&latn))
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        let parse = |pattern: &str| -> Result<CurrencyPatternV1<'static>, DataError> {
+            let parsed: DecimalPattern = pattern.parse().map_err(|e| {
+                DataError::custom("Could not parse currency pattern").with_display_context(&e)
+            })?;
+            Ok(parsed.localize_currency(&symbols.minus_sign))
+        };
+
+        let mut name_patterns = ZeroMap::new();
+        name_patterns.insert(&Count::Other, formats.unit_pattern_other.as_str());
+        for (count, pattern) in [
+            (Count::Zero, &formats.unit_pattern_zero),
+            (Count::One, &formats.unit_pattern_one),
+            (Count::Two, &formats.unit_pattern_two),
+            (Count::Few, &formats.unit_pattern_few),
+            (Count::Many, &formats.unit_pattern_many),
+        ] {
+            match pattern {
+                Some(pattern) if *pattern != formats.unit_pattern_other => {
+                    name_patterns.insert(&count, pattern.as_str());
+                }
+                _ => (),
+            }
+        }
+
+        let result = CurrencyPatternsV1 {
+            standard: parse(&formats.standard)?,
+            accounting: parse(&formats.accounting)?,
+            spacing: Cow::Owned(
+                formats
+                    .currency_spacing
+                    .after_currency
+                    .insert_between
+                    .clone(),
+            ),
+            name_patterns,
+        };
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl IterableResourceProvider<CurrencyPatternsV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        self.supported_options_with_numbering_systems()
+    }
+}
+
+impl ResourceProvider<CurrencyDisplayNamesV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<CurrencyDisplayNamesV1Marker>, DataError> {
+        let langid = req.options.get_langid();
+        let resource: &cldr_serde::currencies::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "currencies.json")?;
+
+        let currencies = &resource
+            .main
+            .0
+            .get(&langid)
+            .ok_or_else(|| DataErrorKind::MissingLocale.into_error())?
+            .numbers
+            .currencies;
+
+        let mut result = CurrencyDisplayNamesV1::default();
+        for (code, currency) in currencies.iter() {
+            let iso_code = parse_code(code)?;
+            let symbol = currency.symbol.as_deref().unwrap_or(code);
+            if symbol != code {
+                result.symbols.insert(&iso_code, symbol);
+            }
+            if let Some(narrow_symbol) = currency.narrow_symbol.as_deref() {
+                if narrow_symbol != symbol {
+                    result.narrow_symbols.insert(&iso_code, narrow_symbol);
+                }
+            }
+
+            let other = currency
+                .other
+                .get("displayName-count-other")
+                .or(currency.display_name.as_ref());
+            if let Some(other) = other {
+                result
+                    .names
+                    .insert(&iso_code, &Count::Other, other.as_str());
+                for (key, name) in currency.other.iter() {
+                    let category = match key
+                        .strip_prefix("displayName-count-")
+                        .and_then(PluralCategory::from_tr35_string)
+                    {
+                        Some(category) => category,
+                        None => continue,
+                    };
+                    if category != PluralCategory::Other && name != other {
+                        result
+                            .names
+                            .insert(&iso_code, &Count::from(category), name.as_str());
+                    }
+                }
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl IterableResourceProvider<CurrencyDisplayNamesV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(Into::<ResourceOptions>::into)
+            .collect())
+    }
+}
+
+impl ResourceProvider<CurrencyFractionsV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<CurrencyFractionsV1Marker>, DataError> {
+        if !req.options.is_empty() {
+            return Err(DataErrorKind::ExtraneousResourceOptions.into_error());
+        }
+
+        let resource: &cldr_serde::currency_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        let convert = |fractions: &cldr_serde::currency_data::Fractions| CurrencyFractions {
+            digits: fractions.digits,
+            rounding: fractions.rounding,
+            cash_digits: fractions.cash_digits.unwrap_or(fractions.digits),
+            cash_rounding: fractions.cash_rounding.unwrap_or(fractions.rounding),
+        };
+
+        let all_fractions = &resource.supplemental.currency_data.fractions;
+        let default = all_fractions
+            .get("DEFAULT")
+            .map(convert)
+            .ok_or_else(|| DataError::custom("Missing default currency fractions"))?;
+        let mut fractions = ZeroMap::new();
+        for (code, currency_fractions) in all_fractions.iter() {
+            if code == "DEFAULT" {
+                continue;
+            }
+            let currency_fractions = convert(currency_fractions);
+            if currency_fractions != default {
+                fractions.insert(&parse_code(code)?, &currency_fractions);
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(CurrencyFractionsV1 {
+                fractions,
+                default,
+            })),
+        })
+    }
+}
+
+impl IterableResourceProvider<CurrencyFractionsV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_currency_data() {
+    use icu_locid::locale;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let en_names: DataPayload<CurrencyDisplayNamesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let cad = tinystr!(3, "CAD");
+    let chf = tinystr!(3, "CHF");
+    assert_eq!(en_names.get().symbols.get(&cad), Some("CA$"));
+    assert_eq!(en_names.get().narrow_symbols.get(&cad), Some("$"));
+    // The symbol of CHF is its ISO code.
+    assert_eq!(en_names.get().symbols.get(&chf), None);
+    assert_eq!(
+        en_names.get().names.get(&cad, &Count::One),
+        Ok("Canadian dollar")
+    );
+    assert_eq!(
+        en_names.get().names.get(&cad, &Count::Other),
+        Ok("Canadian dollars")
+    );
+
+    let fractions: DataPayload<CurrencyFractionsV1Marker> = provider
+        .load_resource(&Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fractions.get().default.digits, 2);
+    assert_eq!(
+        fractions.get().fractions.get_copied(&tinystr!(3, "USD")),
+        None
+    );
+    assert_eq!(
+        fractions
+            .get()
+            .fractions
+            .get_copied(&tinystr!(3, "JPY"))
+            .map(|f| f.digits),
+        Some(0)
+    );
+    assert_eq!(
+        fractions
+            .get()
+            .fractions
+            .get_copied(&chf)
+            .map(|f| (f.cash_digits, f.cash_rounding)),
+        Some((2, 5))
+    );
+}
+
+#[test]
+fn test_currency_patterns() {
+    use icu_locid::locale;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let fr: DataPayload<CurrencyPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fr.get().standard.positive.suffix, "\u{a0}¤");
+    assert_eq!(fr.get().standard.negative.prefix, "-");
+    assert_eq!(fr.get().accounting.negative.prefix, "(");
+    assert_eq!(fr.get().accounting.negative.suffix, "\u{a0}¤)");
+    assert_eq!(fr.get().name_patterns.get(&Count::Other), Some("{0} {1}"));
+}
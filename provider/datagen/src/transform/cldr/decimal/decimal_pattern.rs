@@ -7,7 +7,7 @@
 //! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Number_Format_Patterns>
 
 use displaydoc::Display;
-use icu_decimal::provider::AffixesV1;
+use icu_decimal::provider::{AffixesV1, CurrencyPatternV1, PercentPatternV1};
 use itertools::Itertools;
 use std::borrow::Cow;
 use std::str::FromStr;
@@ -64,6 +64,10 @@ impl FromStr for DecimalSubPattern {
         let (a, b, c, d) = match body {
             "#,##0.###" => (3, 3, 0, 3),
             "#,##,##0.###" => (3, 2, 0, 3),
+            "#,##0" => (3, 3, 0, 0),
+            "#,##,##0" => (3, 2, 0, 0),
+            "#,##0.00" => (3, 3, 2, 2),
+            "#,##,##0.00" => (3, 2, 2, 2),
             "0.######" => (0, 0, 0, 6),
             _ => return Err(Error::UnknownPatternBody(body.to_string())),
         };
@@ -116,6 +120,51 @@ impl DecimalPattern {
             suffix: Cow::Owned(signed_affixes.1.replace('-', sign_str)),
         }
     }
+
+    /// Returns the affixes of a currency pattern, which contain the currency placeholder `¤`.
+    pub fn localize_currency(&self, minus_sign: &str) -> CurrencyPatternV1<'static> {
+        let (positive, negative) = self.localize_affixes(minus_sign);
+        CurrencyPatternV1 { positive, negative }
+    }
+
+    /// Returns the affixes of a percent pattern, which contain the percent placeholder `%`,
+    /// with the percent and permille signs.
+    pub fn localize_percent(
+        &self,
+        minus_sign: &str,
+        percent_sign: &str,
+        permille_sign: &str,
+    ) -> PercentPatternV1<'static> {
+        let (positive, negative) = self.localize_affixes(minus_sign);
+        PercentPatternV1 {
+            positive,
+            negative,
+            percent_sign: Cow::Owned(percent_sign.to_owned()),
+            permille_sign: Cow::Owned(permille_sign.to_owned()),
+        }
+    }
+
+    /// Returns the affixes for positive and negative numbers, with the localized minus sign.
+    fn localize_affixes(&self, minus_sign: &str) -> (AffixesV1<'static>, AffixesV1<'static>) {
+        let positive = &self.positive;
+        // UTS 35: the absence of a negative pattern means the positive pattern with a
+        // prefixed sign
+        let negative = match &self.negative {
+            Some(subpattern) => AffixesV1 {
+                prefix: Cow::Owned(subpattern.prefix.replace('-', minus_sign)),
+                suffix: Cow::Owned(subpattern.suffix.replace('-', minus_sign)),
+            },
+            None => AffixesV1 {
+                prefix: Cow::Owned(format!("{}{}", minus_sign, positive.prefix)),
+                suffix: Cow::Owned(positive.suffix.clone()),
+            },
+        };
+        let positive = AffixesV1 {
+            prefix: Cow::Owned(positive.prefix.clone()),
+            suffix: Cow::Owned(positive.suffix.clone()),
+        };
+        (positive, negative)
+    }
 }
 
 #[test]
@@ -221,3 +270,56 @@ fn test_basic() {
         assert_eq!(cas.expected, actual, "Pattern: {}", cas.pattern);
     }
 }
+
+#[test]
+fn test_currency() {
+    let standard: DecimalPattern = "¤#,##0.00".parse().unwrap();
+    assert_eq!(
+        standard.localize_currency("\u{200e}-"),
+        CurrencyPatternV1 {
+            positive: AffixesV1 {
+                prefix: "¤".into(),
+                suffix: "".into(),
+            },
+            negative: AffixesV1 {
+                prefix: "\u{200e}-¤".into(),
+                suffix: "".into(),
+            },
+        }
+    );
+
+    let accounting: DecimalPattern = "#,##,##0.00¤;(#,##,##0.00¤)".parse().unwrap();
+    assert_eq!(
+        accounting.localize_currency("-"),
+        CurrencyPatternV1 {
+            positive: AffixesV1 {
+                prefix: "".into(),
+                suffix: "¤".into(),
+            },
+            negative: AffixesV1 {
+                prefix: "(".into(),
+                suffix: "¤)".into(),
+            },
+        }
+    );
+}
+
+#[test]
+fn test_percent() {
+    let pattern: DecimalPattern = "%#,##0".parse().unwrap();
+    assert_eq!(
+        pattern.localize_percent("\u{61c}-", "٪\u{61c}", "؉"),
+        PercentPatternV1 {
+            positive: AffixesV1 {
+                prefix: "%".into(),
+                suffix: "".into(),
+            },
+            negative: AffixesV1 {
+                prefix: "\u{61c}-%".into(),
+                suffix: "".into(),
+            },
+            percent_sign: "٪\u{61c}".into(),
+            permille_sign: "؉".into(),
+        }
+    );
+}
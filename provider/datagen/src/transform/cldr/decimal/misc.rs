@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR misc patterns for approximate numbers and ranges.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Miscellaneous_Patterns>
+
+use super::NumbersProvider;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+
+impl ResourceProvider<MiscPatternsV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<MiscPatternsV1Marker>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
+        let patterns = numbers
+            .numsys_data
+            .misc_patterns
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.misc_patterns.get(&tinystr!(8, "latn")))
+            .ok_or_else(|| {
+                DataError::custom("Could not find misc patterns for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(MiscPatternsV1 {
+                approximately: patterns.approximately.clone().into(),
+                at_least: patterns.at_least.clone().into(),
+                at_most: patterns.at_most.clone().into(),
+                range: patterns.range.clone().into(),
+            })),
+        })
+    }
+}
+
+impl IterableResourceProvider<MiscPatternsV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        self.supported_options_with_numbering_systems()
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let ja: DataPayload<MiscPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(ja.get().approximately, "約 {0}");
+    assert_eq!(ja.get().range, "{0}～{1}");
+
+    let es: DataPayload<MiscPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("es").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(es.get().at_least, "Más de {0}");
+}
@@ -5,13 +5,20 @@
 use crate::transform::cldr::cldr_serde;
 use crate::SourceData;
 use icu_decimal::provider::*;
+use icu_locid::extensions::unicode::Value;
+use icu_locid::{extensions_unicode_key as key, LanguageIdentifier, Locale};
 use icu_provider::datagen::IterableResourceProvider;
 use icu_provider::prelude::*;
 use std::borrow::Cow;
-use std::convert::TryFrom;
-use tinystr::TinyStr8;
+use std::str::FromStr;
+use tinystr::{tinystr, TinyStr8};
 
+mod compact;
+mod currency;
 mod decimal_pattern;
+mod misc;
+mod percent;
+mod scientific;
 
 /// A data provider reading from CLDR JSON plural rule files.
 #[derive(Debug)]
@@ -60,34 +67,96 @@ impl NumbersProvider {
             DataError::custom("Could not process numbering system").with_display_context(&nsname)
         })
     }
-}
 
-impl ResourceProvider<DecimalSymbolsV1Marker> for NumbersProvider {
-    fn load_resource(
+    /// Returns the CLDR numbers data for the locale of the request, and the numbering system
+    /// selected by its `nu` keyword.
+    fn get_numbers_for_request(
         &self,
         req: &DataRequest,
-    ) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
-        let langid = req.options.get_langid();
+    ) -> Result<(&cldr_serde::numbers::Numbers, TinyStr8), DataError> {
+        let numbers = self.get_numbers(&req.options.get_langid())?;
+        let nsname = match req.options.get_unicode_ext(&key!("nu")) {
+            Some(nu) => resolve_numbering_system(numbers, &nu.to_string())
+                .ok_or_else(|| DataErrorKind::MissingVariant.into_error())?,
+            None => numbers.default_numbering_system,
+        };
+        Ok((numbers, nsname))
+    }
 
+    /// Returns the locales, each with the numbering systems that can be requested with the
+    /// `nu` keyword.
+    ///
+    /// Only the numbering systems that a locale has symbols for are included; requests for
+    /// other numbering systems fall back to the locale's default at runtime.
+    fn supported_options_with_numbering_systems(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        let mut r = Vec::new();
+        for langid in self.source.cldr()?.numbers().list_langs()? {
+            let numbers = self.get_numbers(&langid)?;
+            let mut nu_values = numbers
+                .numsys_data
+                .symbols
+                .iter_keys()
+                .filter(|&&nsname| nsname != numbers.default_numbering_system)
+                .map(|nsname| nsname.to_string())
+                .collect::<Vec<_>>();
+            // Aliases are only included if they resolve to a non-default numeric numbering system.
+            for alias in ["native", "traditio", "finance"] {
+                if let Some(nsname) = resolve_numbering_system(numbers, alias) {
+                    if nsname != numbers.default_numbering_system
+                        && self.get_digits_for_numbering_system(nsname).is_ok()
+                    {
+                        nu_values.push(alias.to_string());
+                    }
+                }
+            }
+            for nu in nu_values {
+                let mut locale: Locale = langid.clone().into();
+                locale.extensions.unicode.keywords.set(
+                    key!("nu"),
+                    Value::from_str(&nu).map_err(|e| {
+                        DataError::custom("Invalid numbering system").with_display_context(&e)
+                    })?,
+                );
+                r.push(ResourceOptions::from(locale));
+            }
+            r.push(langid.into());
+        }
+        Ok(r)
+    }
+
+    /// Returns the CLDR numbers data for the given locale.
+    fn get_numbers(
+        &self,
+        langid: &LanguageIdentifier,
+    ) -> Result<&cldr_serde::numbers::Numbers, DataError> {
         let resource: &cldr_serde::numbers::Resource = self
             .source
             .cldr()?
             .numbers()
-            .read_and_parse(&langid, "numbers.json")?;
+            .read_and_parse(langid, "numbers.json")?;
 
         #[allow(clippy::expect_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
-        let numbers = &resource
+        Ok(&resource
             .main
             .0
-            .get(&langid)
+            .get(langid)
             .expect("CLDR file contains the expected language")
-            .numbers;
+            .numbers)
+    }
+}
+
+impl ResourceProvider<DecimalSymbolsV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
 
-        let mut result = DecimalSymbolsV1::try_from(numbers).map_err(|s| {
+        let mut result = symbols_for_numbering_system(numbers, nsname).map_err(|s| {
             DataError::custom("Could not create decimal symbols").with_display_context(&s)
         })?;
 
-        result.digits = self.get_digits_for_numbering_system(numbers.default_numbering_system)?;
+        result.digits = self.get_digits_for_numbering_system(nsname)?;
 
         let metadata = DataResponseMetadata::default();
         // TODO(#1109): Set metadata.data_langid correctly.
@@ -98,55 +167,85 @@ impl ResourceProvider<DecimalSymbolsV1Marker> for NumbersProvider {
     }
 }
 
-icu_provider::make_exportable_provider!(NumbersProvider, [DecimalSymbolsV1Marker,]);
+icu_provider::make_exportable_provider!(
+    NumbersProvider,
+    [
+        DecimalSymbolsV1Marker,
+        ShortCompactDecimalFormatDataV1Marker,
+        LongCompactDecimalFormatDataV1Marker,
+        CurrencyPatternsV1Marker,
+        CurrencyDisplayNamesV1Marker,
+        CurrencyFractionsV1Marker,
+        MiscPatternsV1Marker,
+        PercentPatternV1Marker,
+        ScientificSymbolsV1Marker,
+    ]
+);
 
 impl IterableResourceProvider<DecimalSymbolsV1Marker> for NumbersProvider {
     fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
-        Ok(self
-            .source
-            .cldr()?
-            .numbers()
-            .list_langs()?
-            .map(Into::<ResourceOptions>::into)
-            .collect())
+        self.supported_options_with_numbering_systems()
     }
 }
 
-impl TryFrom<&cldr_serde::numbers::Numbers> for DecimalSymbolsV1<'static> {
-    type Error = Cow<'static, str>;
-
-    fn try_from(other: &cldr_serde::numbers::Numbers) -> Result<Self, Self::Error> {
-        // TODO(#510): Select from non-default numbering systems
-        let symbols = other
-            .numsys_data
-            .symbols
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find symbols for default numbering system")?;
-        let formats = other
-            .numsys_data
-            .formats
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find formats for default numbering system")?;
-        let parsed_pattern: decimal_pattern::DecimalPattern = formats
-            .standard
-            .parse()
-            .map_err(|s: decimal_pattern::Error| s.to_string())?;
-
-        Ok(Self {
-            minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
-            plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
-            decimal_separator: Cow::Owned(symbols.decimal.clone()),
-            grouping_separator: Cow::Owned(symbols.group.clone()),
-            grouping_sizes: GroupingSizesV1 {
-                primary: parsed_pattern.positive.primary_grouping,
-                secondary: parsed_pattern.positive.secondary_grouping,
-                min_grouping: other.minimum_grouping_digits,
-            },
-            digits: Default::default(), // to be filled in
-        })
+/// Resolves a `nu` keyword value, which may be an alias, to a numbering system.
+///
+/// As specified by UTS 35, "traditional" falls back to "native", which falls back to the
+/// default numbering system, as does "finance".
+fn resolve_numbering_system(numbers: &cldr_serde::numbers::Numbers, nu: &str) -> Option<TinyStr8> {
+    let other = |alias: &str| numbers.other_numbering_systems.get(alias).copied();
+    match nu {
+        "native" => Some(other("native").unwrap_or(numbers.default_numbering_system)),
+        "traditio" => Some(
+            other("traditional")
+                .or_else(|| other("native"))
+                .unwrap_or(numbers.default_numbering_system),
+        ),
+        "finance" => Some(other("finance").unwrap_or(numbers.default_numbering_system)),
+        _ => nu.parse().ok(),
     }
 }
 
+/// Creates the decimal symbols for the given numbering system, without the digits.
+///
+/// Like ICU, this falls back to the symbols and formats of "latn" if the locale does not
+/// have any for the numbering system.
+fn symbols_for_numbering_system(
+    numbers: &cldr_serde::numbers::Numbers,
+    nsname: TinyStr8,
+) -> Result<DecimalSymbolsV1<'static>, Cow<'static, str>> {
+    let latn = tinystr!(8, "latn");
+    let symbols = numbers
+        .numsys_data
+        .symbols
+        .get(&nsname)
+        .or_else(|| numbers.numsys_data.symbols.get(&latn))
+        .ok_or("Could not find symbols for numbering system")?;
+    let formats = numbers
+        .numsys_data
+        .formats
+        .get(&nsname)
+        .or_else(|| numbers.numsys_data.formats.get(&latn))
+        .ok_or("Could not find formats for numbering system")?;
+    let parsed_pattern: decimal_pattern::DecimalPattern = formats
+        .standard
+        .parse()
+        .map_err(|s: decimal_pattern::Error| s.to_string())?;
+
+    Ok(DecimalSymbolsV1 {
+        minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
+        plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
+        decimal_separator: Cow::Owned(symbols.decimal.clone()),
+        grouping_separator: Cow::Owned(symbols.group.clone()),
+        grouping_sizes: GroupingSizesV1 {
+            primary: parsed_pattern.positive.primary_grouping,
+            secondary: parsed_pattern.positive.secondary_grouping,
+            min_grouping: numbers.minimum_grouping_digits,
+        },
+        digits: Default::default(), // to be filled in
+    })
+}
+
 #[test]
 fn test_basic() {
     use icu_locid::locale;
@@ -165,3 +264,35 @@ fn test_basic() {
     assert_eq!(ar_decimal.get().decimal_separator, "٫");
     assert_eq!(ar_decimal.get().digits[0], '٠');
 }
+
+#[test]
+fn test_numbering_systems() {
+    let provider = NumbersProvider::from(&SourceData::for_test());
+
+    let load = |locale: &str| -> DataPayload<DecimalSymbolsV1Marker> {
+        provider
+            .load_resource(&DataRequest {
+                options: locale.parse::<Locale>().unwrap().into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let ar_latn = load("ar-EG-u-nu-latn");
+    assert_eq!(ar_latn.get().decimal_separator, ".");
+    assert_eq!(ar_latn.get().digits[0], '0');
+
+    // en has no symbols for arab, so the latn symbols are used.
+    let en_arab = load("en-u-nu-arab");
+    assert_eq!(en_arab.get().decimal_separator, ".");
+    assert_eq!(en_arab.get().digits[0], '٠');
+
+    let th_native = load("th-u-nu-native");
+    assert_eq!(th_native.get().digits[0], '๐');
+
+    // th has no traditional numbering system, so it falls back to native.
+    let th_traditional = load("th-u-nu-traditio");
+    assert_eq!(th_traditional.get().digits[0], '๐');
+}
@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR percent patterns.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Number_Format_Patterns>
+
+use super::decimal_pattern::DecimalPattern;
+use super::NumbersProvider;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+
+impl ResourceProvider<PercentPatternV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<PercentPatternV1Marker>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
+        let latn = tinystr!(8, "latn");
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.symbols.get(&latn))
+            .ok_or_else(|| {
+                DataError::custom("Could not find symbols for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let formats = numbers
+            .numsys_data
+            .percent_formats
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.percent_formats.get(&latn))
+            .ok_or_else(|| {
+                DataError::custom("Could not find percent formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        let pattern: DecimalPattern = formats.standard.parse().map_err(|e| {
+            DataError::custom("Could not parse percent pattern").with_display_context(&e)
+        })?;
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(pattern.localize_percent(
+                &symbols.minus_sign,
+                &symbols.percent_sign,
+                &symbols.per_mille,
+            ))),
+        })
+    }
+}
+
+impl IterableResourceProvider<PercentPatternV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        self.supported_options_with_numbering_systems()
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let tr: DataPayload<PercentPatternV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("tr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(tr.get().positive.prefix, "%");
+    assert_eq!(tr.get().negative.prefix, "-%");
+
+    let fr: DataPayload<PercentPatternV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fr.get().positive.suffix, "\u{a0}%");
+
+    let ar: DataPayload<PercentPatternV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ar-EG").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(ar.get().percent_sign, "٪\u{61c}");
+    assert_eq!(ar.get().permille_sign, "؉");
+}
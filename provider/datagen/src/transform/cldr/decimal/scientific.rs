@@ -0,0 +1,121 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR symbols and patterns for scientific notation.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Scientific_Notation>
+
+use super::NumbersProvider;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use tinystr::tinystr;
+
+impl ResourceProvider<ScientificSymbolsV1Marker> for NumbersProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<ScientificSymbolsV1Marker>, DataError> {
+        let (numbers, nsname) = self.get_numbers_for_request(req)?;
+        let latn = tinystr!(8, "latn");
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.symbols.get(&latn))
+            .ok_or_else(|| {
+                DataError::custom("Could not find symbols for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let min_exponent_digits = match numbers
+            .numsys_data
+            .scientific_formats
+            .get(&nsname)
+            .or_else(|| numbers.numsys_data.scientific_formats.get(&latn))
+        {
+            Some(formats) => parse_min_exponent_digits(&formats.standard).map_err(|e| {
+                DataError::custom("Could not parse scientific pattern").with_display_context(&e)
+            })?,
+            None => 1,
+        };
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(ScientificSymbolsV1 {
+                exponential_symbol: Cow::Owned(symbols.exponential.clone()),
+                superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+                min_exponent_digits,
+            })),
+        })
+    }
+}
+
+impl IterableResourceProvider<ScientificSymbolsV1Marker> for NumbersProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        self.supported_options_with_numbering_systems()
+    }
+}
+
+/// Returns the minimum number of exponent digits of a scientific pattern, such as 1 for `#E0`
+/// and 2 for `0.00E+00`.
+fn parse_min_exponent_digits(pattern: &str) -> Result<u8, String> {
+    let exponent = pattern
+        .split(';')
+        .next()
+        .and_then(|pattern| pattern.split_once('E'))
+        .map(|(_, exponent)| exponent.strip_prefix('+').unwrap_or(exponent))
+        .ok_or_else(|| format!("No exponent in scientific pattern: {}", pattern))?;
+    match exponent.bytes().take_while(|&b| b == b'0').count() {
+        0 => Err(format!(
+            "No exponent digits in scientific pattern: {}",
+            pattern
+        )),
+        digits => u8::try_from(digits).map_err(|_| {
+            format!(
+                "Too many exponent digits in scientific pattern: {}",
+                pattern
+            )
+        }),
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = NumbersProvider::from(&crate::SourceData::for_test());
+
+    let ar: DataPayload<ScientificSymbolsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ar-EG").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(ar.get().exponential_symbol, "اس");
+    assert_eq!(ar.get().min_exponent_digits, 1);
+
+    let en: DataPayload<ScientificSymbolsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().exponential_symbol, "E");
+    assert_eq!(en.get().superscripting_exponent, "×");
+}
+
+#[test]
+fn test_parse_min_exponent_digits() {
+    assert_eq!(parse_min_exponent_digits("#E0"), Ok(1));
+    assert_eq!(parse_min_exponent_digits("0.00E+00"), Ok(2));
+    assert!(parse_min_exponent_digits("#,##0.###").is_err());
+    assert!(parse_min_exponent_digits("#E").is_err());
+}
@@ -0,0 +1,393 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR locale display names.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-general.html#Display_Name_Elements>
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_displaynames::provider::*;
+use icu_locid::subtags::{Region, Script, Variant};
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use zerovec::ZeroMap;
+
+/// The CLDR BCP-47 files with the keys whose names are in localeDisplayNames.json.
+const BCP47_KEYWORD_FILES: &[&str] = &[
+    "calendar.json",
+    "collation.json",
+    "measure.json",
+    "number.json",
+];
+
+/// A data provider reading from CLDR JSON locale display name files.
+#[derive(Debug)]
+pub struct DisplayNamesProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for DisplayNamesProvider {
+    fn from(source: &SourceData) -> Self {
+        DisplayNamesProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+macro_rules! impl_resource_provider {
+    ($($marker:ident: $file:literal => $transform:ident),+,) => {
+        $(
+            impl ResourceProvider<$marker> for DisplayNamesProvider {
+                fn load_resource(&self, req: &DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    let langid = req.options.get_langid();
+
+                    let resource: &cldr_serde::displaynames::Resource = self
+                        .source
+                        .cldr()?
+                        .displaynames()
+                        .read_and_parse(&langid, $file)?;
+
+                    let names = &resource
+                        .main
+                        .0
+                        .get(&langid)
+                        .expect("CLDR file contains the expected language")
+                        .locale_display_names;
+
+                    let metadata = DataResponseMetadata::default();
+                    // TODO(#1109): Set metadata.data_langid correctly.
+                    Ok(DataResponse {
+                        metadata,
+                        payload: Some(DataPayload::from_owned(self.$transform(names)?)),
+                    })
+                }
+            }
+
+            impl IterableResourceProvider<$marker> for DisplayNamesProvider {
+                fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+                    Ok(self
+                        .source
+                        .cldr()?
+                        .displaynames()
+                        .list_langs()?
+                        .map(Into::<ResourceOptions>::into)
+                        .collect())
+                }
+            }
+        )+
+
+        icu_provider::make_exportable_provider!(DisplayNamesProvider, [$($marker),+,]);
+    };
+}
+
+impl_resource_provider!(
+    LanguageDisplayNamesV1Marker: "languages.json" => transform_languages,
+    ScriptDisplayNamesV1Marker: "scripts.json" => transform_scripts,
+    RegionDisplayNamesV1Marker: "territories.json" => transform_regions,
+    VariantDisplayNamesV1Marker: "variants.json" => transform_variants,
+    LocaleDisplayNamesV1Marker: "localeDisplayNames.json" => transform_locales,
+);
+
+/// Splits the names into the standard names and the names for each `-alt-` form, such as
+/// `short` in `en-GB-alt-short`, parsing the identifiers with `parse`.
+///
+/// Only the alternative names that differ from the standard names are kept.
+#[allow(clippy::type_complexity)] // a private helper
+fn split_alt_names<'a, K: Ord + Clone>(
+    names: &'a BTreeMap<&'a str, &'a str>,
+    parse: impl Fn(&str) -> Result<K, DataError>,
+) -> Result<(BTreeMap<K, &'a str>, BTreeMap<(&'a str, K), &'a str>), DataError> {
+    let mut standard = BTreeMap::new();
+    let mut alt = BTreeMap::new();
+    for (&key, &name) in names.iter() {
+        match key.split_once("-alt-") {
+            Some((id, form)) => {
+                alt.insert((form, parse(id)?), name);
+            }
+            None => {
+                standard.insert(parse(key)?, name);
+            }
+        }
+    }
+    alt.retain(|(_, id), name| standard.get(id) != Some(&*name));
+    Ok((standard, alt))
+}
+
+/// Collects the names of one `-alt-` form into a [`ZeroMap`].
+fn alt_names<'a, K>(alt: &BTreeMap<(&str, K), &'a str>, form: &str) -> Vec<(K, &'a str)>
+where
+    K: Clone,
+{
+    alt.iter()
+        .filter(|((f, _), _)| *f == form)
+        .map(|((_, id), name)| (id.clone(), *name))
+        .collect()
+}
+
+fn parse_subtag<T: FromStr>(id: &str) -> Result<T, DataError> {
+    id.parse()
+        .map_err(|_| DataError::custom("Invalid subtag").with_display_context(id))
+}
+
+impl DisplayNamesProvider {
+    fn transform_languages(
+        &self,
+        names: &cldr_serde::displaynames::LocaleDisplayNames,
+    ) -> Result<LanguageDisplayNamesV1<'static>, DataError> {
+        let names = names
+            .languages
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        // Languages are keyed by their normalized identifier, such as `zh-Hant`.
+        let (standard, alt) = split_alt_names(&names, |id| {
+            LanguageIdentifier::from_str(id)
+                .map(|id| id.to_string())
+                .map_err(|_| DataError::custom("Invalid language").with_display_context(id))
+        })?;
+        let to_map = |names: Vec<(String, &str)>| {
+            names
+                .iter()
+                .map(|(id, name)| (id.as_str(), *name))
+                .collect::<ZeroMap<str, str>>()
+        };
+        Ok(LanguageDisplayNamesV1 {
+            names: to_map(standard.into_iter().collect()),
+            short_names: to_map(alt_names(&alt, "short")),
+            long_names: to_map(alt_names(&alt, "long")),
+            menu_names: to_map(alt_names(&alt, "menu")),
+        })
+    }
+
+    fn transform_scripts(
+        &self,
+        names: &cldr_serde::displaynames::LocaleDisplayNames,
+    ) -> Result<ScriptDisplayNamesV1<'static>, DataError> {
+        let names = names
+            .scripts
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let (standard, alt) = split_alt_names(&names, parse_subtag::<Script>)?;
+        Ok(ScriptDisplayNamesV1 {
+            names: standard.into_iter().collect(),
+            short_names: alt_names(&alt, "short").into_iter().collect(),
+            stand_alone_names: alt_names(&alt, "stand-alone").into_iter().collect(),
+        })
+    }
+
+    fn transform_regions(
+        &self,
+        names: &cldr_serde::displaynames::LocaleDisplayNames,
+    ) -> Result<RegionDisplayNamesV1<'static>, DataError> {
+        let names = names
+            .territories
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let (standard, alt) = split_alt_names(&names, parse_subtag::<Region>)?;
+        Ok(RegionDisplayNamesV1 {
+            names: standard.into_iter().collect(),
+            short_names: alt_names(&alt, "short").into_iter().collect(),
+        })
+    }
+
+    fn transform_variants(
+        &self,
+        names: &cldr_serde::displaynames::LocaleDisplayNames,
+    ) -> Result<VariantDisplayNamesV1<'static>, DataError> {
+        let names = names
+            .variants
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let (standard, _) = split_alt_names(&names, parse_subtag::<Variant>)?;
+        Ok(VariantDisplayNamesV1 {
+            names: standard.into_iter().collect(),
+        })
+    }
+
+    fn transform_locales(
+        &self,
+        names: &cldr_serde::displaynames::LocaleDisplayNames,
+    ) -> Result<LocaleDisplayNamesV1<'static>, DataError> {
+        let pattern = names
+            .locale_display_pattern
+            .as_ref()
+            .ok_or_else(|| DataError::custom("Missing locale display pattern"))?;
+
+        // The names of keys and values are keyed by their CLDR aliases, such as `calendar`
+        // and `gregorian` for `ca-gregory`, or by the BCP-47 key or value if there is no alias.
+        let mut keys = ZeroMap::new();
+        let mut types = ZeroMap::new();
+        for file in BCP47_KEYWORD_FILES {
+            let resource: &cldr_serde::bcp47_keywords::Resource =
+                self.source.cldr()?.bcp47().read_and_parse(file)?;
+            for (key, key_info) in resource.keyword.u.iter() {
+                let key_aliases = key_info
+                    .alias
+                    .iter()
+                    .flat_map(|alias| alias.split(' '))
+                    .chain(core::iter::once(key.as_str()));
+                if let Some(name) = key_aliases.clone().find_map(|alias| names.keys.get(alias)) {
+                    keys.insert(key.as_str(), name.as_str());
+                }
+                let type_names = match key_aliases.clone().find_map(|alias| names.types.get(alias))
+                {
+                    Some(type_names) => type_names,
+                    None => continue,
+                };
+                for (value, value_info) in key_info.types.iter() {
+                    let name = value_info
+                        .alias
+                        .iter()
+                        .flat_map(|alias| alias.split(' '))
+                        .chain(core::iter::once(value.as_str()))
+                        .find_map(|alias| type_names.get(alias));
+                    if let Some(name) = name {
+                        types.insert(format!("{}-{}", key, value).as_str(), name.as_str());
+                    }
+                }
+            }
+        }
+
+        Ok(LocaleDisplayNamesV1 {
+            pattern: Cow::Owned(pattern.locale_pattern.clone()),
+            separator: Cow::Owned(pattern.locale_separator.clone()),
+            key_type_pattern: Cow::Owned(pattern.locale_key_type_pattern.clone()),
+            keys,
+            types,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay, Style};
+    use icu_displaynames::LocaleDisplayNamesFormatter;
+    use icu_locid::{locale, Locale};
+
+    #[test]
+    fn test_basic() {
+        let provider = DisplayNamesProvider::from(&SourceData::for_test());
+        let data: DataPayload<LanguageDisplayNamesV1Marker> = provider
+            .load_resource(&DataRequest {
+                options: locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().names.get("en-GB"), Some("British English"));
+        assert_eq!(data.get().short_names.get("en-GB"), Some("UK English"));
+        assert_eq!(data.get().names.get("zh-Hant"), Some("Traditional Chinese"));
+        assert_eq!(data.get().menu_names.get("zh"), Some("Chinese, Mandarin"));
+        // Variant names are dropped.
+        assert_eq!(data.get().names.get("ckb"), Some("Central Kurdish"));
+        assert_eq!(data.get().menu_names.get("ckb"), Some("Kurdish, Central"));
+        assert_eq!(data.get().short_names.get("ckb"), None);
+
+        let data: DataPayload<LocaleDisplayNamesV1Marker> = provider
+            .load_resource(&DataRequest {
+                options: locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().keys.get("ca"), Some("Calendar"));
+        assert_eq!(
+            data.get().types.get("ca-gregory"),
+            Some("Gregorian Calendar")
+        );
+        assert_eq!(
+            data.get().types.get("co-phonebk"),
+            Some("Phonebook Sort Order")
+        );
+        assert_eq!(
+            data.get().types.get("hc-h23"),
+            Some("24 Hour System (0–23)")
+        );
+    }
+
+    #[test]
+    fn test_formatter() {
+        let provider = DisplayNamesProvider::from(&SourceData::for_test());
+        let name = |locale: &str, options: DisplayNamesOptions, tag: &str| {
+            LocaleDisplayNamesFormatter::try_new(
+                locale.parse::<Locale>().unwrap(),
+                &provider,
+                options,
+            )
+            .unwrap()
+            .of(&tag.parse().unwrap())
+        };
+        let mut standard = DisplayNamesOptions::default();
+        standard.language_display = LanguageDisplay::Standard;
+        let mut short = DisplayNamesOptions::default();
+        short.style = Style::Short;
+
+        assert_eq!(name("en", Default::default(), "en-GB"), "British English");
+        assert_eq!(name("en", short, "en-GB"), "UK English");
+        assert_eq!(name("en", standard, "en-GB"), "English (United Kingdom)");
+        assert_eq!(name("en", short, "en-CH"), "English (Switzerland)");
+        assert_eq!(
+            name("en", Default::default(), "es-419"),
+            "Latin American Spanish"
+        );
+        assert_eq!(
+            name("en", Default::default(), "es-Latn-419"),
+            "Latin American Spanish (Latin)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "zh-Hant-HK"),
+            "Traditional Chinese (Hong Kong SAR China)"
+        );
+        assert_eq!(
+            name("en", standard, "zh-Hant-TW"),
+            "Chinese (Traditional, Taiwan)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "sr-Cyrl-ME"),
+            "Montenegrin (Cyrillic)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "ca-ES-valencia"),
+            "ca (Spain, Valencian)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "xyz-Qaaa-QM"),
+            "xyz (Qaaa, QM)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "de-u-co-phonebk"),
+            "German (Phonebook Sort Order)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "de-u-ca-xyz"),
+            "German (Calendar: xyz)"
+        );
+        assert_eq!(
+            name("en", Default::default(), "de-u-hc-h12-fw-mon"),
+            "German (fw: mon, 12 Hour System [1–12])"
+        );
+        assert_eq!(
+            name("fr", Default::default(), "en-US-u-ca-gregory"),
+            "anglais américain (calendrier grégorien)"
+        );
+        assert_eq!(
+            name("fr", Default::default(), "pt-u-nu-thai"),
+            "portugais (chiffres : thai)"
+        );
+        assert_eq!(name("ja", standard, "zh-Hans-CN"), "中国語 (簡体字、中国)");
+    }
+}
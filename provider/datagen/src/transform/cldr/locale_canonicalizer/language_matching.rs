@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_locale_canonicalizer::provider::*;
+use icu_locid::subtags::Region;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec};
+
+/// A data provider reading from CLDR JSON language matching and territory containment files.
+#[derive(Debug)]
+pub struct LanguageMatchingProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for LanguageMatchingProvider {
+    fn from(source: &SourceData) -> Self {
+        LanguageMatchingProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+impl ResourceProvider<LanguageMatchingV1Marker> for LanguageMatchingProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<LanguageMatchingV1Marker>, DataError> {
+        // We treat searching for `und` as a request for all data. Other requests
+        // are not currently supported.
+        if !req.options.is_empty() {
+            return Err(DataErrorKind::ExtraneousResourceOptions.into_error());
+        }
+
+        let matching: &cldr_serde::language_matching::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+        let containment: &cldr_serde::territory_containment::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(transform(matching, containment)?)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(LanguageMatchingProvider, [LanguageMatchingV1Marker,]);
+
+impl IterableResourceProvider<LanguageMatchingV1Marker> for LanguageMatchingProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Adds `region` and all the regions it contains, including groupings, to `regions`.
+fn add_contained_regions<'a>(
+    containment: &BTreeMap<&str, Vec<&'a str>>,
+    region: &'a str,
+    regions: &mut BTreeSet<&'a str>,
+) {
+    if regions.insert(region) {
+        for contained in containment.get(region).into_iter().flatten() {
+            add_contained_regions(containment, contained, regions);
+        }
+    }
+}
+
+fn transform(
+    matching: &cldr_serde::language_matching::Resource,
+    containment: &cldr_serde::territory_containment::Resource,
+) -> Result<LanguageMatchingV1<'static>, DataError> {
+    // Containment is keyed by regions such as `019`, and by the same regions with a
+    // suffix such as `-status-grouping` for their groupings.
+    let mut contains: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (key, value) in containment.supplemental.territory_containment.iter() {
+        let region = key.split('-').next().unwrap_or(key);
+        contains
+            .entry(region)
+            .or_default()
+            .extend(value.contains.iter().map(String::as_str));
+    }
+
+    let mut paradigm_locales = Vec::new();
+    let mut match_variables = ZeroMap::new();
+    let mut rules = Vec::new();
+    let mut distances = Vec::new();
+    for entry in matching.supplemental.language_matching.written_new.iter() {
+        for (key, attributes) in entry.iter() {
+            if let Some(locales) = &attributes.locales {
+                paradigm_locales.extend(locales.split(' ').map(|l| l.replace('_', "-")));
+            } else if let (Some(name), Some(value)) = (key.strip_prefix('$'), &attributes.value) {
+                let mut regions = BTreeSet::new();
+                for region in value.split('+') {
+                    add_contained_regions(&contains, region, &mut regions);
+                }
+                let regions = regions
+                    .into_iter()
+                    .map(|region| {
+                        region.parse::<Region>().map_err(|_| {
+                            DataError::custom("Invalid region").with_display_context(region)
+                        })
+                    })
+                    .collect::<Result<Box<[_]>, _>>()?;
+                match_variables.insert(name, ZeroSlice::from_boxed_slice(regions).as_ref());
+            } else if let (Some(desired), Some(distance)) =
+                (&attributes.desired, &attributes.distance)
+            {
+                let distance = distance.parse::<u8>().map_err(|_| {
+                    DataError::custom("Invalid distance").with_display_context(distance)
+                })?;
+                rules.push(StrStrPair(desired.clone().into(), key.clone().into()));
+                distances.push(distance);
+                // Rules that are not one-way also match a desired locale that is like
+                // the supported pattern.
+                if attributes.oneway.as_deref() != Some("true") && desired != key {
+                    rules.push(StrStrPair(key.clone().into(), desired.clone().into()));
+                    distances.push(distance);
+                }
+            } else {
+                return Err(
+                    DataError::custom("Invalid language matching entry").with_display_context(key)
+                );
+            }
+        }
+    }
+
+    Ok(LanguageMatchingV1 {
+        paradigm_locales: VarZeroVec::from(&paradigm_locales),
+        match_variables,
+        rules: VarZeroVec::from(&rules),
+        distances: ZeroVec::alloc_from_slice(&distances),
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::subtags_region as region;
+
+    let provider = LanguageMatchingProvider::from(&SourceData::for_test());
+    let data: DataPayload<LanguageMatchingV1Marker> = provider
+        .load_resource(&DataRequest::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let americas = data.get().match_variables.get("americas").unwrap();
+    assert!(americas.iter().any(|r| r == region!("419")));
+    assert!(americas.iter().any(|r| r == region!("MX")));
+    assert!(!americas.iter().any(|r| r == region!("ES")));
+
+    let rules: Vec<StrStrPair> = data
+        .get()
+        .rules
+        .iter()
+        .map(zerofrom::ZeroFrom::zero_from)
+        .collect();
+    assert_eq!(rules.len(), data.get().distances.len());
+    assert_eq!(rules[0], StrStrPair("nb".into(), "no".into()));
+    assert_eq!(rules[1], StrStrPair("no".into(), "nb".into()));
+}
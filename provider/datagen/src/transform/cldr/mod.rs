@@ -8,20 +8,35 @@ mod calendar;
 mod cldr_serde;
 mod datetime;
 mod decimal;
+#[cfg(feature = "experimental")]
+mod displaynames;
 mod fallback;
 mod list;
 mod locale_canonicalizer;
 mod plurals;
+#[cfg(feature = "experimental")]
+mod rbnf;
 pub(crate) mod source;
 mod time_zones;
+#[cfg(feature = "experimental")]
+mod units;
 
 pub use calendar::japanese::JapaneseErasProvider;
 pub use datetime::week_data::WeekDataProvider;
 pub use datetime::CommonDateProvider;
 pub use decimal::NumbersProvider;
+#[cfg(feature = "experimental")]
+pub use displaynames::DisplayNamesProvider;
 pub use fallback::FallbackRulesProvider;
 pub use list::ListProvider;
 pub use locale_canonicalizer::aliases::AliasesProvider;
+pub use locale_canonicalizer::language_matching::LanguageMatchingProvider;
 pub use locale_canonicalizer::likely_subtags::LikelySubtagsProvider;
+pub use plurals::ranges::PluralRangesProvider;
+pub use plurals::samples::PluralSamplesProvider;
 pub use plurals::PluralsProvider;
+#[cfg(feature = "experimental")]
+pub use rbnf::RbnfProvider;
 pub use time_zones::TimeZonesProvider;
+#[cfg(feature = "experimental")]
+pub use units::{UnitsProvider, UnitsSupplementalProvider};
@@ -9,6 +9,9 @@ use icu_plurals::rules::runtime::ast::Rule;
 use icu_provider::datagen::IterableResourceProvider;
 use icu_provider::prelude::*;
 
+pub mod ranges;
+pub mod samples;
+
 /// A data provider reading from CLDR JSON plural rule files.
 #[derive(Debug)]
 pub struct PluralsProvider {
@@ -25,27 +28,33 @@ impl From<&SourceData> for PluralsProvider {
 
 impl PluralsProvider {
     fn get_rules_for(&self, key: ResourceKey) -> Result<&cldr_serde::plurals::Rules, DataError> {
-        match key {
-            CardinalV1Marker::KEY => self
-                .source
-                .cldr()?
-                .core()
-                .read_and_parse::<cldr_serde::plurals::Resource>("supplemental/plurals.json")?
-                .supplemental
-                .plurals_type_cardinal
-                .as_ref(),
-            OrdinalV1Marker::KEY => self
-                .source
-                .cldr()?
-                .core()
-                .read_and_parse::<cldr_serde::plurals::Resource>("supplemental/ordinals.json")?
-                .supplemental
-                .plurals_type_ordinal
-                .as_ref(),
-            _ => None,
-        }
-        .ok_or(DataError::custom("Unknown key for PluralRulesV1"))
+        get_rules_for(&self.source, key)
+    }
+}
+
+/// Returns the CLDR plural rules of the type of the given rules or samples key.
+pub(super) fn get_rules_for(
+    source: &SourceData,
+    key: ResourceKey,
+) -> Result<&cldr_serde::plurals::Rules, DataError> {
+    match key {
+        CardinalV1Marker::KEY | CardinalSamplesV1Marker::KEY => source
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plurals::Resource>("supplemental/plurals.json")?
+            .supplemental
+            .plurals_type_cardinal
+            .as_ref(),
+        OrdinalV1Marker::KEY | OrdinalSamplesV1Marker::KEY => source
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plurals::Resource>("supplemental/ordinals.json")?
+            .supplemental
+            .plurals_type_ordinal
+            .as_ref(),
+        _ => None,
     }
+    .ok_or(DataError::custom("Unknown key for PluralRulesV1"))
 }
 
 impl<M: ResourceMarker<Yokeable = PluralRulesV1<'static>>> ResourceProvider<M> for PluralsProvider {
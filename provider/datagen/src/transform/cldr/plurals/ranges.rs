@@ -0,0 +1,156 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_plurals::provider::PluralRangesV1;
+use icu_plurals::provider::PluralRangesV1Marker;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap2d;
+
+/// A data provider reading from the CLDR JSON plural ranges file.
+#[derive(Debug)]
+pub struct PluralRangesProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for PluralRangesProvider {
+    fn from(source: &SourceData) -> Self {
+        PluralRangesProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+impl PluralRangesProvider {
+    fn cardinal_rules(&self) -> Result<&cldr_serde::plurals::Rules, DataError> {
+        self.source
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plurals::Resource>("supplemental/plurals.json")?
+            .supplemental
+            .plurals_type_cardinal
+            .as_ref()
+            .ok_or(DataError::custom("Missing cardinal plural rules"))
+    }
+}
+
+impl ResourceProvider<PluralRangesV1Marker> for PluralRangesProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<PluralRangesV1Marker>, DataError> {
+        let langid = req.options.get_langid();
+        if !self.cardinal_rules()?.0.contains_key(&langid) {
+            return Err(DataErrorKind::MissingLocale.into_error());
+        }
+        // Locales with plural rules but without plural ranges use the default category of a
+        // range, which is that of its end.
+        let ranges = match self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plural_ranges::Resource>(
+                "supplemental/pluralRanges.json",
+            )?
+            .supplemental
+            .plurals
+            .get(&langid)
+        {
+            Some(ranges) => PluralRangesV1::try_from(ranges)?,
+            None => PluralRangesV1::default(),
+        };
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(ranges)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(PluralRangesProvider, [PluralRangesV1Marker,]);
+
+impl IterableResourceProvider<PluralRangesV1Marker> for PluralRangesProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(self
+            .cardinal_rules()?
+            .0
+            .iter_keys()
+            // TODO(#568): Avoid the clone
+            .cloned()
+            .map(ResourceOptions::from)
+            .collect())
+    }
+}
+
+impl TryFrom<&cldr_serde::plural_ranges::LocalePluralRanges> for PluralRangesV1<'static> {
+    type Error = DataError;
+    fn try_from(other: &cldr_serde::plural_ranges::LocalePluralRanges) -> Result<Self, DataError> {
+        let parse_category = |category: &str| {
+            PluralCategory::from_tr35_string(category).ok_or_else(|| {
+                DataError::custom("Unknown plural category").with_display_context(category)
+            })
+        };
+        let mut ranges = ZeroMap2d::new();
+        for (key, result) in other.0.iter() {
+            let (start, end) = key
+                .strip_prefix("pluralRange-start-")
+                .and_then(|key| key.split_once("-end-"))
+                .ok_or_else(|| {
+                    DataError::custom("Invalid plural range").with_display_context(key)
+                })?;
+            let (start, end, result) = (
+                parse_category(start)?,
+                parse_category(end)?,
+                parse_category(result)?,
+            );
+            // Only the ranges whose category is not that of their end are stored.
+            if result != end {
+                ranges.insert(&start, &end, &result);
+            }
+        }
+        Ok(Self { ranges })
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = PluralRangesProvider::from(&SourceData::for_test());
+
+    let ar_ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: langid!("ar").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        ar_ranges
+            .get()
+            .ranges
+            .get_copied(&PluralCategory::Zero, &PluralCategory::One),
+        Ok(PluralCategory::Zero)
+    );
+    assert!(ar_ranges
+        .get()
+        .ranges
+        .get_copied(&PluralCategory::Few, &PluralCategory::Many)
+        .is_err());
+
+    let und_ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: langid!("und").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(und_ranges.get().ranges.is_empty());
+}
@@ -0,0 +1,126 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_plurals::provider::PluralSamplesV1;
+use icu_plurals::provider::{CardinalSamplesV1Marker, OrdinalSamplesV1Marker};
+use icu_plurals::rules::reference;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+
+/// A data provider reading the samples of CLDR JSON plural rule files.
+#[derive(Debug)]
+pub struct PluralSamplesProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for PluralSamplesProvider {
+    fn from(source: &SourceData) -> Self {
+        PluralSamplesProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+impl<M: ResourceMarker<Yokeable = PluralSamplesV1<'static>>> ResourceProvider<M>
+    for PluralSamplesProvider
+{
+    fn load_resource(&self, req: &DataRequest) -> Result<DataResponse<M>, DataError> {
+        let rules = super::get_rules_for(&self.source, M::KEY)?
+            .0
+            .get(&req.options.get_langid())
+            .ok_or(DataErrorKind::MissingLocale.into_error())?;
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(PluralSamplesV1::try_from(rules)?)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(
+    PluralSamplesProvider,
+    [CardinalSamplesV1Marker, OrdinalSamplesV1Marker,]
+);
+
+impl<M: ResourceMarker<Yokeable = PluralSamplesV1<'static>>> IterableResourceProvider<M>
+    for PluralSamplesProvider
+{
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(super::get_rules_for(&self.source, M::KEY)?
+            .0
+            .iter_keys()
+            // TODO(#568): Avoid the clone
+            .cloned()
+            .map(ResourceOptions::from)
+            .collect())
+    }
+}
+
+impl TryFrom<&cldr_serde::plurals::LocalePluralRules> for PluralSamplesV1<'static> {
+    type Error = DataError;
+    fn try_from(other: &cldr_serde::plurals::LocalePluralRules) -> Result<Self, DataError> {
+        let mut result = Self::default();
+        for (category, rule) in [
+            (PluralCategory::Zero, &other.zero),
+            (PluralCategory::One, &other.one),
+            (PluralCategory::Two, &other.two),
+            (PluralCategory::Few, &other.few),
+            (PluralCategory::Many, &other.many),
+            (PluralCategory::Other, &other.other),
+        ] {
+            let rule = match rule {
+                Some(rule) => reference::parse(rule.as_bytes()).map_err(|e| {
+                    DataError::custom("Plural rule parsing failed").with_display_context(&e)
+                })?,
+                None => continue,
+            };
+            if rule.samples.is_none() {
+                continue;
+            }
+            // Serialize the samples alone, without the condition of the rule.
+            let mut samples = String::new();
+            reference::serialize(
+                &reference::ast::Rule {
+                    condition: reference::ast::Condition(vec![]),
+                    samples: rule.samples,
+                },
+                &mut samples,
+            )
+            .map_err(|_| DataError::custom("Plural samples serialization failed"))?;
+            result.samples.insert(&category, samples.trim_start());
+        }
+        Ok(result)
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = PluralSamplesProvider::from(&SourceData::for_test());
+
+    let en_samples: DataPayload<CardinalSamplesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        en_samples.get().samples.get(&PluralCategory::One),
+        Some("@integer 1")
+    );
+    assert_eq!(
+        en_samples.get().samples.get(&PluralCategory::Other),
+        Some(
+            "@integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … \
+             @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+        )
+    );
+}
@@ -0,0 +1,195 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR rule-based number formatting rule sets.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting>
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use icu_rbnf::provider::*;
+use std::borrow::Cow;
+use std::str::FromStr;
+use zerovec::ule::encode_varule_to_box;
+
+/// A data provider reading from CLDR JSON rbnf files.
+#[derive(Debug)]
+pub struct RbnfProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for RbnfProvider {
+    fn from(source: &SourceData) -> Self {
+        RbnfProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+/// Parses a rule descriptor, such as `100`, `1000/1000>`, `-x`, or `x.x`, into the kind, base
+/// value, and divisor of the rule. Returns `None` for descriptors that are not supported, such
+/// as `Inf` and `NaN`.
+fn parse_descriptor(descriptor: &str) -> Option<(RuleKind, u64, u64)> {
+    match descriptor {
+        "-x" => return Some((RuleKind::Negative, 0, 1)),
+        "x.x" => return Some((RuleKind::ImproperFraction, 0, 1)),
+        "0.x" => return Some((RuleKind::ProperFraction, 0, 1)),
+        _ => (),
+    }
+    // Each `>` decrements the exponent of the divisor.
+    let decrements = descriptor.len() - descriptor.trim_end_matches('>').len();
+    let descriptor = descriptor.trim_end_matches('>');
+    let (base, radix) = descriptor.split_once('/').unwrap_or((descriptor, "10"));
+    let base = base.replace(',', "").parse::<u64>().ok()?;
+    let radix = radix.parse::<u64>().ok().filter(|&radix| radix > 1)?;
+    let mut exponent = 0;
+    let mut power = 1u64;
+    while let Some(next) = power.checked_mul(radix).filter(|&next| next <= base) {
+        power = next;
+        exponent += 1;
+    }
+    let divisor = radix.pow(exponent - decrements.min(exponent as usize) as u32);
+    Some((RuleKind::Normal, base, divisor))
+}
+
+/// Normalizes a rule body by removing the terminating `;` and the `'` that protects leading
+/// spaces, and by replacing the arrows `←`, `→`, and `≡` with `<`, `>`, and `=`.
+fn normalize_body(body: &str) -> String {
+    let body = body.trim_end_matches(';');
+    let body = body.strip_prefix('\'').unwrap_or(body);
+    body.replace('←', "<").replace('→', ">").replace('≡', "=")
+}
+
+impl ResourceProvider<RbnfRulesV1Marker> for RbnfProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<RbnfRulesV1Marker>, DataError> {
+        let langid = req.options.get_langid();
+        let file_name = if langid == LanguageIdentifier::UND {
+            "root.json".to_string()
+        } else {
+            format!("{}.json", langid)
+        };
+        let resource: &cldr_serde::rbnf::Resource =
+            self.source.cldr()?.rbnf().read_and_parse(&file_name)?;
+
+        let groups = &resource.rbnf.rbnf;
+        let mut result = RbnfRulesV1::default();
+        for (name, rules) in groups.spellout.iter().chain(groups.ordinal.iter()) {
+            // Rule set names are stored without their first `%`.
+            let name = name.strip_prefix('%').unwrap_or(name);
+            let mut index = 0u16;
+            for (descriptor, body) in rules {
+                let (kind, base_value, divisor) = match parse_descriptor(descriptor) {
+                    Some(parsed) => parsed,
+                    None => continue,
+                };
+                let rule = Rule {
+                    kind,
+                    base_value,
+                    divisor,
+                    body: Cow::Owned(normalize_body(body)),
+                };
+                result
+                    .rules
+                    .insert(name, &index, &*encode_varule_to_box(&rule));
+                index += 1;
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(RbnfProvider, [RbnfRulesV1Marker,]);
+
+impl IterableResourceProvider<RbnfRulesV1Marker> for RbnfProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .rbnf()
+            .list_files()?
+            .filter_map(|file| {
+                let name = file.strip_suffix(".json")?;
+                match name {
+                    "root" => Some(LanguageIdentifier::UND),
+                    _ => LanguageIdentifier::from_str(name).ok(),
+                }
+            })
+            .map(ResourceOptions::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_descriptor() {
+    assert_eq!(parse_descriptor("0"), Some((RuleKind::Normal, 0, 1)));
+    assert_eq!(parse_descriptor("20"), Some((RuleKind::Normal, 20, 10)));
+    assert_eq!(parse_descriptor("100"), Some((RuleKind::Normal, 100, 100)));
+    assert_eq!(
+        parse_descriptor("1,000"),
+        Some((RuleKind::Normal, 1000, 1000))
+    );
+    assert_eq!(parse_descriptor("100>"), Some((RuleKind::Normal, 100, 10)));
+    assert_eq!(parse_descriptor("64/16"), Some((RuleKind::Normal, 64, 16)));
+    assert_eq!(
+        parse_descriptor("x.x"),
+        Some((RuleKind::ImproperFraction, 0, 1))
+    );
+    assert_eq!(parse_descriptor("Inf"), None);
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = RbnfProvider::from(&SourceData::for_test());
+
+    let en: DataPayload<RbnfRulesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let cardinal = en.get().rules.get0("spellout-cardinal").unwrap();
+    let hundred = cardinal
+        .iter1()
+        .map(|(_, rule)| Rule::zero_from(rule))
+        .find(|rule| rule.base_value == 100)
+        .unwrap();
+    assert_eq!(hundred.divisor, 100);
+    assert_eq!(hundred.body, "<< hundred[ >>]");
+    // Private rule sets keep their second `%`.
+    assert!(en.get().rules.get0("%th").is_some());
+
+    let ja: DataPayload<RbnfRulesV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let digits_ordinal = ja.get().rules.get0("digits-ordinal").unwrap();
+    assert_eq!(
+        digits_ordinal
+            .iter1()
+            .map(|(_, rule)| Rule::zero_from(rule).body.into_owned())
+            .collect::<Vec<_>>(),
+        ["−>>", "第=#,##0="]
+    );
+}
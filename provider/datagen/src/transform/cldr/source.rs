@@ -71,6 +71,20 @@ impl CldrCache {
         CldrDirLang(self, format!("cldr-misc-{}/main", self.locale_subset))
     }
 
+    pub(crate) fn displaynames(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, format!("cldr-localenames-{}/main", self.locale_subset))
+    }
+
+    #[cfg(feature = "experimental")]
+    pub(crate) fn units(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, format!("cldr-units-{}/main", self.locale_subset))
+    }
+
+    #[cfg(feature = "experimental")]
+    pub(crate) fn rbnf(&self) -> CldrDirNoLang<'_> {
+        CldrDirNoLang(self, "cldr-rbnf/rbnf".to_string())
+    }
+
     pub(crate) fn bcp47(&self) -> CldrDirNoLang<'_> {
         CldrDirNoLang(self, "cldr-bcp47/bcp47".to_string())
     }
@@ -96,6 +110,16 @@ impl<'a> CldrDirNoLang<'a> {
     {
         read_and_parse_json(self.0, &format!("{}/{}", self.1, file_name))
     }
+
+    #[cfg(feature = "experimental")]
+    pub(crate) fn list_files(&self) -> Result<impl Iterator<Item = String>, DataError> {
+        Ok(self
+            .0
+            .root
+            .list(&self.1)?
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned()))
+    }
 }
 
 pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String);
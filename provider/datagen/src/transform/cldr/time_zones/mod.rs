@@ -9,6 +9,8 @@ use icu_datetime::provider::time_zones::*;
 use icu_provider::datagen::IterableResourceProvider;
 use icu_provider::prelude::*;
 use litemap::LiteMap;
+use tinystr::{tinystr, TinyAsciiStr};
+use zerovec::{ZeroMap, ZeroMap2d};
 
 mod convert;
 
@@ -104,8 +106,6 @@ macro_rules! impl_resource_provider {
                 }
             }
         )+
-
-        icu_provider::make_exportable_provider!(TimeZonesProvider, [$($marker),+,]);
     };
 }
 
@@ -119,6 +119,268 @@ impl_resource_provider!(
     MetaZonePeriodV1Marker
 );
 
+impl ResourceProvider<WindowsZonesV1Marker> for TimeZonesProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<WindowsZonesV1Marker>, DataError> {
+        if !req.options.is_empty() {
+            return Err(DataErrorKind::ExtraneousResourceOptions.into_error());
+        }
+
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.source
+                .cldr()?
+                .bcp47()
+                .read_and_parse("timezone.json")?;
+
+        let mut bcp47_tzids = LiteMap::new();
+        for (bcp47_tzid, bcp47_tzid_data) in resource.keyword.u.time_zones.values.iter() {
+            if let Some(alias) = &bcp47_tzid_data.alias {
+                for data_value in alias.split(' ') {
+                    bcp47_tzids.insert(data_value, *bcp47_tzid);
+                }
+            }
+        }
+
+        let resource: &cldr_serde::time_zones::windows_zones::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/windowsZones.json")?;
+
+        let mut windows_to_bcp47 = ZeroMap2d::new();
+        let mut bcp47_to_windows = ZeroMap::new();
+        for map_zone in resource
+            .supplemental
+            .windows_zones
+            .map_time_zones
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let bcp47_ids = map_zone
+                .iana_ids
+                .split(' ')
+                .map(|iana_id| {
+                    bcp47_tzids.get(iana_id).copied().ok_or_else(|| {
+                        DataError::custom("Unknown IANA time zone in windowsZones.json")
+                            .with_display_context(iana_id)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            // The first zone in the list is the preferred one for the territory.
+            if let Some(bcp47_id) = bcp47_ids.first() {
+                windows_to_bcp47.insert(
+                    map_zone.windows_id.as_str(),
+                    &map_zone.territory,
+                    bcp47_id,
+                );
+            }
+            for bcp47_id in bcp47_ids.iter() {
+                bcp47_to_windows.insert(bcp47_id, map_zone.windows_id.as_str());
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(WindowsZonesV1 {
+                windows_to_bcp47,
+                bcp47_to_windows,
+            })),
+        })
+    }
+}
+
+impl IterableResourceProvider<WindowsZonesV1Marker> for TimeZonesProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// The primary time zones of regions with more than one time zone, from the `primaryZones` of
+/// CLDR's metaZones.xml, which is not part of CLDR-JSON.
+const PRIMARY_ZONES: &[(TinyAsciiStr<3>, &str)] = &[
+    (tinystr!(3, "CL"), "America/Santiago"),
+    (tinystr!(3, "CN"), "Asia/Shanghai"),
+    (tinystr!(3, "DE"), "Europe/Berlin"),
+    (tinystr!(3, "EC"), "America/Guayaquil"),
+    (tinystr!(3, "ES"), "Europe/Madrid"),
+    (tinystr!(3, "MH"), "Pacific/Majuro"),
+    (tinystr!(3, "MY"), "Asia/Kuala_Lumpur"),
+    (tinystr!(3, "NZ"), "Pacific/Auckland"),
+    (tinystr!(3, "PT"), "Europe/Lisbon"),
+    (tinystr!(3, "UA"), "Europe/Kiev"),
+    (tinystr!(3, "UZ"), "Asia/Tashkent"),
+];
+
+/// Returns the region of a BCP-47 time zone ID, which is given by its first two letters,
+/// except for a few IDs that predate that convention and for time zones without a region.
+fn region_of(bcp47_id: TimeZoneBcp47Id) -> Option<TinyAsciiStr<3>> {
+    let id = bcp47_id.0.as_str();
+    let region = match id {
+        "gazastrp" | "hebron" => "PS",
+        "jeruslm" => "IL",
+        "gmt" | "unk" => return None,
+        _ if id.starts_with("utc") || id.bytes().any(|b| b.is_ascii_digit()) => return None,
+        _ => id.get(0..2)?,
+    };
+    TinyAsciiStr::from_str(region)
+        .ok()
+        .map(|r| r.to_ascii_uppercase())
+}
+
+impl ResourceProvider<TimeZoneIdentifiersV1Marker> for TimeZonesProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<TimeZoneIdentifiersV1Marker>, DataError> {
+        if !req.options.is_empty() {
+            return Err(DataErrorKind::ExtraneousResourceOptions.into_error());
+        }
+
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.source
+                .cldr()?
+                .bcp47()
+                .read_and_parse("timezone.json")?;
+
+        let mut bcp47_to_iana = ZeroMap::new();
+        let mut iana_to_bcp47 = LiteMap::new();
+        let mut zones_by_region: LiteMap<TinyAsciiStr<3>, Vec<TimeZoneBcp47Id>> = LiteMap::new();
+        for (bcp47_tzid, bcp47_tzid_data) in resource.keyword.u.time_zones.values.iter() {
+            if let Some(alias) = &bcp47_tzid_data.alias {
+                // The first alias is the canonical IANA time zone ID.
+                if let Some(iana_id) = alias.split(' ').next() {
+                    bcp47_to_iana.insert(bcp47_tzid, iana_id);
+                }
+                for iana_id in alias.split(' ') {
+                    iana_to_bcp47.insert(iana_id, *bcp47_tzid);
+                }
+            }
+            if bcp47_tzid_data.deprecated == Some(true) {
+                continue;
+            }
+            if let Some(region) = region_of(*bcp47_tzid) {
+                if let Some(zones) = zones_by_region.get_mut(&region) {
+                    zones.push(*bcp47_tzid);
+                } else {
+                    zones_by_region.insert(region, vec![*bcp47_tzid]);
+                }
+            }
+        }
+
+        let mut primary_zones = ZeroMap::new();
+        for (region, zones) in zones_by_region.iter() {
+            if let [bcp47_tzid] = zones.as_slice() {
+                primary_zones.insert(bcp47_tzid, region);
+            }
+        }
+        for (region, iana_id) in PRIMARY_ZONES {
+            let bcp47_tzid = iana_to_bcp47.get(iana_id).ok_or_else(|| {
+                DataError::custom("Unknown IANA time zone in primary zones")
+                    .with_display_context(iana_id)
+            })?;
+            primary_zones.insert(bcp47_tzid, region);
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(TimeZoneIdentifiersV1 {
+                bcp47_to_iana,
+                primary_zones,
+            })),
+        })
+    }
+}
+
+impl IterableResourceProvider<TimeZoneIdentifiersV1Marker> for TimeZonesProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl ResourceProvider<TimeZoneRegionNamesV1Marker> for TimeZonesProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<TimeZoneRegionNamesV1Marker>, DataError> {
+        let langid = req.options.get_langid();
+
+        let identifiers: DataPayload<TimeZoneIdentifiersV1Marker> = self
+            .load_resource(&DataRequest::default())?
+            .take_payload()?;
+
+        let mut names = ZeroMap::new();
+        // The region names are in the localenames package, which may not cover all locales
+        // that have time zone names. Those locales use the exemplar cities instead.
+        let has_names = self
+            .source
+            .cldr()?
+            .displaynames()
+            .list_langs()
+            .map(|mut langs| langs.any(|l| l == langid))
+            .unwrap_or(false);
+        if has_names {
+            let resource: &cldr_serde::displaynames::Resource = self
+                .source
+                .cldr()?
+                .displaynames()
+                .read_and_parse(&langid, "territories.json")?;
+            let territories = &resource
+                .main
+                .0
+                .get(&langid)
+                .expect("CLDR file contains the expected language")
+                .locale_display_names
+                .territories;
+            for (_, region) in identifiers.get().primary_zones.iter_copied() {
+                if let Some(name) = territories.get(region.as_str()) {
+                    names.insert(&region, name.as_str());
+                }
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(TimeZoneRegionNamesV1(names))),
+        })
+    }
+}
+
+impl IterableResourceProvider<TimeZoneRegionNamesV1Marker> for TimeZonesProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .dates("gregorian")
+            .list_langs()?
+            .map(Into::<ResourceOptions>::into)
+            .collect())
+    }
+}
+
+icu_provider::make_exportable_provider!(
+    TimeZonesProvider,
+    [
+        TimeZoneFormatsV1Marker,
+        ExemplarCitiesV1Marker,
+        MetaZoneGenericNamesLongV1Marker,
+        MetaZoneGenericNamesShortV1Marker,
+        MetaZoneSpecificNamesLongV1Marker,
+        MetaZoneSpecificNamesShortV1Marker,
+        MetaZonePeriodV1Marker,
+        WindowsZonesV1Marker,
+        TimeZoneIdentifiersV1Marker,
+        TimeZoneRegionNamesV1Marker,
+    ]
+);
+
 #[cfg(test)]
 mod tests {
     use tinystr::tinystr;
@@ -284,4 +546,133 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn windows_zones() {
+        use icu_locid::subtags_region as region;
+
+        let provider = TimeZonesProvider::from(&SourceData::for_test());
+
+        let windows_zones: DataPayload<WindowsZonesV1Marker> = provider
+            .load_resource(&DataRequest::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            Some(TimeZoneBcp47Id(tinystr!(8, "uslax"))),
+            windows_zones
+                .get()
+                .get_bcp47_id("Pacific Standard Time", None)
+        );
+        assert_eq!(
+            Some(TimeZoneBcp47Id(tinystr!(8, "cavan"))),
+            windows_zones
+                .get()
+                .get_bcp47_id("Pacific Standard Time", Some(region!("CA")))
+        );
+        assert_eq!(
+            Some(TimeZoneBcp47Id(tinystr!(8, "uslax"))),
+            windows_zones
+                .get()
+                .get_bcp47_id("Pacific Standard Time", Some(region!("FR")))
+        );
+        assert_eq!(
+            None,
+            windows_zones.get().get_bcp47_id("Mars Standard Time", None)
+        );
+        assert_eq!(
+            Some("W. Europe Standard Time"),
+            windows_zones
+                .get()
+                .get_windows_id(TimeZoneBcp47Id(tinystr!(8, "chzrh")))
+        );
+        assert_eq!(
+            None,
+            windows_zones
+                .get()
+                .get_windows_id(TimeZoneBcp47Id(tinystr!(8, "unk")))
+        );
+    }
+
+    #[test]
+    fn time_zone_identifiers() {
+        use icu_locid::subtags_region as region;
+
+        let provider = TimeZonesProvider::from(&SourceData::for_test());
+
+        let identifiers: DataPayload<TimeZoneIdentifiersV1Marker> = provider
+            .load_resource(&DataRequest::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            Some("America/Los_Angeles"),
+            identifiers
+                .get()
+                .get_iana_id(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        );
+        assert_eq!(
+            Some("Etc/Unknown"),
+            identifiers
+                .get()
+                .get_iana_id(TimeZoneBcp47Id(tinystr!(8, "unk")))
+        );
+        // The only time zone in France.
+        assert_eq!(
+            Some(region!("FR")),
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "frpar")))
+        );
+        // The primary time zone of Germany, which also has Europe/Busingen.
+        assert_eq!(
+            Some(region!("DE")),
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "deber")))
+        );
+        assert_eq!(
+            None,
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "debsngn")))
+        );
+        assert_eq!(
+            Some(region!("IL")),
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "jeruslm")))
+        );
+        assert_eq!(
+            None,
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        );
+        assert_eq!(
+            None,
+            identifiers
+                .get()
+                .get_primary_region(TimeZoneBcp47Id(tinystr!(8, "utc")))
+        );
+    }
+
+    #[test]
+    fn time_zone_region_names() {
+        use icu_locid::langid;
+
+        let provider = TimeZonesProvider::from(&SourceData::for_test());
+
+        let region_names: DataPayload<TimeZoneRegionNamesV1Marker> = provider
+            .load_resource(&DataRequest {
+                options: langid!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(Some("France"), region_names.get().0.get(&tinystr!(3, "FR")));
+        // The United States has no primary time zone.
+        assert_eq!(None, region_names.get().0.get(&tinystr!(3, "US")));
+    }
 }
@@ -0,0 +1,222 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR unit patterns.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-general.html#Unit_Elements>
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use std::borrow::Cow;
+use zerovec::ZeroMap;
+
+mod supplemental;
+
+pub use supplemental::UnitsSupplementalProvider;
+
+/// A data provider reading from CLDR JSON unit files.
+#[derive(Debug)]
+pub struct UnitsProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for UnitsProvider {
+    fn from(source: &SourceData) -> Self {
+        UnitsProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+/// Returns the compound unit pattern of `per` or `times`.
+fn compound_pattern(patterns: &cldr_serde::units::UnitPatterns) -> Result<String, DataError> {
+    patterns
+        .get("compoundUnitPattern")
+        .cloned()
+        .ok_or_else(|| DataError::custom("Missing compound unit pattern"))
+}
+
+/// Returns the patterns of `power2` or `power3` by plural category, with the pattern for
+/// [`Count::Other`] and the patterns that differ from it.
+fn power_patterns(
+    patterns: &cldr_serde::units::UnitPatterns,
+) -> Result<ZeroMap<'static, Count, str>, DataError> {
+    let other = patterns
+        .get("compoundUnitPattern1-count-other")
+        .or_else(|| patterns.get("compoundUnitPattern1"))
+        .ok_or_else(|| DataError::custom("Missing power pattern"))?;
+    let mut result = ZeroMap::new();
+    result.insert(&Count::Other, other.as_str());
+    for (key, pattern) in patterns.iter() {
+        // Patterns for grammatical cases and genders do not parse as plural categories.
+        let category = match key
+            .strip_prefix("compoundUnitPattern1-count-")
+            .and_then(PluralCategory::from_tr35_string)
+        {
+            Some(category) => category,
+            None => continue,
+        };
+        if category != PluralCategory::Other && pattern != other {
+            result.insert(&Count::from(category), pattern.as_str());
+        }
+    }
+    Ok(result)
+}
+
+impl<M: ResourceMarker<Yokeable = UnitPatternsV1<'static>>> ResourceProvider<M> for UnitsProvider {
+    fn load_resource(&self, req: &DataRequest) -> Result<DataResponse<M>, DataError> {
+        let langid = req.options.get_langid();
+
+        let resource: &cldr_serde::units::Resource = self
+            .source
+            .cldr()?
+            .units()
+            .read_and_parse(&langid, "units.json")?;
+
+        let units = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .units;
+
+        let units = match M::KEY {
+            LongUnitPatternsV1Marker::KEY => &units.long,
+            ShortUnitPatternsV1Marker::KEY => &units.short,
+            NarrowUnitPatternsV1Marker::KEY => &units.narrow,
+            _ => return Err(DataError::custom("Unknown key for UnitPatternsV1")),
+        };
+
+        let mut result = UnitPatternsV1::default();
+        for (key, patterns) in units.iter() {
+            match key.as_str() {
+                "per" => result.per = Cow::Owned(compound_pattern(patterns)?),
+                "times" => result.times = Cow::Owned(compound_pattern(patterns)?),
+                "power2" => result.power2 = power_patterns(patterns)?,
+                "power3" => result.power3 = power_patterns(patterns)?,
+                _ => {
+                    // Units are keyed by their category and identifier, such as
+                    // `length-kilometer`. Prefixes and coordinate units have no unit patterns.
+                    let (unit, other) =
+                        match (key.split_once('-'), patterns.get("unitPattern-count-other")) {
+                            (Some((_, unit)), Some(other)) => (unit, other),
+                            _ => continue,
+                        };
+                    result.patterns.insert(unit, &Count::Other, other.as_str());
+                    for (attribute, pattern) in patterns.iter() {
+                        // Patterns for grammatical cases do not parse as plural categories.
+                        let category = match attribute
+                            .strip_prefix("unitPattern-count-")
+                            .and_then(PluralCategory::from_tr35_string)
+                        {
+                            Some(category) => category,
+                            None => continue,
+                        };
+                        if category != PluralCategory::Other && pattern != other {
+                            result
+                                .patterns
+                                .insert(unit, &Count::from(category), pattern.as_str());
+                        }
+                    }
+                    if let Some(per_unit_pattern) = patterns.get("perUnitPattern") {
+                        result
+                            .per_unit_patterns
+                            .insert(unit, per_unit_pattern.as_str());
+                    }
+                }
+            }
+        }
+
+        let metadata = DataResponseMetadata::default();
+        // TODO(#1109): Set metadata.data_langid correctly.
+        Ok(DataResponse {
+            metadata,
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(
+    UnitsProvider,
+    [
+        LongUnitPatternsV1Marker,
+        ShortUnitPatternsV1Marker,
+        NarrowUnitPatternsV1Marker,
+    ]
+);
+
+impl<M: ResourceMarker<Yokeable = UnitPatternsV1<'static>>> IterableResourceProvider<M>
+    for UnitsProvider
+{
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .units()
+            .list_langs()?
+            .map(Into::<ResourceOptions>::into)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = UnitsProvider::from(&SourceData::for_test());
+
+    let en_long: DataPayload<LongUnitPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let en_long = en_long.get();
+    assert_eq!(
+        en_long.patterns.get("kilometer", &Count::One),
+        Ok("{0} kilometer")
+    );
+    assert_eq!(
+        en_long.patterns.get("kilometer", &Count::Other),
+        Ok("{0} kilometers")
+    );
+    assert_eq!(en_long.per_unit_patterns.get("hour"), Some("{0} per hour"));
+    assert_eq!(en_long.per, "{0} per {1}");
+    assert_eq!(en_long.power2.get(&Count::Other), Some("square {0}"));
+    // The same as the pattern for `other`
+    assert_eq!(en_long.power2.get(&Count::One), None);
+
+    let ru_long: DataPayload<LongUnitPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        ru_long.get().patterns.get("kilometer", &Count::Many),
+        Ok("{0} километров")
+    );
+
+    let en_short: DataPayload<ShortUnitPatternsV1Marker> = provider
+        .load_resource(&DataRequest {
+            options: locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        en_short.get().patterns.get("kilometer", &Count::Other),
+        Ok("{0} km")
+    );
+    assert_eq!(en_short.get().per, "{0}/{1}");
+}
@@ -0,0 +1,283 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Transformation of CLDR unit conversions and unit preferences.
+//!
+//! Spec reference: <https://unicode.org/reports/tr35/tr35-info.html#Unit_Conversion>
+
+use crate::transform::cldr::cldr_serde;
+use crate::SourceData;
+use icu_provider::datagen::IterableResourceProvider;
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use tinystr::TinyAsciiStr;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::VarZeroVec;
+
+/// A data provider reading from the CLDR JSON supplemental units file.
+#[derive(Debug)]
+pub struct UnitsSupplementalProvider {
+    source: SourceData,
+}
+
+impl From<&SourceData> for UnitsSupplementalProvider {
+    fn from(source: &SourceData) -> Self {
+        UnitsSupplementalProvider {
+            source: source.clone(),
+        }
+    }
+}
+
+impl UnitsSupplementalProvider {
+    fn supplemental(&self) -> Result<&cldr_serde::units_supplemental::Supplemental, DataError> {
+        let resource: &cldr_serde::units_supplemental::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+        Ok(&resource.supplemental)
+    }
+}
+
+/// The factors of a product of decimal numbers divided by a product of decimal numbers.
+#[derive(Default)]
+struct Expression {
+    numerator: Vec<String>,
+    denominator: Vec<String>,
+}
+
+impl Expression {
+    /// Parses an expression of decimal numbers and unit constants, such as `ft_to_m^3/12`,
+    /// replacing the constants by their values.
+    fn parse(
+        expression: &str,
+        constants: &LiteMap<String, cldr_serde::units_supplemental::UnitConstant>,
+    ) -> Result<Self, DataError> {
+        let mut result = Self::default();
+        for (i, product) in expression.split('/').enumerate() {
+            let in_denominator = i > 0;
+            for factor in product.split('*') {
+                let (factor, power) = match factor.trim().split_once('^') {
+                    Some((factor, power)) => (
+                        factor.trim(),
+                        power.trim().parse::<usize>().map_err(|_| {
+                            DataError::custom("Invalid power in unit expression")
+                                .with_display_context(expression)
+                        })?,
+                    ),
+                    None => (factor.trim(), 1),
+                };
+                for _ in 0..power {
+                    match constants.get(factor) {
+                        Some(constant) => {
+                            let Self {
+                                numerator,
+                                denominator,
+                            } = Self::parse(&constant.value, constants)?;
+                            if in_denominator {
+                                result.numerator.extend(denominator);
+                                result.denominator.extend(numerator);
+                            } else {
+                                result.numerator.extend(numerator);
+                                result.denominator.extend(denominator);
+                            }
+                        }
+                        None if factor.parse::<f64>().is_ok() => {
+                            if in_denominator {
+                                result.denominator.push(factor.to_string());
+                            } else {
+                                result.numerator.push(factor.to_string());
+                            }
+                        }
+                        None => {
+                            return Err(DataError::custom("Unknown unit constant")
+                                .with_display_context(factor))
+                        }
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.numerator.is_empty() {
+            write!(f, "1")?;
+        } else {
+            write!(f, "{}", self.numerator.join("*"))?;
+        }
+        if !self.denominator.is_empty() {
+            write!(f, "/{}", self.denominator.join("*"))?;
+        }
+        Ok(())
+    }
+}
+
+impl ResourceProvider<UnitsConversionV1Marker> for UnitsSupplementalProvider {
+    fn load_resource(
+        &self,
+        _: &DataRequest,
+    ) -> Result<DataResponse<UnitsConversionV1Marker>, DataError> {
+        let supplemental = self.supplemental()?;
+
+        let mut result = UnitsConversionV1::default();
+        for (unit, convert) in supplemental.convert_units.iter() {
+            let factor = match &convert.factor {
+                Some(factor) => Expression::parse(factor, &supplemental.unit_constants)?,
+                None => Expression::default(),
+            };
+            let offset = match &convert.offset {
+                Some(offset) => {
+                    Expression::parse(offset, &supplemental.unit_constants)?.to_string()
+                }
+                None => String::new(),
+            };
+            let info = ConversionInfo {
+                base_unit: Cow::Borrowed(&convert.base_unit),
+                factor: Cow::Owned(factor.to_string()),
+                offset: Cow::Owned(offset),
+            };
+            result
+                .conversions
+                .insert(unit.as_str(), &*encode_varule_to_box(&info));
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl ResourceProvider<UnitPreferencesV1Marker> for UnitsSupplementalProvider {
+    fn load_resource(
+        &self,
+        _: &DataRequest,
+    ) -> Result<DataResponse<UnitPreferencesV1Marker>, DataError> {
+        let supplemental = self.supplemental()?;
+
+        let mut result = UnitPreferencesV1::default();
+        for (quantity, usages) in supplemental.unit_preference_data.iter() {
+            for (usage, regions) in usages.iter() {
+                let key = format!("{}-{}", quantity, usage);
+                for (regions, preferences) in regions.iter() {
+                    let units: Vec<&str> = preferences.iter().map(|p| p.unit.as_str()).collect();
+                    // The last unit is used for all remaining values, and the other units
+                    // default to a threshold of 1.
+                    let thresholds: Vec<&str> = preferences
+                        .iter()
+                        .take(preferences.len().saturating_sub(1))
+                        .map(|p| p.geq.as_deref().unwrap_or("1"))
+                        .collect();
+                    let preferences = UnitPreferences {
+                        units: VarZeroVec::from(&units),
+                        thresholds: VarZeroVec::from(&thresholds),
+                    };
+                    for region in regions.split_whitespace() {
+                        let region = TinyAsciiStr::<3>::from_str(region).map_err(|_| {
+                            DataError::custom("Invalid region in unit preferences")
+                                .with_display_context(region)
+                        })?;
+                        result.preferences.insert(
+                            key.as_str(),
+                            &region,
+                            &*encode_varule_to_box(&preferences),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+icu_provider::make_exportable_provider!(
+    UnitsSupplementalProvider,
+    [UnitsConversionV1Marker, UnitPreferencesV1Marker,]
+);
+
+impl IterableResourceProvider<UnitsConversionV1Marker> for UnitsSupplementalProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl IterableResourceProvider<UnitPreferencesV1Marker> for UnitsSupplementalProvider {
+    fn supported_options(&self) -> Result<Vec<ResourceOptions>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = UnitsSupplementalProvider::from(&SourceData::for_test());
+
+    let conversion: DataPayload<UnitsConversionV1Marker> = provider
+        .load_resource(&Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let conversion =
+        |unit| ConversionInfo::zero_from(conversion.get().conversions.get(unit).unwrap());
+    assert_eq!(
+        conversion("inch"),
+        ConversionInfo {
+            base_unit: "meter".into(),
+            factor: "0.3048/12".into(),
+            offset: "".into(),
+        }
+    );
+    // Constants in the denominator are inverted.
+    assert_eq!(
+        conversion("gallon").factor,
+        "231*0.3048*0.3048*0.3048/12*12*12"
+    );
+    assert_eq!(conversion("fahrenheit").offset, "2298.35/9");
+    assert_eq!(conversion("meter").factor, "1");
+
+    let preferences: DataPayload<UnitPreferencesV1Marker> = provider
+        .load_resource(&Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let preferences = |key, region: &str| {
+        let region = TinyAsciiStr::<3>::from_str(region).unwrap();
+        let preferences =
+            UnitPreferences::zero_from(preferences.get().preferences.get(key, &region).unwrap());
+        (
+            preferences
+                .units
+                .iter()
+                .map(String::from)
+                .collect::<Vec<_>>(),
+            preferences
+                .thresholds
+                .iter()
+                .map(String::from)
+                .collect::<Vec<_>>(),
+        )
+    };
+    assert_eq!(
+        preferences("length-person-height", "US"),
+        (
+            vec!["foot-and-inch".into(), "inch".into()],
+            vec!["3".into()]
+        )
+    );
+    // Every region of a space-separated list has the preferences.
+    assert_eq!(
+        preferences("temperature-weather", "PR"),
+        (vec!["fahrenheit".into()], vec![])
+    );
+}
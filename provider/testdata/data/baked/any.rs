@@ -22,12 +22,29 @@ impl AnyProvider for BakedDataProvider {
             <::icu_datetime::provider::time_zones::MetaZoneSpecificNamesLongV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::MetaZoneSpecificNamesLongV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::specific_long_v1::DATA, key, req)?),
             <::icu_datetime::provider::time_zones::MetaZoneSpecificNamesShortV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::MetaZoneSpecificNamesShortV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::specific_short_v1::DATA, key, req)?),
             <::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::formats_v1::DATA, key, req)?),
+            <::icu_datetime::provider::time_zones::TimeZoneIdentifiersV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::TimeZoneIdentifiersV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::identifiers_v1::DATA, key, req)?),
+            <::icu_datetime::provider::time_zones::TimeZoneRegionNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::TimeZoneRegionNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::region_names_v1::DATA, key, req)?),
+            <::icu_datetime::provider::time_zones::WindowsZonesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::time_zones::WindowsZonesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (time_zone::windows_zones_v1::DATA, key, req)?),
             <::icu_datetime::provider::week_data::WeekDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_datetime::provider::week_data::WeekDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (datetime::week_data_v1::DATA, key, req)?),
+            <::icu_decimal::provider::CurrencyDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::CurrencyDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::currency_names_v1::DATA, key, req)?),
+            <::icu_decimal::provider::CurrencyFractionsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::CurrencyFractionsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::currency_fractions_v1::DATA, key, req)?),
+            <::icu_decimal::provider::CurrencyPatternsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::CurrencyPatternsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::currency_patterns_v1::DATA, key, req)?),
             <::icu_decimal::provider::DecimalSymbolsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::DecimalSymbolsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::symbols_v1::DATA, key, req)?),
+            <::icu_decimal::provider::LongCompactDecimalFormatDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::LongCompactDecimalFormatDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::compact_long_v1::DATA, key, req)?),
+            <::icu_decimal::provider::MiscPatternsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::MiscPatternsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::misc_patterns_v1::DATA, key, req)?),
+            <::icu_decimal::provider::PercentPatternV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::PercentPatternV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::percent_v1::DATA, key, req)?),
+            <::icu_decimal::provider::ScientificSymbolsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::ScientificSymbolsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::scientific_v1::DATA, key, req)?),
+            <::icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (decimal::compact_short_v1::DATA, key, req)?),
+            <::icu_displaynames::provider::LanguageDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_displaynames::provider::LanguageDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (displaynames::languages_v1::DATA, key, req)?),
+            <::icu_displaynames::provider::LocaleDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_displaynames::provider::LocaleDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (displaynames::locales_v1::DATA, key, req)?),
+            <::icu_displaynames::provider::RegionDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_displaynames::provider::RegionDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (displaynames::regions_v1::DATA, key, req)?),
+            <::icu_displaynames::provider::ScriptDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_displaynames::provider::ScriptDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (displaynames::scripts_v1::DATA, key, req)?),
+            <::icu_displaynames::provider::VariantDisplayNamesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_displaynames::provider::VariantDisplayNamesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (displaynames::variants_v1::DATA, key, req)?),
             <::icu_list::provider::AndListV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_list::provider::AndListV1Marker as DataMarker >::Yokeable > (litemap_slice_get (list::and_v1::DATA, key, req)?),
             <::icu_list::provider::OrListV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_list::provider::OrListV1Marker as DataMarker >::Yokeable > (litemap_slice_get (list::or_v1::DATA, key, req)?),
             <::icu_list::provider::UnitListV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_list::provider::UnitListV1Marker as DataMarker >::Yokeable > (litemap_slice_get (list::unit_v1::DATA, key, req)?),
             <::icu_locale_canonicalizer::provider::AliasesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_locale_canonicalizer::provider::AliasesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (locale_canonicalizer::aliases_v1::DATA, key, req)?),
+            <::icu_locale_canonicalizer::provider::LanguageMatchingV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_locale_canonicalizer::provider::LanguageMatchingV1Marker as DataMarker >::Yokeable > (litemap_slice_get (locale_canonicalizer::matching_v1::DATA, key, req)?),
             <::icu_locale_canonicalizer::provider::LikelySubtagsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_locale_canonicalizer::provider::LikelySubtagsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (locale_canonicalizer::likelysubtags_v1::DATA, key, req)?),
             <::icu_normalizer::provider::CanonicalCompositionPassthroughV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_normalizer::provider::CanonicalCompositionPassthroughV1Marker as DataMarker >::Yokeable > (litemap_slice_get (normalizer::nfc_v1::DATA, key, req)?),
             <::icu_normalizer::provider::CanonicalCompositionsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_normalizer::provider::CanonicalCompositionsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (normalizer::comp_v1::DATA, key, req)?),
@@ -38,8 +55,11 @@ impl AnyProvider for BakedDataProvider {
             <::icu_normalizer::provider::CompatibilityDecompositionTablesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_normalizer::provider::CompatibilityDecompositionTablesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (normalizer::nfkdex_v1::DATA, key, req)?),
             <::icu_normalizer::provider::Uts46CompositionPassthroughV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_normalizer::provider::Uts46CompositionPassthroughV1Marker as DataMarker >::Yokeable > (litemap_slice_get (normalizer::uts46_v1::DATA, key, req)?),
             <::icu_normalizer::provider::Uts46DecompositionSupplementV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_normalizer::provider::Uts46DecompositionSupplementV1Marker as DataMarker >::Yokeable > (litemap_slice_get (normalizer::uts46d_v1::DATA, key, req)?),
+            <::icu_plurals::provider::CardinalSamplesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_plurals::provider::CardinalSamplesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (plurals::cardinal_samples_v1::DATA, key, req)?),
             <::icu_plurals::provider::CardinalV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_plurals::provider::CardinalV1Marker as DataMarker >::Yokeable > (litemap_slice_get (plurals::cardinal_v1::DATA, key, req)?),
+            <::icu_plurals::provider::OrdinalSamplesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_plurals::provider::OrdinalSamplesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (plurals::ordinal_samples_v1::DATA, key, req)?),
             <::icu_plurals::provider::OrdinalV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_plurals::provider::OrdinalV1Marker as DataMarker >::Yokeable > (litemap_slice_get (plurals::ordinal_v1::DATA, key, req)?),
+            <::icu_plurals::provider::PluralRangesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_plurals::provider::PluralRangesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (plurals::ranges_v1::DATA, key, req)?),
             <::icu_properties::provider::AlphabeticV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_properties::provider::AlphabeticV1Marker as DataMarker >::Yokeable > (litemap_slice_get (props::alpha_v1::DATA, key, req)?),
             <::icu_properties::provider::AsciiHexDigitV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_properties::provider::AsciiHexDigitV1Marker as DataMarker >::Yokeable > (litemap_slice_get (props::ahex_v1::DATA, key, req)?),
             <::icu_properties::provider::BidiClassV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_properties::provider::BidiClassV1Marker as DataMarker >::Yokeable > (litemap_slice_get (props::bc_v1::DATA, key, req)?),
@@ -101,10 +121,16 @@ impl AnyProvider for BakedDataProvider {
             <::icu_properties::provider::XidStartV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_properties::provider::XidStartV1Marker as DataMarker >::Yokeable > (litemap_slice_get (props::xids_v1::DATA, key, req)?),
             <::icu_provider_adapters::fallback::provider::LocaleFallbackLikelySubtagsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_provider_adapters::fallback::provider::LocaleFallbackLikelySubtagsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (fallback::likelysubtags_v1::DATA, key, req)?),
             <::icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (fallback::parents_v1::DATA, key, req)?),
+            <::icu_rbnf::provider::RbnfRulesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_rbnf::provider::RbnfRulesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (rbnf::rules_v1::DATA, key, req)?),
             <::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (segmenter::grapheme_v1::DATA, key, req)?),
             <::icu_segmenter::provider::LineBreakDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_segmenter::provider::LineBreakDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (segmenter::line_v1::DATA, key, req)?),
             <::icu_segmenter::provider::SentenceBreakDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_segmenter::provider::SentenceBreakDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (segmenter::sentence_v1::DATA, key, req)?),
             <::icu_segmenter::provider::WordBreakDataV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_segmenter::provider::WordBreakDataV1Marker as DataMarker >::Yokeable > (litemap_slice_get (segmenter::word_v1::DATA, key, req)?),
+            <::icu_units::provider::LongUnitPatternsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_units::provider::LongUnitPatternsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (units::long_v1::DATA, key, req)?),
+            <::icu_units::provider::NarrowUnitPatternsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_units::provider::NarrowUnitPatternsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (units::narrow_v1::DATA, key, req)?),
+            <::icu_units::provider::ShortUnitPatternsV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_units::provider::ShortUnitPatternsV1Marker as DataMarker >::Yokeable > (litemap_slice_get (units::short_v1::DATA, key, req)?),
+            <::icu_units::provider::UnitPreferencesV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_units::provider::UnitPreferencesV1Marker as DataMarker >::Yokeable > (litemap_slice_get (units::preferences_v1::DATA, key, req)?),
+            <::icu_units::provider::UnitsConversionV1Marker as ResourceMarker >::KEY => AnyPayload::from_static_ref::<<::icu_units::provider::UnitsConversionV1Marker as DataMarker >::Yokeable > (litemap_slice_get (units::conversion_v1::DATA, key, req)?),
             _ => return Err (DataErrorKind::MissingResourceKey . with_req (key, req)), }), metadata : Default::default (), })
     }
 }
@@ -0,0 +1,1324 @@
+// @generated
+type DataStruct = & 'static < :: icu_decimal :: provider :: CurrencyDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG),
+    ("ar-EG", AR_AR_EG),
+    ("bn", BN),
+    ("ccp", CCP),
+    ("en", EN),
+    ("en-001", EN_001),
+    ("en-ZA", EN_ZA),
+    ("es", ES),
+    ("es-AR", ES_AR),
+    ("fil", FIL),
+    ("fr", FR),
+    ("ja", JA),
+    ("ru", RU),
+    ("sr", SR_SR_CYRL),
+    ("sr-Cyrl", SR_SR_CYRL),
+    ("sr-Latn", SR_LATN),
+    ("th", TH),
+    ("tr", TR),
+    ("und", UND),
+];
+static AR_AR_EG: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 67u8, 65u8,
+                    36u8, 216u8, 172u8, 46u8, 217u8, 133u8, 46u8, 226u8, 128u8, 143u8, 226u8,
+                    130u8, 172u8, 85u8, 75u8, 194u8, 163u8, 74u8, 80u8, 194u8, 165u8, 85u8, 83u8,
+                    36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 85u8,
+                    83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 36u8, 69u8, 194u8, 163u8, 194u8, 163u8,
+                    194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8,
+                    66u8, 80u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    5u8, 5u8, 2u8, 3u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8,
+                    0u8, 65u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 130u8,
+                    0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 180u8, 0u8, 0u8,
+                    0u8, 216u8, 175u8, 217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 216u8, 177u8,
+                    32u8, 217u8, 131u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 217u8, 129u8,
+                    216u8, 177u8, 217u8, 134u8, 217u8, 131u8, 32u8, 216u8, 179u8, 217u8, 136u8,
+                    217u8, 138u8, 216u8, 179u8, 216u8, 177u8, 217u8, 138u8, 216u8, 172u8, 217u8,
+                    134u8, 217u8, 138u8, 217u8, 135u8, 216u8, 167u8, 217u8, 134u8, 32u8, 217u8,
+                    133u8, 216u8, 181u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8,
+                    216u8, 172u8, 217u8, 134u8, 217u8, 138u8, 217u8, 135u8, 216u8, 167u8, 216u8,
+                    170u8, 32u8, 217u8, 133u8, 216u8, 181u8, 216u8, 177u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 172u8, 217u8, 134u8, 217u8, 138u8, 217u8, 135u8, 217u8, 139u8,
+                    216u8, 167u8, 32u8, 217u8, 133u8, 216u8, 181u8, 216u8, 177u8, 217u8, 138u8,
+                    217u8, 139u8, 216u8, 167u8, 216u8, 172u8, 217u8, 134u8, 217u8, 138u8, 217u8,
+                    135u8, 32u8, 217u8, 133u8, 216u8, 181u8, 216u8, 177u8, 217u8, 138u8, 217u8,
+                    138u8, 217u8, 136u8, 216u8, 177u8, 217u8, 136u8, 216u8, 172u8, 217u8, 134u8,
+                    217u8, 138u8, 217u8, 135u8, 32u8, 216u8, 165u8, 216u8, 179u8, 216u8, 170u8,
+                    216u8, 177u8, 217u8, 132u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 217u8,
+                    138u8, 217u8, 134u8, 32u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8, 216u8,
+                    167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 175u8, 217u8, 136u8, 217u8, 132u8,
+                    216u8, 167u8, 216u8, 177u8, 32u8, 216u8, 163u8, 217u8, 133u8, 216u8, 177u8,
+                    217u8, 138u8, 217u8, 131u8, 217u8, 138u8,
+                ])
+            },
+        )
+    },
+};
+static BN: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8,
+                    78u8, 82u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 224u8, 167u8, 179u8, 67u8, 65u8, 36u8, 226u8, 130u8, 172u8, 194u8, 163u8,
+                    226u8, 130u8, 185u8, 74u8, 80u8, 194u8, 165u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    36u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8,
+                    66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 83u8, 0u8, 0u8,
+                    0u8, 111u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 197u8,
+                    0u8, 0u8, 0u8, 231u8, 0u8, 0u8, 0u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    166u8, 224u8, 167u8, 135u8, 224u8, 166u8, 182u8, 224u8, 167u8, 128u8, 32u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 184u8, 224u8, 167u8, 129u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    184u8, 32u8, 224u8, 166u8, 171u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 129u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    137u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 172u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 32u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 191u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8,
+                    32u8, 224u8, 166u8, 135u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 161u8, 224u8, 166u8, 178u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static CCP: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8,
+                    78u8, 82u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 224u8, 167u8, 179u8, 67u8, 65u8, 36u8, 226u8, 130u8, 172u8, 194u8, 163u8,
+                    226u8, 130u8, 185u8, 74u8, 80u8, 194u8, 165u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    36u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static EN: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 67u8, 65u8, 36u8,
+                    226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 82u8, 85u8, 66u8, 90u8,
+                    65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 36u8, 107u8, 114u8, 69u8, 194u8, 163u8,
+                    226u8, 130u8, 189u8, 82u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 82u8, 85u8,
+                    66u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 5u8, 1u8,
+                    5u8, 1u8, 5u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 79u8, 0u8,
+                    0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8,
+                    117u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 156u8, 0u8,
+                    0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8,
+                    208u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 67u8, 97u8,
+                    110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                    114u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8,
+                    108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8,
+                    114u8, 97u8, 110u8, 99u8, 115u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8, 32u8,
+                    107u8, 114u8, 111u8, 110u8, 101u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 114u8, 69u8, 103u8, 121u8, 112u8,
+                    116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 69u8,
+                    103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 117u8, 114u8, 111u8, 101u8, 117u8, 114u8, 111u8,
+                    115u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 115u8, 74u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 101u8, 110u8, 82u8, 117u8,
+                    115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8, 101u8, 82u8,
+                    117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8,
+                    101u8, 115u8, 85u8, 83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 85u8,
+                    83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 111u8, 117u8,
+                    116u8, 104u8, 32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 114u8,
+                    97u8, 110u8, 100u8,
+                ])
+            },
+        )
+    },
+};
+static EN_001: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 67u8, 65u8, 36u8,
+                    226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 85u8,
+                    83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 82u8, 85u8, 66u8, 85u8,
+                    83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 36u8, 107u8,
+                    114u8, 69u8, 194u8, 163u8, 226u8, 130u8, 189u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 82u8, 85u8,
+                    66u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 5u8, 1u8,
+                    5u8, 1u8, 5u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 79u8, 0u8,
+                    0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8,
+                    117u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 156u8, 0u8,
+                    0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8,
+                    208u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 67u8, 97u8,
+                    110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                    114u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8,
+                    108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8,
+                    114u8, 97u8, 110u8, 99u8, 115u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8, 32u8,
+                    107u8, 114u8, 111u8, 110u8, 101u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 114u8, 69u8, 103u8, 121u8, 112u8,
+                    116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 69u8,
+                    103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 117u8, 114u8, 111u8, 101u8, 117u8, 114u8, 111u8,
+                    115u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 115u8, 74u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 101u8, 110u8, 82u8, 117u8,
+                    115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8, 101u8, 82u8,
+                    117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8,
+                    101u8, 115u8, 85u8, 83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 85u8,
+                    83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 111u8, 117u8,
+                    116u8, 104u8, 32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 114u8,
+                    97u8, 110u8, 100u8,
+                ])
+            },
+        )
+    },
+};
+static EN_ZA: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 67u8, 65u8, 36u8, 226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8,
+                    194u8, 165u8, 85u8, 83u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 82u8, 85u8, 66u8, 85u8,
+                    83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 36u8, 107u8, 114u8, 69u8, 194u8, 163u8,
+                    226u8, 130u8, 189u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 82u8, 85u8,
+                    66u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 5u8, 1u8,
+                    5u8, 1u8, 5u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 79u8, 0u8,
+                    0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8,
+                    117u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 156u8, 0u8,
+                    0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8,
+                    208u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 67u8, 97u8,
+                    110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                    114u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8,
+                    108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8,
+                    114u8, 97u8, 110u8, 99u8, 115u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8, 32u8,
+                    107u8, 114u8, 111u8, 110u8, 101u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 114u8, 69u8, 103u8, 121u8, 112u8,
+                    116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 69u8,
+                    103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 117u8, 114u8, 111u8, 101u8, 117u8, 114u8, 111u8,
+                    115u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 115u8, 74u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 101u8, 110u8, 82u8, 117u8,
+                    115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8, 101u8, 82u8,
+                    117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 98u8, 108u8,
+                    101u8, 115u8, 85u8, 83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 85u8,
+                    83u8, 32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 111u8, 117u8,
+                    116u8, 104u8, 32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 114u8,
+                    97u8, 110u8, 100u8,
+                ])
+            },
+        )
+    },
+};
+static ES: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 85u8, 82u8, 85u8, 83u8, 68u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 226u8, 130u8,
+                    172u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 67u8, 65u8, 68u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 85u8,
+                    83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 36u8, 36u8, 194u8, 163u8, 194u8, 165u8,
+                    36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8,
+                    66u8, 80u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8,
+                    0u8, 47u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 79u8, 0u8, 0u8, 0u8, 93u8, 0u8,
+                    0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8,
+                    134u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 163u8, 0u8,
+                    0u8, 0u8, 112u8, 101u8, 115u8, 111u8, 32u8, 97u8, 114u8, 103u8, 101u8, 110u8,
+                    116u8, 105u8, 110u8, 111u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 97u8,
+                    114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 115u8, 100u8, 195u8,
+                    179u8, 108u8, 97u8, 114u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8,
+                    110u8, 115u8, 101u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8,
+                    32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 111u8, 32u8, 115u8, 117u8, 105u8, 122u8,
+                    111u8, 102u8, 114u8, 97u8, 110u8, 99u8, 111u8, 115u8, 32u8, 115u8, 117u8,
+                    105u8, 122u8, 111u8, 115u8, 101u8, 117u8, 114u8, 111u8, 101u8, 117u8, 114u8,
+                    111u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8, 32u8, 101u8, 115u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 110u8, 97u8, 108u8, 105u8, 98u8, 114u8, 97u8,
+                    115u8, 32u8, 101u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 97u8,
+                    115u8, 121u8, 101u8, 110u8, 121u8, 101u8, 110u8, 101u8, 115u8, 100u8, 195u8,
+                    179u8, 108u8, 97u8, 114u8, 32u8, 101u8, 115u8, 116u8, 97u8, 100u8, 111u8,
+                    117u8, 110u8, 105u8, 100u8, 101u8, 110u8, 115u8, 101u8, 100u8, 195u8, 179u8,
+                    108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 101u8, 115u8, 116u8, 97u8, 100u8,
+                    111u8, 117u8, 110u8, 105u8, 100u8, 101u8, 110u8, 115u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 69u8, 85u8, 82u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    36u8, 226u8, 130u8, 172u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 36u8, 194u8, 163u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8,
+                    66u8, 80u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8,
+                    0u8, 47u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 79u8, 0u8, 0u8, 0u8, 93u8, 0u8,
+                    0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8,
+                    134u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 163u8, 0u8,
+                    0u8, 0u8, 112u8, 101u8, 115u8, 111u8, 32u8, 97u8, 114u8, 103u8, 101u8, 110u8,
+                    116u8, 105u8, 110u8, 111u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 97u8,
+                    114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 115u8, 100u8, 195u8,
+                    179u8, 108u8, 97u8, 114u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8,
+                    110u8, 115u8, 101u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8,
+                    32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 111u8, 32u8, 115u8, 117u8, 105u8, 122u8,
+                    111u8, 102u8, 114u8, 97u8, 110u8, 99u8, 111u8, 115u8, 32u8, 115u8, 117u8,
+                    105u8, 122u8, 111u8, 115u8, 101u8, 117u8, 114u8, 111u8, 101u8, 117u8, 114u8,
+                    111u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8, 32u8, 101u8, 115u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 110u8, 97u8, 108u8, 105u8, 98u8, 114u8, 97u8,
+                    115u8, 32u8, 101u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 97u8,
+                    115u8, 121u8, 101u8, 110u8, 121u8, 101u8, 110u8, 101u8, 115u8, 100u8, 195u8,
+                    179u8, 108u8, 97u8, 114u8, 32u8, 101u8, 115u8, 116u8, 97u8, 100u8, 111u8,
+                    117u8, 110u8, 105u8, 100u8, 101u8, 110u8, 115u8, 101u8, 100u8, 195u8, 179u8,
+                    108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 101u8, 115u8, 116u8, 97u8, 100u8,
+                    111u8, 117u8, 110u8, 105u8, 100u8, 101u8, 110u8, 115u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 74u8, 80u8, 89u8, 80u8, 72u8, 80u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 226u8, 130u8, 172u8, 74u8, 80u8, 194u8, 165u8, 226u8,
+                    130u8, 177u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[74u8, 80u8, 89u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 194u8, 165u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 74u8, 80u8, 89u8, 80u8, 72u8, 80u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    21u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 101u8, 117u8, 114u8, 111u8, 101u8,
+                    117u8, 114u8, 111u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 121u8, 101u8, 110u8, 112u8, 105u8, 115u8, 111u8, 32u8, 110u8,
+                    103u8, 32u8, 80u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 97u8, 115u8,
+                    100u8, 111u8, 108u8, 121u8, 97u8, 114u8, 32u8, 110u8, 103u8, 32u8, 85u8, 83u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 36u8, 67u8, 65u8, 226u8, 130u8, 172u8, 194u8, 163u8, 71u8,
+                    66u8, 36u8, 85u8, 83u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 36u8, 194u8, 163u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                    0u8, 44u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 67u8, 0u8,
+                    0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8,
+                    121u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                    114u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 100u8, 111u8,
+                    108u8, 108u8, 97u8, 114u8, 115u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8,
+                    101u8, 110u8, 115u8, 102u8, 114u8, 97u8, 110u8, 99u8, 32u8, 115u8, 117u8,
+                    105u8, 115u8, 115u8, 101u8, 102u8, 114u8, 97u8, 110u8, 99u8, 115u8, 32u8,
+                    115u8, 117u8, 105u8, 115u8, 115u8, 101u8, 115u8, 101u8, 117u8, 114u8, 111u8,
+                    101u8, 117u8, 114u8, 111u8, 115u8, 108u8, 105u8, 118u8, 114u8, 101u8, 32u8,
+                    115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 103u8, 108u8, 105u8, 118u8,
+                    114u8, 101u8, 115u8, 32u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8,
+                    103u8, 121u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 97u8,
+                    105u8, 115u8, 121u8, 101u8, 110u8, 115u8, 32u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 97u8, 105u8, 115u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 32u8,
+                    100u8, 101u8, 115u8, 32u8, 195u8, 137u8, 116u8, 97u8, 116u8, 115u8, 45u8, 85u8,
+                    110u8, 105u8, 115u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 32u8,
+                    100u8, 101u8, 115u8, 32u8, 195u8, 137u8, 116u8, 97u8, 116u8, 115u8, 45u8, 85u8,
+                    110u8, 105u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 85u8,
+                    83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 67u8, 65u8, 36u8, 226u8, 130u8, 172u8,
+                    194u8, 163u8, 239u8, 191u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[67u8, 65u8, 68u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8,
+                    0u8, 44u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 227u8,
+                    130u8, 171u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 32u8, 227u8, 131u8,
+                    137u8, 227u8, 131u8, 171u8, 227u8, 130u8, 185u8, 227u8, 130u8, 164u8, 227u8,
+                    130u8, 185u8, 32u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    179u8, 227u8, 131u8, 166u8, 227u8, 131u8, 188u8, 227u8, 131u8, 173u8, 232u8,
+                    139u8, 177u8, 229u8, 155u8, 189u8, 227u8, 131u8, 157u8, 227u8, 131u8, 179u8,
+                    227u8, 131u8, 137u8, 229u8, 134u8, 134u8, 231u8, 177u8, 179u8, 227u8, 131u8,
+                    137u8, 227u8, 131u8, 171u8,
+                ])
+            },
+        )
+    },
+};
+static RU: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8, 80u8, 89u8, 82u8,
+                    85u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 67u8, 65u8, 36u8,
+                    226u8, 130u8, 172u8, 194u8, 163u8, 194u8, 165u8, 226u8, 130u8, 189u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[67u8, 65u8, 68u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 74u8,
+                    80u8, 89u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+                    0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 3u8, 4u8, 5u8, 1u8, 3u8, 4u8, 5u8, 5u8, 1u8, 3u8, 4u8, 5u8, 1u8, 3u8, 4u8,
+                    5u8, 1u8, 3u8, 4u8, 5u8, 1u8, 3u8, 4u8, 5u8, 1u8, 4u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8,
+                    0u8, 99u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8, 167u8, 0u8, 0u8, 0u8, 202u8,
+                    0u8, 0u8, 0u8, 239u8, 0u8, 0u8, 0u8, 20u8, 1u8, 0u8, 0u8, 28u8, 1u8, 0u8, 0u8,
+                    78u8, 1u8, 0u8, 0u8, 130u8, 1u8, 0u8, 0u8, 184u8, 1u8, 0u8, 0u8, 238u8, 1u8,
+                    0u8, 0u8, 7u8, 2u8, 0u8, 0u8, 32u8, 2u8, 0u8, 0u8, 55u8, 2u8, 0u8, 0u8, 80u8,
+                    2u8, 0u8, 0u8, 107u8, 2u8, 0u8, 0u8, 136u8, 2u8, 0u8, 0u8, 167u8, 2u8, 0u8,
+                    0u8, 198u8, 2u8, 0u8, 0u8, 229u8, 2u8, 0u8, 0u8, 4u8, 3u8, 0u8, 0u8, 37u8, 3u8,
+                    0u8, 0u8, 70u8, 3u8, 0u8, 0u8, 89u8, 3u8, 0u8, 0u8, 112u8, 3u8, 0u8, 0u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 180u8, 208u8,
+                    190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8,
+                    187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8,
+                    187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8,
+                    180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8,
+                    134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 186u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8,
+                    134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8,
+                    185u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 186u8, 208u8, 190u8, 208u8, 178u8, 209u8, 136u8, 208u8,
+                    178u8, 208u8, 181u8, 208u8, 185u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8,
+                    209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8, 190u8, 208u8, 177u8,
+                    209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 209u8, 132u8, 209u8,
+                    131u8, 208u8, 189u8, 209u8, 130u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    181u8, 209u8, 128u8, 208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 179u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8,
+                    208u8, 176u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                    209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 190u8, 208u8,
+                    178u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 178u8,
+                    208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8,
+                    32u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8,
+                    32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 178u8, 209u8,
+                    143u8, 208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 184u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 143u8, 208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 184u8, 208u8,
+                    181u8, 208u8, 189u8, 209u8, 139u8, 209u8, 143u8, 208u8, 191u8, 208u8, 190u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                    208u8, 184u8, 208u8, 181u8, 208u8, 189u8, 209u8, 143u8, 208u8, 191u8, 208u8,
+                    190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 185u8,
+                    32u8, 208u8, 184u8, 208u8, 181u8, 208u8, 189u8, 209u8, 139u8, 209u8, 129u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 181u8, 209u8, 128u8, 208u8, 177u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    129u8, 208u8, 181u8, 209u8, 128u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 209u8, 129u8, 208u8,
+                    181u8, 209u8, 128u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                    129u8, 209u8, 129u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 185u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8,
+                    208u8, 187u8, 209u8, 140u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 209u8,
+                    129u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 133u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 208u8, 187u8,
+                    209u8, 143u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 209u8, 129u8, 208u8,
+                    184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8,
+                    32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 185u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 209u8, 129u8, 208u8,
+                    184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8,
+                    208u8, 190u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 208u8, 187u8,
+                    209u8, 143u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8,
+                    176u8, 209u8, 128u8, 32u8, 208u8, 161u8, 208u8, 168u8, 208u8, 144u8, 208u8,
+                    180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 178u8, 32u8, 208u8, 161u8, 208u8, 168u8, 208u8, 144u8,
+                    208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 176u8, 32u8, 208u8, 161u8, 208u8, 168u8, 208u8, 144u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 85u8, 82u8, 85u8, 83u8, 68u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 226u8, 130u8,
+                    172u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[85u8, 83u8, 68u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 82u8, 83u8, 68u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    20u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8,
+                    0u8, 78u8, 0u8, 0u8, 0u8, 101u8, 118u8, 114u8, 111u8, 101u8, 118u8, 114u8,
+                    97u8, 115u8, 114u8, 112u8, 115u8, 107u8, 105u8, 32u8, 100u8, 105u8, 110u8,
+                    97u8, 114u8, 115u8, 114u8, 112u8, 115u8, 107u8, 97u8, 32u8, 100u8, 105u8,
+                    110u8, 97u8, 114u8, 97u8, 115u8, 114u8, 112u8, 115u8, 107u8, 105u8, 104u8,
+                    32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 97u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+                    196u8, 141u8, 107u8, 105u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8,
+                    109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 97u8, 32u8, 100u8, 111u8,
+                    108u8, 97u8, 114u8, 97u8, 97u8, 109u8, 101u8, 114u8, 105u8, 196u8, 141u8,
+                    107u8, 105u8, 104u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 85u8, 82u8, 85u8, 83u8, 68u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 226u8, 130u8,
+                    172u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[85u8, 83u8, 68u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 82u8, 83u8, 68u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 39u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 118u8, 0u8,
+                    0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 208u8, 181u8, 208u8, 178u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    129u8, 209u8, 128u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 129u8, 209u8, 128u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 129u8, 209u8, 128u8, 208u8, 191u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 176u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8, 208u8,
+                    190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8,
+                    187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static TH: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 74u8, 80u8, 89u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 226u8, 130u8, 172u8, 194u8, 165u8, 224u8, 184u8, 191u8,
+                    85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[85u8, 83u8, 68u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 74u8, 80u8, 89u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8,
+                    0u8, 51u8, 0u8, 0u8, 0u8, 224u8, 184u8, 162u8, 224u8, 184u8, 185u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 141u8, 224u8, 184u8, 181u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 144u8,
+                ])
+            },
+        )
+    },
+};
+static TR: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    226u8, 130u8, 172u8, 226u8, 130u8, 186u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8,
+                    0u8, 69u8, 117u8, 114u8, 111u8, 84u8, 195u8, 188u8, 114u8, 107u8, 32u8, 108u8,
+                    105u8, 114u8, 97u8, 115u8, 196u8, 177u8, 65u8, 66u8, 68u8, 32u8, 100u8, 111u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8,
+                ])
+            },
+        )
+    },
+};
+static UND: DataStruct = &::icu_decimal::provider::CurrencyDisplayNamesV1 {
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 65u8, 68u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8,
+                    80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 67u8, 65u8, 36u8,
+                    226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 74u8, 80u8, 194u8,
+                    165u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 74u8, 80u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 224u8, 167u8, 179u8, 36u8, 194u8, 165u8, 36u8,
+                ])
+            },
+        )
+    },
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
@@ -0,0 +1,346 @@
+// @generated
+type DataStruct = & 'static < :: icu_decimal :: provider :: CurrencyPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG),
+    ("ar-EG", AR_AR_EG),
+    ("ar-EG-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
+    ("ar-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
+    ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+    ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+    ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+    ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+    ("en", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("en-001", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("en-ZA", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("es", ES_RU),
+    ("es-AR", ES_AR),
+    ("fil", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("fr", FR_SR_SR_CYRL_SR_LATN),
+    ("ja", JA),
+    ("ru", ES_RU),
+    ("sr", FR_SR_SR_CYRL_SR_LATN),
+    ("sr-Cyrl", FR_SR_SR_CYRL_SR_LATN),
+    ("sr-Latn", FR_SR_SR_CYRL_SR_LATN),
+    ("th", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("th-u-nu-native", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("th-u-nu-thai", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("th-u-nu-traditio", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("tr", EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE),
+    ("und", UND),
+];
+static AR_AR_EG: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
+static AR_U_NU_LATN_AR_EG_U_NU_LATN: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("\u{200e}-¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN: DataStruct =
+    &::icu_decimal::provider::CurrencyPatternsV1 {
+        standard: ::icu_decimal::provider::CurrencyPatternV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed(""),
+                suffix: ::alloc::borrow::Cow::Borrowed("¤"),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("-"),
+                suffix: ::alloc::borrow::Cow::Borrowed("¤"),
+            },
+        },
+        accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed(""),
+                suffix: ::alloc::borrow::Cow::Borrowed("¤"),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("("),
+                suffix: ::alloc::borrow::Cow::Borrowed("¤)"),
+            },
+        },
+        spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+        name_patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8,
+                        49u8, 125u8,
+                    ])
+                },
+            )
+        },
+    };
+static EN_EN_001_EN_ZA_FIL_TH_TH_U_NU_NATIVE: DataStruct =
+    &::icu_decimal::provider::CurrencyPatternsV1 {
+        standard: ::icu_decimal::provider::CurrencyPatternV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("¤"),
+                suffix: ::alloc::borrow::Cow::Borrowed(""),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("-¤"),
+                suffix: ::alloc::borrow::Cow::Borrowed(""),
+            },
+        },
+        accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("¤"),
+                suffix: ::alloc::borrow::Cow::Borrowed(""),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: ::alloc::borrow::Cow::Borrowed("(¤"),
+                suffix: ::alloc::borrow::Cow::Borrowed(")"),
+            },
+        },
+        spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+        name_patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8,
+                        49u8, 125u8,
+                    ])
+                },
+            )
+        },
+    };
+static ES_AR: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("(¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
+static ES_RU: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
+static FR_SR_SR_CYRL_SR_LATN: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("("),
+            suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}¤)"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: ::alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 123u8, 49u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static UND: DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::CurrencyPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    spacing: ::alloc::borrow::Cow::Borrowed("\u{a0}"),
+    name_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 123u8, 49u8,
+                    125u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,84 @@
+// @generated
+type DataStruct = & 'static < :: icu_decimal :: provider :: MiscPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_U_NU_LATN_AR_EG_AR_EG_U_NU_LATN),
+    ("ar-EG", AR_AR_U_NU_LATN_AR_EG_AR_EG_U_NU_LATN),
+    ("ar-EG-u-nu-latn", AR_AR_U_NU_LATN_AR_EG_AR_EG_U_NU_LATN),
+    ("ar-u-nu-latn", AR_AR_U_NU_LATN_AR_EG_AR_EG_U_NU_LATN),
+    ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en-001", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en-ZA", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("es", ES_ES_AR),
+    ("es-AR", ES_ES_AR),
+    ("fil", FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO),
+    ("fr", FR_RU),
+    ("ja", JA),
+    ("ru", FR_RU),
+    ("sr", SR_SR_CYRL_SR_LATN_UND),
+    ("sr-Cyrl", SR_SR_CYRL_SR_LATN_UND),
+    ("sr-Latn", SR_SR_CYRL_SR_LATN_UND),
+    ("th", FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO),
+    (
+        "th-u-nu-native",
+        FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO,
+    ),
+    (
+        "th-u-nu-thai",
+        FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO,
+    ),
+    (
+        "th-u-nu-traditio",
+        FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO,
+    ),
+    ("tr", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("und", SR_SR_CYRL_SR_LATN_UND),
+];
+static AR_AR_U_NU_LATN_AR_EG_AR_EG_U_NU_LATN: DataStruct =
+    &::icu_decimal::provider::MiscPatternsV1 {
+        approximately: ::alloc::borrow::Cow::Borrowed("~{0}"),
+        at_least: ::alloc::borrow::Cow::Borrowed("+{0}"),
+        at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+        range: ::alloc::borrow::Cow::Borrowed("{0}–{1}"),
+    };
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN: DataStruct =
+    &::icu_decimal::provider::MiscPatternsV1 {
+        approximately: ::alloc::borrow::Cow::Borrowed("~{0}"),
+        at_least: ::alloc::borrow::Cow::Borrowed("{0}+"),
+        at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+        range: ::alloc::borrow::Cow::Borrowed("{0}–{1}"),
+    };
+static ES_ES_AR: DataStruct = &::icu_decimal::provider::MiscPatternsV1 {
+    approximately: ::alloc::borrow::Cow::Borrowed("~{0}"),
+    at_least: ::alloc::borrow::Cow::Borrowed("Más de {0}"),
+    at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+    range: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+};
+static FIL_TH_TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO: DataStruct =
+    &::icu_decimal::provider::MiscPatternsV1 {
+        approximately: ::alloc::borrow::Cow::Borrowed("~{0}"),
+        at_least: ::alloc::borrow::Cow::Borrowed("{0}+"),
+        at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+        range: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    };
+static FR_RU: DataStruct = &::icu_decimal::provider::MiscPatternsV1 {
+    approximately: ::alloc::borrow::Cow::Borrowed("≈{0}"),
+    at_least: ::alloc::borrow::Cow::Borrowed("≥{0}"),
+    at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+    range: ::alloc::borrow::Cow::Borrowed("{0}–{1}"),
+};
+static JA: DataStruct = &::icu_decimal::provider::MiscPatternsV1 {
+    approximately: ::alloc::borrow::Cow::Borrowed("約 {0}"),
+    at_least: ::alloc::borrow::Cow::Borrowed("{0} 以上"),
+    at_most: ::alloc::borrow::Cow::Borrowed("{0} 以下"),
+    range: ::alloc::borrow::Cow::Borrowed("{0}～{1}"),
+};
+static SR_SR_CYRL_SR_LATN_UND: DataStruct = &::icu_decimal::provider::MiscPatternsV1 {
+    approximately: ::alloc::borrow::Cow::Borrowed("~{0}"),
+    at_least: ::alloc::borrow::Cow::Borrowed("≥{0}"),
+    at_most: ::alloc::borrow::Cow::Borrowed("≤{0}"),
+    range: ::alloc::borrow::Cow::Borrowed("{0}–{1}"),
+};
@@ -1,2 +1,10 @@
 // @generated
+pub mod compact_long_v1;
+pub mod compact_short_v1;
+pub mod currency_fractions_v1;
+pub mod currency_names_v1;
+pub mod currency_patterns_v1;
+pub mod misc_patterns_v1;
+pub mod percent_v1;
+pub mod scientific_v1;
 pub mod symbols_v1;
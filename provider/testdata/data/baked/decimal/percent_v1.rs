@@ -0,0 +1,91 @@
+// @generated
+type DataStruct = & 'static < :: icu_decimal :: provider :: PercentPatternV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG),
+    ("ar-EG", AR_AR_EG),
+    ("ar-EG-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
+    ("ar-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
+    ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en-001", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("en-ZA", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("es", ES_ES_AR_FR_RU),
+    ("es-AR", ES_ES_AR_FR_RU),
+    ("fil", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("fr", ES_ES_AR_FR_RU),
+    ("ja", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("ru", ES_ES_AR_FR_RU),
+    ("sr", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("sr-Cyrl", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("sr-Latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("th", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("th-u-nu-native", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("th-u-nu-thai", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("th-u-nu-traditio", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ("tr", TR),
+    ("und", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+];
+static AR_AR_EG: DataStruct = &::icu_decimal::provider::PercentPatternV1 {
+    positive: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed(""),
+        suffix: ::alloc::borrow::Cow::Borrowed("%"),
+    },
+    negative: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("\u{61c}-"),
+        suffix: ::alloc::borrow::Cow::Borrowed("%"),
+    },
+    percent_sign: ::alloc::borrow::Cow::Borrowed("٪\u{61c}"),
+    permille_sign: ::alloc::borrow::Cow::Borrowed("؉"),
+};
+static AR_U_NU_LATN_AR_EG_U_NU_LATN: DataStruct = &::icu_decimal::provider::PercentPatternV1 {
+    positive: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed(""),
+        suffix: ::alloc::borrow::Cow::Borrowed("%"),
+    },
+    negative: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("\u{200e}-"),
+        suffix: ::alloc::borrow::Cow::Borrowed("%"),
+    },
+    percent_sign: ::alloc::borrow::Cow::Borrowed("\u{200e}%\u{200e}"),
+    permille_sign: ::alloc::borrow::Cow::Borrowed("‰"),
+};
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN: DataStruct =
+    &::icu_decimal::provider::PercentPatternV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed(""),
+            suffix: ::alloc::borrow::Cow::Borrowed("%"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-"),
+            suffix: ::alloc::borrow::Cow::Borrowed("%"),
+        },
+        percent_sign: ::alloc::borrow::Cow::Borrowed("%"),
+        permille_sign: ::alloc::borrow::Cow::Borrowed("‰"),
+    };
+static ES_ES_AR_FR_RU: DataStruct = &::icu_decimal::provider::PercentPatternV1 {
+    positive: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed(""),
+        suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}%"),
+    },
+    negative: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("-"),
+        suffix: ::alloc::borrow::Cow::Borrowed("\u{a0}%"),
+    },
+    percent_sign: ::alloc::borrow::Cow::Borrowed("%"),
+    permille_sign: ::alloc::borrow::Cow::Borrowed("‰"),
+};
+static TR: DataStruct = &::icu_decimal::provider::PercentPatternV1 {
+    positive: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("%"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    negative: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("-%"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    percent_sign: ::alloc::borrow::Cow::Borrowed("%"),
+    permille_sign: ::alloc::borrow::Cow::Borrowed("‰"),
+};
@@ -0,0 +1,53 @@
+// @generated
+type DataStruct = & 'static < :: icu_decimal :: provider :: ScientificSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG),
+    ("ar-EG", AR_AR_EG),
+    (
+        "ar-EG-u-nu-latn",
+        AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN,
+    ),
+    ("ar-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("bn", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("bn-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("ccp", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    (
+        "ccp-u-nu-latn",
+        AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN,
+    ),
+    ("en", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("en-001", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("en-ZA", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("es", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("es-AR", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("fil", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("fr", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("ja", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("ru", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("sr", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("sr-Cyrl", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("sr-Latn", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("th", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    (
+        "th-u-nu-native",
+        AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN,
+    ),
+    ("th-u-nu-thai", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    (
+        "th-u-nu-traditio",
+        AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN,
+    ),
+    ("tr", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+    ("und", AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN),
+];
+static AR_AR_EG: DataStruct = &::icu_decimal::provider::ScientificSymbolsV1 {
+    exponential_symbol: ::alloc::borrow::Cow::Borrowed("اس"),
+    superscripting_exponent: ::alloc::borrow::Cow::Borrowed("×"),
+    min_exponent_digits: 1u8,
+};
+static AR_U_NU_LATN_AR_EG_U_NU_LATN_BN_BN_U_NU_LATN: DataStruct =
+    &::icu_decimal::provider::ScientificSymbolsV1 {
+        exponential_symbol: ::alloc::borrow::Cow::Borrowed("E"),
+        superscripting_exponent: ::alloc::borrow::Cow::Borrowed("×"),
+        min_exponent_digits: 1u8,
+    };
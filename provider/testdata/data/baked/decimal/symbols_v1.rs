@@ -3,8 +3,12 @@ type DataStruct = & 'static < :: icu_decimal :: provider :: DecimalSymbolsV1Mark
 pub static DATA: &[(&str, DataStruct)] = &[
     ("ar", AR_AR_EG),
     ("ar-EG", AR_AR_EG),
+    ("ar-EG-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
+    ("ar-u-nu-latn", AR_U_NU_LATN_AR_EG_U_NU_LATN),
     ("bn", BN),
+    ("bn-u-nu-latn", BN_U_NU_LATN_CCP_U_NU_LATN),
     ("ccp", CCP),
+    ("ccp-u-nu-latn", BN_U_NU_LATN_CCP_U_NU_LATN),
     ("en", EN_EN_001_FIL_JA_TH_UND),
     ("en-001", EN_EN_001_FIL_JA_TH_UND),
     ("en-ZA", EN_ZA_RU),
@@ -18,6 +22,15 @@ pub static DATA: &[(&str, DataStruct)] = &[
     ("sr-Cyrl", ES_AR_SR_SR_CYRL_SR_LATN_TR),
     ("sr-Latn", ES_AR_SR_SR_CYRL_SR_LATN_TR),
     ("th", EN_EN_001_FIL_JA_TH_UND),
+    (
+        "th-u-nu-native",
+        TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO,
+    ),
+    ("th-u-nu-thai", TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO),
+    (
+        "th-u-nu-traditio",
+        TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO,
+    ),
     ("tr", ES_AR_SR_SR_CYRL_SR_LATN_TR),
     ("und", EN_EN_001_FIL_JA_TH_UND),
 ];
@@ -39,6 +52,24 @@ static AR_AR_EG: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
     },
     digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
 };
+static AR_U_NU_LATN_AR_EG_U_NU_LATN: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
+    minus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("\u{200e}-"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    plus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("\u{200e}+"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    decimal_separator: ::alloc::borrow::Cow::Borrowed("."),
+    grouping_separator: ::alloc::borrow::Cow::Borrowed(","),
+    grouping_sizes: ::icu_decimal::provider::GroupingSizesV1 {
+        primary: 3u8,
+        secondary: 3u8,
+        min_grouping: 1u8,
+    },
+    digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+};
 static BN: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
     minus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
         prefix: ::alloc::borrow::Cow::Borrowed("-"),
@@ -57,6 +88,24 @@ static BN: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
     },
     digits: ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'],
 };
+static BN_U_NU_LATN_CCP_U_NU_LATN: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
+    minus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("-"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    plus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: ::alloc::borrow::Cow::Borrowed("+"),
+        suffix: ::alloc::borrow::Cow::Borrowed(""),
+    },
+    decimal_separator: ::alloc::borrow::Cow::Borrowed("."),
+    grouping_separator: ::alloc::borrow::Cow::Borrowed(","),
+    grouping_sizes: ::icu_decimal::provider::GroupingSizesV1 {
+        primary: 3u8,
+        secondary: 2u8,
+        min_grouping: 1u8,
+    },
+    digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+};
 static CCP: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
     minus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
         prefix: ::alloc::borrow::Cow::Borrowed("-"),
@@ -165,3 +214,22 @@ static FR: DataStruct = &::icu_decimal::provider::DecimalSymbolsV1 {
     },
     digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
 };
+static TH_U_NU_NATIVE_TH_U_NU_THAI_TH_U_NU_TRADITIO: DataStruct =
+    &::icu_decimal::provider::DecimalSymbolsV1 {
+        minus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("-"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        plus_sign_affixes: ::icu_decimal::provider::AffixesV1 {
+            prefix: ::alloc::borrow::Cow::Borrowed("+"),
+            suffix: ::alloc::borrow::Cow::Borrowed(""),
+        },
+        decimal_separator: ::alloc::borrow::Cow::Borrowed("."),
+        grouping_separator: ::alloc::borrow::Cow::Borrowed(","),
+        grouping_sizes: ::icu_decimal::provider::GroupingSizesV1 {
+            primary: 3u8,
+            secondary: 3u8,
+            min_grouping: 1u8,
+        },
+        digits: ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'],
+    };
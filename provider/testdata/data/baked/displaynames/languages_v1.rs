@@ -0,0 +1,404 @@
+// @generated
+type DataStruct = & 'static < :: icu_displaynames :: provider :: LanguageDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_displaynames::provider::LanguageDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    63u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                    0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 26u8,
+                    0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8,
+                    40u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8,
+                    0u8, 57u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 70u8, 0u8,
+                    0u8, 0u8, 75u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 87u8,
+                    0u8, 0u8, 0u8, 89u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 94u8, 0u8, 0u8, 0u8,
+                    99u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 109u8, 0u8,
+                    0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+                    117u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 123u8, 0u8,
+                    0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8,
+                    134u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 146u8, 0u8,
+                    0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 153u8, 0u8, 0u8, 0u8, 155u8, 0u8, 0u8, 0u8,
+                    160u8, 0u8, 0u8, 0u8, 162u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8, 169u8, 0u8,
+                    0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8, 176u8, 0u8, 0u8, 0u8,
+                    178u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 186u8, 0u8,
+                    0u8, 0u8, 188u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 97u8, 102u8, 97u8, 109u8,
+                    97u8, 114u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 97u8, 122u8, 98u8, 110u8,
+                    99u8, 99u8, 112u8, 99u8, 107u8, 98u8, 99u8, 115u8, 100u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 45u8, 65u8, 84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 108u8,
+                    101u8, 110u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8, 65u8,
+                    101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8, 115u8,
+                    101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8, 83u8, 101u8,
+                    115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    105u8, 102u8, 105u8, 108u8, 102u8, 114u8, 102u8, 114u8, 45u8, 67u8, 65u8,
+                    102u8, 114u8, 45u8, 67u8, 72u8, 104u8, 97u8, 119u8, 104u8, 101u8, 104u8, 105u8,
+                    104u8, 117u8, 105u8, 100u8, 105u8, 116u8, 106u8, 97u8, 107u8, 111u8, 110u8,
+                    98u8, 110u8, 108u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 108u8, 112u8, 116u8,
+                    112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8,
+                    114u8, 111u8, 45u8, 77u8, 68u8, 114u8, 117u8, 115u8, 114u8, 115u8, 114u8, 45u8,
+                    77u8, 69u8, 115u8, 118u8, 115u8, 119u8, 115u8, 119u8, 45u8, 67u8, 68u8, 116u8,
+                    104u8, 116u8, 108u8, 104u8, 116u8, 114u8, 117u8, 107u8, 117u8, 110u8, 100u8,
+                    118u8, 105u8, 121u8, 117u8, 101u8, 122u8, 104u8, 122u8, 104u8, 45u8, 72u8,
+                    97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    63u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 22u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 61u8, 0u8,
+                    0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 93u8,
+                    0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8,
+                    136u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 161u8, 0u8, 0u8, 0u8, 177u8, 0u8,
+                    0u8, 0u8, 192u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 215u8, 0u8, 0u8, 0u8,
+                    237u8, 0u8, 0u8, 0u8, 253u8, 0u8, 0u8, 0u8, 12u8, 1u8, 0u8, 0u8, 19u8, 1u8,
+                    0u8, 0u8, 23u8, 1u8, 0u8, 0u8, 30u8, 1u8, 0u8, 0u8, 38u8, 1u8, 0u8, 0u8, 44u8,
+                    1u8, 0u8, 0u8, 59u8, 1u8, 0u8, 0u8, 71u8, 1u8, 0u8, 0u8, 79u8, 1u8, 0u8, 0u8,
+                    85u8, 1u8, 0u8, 0u8, 90u8, 1u8, 0u8, 0u8, 99u8, 1u8, 0u8, 0u8, 109u8, 1u8, 0u8,
+                    0u8, 116u8, 1u8, 0u8, 0u8, 124u8, 1u8, 0u8, 0u8, 130u8, 1u8, 0u8, 0u8, 147u8,
+                    1u8, 0u8, 0u8, 152u8, 1u8, 0u8, 0u8, 159u8, 1u8, 0u8, 0u8, 165u8, 1u8, 0u8,
+                    0u8, 175u8, 1u8, 0u8, 0u8, 195u8, 1u8, 0u8, 0u8, 214u8, 1u8, 0u8, 0u8, 222u8,
+                    1u8, 0u8, 0u8, 231u8, 1u8, 0u8, 0u8, 238u8, 1u8, 0u8, 0u8, 245u8, 1u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 7u8, 2u8, 0u8, 0u8, 14u8, 2u8, 0u8, 0u8, 27u8, 2u8,
+                    0u8, 0u8, 31u8, 2u8, 0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 45u8, 2u8, 0u8, 0u8, 54u8,
+                    2u8, 0u8, 0u8, 70u8, 2u8, 0u8, 0u8, 80u8, 2u8, 0u8, 0u8, 89u8, 2u8, 0u8, 0u8,
+                    96u8, 2u8, 0u8, 0u8, 114u8, 2u8, 0u8, 0u8, 65u8, 102u8, 114u8, 105u8, 107u8,
+                    97u8, 97u8, 110u8, 115u8, 65u8, 109u8, 104u8, 97u8, 114u8, 105u8, 99u8, 65u8,
+                    114u8, 97u8, 98u8, 105u8, 99u8, 77u8, 111u8, 100u8, 101u8, 114u8, 110u8, 32u8,
+                    83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 65u8, 114u8, 97u8,
+                    98u8, 105u8, 99u8, 65u8, 122u8, 101u8, 114u8, 98u8, 97u8, 105u8, 106u8, 97u8,
+                    110u8, 105u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 67u8, 104u8, 97u8, 107u8,
+                    109u8, 97u8, 67u8, 101u8, 110u8, 116u8, 114u8, 97u8, 108u8, 32u8, 75u8, 117u8,
+                    114u8, 100u8, 105u8, 115u8, 104u8, 67u8, 122u8, 101u8, 99u8, 104u8, 68u8, 97u8,
+                    110u8, 105u8, 115u8, 104u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 65u8,
+                    117u8, 115u8, 116u8, 114u8, 105u8, 97u8, 110u8, 32u8, 71u8, 101u8, 114u8,
+                    109u8, 97u8, 110u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 72u8, 105u8, 103u8,
+                    104u8, 32u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 71u8, 114u8, 101u8, 101u8,
+                    107u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 65u8, 117u8, 115u8,
+                    116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8,
+                    105u8, 115u8, 104u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                    69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 66u8, 114u8, 105u8, 116u8,
+                    105u8, 115u8, 104u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 69u8, 110u8, 103u8,
+                    108u8, 105u8, 115u8, 104u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    76u8, 97u8, 116u8, 105u8, 110u8, 32u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8, 69u8, 117u8,
+                    114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8, 105u8,
+                    115u8, 104u8, 77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 32u8, 83u8, 112u8,
+                    97u8, 110u8, 105u8, 115u8, 104u8, 80u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 68u8, 97u8, 114u8, 105u8, 70u8, 105u8, 110u8, 110u8, 105u8, 115u8,
+                    104u8, 70u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 111u8, 70u8, 114u8,
+                    101u8, 110u8, 99u8, 104u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8,
+                    32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 83u8, 119u8, 105u8, 115u8, 115u8,
+                    32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 72u8, 97u8, 119u8, 97u8, 105u8,
+                    105u8, 97u8, 110u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 72u8, 105u8, 110u8,
+                    100u8, 105u8, 72u8, 117u8, 110u8, 103u8, 97u8, 114u8, 105u8, 97u8, 110u8, 73u8,
+                    110u8, 100u8, 111u8, 110u8, 101u8, 115u8, 105u8, 97u8, 110u8, 73u8, 116u8,
+                    97u8, 108u8, 105u8, 97u8, 110u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 75u8, 111u8, 114u8, 101u8, 97u8, 110u8, 78u8, 111u8, 114u8, 119u8,
+                    101u8, 103u8, 105u8, 97u8, 110u8, 32u8, 66u8, 111u8, 107u8, 109u8, 195u8,
+                    165u8, 108u8, 68u8, 117u8, 116u8, 99u8, 104u8, 70u8, 108u8, 101u8, 109u8,
+                    105u8, 115u8, 104u8, 80u8, 111u8, 108u8, 105u8, 115u8, 104u8, 80u8, 111u8,
+                    114u8, 116u8, 117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 66u8, 114u8, 97u8,
+                    122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 80u8, 111u8, 114u8, 116u8,
+                    117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 97u8, 110u8, 32u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8, 117u8,
+                    101u8, 115u8, 101u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 97u8, 110u8, 77u8,
+                    111u8, 108u8, 100u8, 97u8, 118u8, 105u8, 97u8, 110u8, 82u8, 117u8, 115u8,
+                    115u8, 105u8, 97u8, 110u8, 83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 77u8,
+                    111u8, 110u8, 116u8, 101u8, 110u8, 101u8, 103u8, 114u8, 105u8, 110u8, 83u8,
+                    119u8, 101u8, 100u8, 105u8, 115u8, 104u8, 83u8, 119u8, 97u8, 104u8, 105u8,
+                    108u8, 105u8, 67u8, 111u8, 110u8, 103u8, 111u8, 32u8, 83u8, 119u8, 97u8, 104u8,
+                    105u8, 108u8, 105u8, 84u8, 104u8, 97u8, 105u8, 75u8, 108u8, 105u8, 110u8,
+                    103u8, 111u8, 110u8, 84u8, 117u8, 114u8, 107u8, 105u8, 115u8, 104u8, 85u8,
+                    107u8, 114u8, 97u8, 105u8, 110u8, 105u8, 97u8, 110u8, 85u8, 110u8, 107u8,
+                    110u8, 111u8, 119u8, 110u8, 32u8, 108u8, 97u8, 110u8, 103u8, 117u8, 97u8,
+                    103u8, 101u8, 86u8, 105u8, 101u8, 116u8, 110u8, 97u8, 109u8, 101u8, 115u8,
+                    101u8, 67u8, 97u8, 110u8, 116u8, 111u8, 110u8, 101u8, 115u8, 101u8, 67u8,
+                    104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 83u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8,
+                    97u8, 108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    97u8, 122u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 65u8, 122u8, 101u8, 114u8, 105u8, 85u8, 75u8, 32u8, 69u8, 110u8, 103u8,
+                    108u8, 105u8, 115u8, 104u8, 85u8, 83u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8,
+                    115u8, 104u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    122u8, 104u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8,
+                    72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8,
+                    0u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 83u8, 105u8, 109u8, 112u8, 108u8, 105u8,
+                    102u8, 105u8, 101u8, 100u8, 32u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8,
+                    110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 84u8, 114u8, 97u8,
+                    100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 77u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8,
+                ])
+            },
+        )
+    },
+    menu_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    99u8, 107u8, 98u8, 121u8, 117u8, 101u8, 122u8, 104u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8,
+                    0u8, 75u8, 117u8, 114u8, 100u8, 105u8, 115u8, 104u8, 44u8, 32u8, 67u8, 101u8,
+                    110u8, 116u8, 114u8, 97u8, 108u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 44u8, 32u8, 67u8, 97u8, 110u8, 116u8, 111u8, 110u8, 101u8, 115u8, 101u8,
+                    67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 44u8, 32u8, 77u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_displaynames::provider::LanguageDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 22u8, 0u8,
+                    0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 42u8,
+                    0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8,
+                    60u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 72u8, 0u8, 0u8,
+                    0u8, 74u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 80u8, 0u8,
+                    0u8, 0u8, 85u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 97u8,
+                    0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8,
+                    107u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 97u8, 114u8,
+                    97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 101u8, 110u8, 45u8, 65u8,
+                    85u8, 101u8, 110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8,
+                    101u8, 115u8, 45u8, 69u8, 83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 114u8,
+                    102u8, 114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 105u8, 116u8,
+                    106u8, 97u8, 107u8, 111u8, 110u8, 108u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8,
+                    116u8, 112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8,
+                    117u8, 115u8, 114u8, 116u8, 108u8, 104u8, 117u8, 110u8, 100u8, 122u8, 104u8,
+                    122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8,
+                    110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8,
+                    0u8, 35u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 76u8, 0u8,
+                    0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8,
+                    147u8, 0u8, 0u8, 0u8, 155u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 204u8, 0u8,
+                    0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 250u8, 0u8, 0u8, 0u8,
+                    10u8, 1u8, 0u8, 0u8, 17u8, 1u8, 0u8, 0u8, 25u8, 1u8, 0u8, 0u8, 32u8, 1u8, 0u8,
+                    0u8, 44u8, 1u8, 0u8, 0u8, 51u8, 1u8, 0u8, 0u8, 60u8, 1u8, 0u8, 0u8, 80u8, 1u8,
+                    0u8, 0u8, 99u8, 1u8, 0u8, 0u8, 104u8, 1u8, 0u8, 0u8, 109u8, 1u8, 0u8, 0u8,
+                    116u8, 1u8, 0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 162u8, 1u8,
+                    0u8, 0u8, 97u8, 114u8, 97u8, 98u8, 101u8, 97u8, 114u8, 97u8, 98u8, 101u8, 32u8,
+                    115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 109u8, 111u8,
+                    100u8, 101u8, 114u8, 110u8, 101u8, 97u8, 108u8, 108u8, 101u8, 109u8, 97u8,
+                    110u8, 100u8, 97u8, 108u8, 108u8, 101u8, 109u8, 97u8, 110u8, 100u8, 32u8, 97u8,
+                    117u8, 116u8, 114u8, 105u8, 99u8, 104u8, 105u8, 101u8, 110u8, 97u8, 108u8,
+                    108u8, 101u8, 109u8, 97u8, 110u8, 100u8, 32u8, 115u8, 117u8, 105u8, 115u8,
+                    115u8, 101u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8, 97u8, 110u8,
+                    103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8,
+                    108u8, 105u8, 101u8, 110u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8,
+                    32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 97u8, 110u8, 103u8,
+                    108u8, 97u8, 105u8, 115u8, 32u8, 98u8, 114u8, 105u8, 116u8, 97u8, 110u8, 110u8,
+                    105u8, 113u8, 117u8, 101u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8,
+                    32u8, 97u8, 109u8, 195u8, 169u8, 114u8, 105u8, 99u8, 97u8, 105u8, 110u8, 101u8,
+                    115u8, 112u8, 97u8, 103u8, 110u8, 111u8, 108u8, 101u8, 115u8, 112u8, 97u8,
+                    103u8, 110u8, 111u8, 108u8, 32u8, 100u8, 226u8, 128u8, 153u8, 65u8, 109u8,
+                    195u8, 169u8, 114u8, 105u8, 113u8, 117u8, 101u8, 32u8, 108u8, 97u8, 116u8,
+                    105u8, 110u8, 101u8, 101u8, 115u8, 112u8, 97u8, 103u8, 110u8, 111u8, 108u8,
+                    32u8, 100u8, 226u8, 128u8, 153u8, 69u8, 115u8, 112u8, 97u8, 103u8, 110u8,
+                    101u8, 101u8, 115u8, 112u8, 97u8, 103u8, 110u8, 111u8, 108u8, 32u8, 100u8,
+                    117u8, 32u8, 77u8, 101u8, 120u8, 105u8, 113u8, 117u8, 101u8, 102u8, 114u8,
+                    97u8, 110u8, 195u8, 167u8, 97u8, 105u8, 115u8, 102u8, 114u8, 97u8, 110u8,
+                    195u8, 167u8, 97u8, 105u8, 115u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8,
+                    101u8, 110u8, 102u8, 114u8, 97u8, 110u8, 195u8, 167u8, 97u8, 105u8, 115u8,
+                    32u8, 115u8, 117u8, 105u8, 115u8, 115u8, 101u8, 105u8, 116u8, 97u8, 108u8,
+                    105u8, 101u8, 110u8, 106u8, 97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8,
+                    99u8, 111u8, 114u8, 195u8, 169u8, 101u8, 110u8, 110u8, 195u8, 169u8, 101u8,
+                    114u8, 108u8, 97u8, 110u8, 100u8, 97u8, 105u8, 115u8, 102u8, 108u8, 97u8,
+                    109u8, 97u8, 110u8, 100u8, 112u8, 111u8, 114u8, 116u8, 117u8, 103u8, 97u8,
+                    105u8, 115u8, 112u8, 111u8, 114u8, 116u8, 117u8, 103u8, 97u8, 105u8, 115u8,
+                    32u8, 98u8, 114u8, 195u8, 169u8, 115u8, 105u8, 108u8, 105u8, 101u8, 110u8,
+                    112u8, 111u8, 114u8, 116u8, 117u8, 103u8, 97u8, 105u8, 115u8, 32u8, 101u8,
+                    117u8, 114u8, 111u8, 112u8, 195u8, 169u8, 101u8, 110u8, 114u8, 117u8, 115u8,
+                    115u8, 101u8, 115u8, 101u8, 114u8, 98u8, 101u8, 107u8, 108u8, 105u8, 110u8,
+                    103u8, 111u8, 110u8, 108u8, 97u8, 110u8, 103u8, 117u8, 101u8, 32u8, 105u8,
+                    110u8, 100u8, 195u8, 169u8, 116u8, 101u8, 114u8, 109u8, 105u8, 110u8, 195u8,
+                    169u8, 101u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 99u8, 104u8,
+                    105u8, 110u8, 111u8, 105u8, 115u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 195u8, 169u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8,
+                    115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8,
+                    110u8, 101u8, 108u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 101u8, 110u8, 45u8,
+                    71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 97u8, 110u8,
+                    103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 40u8, 82u8, 46u8, 45u8, 85u8, 46u8,
+                    41u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 40u8, 195u8, 137u8,
+                    46u8, 45u8, 85u8, 46u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    menu_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static JA: DataStruct = &::icu_displaynames::provider::LanguageDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                    0u8, 9u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+                    0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 38u8,
+                    0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8,
+                    46u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8,
+                    0u8, 55u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 97u8, 114u8, 100u8, 101u8,
+                    100u8, 101u8, 45u8, 65u8, 84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8,
+                    101u8, 110u8, 45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8, 65u8, 101u8, 110u8,
+                    45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 102u8, 114u8,
+                    105u8, 116u8, 106u8, 97u8, 107u8, 111u8, 112u8, 116u8, 114u8, 117u8, 117u8,
+                    110u8, 100u8, 122u8, 104u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8,
+                    122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8,
+                    0u8, 60u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 120u8, 0u8,
+                    0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 153u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8,
+                    186u8, 0u8, 0u8, 0u8, 201u8, 0u8, 0u8, 0u8, 216u8, 0u8, 0u8, 0u8, 225u8, 0u8,
+                    0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 252u8, 0u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 20u8,
+                    1u8, 0u8, 0u8, 29u8, 1u8, 0u8, 0u8, 44u8, 1u8, 0u8, 0u8, 227u8, 130u8, 162u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 232u8, 170u8,
+                    158u8, 227u8, 131u8, 137u8, 227u8, 130u8, 164u8, 227u8, 131u8, 132u8, 232u8,
+                    170u8, 158u8, 227u8, 131u8, 137u8, 227u8, 130u8, 164u8, 227u8, 131u8, 132u8,
+                    232u8, 170u8, 158u8, 32u8, 40u8, 227u8, 130u8, 170u8, 227u8, 131u8, 188u8,
+                    227u8, 130u8, 185u8, 227u8, 131u8, 136u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    162u8, 41u8, 227u8, 130u8, 185u8, 227u8, 130u8, 164u8, 227u8, 130u8, 185u8,
+                    233u8, 171u8, 152u8, 229u8, 156u8, 176u8, 227u8, 131u8, 137u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 132u8, 232u8, 170u8, 158u8, 232u8, 139u8, 177u8, 232u8,
+                    170u8, 158u8, 227u8, 130u8, 170u8, 227u8, 131u8, 188u8, 227u8, 130u8, 185u8,
+                    227u8, 131u8, 136u8, 227u8, 131u8, 169u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    162u8, 232u8, 139u8, 177u8, 232u8, 170u8, 158u8, 227u8, 130u8, 171u8, 227u8,
+                    131u8, 138u8, 227u8, 131u8, 128u8, 232u8, 139u8, 177u8, 232u8, 170u8, 158u8,
+                    227u8, 130u8, 164u8, 227u8, 130u8, 174u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    185u8, 232u8, 139u8, 177u8, 232u8, 170u8, 158u8, 227u8, 130u8, 162u8, 227u8,
+                    131u8, 161u8, 227u8, 131u8, 170u8, 227u8, 130u8, 171u8, 232u8, 139u8, 177u8,
+                    232u8, 170u8, 158u8, 227u8, 130u8, 185u8, 227u8, 131u8, 154u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 179u8, 232u8, 170u8, 158u8, 227u8, 131u8, 149u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8, 185u8, 232u8, 170u8, 158u8,
+                    227u8, 130u8, 164u8, 227u8, 130u8, 191u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    162u8, 232u8, 170u8, 158u8, 230u8, 151u8, 165u8, 230u8, 156u8, 172u8, 232u8,
+                    170u8, 158u8, 233u8, 159u8, 147u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8,
+                    227u8, 131u8, 157u8, 227u8, 131u8, 171u8, 227u8, 131u8, 136u8, 227u8, 130u8,
+                    172u8, 227u8, 131u8, 171u8, 232u8, 170u8, 158u8, 227u8, 131u8, 173u8, 227u8,
+                    130u8, 183u8, 227u8, 130u8, 162u8, 232u8, 170u8, 158u8, 232u8, 168u8, 128u8,
+                    232u8, 170u8, 158u8, 228u8, 184u8, 141u8, 230u8, 152u8, 142u8, 228u8, 184u8,
+                    173u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8, 231u8, 176u8, 161u8, 228u8,
+                    189u8, 147u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8,
+                    231u8, 185u8, 129u8, 228u8, 189u8, 147u8, 228u8, 184u8, 173u8, 229u8, 155u8,
+                    189u8, 232u8, 170u8, 158u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 101u8, 110u8, 45u8,
+                    71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 232u8, 139u8,
+                    177u8, 232u8, 170u8, 158u8, 32u8, 40u8, 227u8, 130u8, 164u8, 227u8, 130u8,
+                    174u8, 227u8, 131u8, 170u8, 227u8, 130u8, 185u8, 41u8, 232u8, 139u8, 177u8,
+                    232u8, 170u8, 158u8, 32u8, 40u8, 227u8, 130u8, 162u8, 227u8, 131u8, 161u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 171u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    menu_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
@@ -0,0 +1,270 @@
+// @generated
+type DataStruct = & 'static < :: icu_displaynames :: provider :: LocaleDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    pattern: ::alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: ::alloc::borrow::Cow::Borrowed("{0}, {1}"),
+    key_type_pattern: ::alloc::borrow::Cow::Borrowed("{0}: {1}"),
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 99u8, 97u8, 99u8, 111u8, 104u8, 99u8,
+                    109u8, 115u8, 110u8, 117u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 39u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8,
+                    114u8, 72u8, 111u8, 117u8, 114u8, 32u8, 67u8, 121u8, 99u8, 108u8, 101u8, 32u8,
+                    40u8, 49u8, 50u8, 32u8, 118u8, 115u8, 32u8, 50u8, 52u8, 41u8, 77u8, 101u8,
+                    97u8, 115u8, 117u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8, 83u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 78u8, 117u8, 109u8, 98u8, 101u8, 114u8,
+                    115u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    38u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 30u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 59u8, 0u8,
+                    0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 97u8,
+                    0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+                    0u8, 134u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 159u8,
+                    0u8, 0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8, 187u8, 0u8, 0u8,
+                    0u8, 198u8, 0u8, 0u8, 0u8, 207u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 220u8,
+                    0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 238u8, 0u8, 0u8,
+                    0u8, 247u8, 0u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8, 13u8, 1u8, 0u8, 0u8, 20u8, 1u8,
+                    0u8, 0u8, 27u8, 1u8, 0u8, 0u8, 34u8, 1u8, 0u8, 0u8, 41u8, 1u8, 0u8, 0u8, 52u8,
+                    1u8, 0u8, 0u8, 62u8, 1u8, 0u8, 0u8, 69u8, 1u8, 0u8, 0u8, 99u8, 97u8, 45u8,
+                    98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8,
+                    104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8,
+                    116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8,
+                    97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8,
+                    114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8,
+                    114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8,
+                    111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8,
+                    100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8, 101u8, 116u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8,
+                    45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 115u8,
+                    101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 104u8, 99u8, 45u8,
+                    104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8,
+                    104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 109u8, 115u8, 45u8,
+                    109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8,
+                    115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8,
+                    115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8,
+                    97u8, 98u8, 110u8, 117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8,
+                    99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8,
+                    117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 101u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 97u8, 116u8, 110u8, 110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    38u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8,
+                    0u8, 48u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 107u8, 0u8,
+                    0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8,
+                    178u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 212u8, 0u8, 0u8, 0u8, 228u8, 0u8,
+                    0u8, 0u8, 243u8, 0u8, 0u8, 0u8, 24u8, 1u8, 0u8, 0u8, 45u8, 1u8, 0u8, 0u8, 71u8,
+                    1u8, 0u8, 0u8, 91u8, 1u8, 0u8, 0u8, 108u8, 1u8, 0u8, 0u8, 130u8, 1u8, 0u8, 0u8,
+                    149u8, 1u8, 0u8, 0u8, 166u8, 1u8, 0u8, 0u8, 188u8, 1u8, 0u8, 0u8, 211u8, 1u8,
+                    0u8, 0u8, 234u8, 1u8, 0u8, 0u8, 1u8, 2u8, 0u8, 0u8, 24u8, 2u8, 0u8, 0u8, 37u8,
+                    2u8, 0u8, 0u8, 64u8, 2u8, 0u8, 0u8, 85u8, 2u8, 0u8, 0u8, 104u8, 2u8, 0u8, 0u8,
+                    117u8, 2u8, 0u8, 0u8, 130u8, 2u8, 0u8, 0u8, 147u8, 2u8, 0u8, 0u8, 164u8, 2u8,
+                    0u8, 0u8, 188u8, 2u8, 0u8, 0u8, 202u8, 2u8, 0u8, 0u8, 66u8, 117u8, 100u8,
+                    100u8, 104u8, 105u8, 115u8, 116u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 111u8, 112u8, 116u8,
+                    105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 68u8,
+                    97u8, 110u8, 103u8, 105u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 65u8, 109u8,
+                    101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 32u8, 67u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+                    105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 71u8,
+                    114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 32u8, 67u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 32u8,
+                    67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 32u8, 78u8, 97u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8,
+                    67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 72u8, 105u8, 106u8, 114u8,
+                    105u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 83u8,
+                    79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8,
+                    67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 80u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    77u8, 105u8, 110u8, 103u8, 117u8, 111u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 32u8, 45u8,
+                    32u8, 66u8, 105u8, 103u8, 53u8, 68u8, 105u8, 99u8, 116u8, 105u8, 111u8, 110u8,
+                    97u8, 114u8, 121u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8,
+                    101u8, 114u8, 68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 85u8, 110u8,
+                    105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 80u8, 104u8, 111u8, 110u8, 101u8, 98u8, 111u8,
+                    111u8, 107u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8,
+                    114u8, 80u8, 105u8, 110u8, 121u8, 105u8, 110u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 71u8, 101u8, 110u8, 101u8,
+                    114u8, 97u8, 108u8, 45u8, 80u8, 117u8, 114u8, 112u8, 111u8, 115u8, 101u8, 32u8,
+                    83u8, 101u8, 97u8, 114u8, 99u8, 104u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8,
+                    114u8, 100u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8,
+                    114u8, 83u8, 116u8, 114u8, 111u8, 107u8, 101u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 84u8, 114u8, 97u8, 100u8, 105u8,
+                    116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    79u8, 114u8, 100u8, 101u8, 114u8, 49u8, 50u8, 32u8, 72u8, 111u8, 117u8, 114u8,
+                    32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 48u8, 226u8, 128u8,
+                    147u8, 49u8, 49u8, 41u8, 49u8, 50u8, 32u8, 72u8, 111u8, 117u8, 114u8, 32u8,
+                    83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8,
+                    49u8, 50u8, 41u8, 50u8, 52u8, 32u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8,
+                    51u8, 41u8, 50u8, 52u8, 32u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8,
+                    41u8, 77u8, 101u8, 116u8, 114u8, 105u8, 99u8, 32u8, 83u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 73u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 32u8, 77u8,
+                    101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8,
+                    83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 85u8, 83u8, 32u8, 77u8, 101u8, 97u8,
+                    115u8, 117u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8, 83u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8,
+                    110u8, 100u8, 105u8, 99u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 66u8,
+                    97u8, 110u8, 103u8, 108u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 70u8, 117u8, 108u8, 108u8, 45u8, 87u8,
+                    105u8, 100u8, 116u8, 104u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 68u8, 101u8, 99u8, 105u8,
+                    109u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8,
+                    87u8, 101u8, 115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 84u8, 104u8, 97u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    pattern: ::alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: ::alloc::borrow::Cow::Borrowed("{0}, {1}"),
+    key_type_pattern: ::alloc::borrow::Cow::Borrowed("{0} : {1}"),
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 99u8, 97u8, 99u8, 111u8, 104u8, 99u8, 110u8, 117u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8,
+                    0u8, 46u8, 0u8, 0u8, 0u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8,
+                    101u8, 114u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8,
+                    116u8, 114u8, 105u8, 99u8, 121u8, 99u8, 108u8, 101u8, 32u8, 104u8, 111u8,
+                    114u8, 97u8, 105u8, 114u8, 101u8, 32u8, 40u8, 49u8, 50u8, 32u8, 111u8, 117u8,
+                    32u8, 50u8, 52u8, 41u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 32u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 51u8, 0u8,
+                    0u8, 0u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8,
+                    116u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8,
+                    97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 104u8, 99u8,
+                    45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8,
+                    0u8, 61u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 138u8,
+                    0u8, 0u8, 0u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 98u8, 111u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8,
+                    101u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8,
+                    32u8, 103u8, 114u8, 195u8, 169u8, 103u8, 111u8, 114u8, 105u8, 101u8, 110u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    106u8, 97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 115u8, 121u8, 115u8,
+                    116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 100u8, 101u8, 32u8, 49u8, 50u8, 32u8,
+                    104u8, 101u8, 117u8, 114u8, 101u8, 115u8, 32u8, 40u8, 49u8, 32u8, 195u8, 160u8,
+                    32u8, 49u8, 50u8, 41u8, 115u8, 121u8, 115u8, 116u8, 195u8, 168u8, 109u8, 101u8,
+                    32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8, 101u8, 117u8, 114u8, 101u8,
+                    115u8, 32u8, 40u8, 48u8, 32u8, 195u8, 160u8, 32u8, 50u8, 51u8, 41u8, 99u8,
+                    104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 97u8, 114u8, 97u8, 98u8,
+                    101u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8,
+                    111u8, 99u8, 99u8, 105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 117u8, 120u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    pattern: ::alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: ::alloc::borrow::Cow::Borrowed("{0}、{1}"),
+    key_type_pattern: ::alloc::borrow::Cow::Borrowed("{0}: {1}"),
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    99u8, 97u8, 99u8, 111u8, 110u8, 117u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 230u8, 154u8, 166u8, 230u8, 179u8, 149u8, 228u8, 184u8, 166u8, 227u8,
+                    129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 233u8, 160u8, 134u8,
+                    229u8, 186u8, 143u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 32u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 99u8, 97u8, 45u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 103u8, 114u8,
+                    101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                    0u8, 38u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 228u8, 187u8, 143u8, 230u8,
+                    154u8, 166u8, 232u8, 165u8, 191u8, 230u8, 154u8, 166u8, 40u8, 227u8, 130u8,
+                    176u8, 227u8, 131u8, 172u8, 227u8, 130u8, 180u8, 227u8, 131u8, 170u8, 227u8,
+                    130u8, 170u8, 230u8, 154u8, 166u8, 41u8, 229u8, 146u8, 140u8, 230u8, 154u8,
+                    166u8, 230u8, 188u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8,
+                    230u8, 149u8, 176u8, 229u8, 173u8, 151u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,6 @@
+// @generated
+pub mod languages_v1;
+pub mod locales_v1;
+pub mod regions_v1;
+pub mod scripts_v1;
+pub mod variants_v1;
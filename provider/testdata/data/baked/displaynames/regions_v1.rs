@@ -0,0 +1,219 @@
+// @generated
+type DataStruct = & 'static < :: icu_displaynames :: provider :: RegionDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_displaynames::provider::RegionDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    48u8, 48u8, 49u8, 48u8, 48u8, 50u8, 48u8, 49u8, 57u8, 49u8, 52u8, 50u8, 49u8,
+                    53u8, 48u8, 52u8, 49u8, 57u8, 65u8, 82u8, 0u8, 65u8, 84u8, 0u8, 65u8, 85u8,
+                    0u8, 66u8, 65u8, 0u8, 66u8, 68u8, 0u8, 66u8, 69u8, 0u8, 66u8, 82u8, 0u8, 67u8,
+                    65u8, 0u8, 67u8, 68u8, 0u8, 67u8, 72u8, 0u8, 67u8, 73u8, 0u8, 67u8, 78u8, 0u8,
+                    67u8, 90u8, 0u8, 68u8, 69u8, 0u8, 69u8, 71u8, 0u8, 69u8, 83u8, 0u8, 69u8, 85u8,
+                    0u8, 70u8, 82u8, 0u8, 71u8, 66u8, 0u8, 72u8, 75u8, 0u8, 73u8, 76u8, 0u8, 73u8,
+                    78u8, 0u8, 73u8, 84u8, 0u8, 74u8, 80u8, 0u8, 75u8, 82u8, 0u8, 77u8, 68u8, 0u8,
+                    77u8, 69u8, 0u8, 77u8, 79u8, 0u8, 77u8, 88u8, 0u8, 78u8, 76u8, 0u8, 80u8, 72u8,
+                    0u8, 80u8, 83u8, 0u8, 80u8, 84u8, 0u8, 82u8, 83u8, 0u8, 82u8, 85u8, 0u8, 83u8,
+                    65u8, 0u8, 84u8, 72u8, 0u8, 84u8, 82u8, 0u8, 84u8, 87u8, 0u8, 85u8, 78u8, 0u8,
+                    85u8, 83u8, 0u8, 90u8, 65u8, 0u8, 90u8, 90u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 19u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 42u8, 0u8,
+                    0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 87u8,
+                    0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8,
+                    116u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 159u8, 0u8,
+                    0u8, 0u8, 164u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8,
+                    183u8, 0u8, 0u8, 0u8, 188u8, 0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 208u8, 0u8,
+                    0u8, 0u8, 222u8, 0u8, 0u8, 0u8, 241u8, 0u8, 0u8, 0u8, 247u8, 0u8, 0u8, 0u8,
+                    252u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 17u8, 1u8, 0u8,
+                    0u8, 24u8, 1u8, 0u8, 0u8, 34u8, 1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 55u8, 1u8,
+                    0u8, 0u8, 66u8, 1u8, 0u8, 0u8, 77u8, 1u8, 0u8, 0u8, 100u8, 1u8, 0u8, 0u8,
+                    108u8, 1u8, 0u8, 0u8, 114u8, 1u8, 0u8, 0u8, 120u8, 1u8, 0u8, 0u8, 132u8, 1u8,
+                    0u8, 0u8, 140u8, 1u8, 0u8, 0u8, 146u8, 1u8, 0u8, 0u8, 152u8, 1u8, 0u8, 0u8,
+                    166u8, 1u8, 0u8, 0u8, 179u8, 1u8, 0u8, 0u8, 191u8, 1u8, 0u8, 0u8, 119u8, 111u8,
+                    114u8, 108u8, 100u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 115u8, 65u8, 115u8, 105u8, 97u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 76u8, 97u8, 116u8, 105u8, 110u8, 32u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8,
+                    110u8, 97u8, 65u8, 117u8, 115u8, 116u8, 114u8, 105u8, 97u8, 65u8, 117u8, 115u8,
+                    116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 66u8, 111u8, 115u8, 110u8, 105u8, 97u8,
+                    32u8, 38u8, 32u8, 72u8, 101u8, 114u8, 122u8, 101u8, 103u8, 111u8, 118u8, 105u8,
+                    110u8, 97u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8, 115u8, 104u8,
+                    66u8, 101u8, 108u8, 103u8, 105u8, 117u8, 109u8, 66u8, 114u8, 97u8, 122u8,
+                    105u8, 108u8, 67u8, 97u8, 110u8, 97u8, 100u8, 97u8, 67u8, 111u8, 110u8, 103u8,
+                    111u8, 32u8, 45u8, 32u8, 75u8, 105u8, 110u8, 115u8, 104u8, 97u8, 115u8, 97u8,
+                    83u8, 119u8, 105u8, 116u8, 122u8, 101u8, 114u8, 108u8, 97u8, 110u8, 100u8,
+                    67u8, 195u8, 180u8, 116u8, 101u8, 32u8, 100u8, 226u8, 128u8, 153u8, 73u8,
+                    118u8, 111u8, 105u8, 114u8, 101u8, 67u8, 104u8, 105u8, 110u8, 97u8, 67u8,
+                    122u8, 101u8, 99u8, 104u8, 105u8, 97u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8,
+                    121u8, 69u8, 103u8, 121u8, 112u8, 116u8, 83u8, 112u8, 97u8, 105u8, 110u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 85u8, 110u8, 105u8,
+                    111u8, 110u8, 70u8, 114u8, 97u8, 110u8, 99u8, 101u8, 85u8, 110u8, 105u8, 116u8,
+                    101u8, 100u8, 32u8, 75u8, 105u8, 110u8, 103u8, 100u8, 111u8, 109u8, 72u8,
+                    111u8, 110u8, 103u8, 32u8, 75u8, 111u8, 110u8, 103u8, 32u8, 83u8, 65u8, 82u8,
+                    32u8, 67u8, 104u8, 105u8, 110u8, 97u8, 73u8, 115u8, 114u8, 97u8, 101u8, 108u8,
+                    73u8, 110u8, 100u8, 105u8, 97u8, 73u8, 116u8, 97u8, 108u8, 121u8, 74u8, 97u8,
+                    112u8, 97u8, 110u8, 83u8, 111u8, 117u8, 116u8, 104u8, 32u8, 75u8, 111u8, 114u8,
+                    101u8, 97u8, 77u8, 111u8, 108u8, 100u8, 111u8, 118u8, 97u8, 77u8, 111u8, 110u8,
+                    116u8, 101u8, 110u8, 101u8, 103u8, 114u8, 111u8, 77u8, 97u8, 99u8, 97u8, 111u8,
+                    32u8, 83u8, 65u8, 82u8, 32u8, 67u8, 104u8, 105u8, 110u8, 97u8, 77u8, 101u8,
+                    120u8, 105u8, 99u8, 111u8, 78u8, 101u8, 116u8, 104u8, 101u8, 114u8, 108u8,
+                    97u8, 110u8, 100u8, 115u8, 80u8, 104u8, 105u8, 108u8, 105u8, 112u8, 112u8,
+                    105u8, 110u8, 101u8, 115u8, 80u8, 97u8, 108u8, 101u8, 115u8, 116u8, 105u8,
+                    110u8, 105u8, 97u8, 110u8, 32u8, 84u8, 101u8, 114u8, 114u8, 105u8, 116u8,
+                    111u8, 114u8, 105u8, 101u8, 115u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8,
+                    97u8, 108u8, 83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 82u8, 117u8, 115u8, 115u8,
+                    105u8, 97u8, 83u8, 97u8, 117u8, 100u8, 105u8, 32u8, 65u8, 114u8, 97u8, 98u8,
+                    105u8, 97u8, 84u8, 104u8, 97u8, 105u8, 108u8, 97u8, 110u8, 100u8, 84u8, 117u8,
+                    114u8, 107u8, 101u8, 121u8, 84u8, 97u8, 105u8, 119u8, 97u8, 110u8, 85u8, 110u8,
+                    105u8, 116u8, 101u8, 100u8, 32u8, 78u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    115u8, 85u8, 110u8, 105u8, 116u8, 101u8, 100u8, 32u8, 83u8, 116u8, 97u8, 116u8,
+                    101u8, 115u8, 83u8, 111u8, 117u8, 116u8, 104u8, 32u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 85u8, 110u8, 107u8, 110u8, 111u8, 119u8, 110u8, 32u8, 82u8,
+                    101u8, 103u8, 105u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 65u8, 0u8, 71u8, 66u8, 0u8, 72u8, 75u8, 0u8, 77u8, 79u8, 0u8, 80u8, 83u8,
+                    0u8, 85u8, 78u8, 0u8, 85u8, 83u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    17u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8,
+                    0u8, 66u8, 111u8, 115u8, 110u8, 105u8, 97u8, 85u8, 75u8, 72u8, 111u8, 110u8,
+                    103u8, 32u8, 75u8, 111u8, 110u8, 103u8, 77u8, 97u8, 99u8, 97u8, 111u8, 80u8,
+                    97u8, 108u8, 101u8, 115u8, 116u8, 105u8, 110u8, 101u8, 85u8, 78u8, 85u8, 83u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_displaynames::provider::RegionDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    48u8, 48u8, 49u8, 52u8, 49u8, 57u8, 66u8, 69u8, 0u8, 66u8, 82u8, 0u8, 67u8,
+                    65u8, 0u8, 67u8, 72u8, 0u8, 67u8, 78u8, 0u8, 68u8, 69u8, 0u8, 69u8, 83u8, 0u8,
+                    70u8, 82u8, 0u8, 71u8, 66u8, 0u8, 72u8, 75u8, 0u8, 73u8, 84u8, 0u8, 74u8, 80u8,
+                    0u8, 77u8, 88u8, 0u8, 80u8, 84u8, 0u8, 84u8, 87u8, 0u8, 85u8, 83u8, 0u8, 90u8,
+                    90u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 29u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 48u8, 0u8,
+                    0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 75u8,
+                    0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8,
+                    125u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 147u8, 0u8,
+                    0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 77u8, 111u8, 110u8, 100u8, 101u8, 65u8, 109u8,
+                    195u8, 169u8, 114u8, 105u8, 113u8, 117u8, 101u8, 32u8, 108u8, 97u8, 116u8,
+                    105u8, 110u8, 101u8, 66u8, 101u8, 108u8, 103u8, 105u8, 113u8, 117u8, 101u8,
+                    66u8, 114u8, 195u8, 169u8, 115u8, 105u8, 108u8, 67u8, 97u8, 110u8, 97u8, 100u8,
+                    97u8, 83u8, 117u8, 105u8, 115u8, 115u8, 101u8, 67u8, 104u8, 105u8, 110u8,
+                    101u8, 65u8, 108u8, 108u8, 101u8, 109u8, 97u8, 103u8, 110u8, 101u8, 69u8,
+                    115u8, 112u8, 97u8, 103u8, 110u8, 101u8, 70u8, 114u8, 97u8, 110u8, 99u8, 101u8,
+                    82u8, 111u8, 121u8, 97u8, 117u8, 109u8, 101u8, 45u8, 85u8, 110u8, 105u8, 82u8,
+                    46u8, 65u8, 46u8, 83u8, 46u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8,
+                    115u8, 101u8, 32u8, 100u8, 101u8, 32u8, 72u8, 111u8, 110u8, 103u8, 32u8, 75u8,
+                    111u8, 110u8, 103u8, 73u8, 116u8, 97u8, 108u8, 105u8, 101u8, 74u8, 97u8, 112u8,
+                    111u8, 110u8, 77u8, 101u8, 120u8, 105u8, 113u8, 117u8, 101u8, 80u8, 111u8,
+                    114u8, 116u8, 117u8, 103u8, 97u8, 108u8, 84u8, 97u8, 195u8, 175u8, 119u8, 97u8,
+                    110u8, 195u8, 137u8, 116u8, 97u8, 116u8, 115u8, 45u8, 85u8, 110u8, 105u8,
+                    115u8, 114u8, 195u8, 169u8, 103u8, 105u8, 111u8, 110u8, 32u8, 105u8, 110u8,
+                    100u8, 195u8, 169u8, 116u8, 101u8, 114u8, 109u8, 105u8, 110u8, 195u8, 169u8,
+                    101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    71u8, 66u8, 0u8, 72u8, 75u8, 0u8, 85u8, 83u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 82u8, 46u8, 45u8, 85u8, 46u8, 72u8, 111u8, 110u8, 103u8, 32u8, 75u8,
+                    111u8, 110u8, 103u8, 195u8, 137u8, 46u8, 45u8, 85u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_displaynames::provider::RegionDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    48u8, 48u8, 49u8, 52u8, 49u8, 57u8, 67u8, 65u8, 0u8, 67u8, 72u8, 0u8, 67u8,
+                    78u8, 0u8, 68u8, 69u8, 0u8, 69u8, 83u8, 0u8, 70u8, 82u8, 0u8, 71u8, 66u8, 0u8,
+                    72u8, 75u8, 0u8, 74u8, 80u8, 0u8, 77u8, 88u8, 0u8, 84u8, 87u8, 0u8, 85u8, 83u8,
+                    0u8, 90u8, 90u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8,
+                    0u8, 36u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 60u8, 0u8,
+                    0u8, 0u8, 72u8, 0u8, 0u8, 0u8, 84u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 138u8,
+                    0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 162u8, 0u8, 0u8,
+                    0u8, 183u8, 0u8, 0u8, 0u8, 228u8, 184u8, 150u8, 231u8, 149u8, 140u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 227u8, 130u8, 162u8,
+                    227u8, 131u8, 161u8, 227u8, 131u8, 170u8, 227u8, 130u8, 171u8, 227u8, 130u8,
+                    171u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 227u8, 130u8, 185u8, 227u8,
+                    130u8, 164u8, 227u8, 130u8, 185u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8,
+                    227u8, 131u8, 137u8, 227u8, 130u8, 164u8, 227u8, 131u8, 132u8, 227u8, 130u8,
+                    185u8, 227u8, 131u8, 154u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8, 227u8,
+                    131u8, 149u8, 227u8, 131u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8, 185u8,
+                    227u8, 130u8, 164u8, 227u8, 130u8, 174u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    185u8, 228u8, 184u8, 173u8, 232u8, 143u8, 175u8, 228u8, 186u8, 186u8, 230u8,
+                    176u8, 145u8, 229u8, 133u8, 177u8, 229u8, 146u8, 140u8, 229u8, 155u8, 189u8,
+                    233u8, 166u8, 153u8, 230u8, 184u8, 175u8, 231u8, 137u8, 185u8, 229u8, 136u8,
+                    165u8, 232u8, 161u8, 140u8, 230u8, 148u8, 191u8, 229u8, 140u8, 186u8, 230u8,
+                    151u8, 165u8, 230u8, 156u8, 172u8, 227u8, 131u8, 161u8, 227u8, 130u8, 173u8,
+                    227u8, 130u8, 183u8, 227u8, 130u8, 179u8, 229u8, 143u8, 176u8, 230u8, 185u8,
+                    190u8, 227u8, 130u8, 162u8, 227u8, 131u8, 161u8, 227u8, 131u8, 170u8, 227u8,
+                    130u8, 171u8, 229u8, 144u8, 136u8, 232u8, 161u8, 134u8, 229u8, 155u8, 189u8,
+                    228u8, 184u8, 141u8, 230u8, 152u8, 142u8, 227u8, 129u8, 170u8, 229u8, 156u8,
+                    176u8, 229u8, 159u8, 159u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[72u8, 75u8, 0u8, 85u8, 83u8, 0u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 233u8, 166u8,
+                    153u8, 230u8, 184u8, 175u8, 227u8, 130u8, 162u8, 227u8, 131u8, 161u8, 227u8,
+                    131u8, 170u8, 227u8, 130u8, 171u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,177 @@
+// @generated
+type DataStruct = & 'static < :: icu_displaynames :: provider :: ScriptDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 109u8, 110u8, 66u8, 101u8, 110u8, 103u8,
+                    66u8, 114u8, 97u8, 105u8, 67u8, 97u8, 107u8, 109u8, 67u8, 121u8, 114u8, 108u8,
+                    68u8, 101u8, 118u8, 97u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 114u8,
+                    71u8, 114u8, 101u8, 107u8, 72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8,
+                    72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8,
+                    72u8, 105u8, 114u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 110u8, 97u8,
+                    75u8, 111u8, 114u8, 101u8, 76u8, 97u8, 116u8, 110u8, 84u8, 104u8, 97u8, 105u8,
+                    90u8, 109u8, 116u8, 104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8,
+                    109u8, 90u8, 120u8, 120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8,
+                    122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 20u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 41u8, 0u8,
+                    0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 72u8,
+                    0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8,
+                    102u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 124u8, 0u8,
+                    0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8,
+                    147u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8, 0u8, 173u8, 0u8, 0u8, 0u8, 180u8, 0u8,
+                    0u8, 0u8, 189u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 65u8, 114u8, 97u8, 98u8,
+                    105u8, 99u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 66u8, 97u8,
+                    110u8, 103u8, 108u8, 97u8, 66u8, 114u8, 97u8, 105u8, 108u8, 108u8, 101u8, 67u8,
+                    104u8, 97u8, 107u8, 109u8, 97u8, 67u8, 121u8, 114u8, 105u8, 108u8, 108u8,
+                    105u8, 99u8, 68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8,
+                    69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 71u8, 101u8, 111u8,
+                    114u8, 103u8, 105u8, 97u8, 110u8, 71u8, 114u8, 101u8, 101u8, 107u8, 72u8, 97u8,
+                    110u8, 103u8, 117u8, 108u8, 72u8, 97u8, 110u8, 83u8, 105u8, 109u8, 112u8,
+                    108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 84u8, 114u8, 97u8, 100u8, 105u8,
+                    116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 72u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 72u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 74u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8,
+                    97u8, 75u8, 111u8, 114u8, 101u8, 97u8, 110u8, 76u8, 97u8, 116u8, 105u8, 110u8,
+                    84u8, 104u8, 97u8, 105u8, 77u8, 97u8, 116u8, 104u8, 101u8, 109u8, 97u8, 116u8,
+                    105u8, 99u8, 97u8, 108u8, 32u8, 78u8, 111u8, 116u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 69u8, 109u8, 111u8, 106u8, 105u8, 83u8, 121u8, 109u8, 98u8, 111u8,
+                    108u8, 115u8, 85u8, 110u8, 119u8, 114u8, 105u8, 116u8, 116u8, 101u8, 110u8,
+                    67u8, 111u8, 109u8, 109u8, 111u8, 110u8, 85u8, 110u8, 107u8, 110u8, 111u8,
+                    119u8, 110u8, 32u8, 83u8, 99u8, 114u8, 105u8, 112u8, 116u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    stand_alone_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 83u8, 105u8,
+                    109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 72u8, 97u8,
+                    110u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 72u8, 97u8, 110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 114u8, 97u8, 98u8, 67u8, 121u8, 114u8, 108u8, 72u8, 97u8, 110u8, 115u8,
+                    72u8, 97u8, 110u8, 116u8, 74u8, 112u8, 97u8, 110u8, 76u8, 97u8, 116u8, 110u8,
+                    90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 25u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 50u8, 0u8,
+                    0u8, 0u8, 97u8, 114u8, 97u8, 98u8, 101u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                    108u8, 105u8, 113u8, 117u8, 101u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8,
+                    102u8, 105u8, 195u8, 169u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 110u8, 101u8, 108u8, 106u8, 97u8, 112u8, 111u8, 110u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 116u8, 105u8, 110u8, 195u8, 169u8, 99u8, 114u8,
+                    105u8, 116u8, 117u8, 114u8, 101u8, 32u8, 105u8, 110u8, 99u8, 111u8, 110u8,
+                    110u8, 117u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    stand_alone_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 105u8, 100u8,
+                    195u8, 169u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8, 115u8, 32u8,
+                    104u8, 97u8, 110u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8,
+                    105u8, 195u8, 169u8, 115u8, 105u8, 100u8, 195u8, 169u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 109u8, 101u8, 115u8, 32u8, 104u8, 97u8, 110u8, 32u8, 116u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 110u8, 101u8, 108u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 114u8, 97u8, 98u8, 67u8, 121u8, 114u8, 108u8, 72u8, 97u8, 110u8, 115u8,
+                    72u8, 97u8, 110u8, 116u8, 74u8, 112u8, 97u8, 110u8, 76u8, 97u8, 116u8, 110u8,
+                    90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 84u8, 0u8,
+                    0u8, 0u8, 227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8, 227u8,
+                    130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 173u8,
+                    227u8, 131u8, 170u8, 227u8, 131u8, 171u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 231u8, 176u8, 161u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8, 231u8,
+                    185u8, 129u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8, 230u8, 151u8, 165u8,
+                    230u8, 156u8, 172u8, 232u8, 170u8, 158u8, 227u8, 129u8, 174u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 169u8, 227u8, 131u8, 134u8, 227u8,
+                    131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 228u8, 184u8, 141u8,
+                    230u8, 152u8, 142u8, 227u8, 129u8, 170u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    stand_alone_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
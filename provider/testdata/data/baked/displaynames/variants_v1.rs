@@ -0,0 +1,109 @@
+// @generated
+type DataStruct = & 'static < :: icu_displaynames :: provider :: VariantDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8,
+                    0u8, 0u8, 97u8, 108u8, 97u8, 108u8, 99u8, 57u8, 55u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8, 118u8, 109u8, 100u8, 97u8,
+                    0u8, 102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8, 104u8, 101u8, 112u8,
+                    98u8, 117u8, 114u8, 110u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8,
+                    110u8, 0u8, 111u8, 120u8, 101u8, 110u8, 100u8, 105u8, 99u8, 116u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8, 111u8, 108u8, 121u8, 116u8,
+                    111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8, 120u8, 0u8, 0u8, 0u8, 114u8,
+                    101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8, 115u8, 99u8, 111u8, 116u8,
+                    108u8, 97u8, 110u8, 100u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8,
+                    119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8,
+                    0u8, 89u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 134u8,
+                    0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 197u8, 0u8, 0u8,
+                    0u8, 216u8, 0u8, 0u8, 0u8, 225u8, 0u8, 0u8, 0u8, 233u8, 0u8, 0u8, 0u8, 252u8,
+                    0u8, 0u8, 0u8, 21u8, 1u8, 0u8, 0u8, 30u8, 1u8, 0u8, 0u8, 84u8, 114u8, 97u8,
+                    100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 71u8, 101u8,
+                    114u8, 109u8, 97u8, 110u8, 32u8, 111u8, 114u8, 116u8, 104u8, 111u8, 103u8,
+                    114u8, 97u8, 112u8, 104u8, 121u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 32u8,
+                    111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8,
+                    32u8, 111u8, 102u8, 32u8, 49u8, 57u8, 57u8, 54u8, 65u8, 76u8, 65u8, 45u8, 76u8,
+                    67u8, 32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 44u8, 32u8, 49u8, 57u8, 57u8, 55u8, 32u8, 101u8, 100u8, 105u8,
+                    116u8, 105u8, 111u8, 110u8, 69u8, 97u8, 115u8, 116u8, 101u8, 114u8, 110u8,
+                    32u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 87u8, 101u8, 115u8,
+                    116u8, 101u8, 114u8, 110u8, 32u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                    97u8, 110u8, 73u8, 80u8, 65u8, 32u8, 80u8, 104u8, 111u8, 110u8, 101u8, 116u8,
+                    105u8, 99u8, 115u8, 72u8, 101u8, 112u8, 98u8, 117u8, 114u8, 110u8, 32u8, 114u8,
+                    111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    77u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 105u8, 99u8, 79u8, 120u8,
+                    102u8, 111u8, 114u8, 100u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8,
+                    104u8, 32u8, 68u8, 105u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8, 114u8, 121u8,
+                    32u8, 115u8, 112u8, 101u8, 108u8, 108u8, 105u8, 110u8, 103u8, 80u8, 105u8,
+                    110u8, 121u8, 105u8, 110u8, 32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8,
+                    122u8, 97u8, 116u8, 105u8, 111u8, 110u8, 80u8, 111u8, 108u8, 121u8, 116u8,
+                    111u8, 110u8, 105u8, 99u8, 67u8, 111u8, 109u8, 112u8, 117u8, 116u8, 101u8,
+                    114u8, 82u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 32u8, 79u8, 114u8,
+                    116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8, 83u8, 99u8,
+                    111u8, 116u8, 116u8, 105u8, 115u8, 104u8, 32u8, 83u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8,
+                    104u8, 86u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8, 110u8, 87u8, 97u8,
+                    100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8, 115u8, 32u8, 82u8, 111u8, 109u8,
+                    97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8,
+                    97u8, 0u8, 0u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8,
+                    0u8, 111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8,
+                    101u8, 32u8, 97u8, 108u8, 108u8, 101u8, 109u8, 97u8, 110u8, 100u8, 101u8, 32u8,
+                    116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 110u8, 101u8,
+                    108u8, 108u8, 101u8, 97u8, 108u8, 112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 32u8,
+                    112u8, 104u8, 111u8, 110u8, 195u8, 169u8, 116u8, 105u8, 113u8, 117u8, 101u8,
+                    32u8, 105u8, 110u8, 116u8, 101u8, 114u8, 110u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 101u8,
+                    110u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8, 104u8, 101u8, 112u8, 98u8,
+                    117u8, 114u8, 110u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 73u8, 80u8, 65u8,
+                    231u8, 153u8, 186u8, 233u8, 159u8, 179u8, 232u8, 168u8, 152u8, 229u8, 143u8,
+                    183u8, 227u8, 131u8, 152u8, 227u8, 131u8, 156u8, 227u8, 131u8, 179u8, 229u8,
+                    188u8, 143u8, 227u8, 131u8, 173u8, 227u8, 131u8, 188u8, 227u8, 131u8, 158u8,
+                    229u8, 173u8, 151u8,
+                ])
+            },
+        )
+    },
+};
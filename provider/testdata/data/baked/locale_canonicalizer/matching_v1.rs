@@ -0,0 +1,357 @@
+// @generated
+type DataStruct = & 'static < :: icu_locale_canonicalizer :: provider :: LanguageMatchingV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("und", UND)];
+static UND: DataStruct = &::icu_locale_canonicalizer::provider::LanguageMatchingV1 {
+    paradigm_locales: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 101u8, 110u8, 101u8, 110u8,
+            45u8, 71u8, 66u8, 101u8, 115u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 112u8, 116u8,
+            45u8, 66u8, 82u8, 112u8, 116u8, 45u8, 80u8, 84u8,
+        ])
+    },
+    match_variables: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8,
+                    99u8, 110u8, 115u8, 97u8, 114u8, 101u8, 110u8, 85u8, 83u8, 109u8, 97u8, 103u8,
+                    104u8, 114u8, 101u8, 98u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8,
+                    0u8, 222u8, 0u8, 0u8, 0u8, 48u8, 48u8, 51u8, 48u8, 48u8, 53u8, 48u8, 49u8,
+                    51u8, 48u8, 49u8, 57u8, 48u8, 50u8, 49u8, 48u8, 50u8, 57u8, 52u8, 49u8, 57u8,
+                    65u8, 71u8, 0u8, 65u8, 73u8, 0u8, 65u8, 82u8, 0u8, 65u8, 87u8, 0u8, 66u8, 66u8,
+                    0u8, 66u8, 76u8, 0u8, 66u8, 77u8, 0u8, 66u8, 79u8, 0u8, 66u8, 81u8, 0u8, 66u8,
+                    82u8, 0u8, 66u8, 83u8, 0u8, 66u8, 90u8, 0u8, 67u8, 65u8, 0u8, 67u8, 76u8, 0u8,
+                    67u8, 79u8, 0u8, 67u8, 82u8, 0u8, 67u8, 85u8, 0u8, 67u8, 87u8, 0u8, 68u8, 77u8,
+                    0u8, 68u8, 79u8, 0u8, 69u8, 67u8, 0u8, 70u8, 75u8, 0u8, 71u8, 68u8, 0u8, 71u8,
+                    70u8, 0u8, 71u8, 76u8, 0u8, 71u8, 80u8, 0u8, 71u8, 84u8, 0u8, 71u8, 89u8, 0u8,
+                    72u8, 78u8, 0u8, 72u8, 84u8, 0u8, 74u8, 77u8, 0u8, 75u8, 78u8, 0u8, 75u8, 89u8,
+                    0u8, 76u8, 67u8, 0u8, 77u8, 70u8, 0u8, 77u8, 81u8, 0u8, 77u8, 83u8, 0u8, 77u8,
+                    88u8, 0u8, 78u8, 73u8, 0u8, 80u8, 65u8, 0u8, 80u8, 69u8, 0u8, 80u8, 77u8, 0u8,
+                    80u8, 82u8, 0u8, 80u8, 89u8, 0u8, 83u8, 82u8, 0u8, 83u8, 86u8, 0u8, 83u8, 88u8,
+                    0u8, 84u8, 67u8, 0u8, 84u8, 84u8, 0u8, 85u8, 83u8, 0u8, 85u8, 89u8, 0u8, 86u8,
+                    67u8, 0u8, 86u8, 69u8, 0u8, 86u8, 71u8, 0u8, 86u8, 73u8, 0u8, 72u8, 75u8, 0u8,
+                    77u8, 79u8, 0u8, 65u8, 83u8, 0u8, 67u8, 65u8, 0u8, 71u8, 85u8, 0u8, 77u8, 72u8,
+                    0u8, 77u8, 80u8, 0u8, 80u8, 72u8, 0u8, 80u8, 82u8, 0u8, 85u8, 77u8, 0u8, 85u8,
+                    83u8, 0u8, 86u8, 73u8, 0u8, 68u8, 90u8, 0u8, 69u8, 72u8, 0u8, 76u8, 89u8, 0u8,
+                    77u8, 65u8, 0u8, 77u8, 82u8, 0u8, 84u8, 78u8, 0u8,
+                ])
+            },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            185u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8,
+            48u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8,
+            112u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8,
+            176u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8,
+            241u8, 0u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8, 19u8, 1u8, 0u8, 0u8, 35u8, 1u8, 0u8, 0u8,
+            51u8, 1u8, 0u8, 0u8, 67u8, 1u8, 0u8, 0u8, 83u8, 1u8, 0u8, 0u8, 99u8, 1u8, 0u8, 0u8,
+            115u8, 1u8, 0u8, 0u8, 131u8, 1u8, 0u8, 0u8, 147u8, 1u8, 0u8, 0u8, 163u8, 1u8, 0u8, 0u8,
+            179u8, 1u8, 0u8, 0u8, 195u8, 1u8, 0u8, 0u8, 211u8, 1u8, 0u8, 0u8, 228u8, 1u8, 0u8, 0u8,
+            244u8, 1u8, 0u8, 0u8, 4u8, 2u8, 0u8, 0u8, 20u8, 2u8, 0u8, 0u8, 38u8, 2u8, 0u8, 0u8,
+            55u8, 2u8, 0u8, 0u8, 72u8, 2u8, 0u8, 0u8, 88u8, 2u8, 0u8, 0u8, 105u8, 2u8, 0u8, 0u8,
+            121u8, 2u8, 0u8, 0u8, 137u8, 2u8, 0u8, 0u8, 153u8, 2u8, 0u8, 0u8, 169u8, 2u8, 0u8, 0u8,
+            185u8, 2u8, 0u8, 0u8, 201u8, 2u8, 0u8, 0u8, 217u8, 2u8, 0u8, 0u8, 233u8, 2u8, 0u8, 0u8,
+            250u8, 2u8, 0u8, 0u8, 10u8, 3u8, 0u8, 0u8, 26u8, 3u8, 0u8, 0u8, 42u8, 3u8, 0u8, 0u8,
+            58u8, 3u8, 0u8, 0u8, 74u8, 3u8, 0u8, 0u8, 91u8, 3u8, 0u8, 0u8, 107u8, 3u8, 0u8, 0u8,
+            123u8, 3u8, 0u8, 0u8, 139u8, 3u8, 0u8, 0u8, 155u8, 3u8, 0u8, 0u8, 171u8, 3u8, 0u8, 0u8,
+            187u8, 3u8, 0u8, 0u8, 203u8, 3u8, 0u8, 0u8, 219u8, 3u8, 0u8, 0u8, 235u8, 3u8, 0u8, 0u8,
+            251u8, 3u8, 0u8, 0u8, 11u8, 4u8, 0u8, 0u8, 28u8, 4u8, 0u8, 0u8, 44u8, 4u8, 0u8, 0u8,
+            60u8, 4u8, 0u8, 0u8, 76u8, 4u8, 0u8, 0u8, 92u8, 4u8, 0u8, 0u8, 108u8, 4u8, 0u8, 0u8,
+            124u8, 4u8, 0u8, 0u8, 141u8, 4u8, 0u8, 0u8, 158u8, 4u8, 0u8, 0u8, 175u8, 4u8, 0u8, 0u8,
+            191u8, 4u8, 0u8, 0u8, 207u8, 4u8, 0u8, 0u8, 223u8, 4u8, 0u8, 0u8, 239u8, 4u8, 0u8, 0u8,
+            255u8, 4u8, 0u8, 0u8, 15u8, 5u8, 0u8, 0u8, 31u8, 5u8, 0u8, 0u8, 47u8, 5u8, 0u8, 0u8,
+            63u8, 5u8, 0u8, 0u8, 79u8, 5u8, 0u8, 0u8, 95u8, 5u8, 0u8, 0u8, 111u8, 5u8, 0u8, 0u8,
+            128u8, 5u8, 0u8, 0u8, 144u8, 5u8, 0u8, 0u8, 161u8, 5u8, 0u8, 0u8, 177u8, 5u8, 0u8, 0u8,
+            193u8, 5u8, 0u8, 0u8, 209u8, 5u8, 0u8, 0u8, 225u8, 5u8, 0u8, 0u8, 242u8, 5u8, 0u8, 0u8,
+            2u8, 6u8, 0u8, 0u8, 18u8, 6u8, 0u8, 0u8, 34u8, 6u8, 0u8, 0u8, 50u8, 6u8, 0u8, 0u8,
+            66u8, 6u8, 0u8, 0u8, 82u8, 6u8, 0u8, 0u8, 98u8, 6u8, 0u8, 0u8, 114u8, 6u8, 0u8, 0u8,
+            130u8, 6u8, 0u8, 0u8, 146u8, 6u8, 0u8, 0u8, 162u8, 6u8, 0u8, 0u8, 178u8, 6u8, 0u8, 0u8,
+            194u8, 6u8, 0u8, 0u8, 210u8, 6u8, 0u8, 0u8, 226u8, 6u8, 0u8, 0u8, 242u8, 6u8, 0u8, 0u8,
+            2u8, 7u8, 0u8, 0u8, 18u8, 7u8, 0u8, 0u8, 34u8, 7u8, 0u8, 0u8, 51u8, 7u8, 0u8, 0u8,
+            67u8, 7u8, 0u8, 0u8, 83u8, 7u8, 0u8, 0u8, 99u8, 7u8, 0u8, 0u8, 116u8, 7u8, 0u8, 0u8,
+            132u8, 7u8, 0u8, 0u8, 148u8, 7u8, 0u8, 0u8, 164u8, 7u8, 0u8, 0u8, 180u8, 7u8, 0u8, 0u8,
+            196u8, 7u8, 0u8, 0u8, 212u8, 7u8, 0u8, 0u8, 228u8, 7u8, 0u8, 0u8, 244u8, 7u8, 0u8, 0u8,
+            4u8, 8u8, 0u8, 0u8, 20u8, 8u8, 0u8, 0u8, 34u8, 8u8, 0u8, 0u8, 60u8, 8u8, 0u8, 0u8,
+            86u8, 8u8, 0u8, 0u8, 112u8, 8u8, 0u8, 0u8, 138u8, 8u8, 0u8, 0u8, 164u8, 8u8, 0u8, 0u8,
+            190u8, 8u8, 0u8, 0u8, 216u8, 8u8, 0u8, 0u8, 242u8, 8u8, 0u8, 0u8, 12u8, 9u8, 0u8, 0u8,
+            38u8, 9u8, 0u8, 0u8, 64u8, 9u8, 0u8, 0u8, 90u8, 9u8, 0u8, 0u8, 116u8, 9u8, 0u8, 0u8,
+            142u8, 9u8, 0u8, 0u8, 168u8, 9u8, 0u8, 0u8, 194u8, 9u8, 0u8, 0u8, 220u8, 9u8, 0u8, 0u8,
+            246u8, 9u8, 0u8, 0u8, 16u8, 10u8, 0u8, 0u8, 42u8, 10u8, 0u8, 0u8, 68u8, 10u8, 0u8, 0u8,
+            94u8, 10u8, 0u8, 0u8, 120u8, 10u8, 0u8, 0u8, 146u8, 10u8, 0u8, 0u8, 172u8, 10u8, 0u8,
+            0u8, 198u8, 10u8, 0u8, 0u8, 224u8, 10u8, 0u8, 0u8, 250u8, 10u8, 0u8, 0u8, 20u8, 11u8,
+            0u8, 0u8, 46u8, 11u8, 0u8, 0u8, 72u8, 11u8, 0u8, 0u8, 98u8, 11u8, 0u8, 0u8, 124u8,
+            11u8, 0u8, 0u8, 150u8, 11u8, 0u8, 0u8, 176u8, 11u8, 0u8, 0u8, 202u8, 11u8, 0u8, 0u8,
+            228u8, 11u8, 0u8, 0u8, 246u8, 11u8, 0u8, 0u8, 28u8, 12u8, 0u8, 0u8, 68u8, 12u8, 0u8,
+            0u8, 92u8, 12u8, 0u8, 0u8, 124u8, 12u8, 0u8, 0u8, 154u8, 12u8, 0u8, 0u8, 184u8, 12u8,
+            0u8, 0u8, 218u8, 12u8, 0u8, 0u8, 242u8, 12u8, 0u8, 0u8, 26u8, 13u8, 0u8, 0u8, 68u8,
+            13u8, 0u8, 0u8, 92u8, 13u8, 0u8, 0u8, 132u8, 13u8, 0u8, 0u8, 174u8, 13u8, 0u8, 0u8,
+            198u8, 13u8, 0u8, 0u8, 238u8, 13u8, 0u8, 0u8, 24u8, 14u8, 0u8, 0u8, 54u8, 14u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8, 98u8, 110u8,
+            111u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8, 111u8, 110u8,
+            98u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 114u8, 98u8,
+            115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 98u8, 115u8, 104u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 104u8, 98u8,
+            115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 98u8, 115u8, 115u8,
+            104u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 114u8, 98u8,
+            115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 98u8, 115u8, 115u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 104u8, 104u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 114u8, 115u8,
+            104u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 114u8, 104u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 114u8, 115u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 104u8, 115u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 114u8, 115u8,
+            104u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8, 115u8, 121u8,
+            97u8, 97u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 97u8, 97u8,
+            115u8, 115u8, 121u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8,
+            115u8, 119u8, 100u8, 101u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            108u8, 98u8, 100u8, 101u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            100u8, 97u8, 110u8, 111u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            110u8, 111u8, 100u8, 97u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            100u8, 97u8, 110u8, 98u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            110u8, 98u8, 100u8, 97u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 98u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 102u8, 110u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 107u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 109u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 121u8, 101u8, 115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            97u8, 122u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            98u8, 101u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            98u8, 101u8, 109u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 98u8, 104u8, 104u8, 105u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 98u8, 110u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 98u8, 114u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 99u8, 101u8, 98u8, 102u8, 105u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 104u8, 114u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 107u8, 98u8, 97u8, 114u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 99u8, 111u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 114u8, 115u8, 102u8, 114u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 99u8, 121u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 101u8, 101u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 101u8, 111u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 101u8, 116u8, 102u8, 105u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 101u8, 117u8, 101u8, 115u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 102u8, 111u8, 100u8, 97u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 102u8, 121u8, 110u8, 108u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 97u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 97u8, 97u8, 101u8, 110u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 100u8, 101u8, 110u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 108u8, 101u8, 115u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 110u8, 101u8, 115u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 117u8, 104u8, 105u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 97u8, 101u8, 110u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 97u8, 119u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 116u8, 102u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 121u8, 114u8,
+            117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 97u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 103u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 115u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 106u8, 118u8, 105u8,
+            100u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 97u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 103u8, 102u8,
+            114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 107u8, 114u8,
+            117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 109u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 110u8, 101u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 107u8, 114u8, 105u8,
+            101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 117u8,
+            116u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 121u8,
+            114u8, 117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 97u8,
+            105u8, 116u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 103u8,
+            101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 110u8,
+            102u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 111u8,
+            101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 111u8,
+            122u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8,
+            117u8, 97u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            109u8, 102u8, 101u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 103u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 105u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 107u8, 98u8, 103u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 108u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 110u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 114u8, 104u8, 105u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 115u8, 105u8, 100u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 116u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 109u8, 121u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 110u8, 101u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 110u8, 110u8, 110u8, 98u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 110u8, 110u8, 110u8, 111u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 110u8, 115u8, 111u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 110u8, 121u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 110u8, 121u8, 110u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 99u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 109u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 114u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 112u8, 97u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8, 99u8, 109u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 112u8, 115u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 113u8, 117u8, 101u8, 115u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 109u8, 100u8, 101u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 110u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 119u8, 102u8, 114u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 97u8, 104u8, 105u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 100u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 105u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 110u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 111u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 113u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 116u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 117u8, 105u8, 100u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 119u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 97u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 101u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 103u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 105u8, 101u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 107u8, 114u8, 117u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 108u8, 104u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 110u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 111u8, 101u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 116u8, 114u8, 117u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 117u8, 109u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 103u8, 122u8, 104u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 107u8, 114u8, 117u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 114u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 122u8, 114u8, 117u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 119u8, 111u8, 102u8, 114u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 120u8, 104u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 121u8, 105u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 121u8, 111u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 122u8, 97u8, 122u8, 104u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 122u8, 117u8, 101u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 42u8, 42u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 97u8, 122u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 98u8, 110u8, 95u8, 66u8, 101u8, 110u8, 103u8, 101u8,
+            110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 104u8, 121u8, 95u8, 65u8, 114u8, 109u8, 110u8, 114u8, 117u8, 95u8, 67u8,
+            121u8, 114u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8,
+            97u8, 95u8, 71u8, 101u8, 111u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 109u8, 95u8, 75u8,
+            104u8, 109u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 110u8, 95u8, 75u8, 110u8, 100u8, 97u8,
+            101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            7u8, 0u8, 0u8, 0u8, 108u8, 111u8, 95u8, 76u8, 97u8, 111u8, 111u8, 101u8, 110u8, 95u8,
+            76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            109u8, 108u8, 95u8, 77u8, 108u8, 121u8, 109u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 109u8, 121u8, 95u8,
+            77u8, 121u8, 109u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 110u8, 101u8, 95u8, 68u8, 101u8, 118u8,
+            97u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 111u8, 114u8, 95u8, 79u8, 114u8, 121u8, 97u8, 101u8, 110u8,
+            95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 112u8, 97u8, 95u8, 71u8, 117u8, 114u8, 117u8, 101u8, 110u8, 95u8, 76u8, 97u8,
+            116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 112u8, 115u8,
+            95u8, 65u8, 114u8, 97u8, 98u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 100u8, 95u8, 65u8, 114u8,
+            97u8, 98u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 105u8, 95u8, 83u8, 105u8, 110u8, 104u8, 101u8,
+            110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 116u8, 97u8, 95u8, 84u8, 97u8, 109u8, 108u8, 101u8, 110u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8,
+            101u8, 95u8, 84u8, 101u8, 108u8, 117u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8, 105u8, 95u8, 69u8,
+            116u8, 104u8, 105u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8, 107u8, 95u8, 76u8, 97u8, 116u8, 110u8,
+            114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            7u8, 0u8, 0u8, 0u8, 117u8, 114u8, 95u8, 65u8, 114u8, 97u8, 98u8, 101u8, 110u8, 95u8,
+            76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            117u8, 122u8, 95u8, 76u8, 97u8, 116u8, 110u8, 114u8, 117u8, 95u8, 67u8, 121u8, 114u8,
+            108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 121u8, 105u8, 95u8,
+            72u8, 101u8, 98u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 114u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 115u8, 114u8, 95u8, 67u8, 121u8, 114u8, 108u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 114u8, 95u8, 67u8, 121u8, 114u8, 108u8, 115u8,
+            114u8, 95u8, 76u8, 97u8, 116u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 95u8, 72u8,
+            97u8, 110u8, 116u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 122u8,
+            104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 115u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 72u8, 97u8, 110u8, 105u8,
+            106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 72u8, 105u8, 114u8, 97u8, 106u8, 97u8, 95u8,
+            74u8, 112u8, 97u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            106u8, 97u8, 95u8, 75u8, 97u8, 110u8, 97u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8,
+            110u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8,
+            72u8, 114u8, 107u8, 116u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 72u8, 105u8, 114u8,
+            97u8, 106u8, 97u8, 95u8, 72u8, 114u8, 107u8, 116u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 75u8, 97u8, 110u8, 97u8, 106u8, 97u8, 95u8,
+            72u8, 114u8, 107u8, 116u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            107u8, 111u8, 95u8, 72u8, 97u8, 110u8, 105u8, 107u8, 111u8, 95u8, 75u8, 111u8, 114u8,
+            101u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 111u8, 95u8,
+            72u8, 97u8, 110u8, 103u8, 107u8, 111u8, 95u8, 75u8, 111u8, 114u8, 101u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 111u8, 95u8, 74u8, 97u8, 109u8,
+            111u8, 107u8, 111u8, 95u8, 75u8, 111u8, 114u8, 101u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 111u8, 95u8, 74u8, 97u8, 109u8, 111u8, 107u8,
+            111u8, 95u8, 72u8, 97u8, 110u8, 103u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 42u8, 95u8, 42u8, 42u8, 95u8, 42u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 13u8, 0u8, 0u8, 0u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 109u8, 97u8, 103u8,
+            104u8, 114u8, 101u8, 98u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 109u8, 97u8, 103u8,
+            104u8, 114u8, 101u8, 98u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+            97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 33u8, 109u8, 97u8, 103u8, 104u8, 114u8, 101u8,
+            98u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 33u8, 109u8, 97u8, 103u8, 104u8, 114u8,
+            101u8, 98u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 97u8, 114u8,
+            95u8, 42u8, 95u8, 42u8, 97u8, 114u8, 95u8, 42u8, 95u8, 42u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 101u8, 110u8,
+            85u8, 83u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 101u8, 110u8, 85u8, 83u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8, 71u8,
+            66u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 33u8, 101u8, 110u8, 85u8, 83u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8,
+            36u8, 33u8, 101u8, 110u8, 85u8, 83u8, 101u8, 110u8, 95u8, 42u8, 95u8, 71u8, 66u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8,
+            36u8, 33u8, 101u8, 110u8, 85u8, 83u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 33u8,
+            101u8, 110u8, 85u8, 83u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            101u8, 110u8, 95u8, 42u8, 95u8, 42u8, 101u8, 110u8, 95u8, 42u8, 95u8, 42u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8,
+            36u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 101u8, 115u8, 95u8, 42u8,
+            95u8, 36u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8, 36u8, 33u8,
+            97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 101u8, 115u8, 95u8, 42u8, 95u8,
+            36u8, 33u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8, 42u8, 101u8,
+            115u8, 95u8, 42u8, 95u8, 42u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+            0u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+            97u8, 115u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+            99u8, 97u8, 115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 112u8,
+            116u8, 95u8, 42u8, 95u8, 36u8, 33u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+            115u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 33u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+            99u8, 97u8, 115u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 112u8,
+            116u8, 95u8, 42u8, 95u8, 42u8, 112u8, 116u8, 95u8, 42u8, 95u8, 42u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8,
+            116u8, 95u8, 36u8, 99u8, 110u8, 115u8, 97u8, 114u8, 122u8, 104u8, 95u8, 72u8, 97u8,
+            110u8, 116u8, 95u8, 36u8, 99u8, 110u8, 115u8, 97u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8,
+            36u8, 33u8, 99u8, 110u8, 115u8, 97u8, 114u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8,
+            116u8, 95u8, 36u8, 33u8, 99u8, 110u8, 115u8, 97u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 42u8,
+            122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 42u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 42u8, 95u8, 42u8, 95u8, 42u8, 42u8, 95u8, 42u8, 95u8,
+            42u8,
+        ])
+    },
+    distances: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            1u8, 1u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 8u8, 8u8, 8u8, 8u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8,
+            30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 30u8, 80u8, 10u8,
+            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 5u8, 5u8, 15u8, 19u8, 5u8, 5u8, 5u8, 5u8,
+            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 50u8, 4u8, 4u8, 5u8, 4u8, 3u8, 3u8, 4u8, 5u8, 4u8,
+            4u8, 5u8, 4u8, 4u8, 5u8, 4u8, 4u8, 5u8, 4u8,
+        ])
+    },
+};
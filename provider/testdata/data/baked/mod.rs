@@ -3,14 +3,17 @@ mod calendar;
 mod collator;
 mod datetime;
 mod decimal;
+mod displaynames;
 mod fallback;
 mod list;
 mod locale_canonicalizer;
 mod normalizer;
 mod plurals;
 mod props;
+mod rbnf;
 mod segmenter;
 mod time_zone;
+mod units;
 /// This data provider was programmatically generated by [`icu_datagen`](
 /// https://unicode-org.github.io/icu4x-docs/doc/icu_datagen/enum.Out.html#variant.Module).
 #[non_exhaustive]
@@ -292,6 +295,43 @@ impl ResourceProvider<::icu_datetime::provider::time_zones::TimeZoneFormatsV1Mar
         Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (time_zone :: formats_v1 :: DATA , < :: icu_datetime :: provider :: time_zones :: TimeZoneFormatsV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
     }
 }
+impl ResourceProvider<::icu_datetime::provider::time_zones::TimeZoneIdentifiersV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<
+        DataResponse<::icu_datetime::provider::time_zones::TimeZoneIdentifiersV1Marker>,
+        DataError,
+    > {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (time_zone :: identifiers_v1 :: DATA , < :: icu_datetime :: provider :: time_zones :: TimeZoneIdentifiersV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_datetime::provider::time_zones::TimeZoneRegionNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<
+        DataResponse<::icu_datetime::provider::time_zones::TimeZoneRegionNamesV1Marker>,
+        DataError,
+    > {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (time_zone :: region_names_v1 :: DATA , < :: icu_datetime :: provider :: time_zones :: TimeZoneRegionNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_datetime::provider::time_zones::WindowsZonesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_datetime::provider::time_zones::WindowsZonesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (time_zone :: windows_zones_v1 :: DATA , < :: icu_datetime :: provider :: time_zones :: WindowsZonesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
 impl ResourceProvider<::icu_datetime::provider::week_data::WeekDataV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -310,6 +350,58 @@ impl ResourceProvider<::icu_datetime::provider::week_data::WeekDataV1Marker> for
         })
     }
 }
+impl ResourceProvider<::icu_decimal::provider::CurrencyDisplayNamesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::CurrencyDisplayNamesV1Marker>, DataError>
+    {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::currency_names_v1::DATA,
+                    <::icu_decimal::provider::CurrencyDisplayNamesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::CurrencyFractionsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::CurrencyFractionsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::currency_fractions_v1::DATA,
+                    <::icu_decimal::provider::CurrencyFractionsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::CurrencyPatternsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::CurrencyPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::currency_patterns_v1::DATA,
+                    <::icu_decimal::provider::CurrencyPatternsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 impl ResourceProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -327,6 +419,138 @@ impl ResourceProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for Baked
         })
     }
 }
+impl ResourceProvider<::icu_decimal::provider::LongCompactDecimalFormatDataV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<
+        DataResponse<::icu_decimal::provider::LongCompactDecimalFormatDataV1Marker>,
+        DataError,
+    > {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (decimal :: compact_long_v1 :: DATA , < :: icu_decimal :: provider :: LongCompactDecimalFormatDataV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::MiscPatternsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::MiscPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::misc_patterns_v1::DATA,
+                    <::icu_decimal::provider::MiscPatternsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::PercentPatternV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::PercentPatternV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::percent_v1::DATA,
+                    <::icu_decimal::provider::PercentPatternV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::ScientificSymbolsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_decimal::provider::ScientificSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    decimal::scientific_v1::DATA,
+                    <::icu_decimal::provider::ScientificSymbolsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<
+        DataResponse<::icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker>,
+        DataError,
+    > {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (decimal :: compact_short_v1 :: DATA , < :: icu_decimal :: provider :: ShortCompactDecimalFormatDataV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_displaynames::provider::LanguageDisplayNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_displaynames::provider::LanguageDisplayNamesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (displaynames :: languages_v1 :: DATA , < :: icu_displaynames :: provider :: LanguageDisplayNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_displaynames::provider::LocaleDisplayNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_displaynames::provider::LocaleDisplayNamesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (displaynames :: locales_v1 :: DATA , < :: icu_displaynames :: provider :: LocaleDisplayNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_displaynames::provider::RegionDisplayNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_displaynames::provider::RegionDisplayNamesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (displaynames :: regions_v1 :: DATA , < :: icu_displaynames :: provider :: RegionDisplayNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_displaynames::provider::ScriptDisplayNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_displaynames::provider::ScriptDisplayNamesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (displaynames :: scripts_v1 :: DATA , < :: icu_displaynames :: provider :: ScriptDisplayNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
+impl ResourceProvider<::icu_displaynames::provider::VariantDisplayNamesV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_displaynames::provider::VariantDisplayNamesV1Marker>, DataError>
+    {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (displaynames :: variants_v1 :: DATA , < :: icu_displaynames :: provider :: VariantDisplayNamesV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
 impl ResourceProvider<::icu_list::provider::AndListV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -396,6 +620,19 @@ impl ResourceProvider<::icu_locale_canonicalizer::provider::AliasesV1Marker> for
         })
     }
 }
+impl ResourceProvider<::icu_locale_canonicalizer::provider::LanguageMatchingV1Marker>
+    for BakedDataProvider
+{
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<
+        DataResponse<::icu_locale_canonicalizer::provider::LanguageMatchingV1Marker>,
+        DataError,
+    > {
+        Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (locale_canonicalizer :: matching_v1 :: DATA , < :: icu_locale_canonicalizer :: provider :: LanguageMatchingV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
+    }
+}
 impl ResourceProvider<::icu_locale_canonicalizer::provider::LikelySubtagsV1Marker>
     for BakedDataProvider
 {
@@ -522,6 +759,23 @@ impl ResourceProvider<::icu_normalizer::provider::Uts46DecompositionSupplementV1
         Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (normalizer :: uts46d_v1 :: DATA , < :: icu_normalizer :: provider :: Uts46DecompositionSupplementV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
     }
 }
+impl ResourceProvider<::icu_plurals::provider::CardinalSamplesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_plurals::provider::CardinalSamplesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    plurals::cardinal_samples_v1::DATA,
+                    <::icu_plurals::provider::CardinalSamplesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 impl ResourceProvider<::icu_plurals::provider::CardinalV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -539,6 +793,23 @@ impl ResourceProvider<::icu_plurals::provider::CardinalV1Marker> for BakedDataPr
         })
     }
 }
+impl ResourceProvider<::icu_plurals::provider::OrdinalSamplesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_plurals::provider::OrdinalSamplesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    plurals::ordinal_samples_v1::DATA,
+                    <::icu_plurals::provider::OrdinalSamplesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 impl ResourceProvider<::icu_plurals::provider::OrdinalV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -556,6 +827,23 @@ impl ResourceProvider<::icu_plurals::provider::OrdinalV1Marker> for BakedDataPro
         })
     }
 }
+impl ResourceProvider<::icu_plurals::provider::PluralRangesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_plurals::provider::PluralRangesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    plurals::ranges_v1::DATA,
+                    <::icu_plurals::provider::PluralRangesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 impl ResourceProvider<::icu_properties::provider::AlphabeticV1Marker> for BakedDataProvider {
     fn load_resource(
         &self,
@@ -1526,6 +1814,23 @@ impl ResourceProvider<::icu_provider_adapters::fallback::provider::LocaleFallbac
         Ok (DataResponse { metadata : Default :: default () , payload : Some (DataPayload :: from_owned (zerofrom :: ZeroFrom :: zero_from (litemap_slice_get (fallback :: parents_v1 :: DATA , < :: icu_provider_adapters :: fallback :: provider :: LocaleFallbackParentsV1Marker as ResourceMarker > :: KEY , req) ? ,))) , })
     }
 }
+impl ResourceProvider<::icu_rbnf::provider::RbnfRulesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_rbnf::provider::RbnfRulesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    rbnf::rules_v1::DATA,
+                    <::icu_rbnf::provider::RbnfRulesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 impl ResourceProvider<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker>
     for BakedDataProvider
 {
@@ -1588,6 +1893,91 @@ impl ResourceProvider<::icu_segmenter::provider::WordBreakDataV1Marker> for Bake
         })
     }
 }
+impl ResourceProvider<::icu_units::provider::LongUnitPatternsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_units::provider::LongUnitPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    units::long_v1::DATA,
+                    <::icu_units::provider::LongUnitPatternsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_units::provider::NarrowUnitPatternsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_units::provider::NarrowUnitPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    units::narrow_v1::DATA,
+                    <::icu_units::provider::NarrowUnitPatternsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_units::provider::ShortUnitPatternsV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_units::provider::ShortUnitPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    units::short_v1::DATA,
+                    <::icu_units::provider::ShortUnitPatternsV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_units::provider::UnitPreferencesV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_units::provider::UnitPreferencesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    units::preferences_v1::DATA,
+                    <::icu_units::provider::UnitPreferencesV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
+impl ResourceProvider<::icu_units::provider::UnitsConversionV1Marker> for BakedDataProvider {
+    fn load_resource(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<::icu_units::provider::UnitsConversionV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                litemap_slice_get(
+                    units::conversion_v1::DATA,
+                    <::icu_units::provider::UnitsConversionV1Marker as ResourceMarker>::KEY,
+                    req,
+                )?,
+            ))),
+        })
+    }
+}
 fn litemap_slice_get<T: ?Sized>(
     values: &'static [(&'static str, &'static T)],
     key: ResourceKey,
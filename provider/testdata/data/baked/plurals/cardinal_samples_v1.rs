@@ -0,0 +1,377 @@
+// @generated
+type DataStruct = & 'static < :: icu_plurals :: provider :: CardinalSamplesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR),
+    ("bn", BN),
+    ("en", EN),
+    ("es", ES),
+    ("fil", FIL),
+    ("fr", FR),
+    ("ja", JA_TH_UND),
+    ("ru", RU),
+    ("sr", SR),
+    ("th", JA_TH_UND),
+    ("tr", TR),
+    ("und", JA_TH_UND),
+];
+static AR: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 1u8, 2u8, 3u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8,
+                    0u8, 132u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 84u8, 1u8, 0u8, 0u8, 64u8,
+                    105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 32u8, 64u8, 100u8,
+                    101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8, 44u8, 32u8,
+                    48u8, 46u8, 48u8, 48u8, 44u8, 32u8, 48u8, 46u8, 48u8, 48u8, 48u8, 44u8, 32u8,
+                    48u8, 46u8, 48u8, 48u8, 48u8, 48u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8,
+                    101u8, 114u8, 32u8, 49u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8,
+                    108u8, 32u8, 49u8, 46u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 44u8, 32u8,
+                    49u8, 46u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8, 48u8,
+                    64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 50u8, 32u8, 64u8,
+                    100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 50u8, 46u8, 48u8, 44u8,
+                    32u8, 50u8, 46u8, 48u8, 48u8, 44u8, 32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 44u8,
+                    32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 48u8, 64u8, 105u8, 110u8, 116u8, 101u8,
+                    103u8, 101u8, 114u8, 32u8, 51u8, 126u8, 49u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    51u8, 126u8, 49u8, 49u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 51u8, 44u8, 32u8,
+                    226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8,
+                    32u8, 51u8, 46u8, 48u8, 44u8, 32u8, 52u8, 46u8, 48u8, 44u8, 32u8, 53u8, 46u8,
+                    48u8, 44u8, 32u8, 54u8, 46u8, 48u8, 44u8, 32u8, 55u8, 46u8, 48u8, 44u8, 32u8,
+                    56u8, 46u8, 48u8, 44u8, 32u8, 57u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 46u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 51u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    51u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8,
+                    101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 49u8, 126u8, 50u8, 54u8, 44u8, 32u8,
+                    49u8, 49u8, 49u8, 44u8, 32u8, 49u8, 48u8, 49u8, 49u8, 44u8, 32u8, 226u8, 128u8,
+                    166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 49u8,
+                    49u8, 46u8, 48u8, 44u8, 32u8, 49u8, 50u8, 46u8, 48u8, 44u8, 32u8, 49u8, 51u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 52u8, 46u8, 48u8, 44u8, 32u8, 49u8, 53u8, 46u8,
+                    48u8, 44u8, 32u8, 49u8, 54u8, 46u8, 48u8, 44u8, 32u8, 49u8, 55u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 56u8, 46u8, 48u8, 44u8, 32u8, 49u8, 49u8, 49u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 49u8, 49u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8,
+                    166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 48u8,
+                    48u8, 126u8, 49u8, 48u8, 50u8, 44u8, 32u8, 50u8, 48u8, 48u8, 126u8, 50u8, 48u8,
+                    50u8, 44u8, 32u8, 51u8, 48u8, 48u8, 126u8, 51u8, 48u8, 50u8, 44u8, 32u8, 52u8,
+                    48u8, 48u8, 126u8, 52u8, 48u8, 50u8, 44u8, 32u8, 53u8, 48u8, 48u8, 126u8, 53u8,
+                    48u8, 50u8, 44u8, 32u8, 54u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8,
+                    226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8,
+                    32u8, 48u8, 46u8, 49u8, 126u8, 48u8, 46u8, 57u8, 44u8, 32u8, 49u8, 46u8, 49u8,
+                    126u8, 49u8, 46u8, 55u8, 44u8, 32u8, 49u8, 48u8, 46u8, 49u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static BN: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 64u8, 105u8,
+                    110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 49u8, 32u8,
+                    64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8,
+                    126u8, 49u8, 46u8, 48u8, 44u8, 32u8, 48u8, 46u8, 48u8, 48u8, 126u8, 48u8, 46u8,
+                    48u8, 52u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 50u8,
+                    126u8, 49u8, 55u8, 44u8, 32u8, 49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8,
+                    32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8,
+                    108u8, 32u8, 49u8, 46u8, 49u8, 126u8, 50u8, 46u8, 54u8, 44u8, 32u8, 49u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8,
+                    166u8,
+                ])
+            },
+        )
+    },
+};
+static EN: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 64u8, 105u8,
+                    110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 64u8, 105u8, 110u8,
+                    116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 50u8, 126u8, 49u8,
+                    54u8, 44u8, 32u8, 49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8,
+                    128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8,
+                    48u8, 46u8, 48u8, 126u8, 49u8, 46u8, 53u8, 44u8, 32u8, 49u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static ES: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8,
+                    0u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 32u8,
+                    64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 49u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8, 48u8, 64u8, 105u8, 110u8, 116u8,
+                    101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 99u8, 54u8, 44u8, 32u8, 50u8, 99u8, 54u8, 44u8, 32u8, 51u8,
+                    99u8, 54u8, 44u8, 32u8, 52u8, 99u8, 54u8, 44u8, 32u8, 53u8, 99u8, 54u8, 44u8,
+                    32u8, 54u8, 99u8, 54u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8,
+                    101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 49u8, 99u8, 54u8, 44u8, 32u8, 49u8, 46u8, 49u8, 99u8, 54u8,
+                    44u8, 32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 49u8, 99u8, 54u8,
+                    44u8, 32u8, 50u8, 46u8, 49u8, 99u8, 54u8, 44u8, 32u8, 51u8, 46u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 49u8, 99u8, 54u8, 44u8, 32u8, 51u8, 46u8, 49u8, 99u8,
+                    54u8, 44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8,
+                    101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 50u8, 126u8, 49u8, 54u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8,
+                    49u8, 99u8, 51u8, 44u8, 32u8, 50u8, 99u8, 51u8, 44u8, 32u8, 51u8, 99u8, 51u8,
+                    44u8, 32u8, 52u8, 99u8, 51u8, 44u8, 32u8, 53u8, 99u8, 51u8, 44u8, 32u8, 54u8,
+                    99u8, 51u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8,
+                    105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8, 126u8, 48u8, 46u8, 57u8,
+                    44u8, 32u8, 49u8, 46u8, 49u8, 126u8, 49u8, 46u8, 54u8, 44u8, 32u8, 49u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8,
+                    48u8, 48u8, 49u8, 99u8, 51u8, 44u8, 32u8, 49u8, 46u8, 49u8, 99u8, 51u8, 44u8,
+                    32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 49u8, 99u8, 51u8, 44u8, 32u8, 50u8, 46u8,
+                    49u8, 99u8, 51u8, 44u8, 32u8, 51u8, 46u8, 48u8, 48u8, 48u8, 49u8, 99u8, 51u8,
+                    44u8, 32u8, 51u8, 46u8, 49u8, 99u8, 51u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 64u8, 105u8,
+                    110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 126u8, 51u8, 44u8, 32u8,
+                    53u8, 44u8, 32u8, 55u8, 44u8, 32u8, 56u8, 44u8, 32u8, 49u8, 48u8, 126u8, 49u8,
+                    51u8, 44u8, 32u8, 49u8, 53u8, 44u8, 32u8, 49u8, 55u8, 44u8, 32u8, 49u8, 56u8,
+                    44u8, 32u8, 50u8, 48u8, 44u8, 32u8, 50u8, 49u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8,
+                    101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8, 126u8, 48u8,
+                    46u8, 51u8, 44u8, 32u8, 48u8, 46u8, 53u8, 44u8, 32u8, 48u8, 46u8, 55u8, 44u8,
+                    32u8, 48u8, 46u8, 56u8, 44u8, 32u8, 49u8, 46u8, 48u8, 126u8, 49u8, 46u8, 51u8,
+                    44u8, 32u8, 49u8, 46u8, 53u8, 44u8, 32u8, 49u8, 46u8, 55u8, 44u8, 32u8, 49u8,
+                    46u8, 56u8, 44u8, 32u8, 50u8, 46u8, 48u8, 44u8, 32u8, 50u8, 46u8, 49u8, 44u8,
+                    32u8, 49u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8,
+                    226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8,
+                    32u8, 52u8, 44u8, 32u8, 54u8, 44u8, 32u8, 57u8, 44u8, 32u8, 49u8, 52u8, 44u8,
+                    32u8, 49u8, 54u8, 44u8, 32u8, 49u8, 57u8, 44u8, 32u8, 50u8, 52u8, 44u8, 32u8,
+                    50u8, 54u8, 44u8, 32u8, 49u8, 48u8, 52u8, 44u8, 32u8, 49u8, 48u8, 48u8, 52u8,
+                    44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8,
+                    97u8, 108u8, 32u8, 48u8, 46u8, 52u8, 44u8, 32u8, 48u8, 46u8, 54u8, 44u8, 32u8,
+                    48u8, 46u8, 57u8, 44u8, 32u8, 49u8, 46u8, 52u8, 44u8, 32u8, 49u8, 46u8, 54u8,
+                    44u8, 32u8, 49u8, 46u8, 57u8, 44u8, 32u8, 50u8, 46u8, 52u8, 44u8, 32u8, 50u8,
+                    46u8, 54u8, 44u8, 32u8, 49u8, 48u8, 46u8, 52u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    46u8, 52u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 52u8, 44u8, 32u8, 226u8,
+                    128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8,
+                    0u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 44u8,
+                    32u8, 49u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8,
+                    48u8, 46u8, 48u8, 126u8, 49u8, 46u8, 53u8, 64u8, 105u8, 110u8, 116u8, 101u8,
+                    103u8, 101u8, 114u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8,
+                    32u8, 49u8, 99u8, 54u8, 44u8, 32u8, 50u8, 99u8, 54u8, 44u8, 32u8, 51u8, 99u8,
+                    54u8, 44u8, 32u8, 52u8, 99u8, 54u8, 44u8, 32u8, 53u8, 99u8, 54u8, 44u8, 32u8,
+                    54u8, 99u8, 54u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 48u8, 49u8, 99u8, 54u8, 44u8, 32u8, 49u8, 46u8, 49u8, 99u8, 54u8, 44u8,
+                    32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 49u8, 99u8, 54u8, 44u8,
+                    32u8, 50u8, 46u8, 49u8, 99u8, 54u8, 44u8, 32u8, 51u8, 46u8, 48u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 49u8, 99u8, 54u8, 44u8, 32u8, 51u8, 46u8, 49u8, 99u8, 54u8,
+                    44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8,
+                    101u8, 114u8, 32u8, 50u8, 126u8, 49u8, 55u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 99u8, 51u8,
+                    44u8, 32u8, 50u8, 99u8, 51u8, 44u8, 32u8, 51u8, 99u8, 51u8, 44u8, 32u8, 52u8,
+                    99u8, 51u8, 44u8, 32u8, 53u8, 99u8, 51u8, 44u8, 32u8, 54u8, 99u8, 51u8, 44u8,
+                    32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8,
+                    108u8, 32u8, 50u8, 46u8, 48u8, 126u8, 51u8, 46u8, 53u8, 44u8, 32u8, 49u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8,
+                    48u8, 48u8, 49u8, 99u8, 51u8, 44u8, 32u8, 49u8, 46u8, 49u8, 99u8, 51u8, 44u8,
+                    32u8, 50u8, 46u8, 48u8, 48u8, 48u8, 49u8, 99u8, 51u8, 44u8, 32u8, 50u8, 46u8,
+                    49u8, 99u8, 51u8, 44u8, 32u8, 51u8, 46u8, 48u8, 48u8, 48u8, 49u8, 99u8, 51u8,
+                    44u8, 32u8, 51u8, 46u8, 49u8, 99u8, 51u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static JA_TH_UND: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 105u8, 110u8, 116u8, 101u8,
+                    103u8, 101u8, 114u8, 32u8, 48u8, 126u8, 49u8, 53u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8,
+                    100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8, 126u8,
+                    49u8, 46u8, 53u8, 44u8, 32u8, 49u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static RU: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 3u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8,
+                    0u8, 170u8, 0u8, 0u8, 0u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8,
+                    114u8, 32u8, 49u8, 44u8, 32u8, 50u8, 49u8, 44u8, 32u8, 51u8, 49u8, 44u8, 32u8,
+                    52u8, 49u8, 44u8, 32u8, 53u8, 49u8, 44u8, 32u8, 54u8, 49u8, 44u8, 32u8, 55u8,
+                    49u8, 44u8, 32u8, 56u8, 49u8, 44u8, 32u8, 49u8, 48u8, 49u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 49u8, 44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8,
+                    101u8, 103u8, 101u8, 114u8, 32u8, 50u8, 126u8, 52u8, 44u8, 32u8, 50u8, 50u8,
+                    126u8, 50u8, 52u8, 44u8, 32u8, 51u8, 50u8, 126u8, 51u8, 52u8, 44u8, 32u8, 52u8,
+                    50u8, 126u8, 52u8, 52u8, 44u8, 32u8, 53u8, 50u8, 126u8, 53u8, 52u8, 44u8, 32u8,
+                    54u8, 50u8, 44u8, 32u8, 49u8, 48u8, 50u8, 44u8, 32u8, 49u8, 48u8, 48u8, 50u8,
+                    44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8,
+                    101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 53u8, 126u8, 49u8, 57u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                    64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8,
+                    126u8, 49u8, 46u8, 53u8, 44u8, 32u8, 49u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static SR: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 3u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 16u8, 1u8, 0u8,
+                    0u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 44u8,
+                    32u8, 50u8, 49u8, 44u8, 32u8, 51u8, 49u8, 44u8, 32u8, 52u8, 49u8, 44u8, 32u8,
+                    53u8, 49u8, 44u8, 32u8, 54u8, 49u8, 44u8, 32u8, 55u8, 49u8, 44u8, 32u8, 56u8,
+                    49u8, 44u8, 32u8, 49u8, 48u8, 49u8, 44u8, 32u8, 49u8, 48u8, 48u8, 49u8, 44u8,
+                    32u8, 226u8, 128u8, 166u8, 32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8,
+                    108u8, 32u8, 48u8, 46u8, 49u8, 44u8, 32u8, 49u8, 46u8, 49u8, 44u8, 32u8, 50u8,
+                    46u8, 49u8, 44u8, 32u8, 51u8, 46u8, 49u8, 44u8, 32u8, 52u8, 46u8, 49u8, 44u8,
+                    32u8, 53u8, 46u8, 49u8, 44u8, 32u8, 54u8, 46u8, 49u8, 44u8, 32u8, 55u8, 46u8,
+                    49u8, 44u8, 32u8, 49u8, 48u8, 46u8, 49u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8,
+                    49u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 49u8, 44u8, 32u8, 226u8, 128u8,
+                    166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 50u8,
+                    126u8, 52u8, 44u8, 32u8, 50u8, 50u8, 126u8, 50u8, 52u8, 44u8, 32u8, 51u8, 50u8,
+                    126u8, 51u8, 52u8, 44u8, 32u8, 52u8, 50u8, 126u8, 52u8, 52u8, 44u8, 32u8, 53u8,
+                    50u8, 126u8, 53u8, 52u8, 44u8, 32u8, 54u8, 50u8, 44u8, 32u8, 49u8, 48u8, 50u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 50u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8,
+                    64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 50u8,
+                    126u8, 48u8, 46u8, 52u8, 44u8, 32u8, 49u8, 46u8, 50u8, 126u8, 49u8, 46u8, 52u8,
+                    44u8, 32u8, 50u8, 46u8, 50u8, 126u8, 50u8, 46u8, 52u8, 44u8, 32u8, 51u8, 46u8,
+                    50u8, 126u8, 51u8, 46u8, 52u8, 44u8, 32u8, 52u8, 46u8, 50u8, 126u8, 52u8, 46u8,
+                    52u8, 44u8, 32u8, 53u8, 46u8, 50u8, 44u8, 32u8, 49u8, 48u8, 46u8, 50u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 46u8, 50u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8,
+                    50u8, 44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8,
+                    101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 53u8, 126u8, 49u8, 57u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8,
+                    49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                    32u8, 64u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8,
+                    48u8, 44u8, 32u8, 48u8, 46u8, 53u8, 126u8, 49u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    46u8, 53u8, 126u8, 50u8, 46u8, 48u8, 44u8, 32u8, 50u8, 46u8, 53u8, 126u8, 50u8,
+                    46u8, 55u8, 44u8, 32u8, 49u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8,
+                    48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static TR: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 64u8, 105u8,
+                    110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 32u8, 64u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 49u8, 46u8, 48u8, 44u8, 32u8, 49u8,
+                    46u8, 48u8, 48u8, 44u8, 32u8, 49u8, 46u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8,
+                    46u8, 48u8, 48u8, 48u8, 48u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8,
+                    114u8, 32u8, 48u8, 44u8, 32u8, 50u8, 126u8, 49u8, 54u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8, 32u8, 64u8,
+                    100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 48u8, 46u8, 48u8, 126u8,
+                    48u8, 46u8, 57u8, 44u8, 32u8, 49u8, 46u8, 49u8, 126u8, 49u8, 46u8, 54u8, 44u8,
+                    32u8, 49u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 46u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 46u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 46u8, 48u8, 44u8, 32u8,
+                    226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
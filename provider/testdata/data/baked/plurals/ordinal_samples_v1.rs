@@ -0,0 +1,106 @@
+// @generated
+type DataStruct = & 'static < :: icu_plurals :: provider :: OrdinalSamplesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("bn", BN),
+    ("en", EN),
+    ("es", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("fil", FIL_FR),
+    ("fr", FIL_FR),
+    ("ja", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("ru", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("sr", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("th", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("tr", AR_ES_JA_RU_SR_TH_TR_UND),
+    ("und", AR_ES_JA_RU_SR_TH_TR_UND),
+];
+static AR_ES_JA_RU_SR_TH_TR_UND: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 105u8, 110u8, 116u8, 101u8,
+                    103u8, 101u8, 114u8, 32u8, 48u8, 126u8, 49u8, 53u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static BN: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 2u8, 3u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 64u8, 105u8, 110u8, 116u8,
+                    101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 44u8, 32u8, 53u8, 44u8, 32u8, 55u8,
+                    126u8, 49u8, 48u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8,
+                    50u8, 44u8, 32u8, 51u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8,
+                    32u8, 52u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 54u8,
+                    64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 44u8, 32u8,
+                    49u8, 49u8, 126u8, 50u8, 53u8, 44u8, 32u8, 49u8, 48u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static EN: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 2u8, 3u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8,
+                    0u8, 162u8, 0u8, 0u8, 0u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8,
+                    114u8, 32u8, 49u8, 44u8, 32u8, 50u8, 49u8, 44u8, 32u8, 51u8, 49u8, 44u8, 32u8,
+                    52u8, 49u8, 44u8, 32u8, 53u8, 49u8, 44u8, 32u8, 54u8, 49u8, 44u8, 32u8, 55u8,
+                    49u8, 44u8, 32u8, 56u8, 49u8, 44u8, 32u8, 49u8, 48u8, 49u8, 44u8, 32u8, 49u8,
+                    48u8, 48u8, 49u8, 44u8, 32u8, 226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8,
+                    101u8, 103u8, 101u8, 114u8, 32u8, 50u8, 44u8, 32u8, 50u8, 50u8, 44u8, 32u8,
+                    51u8, 50u8, 44u8, 32u8, 52u8, 50u8, 44u8, 32u8, 53u8, 50u8, 44u8, 32u8, 54u8,
+                    50u8, 44u8, 32u8, 55u8, 50u8, 44u8, 32u8, 56u8, 50u8, 44u8, 32u8, 49u8, 48u8,
+                    50u8, 44u8, 32u8, 49u8, 48u8, 48u8, 50u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                    64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 51u8, 44u8, 32u8,
+                    50u8, 51u8, 44u8, 32u8, 51u8, 51u8, 44u8, 32u8, 52u8, 51u8, 44u8, 32u8, 53u8,
+                    51u8, 44u8, 32u8, 54u8, 51u8, 44u8, 32u8, 55u8, 51u8, 44u8, 32u8, 56u8, 51u8,
+                    44u8, 32u8, 49u8, 48u8, 51u8, 44u8, 32u8, 49u8, 48u8, 48u8, 51u8, 44u8, 32u8,
+                    226u8, 128u8, 166u8, 64u8, 105u8, 110u8, 116u8, 101u8, 103u8, 101u8, 114u8,
+                    32u8, 48u8, 44u8, 32u8, 52u8, 126u8, 49u8, 56u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8,
+                    48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8, 128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static FIL_FR: DataStruct = &::icu_plurals::provider::PluralSamplesV1 {
+    samples: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 64u8, 105u8,
+                    110u8, 116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 49u8, 64u8, 105u8, 110u8,
+                    116u8, 101u8, 103u8, 101u8, 114u8, 32u8, 48u8, 44u8, 32u8, 50u8, 126u8, 49u8,
+                    54u8, 44u8, 32u8, 49u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 44u8,
+                    32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8,
+                    48u8, 44u8, 32u8, 49u8, 48u8, 48u8, 48u8, 48u8, 48u8, 48u8, 44u8, 32u8, 226u8,
+                    128u8, 166u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,42 @@
+// @generated
+type DataStruct = & 'static < :: icu_plurals :: provider :: PluralRangesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR),
+    ("bn", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("en", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("es", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("fil", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("fr", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("ja", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("ru", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("sr", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("th", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("tr", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+    ("und", BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND),
+];
+static AR: DataStruct = &::icu_plurals::provider::PluralRangesV1 {
+    ranges: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 1u8, 5u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 2u8, 2u8, 1u8, 2u8]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 5u8, 5u8, 5u8]) },
+        )
+    },
+};
+static BN_EN_ES_FIL_FR_JA_RU_SR_TH_TR_UND: DataStruct = &::icu_plurals::provider::PluralRangesV1 {
+    ranges: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
@@ -0,0 +1,327 @@
+// @generated
+type DataStruct =
+    &'static <::icu_rbnf::provider::RbnfRulesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: &[(&str, DataStruct)] = &[("en", EN), ("fr", FR), ("ja", JA)];
+static EN: DataStruct = &::icu_rbnf::provider::RbnfRulesV1 {
+    rules: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    23u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 37u8, 116u8,
+                    104u8, 37u8, 116u8, 105u8, 101u8, 116u8, 104u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 115u8,
+                    112u8, 101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 99u8, 97u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                    117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 105u8, 110u8,
+                    103u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 111u8,
+                    114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8,
+                    0u8, 45u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                    10u8, 0u8, 11u8, 0u8, 12u8, 0u8, 13u8, 0u8, 14u8, 0u8, 15u8, 0u8, 16u8, 0u8,
+                    17u8, 0u8, 18u8, 0u8, 19u8, 0u8, 20u8, 0u8, 21u8, 0u8, 22u8, 0u8, 23u8, 0u8,
+                    24u8, 0u8, 25u8, 0u8, 26u8, 0u8, 27u8, 0u8, 28u8, 0u8, 29u8, 0u8, 30u8, 0u8,
+                    31u8, 0u8, 32u8, 0u8, 33u8, 0u8, 34u8, 0u8, 35u8, 0u8, 36u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 5u8, 0u8, 6u8, 0u8,
+                    7u8, 0u8, 8u8, 0u8, 9u8, 0u8, 10u8, 0u8, 11u8, 0u8, 12u8, 0u8, 13u8, 0u8, 14u8,
+                    0u8, 15u8, 0u8, 16u8, 0u8, 17u8, 0u8, 18u8, 0u8, 19u8, 0u8, 20u8, 0u8, 21u8,
+                    0u8, 22u8, 0u8, 23u8, 0u8, 24u8, 0u8, 25u8, 0u8, 26u8, 0u8, 27u8, 0u8, 28u8,
+                    0u8, 29u8, 0u8, 30u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    76u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8,
+                    0u8, 78u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 205u8,
+                    0u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8, 23u8, 1u8, 0u8, 0u8,
+                    43u8, 1u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8, 85u8, 1u8, 0u8, 0u8, 106u8, 1u8, 0u8,
+                    0u8, 127u8, 1u8, 0u8, 0u8, 147u8, 1u8, 0u8, 0u8, 169u8, 1u8, 0u8, 0u8, 191u8,
+                    1u8, 0u8, 0u8, 212u8, 1u8, 0u8, 0u8, 232u8, 1u8, 0u8, 0u8, 255u8, 1u8, 0u8,
+                    0u8, 22u8, 2u8, 0u8, 0u8, 47u8, 2u8, 0u8, 0u8, 72u8, 2u8, 0u8, 0u8, 96u8, 2u8,
+                    0u8, 0u8, 120u8, 2u8, 0u8, 0u8, 146u8, 2u8, 0u8, 0u8, 171u8, 2u8, 0u8, 0u8,
+                    196u8, 2u8, 0u8, 0u8, 224u8, 2u8, 0u8, 0u8, 252u8, 2u8, 0u8, 0u8, 23u8, 3u8,
+                    0u8, 0u8, 50u8, 3u8, 0u8, 0u8, 77u8, 3u8, 0u8, 0u8, 106u8, 3u8, 0u8, 0u8,
+                    134u8, 3u8, 0u8, 0u8, 162u8, 3u8, 0u8, 0u8, 194u8, 3u8, 0u8, 0u8, 227u8, 3u8,
+                    0u8, 0u8, 3u8, 4u8, 0u8, 0u8, 35u8, 4u8, 0u8, 0u8, 68u8, 4u8, 0u8, 0u8, 104u8,
+                    4u8, 0u8, 0u8, 128u8, 4u8, 0u8, 0u8, 153u8, 4u8, 0u8, 0u8, 190u8, 4u8, 0u8,
+                    0u8, 215u8, 4u8, 0u8, 0u8, 241u8, 4u8, 0u8, 0u8, 8u8, 5u8, 0u8, 0u8, 30u8, 5u8,
+                    0u8, 0u8, 53u8, 5u8, 0u8, 0u8, 75u8, 5u8, 0u8, 0u8, 98u8, 5u8, 0u8, 0u8, 120u8,
+                    5u8, 0u8, 0u8, 142u8, 5u8, 0u8, 0u8, 166u8, 5u8, 0u8, 0u8, 189u8, 5u8, 0u8,
+                    0u8, 211u8, 5u8, 0u8, 0u8, 233u8, 5u8, 0u8, 0u8, 2u8, 6u8, 0u8, 0u8, 26u8, 6u8,
+                    0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 96u8, 6u8, 0u8, 0u8, 126u8, 6u8, 0u8, 0u8,
+                    155u8, 6u8, 0u8, 0u8, 184u8, 6u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 244u8, 6u8,
+                    0u8, 0u8, 18u8, 7u8, 0u8, 0u8, 48u8, 7u8, 0u8, 0u8, 100u8, 7u8, 0u8, 0u8,
+                    153u8, 7u8, 0u8, 0u8, 205u8, 7u8, 0u8, 0u8, 1u8, 8u8, 0u8, 0u8, 54u8, 8u8, 0u8,
+                    0u8, 110u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 61u8, 37u8, 115u8,
+                    112u8, 101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 111u8, 114u8, 100u8,
+                    105u8, 110u8, 97u8, 108u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 105u8, 101u8, 116u8, 104u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    116u8, 121u8, 45u8, 61u8, 37u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                    117u8, 116u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 61u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    226u8, 136u8, 146u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+                    61u8, 36u8, 40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8,
+                    110u8, 101u8, 123u8, 115u8, 116u8, 125u8, 116u8, 119u8, 111u8, 123u8, 110u8,
+                    100u8, 125u8, 102u8, 101u8, 119u8, 123u8, 114u8, 100u8, 125u8, 111u8, 116u8,
+                    104u8, 101u8, 114u8, 123u8, 116u8, 104u8, 125u8, 41u8, 36u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8,
+                    105u8, 110u8, 117u8, 115u8, 32u8, 62u8, 62u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 60u8, 32u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 32u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 122u8, 101u8, 114u8,
+                    111u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 111u8, 110u8, 101u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 119u8, 111u8, 0u8, 3u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    116u8, 104u8, 114u8, 101u8, 101u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 111u8, 117u8, 114u8, 0u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    102u8, 105u8, 118u8, 101u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 105u8, 120u8, 0u8, 7u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 101u8,
+                    118u8, 101u8, 110u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 105u8, 103u8, 104u8, 116u8, 0u8, 9u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 110u8,
+                    105u8, 110u8, 101u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 101u8, 110u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 108u8,
+                    101u8, 118u8, 101u8, 110u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 119u8, 101u8, 108u8, 118u8, 101u8,
+                    0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 116u8, 104u8, 105u8, 114u8, 116u8, 101u8, 101u8, 110u8, 0u8, 14u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    102u8, 111u8, 117u8, 114u8, 116u8, 101u8, 101u8, 110u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 105u8,
+                    102u8, 116u8, 101u8, 101u8, 110u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 105u8, 120u8, 116u8,
+                    101u8, 101u8, 110u8, 0u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 101u8, 118u8, 101u8, 110u8, 116u8, 101u8,
+                    101u8, 110u8, 0u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 105u8, 103u8, 104u8, 116u8, 101u8, 101u8,
+                    110u8, 0u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 110u8, 105u8, 110u8, 101u8, 116u8, 101u8, 101u8, 110u8, 0u8,
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 116u8, 119u8, 101u8, 110u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8, 93u8,
+                    0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 116u8, 104u8, 105u8, 114u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8,
+                    93u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 102u8, 111u8, 114u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8, 93u8,
+                    0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 102u8, 105u8, 102u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8, 93u8, 0u8,
+                    60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 115u8, 105u8, 120u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8, 93u8, 0u8,
+                    70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 115u8, 101u8, 118u8, 101u8, 110u8, 116u8, 121u8, 91u8, 45u8, 62u8, 62u8,
+                    93u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 101u8, 105u8, 103u8, 104u8, 116u8, 121u8, 91u8, 45u8, 62u8,
+                    62u8, 93u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 110u8, 105u8, 110u8, 101u8, 116u8, 121u8, 91u8, 45u8, 62u8,
+                    62u8, 93u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 60u8, 32u8, 104u8, 117u8, 110u8, 100u8, 114u8,
+                    101u8, 100u8, 91u8, 32u8, 62u8, 62u8, 93u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 60u8, 32u8,
+                    116u8, 104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 91u8, 32u8, 62u8, 62u8,
+                    93u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 66u8, 15u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 60u8, 60u8, 32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 111u8,
+                    110u8, 91u8, 32u8, 62u8, 62u8, 93u8, 0u8, 0u8, 202u8, 154u8, 59u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 60u8, 60u8, 32u8, 98u8,
+                    105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 91u8, 32u8, 62u8, 62u8, 93u8, 0u8,
+                    0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8,
+                    0u8, 0u8, 0u8, 60u8, 60u8, 32u8, 116u8, 114u8, 105u8, 108u8, 108u8, 105u8,
+                    111u8, 110u8, 91u8, 32u8, 62u8, 62u8, 93u8, 0u8, 0u8, 128u8, 198u8, 164u8,
+                    126u8, 141u8, 3u8, 0u8, 0u8, 128u8, 198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 60u8,
+                    60u8, 32u8, 113u8, 117u8, 97u8, 100u8, 114u8, 105u8, 108u8, 108u8, 105u8,
+                    111u8, 110u8, 91u8, 32u8, 62u8, 62u8, 93u8, 0u8, 0u8, 0u8, 100u8, 167u8, 179u8,
+                    182u8, 224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 61u8,
+                    35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 105u8, 110u8, 117u8, 115u8,
+                    32u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                    117u8, 116u8, 45u8, 99u8, 97u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 61u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 109u8, 105u8, 110u8, 117u8, 115u8, 32u8, 62u8, 62u8, 2u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+                    44u8, 35u8, 35u8, 48u8, 46u8, 35u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 122u8, 101u8, 114u8, 111u8,
+                    116u8, 104u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 102u8, 105u8, 114u8, 115u8, 116u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 114u8, 100u8, 0u8, 4u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8,
+                    111u8, 117u8, 114u8, 116u8, 104u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 105u8, 102u8, 116u8, 104u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    115u8, 105u8, 120u8, 116u8, 104u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 101u8, 118u8, 101u8, 110u8,
+                    116u8, 104u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 101u8, 105u8, 103u8, 104u8, 116u8, 104u8, 0u8, 9u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 110u8,
+                    105u8, 110u8, 116u8, 104u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 101u8, 110u8, 116u8, 104u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 101u8, 108u8, 101u8, 118u8, 101u8, 110u8, 116u8, 104u8, 0u8, 12u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8,
+                    119u8, 101u8, 108u8, 102u8, 116u8, 104u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 115u8, 112u8,
+                    101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8,
+                    101u8, 114u8, 105u8, 110u8, 103u8, 61u8, 116u8, 104u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 119u8,
+                    101u8, 110u8, 62u8, 37u8, 37u8, 116u8, 105u8, 101u8, 116u8, 104u8, 62u8, 0u8,
+                    30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 116u8, 104u8, 105u8, 114u8, 62u8, 37u8, 37u8, 116u8, 105u8, 101u8, 116u8,
+                    104u8, 62u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 102u8, 111u8, 114u8, 62u8, 37u8, 37u8, 116u8, 105u8, 101u8,
+                    116u8, 104u8, 62u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 105u8, 102u8, 62u8, 37u8, 37u8, 116u8,
+                    105u8, 101u8, 116u8, 104u8, 62u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 105u8, 120u8, 62u8, 37u8, 37u8,
+                    116u8, 105u8, 101u8, 116u8, 104u8, 62u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 101u8, 118u8, 101u8,
+                    110u8, 62u8, 37u8, 37u8, 116u8, 105u8, 101u8, 116u8, 104u8, 62u8, 0u8, 80u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    101u8, 105u8, 103u8, 104u8, 62u8, 37u8, 37u8, 116u8, 105u8, 101u8, 116u8,
+                    104u8, 62u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 110u8, 105u8, 110u8, 101u8, 62u8, 37u8, 37u8, 116u8, 105u8,
+                    101u8, 116u8, 104u8, 62u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 37u8, 115u8, 112u8, 101u8,
+                    108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8,
+                    114u8, 105u8, 110u8, 103u8, 60u8, 32u8, 104u8, 117u8, 110u8, 100u8, 114u8,
+                    101u8, 100u8, 62u8, 37u8, 37u8, 116u8, 104u8, 62u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 37u8,
+                    115u8, 112u8, 101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 110u8, 117u8,
+                    109u8, 98u8, 101u8, 114u8, 105u8, 110u8, 103u8, 60u8, 32u8, 116u8, 104u8,
+                    111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 62u8, 37u8, 37u8, 116u8, 104u8, 62u8,
+                    0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 60u8, 37u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8, 117u8,
+                    116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 105u8, 110u8, 103u8,
+                    60u8, 32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 62u8, 37u8, 37u8,
+                    116u8, 104u8, 62u8, 0u8, 0u8, 202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 60u8, 37u8, 115u8, 112u8, 101u8, 108u8,
+                    108u8, 111u8, 117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8,
+                    105u8, 110u8, 103u8, 60u8, 32u8, 98u8, 105u8, 108u8, 108u8, 105u8, 111u8,
+                    110u8, 62u8, 37u8, 37u8, 116u8, 104u8, 62u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                    232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 60u8,
+                    37u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8, 117u8, 116u8, 45u8, 110u8,
+                    117u8, 109u8, 98u8, 101u8, 114u8, 105u8, 110u8, 103u8, 60u8, 32u8, 116u8,
+                    114u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 62u8, 37u8, 37u8, 116u8,
+                    104u8, 62u8, 0u8, 0u8, 128u8, 198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 0u8, 128u8,
+                    198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 60u8, 37u8, 115u8, 112u8, 101u8, 108u8,
+                    108u8, 111u8, 117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8,
+                    105u8, 110u8, 103u8, 60u8, 32u8, 113u8, 117u8, 97u8, 100u8, 114u8, 105u8,
+                    108u8, 108u8, 105u8, 111u8, 110u8, 62u8, 37u8, 37u8, 116u8, 104u8, 62u8, 0u8,
+                    0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 0u8, 0u8, 100u8, 167u8,
+                    179u8, 182u8, 224u8, 13u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_rbnf::provider::RbnfRulesV1 {
+    rules: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8,
+                    0u8, 67u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 100u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8,
+                    97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8, 105u8, 110u8, 101u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8,
+                    110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8, 105u8, 110u8,
+                    101u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8, 108u8, 100u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                    45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 100u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8,
+                    97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8,
+                    45u8, 112u8, 108u8, 117u8, 114u8, 97u8, 108u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 66u8, 0u8, 0u8,
+                    0u8, 117u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8, 0u8, 214u8,
+                    0u8, 0u8, 0u8, 9u8, 1u8, 0u8, 0u8, 31u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8,
+                    110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8,
+                    101u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8,
+                    35u8, 35u8, 48u8, 61u8, 36u8, 40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8,
+                    108u8, 44u8, 111u8, 110u8, 101u8, 123u8, 114u8, 101u8, 125u8, 111u8, 116u8,
+                    104u8, 101u8, 114u8, 123u8, 101u8, 125u8, 41u8, 36u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8,
+                    146u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 36u8, 40u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8, 110u8, 101u8,
+                    123u8, 114u8, 101u8, 115u8, 125u8, 111u8, 116u8, 104u8, 101u8, 114u8, 123u8,
+                    101u8, 115u8, 125u8, 41u8, 36u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 62u8, 62u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 36u8, 40u8, 111u8, 114u8, 100u8,
+                    105u8, 110u8, 97u8, 108u8, 44u8, 111u8, 110u8, 101u8, 123u8, 101u8, 114u8,
+                    125u8, 111u8, 116u8, 104u8, 101u8, 114u8, 123u8, 101u8, 125u8, 41u8, 36u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    226u8, 136u8, 146u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+                    61u8, 36u8, 40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8,
+                    110u8, 101u8, 123u8, 101u8, 114u8, 115u8, 125u8, 111u8, 116u8, 104u8, 101u8,
+                    114u8, 123u8, 101u8, 115u8, 125u8, 41u8, 36u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_rbnf::provider::RbnfRulesV1 {
+    rules: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[2u8, 0u8, 0u8, 0u8]) },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 1u8, 0u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                    136u8, 146u8, 62u8, 62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 172u8, 172u8, 61u8, 35u8, 44u8, 35u8,
+                    35u8, 48u8, 61u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,1224 @@
+// @generated
+type DataStruct = & 'static < :: icu_datetime :: provider :: time_zones :: TimeZoneIdentifiersV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("und", UND)];
+static UND: DataStruct = &::icu_datetime::provider::time_zones::TimeZoneIdentifiersV1 {
+    bcp47_to_iana: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    97u8, 100u8, 97u8, 108u8, 118u8, 0u8, 0u8, 0u8, 97u8, 101u8, 100u8, 120u8,
+                    98u8, 0u8, 0u8, 0u8, 97u8, 102u8, 107u8, 98u8, 108u8, 0u8, 0u8, 0u8, 97u8,
+                    103u8, 97u8, 110u8, 117u8, 0u8, 0u8, 0u8, 97u8, 105u8, 97u8, 120u8, 97u8, 0u8,
+                    0u8, 0u8, 97u8, 108u8, 116u8, 105u8, 97u8, 0u8, 0u8, 0u8, 97u8, 109u8, 101u8,
+                    118u8, 110u8, 0u8, 0u8, 0u8, 97u8, 110u8, 99u8, 117u8, 114u8, 0u8, 0u8, 0u8,
+                    97u8, 111u8, 108u8, 97u8, 100u8, 0u8, 0u8, 0u8, 97u8, 113u8, 99u8, 97u8, 115u8,
+                    0u8, 0u8, 0u8, 97u8, 113u8, 100u8, 97u8, 118u8, 0u8, 0u8, 0u8, 97u8, 113u8,
+                    100u8, 100u8, 117u8, 0u8, 0u8, 0u8, 97u8, 113u8, 109u8, 97u8, 119u8, 0u8, 0u8,
+                    0u8, 97u8, 113u8, 109u8, 99u8, 109u8, 0u8, 0u8, 0u8, 97u8, 113u8, 112u8, 108u8,
+                    109u8, 0u8, 0u8, 0u8, 97u8, 113u8, 114u8, 111u8, 116u8, 0u8, 0u8, 0u8, 97u8,
+                    113u8, 115u8, 121u8, 119u8, 0u8, 0u8, 0u8, 97u8, 113u8, 116u8, 114u8, 108u8,
+                    0u8, 0u8, 0u8, 97u8, 113u8, 118u8, 111u8, 115u8, 0u8, 0u8, 0u8, 97u8, 114u8,
+                    98u8, 117u8, 101u8, 0u8, 0u8, 0u8, 97u8, 114u8, 99u8, 111u8, 114u8, 0u8, 0u8,
+                    0u8, 97u8, 114u8, 99u8, 116u8, 99u8, 0u8, 0u8, 0u8, 97u8, 114u8, 105u8, 114u8,
+                    106u8, 0u8, 0u8, 0u8, 97u8, 114u8, 106u8, 117u8, 106u8, 0u8, 0u8, 0u8, 97u8,
+                    114u8, 108u8, 117u8, 113u8, 0u8, 0u8, 0u8, 97u8, 114u8, 109u8, 100u8, 122u8,
+                    0u8, 0u8, 0u8, 97u8, 114u8, 114u8, 103u8, 108u8, 0u8, 0u8, 0u8, 97u8, 114u8,
+                    115u8, 108u8, 97u8, 0u8, 0u8, 0u8, 97u8, 114u8, 116u8, 117u8, 99u8, 0u8, 0u8,
+                    0u8, 97u8, 114u8, 117u8, 97u8, 113u8, 0u8, 0u8, 0u8, 97u8, 114u8, 117u8, 115u8,
+                    104u8, 0u8, 0u8, 0u8, 97u8, 115u8, 112u8, 112u8, 103u8, 0u8, 0u8, 0u8, 97u8,
+                    116u8, 118u8, 105u8, 101u8, 0u8, 0u8, 0u8, 97u8, 117u8, 97u8, 100u8, 108u8,
+                    0u8, 0u8, 0u8, 97u8, 117u8, 98u8, 104u8, 113u8, 0u8, 0u8, 0u8, 97u8, 117u8,
+                    98u8, 110u8, 101u8, 0u8, 0u8, 0u8, 97u8, 117u8, 100u8, 114u8, 119u8, 0u8, 0u8,
+                    0u8, 97u8, 117u8, 101u8, 117u8, 99u8, 0u8, 0u8, 0u8, 97u8, 117u8, 104u8, 98u8,
+                    97u8, 0u8, 0u8, 0u8, 97u8, 117u8, 107u8, 110u8, 115u8, 0u8, 0u8, 0u8, 97u8,
+                    117u8, 108u8, 100u8, 99u8, 0u8, 0u8, 0u8, 97u8, 117u8, 108u8, 100u8, 104u8,
+                    0u8, 0u8, 0u8, 97u8, 117u8, 109u8, 101u8, 108u8, 0u8, 0u8, 0u8, 97u8, 117u8,
+                    109u8, 113u8, 105u8, 0u8, 0u8, 0u8, 97u8, 117u8, 112u8, 101u8, 114u8, 0u8, 0u8,
+                    0u8, 97u8, 117u8, 115u8, 121u8, 100u8, 0u8, 0u8, 0u8, 97u8, 119u8, 97u8, 117u8,
+                    97u8, 0u8, 0u8, 0u8, 97u8, 122u8, 98u8, 97u8, 107u8, 0u8, 0u8, 0u8, 98u8, 97u8,
+                    115u8, 106u8, 106u8, 0u8, 0u8, 0u8, 98u8, 98u8, 98u8, 103u8, 105u8, 0u8, 0u8,
+                    0u8, 98u8, 100u8, 100u8, 97u8, 99u8, 0u8, 0u8, 0u8, 98u8, 101u8, 98u8, 114u8,
+                    117u8, 0u8, 0u8, 0u8, 98u8, 102u8, 111u8, 117u8, 97u8, 0u8, 0u8, 0u8, 98u8,
+                    103u8, 115u8, 111u8, 102u8, 0u8, 0u8, 0u8, 98u8, 104u8, 98u8, 97u8, 104u8, 0u8,
+                    0u8, 0u8, 98u8, 105u8, 98u8, 106u8, 109u8, 0u8, 0u8, 0u8, 98u8, 106u8, 112u8,
+                    116u8, 110u8, 0u8, 0u8, 0u8, 98u8, 109u8, 98u8, 100u8, 97u8, 0u8, 0u8, 0u8,
+                    98u8, 110u8, 98u8, 119u8, 110u8, 0u8, 0u8, 0u8, 98u8, 111u8, 108u8, 112u8,
+                    98u8, 0u8, 0u8, 0u8, 98u8, 113u8, 107u8, 114u8, 97u8, 0u8, 0u8, 0u8, 98u8,
+                    114u8, 97u8, 117u8, 120u8, 0u8, 0u8, 0u8, 98u8, 114u8, 98u8, 101u8, 108u8, 0u8,
+                    0u8, 0u8, 98u8, 114u8, 98u8, 118u8, 98u8, 0u8, 0u8, 0u8, 98u8, 114u8, 99u8,
+                    103u8, 98u8, 0u8, 0u8, 0u8, 98u8, 114u8, 99u8, 103u8, 114u8, 0u8, 0u8, 0u8,
+                    98u8, 114u8, 101u8, 114u8, 110u8, 0u8, 0u8, 0u8, 98u8, 114u8, 102u8, 101u8,
+                    110u8, 0u8, 0u8, 0u8, 98u8, 114u8, 102u8, 111u8, 114u8, 0u8, 0u8, 0u8, 98u8,
+                    114u8, 109u8, 97u8, 111u8, 0u8, 0u8, 0u8, 98u8, 114u8, 109u8, 99u8, 122u8, 0u8,
+                    0u8, 0u8, 98u8, 114u8, 112u8, 118u8, 104u8, 0u8, 0u8, 0u8, 98u8, 114u8, 114u8,
+                    98u8, 114u8, 0u8, 0u8, 0u8, 98u8, 114u8, 114u8, 101u8, 99u8, 0u8, 0u8, 0u8,
+                    98u8, 114u8, 115u8, 97u8, 111u8, 0u8, 0u8, 0u8, 98u8, 114u8, 115u8, 115u8,
+                    97u8, 0u8, 0u8, 0u8, 98u8, 114u8, 115u8, 116u8, 109u8, 0u8, 0u8, 0u8, 98u8,
+                    115u8, 110u8, 97u8, 115u8, 0u8, 0u8, 0u8, 98u8, 116u8, 116u8, 104u8, 105u8,
+                    0u8, 0u8, 0u8, 98u8, 119u8, 103u8, 98u8, 101u8, 0u8, 0u8, 0u8, 98u8, 121u8,
+                    109u8, 115u8, 113u8, 0u8, 0u8, 0u8, 98u8, 122u8, 98u8, 122u8, 101u8, 0u8, 0u8,
+                    0u8, 99u8, 97u8, 99u8, 102u8, 113u8, 0u8, 0u8, 0u8, 99u8, 97u8, 101u8, 100u8,
+                    109u8, 0u8, 0u8, 0u8, 99u8, 97u8, 102u8, 102u8, 115u8, 0u8, 0u8, 0u8, 99u8,
+                    97u8, 102u8, 110u8, 101u8, 0u8, 0u8, 0u8, 99u8, 97u8, 103u8, 108u8, 98u8, 0u8,
+                    0u8, 0u8, 99u8, 97u8, 103u8, 111u8, 111u8, 0u8, 0u8, 0u8, 99u8, 97u8, 104u8,
+                    97u8, 108u8, 0u8, 0u8, 0u8, 99u8, 97u8, 105u8, 113u8, 108u8, 0u8, 0u8, 0u8,
+                    99u8, 97u8, 109u8, 111u8, 110u8, 0u8, 0u8, 0u8, 99u8, 97u8, 109u8, 116u8,
+                    114u8, 0u8, 0u8, 0u8, 99u8, 97u8, 110u8, 112u8, 103u8, 0u8, 0u8, 0u8, 99u8,
+                    97u8, 112u8, 110u8, 116u8, 0u8, 0u8, 0u8, 99u8, 97u8, 114u8, 101u8, 98u8, 0u8,
+                    0u8, 0u8, 99u8, 97u8, 114u8, 101u8, 103u8, 0u8, 0u8, 0u8, 99u8, 97u8, 115u8,
+                    106u8, 102u8, 0u8, 0u8, 0u8, 99u8, 97u8, 116u8, 104u8, 117u8, 0u8, 0u8, 0u8,
+                    99u8, 97u8, 116u8, 111u8, 114u8, 0u8, 0u8, 0u8, 99u8, 97u8, 118u8, 97u8, 110u8,
+                    0u8, 0u8, 0u8, 99u8, 97u8, 119u8, 110u8, 112u8, 0u8, 0u8, 0u8, 99u8, 97u8,
+                    121u8, 98u8, 120u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8, 99u8, 98u8, 0u8, 0u8,
+                    0u8, 99u8, 97u8, 121u8, 100u8, 97u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8, 100u8,
+                    113u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8, 101u8, 107u8, 0u8, 0u8, 0u8, 99u8,
+                    97u8, 121u8, 101u8, 118u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8, 120u8, 121u8, 0u8,
+                    0u8, 0u8, 99u8, 97u8, 121u8, 121u8, 110u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8,
+                    122u8, 102u8, 0u8, 0u8, 0u8, 99u8, 97u8, 121u8, 122u8, 115u8, 0u8, 0u8, 0u8,
+                    99u8, 99u8, 99u8, 99u8, 107u8, 0u8, 0u8, 0u8, 99u8, 100u8, 102u8, 98u8, 109u8,
+                    0u8, 0u8, 0u8, 99u8, 100u8, 102u8, 105u8, 104u8, 0u8, 0u8, 0u8, 99u8, 102u8,
+                    98u8, 103u8, 102u8, 0u8, 0u8, 0u8, 99u8, 103u8, 98u8, 122u8, 118u8, 0u8, 0u8,
+                    0u8, 99u8, 104u8, 122u8, 114u8, 104u8, 0u8, 0u8, 0u8, 99u8, 105u8, 97u8, 98u8,
+                    106u8, 0u8, 0u8, 0u8, 99u8, 107u8, 114u8, 97u8, 114u8, 0u8, 0u8, 0u8, 99u8,
+                    108u8, 105u8, 112u8, 99u8, 0u8, 0u8, 0u8, 99u8, 108u8, 112u8, 117u8, 113u8,
+                    0u8, 0u8, 0u8, 99u8, 108u8, 115u8, 99u8, 108u8, 0u8, 0u8, 0u8, 99u8, 109u8,
+                    100u8, 108u8, 97u8, 0u8, 0u8, 0u8, 99u8, 110u8, 115u8, 104u8, 97u8, 0u8, 0u8,
+                    0u8, 99u8, 110u8, 117u8, 114u8, 99u8, 0u8, 0u8, 0u8, 99u8, 111u8, 98u8, 111u8,
+                    103u8, 0u8, 0u8, 0u8, 99u8, 114u8, 115u8, 106u8, 111u8, 0u8, 0u8, 0u8, 99u8,
+                    115u8, 116u8, 54u8, 99u8, 100u8, 116u8, 0u8, 99u8, 117u8, 104u8, 97u8, 118u8,
+                    0u8, 0u8, 0u8, 99u8, 118u8, 114u8, 97u8, 105u8, 0u8, 0u8, 0u8, 99u8, 120u8,
+                    120u8, 99u8, 104u8, 0u8, 0u8, 0u8, 99u8, 121u8, 102u8, 109u8, 103u8, 0u8, 0u8,
+                    0u8, 99u8, 121u8, 110u8, 105u8, 99u8, 0u8, 0u8, 0u8, 99u8, 122u8, 112u8, 114u8,
+                    103u8, 0u8, 0u8, 0u8, 100u8, 101u8, 98u8, 101u8, 114u8, 0u8, 0u8, 0u8, 100u8,
+                    101u8, 98u8, 115u8, 110u8, 103u8, 110u8, 0u8, 100u8, 106u8, 106u8, 105u8, 98u8,
+                    0u8, 0u8, 0u8, 100u8, 107u8, 99u8, 112u8, 104u8, 0u8, 0u8, 0u8, 100u8, 109u8,
+                    100u8, 111u8, 109u8, 0u8, 0u8, 0u8, 100u8, 111u8, 115u8, 100u8, 113u8, 0u8,
+                    0u8, 0u8, 100u8, 122u8, 97u8, 108u8, 103u8, 0u8, 0u8, 0u8, 101u8, 99u8, 103u8,
+                    112u8, 115u8, 0u8, 0u8, 0u8, 101u8, 99u8, 103u8, 121u8, 101u8, 0u8, 0u8, 0u8,
+                    101u8, 101u8, 116u8, 108u8, 108u8, 0u8, 0u8, 0u8, 101u8, 103u8, 99u8, 97u8,
+                    105u8, 0u8, 0u8, 0u8, 101u8, 104u8, 101u8, 97u8, 105u8, 0u8, 0u8, 0u8, 101u8,
+                    114u8, 97u8, 115u8, 109u8, 0u8, 0u8, 0u8, 101u8, 115u8, 99u8, 101u8, 117u8,
+                    0u8, 0u8, 0u8, 101u8, 115u8, 108u8, 112u8, 97u8, 0u8, 0u8, 0u8, 101u8, 115u8,
+                    109u8, 97u8, 100u8, 0u8, 0u8, 0u8, 101u8, 115u8, 116u8, 53u8, 101u8, 100u8,
+                    116u8, 0u8, 101u8, 116u8, 97u8, 100u8, 100u8, 0u8, 0u8, 0u8, 102u8, 105u8,
+                    104u8, 101u8, 108u8, 0u8, 0u8, 0u8, 102u8, 105u8, 109u8, 104u8, 113u8, 0u8,
+                    0u8, 0u8, 102u8, 106u8, 115u8, 117u8, 118u8, 0u8, 0u8, 0u8, 102u8, 107u8,
+                    112u8, 115u8, 121u8, 0u8, 0u8, 0u8, 102u8, 109u8, 107u8, 115u8, 97u8, 0u8, 0u8,
+                    0u8, 102u8, 109u8, 112u8, 110u8, 105u8, 0u8, 0u8, 0u8, 102u8, 109u8, 116u8,
+                    107u8, 107u8, 0u8, 0u8, 0u8, 102u8, 111u8, 116u8, 104u8, 111u8, 0u8, 0u8, 0u8,
+                    102u8, 114u8, 112u8, 97u8, 114u8, 0u8, 0u8, 0u8, 103u8, 97u8, 108u8, 98u8,
+                    118u8, 0u8, 0u8, 0u8, 103u8, 97u8, 122u8, 97u8, 115u8, 116u8, 114u8, 112u8,
+                    103u8, 98u8, 108u8, 111u8, 110u8, 0u8, 0u8, 0u8, 103u8, 100u8, 103u8, 110u8,
+                    100u8, 0u8, 0u8, 0u8, 103u8, 101u8, 116u8, 98u8, 115u8, 0u8, 0u8, 0u8, 103u8,
+                    102u8, 99u8, 97u8, 121u8, 0u8, 0u8, 0u8, 103u8, 103u8, 103u8, 99u8, 105u8, 0u8,
+                    0u8, 0u8, 103u8, 104u8, 97u8, 99u8, 99u8, 0u8, 0u8, 0u8, 103u8, 105u8, 103u8,
+                    105u8, 98u8, 0u8, 0u8, 0u8, 103u8, 108u8, 100u8, 107u8, 115u8, 104u8, 118u8,
+                    110u8, 103u8, 108u8, 103u8, 111u8, 104u8, 0u8, 0u8, 0u8, 103u8, 108u8, 111u8,
+                    98u8, 121u8, 0u8, 0u8, 0u8, 103u8, 108u8, 116u8, 104u8, 117u8, 0u8, 0u8, 0u8,
+                    103u8, 109u8, 98u8, 106u8, 108u8, 0u8, 0u8, 0u8, 103u8, 109u8, 116u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 103u8, 110u8, 99u8, 107u8, 121u8, 0u8, 0u8, 0u8, 103u8, 112u8,
+                    98u8, 98u8, 114u8, 0u8, 0u8, 0u8, 103u8, 112u8, 109u8, 115u8, 98u8, 0u8, 0u8,
+                    0u8, 103u8, 112u8, 115u8, 98u8, 104u8, 0u8, 0u8, 0u8, 103u8, 113u8, 115u8,
+                    115u8, 103u8, 0u8, 0u8, 0u8, 103u8, 114u8, 97u8, 116u8, 104u8, 0u8, 0u8, 0u8,
+                    103u8, 115u8, 103u8, 114u8, 118u8, 0u8, 0u8, 0u8, 103u8, 116u8, 103u8, 117u8,
+                    97u8, 0u8, 0u8, 0u8, 103u8, 117u8, 103u8, 117u8, 109u8, 0u8, 0u8, 0u8, 103u8,
+                    119u8, 111u8, 120u8, 98u8, 0u8, 0u8, 0u8, 103u8, 121u8, 103u8, 101u8, 111u8,
+                    0u8, 0u8, 0u8, 104u8, 101u8, 98u8, 114u8, 111u8, 110u8, 0u8, 0u8, 104u8, 107u8,
+                    104u8, 107u8, 103u8, 0u8, 0u8, 0u8, 104u8, 110u8, 116u8, 103u8, 117u8, 0u8,
+                    0u8, 0u8, 104u8, 114u8, 122u8, 97u8, 103u8, 0u8, 0u8, 0u8, 104u8, 116u8, 112u8,
+                    97u8, 112u8, 0u8, 0u8, 0u8, 104u8, 117u8, 98u8, 117u8, 100u8, 0u8, 0u8, 0u8,
+                    105u8, 100u8, 100u8, 106u8, 106u8, 0u8, 0u8, 0u8, 105u8, 100u8, 106u8, 107u8,
+                    116u8, 0u8, 0u8, 0u8, 105u8, 100u8, 109u8, 97u8, 107u8, 0u8, 0u8, 0u8, 105u8,
+                    100u8, 112u8, 110u8, 107u8, 0u8, 0u8, 0u8, 105u8, 101u8, 100u8, 117u8, 98u8,
+                    0u8, 0u8, 0u8, 105u8, 109u8, 100u8, 103u8, 115u8, 0u8, 0u8, 0u8, 105u8, 110u8,
+                    99u8, 99u8, 117u8, 0u8, 0u8, 0u8, 105u8, 111u8, 100u8, 103u8, 97u8, 0u8, 0u8,
+                    0u8, 105u8, 113u8, 98u8, 103u8, 119u8, 0u8, 0u8, 0u8, 105u8, 114u8, 116u8,
+                    104u8, 114u8, 0u8, 0u8, 0u8, 105u8, 115u8, 114u8, 101u8, 121u8, 0u8, 0u8, 0u8,
+                    105u8, 116u8, 114u8, 111u8, 109u8, 0u8, 0u8, 0u8, 106u8, 101u8, 114u8, 117u8,
+                    115u8, 108u8, 109u8, 0u8, 106u8, 101u8, 115u8, 116u8, 104u8, 0u8, 0u8, 0u8,
+                    106u8, 109u8, 107u8, 105u8, 110u8, 0u8, 0u8, 0u8, 106u8, 111u8, 97u8, 109u8,
+                    109u8, 0u8, 0u8, 0u8, 106u8, 112u8, 116u8, 121u8, 111u8, 0u8, 0u8, 0u8, 107u8,
+                    101u8, 110u8, 98u8, 111u8, 0u8, 0u8, 0u8, 107u8, 103u8, 102u8, 114u8, 117u8,
+                    0u8, 0u8, 0u8, 107u8, 104u8, 112u8, 110u8, 104u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    99u8, 120u8, 105u8, 0u8, 0u8, 0u8, 107u8, 105u8, 112u8, 104u8, 111u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 116u8, 114u8, 119u8, 0u8, 0u8, 0u8, 107u8, 109u8, 121u8,
+                    118u8, 97u8, 0u8, 0u8, 0u8, 107u8, 110u8, 98u8, 97u8, 115u8, 0u8, 0u8, 0u8,
+                    107u8, 112u8, 102u8, 110u8, 106u8, 0u8, 0u8, 0u8, 107u8, 114u8, 115u8, 101u8,
+                    108u8, 0u8, 0u8, 0u8, 107u8, 119u8, 107u8, 119u8, 105u8, 0u8, 0u8, 0u8, 107u8,
+                    121u8, 103u8, 101u8, 99u8, 0u8, 0u8, 0u8, 107u8, 122u8, 97u8, 97u8, 117u8, 0u8,
+                    0u8, 0u8, 107u8, 122u8, 97u8, 107u8, 120u8, 0u8, 0u8, 0u8, 107u8, 122u8, 97u8,
+                    108u8, 97u8, 0u8, 0u8, 0u8, 107u8, 122u8, 103u8, 117u8, 119u8, 0u8, 0u8, 0u8,
+                    107u8, 122u8, 107u8, 115u8, 110u8, 0u8, 0u8, 0u8, 107u8, 122u8, 107u8, 122u8,
+                    111u8, 0u8, 0u8, 0u8, 107u8, 122u8, 117u8, 114u8, 97u8, 0u8, 0u8, 0u8, 108u8,
+                    97u8, 118u8, 116u8, 101u8, 0u8, 0u8, 0u8, 108u8, 98u8, 98u8, 101u8, 121u8, 0u8,
+                    0u8, 0u8, 108u8, 99u8, 99u8, 97u8, 115u8, 0u8, 0u8, 0u8, 108u8, 105u8, 118u8,
+                    100u8, 122u8, 0u8, 0u8, 0u8, 108u8, 107u8, 99u8, 109u8, 98u8, 0u8, 0u8, 0u8,
+                    108u8, 114u8, 109u8, 108u8, 119u8, 0u8, 0u8, 0u8, 108u8, 115u8, 109u8, 115u8,
+                    117u8, 0u8, 0u8, 0u8, 108u8, 116u8, 118u8, 110u8, 111u8, 0u8, 0u8, 0u8, 108u8,
+                    117u8, 108u8, 117u8, 120u8, 0u8, 0u8, 0u8, 108u8, 118u8, 114u8, 105u8, 120u8,
+                    0u8, 0u8, 0u8, 108u8, 121u8, 116u8, 105u8, 112u8, 0u8, 0u8, 0u8, 109u8, 97u8,
+                    99u8, 97u8, 115u8, 0u8, 0u8, 0u8, 109u8, 99u8, 109u8, 111u8, 110u8, 0u8, 0u8,
+                    0u8, 109u8, 100u8, 107u8, 105u8, 118u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8,
+                    103u8, 100u8, 0u8, 0u8, 0u8, 109u8, 103u8, 116u8, 110u8, 114u8, 0u8, 0u8, 0u8,
+                    109u8, 104u8, 107u8, 119u8, 97u8, 0u8, 0u8, 0u8, 109u8, 104u8, 109u8, 97u8,
+                    106u8, 0u8, 0u8, 0u8, 109u8, 107u8, 115u8, 107u8, 112u8, 0u8, 0u8, 0u8, 109u8,
+                    108u8, 98u8, 107u8, 111u8, 0u8, 0u8, 0u8, 109u8, 109u8, 114u8, 103u8, 110u8,
+                    0u8, 0u8, 0u8, 109u8, 110u8, 99u8, 111u8, 113u8, 0u8, 0u8, 0u8, 109u8, 110u8,
+                    104u8, 118u8, 100u8, 0u8, 0u8, 0u8, 109u8, 110u8, 117u8, 108u8, 110u8, 0u8,
+                    0u8, 0u8, 109u8, 111u8, 109u8, 102u8, 109u8, 0u8, 0u8, 0u8, 109u8, 112u8,
+                    115u8, 112u8, 110u8, 0u8, 0u8, 0u8, 109u8, 113u8, 102u8, 100u8, 102u8, 0u8,
+                    0u8, 0u8, 109u8, 114u8, 110u8, 107u8, 99u8, 0u8, 0u8, 0u8, 109u8, 115u8, 109u8,
+                    110u8, 105u8, 0u8, 0u8, 0u8, 109u8, 115u8, 116u8, 55u8, 109u8, 100u8, 116u8,
+                    0u8, 109u8, 116u8, 109u8, 108u8, 97u8, 0u8, 0u8, 0u8, 109u8, 117u8, 112u8,
+                    108u8, 117u8, 0u8, 0u8, 0u8, 109u8, 118u8, 109u8, 108u8, 101u8, 0u8, 0u8, 0u8,
+                    109u8, 119u8, 98u8, 108u8, 122u8, 0u8, 0u8, 0u8, 109u8, 120u8, 99u8, 104u8,
+                    105u8, 0u8, 0u8, 0u8, 109u8, 120u8, 99u8, 117u8, 110u8, 0u8, 0u8, 0u8, 109u8,
+                    120u8, 104u8, 109u8, 111u8, 0u8, 0u8, 0u8, 109u8, 120u8, 109u8, 97u8, 109u8,
+                    0u8, 0u8, 0u8, 109u8, 120u8, 109u8, 101u8, 120u8, 0u8, 0u8, 0u8, 109u8, 120u8,
+                    109u8, 105u8, 100u8, 0u8, 0u8, 0u8, 109u8, 120u8, 109u8, 116u8, 121u8, 0u8,
+                    0u8, 0u8, 109u8, 120u8, 109u8, 122u8, 116u8, 0u8, 0u8, 0u8, 109u8, 120u8,
+                    111u8, 106u8, 105u8, 0u8, 0u8, 0u8, 109u8, 120u8, 112u8, 118u8, 114u8, 0u8,
+                    0u8, 0u8, 109u8, 120u8, 115u8, 116u8, 105u8, 115u8, 0u8, 0u8, 109u8, 120u8,
+                    116u8, 105u8, 106u8, 0u8, 0u8, 0u8, 109u8, 121u8, 107u8, 99u8, 104u8, 0u8, 0u8,
+                    0u8, 109u8, 121u8, 107u8, 117u8, 108u8, 0u8, 0u8, 0u8, 109u8, 122u8, 109u8,
+                    112u8, 109u8, 0u8, 0u8, 0u8, 110u8, 97u8, 119u8, 100u8, 104u8, 0u8, 0u8, 0u8,
+                    110u8, 99u8, 110u8, 111u8, 117u8, 0u8, 0u8, 0u8, 110u8, 101u8, 110u8, 105u8,
+                    109u8, 0u8, 0u8, 0u8, 110u8, 102u8, 110u8, 108u8, 107u8, 0u8, 0u8, 0u8, 110u8,
+                    103u8, 108u8, 111u8, 115u8, 0u8, 0u8, 0u8, 110u8, 105u8, 109u8, 103u8, 97u8,
+                    0u8, 0u8, 0u8, 110u8, 108u8, 97u8, 109u8, 115u8, 0u8, 0u8, 0u8, 110u8, 111u8,
+                    111u8, 115u8, 108u8, 0u8, 0u8, 0u8, 110u8, 112u8, 107u8, 116u8, 109u8, 0u8,
+                    0u8, 0u8, 110u8, 114u8, 105u8, 110u8, 117u8, 0u8, 0u8, 0u8, 110u8, 117u8,
+                    105u8, 117u8, 101u8, 0u8, 0u8, 0u8, 110u8, 122u8, 97u8, 107u8, 108u8, 0u8, 0u8,
+                    0u8, 110u8, 122u8, 99u8, 104u8, 116u8, 0u8, 0u8, 0u8, 111u8, 109u8, 109u8,
+                    99u8, 116u8, 0u8, 0u8, 0u8, 112u8, 97u8, 112u8, 116u8, 121u8, 0u8, 0u8, 0u8,
+                    112u8, 101u8, 108u8, 105u8, 109u8, 0u8, 0u8, 0u8, 112u8, 102u8, 103u8, 109u8,
+                    114u8, 0u8, 0u8, 0u8, 112u8, 102u8, 110u8, 104u8, 118u8, 0u8, 0u8, 0u8, 112u8,
+                    102u8, 112u8, 112u8, 116u8, 0u8, 0u8, 0u8, 112u8, 103u8, 112u8, 111u8, 109u8,
+                    0u8, 0u8, 0u8, 112u8, 103u8, 114u8, 97u8, 119u8, 0u8, 0u8, 0u8, 112u8, 104u8,
+                    109u8, 110u8, 108u8, 0u8, 0u8, 0u8, 112u8, 107u8, 107u8, 104u8, 105u8, 0u8,
+                    0u8, 0u8, 112u8, 108u8, 119u8, 97u8, 119u8, 0u8, 0u8, 0u8, 112u8, 109u8, 109u8,
+                    113u8, 99u8, 0u8, 0u8, 0u8, 112u8, 110u8, 112u8, 99u8, 110u8, 0u8, 0u8, 0u8,
+                    112u8, 114u8, 115u8, 106u8, 117u8, 0u8, 0u8, 0u8, 112u8, 115u8, 116u8, 56u8,
+                    112u8, 100u8, 116u8, 0u8, 112u8, 116u8, 102u8, 110u8, 99u8, 0u8, 0u8, 0u8,
+                    112u8, 116u8, 108u8, 105u8, 115u8, 0u8, 0u8, 0u8, 112u8, 116u8, 112u8, 100u8,
+                    108u8, 0u8, 0u8, 0u8, 112u8, 119u8, 114u8, 111u8, 114u8, 0u8, 0u8, 0u8, 112u8,
+                    121u8, 97u8, 115u8, 117u8, 0u8, 0u8, 0u8, 113u8, 97u8, 100u8, 111u8, 104u8,
+                    0u8, 0u8, 0u8, 114u8, 101u8, 114u8, 101u8, 117u8, 0u8, 0u8, 0u8, 114u8, 111u8,
+                    98u8, 117u8, 104u8, 0u8, 0u8, 0u8, 114u8, 115u8, 98u8, 101u8, 103u8, 0u8, 0u8,
+                    0u8, 114u8, 117u8, 97u8, 115u8, 102u8, 0u8, 0u8, 0u8, 114u8, 117u8, 98u8, 97u8,
+                    120u8, 0u8, 0u8, 0u8, 114u8, 117u8, 99u8, 104u8, 105u8, 116u8, 97u8, 0u8,
+                    114u8, 117u8, 100u8, 121u8, 114u8, 0u8, 0u8, 0u8, 114u8, 117u8, 103u8, 100u8,
+                    120u8, 0u8, 0u8, 0u8, 114u8, 117u8, 105u8, 107u8, 116u8, 0u8, 0u8, 0u8, 114u8,
+                    117u8, 107u8, 103u8, 100u8, 0u8, 0u8, 0u8, 114u8, 117u8, 107u8, 104u8, 110u8,
+                    100u8, 103u8, 0u8, 114u8, 117u8, 107u8, 114u8, 97u8, 0u8, 0u8, 0u8, 114u8,
+                    117u8, 107u8, 117u8, 102u8, 0u8, 0u8, 0u8, 114u8, 117u8, 107u8, 118u8, 120u8,
+                    0u8, 0u8, 0u8, 114u8, 117u8, 109u8, 111u8, 119u8, 0u8, 0u8, 0u8, 114u8, 117u8,
+                    110u8, 111u8, 122u8, 0u8, 0u8, 0u8, 114u8, 117u8, 111u8, 109u8, 115u8, 0u8,
+                    0u8, 0u8, 114u8, 117u8, 111u8, 118u8, 98u8, 0u8, 0u8, 0u8, 114u8, 117u8, 112u8,
+                    107u8, 99u8, 0u8, 0u8, 0u8, 114u8, 117u8, 114u8, 116u8, 119u8, 0u8, 0u8, 0u8,
+                    114u8, 117u8, 115u8, 114u8, 101u8, 100u8, 0u8, 0u8, 114u8, 117u8, 116u8, 111u8,
+                    102u8, 0u8, 0u8, 0u8, 114u8, 117u8, 117u8, 108u8, 121u8, 0u8, 0u8, 0u8, 114u8,
+                    117u8, 117u8, 110u8, 101u8, 114u8, 97u8, 0u8, 114u8, 117u8, 117u8, 117u8,
+                    115u8, 0u8, 0u8, 0u8, 114u8, 117u8, 118u8, 111u8, 103u8, 0u8, 0u8, 0u8, 114u8,
+                    117u8, 118u8, 118u8, 111u8, 0u8, 0u8, 0u8, 114u8, 117u8, 121u8, 101u8, 107u8,
+                    0u8, 0u8, 0u8, 114u8, 117u8, 121u8, 107u8, 115u8, 0u8, 0u8, 0u8, 114u8, 119u8,
+                    107u8, 103u8, 108u8, 0u8, 0u8, 0u8, 115u8, 97u8, 114u8, 117u8, 104u8, 0u8, 0u8,
+                    0u8, 115u8, 98u8, 104u8, 105u8, 114u8, 0u8, 0u8, 0u8, 115u8, 99u8, 109u8, 97u8,
+                    119u8, 0u8, 0u8, 0u8, 115u8, 100u8, 107u8, 114u8, 116u8, 0u8, 0u8, 0u8, 115u8,
+                    101u8, 115u8, 116u8, 111u8, 0u8, 0u8, 0u8, 115u8, 103u8, 115u8, 105u8, 110u8,
+                    0u8, 0u8, 0u8, 115u8, 104u8, 115u8, 104u8, 110u8, 0u8, 0u8, 0u8, 115u8, 105u8,
+                    108u8, 106u8, 117u8, 0u8, 0u8, 0u8, 115u8, 106u8, 108u8, 121u8, 114u8, 0u8,
+                    0u8, 0u8, 115u8, 107u8, 98u8, 116u8, 115u8, 0u8, 0u8, 0u8, 115u8, 108u8, 102u8,
+                    110u8, 97u8, 0u8, 0u8, 0u8, 115u8, 109u8, 115u8, 97u8, 105u8, 0u8, 0u8, 0u8,
+                    115u8, 110u8, 100u8, 107u8, 114u8, 0u8, 0u8, 0u8, 115u8, 111u8, 109u8, 103u8,
+                    113u8, 0u8, 0u8, 0u8, 115u8, 114u8, 112u8, 98u8, 109u8, 0u8, 0u8, 0u8, 115u8,
+                    115u8, 106u8, 117u8, 98u8, 0u8, 0u8, 0u8, 115u8, 116u8, 116u8, 109u8, 115u8,
+                    0u8, 0u8, 0u8, 115u8, 118u8, 115u8, 97u8, 108u8, 0u8, 0u8, 0u8, 115u8, 120u8,
+                    112u8, 104u8, 105u8, 0u8, 0u8, 0u8, 115u8, 121u8, 100u8, 97u8, 109u8, 0u8, 0u8,
+                    0u8, 115u8, 122u8, 113u8, 109u8, 110u8, 0u8, 0u8, 0u8, 116u8, 99u8, 103u8,
+                    100u8, 116u8, 0u8, 0u8, 0u8, 116u8, 100u8, 110u8, 100u8, 106u8, 0u8, 0u8, 0u8,
+                    116u8, 102u8, 112u8, 102u8, 114u8, 0u8, 0u8, 0u8, 116u8, 103u8, 108u8, 102u8,
+                    119u8, 0u8, 0u8, 0u8, 116u8, 104u8, 98u8, 107u8, 107u8, 0u8, 0u8, 0u8, 116u8,
+                    106u8, 100u8, 121u8, 117u8, 0u8, 0u8, 0u8, 116u8, 107u8, 102u8, 107u8, 111u8,
+                    0u8, 0u8, 0u8, 116u8, 108u8, 100u8, 105u8, 108u8, 0u8, 0u8, 0u8, 116u8, 109u8,
+                    97u8, 115u8, 98u8, 0u8, 0u8, 0u8, 116u8, 110u8, 116u8, 117u8, 110u8, 0u8, 0u8,
+                    0u8, 116u8, 111u8, 116u8, 98u8, 117u8, 0u8, 0u8, 0u8, 116u8, 114u8, 105u8,
+                    115u8, 116u8, 0u8, 0u8, 0u8, 116u8, 116u8, 112u8, 111u8, 115u8, 0u8, 0u8, 0u8,
+                    116u8, 118u8, 102u8, 117u8, 110u8, 0u8, 0u8, 0u8, 116u8, 119u8, 116u8, 112u8,
+                    101u8, 0u8, 0u8, 0u8, 116u8, 122u8, 100u8, 97u8, 114u8, 0u8, 0u8, 0u8, 117u8,
+                    97u8, 105u8, 101u8, 118u8, 0u8, 0u8, 0u8, 117u8, 97u8, 111u8, 122u8, 104u8,
+                    0u8, 0u8, 0u8, 117u8, 97u8, 115u8, 105u8, 112u8, 0u8, 0u8, 0u8, 117u8, 97u8,
+                    117u8, 122u8, 104u8, 0u8, 0u8, 0u8, 117u8, 103u8, 107u8, 108u8, 97u8, 0u8, 0u8,
+                    0u8, 117u8, 109u8, 97u8, 119u8, 107u8, 0u8, 0u8, 0u8, 117u8, 109u8, 106u8,
+                    111u8, 110u8, 0u8, 0u8, 0u8, 117u8, 109u8, 109u8, 100u8, 121u8, 0u8, 0u8, 0u8,
+                    117u8, 110u8, 107u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 115u8, 97u8, 100u8, 107u8,
+                    0u8, 0u8, 0u8, 117u8, 115u8, 97u8, 101u8, 103u8, 0u8, 0u8, 0u8, 117u8, 115u8,
+                    97u8, 110u8, 99u8, 0u8, 0u8, 0u8, 117u8, 115u8, 98u8, 111u8, 105u8, 0u8, 0u8,
+                    0u8, 117u8, 115u8, 99u8, 104u8, 105u8, 0u8, 0u8, 0u8, 117u8, 115u8, 100u8,
+                    101u8, 110u8, 0u8, 0u8, 0u8, 117u8, 115u8, 100u8, 101u8, 116u8, 0u8, 0u8, 0u8,
+                    117u8, 115u8, 104u8, 110u8, 108u8, 0u8, 0u8, 0u8, 117u8, 115u8, 105u8, 110u8,
+                    100u8, 0u8, 0u8, 0u8, 117u8, 115u8, 105u8, 110u8, 118u8, 101u8, 118u8, 0u8,
+                    117u8, 115u8, 106u8, 110u8, 117u8, 0u8, 0u8, 0u8, 117u8, 115u8, 107u8, 110u8,
+                    120u8, 0u8, 0u8, 0u8, 117u8, 115u8, 108u8, 97u8, 120u8, 0u8, 0u8, 0u8, 117u8,
+                    115u8, 108u8, 117u8, 105u8, 0u8, 0u8, 0u8, 117u8, 115u8, 109u8, 110u8, 109u8,
+                    0u8, 0u8, 0u8, 117u8, 115u8, 109u8, 111u8, 99u8, 0u8, 0u8, 0u8, 117u8, 115u8,
+                    109u8, 116u8, 109u8, 0u8, 0u8, 0u8, 117u8, 115u8, 110u8, 100u8, 99u8, 110u8,
+                    116u8, 0u8, 117u8, 115u8, 110u8, 100u8, 110u8, 115u8, 108u8, 0u8, 117u8, 115u8,
+                    110u8, 121u8, 99u8, 0u8, 0u8, 0u8, 117u8, 115u8, 111u8, 101u8, 97u8, 0u8, 0u8,
+                    0u8, 117u8, 115u8, 111u8, 109u8, 101u8, 0u8, 0u8, 0u8, 117u8, 115u8, 112u8,
+                    104u8, 120u8, 0u8, 0u8, 0u8, 117u8, 115u8, 115u8, 105u8, 116u8, 0u8, 0u8, 0u8,
+                    117u8, 115u8, 116u8, 101u8, 108u8, 0u8, 0u8, 0u8, 117u8, 115u8, 119u8, 108u8,
+                    122u8, 0u8, 0u8, 0u8, 117u8, 115u8, 119u8, 115u8, 113u8, 0u8, 0u8, 0u8, 117u8,
+                    115u8, 120u8, 117u8, 108u8, 0u8, 0u8, 0u8, 117u8, 115u8, 121u8, 97u8, 107u8,
+                    0u8, 0u8, 0u8, 117u8, 116u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 116u8, 99u8,
+                    101u8, 48u8, 49u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 48u8, 50u8, 0u8, 0u8,
+                    117u8, 116u8, 99u8, 101u8, 48u8, 51u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8,
+                    48u8, 52u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 48u8, 53u8, 0u8, 0u8, 117u8,
+                    116u8, 99u8, 101u8, 48u8, 54u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 48u8,
+                    55u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 48u8, 56u8, 0u8, 0u8, 117u8, 116u8,
+                    99u8, 101u8, 48u8, 57u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 49u8, 48u8, 0u8,
+                    0u8, 117u8, 116u8, 99u8, 101u8, 49u8, 49u8, 0u8, 0u8, 117u8, 116u8, 99u8,
+                    101u8, 49u8, 50u8, 0u8, 0u8, 117u8, 116u8, 99u8, 101u8, 49u8, 51u8, 0u8, 0u8,
+                    117u8, 116u8, 99u8, 101u8, 49u8, 52u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8,
+                    48u8, 49u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8, 48u8, 50u8, 0u8, 0u8, 117u8,
+                    116u8, 99u8, 119u8, 48u8, 51u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8, 48u8,
+                    52u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8, 48u8, 53u8, 0u8, 0u8, 117u8, 116u8,
+                    99u8, 119u8, 48u8, 54u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8, 48u8, 55u8, 0u8,
+                    0u8, 117u8, 116u8, 99u8, 119u8, 48u8, 56u8, 0u8, 0u8, 117u8, 116u8, 99u8,
+                    119u8, 48u8, 57u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8, 49u8, 48u8, 0u8, 0u8,
+                    117u8, 116u8, 99u8, 119u8, 49u8, 49u8, 0u8, 0u8, 117u8, 116u8, 99u8, 119u8,
+                    49u8, 50u8, 0u8, 0u8, 117u8, 121u8, 109u8, 118u8, 100u8, 0u8, 0u8, 0u8, 117u8,
+                    122u8, 115u8, 107u8, 100u8, 0u8, 0u8, 0u8, 117u8, 122u8, 116u8, 97u8, 115u8,
+                    0u8, 0u8, 0u8, 118u8, 97u8, 118u8, 97u8, 116u8, 0u8, 0u8, 0u8, 118u8, 99u8,
+                    115u8, 118u8, 100u8, 0u8, 0u8, 0u8, 118u8, 101u8, 99u8, 99u8, 115u8, 0u8, 0u8,
+                    0u8, 118u8, 103u8, 116u8, 111u8, 118u8, 0u8, 0u8, 0u8, 118u8, 105u8, 115u8,
+                    116u8, 116u8, 0u8, 0u8, 0u8, 118u8, 110u8, 115u8, 103u8, 110u8, 0u8, 0u8, 0u8,
+                    118u8, 117u8, 118u8, 108u8, 105u8, 0u8, 0u8, 0u8, 119u8, 102u8, 109u8, 97u8,
+                    117u8, 0u8, 0u8, 0u8, 119u8, 115u8, 97u8, 112u8, 119u8, 0u8, 0u8, 0u8, 121u8,
+                    101u8, 97u8, 100u8, 101u8, 0u8, 0u8, 0u8, 121u8, 116u8, 109u8, 97u8, 109u8,
+                    0u8, 0u8, 0u8, 122u8, 97u8, 106u8, 110u8, 98u8, 0u8, 0u8, 0u8, 122u8, 109u8,
+                    108u8, 117u8, 110u8, 0u8, 0u8, 0u8, 122u8, 119u8, 104u8, 114u8, 101u8, 0u8,
+                    0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    205u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 34u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 78u8, 0u8,
+                    0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8,
+                    134u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 192u8, 0u8,
+                    0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 245u8, 0u8, 0u8, 0u8,
+                    5u8, 1u8, 0u8, 0u8, 21u8, 1u8, 0u8, 0u8, 38u8, 1u8, 0u8, 0u8, 58u8, 1u8, 0u8,
+                    0u8, 73u8, 1u8, 0u8, 0u8, 90u8, 1u8, 0u8, 0u8, 116u8, 1u8, 0u8, 0u8, 129u8,
+                    1u8, 0u8, 0u8, 155u8, 1u8, 0u8, 0u8, 170u8, 1u8, 0u8, 0u8, 200u8, 1u8, 0u8,
+                    0u8, 223u8, 1u8, 0u8, 0u8, 248u8, 1u8, 0u8, 0u8, 18u8, 2u8, 0u8, 0u8, 43u8,
+                    2u8, 0u8, 0u8, 60u8, 2u8, 0u8, 0u8, 73u8, 2u8, 0u8, 0u8, 91u8, 2u8, 0u8, 0u8,
+                    112u8, 2u8, 0u8, 0u8, 130u8, 2u8, 0u8, 0u8, 146u8, 2u8, 0u8, 0u8, 161u8, 2u8,
+                    0u8, 0u8, 177u8, 2u8, 0u8, 0u8, 193u8, 2u8, 0u8, 0u8, 211u8, 2u8, 0u8, 0u8,
+                    230u8, 2u8, 0u8, 0u8, 249u8, 2u8, 0u8, 0u8, 13u8, 3u8, 0u8, 0u8, 28u8, 3u8,
+                    0u8, 0u8, 44u8, 3u8, 0u8, 0u8, 57u8, 3u8, 0u8, 0u8, 66u8, 3u8, 0u8, 0u8, 81u8,
+                    3u8, 0u8, 0u8, 97u8, 3u8, 0u8, 0u8, 107u8, 3u8, 0u8, 0u8, 122u8, 3u8, 0u8, 0u8,
+                    140u8, 3u8, 0u8, 0u8, 152u8, 3u8, 0u8, 0u8, 164u8, 3u8, 0u8, 0u8, 180u8, 3u8,
+                    0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 213u8, 3u8, 0u8, 0u8, 224u8, 3u8, 0u8, 0u8,
+                    238u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 17u8, 4u8, 0u8, 0u8, 30u8, 4u8, 0u8,
+                    0u8, 47u8, 4u8, 0u8, 0u8, 61u8, 4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8, 97u8, 4u8,
+                    0u8, 0u8, 112u8, 4u8, 0u8, 0u8, 129u8, 4u8, 0u8, 0u8, 143u8, 4u8, 0u8, 0u8,
+                    157u8, 4u8, 0u8, 0u8, 176u8, 4u8, 0u8, 0u8, 194u8, 4u8, 0u8, 0u8, 208u8, 4u8,
+                    0u8, 0u8, 225u8, 4u8, 0u8, 0u8, 238u8, 4u8, 0u8, 0u8, 254u8, 4u8, 0u8, 0u8,
+                    12u8, 5u8, 0u8, 0u8, 24u8, 5u8, 0u8, 0u8, 39u8, 5u8, 0u8, 0u8, 51u8, 5u8, 0u8,
+                    0u8, 65u8, 5u8, 0u8, 0u8, 80u8, 5u8, 0u8, 0u8, 96u8, 5u8, 0u8, 0u8, 115u8, 5u8,
+                    0u8, 0u8, 134u8, 5u8, 0u8, 0u8, 151u8, 5u8, 0u8, 0u8, 168u8, 5u8, 0u8, 0u8,
+                    183u8, 5u8, 0u8, 0u8, 198u8, 5u8, 0u8, 0u8, 213u8, 5u8, 0u8, 0u8, 229u8, 5u8,
+                    0u8, 0u8, 244u8, 5u8, 0u8, 0u8, 7u8, 6u8, 0u8, 0u8, 23u8, 6u8, 0u8, 0u8, 37u8,
+                    6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 72u8, 6u8, 0u8, 0u8, 87u8, 6u8, 0u8, 0u8,
+                    104u8, 6u8, 0u8, 0u8, 120u8, 6u8, 0u8, 0u8, 140u8, 6u8, 0u8, 0u8, 161u8, 6u8,
+                    0u8, 0u8, 175u8, 6u8, 0u8, 0u8, 195u8, 6u8, 0u8, 0u8, 215u8, 6u8, 0u8, 0u8,
+                    229u8, 6u8, 0u8, 0u8, 247u8, 6u8, 0u8, 0u8, 12u8, 7u8, 0u8, 0u8, 31u8, 7u8,
+                    0u8, 0u8, 52u8, 7u8, 0u8, 0u8, 64u8, 7u8, 0u8, 0u8, 81u8, 7u8, 0u8, 0u8, 96u8,
+                    7u8, 0u8, 0u8, 109u8, 7u8, 0u8, 0u8, 127u8, 7u8, 0u8, 0u8, 140u8, 7u8, 0u8,
+                    0u8, 154u8, 7u8, 0u8, 0u8, 171u8, 7u8, 0u8, 0u8, 185u8, 7u8, 0u8, 0u8, 205u8,
+                    7u8, 0u8, 0u8, 221u8, 7u8, 0u8, 0u8, 234u8, 7u8, 0u8, 0u8, 247u8, 7u8, 0u8,
+                    0u8, 2u8, 8u8, 0u8, 0u8, 16u8, 8u8, 0u8, 0u8, 34u8, 8u8, 0u8, 0u8, 41u8, 8u8,
+                    0u8, 0u8, 55u8, 8u8, 0u8, 0u8, 74u8, 8u8, 0u8, 0u8, 90u8, 8u8, 0u8, 0u8, 104u8,
+                    8u8, 0u8, 0u8, 116u8, 8u8, 0u8, 0u8, 129u8, 8u8, 0u8, 0u8, 142u8, 8u8, 0u8,
+                    0u8, 157u8, 8u8, 0u8, 0u8, 172u8, 8u8, 0u8, 0u8, 189u8, 8u8, 0u8, 0u8, 205u8,
+                    8u8, 0u8, 0u8, 226u8, 8u8, 0u8, 0u8, 240u8, 8u8, 0u8, 0u8, 1u8, 9u8, 0u8, 0u8,
+                    18u8, 9u8, 0u8, 0u8, 32u8, 9u8, 0u8, 0u8, 44u8, 9u8, 0u8, 0u8, 59u8, 9u8, 0u8,
+                    0u8, 72u8, 9u8, 0u8, 0u8, 84u8, 9u8, 0u8, 0u8, 99u8, 9u8, 0u8, 0u8, 112u8, 9u8,
+                    0u8, 0u8, 119u8, 9u8, 0u8, 0u8, 137u8, 9u8, 0u8, 0u8, 152u8, 9u8, 0u8, 0u8,
+                    168u8, 9u8, 0u8, 0u8, 180u8, 9u8, 0u8, 0u8, 196u8, 9u8, 0u8, 0u8, 210u8, 9u8,
+                    0u8, 0u8, 224u8, 9u8, 0u8, 0u8, 236u8, 9u8, 0u8, 0u8, 251u8, 9u8, 0u8, 0u8,
+                    7u8, 10u8, 0u8, 0u8, 24u8, 10u8, 0u8, 0u8, 33u8, 10u8, 0u8, 0u8, 46u8, 10u8,
+                    0u8, 0u8, 61u8, 10u8, 0u8, 0u8, 73u8, 10u8, 0u8, 0u8, 88u8, 10u8, 0u8, 0u8,
+                    103u8, 10u8, 0u8, 0u8, 115u8, 10u8, 0u8, 0u8, 131u8, 10u8, 0u8, 0u8, 151u8,
+                    10u8, 0u8, 0u8, 166u8, 10u8, 0u8, 0u8, 186u8, 10u8, 0u8, 0u8, 199u8, 10u8, 0u8,
+                    0u8, 212u8, 10u8, 0u8, 0u8, 219u8, 10u8, 0u8, 0u8, 233u8, 10u8, 0u8, 0u8,
+                    251u8, 10u8, 0u8, 0u8, 10u8, 11u8, 0u8, 0u8, 31u8, 11u8, 0u8, 0u8, 44u8, 11u8,
+                    0u8, 0u8, 57u8, 11u8, 0u8, 0u8, 79u8, 11u8, 0u8, 0u8, 96u8, 11u8, 0u8, 0u8,
+                    108u8, 11u8, 0u8, 0u8, 121u8, 11u8, 0u8, 0u8, 135u8, 11u8, 0u8, 0u8, 146u8,
+                    11u8, 0u8, 0u8, 160u8, 11u8, 0u8, 0u8, 179u8, 11u8, 0u8, 0u8, 192u8, 11u8, 0u8,
+                    0u8, 214u8, 11u8, 0u8, 0u8, 229u8, 11u8, 0u8, 0u8, 242u8, 11u8, 0u8, 0u8,
+                    254u8, 11u8, 0u8, 0u8, 11u8, 12u8, 0u8, 0u8, 25u8, 12u8, 0u8, 0u8, 38u8, 12u8,
+                    0u8, 0u8, 56u8, 12u8, 0u8, 0u8, 69u8, 12u8, 0u8, 0u8, 82u8, 12u8, 0u8, 0u8,
+                    94u8, 12u8, 0u8, 0u8, 105u8, 12u8, 0u8, 0u8, 123u8, 12u8, 0u8, 0u8, 134u8,
+                    12u8, 0u8, 0u8, 148u8, 12u8, 0u8, 0u8, 161u8, 12u8, 0u8, 0u8, 176u8, 12u8, 0u8,
+                    0u8, 186u8, 12u8, 0u8, 0u8, 196u8, 12u8, 0u8, 0u8, 210u8, 12u8, 0u8, 0u8,
+                    222u8, 12u8, 0u8, 0u8, 237u8, 12u8, 0u8, 0u8, 255u8, 12u8, 0u8, 0u8, 16u8,
+                    13u8, 0u8, 0u8, 30u8, 13u8, 0u8, 0u8, 43u8, 13u8, 0u8, 0u8, 59u8, 13u8, 0u8,
+                    0u8, 73u8, 13u8, 0u8, 0u8, 83u8, 13u8, 0u8, 0u8, 94u8, 13u8, 0u8, 0u8, 108u8,
+                    13u8, 0u8, 0u8, 118u8, 13u8, 0u8, 0u8, 129u8, 13u8, 0u8, 0u8, 140u8, 13u8, 0u8,
+                    0u8, 151u8, 13u8, 0u8, 0u8, 164u8, 13u8, 0u8, 0u8, 178u8, 13u8, 0u8, 0u8,
+                    187u8, 13u8, 0u8, 0u8, 201u8, 13u8, 0u8, 0u8, 212u8, 13u8, 0u8, 0u8, 228u8,
+                    13u8, 0u8, 0u8, 240u8, 13u8, 0u8, 0u8, 252u8, 13u8, 0u8, 0u8, 11u8, 14u8, 0u8,
+                    0u8, 24u8, 14u8, 0u8, 0u8, 38u8, 14u8, 0u8, 0u8, 55u8, 14u8, 0u8, 0u8, 66u8,
+                    14u8, 0u8, 0u8, 80u8, 14u8, 0u8, 0u8, 97u8, 14u8, 0u8, 0u8, 110u8, 14u8, 0u8,
+                    0u8, 125u8, 14u8, 0u8, 0u8, 141u8, 14u8, 0u8, 0u8, 160u8, 14u8, 0u8, 0u8,
+                    177u8, 14u8, 0u8, 0u8, 191u8, 14u8, 0u8, 0u8, 204u8, 14u8, 0u8, 0u8, 217u8,
+                    14u8, 0u8, 0u8, 229u8, 14u8, 0u8, 0u8, 244u8, 14u8, 0u8, 0u8, 253u8, 14u8, 0u8,
+                    0u8, 13u8, 15u8, 0u8, 0u8, 23u8, 15u8, 0u8, 0u8, 37u8, 15u8, 0u8, 0u8, 55u8,
+                    15u8, 0u8, 0u8, 72u8, 15u8, 0u8, 0u8, 90u8, 15u8, 0u8, 0u8, 97u8, 15u8, 0u8,
+                    0u8, 109u8, 15u8, 0u8, 0u8, 125u8, 15u8, 0u8, 0u8, 140u8, 15u8, 0u8, 0u8,
+                    155u8, 15u8, 0u8, 0u8, 172u8, 15u8, 0u8, 0u8, 186u8, 15u8, 0u8, 0u8, 204u8,
+                    15u8, 0u8, 0u8, 221u8, 15u8, 0u8, 0u8, 240u8, 15u8, 0u8, 0u8, 254u8, 15u8, 0u8,
+                    0u8, 15u8, 16u8, 0u8, 0u8, 31u8, 16u8, 0u8, 0u8, 46u8, 16u8, 0u8, 0u8, 68u8,
+                    16u8, 0u8, 0u8, 88u8, 16u8, 0u8, 0u8, 103u8, 16u8, 0u8, 0u8, 115u8, 16u8, 0u8,
+                    0u8, 132u8, 16u8, 0u8, 0u8, 145u8, 16u8, 0u8, 0u8, 160u8, 16u8, 0u8, 0u8,
+                    174u8, 16u8, 0u8, 0u8, 187u8, 16u8, 0u8, 0u8, 202u8, 16u8, 0u8, 0u8, 214u8,
+                    16u8, 0u8, 0u8, 229u8, 16u8, 0u8, 0u8, 245u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8,
+                    0u8, 13u8, 17u8, 0u8, 0u8, 26u8, 17u8, 0u8, 0u8, 38u8, 17u8, 0u8, 0u8, 54u8,
+                    17u8, 0u8, 0u8, 69u8, 17u8, 0u8, 0u8, 80u8, 17u8, 0u8, 0u8, 94u8, 17u8, 0u8,
+                    0u8, 106u8, 17u8, 0u8, 0u8, 121u8, 17u8, 0u8, 0u8, 138u8, 17u8, 0u8, 0u8,
+                    152u8, 17u8, 0u8, 0u8, 172u8, 17u8, 0u8, 0u8, 192u8, 17u8, 0u8, 0u8, 203u8,
+                    17u8, 0u8, 0u8, 215u8, 17u8, 0u8, 0u8, 228u8, 17u8, 0u8, 0u8, 244u8, 17u8, 0u8,
+                    0u8, 4u8, 18u8, 0u8, 0u8, 23u8, 18u8, 0u8, 0u8, 30u8, 18u8, 0u8, 0u8, 46u8,
+                    18u8, 0u8, 0u8, 59u8, 18u8, 0u8, 0u8, 74u8, 18u8, 0u8, 0u8, 87u8, 18u8, 0u8,
+                    0u8, 103u8, 18u8, 0u8, 0u8, 113u8, 18u8, 0u8, 0u8, 127u8, 18u8, 0u8, 0u8,
+                    143u8, 18u8, 0u8, 0u8, 158u8, 18u8, 0u8, 0u8, 174u8, 18u8, 0u8, 0u8, 186u8,
+                    18u8, 0u8, 0u8, 196u8, 18u8, 0u8, 0u8, 207u8, 18u8, 0u8, 0u8, 219u8, 18u8, 0u8,
+                    0u8, 231u8, 18u8, 0u8, 0u8, 249u8, 18u8, 0u8, 0u8, 6u8, 19u8, 0u8, 0u8, 22u8,
+                    19u8, 0u8, 0u8, 35u8, 19u8, 0u8, 0u8, 47u8, 19u8, 0u8, 0u8, 60u8, 19u8, 0u8,
+                    0u8, 77u8, 19u8, 0u8, 0u8, 86u8, 19u8, 0u8, 0u8, 102u8, 19u8, 0u8, 0u8, 116u8,
+                    19u8, 0u8, 0u8, 130u8, 19u8, 0u8, 0u8, 148u8, 19u8, 0u8, 0u8, 158u8, 19u8, 0u8,
+                    0u8, 174u8, 19u8, 0u8, 0u8, 187u8, 19u8, 0u8, 0u8, 200u8, 19u8, 0u8, 0u8,
+                    216u8, 19u8, 0u8, 0u8, 232u8, 19u8, 0u8, 0u8, 250u8, 19u8, 0u8, 0u8, 6u8, 20u8,
+                    0u8, 0u8, 19u8, 20u8, 0u8, 0u8, 30u8, 20u8, 0u8, 0u8, 49u8, 20u8, 0u8, 0u8,
+                    60u8, 20u8, 0u8, 0u8, 75u8, 20u8, 0u8, 0u8, 91u8, 20u8, 0u8, 0u8, 105u8, 20u8,
+                    0u8, 0u8, 123u8, 20u8, 0u8, 0u8, 139u8, 20u8, 0u8, 0u8, 158u8, 20u8, 0u8, 0u8,
+                    175u8, 20u8, 0u8, 0u8, 190u8, 20u8, 0u8, 0u8, 207u8, 20u8, 0u8, 0u8, 219u8,
+                    20u8, 0u8, 0u8, 235u8, 20u8, 0u8, 0u8, 253u8, 20u8, 0u8, 0u8, 8u8, 21u8, 0u8,
+                    0u8, 23u8, 21u8, 0u8, 0u8, 42u8, 21u8, 0u8, 0u8, 63u8, 21u8, 0u8, 0u8, 76u8,
+                    21u8, 0u8, 0u8, 90u8, 21u8, 0u8, 0u8, 108u8, 21u8, 0u8, 0u8, 123u8, 21u8, 0u8,
+                    0u8, 139u8, 21u8, 0u8, 0u8, 150u8, 21u8, 0u8, 0u8, 162u8, 21u8, 0u8, 0u8,
+                    175u8, 21u8, 0u8, 0u8, 190u8, 21u8, 0u8, 0u8, 199u8, 21u8, 0u8, 0u8, 212u8,
+                    21u8, 0u8, 0u8, 224u8, 21u8, 0u8, 0u8, 241u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8,
+                    0u8, 21u8, 22u8, 0u8, 0u8, 37u8, 22u8, 0u8, 0u8, 48u8, 22u8, 0u8, 0u8, 68u8,
+                    22u8, 0u8, 0u8, 79u8, 22u8, 0u8, 0u8, 96u8, 22u8, 0u8, 0u8, 113u8, 22u8, 0u8,
+                    0u8, 128u8, 22u8, 0u8, 0u8, 142u8, 22u8, 0u8, 0u8, 154u8, 22u8, 0u8, 0u8,
+                    170u8, 22u8, 0u8, 0u8, 184u8, 22u8, 0u8, 0u8, 195u8, 22u8, 0u8, 0u8, 207u8,
+                    22u8, 0u8, 0u8, 230u8, 22u8, 0u8, 0u8, 247u8, 22u8, 0u8, 0u8, 4u8, 23u8, 0u8,
+                    0u8, 19u8, 23u8, 0u8, 0u8, 33u8, 23u8, 0u8, 0u8, 48u8, 23u8, 0u8, 0u8, 64u8,
+                    23u8, 0u8, 0u8, 84u8, 23u8, 0u8, 0u8, 105u8, 23u8, 0u8, 0u8, 119u8, 23u8, 0u8,
+                    0u8, 139u8, 23u8, 0u8, 0u8, 158u8, 23u8, 0u8, 0u8, 176u8, 23u8, 0u8, 0u8,
+                    193u8, 23u8, 0u8, 0u8, 220u8, 23u8, 0u8, 0u8, 238u8, 23u8, 0u8, 0u8, 9u8, 24u8,
+                    0u8, 0u8, 39u8, 24u8, 0u8, 0u8, 55u8, 24u8, 0u8, 0u8, 80u8, 24u8, 0u8, 0u8,
+                    92u8, 24u8, 0u8, 0u8, 107u8, 24u8, 0u8, 0u8, 120u8, 24u8, 0u8, 0u8, 145u8,
+                    24u8, 0u8, 0u8, 168u8, 24u8, 0u8, 0u8, 194u8, 24u8, 0u8, 0u8, 221u8, 24u8, 0u8,
+                    0u8, 236u8, 24u8, 0u8, 0u8, 243u8, 24u8, 0u8, 0u8, 252u8, 24u8, 0u8, 0u8, 5u8,
+                    25u8, 0u8, 0u8, 14u8, 25u8, 0u8, 0u8, 23u8, 25u8, 0u8, 0u8, 32u8, 25u8, 0u8,
+                    0u8, 41u8, 25u8, 0u8, 0u8, 50u8, 25u8, 0u8, 0u8, 59u8, 25u8, 0u8, 0u8, 68u8,
+                    25u8, 0u8, 0u8, 78u8, 25u8, 0u8, 0u8, 88u8, 25u8, 0u8, 0u8, 98u8, 25u8, 0u8,
+                    0u8, 108u8, 25u8, 0u8, 0u8, 118u8, 25u8, 0u8, 0u8, 127u8, 25u8, 0u8, 0u8,
+                    136u8, 25u8, 0u8, 0u8, 145u8, 25u8, 0u8, 0u8, 154u8, 25u8, 0u8, 0u8, 163u8,
+                    25u8, 0u8, 0u8, 172u8, 25u8, 0u8, 0u8, 181u8, 25u8, 0u8, 0u8, 190u8, 25u8, 0u8,
+                    0u8, 199u8, 25u8, 0u8, 0u8, 209u8, 25u8, 0u8, 0u8, 219u8, 25u8, 0u8, 0u8,
+                    229u8, 25u8, 0u8, 0u8, 247u8, 25u8, 0u8, 0u8, 5u8, 26u8, 0u8, 0u8, 18u8, 26u8,
+                    0u8, 0u8, 32u8, 26u8, 0u8, 0u8, 50u8, 26u8, 0u8, 0u8, 65u8, 26u8, 0u8, 0u8,
+                    80u8, 26u8, 0u8, 0u8, 97u8, 26u8, 0u8, 0u8, 108u8, 26u8, 0u8, 0u8, 121u8, 26u8,
+                    0u8, 0u8, 135u8, 26u8, 0u8, 0u8, 147u8, 26u8, 0u8, 0u8, 156u8, 26u8, 0u8, 0u8,
+                    170u8, 26u8, 0u8, 0u8, 189u8, 26u8, 0u8, 0u8, 202u8, 26u8, 0u8, 0u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 65u8, 110u8, 100u8, 111u8, 114u8,
+                    114u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 68u8, 117u8, 98u8, 97u8, 105u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 75u8, 97u8, 98u8, 117u8, 108u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 110u8, 116u8, 105u8, 103u8, 117u8,
+                    97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 110u8, 103u8,
+                    117u8, 105u8, 108u8, 108u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 84u8, 105u8, 114u8, 97u8, 110u8, 101u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    89u8, 101u8, 114u8, 101u8, 118u8, 97u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 67u8, 117u8, 114u8, 97u8, 99u8, 97u8, 111u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 117u8, 97u8, 110u8, 100u8, 97u8,
+                    65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 67u8,
+                    97u8, 115u8, 101u8, 121u8, 65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8,
+                    99u8, 97u8, 47u8, 68u8, 97u8, 118u8, 105u8, 115u8, 65u8, 110u8, 116u8, 97u8,
+                    114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 68u8, 117u8, 109u8, 111u8, 110u8,
+                    116u8, 68u8, 85u8, 114u8, 118u8, 105u8, 108u8, 108u8, 101u8, 65u8, 110u8,
+                    116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 119u8,
+                    115u8, 111u8, 110u8, 65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8,
+                    97u8, 47u8, 77u8, 99u8, 77u8, 117u8, 114u8, 100u8, 111u8, 65u8, 110u8, 116u8,
+                    97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 80u8, 97u8, 108u8, 109u8,
+                    101u8, 114u8, 65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8,
+                    47u8, 82u8, 111u8, 116u8, 104u8, 101u8, 114u8, 97u8, 65u8, 110u8, 116u8, 97u8,
+                    114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 83u8, 121u8, 111u8, 119u8, 97u8,
+                    65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8, 84u8,
+                    114u8, 111u8, 108u8, 108u8, 65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8,
+                    105u8, 99u8, 97u8, 47u8, 86u8, 111u8, 115u8, 116u8, 111u8, 107u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 117u8, 101u8, 110u8, 111u8, 115u8,
+                    95u8, 65u8, 105u8, 114u8, 101u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 67u8, 111u8, 114u8, 100u8, 111u8, 98u8, 97u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8, 116u8, 97u8, 109u8, 97u8, 114u8,
+                    99u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 114u8,
+                    103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8, 76u8, 97u8, 95u8, 82u8,
+                    105u8, 111u8, 106u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    74u8, 117u8, 106u8, 117u8, 121u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8, 83u8,
+                    97u8, 110u8, 95u8, 76u8, 117u8, 105u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 77u8, 101u8, 110u8, 100u8, 111u8, 122u8, 97u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8,
+                    105u8, 110u8, 97u8, 47u8, 82u8, 105u8, 111u8, 95u8, 71u8, 97u8, 108u8, 108u8,
+                    101u8, 103u8, 111u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8, 83u8, 97u8,
+                    108u8, 116u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8,
+                    114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8, 84u8, 117u8, 99u8,
+                    117u8, 109u8, 97u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8, 83u8, 97u8,
+                    110u8, 95u8, 74u8, 117u8, 97u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 47u8,
+                    85u8, 115u8, 104u8, 117u8, 97u8, 105u8, 97u8, 80u8, 97u8, 99u8, 105u8, 102u8,
+                    105u8, 99u8, 47u8, 80u8, 97u8, 103u8, 111u8, 95u8, 80u8, 97u8, 103u8, 111u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 86u8, 105u8, 101u8, 110u8,
+                    110u8, 97u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8,
+                    65u8, 100u8, 101u8, 108u8, 97u8, 105u8, 100u8, 101u8, 65u8, 117u8, 115u8,
+                    116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 66u8, 114u8, 111u8, 107u8, 101u8,
+                    110u8, 95u8, 72u8, 105u8, 108u8, 108u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8,
+                    108u8, 105u8, 97u8, 47u8, 66u8, 114u8, 105u8, 115u8, 98u8, 97u8, 110u8, 101u8,
+                    65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 68u8, 97u8,
+                    114u8, 119u8, 105u8, 110u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8,
+                    105u8, 97u8, 47u8, 69u8, 117u8, 99u8, 108u8, 97u8, 65u8, 117u8, 115u8, 116u8,
+                    114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 72u8, 111u8, 98u8, 97u8, 114u8, 116u8,
+                    65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 67u8, 117u8,
+                    114u8, 114u8, 105u8, 101u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8,
+                    105u8, 97u8, 47u8, 76u8, 105u8, 110u8, 100u8, 101u8, 109u8, 97u8, 110u8, 65u8,
+                    117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 76u8, 111u8, 114u8,
+                    100u8, 95u8, 72u8, 111u8, 119u8, 101u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8,
+                    108u8, 105u8, 97u8, 47u8, 77u8, 101u8, 108u8, 98u8, 111u8, 117u8, 114u8, 110u8,
+                    101u8, 65u8, 110u8, 116u8, 97u8, 114u8, 99u8, 116u8, 105u8, 99u8, 97u8, 47u8,
+                    77u8, 97u8, 99u8, 113u8, 117u8, 97u8, 114u8, 105u8, 101u8, 65u8, 117u8, 115u8,
+                    116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 80u8, 101u8, 114u8, 116u8, 104u8,
+                    65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 47u8, 83u8, 121u8,
+                    100u8, 110u8, 101u8, 121u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    65u8, 114u8, 117u8, 98u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 66u8, 97u8,
+                    107u8, 117u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 83u8, 97u8, 114u8,
+                    97u8, 106u8, 101u8, 118u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 66u8, 97u8, 114u8, 98u8, 97u8, 100u8, 111u8, 115u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 68u8, 104u8, 97u8, 107u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 66u8, 114u8, 117u8, 115u8, 115u8, 101u8, 108u8, 115u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 79u8, 117u8, 97u8, 103u8, 97u8, 100u8,
+                    111u8, 117u8, 103u8, 111u8, 117u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 83u8, 111u8, 102u8, 105u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 66u8,
+                    97u8, 104u8, 114u8, 97u8, 105u8, 110u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 66u8, 117u8, 106u8, 117u8, 109u8, 98u8, 117u8, 114u8, 97u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 80u8, 111u8, 114u8, 116u8, 111u8, 45u8, 78u8,
+                    111u8, 118u8, 111u8, 65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8,
+                    66u8, 101u8, 114u8, 109u8, 117u8, 100u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    66u8, 114u8, 117u8, 110u8, 101u8, 105u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 76u8, 97u8, 95u8, 80u8, 97u8, 122u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 75u8, 114u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    105u8, 106u8, 107u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8,
+                    114u8, 97u8, 103u8, 117u8, 97u8, 105u8, 110u8, 97u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 66u8, 101u8, 108u8, 101u8, 109u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 111u8, 97u8, 95u8, 86u8, 105u8, 115u8,
+                    116u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 117u8,
+                    105u8, 97u8, 98u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    67u8, 97u8, 109u8, 112u8, 111u8, 95u8, 71u8, 114u8, 97u8, 110u8, 100u8, 101u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 69u8, 105u8, 114u8, 117u8,
+                    110u8, 101u8, 112u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    78u8, 111u8, 114u8, 111u8, 110u8, 104u8, 97u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 70u8, 111u8, 114u8, 116u8, 97u8, 108u8, 101u8, 122u8,
+                    97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 110u8,
+                    97u8, 117u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8,
+                    97u8, 99u8, 101u8, 105u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 80u8, 111u8, 114u8, 116u8, 111u8, 95u8, 86u8, 101u8, 108u8, 104u8, 111u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 82u8, 105u8, 111u8, 95u8,
+                    66u8, 114u8, 97u8, 110u8, 99u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 82u8, 101u8, 99u8, 105u8, 102u8, 101u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 83u8, 97u8, 111u8, 95u8, 80u8, 97u8, 117u8, 108u8,
+                    111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 97u8, 104u8,
+                    105u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 97u8,
+                    110u8, 116u8, 97u8, 114u8, 101u8, 109u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 78u8, 97u8, 115u8, 115u8, 97u8, 117u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 84u8, 104u8, 105u8, 109u8, 112u8, 104u8, 117u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 71u8, 97u8, 98u8, 111u8, 114u8, 111u8, 110u8, 101u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 77u8, 105u8, 110u8, 115u8,
+                    107u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 101u8, 108u8,
+                    105u8, 122u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8,
+                    114u8, 101u8, 115u8, 116u8, 111u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 69u8, 100u8, 109u8, 111u8, 110u8, 116u8, 111u8, 110u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 82u8, 97u8, 105u8, 110u8, 121u8,
+                    95u8, 82u8, 105u8, 118u8, 101u8, 114u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 70u8, 111u8, 114u8, 116u8, 95u8, 78u8, 101u8, 108u8, 115u8, 111u8,
+                    110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 71u8, 108u8, 97u8,
+                    99u8, 101u8, 95u8, 66u8, 97u8, 121u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 71u8, 111u8, 111u8, 115u8, 101u8, 95u8, 66u8, 97u8, 121u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 72u8, 97u8, 108u8, 105u8, 102u8,
+                    97u8, 120u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 113u8,
+                    97u8, 108u8, 117u8, 105u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 77u8, 111u8, 110u8, 99u8, 116u8, 111u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 77u8, 111u8, 110u8, 116u8, 114u8, 101u8, 97u8, 108u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8, 105u8, 112u8, 105u8,
+                    103u8, 111u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 80u8,
+                    97u8, 110u8, 103u8, 110u8, 105u8, 114u8, 116u8, 117u8, 110u8, 103u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 82u8, 101u8, 115u8, 111u8, 108u8,
+                    117u8, 116u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 82u8,
+                    101u8, 103u8, 105u8, 110u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 83u8, 116u8, 95u8, 74u8, 111u8, 104u8, 110u8, 115u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 84u8, 104u8, 117u8, 110u8, 100u8, 101u8, 114u8,
+                    95u8, 66u8, 97u8, 121u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    84u8, 111u8, 114u8, 111u8, 110u8, 116u8, 111u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 86u8, 97u8, 110u8, 99u8, 111u8, 117u8, 118u8, 101u8,
+                    114u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 87u8, 105u8, 110u8,
+                    110u8, 105u8, 112u8, 101u8, 103u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 66u8, 108u8, 97u8, 110u8, 99u8, 45u8, 83u8, 97u8, 98u8, 108u8,
+                    111u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8,
+                    109u8, 98u8, 114u8, 105u8, 100u8, 103u8, 101u8, 95u8, 66u8, 97u8, 121u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 68u8, 97u8, 119u8, 115u8, 111u8,
+                    110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 68u8, 97u8, 119u8,
+                    115u8, 111u8, 110u8, 95u8, 67u8, 114u8, 101u8, 101u8, 107u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 82u8, 97u8, 110u8, 107u8, 105u8, 110u8,
+                    95u8, 73u8, 110u8, 108u8, 101u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 73u8, 110u8, 117u8, 118u8, 105u8, 107u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 87u8, 104u8, 105u8, 116u8, 101u8, 104u8, 111u8, 114u8,
+                    115u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 119u8,
+                    105u8, 102u8, 116u8, 95u8, 67u8, 117u8, 114u8, 114u8, 101u8, 110u8, 116u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 89u8, 101u8, 108u8, 108u8,
+                    111u8, 119u8, 107u8, 110u8, 105u8, 102u8, 101u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 67u8, 111u8, 114u8, 97u8, 108u8, 95u8, 72u8, 97u8,
+                    114u8, 98u8, 111u8, 117u8, 114u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 47u8,
+                    67u8, 111u8, 99u8, 111u8, 115u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    76u8, 117u8, 98u8, 117u8, 109u8, 98u8, 97u8, 115u8, 104u8, 105u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 75u8, 105u8, 110u8, 115u8, 104u8, 97u8, 115u8,
+                    97u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 97u8, 110u8, 103u8,
+                    117u8, 105u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 114u8, 97u8,
+                    122u8, 122u8, 97u8, 118u8, 105u8, 108u8, 108u8, 101u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 47u8, 90u8, 117u8, 114u8, 105u8, 99u8, 104u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 98u8, 105u8, 100u8, 106u8, 97u8, 110u8,
+                    80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 82u8, 97u8, 114u8, 111u8,
+                    116u8, 111u8, 110u8, 103u8, 97u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8,
+                    47u8, 69u8, 97u8, 115u8, 116u8, 101u8, 114u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 80u8, 117u8, 110u8, 116u8, 97u8, 95u8, 65u8, 114u8, 101u8,
+                    110u8, 97u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8,
+                    97u8, 110u8, 116u8, 105u8, 97u8, 103u8, 111u8, 65u8, 102u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 68u8, 111u8, 117u8, 97u8, 108u8, 97u8, 65u8, 115u8, 105u8, 97u8,
+                    47u8, 83u8, 104u8, 97u8, 110u8, 103u8, 104u8, 97u8, 105u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 85u8, 114u8, 117u8, 109u8, 113u8, 105u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 66u8, 111u8, 103u8, 111u8, 116u8, 97u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 111u8, 115u8, 116u8, 97u8, 95u8,
+                    82u8, 105u8, 99u8, 97u8, 67u8, 83u8, 84u8, 54u8, 67u8, 68u8, 84u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 72u8, 97u8, 118u8, 97u8, 110u8, 97u8,
+                    65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8, 67u8, 97u8, 112u8,
+                    101u8, 95u8, 86u8, 101u8, 114u8, 100u8, 101u8, 73u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 47u8, 67u8, 104u8, 114u8, 105u8, 115u8, 116u8, 109u8, 97u8, 115u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 70u8, 97u8, 109u8, 97u8, 103u8, 117u8, 115u8, 116u8,
+                    97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 78u8, 105u8, 99u8, 111u8, 115u8, 105u8,
+                    97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 80u8, 114u8, 97u8, 103u8,
+                    117u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 66u8, 101u8,
+                    114u8, 108u8, 105u8, 110u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8,
+                    66u8, 117u8, 115u8, 105u8, 110u8, 103u8, 101u8, 110u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 68u8, 106u8, 105u8, 98u8, 111u8, 117u8, 116u8, 105u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 67u8, 111u8, 112u8, 101u8,
+                    110u8, 104u8, 97u8, 103u8, 101u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 68u8, 111u8, 109u8, 105u8, 110u8, 105u8, 99u8, 97u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 97u8, 110u8, 116u8, 111u8,
+                    95u8, 68u8, 111u8, 109u8, 105u8, 110u8, 103u8, 111u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 65u8, 108u8, 103u8, 105u8, 101u8, 114u8, 115u8, 80u8,
+                    97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 71u8, 97u8, 108u8, 97u8, 112u8,
+                    97u8, 103u8, 111u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    71u8, 117u8, 97u8, 121u8, 97u8, 113u8, 117u8, 105u8, 108u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 47u8, 84u8, 97u8, 108u8, 108u8, 105u8, 110u8, 110u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8, 105u8, 114u8, 111u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 69u8, 108u8, 95u8, 65u8, 97u8, 105u8,
+                    117u8, 110u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 115u8, 109u8,
+                    101u8, 114u8, 97u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 101u8,
+                    117u8, 116u8, 97u8, 65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8,
+                    67u8, 97u8, 110u8, 97u8, 114u8, 121u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 77u8, 97u8, 100u8, 114u8, 105u8, 100u8, 69u8, 83u8, 84u8, 53u8, 69u8,
+                    68u8, 84u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 100u8, 100u8,
+                    105u8, 115u8, 95u8, 65u8, 98u8, 97u8, 98u8, 97u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 72u8, 101u8, 108u8, 115u8, 105u8, 110u8, 107u8, 105u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 77u8, 97u8, 114u8, 105u8, 101u8,
+                    104u8, 97u8, 109u8, 110u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8,
+                    70u8, 105u8, 106u8, 105u8, 65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8,
+                    47u8, 83u8, 116u8, 97u8, 110u8, 108u8, 101u8, 121u8, 80u8, 97u8, 99u8, 105u8,
+                    102u8, 105u8, 99u8, 47u8, 75u8, 111u8, 115u8, 114u8, 97u8, 101u8, 80u8, 97u8,
+                    99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 80u8, 111u8, 110u8, 97u8, 112u8, 101u8,
+                    80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 84u8, 114u8, 117u8, 107u8,
+                    65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8, 70u8, 97u8, 101u8,
+                    114u8, 111u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 80u8, 97u8,
+                    114u8, 105u8, 115u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 105u8,
+                    98u8, 114u8, 101u8, 118u8, 105u8, 108u8, 108u8, 101u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 71u8, 97u8, 122u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 76u8, 111u8, 110u8, 100u8, 111u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 71u8, 114u8, 101u8, 110u8, 97u8, 100u8, 97u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 84u8, 98u8, 105u8, 108u8, 105u8, 115u8, 105u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8, 121u8, 101u8, 110u8,
+                    110u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 71u8, 117u8,
+                    101u8, 114u8, 110u8, 115u8, 101u8, 121u8, 65u8, 102u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 65u8, 99u8, 99u8, 114u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 71u8, 105u8, 98u8, 114u8, 97u8, 108u8, 116u8, 97u8, 114u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 68u8, 97u8, 110u8, 109u8, 97u8,
+                    114u8, 107u8, 115u8, 104u8, 97u8, 118u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 71u8, 111u8, 100u8, 116u8, 104u8, 97u8, 98u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 99u8, 111u8, 114u8, 101u8,
+                    115u8, 98u8, 121u8, 115u8, 117u8, 110u8, 100u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 84u8, 104u8, 117u8, 108u8, 101u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 66u8, 97u8, 110u8, 106u8, 117u8, 108u8, 69u8, 116u8,
+                    99u8, 47u8, 71u8, 77u8, 84u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    67u8, 111u8, 110u8, 97u8, 107u8, 114u8, 121u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 71u8, 117u8, 97u8, 100u8, 101u8, 108u8, 111u8, 117u8,
+                    112u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8,
+                    114u8, 105u8, 103u8, 111u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 83u8, 116u8, 95u8, 66u8, 97u8, 114u8, 116u8, 104u8, 101u8, 108u8,
+                    101u8, 109u8, 121u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8,
+                    108u8, 97u8, 98u8, 111u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 65u8,
+                    116u8, 104u8, 101u8, 110u8, 115u8, 65u8, 116u8, 108u8, 97u8, 110u8, 116u8,
+                    105u8, 99u8, 47u8, 83u8, 111u8, 117u8, 116u8, 104u8, 95u8, 71u8, 101u8, 111u8,
+                    114u8, 103u8, 105u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    71u8, 117u8, 97u8, 116u8, 101u8, 109u8, 97u8, 108u8, 97u8, 80u8, 97u8, 99u8,
+                    105u8, 102u8, 105u8, 99u8, 47u8, 71u8, 117u8, 97u8, 109u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 66u8, 105u8, 115u8, 115u8, 97u8, 117u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 71u8, 117u8, 121u8, 97u8, 110u8, 97u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 72u8, 101u8, 98u8, 114u8, 111u8, 110u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 72u8, 111u8, 110u8, 103u8, 95u8, 75u8, 111u8, 110u8,
+                    103u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 84u8, 101u8, 103u8,
+                    117u8, 99u8, 105u8, 103u8, 97u8, 108u8, 112u8, 97u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 90u8, 97u8, 103u8, 114u8, 101u8, 98u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 80u8, 111u8, 114u8, 116u8, 45u8, 97u8, 117u8,
+                    45u8, 80u8, 114u8, 105u8, 110u8, 99u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 66u8, 117u8, 100u8, 97u8, 112u8, 101u8, 115u8, 116u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 74u8, 97u8, 121u8, 97u8, 112u8, 117u8, 114u8, 97u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 74u8, 97u8, 107u8, 97u8, 114u8, 116u8, 97u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 77u8, 97u8, 107u8, 97u8, 115u8, 115u8, 97u8, 114u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 80u8, 111u8, 110u8, 116u8, 105u8, 97u8, 110u8,
+                    97u8, 107u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 68u8, 117u8, 98u8,
+                    108u8, 105u8, 110u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 73u8,
+                    115u8, 108u8, 101u8, 95u8, 111u8, 102u8, 95u8, 77u8, 97u8, 110u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 67u8, 97u8, 108u8, 99u8, 117u8, 116u8, 116u8, 97u8, 73u8,
+                    110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 67u8, 104u8, 97u8, 103u8, 111u8, 115u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 66u8, 97u8, 103u8, 104u8, 100u8, 97u8, 100u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 84u8, 101u8, 104u8, 114u8, 97u8, 110u8, 65u8,
+                    116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8, 82u8, 101u8, 121u8, 107u8,
+                    106u8, 97u8, 118u8, 105u8, 107u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 82u8, 111u8, 109u8, 101u8, 65u8, 115u8, 105u8, 97u8, 47u8, 74u8, 101u8,
+                    114u8, 117u8, 115u8, 97u8, 108u8, 101u8, 109u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 74u8, 101u8, 114u8, 115u8, 101u8, 121u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 74u8, 97u8, 109u8, 97u8, 105u8, 99u8,
+                    97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 65u8, 109u8, 109u8, 97u8, 110u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 84u8, 111u8, 107u8, 121u8, 111u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 78u8, 97u8, 105u8, 114u8, 111u8, 98u8, 105u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 66u8, 105u8, 115u8, 104u8, 107u8, 101u8, 107u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 80u8, 104u8, 110u8, 111u8, 109u8, 95u8, 80u8, 101u8,
+                    110u8, 104u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 75u8, 105u8,
+                    114u8, 105u8, 116u8, 105u8, 109u8, 97u8, 116u8, 105u8, 80u8, 97u8, 99u8, 105u8,
+                    102u8, 105u8, 99u8, 47u8, 69u8, 110u8, 100u8, 101u8, 114u8, 98u8, 117u8, 114u8,
+                    121u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 84u8, 97u8, 114u8,
+                    97u8, 119u8, 97u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 67u8, 111u8,
+                    109u8, 111u8, 114u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    83u8, 116u8, 95u8, 75u8, 105u8, 116u8, 116u8, 115u8, 65u8, 115u8, 105u8, 97u8,
+                    47u8, 80u8, 121u8, 111u8, 110u8, 103u8, 121u8, 97u8, 110u8, 103u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 83u8, 101u8, 111u8, 117u8, 108u8, 65u8, 115u8, 105u8, 97u8,
+                    47u8, 75u8, 117u8, 119u8, 97u8, 105u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 67u8, 97u8, 121u8, 109u8, 97u8, 110u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 65u8, 113u8, 116u8, 97u8, 117u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    65u8, 113u8, 116u8, 111u8, 98u8, 101u8, 65u8, 115u8, 105u8, 97u8, 47u8, 65u8,
+                    108u8, 109u8, 97u8, 116u8, 121u8, 65u8, 115u8, 105u8, 97u8, 47u8, 65u8, 116u8,
+                    121u8, 114u8, 97u8, 117u8, 65u8, 115u8, 105u8, 97u8, 47u8, 81u8, 111u8, 115u8,
+                    116u8, 97u8, 110u8, 97u8, 121u8, 65u8, 115u8, 105u8, 97u8, 47u8, 81u8, 121u8,
+                    122u8, 121u8, 108u8, 111u8, 114u8, 100u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    79u8, 114u8, 97u8, 108u8, 65u8, 115u8, 105u8, 97u8, 47u8, 86u8, 105u8, 101u8,
+                    110u8, 116u8, 105u8, 97u8, 110u8, 101u8, 65u8, 115u8, 105u8, 97u8, 47u8, 66u8,
+                    101u8, 105u8, 114u8, 117u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 83u8, 116u8, 95u8, 76u8, 117u8, 99u8, 105u8, 97u8, 69u8, 117u8,
+                    114u8, 111u8, 112u8, 101u8, 47u8, 86u8, 97u8, 100u8, 117u8, 122u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 67u8, 111u8, 108u8, 111u8, 109u8, 98u8, 111u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 111u8, 110u8, 114u8, 111u8, 118u8, 105u8,
+                    97u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 115u8, 101u8,
+                    114u8, 117u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 86u8, 105u8,
+                    108u8, 110u8, 105u8, 117u8, 115u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 76u8, 117u8, 120u8, 101u8, 109u8, 98u8, 111u8, 117u8, 114u8, 103u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 82u8, 105u8, 103u8, 97u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 84u8, 114u8, 105u8, 112u8, 111u8, 108u8, 105u8,
+                    65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8, 115u8, 97u8, 98u8,
+                    108u8, 97u8, 110u8, 99u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8,
+                    77u8, 111u8, 110u8, 97u8, 99u8, 111u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 67u8, 104u8, 105u8, 115u8, 105u8, 110u8, 97u8, 117u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 47u8, 80u8, 111u8, 100u8, 103u8, 111u8, 114u8, 105u8,
+                    99u8, 97u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 65u8, 110u8, 116u8,
+                    97u8, 110u8, 97u8, 110u8, 97u8, 114u8, 105u8, 118u8, 111u8, 80u8, 97u8, 99u8,
+                    105u8, 102u8, 105u8, 99u8, 47u8, 75u8, 119u8, 97u8, 106u8, 97u8, 108u8, 101u8,
+                    105u8, 110u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 77u8, 97u8,
+                    106u8, 117u8, 114u8, 111u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8,
+                    83u8, 107u8, 111u8, 112u8, 106u8, 101u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 66u8, 97u8, 109u8, 97u8, 107u8, 111u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    82u8, 97u8, 110u8, 103u8, 111u8, 111u8, 110u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    67u8, 104u8, 111u8, 105u8, 98u8, 97u8, 108u8, 115u8, 97u8, 110u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 72u8, 111u8, 118u8, 100u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    85u8, 108u8, 97u8, 97u8, 110u8, 98u8, 97u8, 97u8, 116u8, 97u8, 114u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 77u8, 97u8, 99u8, 97u8, 117u8, 80u8, 97u8, 99u8,
+                    105u8, 102u8, 105u8, 99u8, 47u8, 83u8, 97u8, 105u8, 112u8, 97u8, 110u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 114u8, 116u8, 105u8,
+                    110u8, 105u8, 113u8, 117u8, 101u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    78u8, 111u8, 117u8, 97u8, 107u8, 99u8, 104u8, 111u8, 116u8, 116u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 111u8, 110u8, 116u8, 115u8, 101u8,
+                    114u8, 114u8, 97u8, 116u8, 77u8, 83u8, 84u8, 55u8, 77u8, 68u8, 84u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 77u8, 97u8, 108u8, 116u8, 97u8, 73u8,
+                    110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 77u8, 97u8, 117u8, 114u8, 105u8, 116u8,
+                    105u8, 117u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 77u8, 97u8,
+                    108u8, 100u8, 105u8, 118u8, 101u8, 115u8, 65u8, 102u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 66u8, 108u8, 97u8, 110u8, 116u8, 121u8, 114u8, 101u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 104u8, 105u8, 104u8, 117u8, 97u8,
+                    104u8, 117u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8,
+                    97u8, 110u8, 99u8, 117u8, 110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 72u8, 101u8, 114u8, 109u8, 111u8, 115u8, 105u8, 108u8, 108u8, 111u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 116u8, 97u8,
+                    109u8, 111u8, 114u8, 111u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 77u8, 101u8, 120u8, 105u8, 99u8, 111u8, 95u8, 67u8, 105u8, 116u8,
+                    121u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 101u8, 114u8,
+                    105u8, 100u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8,
+                    111u8, 110u8, 116u8, 101u8, 114u8, 114u8, 101u8, 121u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 97u8, 122u8, 97u8, 116u8, 108u8, 97u8,
+                    110u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 79u8, 106u8, 105u8,
+                    110u8, 97u8, 103u8, 97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    66u8, 97u8, 104u8, 105u8, 97u8, 95u8, 66u8, 97u8, 110u8, 100u8, 101u8, 114u8,
+                    97u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 97u8,
+                    110u8, 116u8, 97u8, 95u8, 73u8, 115u8, 97u8, 98u8, 101u8, 108u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 84u8, 105u8, 106u8, 117u8, 97u8, 110u8,
+                    97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 75u8, 117u8, 99u8, 104u8, 105u8, 110u8,
+                    103u8, 65u8, 115u8, 105u8, 97u8, 47u8, 75u8, 117u8, 97u8, 108u8, 97u8, 95u8,
+                    76u8, 117u8, 109u8, 112u8, 117u8, 114u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 77u8, 97u8, 112u8, 117u8, 116u8, 111u8, 65u8, 102u8, 114u8, 105u8, 99u8,
+                    97u8, 47u8, 87u8, 105u8, 110u8, 100u8, 104u8, 111u8, 101u8, 107u8, 80u8, 97u8,
+                    99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 78u8, 111u8, 117u8, 109u8, 101u8, 97u8,
+                    65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8, 105u8, 97u8, 109u8, 101u8,
+                    121u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 78u8, 111u8, 114u8,
+                    102u8, 111u8, 108u8, 107u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8,
+                    97u8, 103u8, 111u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    77u8, 97u8, 110u8, 97u8, 103u8, 117u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 65u8, 109u8, 115u8, 116u8, 101u8, 114u8, 100u8, 97u8, 109u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 79u8, 115u8, 108u8, 111u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 75u8, 97u8, 116u8, 109u8, 97u8, 110u8, 100u8, 117u8,
+                    80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 78u8, 97u8, 117u8, 114u8,
+                    117u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 78u8, 105u8, 117u8,
+                    101u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 65u8, 117u8, 99u8,
+                    107u8, 108u8, 97u8, 110u8, 100u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8,
+                    47u8, 67u8, 104u8, 97u8, 116u8, 104u8, 97u8, 109u8, 65u8, 115u8, 105u8, 97u8,
+                    47u8, 77u8, 117u8, 115u8, 99u8, 97u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 80u8, 97u8, 110u8, 97u8, 109u8, 97u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 105u8, 109u8, 97u8, 80u8, 97u8, 99u8,
+                    105u8, 102u8, 105u8, 99u8, 47u8, 71u8, 97u8, 109u8, 98u8, 105u8, 101u8, 114u8,
+                    80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 77u8, 97u8, 114u8, 113u8,
+                    117u8, 101u8, 115u8, 97u8, 115u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8,
+                    47u8, 84u8, 97u8, 104u8, 105u8, 116u8, 105u8, 80u8, 97u8, 99u8, 105u8, 102u8,
+                    105u8, 99u8, 47u8, 80u8, 111u8, 114u8, 116u8, 95u8, 77u8, 111u8, 114u8, 101u8,
+                    115u8, 98u8, 121u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 66u8,
+                    111u8, 117u8, 103u8, 97u8, 105u8, 110u8, 118u8, 105u8, 108u8, 108u8, 101u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 77u8, 97u8, 110u8, 105u8, 108u8, 97u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 75u8, 97u8, 114u8, 97u8, 99u8, 104u8, 105u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 87u8, 97u8, 114u8, 115u8, 97u8, 119u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 105u8, 113u8, 117u8,
+                    101u8, 108u8, 111u8, 110u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8,
+                    80u8, 105u8, 116u8, 99u8, 97u8, 105u8, 114u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 80u8, 117u8, 101u8, 114u8, 116u8, 111u8, 95u8, 82u8,
+                    105u8, 99u8, 111u8, 80u8, 83u8, 84u8, 56u8, 80u8, 68u8, 84u8, 65u8, 116u8,
+                    108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8, 77u8, 97u8, 100u8, 101u8, 105u8,
+                    114u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 76u8, 105u8, 115u8,
+                    98u8, 111u8, 110u8, 65u8, 116u8, 108u8, 97u8, 110u8, 116u8, 105u8, 99u8, 47u8,
+                    65u8, 122u8, 111u8, 114u8, 101u8, 115u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8,
+                    99u8, 47u8, 80u8, 97u8, 108u8, 97u8, 117u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 65u8, 115u8, 117u8, 110u8, 99u8, 105u8, 111u8, 110u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 81u8, 97u8, 116u8, 97u8, 114u8, 73u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 47u8, 82u8, 101u8, 117u8, 110u8, 105u8, 111u8, 110u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 66u8, 117u8, 99u8, 104u8, 97u8, 114u8,
+                    101u8, 115u8, 116u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 66u8,
+                    101u8, 108u8, 103u8, 114u8, 97u8, 100u8, 101u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 65u8, 115u8, 116u8, 114u8, 97u8, 107u8, 104u8, 97u8, 110u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 66u8, 97u8, 114u8, 110u8, 97u8, 117u8, 108u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 67u8, 104u8, 105u8, 116u8, 97u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 65u8, 110u8, 97u8, 100u8, 121u8, 114u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 77u8, 97u8, 103u8, 97u8, 100u8, 97u8, 110u8, 65u8, 115u8, 105u8,
+                    97u8, 47u8, 73u8, 114u8, 107u8, 117u8, 116u8, 115u8, 107u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 47u8, 75u8, 97u8, 108u8, 105u8, 110u8, 105u8, 110u8,
+                    103u8, 114u8, 97u8, 100u8, 65u8, 115u8, 105u8, 97u8, 47u8, 75u8, 104u8, 97u8,
+                    110u8, 100u8, 121u8, 103u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 75u8, 114u8,
+                    97u8, 115u8, 110u8, 111u8, 121u8, 97u8, 114u8, 115u8, 107u8, 69u8, 117u8,
+                    114u8, 111u8, 112u8, 101u8, 47u8, 83u8, 97u8, 109u8, 97u8, 114u8, 97u8, 69u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 75u8, 105u8, 114u8, 111u8, 118u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 77u8, 111u8, 115u8, 99u8, 111u8,
+                    119u8, 65u8, 115u8, 105u8, 97u8, 47u8, 78u8, 111u8, 118u8, 111u8, 107u8, 117u8,
+                    122u8, 110u8, 101u8, 116u8, 115u8, 107u8, 65u8, 115u8, 105u8, 97u8, 47u8, 79u8,
+                    109u8, 115u8, 107u8, 65u8, 115u8, 105u8, 97u8, 47u8, 78u8, 111u8, 118u8, 111u8,
+                    115u8, 105u8, 98u8, 105u8, 114u8, 115u8, 107u8, 65u8, 115u8, 105u8, 97u8, 47u8,
+                    75u8, 97u8, 109u8, 99u8, 104u8, 97u8, 116u8, 107u8, 97u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 47u8, 83u8, 97u8, 114u8, 97u8, 116u8, 111u8, 118u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 83u8, 114u8, 101u8, 100u8, 110u8, 101u8, 107u8,
+                    111u8, 108u8, 121u8, 109u8, 115u8, 107u8, 65u8, 115u8, 105u8, 97u8, 47u8, 84u8,
+                    111u8, 109u8, 115u8, 107u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8,
+                    85u8, 108u8, 121u8, 97u8, 110u8, 111u8, 118u8, 115u8, 107u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 85u8, 115u8, 116u8, 45u8, 78u8, 101u8, 114u8, 97u8, 65u8,
+                    115u8, 105u8, 97u8, 47u8, 83u8, 97u8, 107u8, 104u8, 97u8, 108u8, 105u8, 110u8,
+                    69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 86u8, 111u8, 108u8, 103u8,
+                    111u8, 103u8, 114u8, 97u8, 100u8, 65u8, 115u8, 105u8, 97u8, 47u8, 86u8, 108u8,
+                    97u8, 100u8, 105u8, 118u8, 111u8, 115u8, 116u8, 111u8, 107u8, 65u8, 115u8,
+                    105u8, 97u8, 47u8, 89u8, 101u8, 107u8, 97u8, 116u8, 101u8, 114u8, 105u8, 110u8,
+                    98u8, 117u8, 114u8, 103u8, 65u8, 115u8, 105u8, 97u8, 47u8, 89u8, 97u8, 107u8,
+                    117u8, 116u8, 115u8, 107u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 75u8,
+                    105u8, 103u8, 97u8, 108u8, 105u8, 65u8, 115u8, 105u8, 97u8, 47u8, 82u8, 105u8,
+                    121u8, 97u8, 100u8, 104u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8,
+                    71u8, 117u8, 97u8, 100u8, 97u8, 108u8, 99u8, 97u8, 110u8, 97u8, 108u8, 73u8,
+                    110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 77u8, 97u8, 104u8, 101u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 75u8, 104u8, 97u8, 114u8, 116u8, 111u8, 117u8,
+                    109u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 83u8, 116u8, 111u8, 99u8,
+                    107u8, 104u8, 111u8, 108u8, 109u8, 65u8, 115u8, 105u8, 97u8, 47u8, 83u8, 105u8,
+                    110u8, 103u8, 97u8, 112u8, 111u8, 114u8, 101u8, 65u8, 116u8, 108u8, 97u8,
+                    110u8, 116u8, 105u8, 99u8, 47u8, 83u8, 116u8, 95u8, 72u8, 101u8, 108u8, 101u8,
+                    110u8, 97u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 76u8, 106u8, 117u8,
+                    98u8, 108u8, 106u8, 97u8, 110u8, 97u8, 65u8, 114u8, 99u8, 116u8, 105u8, 99u8,
+                    47u8, 76u8, 111u8, 110u8, 103u8, 121u8, 101u8, 97u8, 114u8, 98u8, 121u8, 101u8,
+                    110u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 66u8, 114u8, 97u8, 116u8,
+                    105u8, 115u8, 108u8, 97u8, 118u8, 97u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 70u8, 114u8, 101u8, 101u8, 116u8, 111u8, 119u8, 110u8, 69u8, 117u8,
+                    114u8, 111u8, 112u8, 101u8, 47u8, 83u8, 97u8, 110u8, 95u8, 77u8, 97u8, 114u8,
+                    105u8, 110u8, 111u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 68u8, 97u8,
+                    107u8, 97u8, 114u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 111u8,
+                    103u8, 97u8, 100u8, 105u8, 115u8, 104u8, 117u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 80u8, 97u8, 114u8, 97u8, 109u8, 97u8, 114u8, 105u8,
+                    98u8, 111u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 74u8, 117u8, 98u8,
+                    97u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 83u8, 97u8, 111u8, 95u8,
+                    84u8, 111u8, 109u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    69u8, 108u8, 95u8, 83u8, 97u8, 108u8, 118u8, 97u8, 100u8, 111u8, 114u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 111u8, 119u8, 101u8, 114u8,
+                    95u8, 80u8, 114u8, 105u8, 110u8, 99u8, 101u8, 115u8, 65u8, 115u8, 105u8, 97u8,
+                    47u8, 68u8, 97u8, 109u8, 97u8, 115u8, 99u8, 117u8, 115u8, 65u8, 102u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 77u8, 98u8, 97u8, 98u8, 97u8, 110u8, 101u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 71u8, 114u8, 97u8, 110u8, 100u8,
+                    95u8, 84u8, 117u8, 114u8, 107u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    78u8, 100u8, 106u8, 97u8, 109u8, 101u8, 110u8, 97u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 47u8, 75u8, 101u8, 114u8, 103u8, 117u8, 101u8, 108u8, 101u8,
+                    110u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 111u8, 109u8, 101u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 66u8, 97u8, 110u8, 103u8, 107u8, 111u8, 107u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 68u8, 117u8, 115u8, 104u8, 97u8, 110u8, 98u8,
+                    101u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 70u8, 97u8, 107u8,
+                    97u8, 111u8, 102u8, 111u8, 65u8, 115u8, 105u8, 97u8, 47u8, 68u8, 105u8, 108u8,
+                    105u8, 65u8, 115u8, 105u8, 97u8, 47u8, 65u8, 115u8, 104u8, 103u8, 97u8, 98u8,
+                    97u8, 116u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 84u8, 117u8, 110u8,
+                    105u8, 115u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 84u8, 111u8,
+                    110u8, 103u8, 97u8, 116u8, 97u8, 112u8, 117u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 73u8, 115u8, 116u8, 97u8, 110u8, 98u8, 117u8, 108u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 80u8, 111u8, 114u8, 116u8, 95u8,
+                    111u8, 102u8, 95u8, 83u8, 112u8, 97u8, 105u8, 110u8, 80u8, 97u8, 99u8, 105u8,
+                    102u8, 105u8, 99u8, 47u8, 70u8, 117u8, 110u8, 97u8, 102u8, 117u8, 116u8, 105u8,
+                    65u8, 115u8, 105u8, 97u8, 47u8, 84u8, 97u8, 105u8, 112u8, 101u8, 105u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 68u8, 97u8, 114u8, 95u8, 101u8, 115u8,
+                    95u8, 83u8, 97u8, 108u8, 97u8, 97u8, 109u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 75u8, 105u8, 101u8, 118u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 47u8, 90u8, 97u8, 112u8, 111u8, 114u8, 111u8, 122u8, 104u8, 121u8,
+                    101u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 47u8, 83u8, 105u8, 109u8,
+                    102u8, 101u8, 114u8, 111u8, 112u8, 111u8, 108u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 47u8, 85u8, 122u8, 104u8, 103u8, 111u8, 114u8, 111u8, 100u8,
+                    65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 75u8, 97u8, 109u8, 112u8, 97u8,
+                    108u8, 97u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 87u8, 97u8,
+                    107u8, 101u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 74u8, 111u8,
+                    104u8, 110u8, 115u8, 116u8, 111u8, 110u8, 80u8, 97u8, 99u8, 105u8, 102u8,
+                    105u8, 99u8, 47u8, 77u8, 105u8, 100u8, 119u8, 97u8, 121u8, 69u8, 116u8, 99u8,
+                    47u8, 85u8, 110u8, 107u8, 110u8, 111u8, 119u8, 110u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 65u8, 100u8, 97u8, 107u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 97u8,
+                    47u8, 77u8, 97u8, 114u8, 101u8, 110u8, 103u8, 111u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 65u8, 110u8, 99u8, 104u8, 111u8, 114u8, 97u8, 103u8,
+                    101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 66u8, 111u8, 105u8,
+                    115u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 104u8,
+                    105u8, 99u8, 97u8, 103u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 68u8, 101u8, 110u8, 118u8, 101u8, 114u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 68u8, 101u8, 116u8, 114u8, 111u8, 105u8, 116u8, 80u8,
+                    97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 72u8, 111u8, 110u8, 111u8, 108u8,
+                    117u8, 108u8, 117u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 73u8,
+                    110u8, 100u8, 105u8, 97u8, 110u8, 97u8, 112u8, 111u8, 108u8, 105u8, 115u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 97u8, 47u8, 86u8, 101u8, 118u8, 97u8, 121u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 74u8, 117u8, 110u8, 101u8, 97u8, 117u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 97u8, 47u8, 75u8, 110u8, 111u8, 120u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 76u8, 111u8, 115u8, 95u8, 65u8, 110u8, 103u8, 101u8, 108u8,
+                    101u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 111u8,
+                    117u8, 105u8, 115u8, 118u8, 105u8, 108u8, 108u8, 101u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 77u8, 101u8, 110u8, 111u8, 109u8, 105u8, 110u8,
+                    101u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 75u8, 101u8,
+                    110u8, 116u8, 117u8, 99u8, 107u8, 121u8, 47u8, 77u8, 111u8, 110u8, 116u8,
+                    105u8, 99u8, 101u8, 108u8, 108u8, 111u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 77u8, 101u8, 116u8, 108u8, 97u8, 107u8, 97u8, 116u8, 108u8,
+                    97u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8, 111u8, 114u8,
+                    116u8, 104u8, 95u8, 68u8, 97u8, 107u8, 111u8, 116u8, 97u8, 47u8, 67u8, 101u8,
+                    110u8, 116u8, 101u8, 114u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8,
+                    78u8, 111u8, 114u8, 116u8, 104u8, 95u8, 68u8, 97u8, 107u8, 111u8, 116u8, 97u8,
+                    47u8, 78u8, 101u8, 119u8, 95u8, 83u8, 97u8, 108u8, 101u8, 109u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8, 101u8, 119u8, 95u8, 89u8, 111u8,
+                    114u8, 107u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 97u8, 47u8, 86u8, 105u8, 110u8, 99u8, 101u8, 110u8,
+                    110u8, 101u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8,
+                    111u8, 109u8, 101u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 80u8,
+                    104u8, 111u8, 101u8, 110u8, 105u8, 120u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 97u8, 47u8, 83u8, 105u8, 116u8, 107u8, 97u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 97u8, 47u8,
+                    84u8, 101u8, 108u8, 108u8, 95u8, 67u8, 105u8, 116u8, 121u8, 65u8, 109u8, 101u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 97u8,
+                    47u8, 87u8, 105u8, 110u8, 97u8, 109u8, 97u8, 99u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 97u8, 47u8,
+                    80u8, 101u8, 116u8, 101u8, 114u8, 115u8, 98u8, 117u8, 114u8, 103u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 78u8, 111u8, 114u8, 116u8, 104u8,
+                    95u8, 68u8, 97u8, 107u8, 111u8, 116u8, 97u8, 47u8, 66u8, 101u8, 117u8, 108u8,
+                    97u8, 104u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 89u8, 97u8,
+                    107u8, 117u8, 116u8, 97u8, 116u8, 69u8, 116u8, 99u8, 47u8, 85u8, 84u8, 67u8,
+                    69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 49u8, 69u8, 116u8, 99u8, 47u8,
+                    71u8, 77u8, 84u8, 45u8, 50u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8,
+                    51u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 52u8, 69u8, 116u8, 99u8,
+                    47u8, 71u8, 77u8, 84u8, 45u8, 53u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8,
+                    45u8, 54u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 55u8, 69u8, 116u8,
+                    99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 56u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8,
+                    84u8, 45u8, 57u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 49u8, 48u8,
+                    69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 49u8, 49u8, 69u8, 116u8, 99u8,
+                    47u8, 71u8, 77u8, 84u8, 45u8, 49u8, 50u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8,
+                    84u8, 45u8, 49u8, 51u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 45u8, 49u8,
+                    52u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 49u8, 69u8, 116u8, 99u8,
+                    47u8, 71u8, 77u8, 84u8, 43u8, 50u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8,
+                    43u8, 51u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 52u8, 69u8, 116u8,
+                    99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 53u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8,
+                    84u8, 43u8, 54u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 55u8, 69u8,
+                    116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 56u8, 69u8, 116u8, 99u8, 47u8, 71u8,
+                    77u8, 84u8, 43u8, 57u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 49u8,
+                    48u8, 69u8, 116u8, 99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 49u8, 49u8, 69u8, 116u8,
+                    99u8, 47u8, 71u8, 77u8, 84u8, 43u8, 49u8, 50u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 77u8, 111u8, 110u8, 116u8, 101u8, 118u8, 105u8, 100u8,
+                    101u8, 111u8, 65u8, 115u8, 105u8, 97u8, 47u8, 83u8, 97u8, 109u8, 97u8, 114u8,
+                    107u8, 97u8, 110u8, 100u8, 65u8, 115u8, 105u8, 97u8, 47u8, 84u8, 97u8, 115u8,
+                    104u8, 107u8, 101u8, 110u8, 116u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8,
+                    47u8, 86u8, 97u8, 116u8, 105u8, 99u8, 97u8, 110u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 83u8, 116u8, 95u8, 86u8, 105u8, 110u8, 99u8, 101u8,
+                    110u8, 116u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 47u8, 67u8, 97u8,
+                    114u8, 97u8, 99u8, 97u8, 115u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8,
+                    47u8, 84u8, 111u8, 114u8, 116u8, 111u8, 108u8, 97u8, 65u8, 109u8, 101u8, 114u8,
+                    105u8, 99u8, 97u8, 47u8, 83u8, 116u8, 95u8, 84u8, 104u8, 111u8, 109u8, 97u8,
+                    115u8, 65u8, 115u8, 105u8, 97u8, 47u8, 83u8, 97u8, 105u8, 103u8, 111u8, 110u8,
+                    80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 69u8, 102u8, 97u8, 116u8,
+                    101u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 87u8, 97u8, 108u8,
+                    108u8, 105u8, 115u8, 80u8, 97u8, 99u8, 105u8, 102u8, 105u8, 99u8, 47u8, 65u8,
+                    112u8, 105u8, 97u8, 65u8, 115u8, 105u8, 97u8, 47u8, 65u8, 100u8, 101u8, 110u8,
+                    73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 47u8, 77u8, 97u8, 121u8, 111u8, 116u8,
+                    116u8, 101u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 74u8, 111u8, 104u8,
+                    97u8, 110u8, 110u8, 101u8, 115u8, 98u8, 117u8, 114u8, 103u8, 65u8, 102u8,
+                    114u8, 105u8, 99u8, 97u8, 47u8, 76u8, 117u8, 115u8, 97u8, 107u8, 97u8, 65u8,
+                    102u8, 114u8, 105u8, 99u8, 97u8, 47u8, 72u8, 97u8, 114u8, 97u8, 114u8, 101u8,
+                ])
+            },
+        )
+    },
+    primary_zones: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    97u8, 100u8, 97u8, 108u8, 118u8, 0u8, 0u8, 0u8, 97u8, 101u8, 100u8, 120u8,
+                    98u8, 0u8, 0u8, 0u8, 97u8, 102u8, 107u8, 98u8, 108u8, 0u8, 0u8, 0u8, 97u8,
+                    103u8, 97u8, 110u8, 117u8, 0u8, 0u8, 0u8, 97u8, 105u8, 97u8, 120u8, 97u8, 0u8,
+                    0u8, 0u8, 97u8, 108u8, 116u8, 105u8, 97u8, 0u8, 0u8, 0u8, 97u8, 109u8, 101u8,
+                    118u8, 110u8, 0u8, 0u8, 0u8, 97u8, 110u8, 99u8, 117u8, 114u8, 0u8, 0u8, 0u8,
+                    97u8, 111u8, 108u8, 97u8, 100u8, 0u8, 0u8, 0u8, 97u8, 115u8, 112u8, 112u8,
+                    103u8, 0u8, 0u8, 0u8, 97u8, 116u8, 118u8, 105u8, 101u8, 0u8, 0u8, 0u8, 97u8,
+                    119u8, 97u8, 117u8, 97u8, 0u8, 0u8, 0u8, 97u8, 122u8, 98u8, 97u8, 107u8, 0u8,
+                    0u8, 0u8, 98u8, 97u8, 115u8, 106u8, 106u8, 0u8, 0u8, 0u8, 98u8, 98u8, 98u8,
+                    103u8, 105u8, 0u8, 0u8, 0u8, 98u8, 100u8, 100u8, 97u8, 99u8, 0u8, 0u8, 0u8,
+                    98u8, 101u8, 98u8, 114u8, 117u8, 0u8, 0u8, 0u8, 98u8, 102u8, 111u8, 117u8,
+                    97u8, 0u8, 0u8, 0u8, 98u8, 103u8, 115u8, 111u8, 102u8, 0u8, 0u8, 0u8, 98u8,
+                    104u8, 98u8, 97u8, 104u8, 0u8, 0u8, 0u8, 98u8, 105u8, 98u8, 106u8, 109u8, 0u8,
+                    0u8, 0u8, 98u8, 106u8, 112u8, 116u8, 110u8, 0u8, 0u8, 0u8, 98u8, 109u8, 98u8,
+                    100u8, 97u8, 0u8, 0u8, 0u8, 98u8, 110u8, 98u8, 119u8, 110u8, 0u8, 0u8, 0u8,
+                    98u8, 111u8, 108u8, 112u8, 98u8, 0u8, 0u8, 0u8, 98u8, 113u8, 107u8, 114u8,
+                    97u8, 0u8, 0u8, 0u8, 98u8, 115u8, 110u8, 97u8, 115u8, 0u8, 0u8, 0u8, 98u8,
+                    116u8, 116u8, 104u8, 105u8, 0u8, 0u8, 0u8, 98u8, 119u8, 103u8, 98u8, 101u8,
+                    0u8, 0u8, 0u8, 98u8, 121u8, 109u8, 115u8, 113u8, 0u8, 0u8, 0u8, 98u8, 122u8,
+                    98u8, 122u8, 101u8, 0u8, 0u8, 0u8, 99u8, 99u8, 99u8, 99u8, 107u8, 0u8, 0u8,
+                    0u8, 99u8, 102u8, 98u8, 103u8, 102u8, 0u8, 0u8, 0u8, 99u8, 103u8, 98u8, 122u8,
+                    118u8, 0u8, 0u8, 0u8, 99u8, 104u8, 122u8, 114u8, 104u8, 0u8, 0u8, 0u8, 99u8,
+                    105u8, 97u8, 98u8, 106u8, 0u8, 0u8, 0u8, 99u8, 107u8, 114u8, 97u8, 114u8, 0u8,
+                    0u8, 0u8, 99u8, 108u8, 115u8, 99u8, 108u8, 0u8, 0u8, 0u8, 99u8, 109u8, 100u8,
+                    108u8, 97u8, 0u8, 0u8, 0u8, 99u8, 110u8, 115u8, 104u8, 97u8, 0u8, 0u8, 0u8,
+                    99u8, 111u8, 98u8, 111u8, 103u8, 0u8, 0u8, 0u8, 99u8, 114u8, 115u8, 106u8,
+                    111u8, 0u8, 0u8, 0u8, 99u8, 117u8, 104u8, 97u8, 118u8, 0u8, 0u8, 0u8, 99u8,
+                    118u8, 114u8, 97u8, 105u8, 0u8, 0u8, 0u8, 99u8, 120u8, 120u8, 99u8, 104u8, 0u8,
+                    0u8, 0u8, 99u8, 122u8, 112u8, 114u8, 103u8, 0u8, 0u8, 0u8, 100u8, 101u8, 98u8,
+                    101u8, 114u8, 0u8, 0u8, 0u8, 100u8, 106u8, 106u8, 105u8, 98u8, 0u8, 0u8, 0u8,
+                    100u8, 107u8, 99u8, 112u8, 104u8, 0u8, 0u8, 0u8, 100u8, 109u8, 100u8, 111u8,
+                    109u8, 0u8, 0u8, 0u8, 100u8, 111u8, 115u8, 100u8, 113u8, 0u8, 0u8, 0u8, 100u8,
+                    122u8, 97u8, 108u8, 103u8, 0u8, 0u8, 0u8, 101u8, 99u8, 103u8, 121u8, 101u8,
+                    0u8, 0u8, 0u8, 101u8, 101u8, 116u8, 108u8, 108u8, 0u8, 0u8, 0u8, 101u8, 103u8,
+                    99u8, 97u8, 105u8, 0u8, 0u8, 0u8, 101u8, 104u8, 101u8, 97u8, 105u8, 0u8, 0u8,
+                    0u8, 101u8, 114u8, 97u8, 115u8, 109u8, 0u8, 0u8, 0u8, 101u8, 115u8, 109u8,
+                    97u8, 100u8, 0u8, 0u8, 0u8, 101u8, 116u8, 97u8, 100u8, 100u8, 0u8, 0u8, 0u8,
+                    102u8, 106u8, 115u8, 117u8, 118u8, 0u8, 0u8, 0u8, 102u8, 107u8, 112u8, 115u8,
+                    121u8, 0u8, 0u8, 0u8, 102u8, 111u8, 116u8, 104u8, 111u8, 0u8, 0u8, 0u8, 102u8,
+                    114u8, 112u8, 97u8, 114u8, 0u8, 0u8, 0u8, 103u8, 97u8, 108u8, 98u8, 118u8, 0u8,
+                    0u8, 0u8, 103u8, 98u8, 108u8, 111u8, 110u8, 0u8, 0u8, 0u8, 103u8, 100u8, 103u8,
+                    110u8, 100u8, 0u8, 0u8, 0u8, 103u8, 101u8, 116u8, 98u8, 115u8, 0u8, 0u8, 0u8,
+                    103u8, 102u8, 99u8, 97u8, 121u8, 0u8, 0u8, 0u8, 103u8, 103u8, 103u8, 99u8,
+                    105u8, 0u8, 0u8, 0u8, 103u8, 104u8, 97u8, 99u8, 99u8, 0u8, 0u8, 0u8, 103u8,
+                    105u8, 103u8, 105u8, 98u8, 0u8, 0u8, 0u8, 103u8, 109u8, 98u8, 106u8, 108u8,
+                    0u8, 0u8, 0u8, 103u8, 110u8, 99u8, 107u8, 121u8, 0u8, 0u8, 0u8, 103u8, 113u8,
+                    115u8, 115u8, 103u8, 0u8, 0u8, 0u8, 103u8, 114u8, 97u8, 116u8, 104u8, 0u8, 0u8,
+                    0u8, 103u8, 115u8, 103u8, 114u8, 118u8, 0u8, 0u8, 0u8, 103u8, 116u8, 103u8,
+                    117u8, 97u8, 0u8, 0u8, 0u8, 103u8, 117u8, 103u8, 117u8, 109u8, 0u8, 0u8, 0u8,
+                    103u8, 119u8, 111u8, 120u8, 98u8, 0u8, 0u8, 0u8, 103u8, 121u8, 103u8, 101u8,
+                    111u8, 0u8, 0u8, 0u8, 104u8, 107u8, 104u8, 107u8, 103u8, 0u8, 0u8, 0u8, 104u8,
+                    110u8, 116u8, 103u8, 117u8, 0u8, 0u8, 0u8, 104u8, 114u8, 122u8, 97u8, 103u8,
+                    0u8, 0u8, 0u8, 104u8, 116u8, 112u8, 97u8, 112u8, 0u8, 0u8, 0u8, 104u8, 117u8,
+                    98u8, 117u8, 100u8, 0u8, 0u8, 0u8, 105u8, 101u8, 100u8, 117u8, 98u8, 0u8, 0u8,
+                    0u8, 105u8, 109u8, 100u8, 103u8, 115u8, 0u8, 0u8, 0u8, 105u8, 110u8, 99u8,
+                    99u8, 117u8, 0u8, 0u8, 0u8, 105u8, 111u8, 100u8, 103u8, 97u8, 0u8, 0u8, 0u8,
+                    105u8, 113u8, 98u8, 103u8, 119u8, 0u8, 0u8, 0u8, 105u8, 114u8, 116u8, 104u8,
+                    114u8, 0u8, 0u8, 0u8, 105u8, 115u8, 114u8, 101u8, 121u8, 0u8, 0u8, 0u8, 105u8,
+                    116u8, 114u8, 111u8, 109u8, 0u8, 0u8, 0u8, 106u8, 101u8, 114u8, 117u8, 115u8,
+                    108u8, 109u8, 0u8, 106u8, 101u8, 115u8, 116u8, 104u8, 0u8, 0u8, 0u8, 106u8,
+                    109u8, 107u8, 105u8, 110u8, 0u8, 0u8, 0u8, 106u8, 111u8, 97u8, 109u8, 109u8,
+                    0u8, 0u8, 0u8, 106u8, 112u8, 116u8, 121u8, 111u8, 0u8, 0u8, 0u8, 107u8, 101u8,
+                    110u8, 98u8, 111u8, 0u8, 0u8, 0u8, 107u8, 103u8, 102u8, 114u8, 117u8, 0u8, 0u8,
+                    0u8, 107u8, 104u8, 112u8, 110u8, 104u8, 0u8, 0u8, 0u8, 107u8, 109u8, 121u8,
+                    118u8, 97u8, 0u8, 0u8, 0u8, 107u8, 110u8, 98u8, 97u8, 115u8, 0u8, 0u8, 0u8,
+                    107u8, 112u8, 102u8, 110u8, 106u8, 0u8, 0u8, 0u8, 107u8, 114u8, 115u8, 101u8,
+                    108u8, 0u8, 0u8, 0u8, 107u8, 119u8, 107u8, 119u8, 105u8, 0u8, 0u8, 0u8, 107u8,
+                    121u8, 103u8, 101u8, 99u8, 0u8, 0u8, 0u8, 108u8, 97u8, 118u8, 116u8, 101u8,
+                    0u8, 0u8, 0u8, 108u8, 98u8, 98u8, 101u8, 121u8, 0u8, 0u8, 0u8, 108u8, 99u8,
+                    99u8, 97u8, 115u8, 0u8, 0u8, 0u8, 108u8, 105u8, 118u8, 100u8, 122u8, 0u8, 0u8,
+                    0u8, 108u8, 107u8, 99u8, 109u8, 98u8, 0u8, 0u8, 0u8, 108u8, 114u8, 109u8,
+                    108u8, 119u8, 0u8, 0u8, 0u8, 108u8, 115u8, 109u8, 115u8, 117u8, 0u8, 0u8, 0u8,
+                    108u8, 116u8, 118u8, 110u8, 111u8, 0u8, 0u8, 0u8, 108u8, 117u8, 108u8, 117u8,
+                    120u8, 0u8, 0u8, 0u8, 108u8, 118u8, 114u8, 105u8, 120u8, 0u8, 0u8, 0u8, 108u8,
+                    121u8, 116u8, 105u8, 112u8, 0u8, 0u8, 0u8, 109u8, 97u8, 99u8, 97u8, 115u8, 0u8,
+                    0u8, 0u8, 109u8, 99u8, 109u8, 111u8, 110u8, 0u8, 0u8, 0u8, 109u8, 100u8, 107u8,
+                    105u8, 118u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 103u8, 100u8, 0u8, 0u8, 0u8,
+                    109u8, 103u8, 116u8, 110u8, 114u8, 0u8, 0u8, 0u8, 109u8, 104u8, 109u8, 97u8,
+                    106u8, 0u8, 0u8, 0u8, 109u8, 107u8, 115u8, 107u8, 112u8, 0u8, 0u8, 0u8, 109u8,
+                    108u8, 98u8, 107u8, 111u8, 0u8, 0u8, 0u8, 109u8, 109u8, 114u8, 103u8, 110u8,
+                    0u8, 0u8, 0u8, 109u8, 111u8, 109u8, 102u8, 109u8, 0u8, 0u8, 0u8, 109u8, 112u8,
+                    115u8, 112u8, 110u8, 0u8, 0u8, 0u8, 109u8, 113u8, 102u8, 100u8, 102u8, 0u8,
+                    0u8, 0u8, 109u8, 114u8, 110u8, 107u8, 99u8, 0u8, 0u8, 0u8, 109u8, 115u8, 109u8,
+                    110u8, 105u8, 0u8, 0u8, 0u8, 109u8, 116u8, 109u8, 108u8, 97u8, 0u8, 0u8, 0u8,
+                    109u8, 117u8, 112u8, 108u8, 117u8, 0u8, 0u8, 0u8, 109u8, 118u8, 109u8, 108u8,
+                    101u8, 0u8, 0u8, 0u8, 109u8, 119u8, 98u8, 108u8, 122u8, 0u8, 0u8, 0u8, 109u8,
+                    121u8, 107u8, 117u8, 108u8, 0u8, 0u8, 0u8, 109u8, 122u8, 109u8, 112u8, 109u8,
+                    0u8, 0u8, 0u8, 110u8, 97u8, 119u8, 100u8, 104u8, 0u8, 0u8, 0u8, 110u8, 99u8,
+                    110u8, 111u8, 117u8, 0u8, 0u8, 0u8, 110u8, 101u8, 110u8, 105u8, 109u8, 0u8,
+                    0u8, 0u8, 110u8, 102u8, 110u8, 108u8, 107u8, 0u8, 0u8, 0u8, 110u8, 103u8,
+                    108u8, 111u8, 115u8, 0u8, 0u8, 0u8, 110u8, 105u8, 109u8, 103u8, 97u8, 0u8, 0u8,
+                    0u8, 110u8, 108u8, 97u8, 109u8, 115u8, 0u8, 0u8, 0u8, 110u8, 111u8, 111u8,
+                    115u8, 108u8, 0u8, 0u8, 0u8, 110u8, 112u8, 107u8, 116u8, 109u8, 0u8, 0u8, 0u8,
+                    110u8, 114u8, 105u8, 110u8, 117u8, 0u8, 0u8, 0u8, 110u8, 117u8, 105u8, 117u8,
+                    101u8, 0u8, 0u8, 0u8, 110u8, 122u8, 97u8, 107u8, 108u8, 0u8, 0u8, 0u8, 111u8,
+                    109u8, 109u8, 99u8, 116u8, 0u8, 0u8, 0u8, 112u8, 97u8, 112u8, 116u8, 121u8,
+                    0u8, 0u8, 0u8, 112u8, 101u8, 108u8, 105u8, 109u8, 0u8, 0u8, 0u8, 112u8, 104u8,
+                    109u8, 110u8, 108u8, 0u8, 0u8, 0u8, 112u8, 107u8, 107u8, 104u8, 105u8, 0u8,
+                    0u8, 0u8, 112u8, 108u8, 119u8, 97u8, 119u8, 0u8, 0u8, 0u8, 112u8, 109u8, 109u8,
+                    113u8, 99u8, 0u8, 0u8, 0u8, 112u8, 110u8, 112u8, 99u8, 110u8, 0u8, 0u8, 0u8,
+                    112u8, 114u8, 115u8, 106u8, 117u8, 0u8, 0u8, 0u8, 112u8, 116u8, 108u8, 105u8,
+                    115u8, 0u8, 0u8, 0u8, 112u8, 119u8, 114u8, 111u8, 114u8, 0u8, 0u8, 0u8, 112u8,
+                    121u8, 97u8, 115u8, 117u8, 0u8, 0u8, 0u8, 113u8, 97u8, 100u8, 111u8, 104u8,
+                    0u8, 0u8, 0u8, 114u8, 101u8, 114u8, 101u8, 117u8, 0u8, 0u8, 0u8, 114u8, 111u8,
+                    98u8, 117u8, 104u8, 0u8, 0u8, 0u8, 114u8, 115u8, 98u8, 101u8, 103u8, 0u8, 0u8,
+                    0u8, 114u8, 119u8, 107u8, 103u8, 108u8, 0u8, 0u8, 0u8, 115u8, 97u8, 114u8,
+                    117u8, 104u8, 0u8, 0u8, 0u8, 115u8, 98u8, 104u8, 105u8, 114u8, 0u8, 0u8, 0u8,
+                    115u8, 99u8, 109u8, 97u8, 119u8, 0u8, 0u8, 0u8, 115u8, 100u8, 107u8, 114u8,
+                    116u8, 0u8, 0u8, 0u8, 115u8, 101u8, 115u8, 116u8, 111u8, 0u8, 0u8, 0u8, 115u8,
+                    103u8, 115u8, 105u8, 110u8, 0u8, 0u8, 0u8, 115u8, 104u8, 115u8, 104u8, 110u8,
+                    0u8, 0u8, 0u8, 115u8, 105u8, 108u8, 106u8, 117u8, 0u8, 0u8, 0u8, 115u8, 106u8,
+                    108u8, 121u8, 114u8, 0u8, 0u8, 0u8, 115u8, 107u8, 98u8, 116u8, 115u8, 0u8, 0u8,
+                    0u8, 115u8, 108u8, 102u8, 110u8, 97u8, 0u8, 0u8, 0u8, 115u8, 109u8, 115u8,
+                    97u8, 105u8, 0u8, 0u8, 0u8, 115u8, 110u8, 100u8, 107u8, 114u8, 0u8, 0u8, 0u8,
+                    115u8, 111u8, 109u8, 103u8, 113u8, 0u8, 0u8, 0u8, 115u8, 114u8, 112u8, 98u8,
+                    109u8, 0u8, 0u8, 0u8, 115u8, 115u8, 106u8, 117u8, 98u8, 0u8, 0u8, 0u8, 115u8,
+                    116u8, 116u8, 109u8, 115u8, 0u8, 0u8, 0u8, 115u8, 118u8, 115u8, 97u8, 108u8,
+                    0u8, 0u8, 0u8, 115u8, 120u8, 112u8, 104u8, 105u8, 0u8, 0u8, 0u8, 115u8, 121u8,
+                    100u8, 97u8, 109u8, 0u8, 0u8, 0u8, 115u8, 122u8, 113u8, 109u8, 110u8, 0u8, 0u8,
+                    0u8, 116u8, 99u8, 103u8, 100u8, 116u8, 0u8, 0u8, 0u8, 116u8, 100u8, 110u8,
+                    100u8, 106u8, 0u8, 0u8, 0u8, 116u8, 102u8, 112u8, 102u8, 114u8, 0u8, 0u8, 0u8,
+                    116u8, 103u8, 108u8, 102u8, 119u8, 0u8, 0u8, 0u8, 116u8, 104u8, 98u8, 107u8,
+                    107u8, 0u8, 0u8, 0u8, 116u8, 106u8, 100u8, 121u8, 117u8, 0u8, 0u8, 0u8, 116u8,
+                    107u8, 102u8, 107u8, 111u8, 0u8, 0u8, 0u8, 116u8, 108u8, 100u8, 105u8, 108u8,
+                    0u8, 0u8, 0u8, 116u8, 109u8, 97u8, 115u8, 98u8, 0u8, 0u8, 0u8, 116u8, 110u8,
+                    116u8, 117u8, 110u8, 0u8, 0u8, 0u8, 116u8, 111u8, 116u8, 98u8, 117u8, 0u8, 0u8,
+                    0u8, 116u8, 114u8, 105u8, 115u8, 116u8, 0u8, 0u8, 0u8, 116u8, 116u8, 112u8,
+                    111u8, 115u8, 0u8, 0u8, 0u8, 116u8, 118u8, 102u8, 117u8, 110u8, 0u8, 0u8, 0u8,
+                    116u8, 119u8, 116u8, 112u8, 101u8, 0u8, 0u8, 0u8, 116u8, 122u8, 100u8, 97u8,
+                    114u8, 0u8, 0u8, 0u8, 117u8, 97u8, 105u8, 101u8, 118u8, 0u8, 0u8, 0u8, 117u8,
+                    103u8, 107u8, 108u8, 97u8, 0u8, 0u8, 0u8, 117u8, 121u8, 109u8, 118u8, 100u8,
+                    0u8, 0u8, 0u8, 117u8, 122u8, 116u8, 97u8, 115u8, 0u8, 0u8, 0u8, 118u8, 97u8,
+                    118u8, 97u8, 116u8, 0u8, 0u8, 0u8, 118u8, 99u8, 115u8, 118u8, 100u8, 0u8, 0u8,
+                    0u8, 118u8, 101u8, 99u8, 99u8, 115u8, 0u8, 0u8, 0u8, 118u8, 103u8, 116u8,
+                    111u8, 118u8, 0u8, 0u8, 0u8, 118u8, 105u8, 115u8, 116u8, 116u8, 0u8, 0u8, 0u8,
+                    118u8, 110u8, 115u8, 103u8, 110u8, 0u8, 0u8, 0u8, 118u8, 117u8, 118u8, 108u8,
+                    105u8, 0u8, 0u8, 0u8, 119u8, 102u8, 109u8, 97u8, 117u8, 0u8, 0u8, 0u8, 119u8,
+                    115u8, 97u8, 112u8, 119u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 100u8, 101u8,
+                    0u8, 0u8, 0u8, 121u8, 116u8, 109u8, 97u8, 109u8, 0u8, 0u8, 0u8, 122u8, 97u8,
+                    106u8, 110u8, 98u8, 0u8, 0u8, 0u8, 122u8, 109u8, 108u8, 117u8, 110u8, 0u8, 0u8,
+                    0u8, 122u8, 119u8, 104u8, 114u8, 101u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 68u8, 0u8, 65u8, 69u8, 0u8, 65u8, 70u8, 0u8, 65u8, 71u8, 0u8, 65u8, 73u8,
+                    0u8, 65u8, 76u8, 0u8, 65u8, 77u8, 0u8, 65u8, 78u8, 0u8, 65u8, 79u8, 0u8, 65u8,
+                    83u8, 0u8, 65u8, 84u8, 0u8, 65u8, 87u8, 0u8, 65u8, 90u8, 0u8, 66u8, 65u8, 0u8,
+                    66u8, 66u8, 0u8, 66u8, 68u8, 0u8, 66u8, 69u8, 0u8, 66u8, 70u8, 0u8, 66u8, 71u8,
+                    0u8, 66u8, 72u8, 0u8, 66u8, 73u8, 0u8, 66u8, 74u8, 0u8, 66u8, 77u8, 0u8, 66u8,
+                    78u8, 0u8, 66u8, 79u8, 0u8, 66u8, 81u8, 0u8, 66u8, 83u8, 0u8, 66u8, 84u8, 0u8,
+                    66u8, 87u8, 0u8, 66u8, 89u8, 0u8, 66u8, 90u8, 0u8, 67u8, 67u8, 0u8, 67u8, 70u8,
+                    0u8, 67u8, 71u8, 0u8, 67u8, 72u8, 0u8, 67u8, 73u8, 0u8, 67u8, 75u8, 0u8, 67u8,
+                    76u8, 0u8, 67u8, 77u8, 0u8, 67u8, 78u8, 0u8, 67u8, 79u8, 0u8, 67u8, 82u8, 0u8,
+                    67u8, 85u8, 0u8, 67u8, 86u8, 0u8, 67u8, 88u8, 0u8, 67u8, 90u8, 0u8, 68u8, 69u8,
+                    0u8, 68u8, 74u8, 0u8, 68u8, 75u8, 0u8, 68u8, 77u8, 0u8, 68u8, 79u8, 0u8, 68u8,
+                    90u8, 0u8, 69u8, 67u8, 0u8, 69u8, 69u8, 0u8, 69u8, 71u8, 0u8, 69u8, 72u8, 0u8,
+                    69u8, 82u8, 0u8, 69u8, 83u8, 0u8, 69u8, 84u8, 0u8, 70u8, 74u8, 0u8, 70u8, 75u8,
+                    0u8, 70u8, 79u8, 0u8, 70u8, 82u8, 0u8, 71u8, 65u8, 0u8, 71u8, 66u8, 0u8, 71u8,
+                    68u8, 0u8, 71u8, 69u8, 0u8, 71u8, 70u8, 0u8, 71u8, 71u8, 0u8, 71u8, 72u8, 0u8,
+                    71u8, 73u8, 0u8, 71u8, 77u8, 0u8, 71u8, 78u8, 0u8, 71u8, 81u8, 0u8, 71u8, 82u8,
+                    0u8, 71u8, 83u8, 0u8, 71u8, 84u8, 0u8, 71u8, 85u8, 0u8, 71u8, 87u8, 0u8, 71u8,
+                    89u8, 0u8, 72u8, 75u8, 0u8, 72u8, 78u8, 0u8, 72u8, 82u8, 0u8, 72u8, 84u8, 0u8,
+                    72u8, 85u8, 0u8, 73u8, 69u8, 0u8, 73u8, 77u8, 0u8, 73u8, 78u8, 0u8, 73u8, 79u8,
+                    0u8, 73u8, 81u8, 0u8, 73u8, 82u8, 0u8, 73u8, 83u8, 0u8, 73u8, 84u8, 0u8, 73u8,
+                    76u8, 0u8, 74u8, 69u8, 0u8, 74u8, 77u8, 0u8, 74u8, 79u8, 0u8, 74u8, 80u8, 0u8,
+                    75u8, 69u8, 0u8, 75u8, 71u8, 0u8, 75u8, 72u8, 0u8, 75u8, 77u8, 0u8, 75u8, 78u8,
+                    0u8, 75u8, 80u8, 0u8, 75u8, 82u8, 0u8, 75u8, 87u8, 0u8, 75u8, 89u8, 0u8, 76u8,
+                    65u8, 0u8, 76u8, 66u8, 0u8, 76u8, 67u8, 0u8, 76u8, 73u8, 0u8, 76u8, 75u8, 0u8,
+                    76u8, 82u8, 0u8, 76u8, 83u8, 0u8, 76u8, 84u8, 0u8, 76u8, 85u8, 0u8, 76u8, 86u8,
+                    0u8, 76u8, 89u8, 0u8, 77u8, 65u8, 0u8, 77u8, 67u8, 0u8, 77u8, 68u8, 0u8, 77u8,
+                    69u8, 0u8, 77u8, 71u8, 0u8, 77u8, 72u8, 0u8, 77u8, 75u8, 0u8, 77u8, 76u8, 0u8,
+                    77u8, 77u8, 0u8, 77u8, 79u8, 0u8, 77u8, 80u8, 0u8, 77u8, 81u8, 0u8, 77u8, 82u8,
+                    0u8, 77u8, 83u8, 0u8, 77u8, 84u8, 0u8, 77u8, 85u8, 0u8, 77u8, 86u8, 0u8, 77u8,
+                    87u8, 0u8, 77u8, 89u8, 0u8, 77u8, 90u8, 0u8, 78u8, 65u8, 0u8, 78u8, 67u8, 0u8,
+                    78u8, 69u8, 0u8, 78u8, 70u8, 0u8, 78u8, 71u8, 0u8, 78u8, 73u8, 0u8, 78u8, 76u8,
+                    0u8, 78u8, 79u8, 0u8, 78u8, 80u8, 0u8, 78u8, 82u8, 0u8, 78u8, 85u8, 0u8, 78u8,
+                    90u8, 0u8, 79u8, 77u8, 0u8, 80u8, 65u8, 0u8, 80u8, 69u8, 0u8, 80u8, 72u8, 0u8,
+                    80u8, 75u8, 0u8, 80u8, 76u8, 0u8, 80u8, 77u8, 0u8, 80u8, 78u8, 0u8, 80u8, 82u8,
+                    0u8, 80u8, 84u8, 0u8, 80u8, 87u8, 0u8, 80u8, 89u8, 0u8, 81u8, 65u8, 0u8, 82u8,
+                    69u8, 0u8, 82u8, 79u8, 0u8, 82u8, 83u8, 0u8, 82u8, 87u8, 0u8, 83u8, 65u8, 0u8,
+                    83u8, 66u8, 0u8, 83u8, 67u8, 0u8, 83u8, 68u8, 0u8, 83u8, 69u8, 0u8, 83u8, 71u8,
+                    0u8, 83u8, 72u8, 0u8, 83u8, 73u8, 0u8, 83u8, 74u8, 0u8, 83u8, 75u8, 0u8, 83u8,
+                    76u8, 0u8, 83u8, 77u8, 0u8, 83u8, 78u8, 0u8, 83u8, 79u8, 0u8, 83u8, 82u8, 0u8,
+                    83u8, 83u8, 0u8, 83u8, 84u8, 0u8, 83u8, 86u8, 0u8, 83u8, 88u8, 0u8, 83u8, 89u8,
+                    0u8, 83u8, 90u8, 0u8, 84u8, 67u8, 0u8, 84u8, 68u8, 0u8, 84u8, 70u8, 0u8, 84u8,
+                    71u8, 0u8, 84u8, 72u8, 0u8, 84u8, 74u8, 0u8, 84u8, 75u8, 0u8, 84u8, 76u8, 0u8,
+                    84u8, 77u8, 0u8, 84u8, 78u8, 0u8, 84u8, 79u8, 0u8, 84u8, 82u8, 0u8, 84u8, 84u8,
+                    0u8, 84u8, 86u8, 0u8, 84u8, 87u8, 0u8, 84u8, 90u8, 0u8, 85u8, 65u8, 0u8, 85u8,
+                    71u8, 0u8, 85u8, 89u8, 0u8, 85u8, 90u8, 0u8, 86u8, 65u8, 0u8, 86u8, 67u8, 0u8,
+                    86u8, 69u8, 0u8, 86u8, 71u8, 0u8, 86u8, 73u8, 0u8, 86u8, 78u8, 0u8, 86u8, 85u8,
+                    0u8, 87u8, 70u8, 0u8, 87u8, 83u8, 0u8, 89u8, 69u8, 0u8, 89u8, 84u8, 0u8, 90u8,
+                    65u8, 0u8, 90u8, 77u8, 0u8, 90u8, 87u8, 0u8,
+                ])
+            },
+        )
+    },
+};
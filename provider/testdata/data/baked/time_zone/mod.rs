@@ -3,6 +3,9 @@ pub mod exemplar_cities_v1;
 pub mod formats_v1;
 pub mod generic_long_v1;
 pub mod generic_short_v1;
+pub mod identifiers_v1;
 pub mod metazone_period_v1;
+pub mod region_names_v1;
 pub mod specific_long_v1;
 pub mod specific_short_v1;
+pub mod windows_zones_v1;
@@ -0,0 +1,104 @@
+// @generated
+type DataStruct = & 'static < :: icu_units :: provider :: UnitsConversionV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("und", UND)];
+static UND: DataStruct = &::icu_units::provider::UnitsConversionV1 {
+    conversions: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+                    0u8, 14u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 39u8, 0u8,
+                    0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 68u8,
+                    0u8, 0u8, 0u8, 72u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8,
+                    91u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 106u8, 0u8,
+                    0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8,
+                    133u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 98u8, 105u8,
+                    116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8,
+                    115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8,
+                    111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8,
+                    97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8, 105u8,
+                    110u8, 99u8, 104u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 116u8, 111u8, 110u8, 101u8, 121u8, 97u8,
+                    114u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8,
+                    0u8, 69u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 158u8,
+                    0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 0u8, 29u8, 1u8, 0u8, 0u8,
+                    55u8, 1u8, 0u8, 0u8, 85u8, 1u8, 0u8, 0u8, 108u8, 1u8, 0u8, 0u8, 133u8, 1u8,
+                    0u8, 0u8, 166u8, 1u8, 0u8, 0u8, 188u8, 1u8, 0u8, 0u8, 220u8, 1u8, 0u8, 0u8,
+                    244u8, 1u8, 0u8, 0u8, 37u8, 2u8, 0u8, 0u8, 74u8, 2u8, 0u8, 0u8, 108u8, 2u8,
+                    0u8, 0u8, 174u8, 2u8, 0u8, 0u8, 197u8, 2u8, 0u8, 0u8, 234u8, 2u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    98u8, 105u8, 116u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                    0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 98u8, 105u8, 116u8, 56u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 101u8,
+                    108u8, 118u8, 105u8, 110u8, 49u8, 50u8, 55u8, 51u8, 46u8, 49u8, 53u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 49u8, 3u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 53u8, 47u8, 57u8, 50u8, 50u8, 57u8,
+                    56u8, 46u8, 51u8, 53u8, 47u8, 57u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    48u8, 46u8, 51u8, 48u8, 52u8, 56u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 50u8, 51u8, 49u8, 42u8, 48u8, 46u8, 51u8,
+                    48u8, 52u8, 56u8, 42u8, 48u8, 46u8, 51u8, 48u8, 52u8, 56u8, 42u8, 48u8, 46u8,
+                    51u8, 48u8, 52u8, 56u8, 47u8, 49u8, 50u8, 42u8, 49u8, 50u8, 42u8, 49u8, 50u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    48u8, 46u8, 48u8, 48u8, 52u8, 53u8, 52u8, 54u8, 48u8, 57u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 49u8, 47u8, 49u8, 48u8, 48u8, 48u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 51u8, 54u8, 48u8, 48u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 48u8, 46u8, 51u8, 48u8, 52u8, 56u8, 47u8, 49u8,
+                    50u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8,
+                    0u8, 0u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 49u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 49u8, 47u8, 49u8, 48u8, 48u8,
+                    48u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 48u8, 46u8, 51u8, 48u8, 52u8, 56u8, 42u8, 53u8, 50u8, 56u8, 48u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 54u8, 48u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 48u8, 46u8, 52u8, 53u8, 51u8, 53u8, 57u8, 50u8,
+                    51u8, 55u8, 47u8, 49u8, 54u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 48u8, 46u8, 52u8, 53u8, 51u8, 53u8, 57u8, 50u8, 51u8, 55u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 48u8, 46u8, 52u8,
+                    53u8, 51u8, 53u8, 57u8, 50u8, 51u8, 55u8, 42u8, 57u8, 46u8, 56u8, 48u8, 54u8,
+                    54u8, 53u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8,
+                    0u8, 0u8, 0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 49u8, 3u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 48u8, 46u8, 52u8, 53u8, 51u8, 53u8,
+                    57u8, 50u8, 51u8, 55u8, 42u8, 49u8, 52u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 48u8, 46u8, 51u8, 48u8, 52u8, 56u8, 42u8, 51u8,
+                ])
+            },
+        )
+    },
+};
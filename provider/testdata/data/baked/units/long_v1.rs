@@ -0,0 +1,1392 @@
+// @generated
+type DataStruct = & 'static < :: icu_units :: provider :: LongUnitPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG),
+    ("ar-EG", AR_AR_EG),
+    ("bn", BN),
+    ("ccp", CCP_UND),
+    ("en", EN_EN_001_EN_ZA),
+    ("en-001", EN_EN_001_EN_ZA),
+    ("en-ZA", EN_EN_001_EN_ZA),
+    ("es", ES_ES_AR),
+    ("es-AR", ES_ES_AR),
+    ("fil", FIL),
+    ("fr", FR),
+    ("ja", JA),
+    ("ru", RU),
+    ("sr", SR_SR_CYRL),
+    ("sr-Cyrl", SR_SR_CYRL),
+    ("sr-Latn", SR_LATN),
+    ("th", TH),
+    ("tr", TR),
+    ("und", CCP_UND),
+];
+static AR_AR_EG: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 2u8, 3u8, 5u8, 1u8, 2u8, 3u8, 4u8, 5u8, 1u8, 2u8, 3u8, 4u8, 5u8, 1u8, 5u8,
+                    1u8, 2u8, 3u8, 4u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 34u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 82u8, 0u8,
+                    0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8,
+                    164u8, 0u8, 0u8, 0u8, 182u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8, 226u8, 0u8,
+                    0u8, 0u8, 244u8, 0u8, 0u8, 0u8, 20u8, 1u8, 0u8, 0u8, 56u8, 1u8, 0u8, 0u8, 62u8,
+                    1u8, 0u8, 0u8, 72u8, 1u8, 0u8, 0u8, 86u8, 1u8, 0u8, 0u8, 100u8, 1u8, 0u8, 0u8,
+                    216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 169u8, 216u8, 179u8, 216u8,
+                    167u8, 216u8, 185u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 167u8, 216u8,
+                    170u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8,
+                    216u8, 169u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8,
+                    186u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 217u8, 131u8, 217u8, 138u8,
+                    217u8, 132u8, 217u8, 136u8, 216u8, 186u8, 216u8, 177u8, 216u8, 167u8, 217u8,
+                    133u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8,
+                    217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 186u8, 216u8, 177u8, 216u8,
+                    167u8, 217u8, 133u8, 216u8, 167u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 186u8, 216u8,
+                    177u8, 216u8, 167u8, 217u8, 133u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8,
+                    186u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 217u8, 131u8, 217u8, 138u8,
+                    217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8,
+                    131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 177u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 177u8, 216u8, 167u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 177u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 177u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8,
+                    133u8, 216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 129u8, 217u8, 138u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8,
+                    169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8,
+                    217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 129u8,
+                    217u8, 138u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 167u8,
+                    216u8, 185u8, 216u8, 169u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8,
+                    133u8, 216u8, 170u8, 216u8, 177u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 163u8, 217u8, 133u8, 216u8, 170u8, 216u8, 167u8, 216u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 216u8,
+                    170u8, 216u8, 177u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8,
+                    0u8, 64u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 216u8, 179u8, 216u8, 167u8,
+                    216u8, 185u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 132u8, 217u8,
+                    131u8, 217u8, 132u8, 32u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8,
+                    136u8, 216u8, 186u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 132u8, 217u8, 131u8, 217u8, 132u8, 32u8, 217u8, 131u8,
+                    217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 132u8, 217u8, 131u8, 217u8, 132u8,
+                    32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} لكل {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}.{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    216u8, 177u8, 216u8, 168u8, 216u8, 185u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    217u8, 131u8, 216u8, 185u8, 216u8, 168u8,
+                ])
+            },
+        )
+    },
+};
+static BN: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8,
+                    0u8, 81u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 152u8, 224u8, 166u8, 163u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8,
+                    151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 174u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 152u8, 224u8, 166u8, 163u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8,
+                    0u8, 57u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 224u8, 166u8, 152u8, 224u8,
+                    166u8, 163u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8,
+                    123u8, 48u8, 125u8, 47u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    123u8, 48u8, 125u8, 47u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 47u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} প\u{9cd}রতি {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 152u8, 224u8, 166u8,
+                    168u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static CCP_UND: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    47u8, 109u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 178u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 179u8,
+                ])
+            },
+        )
+    },
+};
+static EN_EN_001_EN_ZA: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 33u8, 0u8,
+                    0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 68u8, 0u8, 0u8, 0u8, 73u8,
+                    0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8,
+                    101u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+                    117u8, 115u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8, 102u8, 111u8, 111u8, 116u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8,
+                    117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8,
+                    109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 24u8, 0u8,
+                    0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 32u8,
+                    0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8,
+                    0u8, 58u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8, 0u8, 96u8, 0u8,
+                    0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8,
+                    130u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 148u8, 0u8, 0u8, 0u8, 160u8, 0u8,
+                    0u8, 0u8, 173u8, 0u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8,
+                    222u8, 0u8, 0u8, 0u8, 245u8, 0u8, 0u8, 0u8, 254u8, 0u8, 0u8, 0u8, 8u8, 1u8,
+                    0u8, 0u8, 17u8, 1u8, 0u8, 0u8, 27u8, 1u8, 0u8, 0u8, 35u8, 1u8, 0u8, 0u8, 44u8,
+                    1u8, 0u8, 0u8, 61u8, 1u8, 0u8, 0u8, 79u8, 1u8, 0u8, 0u8, 89u8, 1u8, 0u8, 0u8,
+                    100u8, 1u8, 0u8, 0u8, 109u8, 1u8, 0u8, 0u8, 119u8, 1u8, 0u8, 0u8, 129u8, 1u8,
+                    0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8,
+                    32u8, 67u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 67u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 103u8,
+                    114u8, 101u8, 101u8, 32u8, 70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 115u8, 32u8, 70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 123u8, 48u8, 125u8, 32u8, 102u8, 111u8, 111u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 101u8, 101u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 97u8, 109u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 104u8, 111u8, 117u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 111u8, 117u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    105u8, 110u8, 99u8, 104u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 99u8, 104u8,
+                    101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 32u8, 112u8, 101u8, 114u8, 32u8, 104u8, 111u8, 117u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 104u8, 111u8, 117u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 105u8, 116u8, 101u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 105u8, 116u8, 101u8, 114u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 101u8, 116u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    108u8, 101u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 108u8, 101u8, 32u8, 112u8, 101u8,
+                    114u8, 32u8, 104u8, 111u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    105u8, 108u8, 101u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 104u8, 111u8,
+                    117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 117u8, 116u8,
+                    101u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 33u8, 0u8,
+                    0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 54u8,
+                    0u8, 0u8, 0u8, 102u8, 111u8, 111u8, 116u8, 103u8, 114u8, 97u8, 109u8, 104u8,
+                    111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 112u8, 111u8, 117u8, 110u8,
+                    100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 36u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 81u8, 0u8,
+                    0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8,
+                    134u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8, 114u8, 32u8,
+                    102u8, 111u8, 111u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8, 114u8,
+                    32u8, 103u8, 114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8, 114u8,
+                    32u8, 104u8, 111u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8,
+                    114u8, 32u8, 105u8, 110u8, 99u8, 104u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8,
+                    114u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 101u8, 114u8, 32u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8,
+                    114u8, 32u8, 108u8, 105u8, 116u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 101u8, 114u8, 32u8, 109u8, 101u8, 116u8, 101u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 101u8, 114u8, 32u8, 109u8, 105u8, 110u8, 117u8, 116u8,
+                    101u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8, 114u8, 32u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 123u8, 48u8, 125u8, 32u8, 112u8, 101u8, 114u8, 32u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} per {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8,
+                    123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static ES_ES_AR: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8,
+                    0u8, 30u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 73u8, 0u8,
+                    0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 111u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    104u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 111u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 111u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8,
+                    114u8, 111u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 195u8, 179u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8, 112u8,
+                    111u8, 114u8, 32u8, 104u8, 111u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8,
+                    112u8, 111u8, 114u8, 32u8, 104u8, 111u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8,
+                    0u8, 47u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 112u8, 111u8, 114u8, 32u8,
+                    104u8, 111u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8, 112u8, 111u8, 114u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 111u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 111u8, 114u8, 32u8, 107u8, 105u8, 108u8, 195u8, 179u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 123u8, 48u8, 125u8, 32u8, 112u8, 111u8,
+                    114u8, 32u8, 109u8, 101u8, 116u8, 114u8, 111u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} por {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8,
+                    0u8, 27u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 67u8, 0u8,
+                    0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 97u8, 32u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    105u8, 108u8, 111u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 107u8, 105u8,
+                    108u8, 111u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 114u8, 111u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8,
+                    98u8, 97u8, 119u8, 97u8, 116u8, 32u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 110u8, 97u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 32u8, 98u8, 97u8, 119u8, 97u8, 116u8, 32u8, 111u8, 114u8,
+                    97u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8, 116u8, 114u8, 111u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 109u8, 101u8, 116u8, 114u8, 111u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 20u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 111u8, 114u8, 97u8, 115u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 47u8, 109u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} bawat {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 107u8, 117u8,
+                    119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 107u8, 117u8,
+                    98u8, 105u8, 107u8, 111u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8,
+                    0u8, 33u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 77u8, 0u8,
+                    0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 101u8, 117u8, 114u8, 101u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 101u8, 117u8, 114u8, 101u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 109u8, 101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 195u8, 168u8,
+                    116u8, 114u8, 101u8, 32u8, 195u8, 160u8, 32u8, 108u8, 226u8, 128u8, 153u8,
+                    104u8, 101u8, 117u8, 114u8, 101u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8, 32u8, 195u8,
+                    160u8, 32u8, 108u8, 226u8, 128u8, 153u8, 104u8, 101u8, 117u8, 114u8, 101u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8,
+                    0u8, 49u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 112u8, 97u8, 114u8, 32u8,
+                    104u8, 101u8, 117u8, 114u8, 101u8, 123u8, 48u8, 125u8, 32u8, 112u8, 97u8,
+                    114u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    101u8, 123u8, 48u8, 125u8, 32u8, 112u8, 97u8, 114u8, 32u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 97u8, 114u8, 32u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} par {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 115u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 117u8, 98u8, 101u8, 123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 98u8,
+                    101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8,
+                    0u8, 51u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 230u8,
+                    153u8, 130u8, 233u8, 150u8, 147u8, 123u8, 48u8, 125u8, 32u8, 227u8, 130u8,
+                    173u8, 227u8, 131u8, 173u8, 227u8, 130u8, 176u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 160u8, 123u8, 48u8, 125u8, 32u8, 227u8, 130u8, 173u8, 227u8, 131u8,
+                    173u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8,
+                    131u8, 171u8, 230u8, 153u8, 130u8, 233u8, 128u8, 159u8, 32u8, 123u8, 48u8,
+                    125u8, 32u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8, 161u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 123u8, 48u8,
+                    125u8, 32u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8,
+                    227u8, 131u8, 171u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+                    0u8, 48u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 230u8, 153u8, 130u8, 123u8,
+                    48u8, 125u8, 47u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 130u8,
+                    176u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 123u8, 48u8, 125u8, 47u8,
+                    227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8, 161u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 123u8, 48u8, 125u8, 47u8,
+                    227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8,
+                    171u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 185u8, 179u8, 230u8, 150u8,
+                    185u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 171u8, 139u8, 230u8, 150u8,
+                    185u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static RU: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 4u8, 5u8, 1u8, 4u8, 5u8, 1u8, 4u8, 5u8, 1u8, 4u8, 5u8, 1u8, 4u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 36u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 84u8, 0u8, 0u8, 0u8, 108u8, 0u8,
+                    0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8,
+                    204u8, 0u8, 0u8, 0u8, 238u8, 0u8, 0u8, 0u8, 14u8, 1u8, 0u8, 0u8, 26u8, 1u8,
+                    0u8, 0u8, 42u8, 1u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 209u8, 135u8, 208u8,
+                    176u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 209u8, 135u8, 208u8, 176u8,
+                    209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 208u8, 190u8, 208u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 188u8,
+                    208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    32u8, 208u8, 178u8, 32u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 32u8, 208u8, 178u8, 32u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                    32u8, 208u8, 178u8, 32u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8,
+                    0u8, 65u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 208u8, 178u8, 32u8, 209u8,
+                    135u8, 208u8, 176u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8,
+                    208u8, 176u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0} на {1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0} {1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 3u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8,
+                    0u8, 72u8, 0u8, 0u8, 0u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                    180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 185u8, 32u8, 123u8, 48u8, 125u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    209u8, 139u8, 209u8, 133u8, 32u8, 123u8, 48u8, 125u8, 208u8, 186u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 189u8, 209u8, 139u8, 209u8, 133u8, 32u8, 123u8, 48u8, 125u8, 208u8,
+                    186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 130u8, 208u8, 189u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8,
+                    123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 3u8, 4u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8,
+                    0u8, 72u8, 0u8, 0u8, 0u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 123u8, 48u8, 125u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 133u8, 32u8, 123u8, 48u8, 125u8, 208u8, 186u8, 209u8,
+                    131u8, 208u8, 177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 123u8, 48u8, 125u8, 208u8,
+                    186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8,
+                    123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 14u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 3u8, 5u8, 1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 23u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 61u8, 0u8,
+                    0u8, 0u8, 74u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8,
+                    128u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 167u8, 0u8,
+                    0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 116u8, 97u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 116u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 97u8, 114u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 97u8,
+                    114u8, 32u8, 110u8, 97u8, 32u8, 115u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 97u8, 32u8, 110u8,
+                    97u8, 32u8, 115u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 97u8, 114u8, 97u8, 32u8, 110u8, 97u8, 32u8, 115u8,
+                    97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8, 116u8, 97u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 101u8, 116u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 101u8, 116u8, 97u8, 114u8, 97u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 18u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 196u8, 141u8, 123u8, 48u8,
+                    125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 118u8, 97u8, 100u8, 114u8, 97u8,
+                    116u8, 110u8, 105u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 117u8, 98u8, 110u8, 105u8, 32u8,
+                    123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 14u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 3u8, 5u8, 1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8, 1u8, 3u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8,
+                    0u8, 34u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 98u8, 0u8,
+                    0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8,
+                    212u8, 0u8, 0u8, 0u8, 248u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 20u8, 1u8, 0u8,
+                    0u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                    184u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 176u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8,
+                    128u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 176u8,
+                    209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8,
+                    176u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 176u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8,
+                    209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 22u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 209u8, 135u8, 123u8, 48u8,
+                    125u8, 47u8, 208u8, 186u8, 208u8, 179u8, 123u8, 48u8, 125u8, 47u8, 208u8,
+                    186u8, 208u8, 188u8, 123u8, 48u8, 125u8, 47u8, 208u8, 188u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    208u8, 184u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    177u8, 208u8, 189u8, 208u8, 184u8, 32u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static TH: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8,
+                    0u8, 81u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 135u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 161u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 135u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8,
+                    0u8, 81u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 138u8, 224u8,
+                    184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 135u8, 123u8, 48u8, 125u8, 47u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    161u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 123u8, 48u8, 125u8, 47u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 123u8,
+                    48u8, 125u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 168u8, 224u8, 184u8, 129u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8,
+                ])
+            },
+        )
+    },
+};
+static TR: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 33u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    97u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 114u8, 101u8, 115u8, 97u8, 97u8, 116u8, 116u8, 101u8,
+                    32u8, 123u8, 48u8, 125u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 114u8, 101u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 33u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 115u8, 97u8, 97u8, 116u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 114u8, 101u8, 123u8, 48u8, 125u8, 47u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}-{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8,
+                    114u8, 101u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 107u8, 195u8,
+                    188u8, 112u8,
+                ])
+            },
+        )
+    },
+};
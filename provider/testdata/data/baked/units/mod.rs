@@ -0,0 +1,6 @@
+// @generated
+pub mod conversion_v1;
+pub mod long_v1;
+pub mod narrow_v1;
+pub mod preferences_v1;
+pub mod short_v1;
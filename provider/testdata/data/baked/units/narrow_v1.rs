@@ -0,0 +1,369 @@
+// @generated
+type DataStruct = & 'static < :: icu_units :: provider :: NarrowUnitPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[
+    ("ar", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("ar-EG", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("bn", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("ccp", CCP_RU_UND),
+    ("en", EN_EN_001_EN_ZA),
+    ("en-001", EN_EN_001_EN_ZA),
+    ("en-ZA", EN_EN_001_EN_ZA),
+    ("es", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("es-AR", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("fil", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("fr", FR),
+    ("ja", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("ru", CCP_RU_UND),
+    ("sr", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("sr-Cyrl", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("sr-Latn", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("th", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("tr", AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL),
+    ("und", CCP_RU_UND),
+];
+static AR_AR_EG_BN_ES_ES_AR_FIL_JA_SR_SR_CYRL: DataStruct =
+    &::icu_units::provider::UnitPatternsV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                        0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                        107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                        111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                        109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+                        111u8, 117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                        0u8, 5u8, 0u8, 0u8, 0u8,
+                    ])
+                },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+                        0u8, 14u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 104u8,
+                        123u8, 48u8, 125u8, 107u8, 103u8, 123u8, 48u8, 125u8, 107u8, 109u8, 123u8,
+                        48u8, 125u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 109u8,
+                    ])
+                },
+            )
+        },
+        per_unit_patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                        0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                        111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                        116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                        0u8, 17u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8,
+                        125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8,
+                        48u8, 125u8, 47u8, 109u8,
+                    ])
+                },
+            )
+        },
+        per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+        times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+        power2: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 178u8,
+                    ])
+                },
+            )
+        },
+        power3: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 179u8,
+                    ])
+                },
+            )
+        },
+    };
+static CCP_RU_UND: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    47u8, 109u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 178u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 179u8,
+                ])
+            },
+        )
+    },
+};
+static EN_EN_001_EN_ZA: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 33u8, 0u8,
+                    0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 68u8, 0u8, 0u8, 0u8, 73u8,
+                    0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8,
+                    101u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+                    117u8, 115u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8, 102u8, 111u8, 111u8, 116u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8,
+                    117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8,
+                    109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 13u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 31u8, 0u8,
+                    0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 52u8,
+                    0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8,
+                    71u8, 0u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    67u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    178u8, 123u8, 48u8, 125u8, 103u8, 123u8, 48u8, 125u8, 104u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 179u8, 123u8, 48u8, 125u8, 107u8, 103u8, 123u8, 48u8,
+                    125u8, 107u8, 109u8, 123u8, 48u8, 125u8, 107u8, 109u8, 47u8, 104u8, 123u8,
+                    48u8, 125u8, 76u8, 123u8, 48u8, 125u8, 109u8, 123u8, 48u8, 125u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 109u8, 112u8, 104u8, 123u8, 48u8, 125u8, 109u8, 123u8,
+                    48u8, 125u8, 35u8, 123u8, 48u8, 125u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 33u8, 0u8,
+                    0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 54u8,
+                    0u8, 0u8, 0u8, 102u8, 111u8, 111u8, 116u8, 103u8, 114u8, 97u8, 109u8, 104u8,
+                    111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 112u8, 111u8, 117u8, 110u8,
+                    100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 16u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 34u8, 0u8,
+                    0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 57u8,
+                    0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 102u8, 116u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 76u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8,
+                    123u8, 48u8, 125u8, 47u8, 115u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 178u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 179u8,
+                ])
+            },
+        )
+    },
+};
+static FR: DataStruct = &::icu_units::provider::UnitPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 23u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 104u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 109u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    47u8, 109u8,
+                ])
+            },
+        )
+    },
+    per: ::alloc::borrow::Cow::Borrowed("{0}/{1}"),
+    times: ::alloc::borrow::Cow::Borrowed("{0}⋅{1}"),
+    power2: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 178u8,
+                ])
+            },
+        )
+    },
+    power3: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 48u8, 125u8, 194u8, 179u8,
+                ])
+            },
+        )
+    },
+};
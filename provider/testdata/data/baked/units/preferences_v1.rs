@@ -0,0 +1,145 @@
+// @generated
+type DataStruct = & 'static < :: icu_units :: provider :: UnitPreferencesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: &[(&str, DataStruct)] = &[("und", UND)];
+static UND: DataStruct = &::icu_units::provider::UnitPreferencesV1 {
+    preferences: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8,
+                    0u8, 58u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 92u8, 0u8,
+                    0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8,
+                    116u8, 105u8, 111u8, 110u8, 45u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8,
+                    101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+                    104u8, 45u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 108u8, 101u8,
+                    110u8, 103u8, 116u8, 104u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+                    45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 45u8, 114u8, 111u8, 97u8, 100u8, 109u8, 97u8, 115u8, 115u8, 45u8,
+                    100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 109u8, 97u8, 115u8, 115u8,
+                    45u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 116u8, 101u8, 109u8, 112u8,
+                    101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 45u8, 100u8, 101u8, 102u8,
+                    97u8, 117u8, 108u8, 116u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8,
+                    116u8, 117u8, 114u8, 101u8, 45u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 29u8, 0u8,
+                    0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    48u8, 48u8, 49u8, 71u8, 66u8, 0u8, 85u8, 83u8, 0u8, 48u8, 48u8, 49u8, 85u8,
+                    83u8, 0u8, 48u8, 48u8, 49u8, 85u8, 83u8, 0u8, 48u8, 48u8, 49u8, 71u8, 66u8,
+                    0u8, 85u8, 83u8, 0u8, 48u8, 48u8, 49u8, 85u8, 83u8, 0u8, 48u8, 48u8, 49u8,
+                    71u8, 66u8, 0u8, 85u8, 83u8, 0u8, 48u8, 48u8, 49u8, 66u8, 83u8, 0u8, 66u8,
+                    90u8, 0u8, 75u8, 89u8, 0u8, 80u8, 82u8, 0u8, 80u8, 87u8, 0u8, 85u8, 83u8, 0u8,
+                    48u8, 48u8, 49u8, 66u8, 83u8, 0u8, 66u8, 90u8, 0u8, 75u8, 89u8, 0u8, 80u8,
+                    82u8, 0u8, 80u8, 87u8, 0u8, 85u8, 83u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8,
+                    0u8, 134u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 254u8, 0u8, 0u8, 0u8, 32u8,
+                    1u8, 0u8, 0u8, 82u8, 1u8, 0u8, 0u8, 147u8, 1u8, 0u8, 0u8, 205u8, 1u8, 0u8, 0u8,
+                    7u8, 2u8, 0u8, 0u8, 52u8, 2u8, 0u8, 0u8, 95u8, 2u8, 0u8, 0u8, 127u8, 2u8, 0u8,
+                    0u8, 166u8, 2u8, 0u8, 0u8, 195u8, 2u8, 0u8, 0u8, 226u8, 2u8, 0u8, 0u8, 4u8,
+                    3u8, 0u8, 0u8, 38u8, 3u8, 0u8, 0u8, 72u8, 3u8, 0u8, 0u8, 106u8, 3u8, 0u8, 0u8,
+                    140u8, 3u8, 0u8, 0u8, 174u8, 3u8, 0u8, 0u8, 205u8, 3u8, 0u8, 0u8, 239u8, 3u8,
+                    0u8, 0u8, 17u8, 4u8, 0u8, 0u8, 51u8, 4u8, 0u8, 0u8, 85u8, 4u8, 0u8, 0u8, 119u8,
+                    4u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    23u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 105u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8,
+                    111u8, 110u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8,
+                    0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    14u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 49u8, 49u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+                    0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 109u8, 105u8, 108u8, 101u8, 102u8, 111u8, 111u8, 116u8, 105u8, 110u8,
+                    99u8, 104u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 49u8,
+                    49u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 29u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+                    0u8, 0u8, 102u8, 111u8, 111u8, 116u8, 45u8, 97u8, 110u8, 100u8, 45u8, 105u8,
+                    110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 48u8, 46u8, 57u8, 51u8, 48u8,
+                    48u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 109u8,
+                    105u8, 108u8, 101u8, 121u8, 97u8, 114u8, 100u8, 121u8, 97u8, 114u8, 100u8, 2u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 48u8, 46u8, 53u8, 49u8,
+                    48u8, 48u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 3u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    109u8, 105u8, 108u8, 101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 111u8,
+                    116u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 48u8, 46u8,
+                    53u8, 49u8, 48u8, 48u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 103u8, 114u8, 97u8, 109u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    22u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+                    0u8, 112u8, 111u8, 117u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 16u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 115u8, 116u8, 111u8, 110u8, 101u8, 45u8, 97u8, 110u8, 100u8, 45u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8,
+                    101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8,
+                    97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 0u8, 0u8, 0u8,
+                    0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
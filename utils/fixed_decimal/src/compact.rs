@@ -0,0 +1,242 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A decimal with a compact or scientific exponent.
+
+use core::fmt;
+use core::str::FromStr;
+use writeable::Writeable;
+
+use crate::Error;
+use crate::FixedDecimal;
+
+/// A decimal number written as a significand and a power of ten, as in compact notation
+/// ("1.2M") or scientific notation ("1.2E6" or "1.2E-3").
+///
+/// Unlike a [`FixedDecimal`] that has been multiplied by the power of ten, a [`CompactDecimal`]
+/// remembers the exponent, which is needed for plural rule selection: the CLDR operands `c` and
+/// `e` are the exponent, so that for example 1.2 million can select a different plural form
+/// than 1,200,000 in French.
+///
+/// The string syntax is the one used by the
+/// [CLDR plural rule samples](https://unicode.org/reports/tr35/tr35-numbers.html#Samples):
+/// the significand in [`FixedDecimal`] syntax, followed by `c` and the exponent if the exponent
+/// is nonzero. Negative exponents, which occur in scientific notation, are written with a
+/// minus sign, such as "1.2c-3".
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::{CompactDecimal, FixedDecimal};
+/// use writeable::Writeable;
+///
+/// let compact: CompactDecimal = "1.2c6".parse().expect("valid syntax");
+/// assert_eq!(compact.significand(), &"1.2".parse::<FixedDecimal>().unwrap());
+/// assert_eq!(compact.exponent(), 6);
+/// assert_eq!(compact.write_to_string(), "1.2c6");
+///
+/// let scientific: CompactDecimal = "1.2c-3".parse().expect("valid syntax");
+/// assert_eq!(scientific.exponent(), -3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactDecimal {
+    significand: FixedDecimal,
+    exponent: i16,
+}
+
+impl CompactDecimal {
+    /// Constructs a [`CompactDecimal`] from its significand and exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    /// use writeable::Writeable;
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(FixedDecimal::from(15), 3);
+    /// assert_eq!(compact.write_to_string(), "15c3");
+    /// ```
+    pub fn from_significand_and_exponent(significand: FixedDecimal, exponent: i16) -> Self {
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Returns the significand of this [`CompactDecimal`], for example 1.2 for 1.2c6.
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns the significand of this [`CompactDecimal`], consuming it.
+    pub fn into_significand(self) -> FixedDecimal {
+        self.significand
+    }
+
+    /// Returns the exponent of this [`CompactDecimal`], for example 6 for 1.2c6.
+    pub fn exponent(&self) -> i16 {
+        self.exponent
+    }
+
+    /// Returns the value of this [`CompactDecimal`] as a [`FixedDecimal`], for example
+    /// 1200000 for 1.2c6.
+    ///
+    /// Returns [`Error::Limit`] if the value exceeds the bounds of a [`FixedDecimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    ///
+    /// let compact: CompactDecimal = "1.2c6".parse().expect("valid syntax");
+    /// assert_eq!(compact.to_fixed_decimal(), Ok(FixedDecimal::from(1_200_000)));
+    /// ```
+    pub fn to_fixed_decimal(&self) -> Result<FixedDecimal, Error> {
+        self.significand.clone().multiplied_pow10(self.exponent)
+    }
+}
+
+impl From<FixedDecimal> for CompactDecimal {
+    /// Wraps a [`FixedDecimal`] as a [`CompactDecimal`] with exponent zero.
+    fn from(significand: FixedDecimal) -> Self {
+        Self::from_significand_and_exponent(significand, 0)
+    }
+}
+
+impl Writeable for CompactDecimal {
+    /// Renders the significand, followed by `c` and the exponent if the exponent is nonzero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    /// use writeable::Writeable;
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(FixedDecimal::from(-3), 0);
+    /// assert_eq!(compact.write_to_string(), "-3");
+    /// ```
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.significand.write_to(sink)?;
+        if self.exponent != 0 {
+            sink.write_char('c')?;
+            self.exponent.write_to(sink)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> writeable::LengthHint {
+        let len = self.significand.write_len();
+        if self.exponent == 0 {
+            len
+        } else {
+            len + 1 + self.exponent.write_len()
+        }
+    }
+}
+
+/// Renders the `CompactDecimal` according to the syntax documented in `CompactDecimal::write_to`.
+impl fmt::Display for CompactDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl FromStr for CompactDecimal {
+    type Err = Error;
+    /// Parses a significand in [`FixedDecimal`] syntax, optionally followed by `c` and an
+    /// exponent with an optional minus sign, such as "1.2c6", "1.2c-3", or "12".
+    fn from_str(input_str: &str) -> Result<Self, Self::Err> {
+        let (significand, exponent) = match input_str.split_once('c') {
+            Some((significand, exponent)) => {
+                let digits = exponent.strip_prefix('-').unwrap_or(exponent);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(Error::Syntax);
+                }
+                let exponent = exponent.parse::<i16>().map_err(|_| Error::Limit)?;
+                (significand, exponent)
+            }
+            None => (input_str, 0),
+        };
+        Ok(Self::from_significand_and_exponent(
+            significand.parse()?,
+            exponent,
+        ))
+    }
+}
+
+#[test]
+fn test_compact_syntax() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub expected: Result<&'static str, Error>,
+    }
+    let cases = [
+        TestCase {
+            input: "1.2c6",
+            expected: Ok("1.2c6"),
+        },
+        TestCase {
+            input: "-1.0000001c6",
+            expected: Ok("-1.0000001c6"),
+        },
+        TestCase {
+            input: "1.20c3",
+            expected: Ok("1.20c3"),
+        },
+        TestCase {
+            input: "42",
+            expected: Ok("42"),
+        },
+        TestCase {
+            input: "42c0",
+            expected: Ok("42"),
+        },
+        TestCase {
+            input: "1c",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "c6",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1.2c-3",
+            expected: Ok("1.2c-3"),
+        },
+        TestCase {
+            input: "1c-",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1c+6",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1c6c6",
+            expected: Err(Error::Syntax),
+        },
+        TestCase {
+            input: "1c32768",
+            expected: Err(Error::Limit),
+        },
+    ];
+    for case in cases.iter() {
+        let result = case.input.parse::<CompactDecimal>();
+        match (&result, &case.expected) {
+            (Ok(compact), Ok(expected)) => {
+                writeable::assert_writeable_eq!(compact, *expected, "{:?}", case);
+            }
+            (Err(err), Err(expected)) => assert_eq!(err, expected, "{:?}", case),
+            _ => panic!("{:?}: got {:?}", case, result),
+        }
+    }
+
+    let compact: CompactDecimal = "1.5c3".parse().unwrap();
+    assert_eq!(compact.to_fixed_decimal(), Ok(FixedDecimal::from(1500)));
+    assert_eq!(compact.write_len(), writeable::LengthHint::exact(5));
+    let compact: CompactDecimal = "1.5c-3".parse().unwrap();
+    assert_eq!(compact.to_fixed_decimal(), "0.0015".parse());
+    assert_eq!(compact.write_len(), writeable::LengthHint::exact(6));
+}
@@ -6,8 +6,9 @@ use smallvec::SmallVec;
 
 use core::cmp;
 use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt;
-use core::ops::RangeInclusive;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign};
 
 use core::str::FromStr;
 
@@ -129,6 +130,44 @@ pub enum Sign {
     Positive,
 }
 
+/// A mode for rounding a number at a position, used by [`FixedDecimal::round`] and
+/// [`FixedDecimal::checked_div`].
+///
+/// The modes follow the rounding modes of ECMA-402 `Intl.NumberFormat`, and the default is
+/// [`RoundingMode::HalfExpand`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Round towards positive infinity, like [`FixedDecimal::ceil`].
+    Ceil,
+    /// Round towards negative infinity, like [`FixedDecimal::floor`].
+    Floor,
+    /// Round away from zero, like [`FixedDecimal::expand`].
+    Expand,
+    /// Round towards zero, like [`FixedDecimal::truncate_right`].
+    Trunc,
+    /// Round to the nearest number, with ties towards positive infinity, like
+    /// [`FixedDecimal::half_ceil`].
+    HalfCeil,
+    /// Round to the nearest number, with ties towards negative infinity, like
+    /// [`FixedDecimal::half_floor`].
+    HalfFloor,
+    /// Round to the nearest number, with ties away from zero, like
+    /// [`FixedDecimal::half_expand`].
+    HalfExpand,
+    /// Round to the nearest number, with ties towards zero, like
+    /// [`FixedDecimal::half_truncate_right`].
+    HalfTrunc,
+    /// Round to the nearest number, with ties to the even number, like
+    /// [`FixedDecimal::half_even`].
+    HalfEven,
+}
+
+impl Default for RoundingMode {
+    fn default() -> Self {
+        Self::HalfExpand
+    }
+}
+
 impl Default for FixedDecimal {
     /// Returns a `FixedDecimal` representing zero.
     fn default() -> Self {
@@ -1603,6 +1642,449 @@ impl FixedDecimal {
     }
 }
 
+/// The digits of a non-negative integer in ascending order of magnitude, without zeros above
+/// the most significant nonzero digit. Zero has no digits.
+type Coefficient = SmallVec<[u8; 8]>;
+
+/// Removes the zeros above the most significant nonzero digit.
+fn trim_coefficient(coefficient: &mut Coefficient) {
+    while coefficient.last() == Some(&0) {
+        coefficient.pop();
+    }
+}
+
+fn cmp_coefficients(a: &[u8], b: &[u8]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_coefficients(a: &[u8], b: &[u8]) -> Coefficient {
+    let len = cmp::max(a.len(), b.len());
+    let mut result = Coefficient::with_capacity(len + 1);
+    let mut carry = 0;
+    for i in 0..len {
+        let sum = a.get(i).copied().unwrap_or(0) + b.get(i).copied().unwrap_or(0) + carry;
+        result.push(sum % 10);
+        carry = sum / 10;
+    }
+    if carry != 0 {
+        result.push(carry);
+    }
+    result
+}
+
+/// Computes `a - b` where `a >= b`.
+fn sub_coefficients(a: &[u8], b: &[u8]) -> Coefficient {
+    debug_assert_ne!(cmp_coefficients(a, b), Ordering::Less);
+    let mut result = Coefficient::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let y = b.get(i).copied().unwrap_or(0) + borrow;
+        if x >= y {
+            result.push(x - y);
+            borrow = 0;
+        } else {
+            result.push(x + 10 - y);
+            borrow = 1;
+        }
+    }
+    trim_coefficient(&mut result);
+    result
+}
+
+fn mul_coefficients(a: &[u8], b: &[u8]) -> Coefficient {
+    let mut result = Coefficient::new();
+    if a.is_empty() || b.is_empty() {
+        return result;
+    }
+    result.resize(a.len() + b.len(), 0);
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, digit) in result.iter_mut().skip(i).enumerate() {
+            if j >= b.len() && carry == 0 {
+                break;
+            }
+            // At most 9 + 9 * 9 + 9 = 99
+            let value = *digit + x * b.get(j).copied().unwrap_or(0) + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+    }
+    trim_coefficient(&mut result);
+    result
+}
+
+/// Computes the quotient of `a` and `b`, where `b` is nonzero, and whether the division leaves
+/// a remainder.
+fn div_coefficients(a: &[u8], b: &[u8]) -> (Coefficient, bool) {
+    debug_assert!(!b.is_empty());
+    let mut quotient = Coefficient::with_capacity(a.len());
+    let mut remainder = Coefficient::new();
+    for &digit in a.iter().rev() {
+        remainder.insert(0, digit);
+        trim_coefficient(&mut remainder);
+        let mut q = 0;
+        while cmp_coefficients(&remainder, b) != Ordering::Less {
+            remainder = sub_coefficients(&remainder, b);
+            q += 1;
+        }
+        quotient.push(q);
+    }
+    quotient.reverse();
+    trim_coefficient(&mut quotient);
+    (quotient, !remainder.is_empty())
+}
+
+/// Multiplies a coefficient by a power of 10.
+fn shift_coefficient(coefficient: Coefficient, delta: usize) -> Coefficient {
+    if coefficient.is_empty() || delta == 0 {
+        return coefficient;
+    }
+    let mut result = Coefficient::with_capacity(coefficient.len() + delta);
+    result.resize(delta, 0);
+    result.extend(coefficient);
+    result
+}
+
+impl FixedDecimal {
+    /// Returns the digits of the absolute value in ascending order of magnitude, starting at
+    /// `lower`, which must not exceed the magnitude of the lowest nonzero digit.
+    fn coefficient(&self, lower: i16) -> Coefficient {
+        let mut result = Coefficient::new();
+        if !self.is_zero() {
+            let zeros = crate::ops::i16_abs_sub(self.nonzero_magnitude_right(), lower) as usize;
+            result.resize(zeros, 0);
+            result.extend(self.digits.iter().rev().copied());
+        }
+        result
+    }
+
+    /// Returns the upper magnitude if the number has leading zeros, and 0 otherwise.
+    fn padded_upper_magnitude(&self) -> i16 {
+        if self.upper_magnitude > self.magnitude {
+            self.upper_magnitude
+        } else {
+            0
+        }
+    }
+
+    /// Creates a `FixedDecimal` from the digits of its absolute value in ascending order of
+    /// magnitude, starting at `lower`. The visible range of magnitudes includes
+    /// `upper_magnitude` and `lower_magnitude`, which must not be below and above zero.
+    fn from_coefficient(
+        coefficient: &[u8],
+        lower: i32,
+        negative: bool,
+        upper_magnitude: i16,
+        lower_magnitude: i16,
+    ) -> Result<Self, Error> {
+        let mut result = FixedDecimal {
+            upper_magnitude,
+            lower_magnitude,
+            ..Default::default()
+        };
+        if let Some(top) = coefficient.iter().rposition(|&d| d != 0) {
+            let bottom = coefficient.iter().position(|&d| d != 0).unwrap_or(top);
+            let magnitude = lower + top as i32;
+            let bottom_magnitude = lower + bottom as i32;
+            result.magnitude = i16::try_from(magnitude).map_err(|_| Error::Limit)?;
+            let bottom_magnitude = i16::try_from(bottom_magnitude).map_err(|_| Error::Limit)?;
+            result.upper_magnitude = cmp::max(result.upper_magnitude, result.magnitude);
+            result.lower_magnitude = cmp::min(result.lower_magnitude, bottom_magnitude);
+            result.digits.extend(
+                coefficient
+                    .iter()
+                    .skip(bottom)
+                    .take(top - bottom + 1)
+                    .rev()
+                    .copied(),
+            );
+        }
+        if negative {
+            result.sign = Sign::Negative;
+        }
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Adds another `FixedDecimal` to this one exactly, returning a new object if successful.
+    ///
+    /// The result shows the leading and trailing zeros of both operands, so that adding
+    /// "1.50" and "02" gives "03.50". It has a minus sign if it is negative, or if both operands
+    /// are negative zeros, and no sign otherwise.
+    ///
+    /// Can fail if the magnitude of the result does not fit in an i16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let a: FixedDecimal = "1.50".parse().unwrap();
+    /// let b: FixedDecimal = "-12".parse().unwrap();
+    ///
+    /// assert_eq!("-10.50", a.checked_add(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, Error> {
+        let lower = cmp::min(self.lower_magnitude, rhs.lower_magnitude);
+        let a = self.coefficient(lower);
+        let b = rhs.coefficient(lower);
+        let a_negative = self.sign == Sign::Negative;
+        let b_negative = rhs.sign == Sign::Negative;
+        let (coefficient, negative) = if a_negative == b_negative {
+            (add_coefficients(&a, &b), a_negative)
+        } else {
+            match cmp_coefficients(&a, &b) {
+                Ordering::Less => (sub_coefficients(&b, &a), b_negative),
+                Ordering::Equal => (Coefficient::new(), false),
+                Ordering::Greater => (sub_coefficients(&a, &b), a_negative),
+            }
+        };
+        Self::from_coefficient(
+            &coefficient,
+            lower.into(),
+            negative,
+            cmp::max(self.padded_upper_magnitude(), rhs.padded_upper_magnitude()),
+            lower,
+        )
+    }
+
+    /// Subtracts another `FixedDecimal` from this one exactly, returning a new object if
+    /// successful.
+    ///
+    /// The result shows the leading and trailing zeros of both operands, as with
+    /// [`FixedDecimal::checked_add`].
+    ///
+    /// Can fail if the magnitude of the result does not fit in an i16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let a: FixedDecimal = "0.3".parse().unwrap();
+    /// let b: FixedDecimal = "0.1".parse().unwrap();
+    ///
+    /// assert_eq!("0.2", a.checked_sub(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, Error> {
+        self.checked_add(&-rhs.clone())
+    }
+
+    /// Multiplies this `FixedDecimal` by another one exactly, returning a new object if
+    /// successful.
+    ///
+    /// The result has as many fraction digits as both operands together, so that multiplying
+    /// "1.50" and "2.0" gives "3.000", and shows the leading zeros of both operands. It has a
+    /// minus sign if exactly one operand has one, and no sign otherwise.
+    ///
+    /// Can fail if the magnitude of the result does not fit in an i16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let a: FixedDecimal = "1.50".parse().unwrap();
+    /// let b: FixedDecimal = "-2.0".parse().unwrap();
+    ///
+    /// assert_eq!("-3.000", a.checked_mul(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, Error> {
+        let a = self.coefficient(self.lower_magnitude);
+        let b = rhs.coefficient(rhs.lower_magnitude);
+        let lower = i32::from(self.lower_magnitude) + i32::from(rhs.lower_magnitude);
+        Self::from_coefficient(
+            &mul_coefficients(&a, &b),
+            lower,
+            (self.sign == Sign::Negative) != (rhs.sign == Sign::Negative),
+            cmp::max(self.padded_upper_magnitude(), rhs.padded_upper_magnitude()),
+            i16::try_from(lower).map_err(|_| Error::Limit)?,
+        )
+    }
+
+    /// Divides this `FixedDecimal` by another one, rounding the quotient at a particular
+    /// position with a rounding mode, and returning a new object if successful.
+    ///
+    /// The quotient is padded with trailing zeros up to the position, as with
+    /// [`FixedDecimal::pad_right`]. It has a minus sign if exactly one operand has one, and no
+    /// sign otherwise.
+    ///
+    /// Fails with [`Error::DivisionByZero`] if the divisor is zero, and with [`Error::Limit`] if
+    /// the magnitude of the quotient does not fit in an i16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    ///
+    /// let a = FixedDecimal::from(10);
+    /// let b = FixedDecimal::from(3);
+    ///
+    /// assert_eq!(
+    ///     "3.33",
+    ///     a.checked_div(&b, -2, RoundingMode::HalfExpand)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "4",
+    ///     a.checked_div(&b, 0, RoundingMode::Ceil)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "0.2500",
+    ///     FixedDecimal::from(1)
+    ///         .checked_div(&FixedDecimal::from(4), -4, RoundingMode::HalfEven)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn checked_div(
+        &self,
+        rhs: &Self,
+        position: i16,
+        mode: RoundingMode,
+    ) -> Result<Self, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        let a = self.coefficient(self.lower_magnitude);
+        let b = rhs.coefficient(rhs.lower_magnitude);
+        // The quotient is computed down to the digit below the position, which decides whether
+        // to round up, together with whether there is a remainder.
+        let mut lower = i32::from(position) - 1;
+        let shift = i32::from(self.lower_magnitude) - i32::from(rhs.lower_magnitude) - lower;
+        let (a, b) = if shift >= 0 {
+            (shift_coefficient(a, shift as usize), b)
+        } else {
+            (a, shift_coefficient(b, shift.unsigned_abs() as usize))
+        };
+        let (mut quotient, inexact) = div_coefficients(&a, &b);
+        if inexact {
+            // A nonzero digit below the deciding digit breaks ties.
+            quotient.insert(0, 1);
+            lower -= 1;
+        }
+        let mut result = Self::from_coefficient(
+            &quotient,
+            lower,
+            (self.sign == Sign::Negative) != (rhs.sign == Sign::Negative),
+            0,
+            0,
+        )?;
+        result.round(position, mode);
+        result.pad_right(position);
+        Ok(result)
+    }
+
+    /// Rounds the number at a particular position with a rounding mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-1.5").unwrap();
+    /// dec.round(0, RoundingMode::HalfEven);
+    /// assert_eq!("-2", dec.to_string());
+    /// let mut dec = FixedDecimal::from_str("-1.5").unwrap();
+    /// dec.round(0, RoundingMode::Ceil);
+    /// assert_eq!("-1", dec.to_string());
+    /// ```
+    pub fn round(&mut self, position: i16, mode: RoundingMode) {
+        match mode {
+            RoundingMode::Ceil => self.ceil(position),
+            RoundingMode::Floor => self.floor(position),
+            RoundingMode::Expand => self.expand(position),
+            RoundingMode::Trunc => self.truncate_right(position),
+            RoundingMode::HalfCeil => self.half_ceil(position),
+            RoundingMode::HalfFloor => self.half_floor(position),
+            RoundingMode::HalfExpand => self.half_expand(position),
+            RoundingMode::HalfTrunc => self.half_truncate_right(position),
+            RoundingMode::HalfEven => self.half_even(position),
+        }
+    }
+
+    /// Rounds the number at a particular position with a rounding mode, consuming self and
+    /// returning a new object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("0.125").unwrap();
+    /// assert_eq!("0.12", dec.rounded(-2, RoundingMode::HalfEven).to_string());
+    /// ```
+    pub fn rounded(mut self, position: i16, mode: RoundingMode) -> Self {
+        self.round(position, mode);
+        self
+    }
+}
+
+impl Neg for FixedDecimal {
+    type Output = Self;
+
+    /// Negates the number: a negative number loses its minus sign, and any other number gets
+    /// one.
+    fn neg(mut self) -> Self {
+        self.sign = match self.sign {
+            Sign::Negative => Sign::None,
+            Sign::None | Sign::Positive => Sign::Negative,
+        };
+        self
+    }
+}
+
+macro_rules! impl_arithmetic_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $checked_fn:ident) => {
+        impl $op<&FixedDecimal> for &FixedDecimal {
+            type Output = FixedDecimal;
+
+            /// # Panics
+            ///
+            /// Panics if the magnitude of the result does not fit in an i16. Use
+            #[doc = concat!("[`FixedDecimal::", stringify!($checked_fn), "`]")]
+            /// to handle this case.
+            fn $op_fn(self, rhs: &FixedDecimal) -> FixedDecimal {
+                #[allow(clippy::expect_used)] // documented, like integer overflow
+                self.$checked_fn(rhs)
+                    .expect("The magnitude of the result should fit in an i16")
+            }
+        }
+
+        impl $op for FixedDecimal {
+            type Output = FixedDecimal;
+
+            fn $op_fn(self, rhs: FixedDecimal) -> FixedDecimal {
+                (&self).$op_fn(&rhs)
+            }
+        }
+
+        impl $op_assign<&FixedDecimal> for FixedDecimal {
+            fn $op_assign_fn(&mut self, rhs: &FixedDecimal) {
+                *self = (&*self).$op_fn(rhs);
+            }
+        }
+
+        impl $op_assign for FixedDecimal {
+            fn $op_assign_fn(&mut self, rhs: FixedDecimal) {
+                *self = (&*self).$op_fn(&rhs);
+            }
+        }
+    };
+}
+
+impl_arithmetic_op!(Add, add, AddAssign, add_assign, checked_add);
+impl_arithmetic_op!(Sub, sub, SubAssign, sub_assign, checked_sub);
+impl_arithmetic_op!(Mul, mul, MulAssign, mul_assign, checked_mul);
+
 impl writeable::Writeable for FixedDecimal {
     /// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
     ///
@@ -3272,3 +3754,92 @@ fn test_concatenate() {
         }
     }
 }
+
+#[test]
+fn test_arithmetic() {
+    let cases = [
+        // (a, b, a + b, a - b, a * b)
+        ("1", "2", "3", "-1", "2"),
+        ("0", "0", "0", "0", "0"),
+        ("-0", "-0", "-0", "0", "0"),
+        ("-0", "0", "0", "-0", "-0"),
+        ("999", "1", "1000", "998", "999"),
+        ("1.50", "2", "3.50", "-0.50", "3.00"),
+        ("1.50", "2.0", "3.50", "-0.50", "3.000"),
+        ("-12.5", "2.5", "-10.0", "-15.0", "-31.25"),
+        ("-12.5", "-2.5", "-15.0", "-10.0", "31.25"),
+        ("0.1", "0.2", "0.3", "-0.1", "0.02"),
+        ("007", "0.5", "007.5", "006.5", "003.5"),
+        ("+3", "4", "7", "-1", "12"),
+        ("100", "-100", "0", "200", "-10000"),
+        ("0.001", "1000", "1000.001", "-999.999", "1.000"),
+        (
+            "12345678901234567890",
+            "98765432109876543210",
+            "111111111011111111100",
+            "-86419753208641975320",
+            "1219326311370217952237463801111263526900",
+        ),
+    ];
+    for (a, b, sum, difference, product) in cases {
+        let a = FixedDecimal::from_str(a).unwrap();
+        let b = FixedDecimal::from_str(b).unwrap();
+        assert_eq!(sum, (&a + &b).to_string(), "{} + {}", a, b);
+        assert_eq!(difference, (&a - &b).to_string(), "{} - {}", a, b);
+        assert_eq!(product, (&a * &b).to_string(), "{} * {}", a, b);
+
+        let mut c = a.clone();
+        c += &b;
+        c -= b.clone();
+        assert_eq!(c, a.clone() + b.clone() - b.clone());
+    }
+
+    let max = FixedDecimal::from(9).multiplied_pow10(i16::MAX).unwrap();
+    assert_eq!(Err(Error::Limit), max.checked_add(&max));
+    assert_eq!(Err(Error::Limit), max.checked_mul(&max));
+    let min = FixedDecimal::from(1).multiplied_pow10(i16::MIN).unwrap();
+    assert_eq!(Err(Error::Limit), min.checked_mul(&min));
+}
+
+#[test]
+fn test_div() {
+    let cases = [
+        // (a, b, position, mode, a / b)
+        ("10", "3", -2, RoundingMode::HalfExpand, "3.33"),
+        ("20", "3", -2, RoundingMode::HalfExpand, "6.67"),
+        ("20", "3", -2, RoundingMode::Trunc, "6.66"),
+        ("-20", "3", -2, RoundingMode::Floor, "-6.67"),
+        ("-20", "3", -2, RoundingMode::Ceil, "-6.66"),
+        ("1", "8", -2, RoundingMode::HalfEven, "0.12"),
+        ("3", "8", -2, RoundingMode::HalfEven, "0.38"),
+        ("1", "8", -2, RoundingMode::HalfTrunc, "0.12"),
+        ("1", "8", -2, RoundingMode::HalfExpand, "0.13"),
+        // A remainder below a tie rounds up.
+        ("1.0000001", "8", -2, RoundingMode::HalfEven, "0.13"),
+        ("1.0000001", "8", -2, RoundingMode::HalfTrunc, "0.13"),
+        ("1", "4", -4, RoundingMode::HalfEven, "0.2500"),
+        ("6", "-0.2", 0, RoundingMode::HalfEven, "-30"),
+        ("0.006", "0.02", -1, RoundingMode::HalfEven, "0.3"),
+        ("12345", "1", 2, RoundingMode::HalfExpand, "12300"),
+        ("0", "7", -1, RoundingMode::HalfEven, "0.0"),
+        ("1", "3", 0, RoundingMode::Expand, "1"),
+    ];
+    for (a, b, position, mode, quotient) in cases {
+        let a = FixedDecimal::from_str(a).unwrap();
+        let b = FixedDecimal::from_str(b).unwrap();
+        assert_eq!(
+            quotient,
+            a.checked_div(&b, position, mode).unwrap().to_string(),
+            "{} / {} at {} with {:?}",
+            a,
+            b,
+            position,
+            mode
+        );
+    }
+
+    assert_eq!(
+        Err(Error::DivisionByZero),
+        FixedDecimal::from(1).checked_div(&FixedDecimal::from(0), 0, RoundingMode::HalfEven)
+    );
+}
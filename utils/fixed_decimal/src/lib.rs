@@ -50,6 +50,7 @@
     )
 )]
 
+pub mod compact;
 pub mod decimal;
 mod ops;
 pub mod signum;
@@ -58,12 +59,15 @@ mod uint_iterator;
 #[cfg(feature = "ryu")]
 pub use decimal::DoublePrecision;
 
+pub use compact::CompactDecimal;
 pub use decimal::FixedDecimal;
+pub use decimal::RoundingMode;
 pub use decimal::Sign;
 use displaydoc::Display;
 pub use signum::Signum;
 
 #[derive(Display, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// The magnitude or number of digits exceeds the limit of the FixedDecimal. The highest
     /// magnitude of the most significant digit is core::i16::MAX, and the lowest magnitude of the
@@ -92,6 +96,22 @@ pub enum Error {
     /// 123 (or 123.0) must be used.
     #[displaydoc("Failed to parse the input string")]
     Syntax,
+    /// The divisor of a division is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Error, FixedDecimal, RoundingMode};
+    ///
+    /// let dec = FixedDecimal::from(1);
+    /// assert_eq!(
+    ///     Error::DivisionByZero,
+    ///     dec.checked_div(&FixedDecimal::from(0), 0, RoundingMode::HalfEven)
+    ///         .unwrap_err()
+    /// );
+    /// ```
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
 }
 
 #[cfg(feature = "std")]
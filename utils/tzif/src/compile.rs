@@ -0,0 +1,820 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compiles zic source data into `TZif` data.
+//!
+//! This follows the reference implementation of zic in the IANA `tzcode` distribution,
+//! generating "slim" output: transitions are listed only until the POSIX TZ string in
+//! the footer can predict all future transitions, and the version-1 data block is minimal.
+
+use crate::data::posix::{
+    DstTransitionInfo, PosixTzString, TransitionDate, TransitionDay, ZoneVariantInfo,
+};
+use crate::data::time::Seconds;
+use crate::data::tzif::{DataBlock, LocalTimeTypeRecord, TzifData, TzifHeader};
+use crate::data::zic::{Rule, RuleDay, RuleTime, RuleYear, TimeKind, ZicData, ZoneLine, ZoneRules};
+use crate::error::Error;
+use std::cmp::Ordering;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The year from which transitions are generated, unless earlier data requires otherwise.
+const EPOCH_YEAR: i64 = 1970;
+
+/// Returns whether the year is a leap year in the proleptic Gregorian calendar.
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the month, which is in range `[1, 12]`.
+fn month_length(year: i64, month: u8) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the given date.
+fn days_since_epoch(year: i64, month: u8, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the weekday of the day since 1970-01-01, from 0 (Sunday) to 6 (Saturday).
+fn weekday(days: i64) -> i64 {
+    // 1970-01-01 was a Thursday.
+    (days + 4).rem_euclid(7)
+}
+
+/// Returns the number of days from 1970-01-01 to the given day of the month.
+fn rule_day_since_epoch(year: i64, month: u8, day: RuleDay) -> Result<i64, &'static str> {
+    let is_feb_29 = |day_of_month| month == 2 && day_of_month == 29 && !is_leap_year(year);
+    let check_day = |day_of_month: u8| {
+        if i64::from(day_of_month) > month_length(2000, month) {
+            Err("invalid day of month")
+        } else {
+            Ok(i64::from(day_of_month))
+        }
+    };
+    Ok(match day {
+        RuleDay::Day(day_of_month) => {
+            if is_feb_29(day_of_month) {
+                return Err("use of 2/29 in non leap-year");
+            }
+            days_since_epoch(year, month, check_day(day_of_month)?)
+        }
+        RuleDay::LastWeekday(weekday_of_month) => {
+            let last = days_since_epoch(year, month, month_length(year, month));
+            last - (weekday(last) - i64::from(weekday_of_month)).rem_euclid(7)
+        }
+        RuleDay::WeekdayOnOrAfter(weekday_of_month, day_of_month) => {
+            if is_feb_29(day_of_month) {
+                return Err("use of 2/29 in non leap-year");
+            }
+            let first = days_since_epoch(year, month, check_day(day_of_month)?);
+            first + (i64::from(weekday_of_month) - weekday(first)).rem_euclid(7)
+        }
+        RuleDay::WeekdayOnOrBefore(weekday_of_month, day_of_month) => {
+            let day_of_month = if is_feb_29(day_of_month) {
+                28
+            } else {
+                check_day(day_of_month)?
+            };
+            let last = days_since_epoch(year, month, day_of_month);
+            last - (weekday(last) - i64::from(weekday_of_month)).rem_euclid(7)
+        }
+    })
+}
+
+/// Returns the local time, in seconds since the epoch, at which the rule takes effect in the year.
+/// The local time is measured against the clock designated by the rule.
+fn rule_time_in_year(rule: &Rule, year: i64) -> Result<i64, &'static str> {
+    Ok(rule_day_since_epoch(year, rule.month, rule.day)? * SECONDS_PER_DAY + rule.at.time.0)
+}
+
+/// Converts a local time to UT, given the standard offset and saved time in effect.
+fn to_universal(time: i64, kind: TimeKind, std_offset: i64, save: i64) -> i64 {
+    match kind {
+        TimeKind::Wall => time - std_offset - save,
+        TimeKind::Standard => time - std_offset,
+        TimeKind::Universal => time,
+    }
+}
+
+/// The variable part of a time zone abbreviation to substitute for `%s`.
+#[derive(Clone, Copy)]
+enum Letters<'a> {
+    /// The letters of a rule.
+    Rule(&'a str),
+    /// No rule applies, so `%s` is left as is.
+    None,
+    /// The letters are unknown, so an abbreviation that depends on them cannot be formed.
+    Unknown,
+}
+
+/// Returns the letters of the rule, if any.
+fn letters(rule: Option<&Rule>) -> Letters {
+    match rule {
+        Some(rule) => Letters::Rule(&rule.letters),
+        None => Letters::None,
+    }
+}
+
+/// Formats a time zone abbreviation from the format of a zone line.
+///
+/// Returns [`None`] if the abbreviation depends on unknown letters.
+fn abbreviation(
+    format: &str,
+    letters: Letters,
+    is_dst: bool,
+    save: i64,
+    std_offset: i64,
+) -> Option<String> {
+    if let Some((std, dst)) = format.split_once('/') {
+        return Some(if is_dst { dst } else { std }.to_string());
+    }
+    if format.contains("%z") {
+        return Some(format.replace("%z", &offset_abbreviation(std_offset + save)));
+    }
+    match letters {
+        Letters::Rule(letters) => Some(format.replace("%s", letters)),
+        Letters::None => Some(format.to_string()),
+        Letters::Unknown => None,
+    }
+}
+
+/// Formats a UT offset as a numeric abbreviation, e.g. `+05`, `-0330`, or `+054500`.
+fn offset_abbreviation(offset: i64) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    if seconds != 0 {
+        format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds)
+    } else if minutes != 0 {
+        format!("{}{:02}{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}", sign, hours)
+    }
+}
+
+/// A local time type before designations are assigned indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalTimeType {
+    utoff: i64,
+    is_dst: bool,
+    designation: String,
+}
+
+/// A zone line along with the rules that it names, if any.
+struct ResolvedZoneLine<'a> {
+    line: &'a ZoneLine,
+    rules: &'a [Rule],
+}
+
+/// Accumulates the local time types and transitions of a zone.
+struct Compiler<'a> {
+    name: &'a str,
+    types: Vec<LocalTimeType>,
+    transitions: Vec<(i64, usize)>,
+}
+
+impl<'a> Compiler<'a> {
+    fn error(&self, message: &'static str) -> Error {
+        Error::ZicCompile(self.name.to_string(), message)
+    }
+
+    fn add_type(&mut self, utoff: i64, is_dst: bool, designation: String) -> usize {
+        let local_time_type = LocalTimeType {
+            utoff,
+            is_dst,
+            designation,
+        };
+        match self.types.iter().position(|ty| *ty == local_time_type) {
+            Some(index) => index,
+            None => {
+                self.types.push(local_time_type);
+                self.types.len() - 1
+            }
+        }
+    }
+
+    /// Generates the transitions of the zone lines, returning the default local time type,
+    /// which applies before the first transition.
+    fn generate(&mut self, lines: &[ResolvedZoneLine]) -> Result<usize, Error> {
+        let mut min_year = EPOCH_YEAR;
+        let mut max_year = EPOCH_YEAR;
+        for (index, resolved) in lines.iter().enumerate() {
+            let mut years = Vec::new();
+            if index < lines.len() - 1 {
+                years.extend(resolved.line.until.map(|until| until.year));
+            }
+            for rule in resolved.rules {
+                for year in [rule.from, rule.to] {
+                    if let RuleYear::Year(year) = year {
+                        years.push(year);
+                    }
+                }
+            }
+            for year in years {
+                min_year = min_year.min(year);
+                max_year = max_year.max(year);
+            }
+        }
+
+        let mut default_type = None;
+        // The time at which the current zone line takes effect, in UT.
+        let mut start_time = None;
+        for (index, resolved) in lines.iter().enumerate() {
+            let ResolvedZoneLine { line, rules } = resolved;
+            let use_until = index < lines.len() - 1;
+            let mut use_start = start_time.is_some();
+            let std_offset = line.std_offset.0;
+            let until = match line.until {
+                Some(until) if use_until => Some((
+                    rule_day_since_epoch(until.year, until.month, until.day)
+                        .map_err(|message| self.error(message))?
+                        * SECONDS_PER_DAY
+                        + until.time.time.0,
+                    until.time.kind,
+                    until.year,
+                )),
+                _ => None,
+            };
+            let start = start_time.unwrap_or_default();
+            let mut start_designation = None;
+            let mut start_offset = std_offset;
+            let mut save = 0;
+
+            match line.rules {
+                ZoneRules::Fixed {
+                    save: fixed_save,
+                    is_dst,
+                } => {
+                    save = fixed_save.0;
+                    let designation =
+                        abbreviation(&line.format, Letters::None, is_dst, save, std_offset)
+                            .unwrap_or_default();
+                    let ty = self.add_type(std_offset + save, is_dst, designation);
+                    if use_start {
+                        self.transitions.push((start, ty));
+                        use_start = false;
+                    } else {
+                        default_type = Some(ty);
+                    }
+                }
+                ZoneRules::Named(_) => {
+                    let mut previous_rule: Option<&Rule> = None;
+                    for year in min_year..=max_year {
+                        if matches!(until, Some((_, _, until_year)) if year > until_year) {
+                            break;
+                        }
+                        let mut todo = rules
+                            .iter()
+                            .map(|rule| {
+                                if RuleYear::Year(year) < rule.from
+                                    || rule.to < RuleYear::Year(year)
+                                {
+                                    Ok(None)
+                                } else {
+                                    rule_time_in_year(rule, year).map(Some)
+                                }
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|message| self.error(message))?;
+                        loop {
+                            let until_time = until
+                                .map(|(time, kind, _)| to_universal(time, kind, std_offset, save));
+                            let mut earliest: Option<(usize, i64)> = None;
+                            for (index, rule) in rules.iter().enumerate() {
+                                let time = match todo[index] {
+                                    Some(time) => {
+                                        to_universal(time, rule.at.kind, std_offset, save)
+                                    }
+                                    None => continue,
+                                };
+                                match earliest {
+                                    Some((_, earliest_time)) if earliest_time == time => {
+                                        return Err(self.error("two rules for same instant"));
+                                    }
+                                    Some((_, earliest_time)) if earliest_time < time => {}
+                                    _ => earliest = Some((index, time)),
+                                }
+                            }
+                            let (rule_index, time) = match earliest {
+                                Some(earliest) => earliest,
+                                None => break,
+                            };
+                            let rule = &rules[rule_index];
+                            todo[rule_index] = None;
+                            let letters = Letters::Rule(&rule.letters);
+                            if until_time.map_or(false, |until_time| time >= until_time) {
+                                if start_designation.is_none()
+                                    && std_offset + rule.save.0 == start_offset
+                                {
+                                    start_designation = abbreviation(
+                                        &line.format,
+                                        letters,
+                                        rule.is_dst,
+                                        rule.save.0,
+                                        std_offset,
+                                    );
+                                }
+                                break;
+                            }
+                            save = rule.save.0;
+                            if use_start && time == start {
+                                use_start = false;
+                            }
+                            if use_start {
+                                if time < start {
+                                    start_offset = std_offset + save;
+                                    start_designation = abbreviation(
+                                        &line.format,
+                                        letters,
+                                        rule.is_dst,
+                                        save,
+                                        std_offset,
+                                    );
+                                    continue;
+                                }
+                                if start_designation.is_none() && start_offset == std_offset + save
+                                {
+                                    start_designation = abbreviation(
+                                        &line.format,
+                                        letters,
+                                        rule.is_dst,
+                                        save,
+                                        std_offset,
+                                    );
+                                }
+                            }
+                            // Once two rules that apply indefinitely have taken effect on the
+                            // final zone line, the footer predicts all later transitions.
+                            if !use_until
+                                && rule.to == RuleYear::Maximum
+                                && previous_rule.map_or(false, |rule| rule.to == RuleYear::Maximum)
+                            {
+                                break;
+                            }
+                            let designation =
+                                abbreviation(&line.format, letters, rule.is_dst, save, std_offset)
+                                    .unwrap_or_default();
+                            let ty = self.add_type(std_offset + save, rule.is_dst, designation);
+                            if default_type.is_none() && !rule.is_dst {
+                                default_type = Some(ty);
+                            }
+                            self.transitions.push((time, ty));
+                            previous_rule = Some(rule);
+                        }
+                    }
+                }
+            }
+
+            if use_start {
+                let is_dst = start_offset != std_offset;
+                let designation = start_designation
+                    .or_else(|| {
+                        abbreviation(&line.format, Letters::Unknown, is_dst, save, std_offset)
+                    })
+                    .ok_or_else(|| {
+                        self.error(
+                            "can't determine time zone abbreviation to use just after until time",
+                        )
+                    })?;
+                let ty = self.add_type(start_offset, is_dst, designation);
+                if default_type.is_none() && !is_dst {
+                    default_type = Some(ty);
+                }
+                self.transitions.push((start, ty));
+            }
+
+            start_time = until.map(|(time, kind, _)| to_universal(time, kind, std_offset, save));
+        }
+        Ok(default_type.unwrap_or_default())
+    }
+
+    /// Sorts the transitions and removes those that do not change the local time type,
+    /// as well as those that are overtaken by the next transition in local time.
+    fn optimize(&mut self) {
+        self.transitions.sort_by_key(|&(time, _)| time);
+        let mut optimized: Vec<(i64, usize)> = Vec::with_capacity(self.transitions.len());
+        for &(time, ty) in &self.transitions {
+            if let Some(&(previous_time, previous_ty)) = optimized.last() {
+                let before_previous_ty = match optimized.len() {
+                    1 => 0,
+                    len => optimized[len - 2].1,
+                };
+                if time + self.types[previous_ty].utoff
+                    <= previous_time + self.types[before_previous_ty].utoff
+                {
+                    if let Some(last) = optimized.last_mut() {
+                        last.1 = ty;
+                    }
+                    continue;
+                }
+                if self.types[previous_ty] == self.types[ty] {
+                    continue;
+                }
+            }
+            optimized.push((time, ty));
+        }
+        self.transitions = optimized;
+    }
+
+    /// Builds the version-2+ data block, in which the default local time type is type 0
+    /// and unused local time types are omitted.
+    fn data_block(&self, default_type: usize) -> DataBlock {
+        let mut used = vec![false; self.types.len()];
+        used[default_type] = true;
+        for &(_, ty) in &self.transitions {
+            used[ty] = true;
+        }
+        let first_used = used.iter().position(|&used| used).unwrap_or_default();
+
+        // Swap the first used type and the default type, so that the default type is type 0.
+        let order = (first_used..self.types.len())
+            .map(|index| match index {
+                index if index == first_used => default_type,
+                index if index == default_type => first_used,
+                index => index,
+            })
+            .filter(|&index| used[index])
+            .collect::<Vec<_>>();
+
+        // Designations are shared if one is equal to, or a suffix of, another.
+        let mut chars = String::new();
+        let mut designation_indices = vec![0; self.types.len()];
+        for index in (first_used..self.types.len()).filter(|&index| used[index]) {
+            let terminated = format!("{}\0", self.types[index].designation);
+            designation_indices[index] = match chars.find(&terminated) {
+                Some(position) => position,
+                None => {
+                    chars.push_str(&terminated);
+                    chars.len() - terminated.len()
+                }
+            };
+        }
+
+        DataBlock {
+            transition_times: self
+                .transitions
+                .iter()
+                .map(|&(time, _)| Seconds(time))
+                .collect(),
+            transition_types: self
+                .transitions
+                .iter()
+                .map(|&(_, ty)| {
+                    order
+                        .iter()
+                        .position(|&index| index == ty)
+                        .unwrap_or_default()
+                })
+                .collect(),
+            local_time_type_records: order
+                .iter()
+                .map(|&index| LocalTimeTypeRecord {
+                    utoff: Seconds(self.types[index].utoff),
+                    is_dst: self.types[index].is_dst,
+                    idx: designation_indices[index],
+                })
+                .collect(),
+            time_zone_designations: order
+                .iter()
+                .map(|&index| self.types[index].designation.clone())
+                .collect(),
+            ..DataBlock::default()
+        }
+    }
+}
+
+/// Compares rules by when they last take effect, as zic does to find the rules that apply
+/// indefinitely. Rules that both apply indefinitely compare equal.
+fn compare_last_rules(a: Option<&Rule>, b: Option<&Rule>) -> Ordering {
+    let (a, b) = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Less,
+        (Some(_), None) => return Ordering::Greater,
+        (Some(a), Some(b)) => (a, b),
+    };
+    if a.to != b.to || a.to == RuleYear::Maximum {
+        return a.to.cmp(&b.to);
+    }
+    let day_of_month = |rule: &Rule| match rule.day {
+        RuleDay::Day(day)
+        | RuleDay::WeekdayOnOrAfter(_, day)
+        | RuleDay::WeekdayOnOrBefore(_, day) => i64::from(day),
+        RuleDay::LastWeekday(_) => month_length(2000, rule.month),
+    };
+    (a.month, day_of_month(a)).cmp(&(b.month, day_of_month(b)))
+}
+
+/// Converts a UT offset into a POSIX offset, which is negated, returning [`None`] if it is
+/// out of range.
+fn posix_offset(offset: i64) -> Option<Seconds> {
+    (offset.abs() < 7 * SECONDS_PER_DAY).then(|| Seconds(-offset))
+}
+
+/// Converts a rule into a POSIX transition date, returning the date and the year of
+/// the `TZif` version that it requires, or [`None`] if the rule cannot be represented.
+fn transition_date(rule: &Rule, save: i64, std_offset: i64) -> Option<(TransitionDate, u16)> {
+    let mut version_year = 0;
+    let mut time = rule.at.time.0;
+    let month = u16::from(rule.month);
+    // Represents the first weekday on or after a day as a weekday of a week of the month,
+    // shifting the weekday and time by the days the week starts after the day.
+    let mut shifted = |weekday: u8, offset: i64, week: i64| {
+        if offset != 0 {
+            version_year = 2013;
+        }
+        time += offset * SECONDS_PER_DAY;
+        TransitionDay::Mwd(
+            month,
+            week as u16,
+            (i64::from(weekday) - offset).rem_euclid(7) as u16,
+        )
+    };
+    let day = match rule.day {
+        RuleDay::Day(29) if rule.month == 2 => return None,
+        RuleDay::Day(day) => {
+            let days_before_month = (1..rule.month)
+                .map(|month| month_length(2001, month))
+                .sum::<i64>();
+            // The `J` is omitted in January and February, as it is shorter.
+            if rule.month <= 2 {
+                TransitionDay::WithLeap((days_before_month + i64::from(day) - 1) as u16)
+            } else {
+                TransitionDay::NoLeap((days_before_month + i64::from(day)) as u16)
+            }
+        }
+        RuleDay::LastWeekday(weekday) => TransitionDay::Mwd(month, 5, u16::from(weekday)),
+        RuleDay::WeekdayOnOrBefore(weekday, day)
+            if i64::from(day) == month_length(2000, rule.month) =>
+        {
+            TransitionDay::Mwd(month, 5, u16::from(weekday))
+        }
+        RuleDay::WeekdayOnOrAfter(weekday, day) => {
+            let day = i64::from(day);
+            shifted(weekday, (day - 1) % 7, 1 + (day - 1) / 7)
+        }
+        RuleDay::WeekdayOnOrBefore(weekday, day) => {
+            let day = i64::from(day);
+            shifted(weekday, day % 7, day / 7)
+        }
+    };
+    if rule.at.kind == TimeKind::Universal {
+        time += std_offset;
+    }
+    if rule.at.kind != TimeKind::Wall && !rule.is_dst {
+        time += save;
+    }
+    if time.abs() >= 7 * SECONDS_PER_DAY {
+        return None;
+    }
+    if time < 0 {
+        version_year = 2013;
+    } else if time >= SECONDS_PER_DAY {
+        version_year = version_year.max(1994);
+    }
+    Some((
+        TransitionDate {
+            day,
+            time: Seconds(time),
+        },
+        version_year,
+    ))
+}
+
+/// Builds the POSIX TZ string that predicts the transitions after the final zone line takes
+/// effect, returning the string and the year of the `TZif` version that it requires,
+/// or [`None`] if the final zone line cannot be represented.
+fn footer(last: &ResolvedZoneLine) -> Option<(PosixTzString, u16)> {
+    let line = last.line;
+    let mut last_rules: [Option<&Rule>; 2] = [None, None];
+    for rule in last.rules {
+        let last = &mut last_rules[usize::from(rule.is_dst)];
+        match compare_last_rules(*last, Some(rule)) {
+            Ordering::Less => *last = Some(rule),
+            Ordering::Equal => return None,
+            Ordering::Greater => {}
+        }
+    }
+    let [std_rule, dst_rule] = last_rules;
+    let std_offset = line.std_offset.0;
+    let dst_ordering = match line.rules {
+        ZoneRules::Named(_) => compare_last_rules(dst_rule, std_rule),
+        ZoneRules::Fixed { is_dst: true, .. } => Ordering::Greater,
+        ZoneRules::Fixed { is_dst: false, .. } => Ordering::Less,
+    };
+    match dst_ordering {
+        // Standard time all year.
+        Ordering::Less => Some((
+            PosixTzString {
+                std_info: ZoneVariantInfo {
+                    name: abbreviation(&line.format, letters(std_rule), false, 0, std_offset)?,
+                    offset: posix_offset(std_offset)?,
+                },
+                dst_info: None,
+            },
+            0,
+        )),
+        // Daylight saving time all year, represented as a transition to DST at the start of
+        // the year and a transition to standard time at the end of the year. If the saved time
+        // is positive, the standard time is a fake one that is ahead of DST, e.g. `XXX3EDT4`.
+        Ordering::Greater => {
+            let save = match (dst_rule, &line.rules) {
+                (Some(rule), _) => rule.save.0,
+                (None, ZoneRules::Fixed { save, .. }) => save.0,
+                (None, ZoneRules::Named(_)) => return None,
+            };
+            let dst_save = -save.abs();
+            let (std_name, std_offset) = if save >= 0 {
+                ("XXX".to_string(), std_offset + 2 * save)
+            } else {
+                let std_letters = letters(std_rule);
+                let name = abbreviation(&line.format, std_letters, false, 0, std_offset)?;
+                (name, std_offset)
+            };
+            let dst = Rule {
+                from: RuleYear::Minimum,
+                to: RuleYear::Maximum,
+                month: 1,
+                day: RuleDay::Day(1),
+                at: RuleTime {
+                    time: Seconds(0),
+                    kind: TimeKind::Wall,
+                },
+                save: Seconds(dst_save),
+                is_dst: true,
+                letters: String::new(),
+            };
+            let std = Rule {
+                month: 12,
+                day: RuleDay::Day(31),
+                at: RuleTime {
+                    time: Seconds(SECONDS_PER_DAY + dst_save),
+                    kind: TimeKind::Wall,
+                },
+                save: Seconds(0),
+                is_dst: false,
+                ..dst.clone()
+            };
+            let dst_name =
+                abbreviation(&line.format, letters(dst_rule), true, dst_save, std_offset)?;
+            posix_tz_string(
+                (std_name, std_offset),
+                (dst_name, std_offset + dst_save),
+                (&dst, &std),
+                dst_save,
+            )
+        }
+        // Both standard time and DST apply indefinitely.
+        Ordering::Equal => {
+            let (std_rule, dst_rule) = (std_rule?, dst_rule?);
+            let save = dst_rule.save.0;
+            posix_tz_string(
+                (
+                    abbreviation(&line.format, letters(Some(std_rule)), false, 0, std_offset)?,
+                    std_offset,
+                ),
+                (
+                    abbreviation(
+                        &line.format,
+                        letters(Some(dst_rule)),
+                        true,
+                        save,
+                        std_offset,
+                    )?,
+                    std_offset + save,
+                ),
+                (dst_rule, std_rule),
+                save,
+            )
+        }
+    }
+}
+
+/// Builds a POSIX TZ string with daylight saving time from the names and UT offsets of standard
+/// time and DST, and the rules that start and end DST.
+fn posix_tz_string(
+    (std_name, std_offset): (String, i64),
+    (dst_name, dst_offset): (String, i64),
+    (start_rule, end_rule): (&Rule, &Rule),
+    save: i64,
+) -> Option<(PosixTzString, u16)> {
+    let (start_date, start_version_year) = transition_date(start_rule, save, std_offset)?;
+    let (end_date, end_version_year) = transition_date(end_rule, save, std_offset)?;
+    Some((
+        PosixTzString {
+            std_info: ZoneVariantInfo {
+                name: std_name,
+                offset: posix_offset(std_offset)?,
+            },
+            dst_info: Some(DstTransitionInfo {
+                variant_info: ZoneVariantInfo {
+                    name: dst_name,
+                    offset: posix_offset(dst_offset)?,
+                },
+                start_date,
+                end_date,
+            }),
+        },
+        start_version_year.max(end_version_year),
+    ))
+}
+
+impl ZicData {
+    /// Compiles the zone or link with the given name into `TZif` data, as zic would.
+    ///
+    /// The output is "slim": the version-2+ data block lists transitions only until the footer
+    /// can predict all later transitions, and the version-1 data block is minimal. Leap seconds
+    /// are not supported.
+    ///
+    /// Returns an error if the name is unknown, if the zone references undefined rules, or if
+    /// the transitions after the final zone line cannot be represented by a POSIX TZ string.
+    pub fn compile(&self, name: &str) -> Result<TzifData, Error> {
+        let error = |message| Error::ZicCompile(name.to_string(), message);
+        let mut target = name;
+        for _ in 0..=self.links.len() {
+            if self.zones.contains_key(target) {
+                break;
+            }
+            target = self
+                .links
+                .get(target)
+                .ok_or_else(|| error("unknown zone or link"))?;
+        }
+        let lines = self
+            .zones
+            .get(target)
+            .ok_or_else(|| error("link cycle"))?
+            .iter()
+            .map(|line| {
+                Ok(ResolvedZoneLine {
+                    line,
+                    rules: match &line.rules {
+                        ZoneRules::Named(rules) => self
+                            .rules
+                            .get(rules)
+                            .ok_or_else(|| error("undefined rule"))?,
+                        ZoneRules::Fixed { .. } => &[],
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let (footer, version_year) = lines
+            .last()
+            .and_then(footer)
+            .ok_or_else(|| error("no POSIX TZ string can represent future timestamps"))?;
+
+        let mut compiler = Compiler {
+            name,
+            types: Vec::new(),
+            transitions: Vec::new(),
+        };
+        let default_type = compiler.generate(&lines)?;
+        compiler.optimize();
+        let data_block = compiler.data_block(default_type);
+
+        let version = if version_year < 2013 { 2 } else { 3 };
+        let header1 = TzifHeader {
+            version,
+            isutcnt: 0,
+            isstdcnt: 0,
+            leapcnt: 0,
+            timecnt: 0,
+            typecnt: 1,
+            charcnt: 1,
+        };
+        let header2 = TzifHeader {
+            timecnt: data_block.transition_times.len(),
+            typecnt: data_block.local_time_type_records.len(),
+            charcnt: data_block
+                .local_time_type_records
+                .iter()
+                .zip(&data_block.time_zone_designations)
+                .map(|(record, designation)| record.idx + designation.len() + 1)
+                .max()
+                .unwrap_or_default(),
+            ..header1
+        };
+        Ok(TzifData {
+            header1,
+            data_block1: DataBlock {
+                local_time_type_records: vec![LocalTimeTypeRecord::default()],
+                time_zone_designations: vec![String::new()],
+                ..DataBlock::default()
+            },
+            header2: Some(header2),
+            data_block2: Some(data_block),
+            footer: Some(footer),
+        })
+    }
+}
@@ -12,3 +12,7 @@ pub mod time;
 /// Structs for holding data parsed from `TZif` binary files, as specified by
 /// <https://datatracker.ietf.org/doc/html/rfc8536>
 pub mod tzif;
+
+/// Structs for holding data parsed from zic source files, as specified by
+/// <https://man7.org/linux/man-pages/man8/zic.8.html>
+pub mod zic;
@@ -2,7 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::time::Seconds;
+use super::time::{Hours, Seconds};
+use std::fmt;
 
 /// A struct to hold a time-zone variant name and its offset.
 /// The offset is how many hours must be added to the time to reach UTC.
@@ -68,3 +69,61 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+/// Writes a time value of the form `\[-\]hh\[:mm\[:ss\]\]`, omitting zero minutes and seconds.
+fn write_time(f: &mut fmt::Formatter<'_>, time: Seconds) -> fmt::Result {
+    if time.0 < 0 {
+        write!(f, "-")?;
+    }
+    let time = time.0.abs();
+    let (hours, minutes, seconds) = (time / 3600, time / 60 % 60, time % 60);
+    write!(f, "{}", hours)?;
+    if minutes != 0 || seconds != 0 {
+        write!(f, ":{:02}", minutes)?;
+    }
+    if seconds != 0 {
+        write!(f, ":{:02}", seconds)?;
+    }
+    Ok(())
+}
+
+/// Writes a time-zone variant name, enclosing it in angled brackets unless it is alphabetic.
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    if !name.is_empty() && name.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        write!(f, "{}", name)
+    } else {
+        write!(f, "<{}>", name)
+    }
+}
+
+impl fmt::Display for TransitionDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.day {
+            TransitionDay::NoLeap(day) => write!(f, "J{}", day)?,
+            TransitionDay::WithLeap(day) => write!(f, "{}", day)?,
+            TransitionDay::Mwd(month, week, day) => write!(f, "M{}.{}.{}", month, week, day)?,
+        }
+        if self.time != Hours(2).as_seconds() {
+            write!(f, "/")?;
+            write_time(f, self.time)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the POSIX time-zone string, omitting the DST offset and transition times
+/// where they equal the defaults.
+impl fmt::Display for PosixTzString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_name(f, &self.std_info.name)?;
+        write_time(f, self.std_info.offset)?;
+        if let Some(dst_info) = &self.dst_info {
+            write_name(f, &dst_info.variant_info.name)?;
+            if dst_info.variant_info.offset != self.std_info.offset - Hours(1).as_seconds() {
+                write_time(f, dst_info.variant_info.offset)?;
+            }
+            write!(f, ",{},{}", dst_info.start_date, dst_info.end_date)?;
+        }
+        Ok(())
+    }
+}
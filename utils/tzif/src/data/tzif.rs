@@ -113,7 +113,7 @@ impl TzifHeader {
 /// >                     |   Footer    |
 /// >                     +-------------+
 /// > ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TzifData {
     /// The version-1 header, which is always present.
     pub header1: TzifHeader,
@@ -261,7 +261,7 @@ pub enum UtLocalIndicator {
 /// >      |  UT/local indicators       (isutcnt)                    |
 /// >      +---------------------------------------------------------+
 /// > ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DataBlock {
     /// A series of four- or eight-byte UNIX leap-time
     /// values sorted in strictly ascending order.  Each value is used as
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::time::Seconds;
+use std::collections::BTreeMap;
+
+/// A year in the `FROM` or `TO` field of a rule line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RuleYear {
+    /// The indefinite past, written as `minimum`.
+    Minimum,
+    /// A specific year.
+    Year(i64),
+    /// The indefinite future, written as `maximum`.
+    Maximum,
+}
+
+/// The clock that a time of day is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeKind {
+    /// Local wall-clock time. This is the default, or designated by a `w` suffix.
+    Wall,
+    /// Local standard time, designated by an `s` suffix.
+    Standard,
+    /// Universal time, designated by a `u`, `g`, or `z` suffix.
+    Universal,
+}
+
+/// A time of day, as used by the `AT` field of rule lines and the `UNTIL` field of zone lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTime {
+    /// The time since midnight. This may be negative or exceed 24 hours.
+    pub time: Seconds,
+    /// The clock that the time is measured against.
+    pub kind: TimeKind,
+}
+
+/// A day of the month, as used by the `ON` field of rule lines and the `UNTIL` field of zone lines.
+///
+/// Weekdays range from 0 (Sunday) to 6 (Saturday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleDay {
+    /// A fixed day of the month, e.g. `5`.
+    Day(u8),
+    /// The last given weekday of the month, e.g. `lastSun`.
+    LastWeekday(u8),
+    /// The first given weekday on or after the day of the month, e.g. `Sun>=8`.
+    WeekdayOnOrAfter(u8, u8),
+    /// The last given weekday on or before the day of the month, e.g. `Sun<=25`.
+    WeekdayOnOrBefore(u8, u8),
+}
+
+/// A rule line, describing a recurring change to the amount of saved time.
+///
+/// > ```text
+/// > Rule  NAME  FROM  TO    -  IN   ON       AT    SAVE  LETTER/S
+/// > Rule  US    1967  1973  -  Apr  lastSun  2:00w  1:00d  D
+/// > ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// The first year in which the rule applies.
+    pub from: RuleYear,
+    /// The final year in which the rule applies.
+    pub to: RuleYear,
+    /// The month in which the rule takes effect, in range `[1, 12]`.
+    pub month: u8,
+    /// The day on which the rule takes effect.
+    pub day: RuleDay,
+    /// The time of day at which the rule takes effect.
+    pub at: RuleTime,
+    /// The amount of time to be added to local standard time while the rule is in effect.
+    pub save: Seconds,
+    /// Whether the rule introduces daylight saving time.
+    pub is_dst: bool,
+    /// The variable part of time zone abbreviations used while the rule is in effect.
+    pub letters: String,
+}
+
+/// The `RULES` field of a zone line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZoneRules {
+    /// A fixed amount of time to be added to local standard time, or zero if written as `-`.
+    Fixed {
+        /// The amount of saved time.
+        save: Seconds,
+        /// Whether the saved time is daylight saving time.
+        is_dst: bool,
+    },
+    /// The name of the rules that apply to the zone line.
+    Named(String),
+}
+
+/// The `UNTIL` field of a zone line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneUntil {
+    /// The year.
+    pub year: i64,
+    /// The month, in range `[1, 12]`.
+    pub month: u8,
+    /// The day of the month.
+    pub day: RuleDay,
+    /// The time of day.
+    pub time: RuleTime,
+}
+
+/// A zone line or zone continuation line.
+///
+/// > ```text
+/// > Zone  NAME                 STDOFF    RULES  FORMAT  [UNTIL]
+/// > Zone  America/Los_Angeles  -7:52:58  -      LMT     1883 Nov 18 20:00u
+/// >                            -8:00     US     P%sT    1946
+/// > ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneLine {
+    /// The amount of time to add to UT to get standard time.
+    pub std_offset: Seconds,
+    /// The rules that apply to the zone line.
+    pub rules: ZoneRules,
+    /// The format of time zone abbreviations, e.g. `P%sT`, `%z`, or `GMT/BST`.
+    pub format: String,
+    /// The time at which the next zone line takes effect, if any.
+    pub until: Option<ZoneUntil>,
+}
+
+/// A struct containing the data of zic source files, as specified by
+/// <https://man7.org/linux/man-pages/man8/zic.8.html>.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZicData {
+    /// The rule lines, grouped by rule name in the order they appear in the source.
+    pub rules: BTreeMap<String, Vec<Rule>>,
+    /// The zone lines, grouped by zone name in the order they appear in the source.
+    pub zones: BTreeMap<String, Vec<ZoneLine>>,
+    /// The link lines, mapping each link name to its target.
+    pub links: BTreeMap<String, String>,
+}
@@ -13,6 +13,10 @@ pub enum Error {
     Read(combine::stream::read::Error),
     /// A [`combine::error::UnexpectedParse`].
     Parse(combine::error::UnexpectedParse),
+    /// A syntax error in zic source text, with the line number and a description.
+    ZicSyntax(usize, &'static str),
+    /// An error compiling zic source data, with the zone name and a description.
+    ZicCompile(String, &'static str),
 }
 
 impl From<std::io::Error> for Error {
@@ -39,6 +43,8 @@ impl fmt::Display for Error {
             Error::Io(err) => write!(f, "{}", err),
             Error::Read(err) => write!(f, "{}", err),
             Error::Parse(err) => write!(f, "{}", err),
+            Error::ZicSyntax(line, message) => write!(f, "line {}: {}", line, message),
+            Error::ZicCompile(zone, message) => write!(f, "{}: {}", zone, message),
         }
     }
 }
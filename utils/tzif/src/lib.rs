@@ -10,6 +10,9 @@
 //! Resources to generate `TZif` files are provided by the [IANA database](https://www.iana.org/time-zones).
 //! `TZif` files are also included in some operating systems.
 //!
+//! Also includes a parser for the [zic](https://man7.org/linux/man-pages/man8/zic.8.html) source files
+//! of the IANA database, which can be compiled into `TZif` data and written as `TZif` files.
+//!
 //! # Examples
 //!
 //! ### Parse TZif Files
@@ -21,13 +24,33 @@
 //! ```rust
 //! let data = tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
 //! ```
+//!
+//! ### Compile zic source files
+//! ```rust
+//! let data = tzif::parse_zic_source(
+//!     "Rule US 2007 max - Mar Sun>=8 2:00 1:00 D
+//!      Rule US 2007 max - Nov Sun>=1 2:00 0    S
+//!      Zone America/New_York -5:00 US E%sT",
+//! )
+//! .unwrap();
+//! let tzif = data.compile("America/New_York").unwrap();
+//! assert_eq!(tzif.footer.unwrap().to_string(), "EST5EDT,M3.2.0,M11.1.0");
+//! ```
+//!
+//! ### Write TZif Files
+//! ```no_run
+//! let data = tzif::parse_zic_file("path_to_source_file").unwrap();
+//! let tzif = data.compile("America/New_York").unwrap();
+//! tzif::write_tzif_file("path_to_file", &tzif).unwrap();
+//! ```
 
 #![warn(missing_docs)]
 
 use combine::{stream, Parser};
-use data::{posix::PosixTzString, tzif::TzifData};
+use data::{posix::PosixTzString, tzif::TzifData, zic::ZicData};
 use error::Error;
-use std::fs::File;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 /// The parsed data representations.
@@ -39,6 +62,11 @@ pub mod parse;
 /// Error types an implementations.
 pub mod error;
 
+/// The writer implementations.
+pub mod write;
+
+mod compile;
+
 /// Parses a `TZif` file at the provided `path`.
 pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     let file = File::open(path)?;
@@ -53,3 +81,21 @@ pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
 }
+
+/// Parses a zic source file at the provided `path`.
+pub fn parse_zic_file<P: AsRef<Path>>(path: P) -> Result<ZicData, Error> {
+    parse_zic_source(&fs::read_to_string(path)?)
+}
+
+/// Parses zic source text, such as the `tzdata.zi` file of the IANA database.
+pub fn parse_zic_source(source: &str) -> Result<ZicData, Error> {
+    parse::zic::zic(source)
+}
+
+/// Writes `TZif` data to a file at the provided `path`.
+pub fn write_tzif_file<P: AsRef<Path>>(path: P, data: &TzifData) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write::tzif(&mut writer, data)?;
+    writer.flush()?;
+    Ok(())
+}
@@ -15,6 +15,10 @@ pub mod posix;
 /// <https://datatracker.ietf.org/doc/html/rfc8536>
 pub mod tzif;
 
+/// Parser definition for zic source files as specified by
+/// <https://man7.org/linux/man-pages/man8/zic.8.html>
+pub mod zic;
+
 /// Ensures that the predicate is [`true`], otherwise returns an error with the provided
 /// messages though combine's error machinery.
 fn ensure<Input: Stream, L>(
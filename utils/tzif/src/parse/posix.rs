@@ -87,7 +87,7 @@ where
 }
 
 /// Parses one ore more digits.
-fn digits<Input>() -> impl Parser<Input, Output = Vec<u8>>
+pub(super) fn digits<Input>() -> impl Parser<Input, Output = Vec<u8>>
 where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
@@ -96,7 +96,7 @@ where
 }
 
 /// Parses a natural number as an i64.
-fn natural<Input>() -> impl Parser<Input, Output = i64>
+pub(super) fn natural<Input>() -> impl Parser<Input, Output = i64>
 where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
@@ -113,7 +113,10 @@ where
 }
 
 /// Parses a natural number as an i64 and esnures that it falls within `[lower_bound, upper_bound]`.
-fn bounded_natural<Input>(lower_bound: i64, upper_bound: i64) -> impl Parser<Input, Output = i64>
+pub(super) fn bounded_natural<Input>(
+    lower_bound: i64,
+    upper_bound: i64,
+) -> impl Parser<Input, Output = i64>
 where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
@@ -0,0 +1,653 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::ensure;
+use super::posix::{bounded_natural, digits, natural};
+use crate::data::time::Seconds;
+use crate::data::zic::{
+    Rule, RuleDay, RuleTime, RuleYear, TimeKind, ZicData, ZoneLine, ZoneRules, ZoneUntil,
+};
+use crate::error::Error;
+use combine::parser::byte::byte;
+use combine::{choice, eof, many1, optional, satisfy, ParseError, Parser, Stream};
+
+/// The keywords that begin each type of line.
+const LINE_TYPES: &[&str] = &["Rule", "Zone", "Link"];
+
+/// The names of the months, in order.
+const MONTHS: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The names of the weekdays, starting with Sunday.
+const WEEKDAYS: &[&str] = &[
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// The keywords that may be used in the `FROM` field of a rule line.
+const FROM_YEARS: &[&str] = &["minimum", "maximum"];
+
+/// The keywords that may be used in the `TO` field of a rule line.
+const TO_YEARS: &[&str] = &["minimum", "maximum", "only"];
+
+/// Looks up a word in the table, ignoring case.
+///
+/// As with zic, a word also matches any entry of which it is an unambiguous prefix,
+/// e.g. `Ja` matches `January`, but `Ju` matches neither `June` nor `July`.
+fn lookup(word: &[u8], table: &[&str]) -> Option<usize> {
+    if let Some(index) = table
+        .iter()
+        .position(|entry| entry.as_bytes().eq_ignore_ascii_case(word))
+    {
+        return Some(index);
+    }
+    let mut matches = table.iter().enumerate().filter(|(_, entry)| {
+        entry.len() >= word.len() && entry.as_bytes()[..word.len()].eq_ignore_ascii_case(word)
+    });
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+/// Parses one or more ASCII letters.
+fn word<Input>() -> impl Parser<Input, Output = Vec<u8>>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    many1(satisfy(|byte: u8| byte.is_ascii_alphabetic()))
+}
+
+/// Parses a word and looks it up in the table, returning the index of the matching entry.
+///
+/// See [`lookup`] for more information.
+fn keyword<Input>(table: &'static [&'static str]) -> impl Parser<Input, Output = usize>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    word()
+        .map(move |word: Vec<u8>| lookup(&word, table))
+        .then(|index| ensure(index, Option::is_some, "unrecognized keyword"))
+        .map(|index| index.unwrap_or_default())
+}
+
+/// Parses a year, which may be negative.
+fn year<Input>() -> impl Parser<Input, Output = i64>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (optional(byte(b'-')), natural()).map(|(sign, year)| if sign.is_some() { -year } else { year })
+}
+
+/// Parses the `FROM` field of a rule line, which is either a year, `minimum`, or `maximum`.
+fn from_year<Input>() -> impl Parser<Input, Output = RuleYear>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice((
+        year().map(RuleYear::Year),
+        keyword(FROM_YEARS).map(|index| match index {
+            0 => RuleYear::Minimum,
+            _ => RuleYear::Maximum,
+        }),
+    ))
+}
+
+/// Parses the `TO` field of a rule line, which is either a year, `minimum`, `maximum`, or `only`.
+///
+/// Returns [`None`] for `only`, which designates the year of the `FROM` field.
+fn to_year<Input>() -> impl Parser<Input, Output = Option<RuleYear>>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice((
+        year().map(|year| Some(RuleYear::Year(year))),
+        keyword(TO_YEARS).map(|index| match index {
+            0 => Some(RuleYear::Minimum),
+            1 => Some(RuleYear::Maximum),
+            _ => None,
+        }),
+    ))
+}
+
+/// Parses the name of a month, returning the month in range `[1, 12]`.
+fn month<Input>() -> impl Parser<Input, Output = u8>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    keyword(MONTHS).map(|index| index as u8 + 1)
+}
+
+/// Parses a day of the month in range `[1, 31]`.
+fn day_of_month<Input>() -> impl Parser<Input, Output = u8>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    bounded_natural(1, 31).map(|day| day as u8)
+}
+
+/// Parses a day in any of the forms `5`, `lastSun`, `Sun>=8`, or `Sun<=25`.
+fn rule_day<Input>() -> impl Parser<Input, Output = RuleDay>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let comparison = choice((byte(b'>'), byte(b'<'))).skip(byte(b'='));
+    choice((
+        day_of_month().map(RuleDay::Day),
+        (word(), optional((comparison, day_of_month())))
+            .map(|(word, comparison)| match comparison {
+                None if word.len() > 4 && word[..4].eq_ignore_ascii_case(b"last") => {
+                    lookup(&word[4..], WEEKDAYS).map(|weekday| RuleDay::LastWeekday(weekday as u8))
+                }
+                None => None,
+                Some((b'>', day)) => lookup(&word, WEEKDAYS)
+                    .map(|weekday| RuleDay::WeekdayOnOrAfter(weekday as u8, day)),
+                Some((_, day)) => lookup(&word, WEEKDAYS)
+                    .map(|weekday| RuleDay::WeekdayOnOrBefore(weekday as u8, day)),
+            })
+            .then(|day| ensure(day, Option::is_some, "invalid day of month"))
+            .map(|day| day.unwrap_or(RuleDay::Day(1))),
+    ))
+}
+
+/// Parses a time value of the form `\[-\]hh\[:mm\[:ss\[.fraction\]\]\]`, or a lone `-` for zero.
+///
+/// The hour is unbounded, and the minutes and seconds must be in range `[0, 59]`.
+/// Fractional seconds are rounded to the nearest second, with ties rounding to even.
+fn hms<Input>() -> impl Parser<Input, Output = Seconds>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let seconds = (
+        byte(b':').with(bounded_natural(0, 59)),
+        optional(byte(b'.').with(digits())),
+    )
+        .map(|(seconds, fraction)| match fraction.as_deref() {
+            Some([digit, ..]) if *digit > b'5' || (*digit == b'5' && seconds % 2 == 1) => {
+                seconds + 1
+            }
+            _ => seconds,
+        });
+    let time = (
+        natural(),
+        optional((byte(b':').with(bounded_natural(0, 59)), optional(seconds))),
+    )
+        .map(|(hours, rest)| {
+            let (minutes, seconds) = rest.unwrap_or_default();
+            hours * 3600 + minutes * 60 + seconds.unwrap_or_default()
+        });
+    (optional(byte(b'-')), optional(time))
+        .map(|(sign, time)| match (sign, time) {
+            (Some(_), time) => Some(Seconds(-time.unwrap_or_default())),
+            (None, time) => time.map(Seconds),
+        })
+        .then(|time| ensure(time, Option::is_some, "invalid time"))
+        .map(Option::unwrap_or_default)
+}
+
+/// Parses a time of day followed by an optional suffix that designates its [`TimeKind`].
+fn rule_time<Input>() -> impl Parser<Input, Output = RuleTime>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        hms(),
+        optional(satisfy(|byte| {
+            matches!(byte, b'w' | b's' | b'u' | b'g' | b'z')
+        })),
+    )
+        .map(|(time, suffix)| RuleTime {
+            time,
+            kind: match suffix {
+                Some(b's') => TimeKind::Standard,
+                Some(b'u' | b'g' | b'z') => TimeKind::Universal,
+                _ => TimeKind::Wall,
+            },
+        })
+}
+
+/// Parses an amount of saved time, followed by an optional `s` or `d` suffix that
+/// designates standard time or daylight saving time respectively.
+///
+/// Without a suffix, any nonzero amount of saved time is daylight saving time.
+fn save<Input>() -> impl Parser<Input, Output = (Seconds, bool)>
+where
+    Input: Stream<Token = u8>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (hms(), optional(satisfy(|byte| matches!(byte, b's' | b'd')))).map(|(save, suffix)| {
+        let is_dst = match suffix {
+            Some(suffix) => suffix == b'd',
+            None => save != Seconds(0),
+        };
+        (save, is_dst)
+    })
+}
+
+/// Splits a line into fields separated by whitespace, discarding any comment.
+///
+/// Double quotes are removed, and allow a field to contain whitespace or `#`.
+/// Returns [`None`] if a quotation is not terminated.
+fn fields(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.next_if(char::is_ascii_whitespace).is_some() {}
+        if matches!(chars.peek(), None | Some('#')) {
+            return Some(fields);
+        }
+        let mut field = String::new();
+        while let Some(c) = chars.next_if(|&c| !c.is_ascii_whitespace() && c != '#') {
+            if c == '"' {
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        c => field.push(c),
+                    }
+                }
+            } else {
+                field.push(c);
+            }
+        }
+        fields.push(field);
+    }
+}
+
+/// Parses an entire field with the parser, returning a syntax error with the line number
+/// and message if the parser fails or does not consume the entire field.
+fn field<'a, P>(
+    parser: P,
+    field: &'a str,
+    line: usize,
+    message: &'static str,
+) -> Result<P::Output, Error>
+where
+    P: Parser<&'a [u8]>,
+{
+    parser
+        .skip(eof())
+        .parse(field.as_bytes())
+        .map(|(output, _)| output)
+        .map_err(|_| Error::ZicSyntax(line, message))
+}
+
+/// Parses the fields of a rule line, excluding the leading `Rule` keyword.
+fn rule(fields: &[String], line: usize) -> Result<(String, Rule), Error> {
+    if fields.len() != 9 {
+        return Err(Error::ZicSyntax(
+            line,
+            "wrong number of fields on Rule line",
+        ));
+    }
+    let name = &fields[0];
+    if name.is_empty() || name.starts_with(|c: char| c == '+' || c == '-' || c.is_ascii_digit()) {
+        return Err(Error::ZicSyntax(line, "invalid rule name"));
+    }
+    let from = field(from_year(), &fields[1], line, "invalid starting year")?;
+    let to = field(to_year(), &fields[2], line, "invalid ending year")?.unwrap_or(from);
+    if from > to {
+        return Err(Error::ZicSyntax(
+            line,
+            "starting year greater than ending year",
+        ));
+    }
+    if !matches!(fields[3].as_str(), "" | "-") {
+        return Err(Error::ZicSyntax(
+            line,
+            "year type is obsolete; use \"-\" instead",
+        ));
+    }
+    let (save, is_dst) = field(save(), &fields[7], line, "invalid saved time")?;
+    let rule = Rule {
+        from,
+        to,
+        month: field(month(), &fields[4], line, "invalid month name")?,
+        day: field(rule_day(), &fields[5], line, "invalid day of month")?,
+        at: field(rule_time(), &fields[6], line, "invalid time of day")?,
+        save,
+        is_dst,
+        letters: match fields[8].as_str() {
+            "-" => String::new(),
+            letters => letters.to_string(),
+        },
+    };
+    Ok((name.clone(), rule))
+}
+
+/// Parses the fields of a zone line, excluding the leading `Zone` keyword and the zone name.
+fn zone_line(fields: &[String], line: usize) -> Result<ZoneLine, Error> {
+    if !(3..=7).contains(&fields.len()) {
+        return Err(Error::ZicSyntax(
+            line,
+            "wrong number of fields on Zone line",
+        ));
+    }
+    let std_offset = field(hms(), &fields[0], line, "invalid UT offset")?;
+    let rules = if fields[1].starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+        let (save, is_dst) = field(save(), &fields[1], line, "invalid saved time")?;
+        ZoneRules::Fixed { save, is_dst }
+    } else {
+        ZoneRules::Named(fields[1].clone())
+    };
+    let format = fields[2].clone();
+    if let Some((_, specifier)) = format.split_once('%') {
+        if !(specifier.starts_with('s') || specifier.starts_with('z'))
+            || specifier.contains('%')
+            || format.contains('/')
+        {
+            return Err(Error::ZicSyntax(line, "invalid abbreviation format"));
+        }
+    }
+    let until = match fields.get(3) {
+        None => None,
+        Some(year_field) => Some(ZoneUntil {
+            year: field(year(), year_field, line, "invalid year")?,
+            month: match fields.get(4) {
+                Some(month_field) => field(month(), month_field, line, "invalid month name")?,
+                None => 1,
+            },
+            day: match fields.get(5) {
+                Some(day_field) => field(rule_day(), day_field, line, "invalid day of month")?,
+                None => RuleDay::Day(1),
+            },
+            time: match fields.get(6) {
+                Some(time_field) => field(rule_time(), time_field, line, "invalid time of day")?,
+                None => RuleTime {
+                    time: Seconds(0),
+                    kind: TimeKind::Wall,
+                },
+            },
+        }),
+    };
+    Ok(ZoneLine {
+        std_offset,
+        rules,
+        format,
+        until,
+    })
+}
+
+/// Parses zic source text according to the following specification:
+/// <https://man7.org/linux/man-pages/man8/zic.8.html>
+///
+/// Unlike the other parsers in this crate, the source is parsed line by line,
+/// since each zone line that has an `UNTIL` field must be followed by a continuation line.
+pub fn zic(source: &str) -> Result<ZicData, Error> {
+    let mut data = ZicData::default();
+    // The name of the zone that the next line continues, if any.
+    let mut continued_zone: Option<String> = None;
+    let mut line = 0;
+    for text in source.lines() {
+        line += 1;
+        let fields = fields(text).ok_or(Error::ZicSyntax(line, "odd number of quotation marks"))?;
+        if fields.is_empty() {
+            continue;
+        }
+        if let Some(name) = continued_zone.take() {
+            let zone_line = zone_line(&fields, line)?;
+            if zone_line.until.is_some() {
+                continued_zone = Some(name.clone());
+            }
+            data.zones.entry(name).or_default().push(zone_line);
+            continue;
+        }
+        match lookup(fields[0].as_bytes(), LINE_TYPES) {
+            Some(0) => {
+                let (name, rule) = rule(&fields[1..], line)?;
+                data.rules.entry(name).or_default().push(rule);
+            }
+            Some(1) => {
+                let name = fields.get(1).ok_or(Error::ZicSyntax(
+                    line,
+                    "wrong number of fields on Zone line",
+                ))?;
+                if data.zones.contains_key(name) {
+                    return Err(Error::ZicSyntax(line, "duplicate zone name"));
+                }
+                let zone_line = zone_line(&fields[2..], line)?;
+                if zone_line.until.is_some() {
+                    continued_zone = Some(name.clone());
+                }
+                data.zones.insert(name.clone(), vec![zone_line]);
+            }
+            Some(_) => {
+                if fields.len() != 3 {
+                    return Err(Error::ZicSyntax(
+                        line,
+                        "wrong number of fields on Link line",
+                    ));
+                }
+                data.links.insert(fields[2].clone(), fields[1].clone());
+            }
+            None => return Err(Error::ZicSyntax(line, "input line of unknown type")),
+        }
+    }
+    if continued_zone.is_some() {
+        return Err(Error::ZicSyntax(
+            line,
+            "expected continuation line not found",
+        ));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{assert_parse_eq, assert_parse_err};
+    use combine::EasyParser;
+
+    #[test]
+    fn parse_keyword() {
+        assert_parse_eq!(month(), "Jan", 1);
+        assert_parse_eq!(month(), "ja", 1);
+        assert_parse_eq!(month(), "F", 2);
+        assert_parse_eq!(month(), "May", 5);
+        assert_parse_eq!(month(), "Sept", 9);
+        assert_parse_eq!(month(), "DECEMBER", 12);
+        assert_parse_err!(month(), "Ju");
+        assert_parse_err!(month(), "Mayday");
+        assert_parse_eq!(from_year(), "1970", RuleYear::Year(1970));
+        assert_parse_eq!(from_year(), "min", RuleYear::Minimum);
+        assert_parse_eq!(from_year(), "ma", RuleYear::Maximum);
+        assert_parse_err!(from_year(), "o");
+        assert_parse_eq!(to_year(), "o", None::<RuleYear>);
+        assert_parse_eq!(to_year(), "max", Some(RuleYear::Maximum));
+        assert_parse_err!(to_year(), "m");
+    }
+
+    #[test]
+    fn parse_rule_day() {
+        assert_parse_eq!(rule_day(), "5", RuleDay::Day(5));
+        assert_parse_eq!(rule_day(), "lastSun", RuleDay::LastWeekday(0));
+        assert_parse_eq!(rule_day(), "lastTh", RuleDay::LastWeekday(4));
+        assert_parse_eq!(rule_day(), "Sun>=8", RuleDay::WeekdayOnOrAfter(0, 8));
+        assert_parse_eq!(rule_day(), "Sa<=25", RuleDay::WeekdayOnOrBefore(6, 25));
+        assert_parse_err!(rule_day(), "0");
+        assert_parse_err!(rule_day(), "32");
+        assert_parse_err!(rule_day(), "lastS");
+        assert_parse_err!(rule_day(), "Sun");
+        assert_parse_err!(rule_day(), "T>=1");
+    }
+
+    #[test]
+    fn parse_hms() {
+        assert_parse_eq!(hms(), "2", Seconds(7200));
+        assert_parse_eq!(hms(), "-", Seconds(0));
+        assert_parse_eq!(hms(), "0", Seconds(0));
+        assert_parse_eq!(hms(), "-7:52:58", Seconds(-28378));
+        assert_parse_eq!(hms(), "25:00", Seconds(90000));
+        assert_parse_eq!(hms(), "0:19:32.13", Seconds(1172));
+        assert_parse_eq!(hms(), "0:19:32.5", Seconds(1172));
+        assert_parse_eq!(hms(), "0:19:33.5", Seconds(1174));
+        assert_parse_err!(hms(), "1:60");
+        assert_parse_err!(hms(), "");
+    }
+
+    #[test]
+    fn parse_rule_time() {
+        assert_parse_eq!(
+            rule_time(),
+            "2:00",
+            RuleTime {
+                time: Seconds(7200),
+                kind: TimeKind::Wall
+            }
+        );
+        assert_parse_eq!(
+            rule_time(),
+            "1u",
+            RuleTime {
+                time: Seconds(3600),
+                kind: TimeKind::Universal
+            }
+        );
+        assert_parse_eq!(
+            rule_time(),
+            "2s",
+            RuleTime {
+                time: Seconds(7200),
+                kind: TimeKind::Standard
+            }
+        );
+        assert_parse_eq!(save(), "1:00", (Seconds(3600), true));
+        assert_parse_eq!(save(), "-1", (Seconds(-3600), true));
+        assert_parse_eq!(save(), "0", (Seconds(0), false));
+        assert_parse_eq!(save(), "0:30s", (Seconds(1800), false));
+        assert_parse_eq!(save(), "0d", (Seconds(0), true));
+    }
+
+    #[test]
+    fn split_fields() {
+        assert_eq!(
+            fields("R US 1967 2006 - O lastSu 2 0 S # comment"),
+            Some(
+                ["R", "US", "1967", "2006", "-", "O", "lastSu", "2", "0", "S"]
+                    .map(String::from)
+                    .to_vec()
+            ),
+        );
+        assert_eq!(
+            fields("  \"Quoted # field\" \"\" x\"y\"z"),
+            Some(["Quoted # field", "", "xyz"].map(String::from).to_vec()),
+        );
+        assert_eq!(fields("# comment only"), Some(vec![]));
+        assert_eq!(fields("\"unterminated"), None);
+    }
+
+    #[test]
+    fn parse_zic() {
+        let data = zic("# tzdb data
+R Be 1992 ma - Mar lastSu 0 1 D
+R Be 1992 ma - O lastSu 0 0 S
+Z Example/Zone 0:50 - LMT 1900 Jan 1 0u
+-1 Be -01/+00 2000 O
+1 1:00s %z
+L Example/Zone Example/Link
+")
+        .unwrap();
+        assert_eq!(
+            data.rules["Be"][0],
+            Rule {
+                from: RuleYear::Year(1992),
+                to: RuleYear::Maximum,
+                month: 3,
+                day: RuleDay::LastWeekday(0),
+                at: RuleTime {
+                    time: Seconds(0),
+                    kind: TimeKind::Wall,
+                },
+                save: Seconds(3600),
+                is_dst: true,
+                letters: "D".to_string(),
+            }
+        );
+        let zone = &data.zones["Example/Zone"];
+        assert_eq!(zone.len(), 3);
+        assert_eq!(
+            zone[0].until,
+            Some(ZoneUntil {
+                year: 1900,
+                month: 1,
+                day: RuleDay::Day(1),
+                time: RuleTime {
+                    time: Seconds(0),
+                    kind: TimeKind::Universal,
+                },
+            })
+        );
+        assert_eq!(zone[1].rules, ZoneRules::Named("Be".to_string()));
+        assert_eq!(
+            zone[1].until.map(|until| (until.month, until.day)),
+            Some((10, RuleDay::Day(1)))
+        );
+        assert_eq!(
+            zone[2],
+            ZoneLine {
+                std_offset: Seconds(3600),
+                rules: ZoneRules::Fixed {
+                    save: Seconds(3600),
+                    is_dst: false,
+                },
+                format: "%z".to_string(),
+                until: None,
+            }
+        );
+        assert_eq!(data.links["Example/Link"], "Example/Zone");
+    }
+
+    #[test]
+    fn parse_zic_errors() {
+        assert!(matches!(
+            zic("Leap 2016 Dec 31 23:59:60 + S"),
+            Err(Error::ZicSyntax(1, _))
+        ));
+        assert!(matches!(
+            zic("Zone A 0 - LMT 1900\n# end\n"),
+            Err(Error::ZicSyntax(2, "expected continuation line not found"))
+        ));
+        assert!(matches!(
+            zic("Zone A 0 - LMT\nZone A 0 - LMT"),
+            Err(Error::ZicSyntax(2, "duplicate zone name"))
+        ));
+        assert!(matches!(
+            zic("Rule X 2000 1999 - Jan 1 0 0 -"),
+            Err(Error::ZicSyntax(
+                1,
+                "starting year greater than ending year"
+            ))
+        ));
+        assert!(matches!(
+            zic("Zone A 0 - %s%z"),
+            Err(Error::ZicSyntax(1, "invalid abbreviation format"))
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::time::Seconds;
+use crate::data::tzif::{DataBlock, StandardWallIndicator, TzifData, TzifHeader, UtLocalIndicator};
+use crate::error::Error;
+use std::io::{self, Write};
+
+/// Returns an error for data that cannot be written.
+fn invalid_data(message: &'static str) -> Error {
+    Error::Io(io::Error::new(io::ErrorKind::InvalidData, message))
+}
+
+/// Writes a `TZif` header.
+fn header<W: Write>(writer: &mut W, header: &TzifHeader) -> Result<(), Error> {
+    writer.write_all(b"TZif")?;
+    let version = match header.version {
+        1 => 0,
+        version @ 2..=9 => b'0' + version as u8,
+        _ => return Err(invalid_data("unsupported TZif version")),
+    };
+    writer.write_all(&[version])?;
+    writer.write_all(&[0; 15])?;
+    for count in [
+        header.isutcnt,
+        header.isstdcnt,
+        header.leapcnt,
+        header.timecnt,
+        header.typecnt,
+        header.charcnt,
+    ] {
+        let count = u32::try_from(count).map_err(|_| invalid_data("count is out of range"))?;
+        writer.write_all(&count.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+/// Writes a four- or eight-byte time value, depending on the version of the data block.
+fn time<const V: usize, W: Write>(writer: &mut W, time: Seconds) -> Result<(), Error> {
+    match V {
+        1 => {
+            let time = i32::try_from(time.0)
+                .map_err(|_| invalid_data("time is out of range for TZif v1"))?;
+            writer.write_all(&time.to_be_bytes())?;
+        }
+        _ => writer.write_all(&time.0.to_be_bytes())?,
+    }
+    Ok(())
+}
+
+/// Writes a `TZif` data block, which must be consistent with the header.
+///
+/// The time zone designations are written at the indices given by the local time type records,
+/// and any bytes of the designations not covered by a record are NUL.
+fn data_block<const V: usize, W: Write>(
+    writer: &mut W,
+    header: &TzifHeader,
+    block: &DataBlock,
+) -> Result<(), Error> {
+    if block.transition_times.len() != header.timecnt
+        || block.transition_types.len() != header.timecnt
+        || block.local_time_type_records.len() != header.typecnt
+        || block.time_zone_designations.len() != header.typecnt
+        || block.leap_second_records.len() != header.leapcnt
+        || block.standard_wall_indicators.len() != header.isstdcnt
+        || block.ut_local_indicators.len() != header.isutcnt
+    {
+        return Err(invalid_data("data block does not match header counts"));
+    }
+
+    for &transition_time in &block.transition_times {
+        time::<V, _>(writer, transition_time)?;
+    }
+    for &transition_type in &block.transition_types {
+        if transition_type >= header.typecnt {
+            return Err(invalid_data("transition type is out of range"));
+        }
+        writer.write_all(&[transition_type as u8])?;
+    }
+
+    let mut designations = vec![0; header.charcnt];
+    for (record, designation) in block
+        .local_time_type_records
+        .iter()
+        .zip(&block.time_zone_designations)
+    {
+        let utoff =
+            i32::try_from(record.utoff.0).map_err(|_| invalid_data("utoff is out of range"))?;
+        let idx = u8::try_from(record.idx).map_err(|_| invalid_data("idx is out of range"))?;
+        writer.write_all(&utoff.to_be_bytes())?;
+        writer.write_all(&[u8::from(record.is_dst), idx])?;
+        designations
+            .get_mut(record.idx..record.idx + designation.len() + 1)
+            .ok_or_else(|| invalid_data("time zone designation exceeds charcnt"))?
+            .copy_from_slice(format!("{}\0", designation).as_bytes());
+    }
+    writer.write_all(&designations)?;
+
+    for record in &block.leap_second_records {
+        time::<V, _>(writer, record.occurrence)?;
+        writer.write_all(&record.correction.to_be_bytes())?;
+    }
+    for indicator in &block.standard_wall_indicators {
+        writer.write_all(&[u8::from(*indicator == StandardWallIndicator::Standard)])?;
+    }
+    for indicator in &block.ut_local_indicators {
+        writer.write_all(&[u8::from(*indicator == UtLocalIndicator::Ut)])?;
+    }
+    Ok(())
+}
+
+/// Writes `TZif` data according to the following specification:
+/// <https://datatracker.ietf.org/doc/html/rfc8536>
+///
+/// The data must be consistent: each data block must match the counts of its header,
+/// and version 2 and 3 data must have a version-2+ header and data block.
+pub fn tzif<W: Write>(writer: &mut W, data: &TzifData) -> Result<(), Error> {
+    header(writer, &data.header1)?;
+    data_block::<1, _>(writer, &data.header1, &data.data_block1)?;
+    if data.header1.version == 1 {
+        return Ok(());
+    }
+    match (&data.header2, &data.data_block2) {
+        (Some(header2), Some(data_block2)) => {
+            header(writer, header2)?;
+            data_block::<2, _>(writer, header2, data_block2)?;
+        }
+        _ => return Err(invalid_data("version-2+ header or data block is missing")),
+    }
+    match &data.footer {
+        Some(footer) => writeln!(writer, "\n{}", footer)?,
+        None => writer.write_all(b"\n\n")?,
+    }
+    Ok(())
+}
@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use combine::Parser;
+use std::path::Path;
+use tzif::data::posix::PosixTzString;
+use tzif::data::time::Seconds;
+use tzif::data::tzif::{DataBlock, TzifData};
+use walkdir::WalkDir;
+
+/// A local time type, with its designation in place of an index.
+type LocalTimeType = (Seconds, bool, String);
+
+/// The observable behavior of `TZif` data: the local time type before the first transition,
+/// the transitions that change the local time type, and the footer.
+#[derive(Debug, PartialEq)]
+struct Behavior {
+    initial: LocalTimeType,
+    transitions: Vec<(Seconds, LocalTimeType)>,
+    footer: Option<PosixTzString>,
+}
+
+impl Behavior {
+    fn new(data: &TzifData) -> Self {
+        let block: &DataBlock = data.data_block2.as_ref().unwrap_or(&data.data_block1);
+        let local_time_type = |index: usize| {
+            (
+                block.local_time_type_records[index].utoff,
+                block.local_time_type_records[index].is_dst,
+                block.time_zone_designations[index].clone(),
+            )
+        };
+        let initial = local_time_type(0);
+        let mut transitions: Vec<(Seconds, LocalTimeType)> = Vec::new();
+        for (&time, &index) in block.transition_times.iter().zip(&block.transition_types) {
+            let current = transitions.last().map_or(&initial, |(_, current)| current);
+            if *current != local_time_type(index) {
+                transitions.push((time, local_time_type(index)));
+            }
+        }
+        Behavior {
+            initial,
+            transitions,
+            footer: data.footer.clone(),
+        }
+    }
+
+    /// Truncates the transitions to those that occur no later than the given time.
+    fn truncate(mut self, time: Seconds) -> Self {
+        self.transitions
+            .retain(|&(transition_time, _)| transition_time <= time);
+        self
+    }
+}
+
+fn write_and_parse(data: &TzifData) -> Result<TzifData, tzif::error::Error> {
+    let mut bytes = Vec::new();
+    tzif::write::tzif(&mut bytes, data)?;
+    let (parsed, _) = tzif::parse::tzif::tzif().parse(bytes.as_slice())?;
+    Ok(parsed)
+}
+
+/// Compiles each zone and link of a zic source file, comparing the behavior of the
+/// compiled data with the `TZif` file of the same name in the directory.
+fn compile_and_compare<P: AsRef<Path>>(source: P, compiled: P, truncate: bool) {
+    let data = tzif::parse_zic_file(source).unwrap();
+    let names = data.zones.keys().chain(data.links.keys());
+    let mut count = 0;
+    for name in names {
+        let path = compiled.as_ref().join(name);
+        if !path.is_file() {
+            continue;
+        }
+        let tzif = data
+            .compile(name)
+            .unwrap_or_else(|err| panic!("failed to compile {}: {}", name, err));
+        assert_eq!(write_and_parse(&tzif).unwrap(), tzif, "{}", name);
+
+        let expected = Behavior::new(&tzif::parse_tzif_file(&path).unwrap());
+        let actual = Behavior::new(&tzif);
+        let expected = match actual.transitions.last() {
+            // Files compiled with `-b fat` also list the transitions predicted by the footer.
+            Some(&(last, _)) if truncate => expected.truncate(last),
+            _ => expected,
+        };
+        assert_eq!(actual, expected, "{}", name);
+        count += 1;
+    }
+    assert!(count > 0, "no compiled files to compare");
+}
+
+#[test]
+fn write_tzif_testdata() {
+    for entry in WalkDir::new("testdata").follow_links(true) {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            let data = tzif::parse_tzif_file(entry.path()).unwrap();
+            assert_eq!(write_and_parse(&data).unwrap(), data, "{:?}", entry.path());
+        }
+    }
+}
+
+#[test]
+fn posix_tz_string_round_trip() {
+    for string in [
+        "EST5EDT,M3.2.0,M11.1.0",
+        "IST-1GMT0,M10.5.0,M3.5.0/1",
+        "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+        "<-04>4<-03>,M9.1.6/24,M4.1.6/24",
+        "NZST-12NZDT,M9.5.0,M4.1.0/3",
+        "XXX-2<+01>-1,0/0,J365/23",
+        "<+0545>-5:45",
+    ] {
+        let parsed = tzif::parse_posix_tz_string(string.as_bytes()).unwrap();
+        assert_eq!(parsed.to_string(), string);
+    }
+}
+
+/// The files in `zicdata/compiled` were compiled from `zicdata/tzdata.zi` with
+/// `zic -b slim -d zicdata/compiled zicdata/tzdata.zi`.
+#[test]
+fn compile_zic_testdata() {
+    compile_and_compare("zicdata/tzdata.zi", "zicdata/compiled", false);
+}
+
+/// Compiles the source of the system time zone database, which many systems do not provide.
+#[test]
+#[ignore]
+fn compile_system_tzdata() {
+    compile_and_compare("/usr/share/zoneinfo/tzdata.zi", "/usr/share/zoneinfo", true);
+}
+
+#[test]
+fn compile_errors() {
+    let data = tzif::parse_zic_source(
+        "Rule A 2000 max - Mar lastSun 2:00 1:00 D
+         Rule A 2000 max - Oct lastSun 3:00 2:00 D
+         Zone No/Footer 0 A X%sT
+         Zone Undefined/Rule 0 B X%sT
+         Link Cycle/A Cycle/B
+         Link Cycle/B Cycle/A",
+    )
+    .unwrap();
+    for (name, message) in [
+        (
+            "No/Footer",
+            "no POSIX TZ string can represent future timestamps",
+        ),
+        ("Undefined/Rule", "undefined rule"),
+        ("Unknown/Zone", "unknown zone or link"),
+        ("Cycle/A", "link cycle"),
+    ] {
+        match data.compile(name) {
+            Err(tzif::error::Error::ZicCompile(zone, actual)) => {
+                assert_eq!((zone.as_str(), actual), (name, message));
+            }
+            result => panic!("expected an error compiling {}, got {:?}", name, result),
+        }
+    }
+}
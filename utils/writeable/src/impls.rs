@@ -194,6 +194,55 @@ impl<'a, T: Writeable + ?Sized> Writeable for &T {
     }
 }
 
+/// Writes whichever of the two [`Writeable`]s is present, so that values of two different types,
+/// such as a formatted number and a string, can be written where a single type is expected
+/// without converting them to strings first.
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use writeable::assert_writeable_eq;
+///
+/// let values: [Either<u32, &str>; 2] = [Either::Left(42), Either::Right("many")];
+/// assert_writeable_eq!(&values[0], "42");
+/// assert_writeable_eq!(&values[1], "many");
+/// ```
+#[cfg(feature = "either")]
+impl<L: Writeable, R: Writeable> Writeable for either::Either<L, R> {
+    #[inline]
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            either::Either::Left(w) => w.write_to(sink),
+            either::Either::Right(w) => w.write_to(sink),
+        }
+    }
+
+    #[inline]
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            either::Either::Left(w) => w.write_to_parts(sink),
+            either::Either::Right(w) => w.write_to_parts(sink),
+        }
+    }
+
+    #[inline]
+    fn write_len(&self) -> LengthHint {
+        match self {
+            either::Either::Left(w) => w.write_len(),
+            either::Either::Right(w) => w.write_len(),
+        }
+    }
+
+    #[inline]
+    fn write_to_string(&self) -> Cow<str> {
+        match self {
+            either::Either::Left(w) => w.write_to_string(),
+            either::Either::Right(w) => w.write_to_string(),
+        }
+    }
+}
+
 #[test]
 fn test_string_impls() {
     fn check_writeable_slice<W: Writeable>(writeables: &[W]) {